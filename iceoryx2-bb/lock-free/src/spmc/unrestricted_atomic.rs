@@ -152,6 +152,13 @@ impl UnrestrictedAtomicMgmt {
         }
     }
 
+    #[doc(hidden)]
+    /// Returns a counter that is incremented with every store operation. It can be used to
+    /// detect whether the value was updated since it was loaded the last time.
+    pub fn __internal_get_write_generation(&self) -> u32 {
+        self.write_cell.load(Ordering::Acquire)
+    }
+
     #[doc(hidden)]
     /// # Safety
     ///
@@ -175,6 +182,30 @@ impl UnrestrictedAtomicMgmt {
         value_size: usize,
         value_alignment: usize,
         data_ptr: *const u8,
+    ) {
+        unsafe {
+            self.__internal_load_with(value_size, value_alignment, data_ptr, |data_cell_ptr| {
+                core::ptr::copy_nonoverlapping(data_cell_ptr, value_ptr, value_size);
+            })
+        }
+    }
+
+    #[doc(hidden)]
+    /// Calls `reader` with a pointer to the most recently written data cell until the
+    /// content could be read without being overwritten by the producer in the meantime. Only
+    /// the result of the last call of `reader` represents a consistent snapshot.
+    ///
+    /// # Safety
+    ///
+    ///   * see Safety section of core::ptr::add
+    ///   * `reader` must only copy from the provided cell and must not rely on the consistency
+    ///     of the read content, since it can be modified concurrently
+    pub unsafe fn __internal_load_with<F: FnMut(*const u8)>(
+        &self,
+        value_size: usize,
+        value_alignment: usize,
+        data_ptr: *const u8,
+        mut reader: F,
     ) {
         /////////////////////////
         // SYNC POINT - read
@@ -189,7 +220,7 @@ impl UnrestrictedAtomicMgmt {
                     data_ptr,
                     read_cell,
                 );
-                core::ptr::copy_nonoverlapping(data_cell_ptr as *const u8, value_ptr, value_size);
+                reader(data_cell_ptr as *const u8);
             }
 
             /////////////////////////
@@ -131,6 +131,24 @@ fn spmc_unrestricted_atomic_get_ptr_write_and_update_works() {
     assert_that!(sut.load(), eq 1);
 }
 
+#[test]
+fn spmc_unrestricted_atomic_write_generation_is_incremented_with_every_store() {
+    let _test_lock = TEST_LOCK.lock().unwrap();
+    let sut = UnrestrictedAtomic::<u32>::new(0);
+    let p = sut.acquire_producer().unwrap();
+
+    let initial_generation = sut.__internal_get_mgmt().__internal_get_write_generation();
+    assert_that!(sut.__internal_get_mgmt().__internal_get_write_generation(), eq initial_generation);
+
+    p.store(1);
+    assert_that!(sut.__internal_get_mgmt().__internal_get_write_generation(), eq initial_generation + 1);
+
+    let entry = unsafe { p.__internal_get_ptr_to_write_cell() };
+    unsafe { *entry = 2 };
+    unsafe { p.__internal_update_write_cell() };
+    assert_that!(sut.__internal_get_mgmt().__internal_get_write_generation(), eq initial_generation + 2);
+}
+
 #[test]
 fn spmc_unrestricted_atomic_get_ptr_write_and_update_works_concurrently() {
     let _test_lock = TEST_LOCK.lock().unwrap();
@@ -35,6 +35,53 @@
 //!         CallbackProgression::Continue
 //!     });
 //! ```
+//!
+//! ## Timers
+//!
+//! Besides fixed intervals, the [`DeadlineQueue`] supports timers that fire at absolute
+//! time points, one-shot timers and intervals that are aligned to the period boundaries of
+//! the underlying clock. They are described with a [`TimerSchedule`].
+//!
+//! ```no_run
+//! use iceoryx2_bb_posix::clock::*;
+//! use iceoryx2_bb_posix::deadline_queue::*;
+//! use core::time::Duration;
+//!
+//! let deadline_queue = DeadlineQueueBuilder::new().create().unwrap();
+//!
+//! // fires on every full 100ms of the clock, for instance at 1.1s, 1.2s, 1.3s, ...
+//! let guard_1 = deadline_queue.add_timer(
+//!     TimerSchedule::aligned_interval(Duration::from_millis(100))
+//!         // when ticks were missed, all of them are reported
+//!         .missed_tick_policy(MissedTickPolicy::CatchUp),
+//! );
+//!
+//! // fires once in 5 seconds
+//! let guard_2 = deadline_queue.add_timer(TimerSchedule::one_shot_after(Duration::from_secs(5)));
+//!
+//! // fires once at an absolute wall-clock time, adjustments of the system time are respected
+//! let time = TimeBuilder::new()
+//!     .clock_type(ClockType::Realtime)
+//!     .seconds(4102444800)
+//!     .create();
+//! let guard_3 = deadline_queue.add_timer(TimerSchedule::one_shot_at(time));
+//! ```
+//!
+//! ### Clocks
+//!
+//! Relative timers and aligned intervals are measured with the [`ClockType`] of the
+//! [`DeadlineQueue`], see [`DeadlineQueueBuilder::clock_type()`]. Timers that fire at an
+//! absolute [`Time`] are measured with the [`ClockType`] of that [`Time`] instead, so that a
+//! [`ClockType::Realtime`] time point still fires at the intended wall-clock time when the
+//! system time is adjusted after the timer was added. Such an adjustment is observed with
+//! the next call to [`DeadlineQueue::duration_until_next_deadline()`] or
+//! [`DeadlineQueue::missed_deadlines()`].
+//!
+//! An aligned interval is aligned to the epoch of the [`DeadlineQueue`]s clock. The epoch of
+//! [`ClockType::Monotonic`] is unspecified, commonly the system boot, therefore the
+//! boundaries are not aligned to the wall-clock. Use a [`DeadlineQueue`] with
+//! [`ClockType::Realtime`] when the ticks shall be aligned to the wall-clock, for instance
+//! on every full second of the system time.
 
 pub use iceoryx2_bb_elementary::CallbackProgression;
 
@@ -109,56 +156,246 @@ impl DeadlineQueueBuilder {
 
     /// Creates a new [`DeadlineQueue`]
     pub fn create(self) -> Result<DeadlineQueue, TimeError> {
-        let start_time = fail!(from "DeadlineQueue::new()", when Time::now_with_clock(self.clock_type),
-                                "Failed to create DeadlineQueue since the current time could not be acquired.");
-        let start_time = start_time.as_duration().as_nanos();
+        fail!(from "DeadlineQueue::new()", when Time::now_with_clock(self.clock_type),
+                "Failed to create DeadlineQueue since the current time could not be acquired.");
 
         Ok(DeadlineQueue {
             attachments: RefCell::new(vec![]),
             id_count: IoxAtomicU64::new(0),
             clock_type: self.clock_type,
-            previous_iteration: RefCell::new(start_time),
         })
     }
 }
 
+/// Defines how a periodic timer of the [`DeadlineQueue`] handles ticks that were missed,
+/// for instance since [`DeadlineQueue::missed_deadlines()`] was called too late.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MissedTickPolicy {
+    /// All missed ticks are reported once and the timer continues with the next tick in the
+    /// future.
+    #[default]
+    Skip,
+    /// Every missed tick is reported so that the number of reported ticks is always equal to
+    /// the number of elapsed periods.
+    CatchUp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimerStart {
+    After(Duration),
+    At(Time),
+    Aligned,
+}
+
+/// Describes when a timer that is added with [`DeadlineQueue::add_timer()`] fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerSchedule {
+    start: TimerStart,
+    period: Option<Duration>,
+    missed_tick_policy: MissedTickPolicy,
+}
+
+impl TimerSchedule {
+    fn new(start: TimerStart, period: Option<Duration>) -> Self {
+        Self {
+            start,
+            period,
+            missed_tick_policy: MissedTickPolicy::default(),
+        }
+    }
+
+    /// A periodic timer that fires for the first time one period after it was added.
+    /// It is equal to [`DeadlineQueue::add_deadline_interval()`].
+    pub fn interval(period: Duration) -> Self {
+        Self::new(TimerStart::After(period), Some(period))
+    }
+
+    /// A periodic timer that fires for the first time at the provided [`Time`] and then
+    /// every period. The timer is measured with the [`ClockType`] of the [`Time`], even when
+    /// the [`DeadlineQueue`] uses another [`ClockType`].
+    pub fn interval_starting_at(start: Time, period: Duration) -> Self {
+        Self::new(TimerStart::At(start), Some(period))
+    }
+
+    /// A periodic timer that fires on every multiple of the period of the [`DeadlineQueue`]s
+    /// clock, for instance on every full second. The multiples are counted from the epoch of
+    /// the [`DeadlineQueue`]s [`ClockType`], see the [module documentation](self) for
+    /// aligning the ticks to the wall-clock.
+    pub fn aligned_interval(period: Duration) -> Self {
+        Self::new(TimerStart::Aligned, Some(period))
+    }
+
+    /// A timer that fires exactly once after the provided delay.
+    pub fn one_shot_after(delay: Duration) -> Self {
+        Self::new(TimerStart::After(delay), None)
+    }
+
+    /// A timer that fires exactly once at the provided [`Time`]. The timer is measured with
+    /// the [`ClockType`] of the [`Time`], even when the [`DeadlineQueue`] uses another
+    /// [`ClockType`].
+    pub fn one_shot_at(time: Time) -> Self {
+        Self::new(TimerStart::At(time), None)
+    }
+
+    /// Defines the [`MissedTickPolicy`] of a periodic timer. By default it is
+    /// [`MissedTickPolicy::Skip`].
+    pub fn missed_tick_policy(mut self, value: MissedTickPolicy) -> Self {
+        self.missed_tick_policy = value;
+        self
+    }
+}
+
+/// Acquires the current time of every [`ClockType`] at most once per
+/// [`DeadlineQueue`] operation.
+struct Now {
+    monotonic: Option<u128>,
+    realtime: Option<u128>,
+    msg: &'static str,
+}
+
+impl Now {
+    fn new(msg: &'static str) -> Self {
+        Self {
+            monotonic: None,
+            realtime: None,
+            msg,
+        }
+    }
+
+    fn of(&mut self, clock_type: ClockType) -> Result<u128, TimeError> {
+        let value = match clock_type {
+            ClockType::Monotonic => &mut self.monotonic,
+            ClockType::Realtime => &mut self.realtime,
+        };
+
+        match value {
+            Some(now) => Ok(*now),
+            None => {
+                let now = now_in_nanos(clock_type, self.msg)?;
+                *value = Some(now);
+                Ok(now)
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Attachment {
     index: u64,
-    period: u128,
-    start_time: u128,
+    // the clock with which next_deadline is measured
+    clock_type: ClockType,
+    period: Option<u128>,
+    is_aligned: bool,
+    initial_delay: u128,
+    next_deadline: u128,
+    is_expired: bool,
+    missed_tick_policy: MissedTickPolicy,
 }
 
 impl Attachment {
-    fn new(index: u64, period: u128, clock_type: ClockType) -> Result<Self, TimeError> {
-        let start_time = fail!(from "Attachment::new()", when Time::now_with_clock(clock_type),
-                                "Failed to create DeadlineQueue attachment since the current time could not be acquired.");
-        let start_time = start_time.as_duration().as_nanos();
+    fn new(index: u64, schedule: &TimerSchedule, clock_type: ClockType) -> Result<Self, TimeError> {
+        let period = schedule.period.map(|p| p.as_nanos());
+        let (clock_type, now, next_deadline) = match schedule.start {
+            TimerStart::At(time) => {
+                let clock_type = time.clock_type();
+                let now = now_in_nanos(clock_type, "Failed to create DeadlineQueue attachment")?;
+                (clock_type, now, time.as_duration().as_nanos())
+            }
+            start => {
+                let now = now_in_nanos(clock_type, "Failed to create DeadlineQueue attachment")?;
+                let next_deadline = match (start, period) {
+                    (TimerStart::Aligned, Some(p)) if p != 0 => (now / p + 1) * p,
+                    (TimerStart::After(delay), _) => now + delay.as_nanos(),
+                    _ => now,
+                };
+                (clock_type, now, next_deadline)
+            }
+        };
 
         Ok(Self {
             index,
+            clock_type,
             period,
-            start_time,
+            is_aligned: schedule.start == TimerStart::Aligned,
+            initial_delay: next_deadline.saturating_sub(now),
+            next_deadline,
+            is_expired: false,
+            missed_tick_policy: schedule.missed_tick_policy,
         })
     }
 
     fn reset(&mut self, clock_type: ClockType) -> Result<(), TimeError> {
-        let start_time = fail!(from "Attachment::new()", when Time::now_with_clock(clock_type),
-                                "Failed to reset DeadlineQueue attachment since the current time could not be acquired.");
-        self.start_time = start_time.as_duration().as_nanos();
+        // a reset restarts the timer relative to now, therefore it is measured with the clock
+        // of the DeadlineQueue afterwards
+        let now = now_in_nanos(clock_type, "Failed to reset DeadlineQueue attachment")?;
+        self.clock_type = clock_type;
+        self.next_deadline = match self.period {
+            Some(p) if self.is_aligned && p != 0 => (now / p + 1) * p,
+            Some(p) => now + p,
+            None => now + self.initial_delay,
+        };
+        self.is_expired = false;
         Ok(())
     }
+
+    fn duration_until_next_deadline(&self, now: u128) -> Option<u128> {
+        match self.period {
+            _ if self.is_expired => None,
+            Some(0) => Some(0),
+            _ => Some(self.next_deadline.saturating_sub(now)),
+        }
+    }
+
+    fn handle_missed_deadlines<F: FnMut(DeadlineQueueIndex) -> CallbackProgression>(
+        &mut self,
+        now: u128,
+        call: &mut F,
+    ) -> CallbackProgression {
+        let index = DeadlineQueueIndex(self.index);
+        match self.period {
+            _ if self.is_expired || now < self.next_deadline => CallbackProgression::Continue,
+            None => {
+                self.is_expired = true;
+                call(index)
+            }
+            Some(0) => call(index),
+            Some(period) => {
+                let missed_ticks = (now - self.next_deadline) / period + 1;
+                match self.missed_tick_policy {
+                    MissedTickPolicy::Skip => {
+                        self.next_deadline += missed_ticks * period;
+                        call(index)
+                    }
+                    MissedTickPolicy::CatchUp => {
+                        for _ in 0..missed_ticks {
+                            self.next_deadline += period;
+                            if call(index) == CallbackProgression::Stop {
+                                return CallbackProgression::Stop;
+                            }
+                        }
+                        CallbackProgression::Continue
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn now_in_nanos(clock_type: ClockType, msg: &str) -> Result<u128, TimeError> {
+    let now = fail!(from "DeadlineQueue", when Time::now_with_clock(clock_type),
+                    "{msg} since the current time could not be acquired.");
+    Ok(now.as_duration().as_nanos())
 }
 
 /// The [`DeadlineQueue`] allows the user to attach multiple periodic deadline_queues with
-/// [`DeadlineQueue::add_deadline_interval()`], to wait on them by acquiring the waiting time to the next deadline_queue
+/// [`DeadlineQueue::add_deadline_interval()`] or timers with [`DeadlineQueue::add_timer()`],
+/// to wait on them by acquiring the waiting time to the next deadline_queue
 /// with [`DeadlineQueue::duration_until_next_deadline()`] and to acquire all missed deadline_queues via
 /// [`DeadlineQueue::missed_deadlines()`].
 #[derive(Debug)]
 pub struct DeadlineQueue {
     attachments: RefCell<Vec<Attachment>>,
     id_count: IoxAtomicU64,
-    previous_iteration: RefCell<u128>,
 
     clock_type: ClockType,
 }
@@ -182,10 +419,18 @@ impl DeadlineQueue {
         &self,
         deadline: Duration,
     ) -> Result<DeadlineQueueGuard<'_>, TimeError> {
+        self.add_timer(TimerSchedule::interval(deadline))
+    }
+
+    /// Adds a timer that fires according to the provided [`TimerSchedule`] and returns a
+    /// [`DeadlineQueueGuard`] to identify the attachment uniquely. One-shot timers are
+    /// reported once by [`DeadlineQueue::missed_deadlines()`] and remain in the
+    /// [`DeadlineQueue`] without firing again until they are reset or removed.
+    pub fn add_timer(&self, schedule: TimerSchedule) -> Result<DeadlineQueueGuard<'_>, TimeError> {
         let current_idx = self.id_count.load(Ordering::Relaxed);
         self.attachments.borrow_mut().push(Attachment::new(
             current_idx,
-            deadline.as_nanos(),
+            &schedule,
             self.clock_type,
         )?);
         self.id_count.fetch_add(1, Ordering::Relaxed);
@@ -223,86 +468,45 @@ impl DeadlineQueue {
     }
 
     /// Returns the waiting duration until the next deadline is reached. If there have been
-    /// already deadlines missed it returns a duration of zero.
+    /// already deadlines missed it returns a duration of zero. If no timer will fire anymore,
+    /// it returns [`Duration::MAX`].
     pub fn duration_until_next_deadline(&self) -> Result<Duration, TimeError> {
         if self.is_empty() {
             return Ok(Duration::MAX);
         }
 
-        let now = fail!(from self, when Time::now_with_clock(self.clock_type),
-                        "Unable to return next duration since the current time could not be acquired.");
-        let now = now.as_duration().as_nanos();
-        let mut has_missed_deadline = false;
-        self.handle_missed_deadlines(now, |_| {
-            has_missed_deadline = true;
-            CallbackProgression::Stop
-        });
-
-        if has_missed_deadline {
-            return Ok(Duration::ZERO);
-        }
-
-        // must be set after the return caused by a missed deadline, otherwise the user is unable
-        // to acquire the missed deadline
-        *self.previous_iteration.borrow_mut() = now;
-
-        let mut min_time = u128::MAX;
+        let mut now = Now::new("Unable to return next duration");
+        let mut min_time = None;
         for attachment in &*self.attachments.borrow() {
-            min_time =
-                min_time.min(attachment.period - (now - attachment.start_time) % attachment.period);
+            let now = now.of(attachment.clock_type)?;
+            min_time = match (min_time, attachment.duration_until_next_deadline(now)) {
+                (Some(a), Some(b)) => Some(core::cmp::min(a, b)),
+                (a, b) => a.or(b),
+            };
         }
 
-        Ok(Duration::from_nanos(min_time as _))
-    }
-
-    fn handle_missed_deadlines<F: FnMut(DeadlineQueueIndex) -> CallbackProgression>(
-        &self,
-        now: u128,
-        mut call: F,
-    ) {
-        let last = *self.previous_iteration.borrow();
-
-        for attachment in &*self.attachments.borrow() {
-            let duration_until_last = last.max(attachment.start_time) - attachment.start_time;
-            let duration_until_now = now - attachment.start_time;
-            match attachment.period {
-                0 => {
-                    if matches!(
-                        call(DeadlineQueueIndex(attachment.index)),
-                        CallbackProgression::Stop
-                    ) {
-                        return;
-                    }
-                }
-                _ => {
-                    let last = duration_until_last / attachment.period;
-                    let current = duration_until_now / attachment.period;
-
-                    if last < current
-                        && matches!(
-                            call(DeadlineQueueIndex(attachment.index)),
-                            CallbackProgression::Stop
-                        )
-                    {
-                        return;
-                    }
-                }
-            }
+        match min_time {
+            Some(min_time) => Ok(Duration::from_nanos(min_time.min(u64::MAX as u128) as u64)),
+            None => Ok(Duration::MAX),
         }
     }
 
     /// Iterates over all missed deadlines and calls the provided callback for each of them
-    /// and provide the [`DeadlineQueueIndex`] to identify them.
+    /// and provide the [`DeadlineQueueIndex`] to identify them. Depending on the
+    /// [`MissedTickPolicy`] of a timer, its [`DeadlineQueueIndex`] is provided once or once
+    /// for every missed tick.
     pub fn missed_deadlines<F: FnMut(DeadlineQueueIndex) -> CallbackProgression>(
         &self,
         mut call: F,
     ) -> Result<(), TimeError> {
-        let now = fail!(from self, when Time::now_with_clock(self.clock_type),
-                        "Unable to return next duration since the current time could not be acquired.");
+        let mut now = Now::new("Unable to handle missed deadlines");
 
-        let now = now.as_duration().as_nanos();
-        self.handle_missed_deadlines(now, |idx| -> CallbackProgression { call(idx) });
-        *self.previous_iteration.borrow_mut() = now;
+        for attachment in &mut *self.attachments.borrow_mut() {
+            let now = now.of(attachment.clock_type)?;
+            if attachment.handle_missed_deadlines(now, &mut call) == CallbackProgression::Stop {
+                break;
+            }
+        }
 
         Ok(())
     }
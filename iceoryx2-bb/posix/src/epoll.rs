@@ -0,0 +1,368 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Abstracts the Linux epoll facility. Like the
+//! [`FileDescriptorSet`](crate::file_descriptor_set::FileDescriptorSet) it can be used to wait
+//! on multiple objects which implement the [`SynchronousMultiplexing`] trait but the number of
+//! attachments is only limited by the file descriptor limit of the system and the cost of a
+//! wait call does not depend on the number of attachments.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use iceoryx2_bb_posix::epoll::*;
+//! use iceoryx2_bb_posix::unix_datagram_socket::*;
+//! use core::time::Duration;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! let socket_name = FilePath::new(b"some_socket").unwrap();
+//!
+//! let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+//!     .creation_mode(CreationMode::PurgeAndCreate)
+//!     .create()
+//!     .unwrap();
+//!
+//! let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+//!     .create()
+//!     .unwrap();
+//!
+//! let epoll = EpollBuilder::new().create().unwrap();
+//! let _guard = epoll.add(&sut_receiver).unwrap();
+//! let send_data: Vec<u8> = vec![1u8, 3u8, 3u8, 7u8, 13u8, 37u8];
+//! sut_sender.try_send(send_data.as_slice()).unwrap();
+//!
+//! // in some other process
+//! let result = epoll.timed_wait(Duration::from_secs(1),
+//!     |fd| println!("Fd was triggered {}", unsafe { fd.native_handle() })).unwrap();
+//! ```
+
+use core::{cell::Cell, fmt::Debug, time::Duration};
+
+use crate::{
+    clock::{ClockType, Time},
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::SynchronousMultiplexing,
+    handle_errno,
+    system_configuration::Limit,
+};
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_pal_posix::posix::{self, Errno};
+
+/// The maximum number of events that are acquired from the kernel with a single wait call.
+/// When more attachments are triggered, the remaining ones are reported in the next wait call.
+const MAX_EVENTS_PER_WAIT: usize = 256;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollCreateError {
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientMemory,
+    FileDescriptorBroken,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollAttachError {
+    AlreadyAttached,
+    UnsupportedFileDescriptor,
+    InsufficientMemory,
+    WatchLimitReached,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollMaskError {
+    InsufficientMemory,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollWaitError {
+    Interrupt,
+    UnknownError(i32),
+}
+
+/// Keeps an object attached to the [`Epoll`]. As soon as it goes out of scope the object is
+/// detached.
+pub struct EpollGuard<'epoll, 'fd> {
+    epoll: &'epoll Epoll,
+    fd: &'fd FileDescriptor,
+}
+
+impl<'fd> EpollGuard<'_, 'fd> {
+    pub fn file_descriptor(&self) -> &'fd FileDescriptor {
+        self.fd
+    }
+
+    /// Masks the attached object so that it is no longer reported in the wait calls without
+    /// detaching it.
+    pub fn mask(&self) -> Result<(), EpollMaskError> {
+        self.epoll.modify(self.fd, 0)
+    }
+
+    /// Reverts [`EpollGuard::mask()`] so that the attached object is reported again whenever
+    /// it has data to read.
+    pub fn unmask(&self) -> Result<(), EpollMaskError> {
+        self.epoll.modify(self.fd, posix::EPOLLIN)
+    }
+}
+
+impl Drop for EpollGuard<'_, '_> {
+    fn drop(&mut self) {
+        self.epoll.remove(unsafe { self.fd.native_handle() })
+    }
+}
+
+/// Creates a new [`Epoll`].
+#[derive(Debug, Default)]
+pub struct EpollBuilder {}
+
+impl EpollBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`Epoll`]. The underlying file descriptor is closed on exec.
+    pub fn create(self) -> Result<Epoll, EpollCreateError> {
+        let msg = "Unable to create epoll";
+        let raw_fd = unsafe { posix::epoll_create1(posix::EPOLL_CLOEXEC) };
+
+        if raw_fd != -1 {
+            match FileDescriptor::new(raw_fd) {
+                Some(epoll_fd) => {
+                    return Ok(Epoll {
+                        epoll_fd,
+                        len: Cell::new(0),
+                    })
+                }
+                None => {
+                    fail!(from self, with EpollCreateError::FileDescriptorBroken,
+                        "This should never happen! {msg} since epoll_create1 returned a broken file descriptor.");
+                }
+            }
+        }
+
+        handle_errno!(EpollCreateError, from self,
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{msg} since the processes file descriptor limit was reached."),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{msg} since the system wide file descriptor limit was reached."),
+            Errno::ENOMEM => (InsufficientMemory, "{msg} due to insufficient memory."),
+            v => (UnknownError(v as i32), "{msg} since an unknown error occurred ({v}).")
+        )
+    }
+}
+
+/// The Linux epoll abstraction to wait on multiple objects which implement the
+/// [`SynchronousMultiplexing`] trait.
+#[derive(Debug)]
+pub struct Epoll {
+    epoll_fd: FileDescriptor,
+    len: Cell<usize>,
+}
+
+impl FileDescriptorBased for Epoll {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.epoll_fd
+    }
+}
+
+impl SynchronousMultiplexing for Epoll {}
+
+impl Epoll {
+    /// Attaches an object so that it is reported in the wait calls whenever it has data to
+    /// read. The object stays attached until the returned [`EpollGuard`] goes out of scope.
+    pub fn add<'epoll, 'fd, F: SynchronousMultiplexing>(
+        &'epoll self,
+        fd: &'fd F,
+    ) -> Result<EpollGuard<'epoll, 'fd>, EpollAttachError> {
+        self.add_impl(fd.file_descriptor())
+    }
+
+    fn add_impl<'epoll, 'fd>(
+        &'epoll self,
+        fd: &'fd FileDescriptor,
+    ) -> Result<EpollGuard<'epoll, 'fd>, EpollAttachError> {
+        let msg = "Unable to add file descriptor";
+        let raw_fd = unsafe { fd.native_handle() };
+        let mut event = posix::epoll_event_new(posix::EPOLLIN, raw_fd as u64);
+
+        if unsafe {
+            posix::epoll_ctl(
+                self.epoll_fd.native_handle(),
+                posix::EPOLL_CTL_ADD,
+                raw_fd,
+                &mut event,
+            )
+        } == 0
+        {
+            self.len.set(self.len.get() + 1);
+            return Ok(EpollGuard { epoll: self, fd });
+        }
+
+        handle_errno!(EpollAttachError, from self,
+            fatal Errno::EBADF => ("This should never happen! {msg} {:?} since either the epoll or the attached file descriptor is invalid.", fd),
+            Errno::EPERM => (UnsupportedFileDescriptor, "{msg} {:?} since it does not support epoll.", fd),
+            Errno::EEXIST => (AlreadyAttached, "{msg} {:?} since it is already attached.", fd),
+            Errno::ENOMEM => (InsufficientMemory, "{msg} {:?} due to insufficient memory.", fd),
+            Errno::ENOSPC => (WatchLimitReached, "{msg} {:?} since the system wide limit of epoll watches was reached.", fd),
+            v => (UnknownError(v as i32), "{msg} {:?} since an unknown error occurred ({v}).", fd)
+        )
+    }
+
+    fn modify(&self, fd: &FileDescriptor, events: u32) -> Result<(), EpollMaskError> {
+        let msg = "Unable to modify the events of file descriptor";
+        let raw_fd = unsafe { fd.native_handle() };
+        let mut event = posix::epoll_event_new(events, raw_fd as u64);
+
+        if unsafe {
+            posix::epoll_ctl(
+                self.epoll_fd.native_handle(),
+                posix::EPOLL_CTL_MOD,
+                raw_fd,
+                &mut event,
+            )
+        } == 0
+        {
+            return Ok(());
+        }
+
+        handle_errno!(EpollMaskError, from self,
+            fatal Errno::EBADF => ("This should never happen! {msg} {:?} since either the epoll or the attached file descriptor is invalid.", fd);
+            fatal Errno::ENOENT => ("This should never happen! {msg} {:?} since it is not attached.", fd),
+            Errno::ENOMEM => (InsufficientMemory, "{msg} {:?} due to insufficient memory.", fd),
+            v => (UnknownError(v as i32), "{msg} {:?} since an unknown error occurred ({v}).", fd)
+        )
+    }
+
+    fn remove(&self, value: i32) {
+        let mut event = posix::epoll_event_new(0, 0);
+        if unsafe {
+            posix::epoll_ctl(
+                self.epoll_fd.native_handle(),
+                posix::EPOLL_CTL_DEL,
+                value,
+                &mut event,
+            )
+        } == -1
+        {
+            warn!(from self, "Unable to remove file descriptor {} ({:?}).", value, Errno::get());
+        }
+
+        self.len.set(self.len.get() - 1);
+    }
+
+    /// Returns the maximum number of attachments which is only limited by the number of file
+    /// descriptors a process can open.
+    pub fn capacity() -> usize {
+        Limit::MaxNumberOfOpenFiles.value() as usize
+    }
+
+    /// Returns the number of attached [`FileDescriptor`]s
+    pub fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    /// Returns true if the [`Epoll`] is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns immediately and calls the callback for every [`FileDescriptor`] that has data
+    /// to read.
+    pub fn try_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fd_callback: F,
+    ) -> Result<usize, EpollWaitError> {
+        self.wait(0, fd_callback)
+    }
+
+    /// Blocks until at least one [`FileDescriptor`] has data to read and calls the callback for
+    /// every [`FileDescriptor`] that has data to read.
+    pub fn blocking_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fd_callback: F,
+    ) -> Result<usize, EpollWaitError> {
+        self.wait(-1, fd_callback)
+    }
+
+    /// Waits until either the timeout has passed or at least one [`FileDescriptor`] has data
+    /// to read and calls the callback for every [`FileDescriptor`] that has data to read.
+    pub fn timed_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        timeout: Duration,
+        mut fd_callback: F,
+    ) -> Result<usize, EpollWaitError> {
+        let start = Time::now_with_clock(ClockType::Monotonic);
+
+        // epoll_wait has a millisecond resolution and a limited range, therefore the wait is
+        // repeated until the full timeout has passed
+        let mut remaining = timeout;
+        loop {
+            let timeout_in_ms = remaining
+                .as_nanos()
+                .div_ceil(1_000_000)
+                .min(posix::int::MAX as u128) as posix::int;
+
+            let number_of_notifications = self.wait(timeout_in_ms, &mut fd_callback)?;
+            if number_of_notifications != 0 {
+                return Ok(number_of_notifications);
+            }
+
+            let elapsed = match &start {
+                Ok(start) => start.elapsed().unwrap_or(timeout),
+                Err(_) => timeout,
+            };
+
+            if elapsed >= timeout {
+                return Ok(0);
+            }
+            remaining = timeout - elapsed;
+        }
+    }
+
+    fn wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        timeout_in_ms: posix::int,
+        mut fd_callback: F,
+    ) -> Result<usize, EpollWaitError> {
+        let msg = "Failure while waiting for file descriptor events";
+        let mut events = [posix::epoll_event_new(0, 0); MAX_EVENTS_PER_WAIT];
+
+        let number_of_notifications = unsafe {
+            posix::epoll_wait(
+                self.epoll_fd.native_handle(),
+                events.as_mut_ptr(),
+                MAX_EVENTS_PER_WAIT as _,
+                timeout_in_ms,
+            )
+        };
+
+        if number_of_notifications == -1 {
+            handle_errno!(EpollWaitError, from self,
+                fatal Errno::EBADF => ("This should never happen! {} since the epoll file descriptor is invalid.", msg);
+                fatal Errno::EFAULT => ("This should never happen! {} since the event buffer is not accessible.", msg);
+                fatal Errno::EINVAL => ("This should never happen! {} since the epoll or the number of events is invalid.", msg),
+                Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        for event in &events[..number_of_notifications as usize] {
+            let raw_fd = posix::epoll_event_data(event) as i32;
+            if let Some(fd) = FileDescriptor::non_owning_new(raw_fd) {
+                fd_callback(&fd);
+            }
+        }
+
+        Ok(number_of_notifications as _)
+    }
+}
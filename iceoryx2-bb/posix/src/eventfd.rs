@@ -0,0 +1,242 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Abstracts the Linux eventfd, a kernel maintained counter behind a [`FileDescriptor`] which
+//! can be used as a lightweight wakeup mechanism. Since it is a [`FileDescriptor`] it can be
+//! attached to any event loop or multiplexer like
+//! [`Epoll`](crate::epoll::Epoll) and it can be shared with other processes via
+//! [`SocketAncillary`](crate::socket_ancillary::SocketAncillary).
+//!
+//! # Example
+//!
+//! ```ignore
+//! use iceoryx2_bb_posix::eventfd::*;
+//! use core::time::Duration;
+//!
+//! let event = EventFdBuilder::new().create().unwrap();
+//!
+//! event.notify().unwrap();
+//! event.notify().unwrap();
+//!
+//! // returns the number of notifications since the last wait call
+//! assert_eq!(event.timed_wait(Duration::from_millis(10)).unwrap(), Some(2));
+//! assert_eq!(event.try_wait().unwrap(), None);
+//! ```
+
+use core::{fmt::Debug, time::Duration};
+
+use crate::{
+    clock::{ClockType, Time},
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::SynchronousMultiplexing,
+    handle_errno,
+};
+use iceoryx2_bb_log::fail;
+use iceoryx2_pal_posix::posix::{self, Errno};
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EventFdCreateError {
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientMemory,
+    FileDescriptorBroken,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EventFdNotifyError {
+    Interrupt,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EventFdWaitError {
+    Interrupt,
+    InsufficientResources,
+    UnknownError(i32),
+}
+
+/// Creates a new [`EventFd`].
+#[derive(Debug, Default)]
+pub struct EventFdBuilder {}
+
+impl EventFdBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new non-blocking [`EventFd`] with a counter of zero. The underlying file
+    /// descriptor is closed on exec.
+    pub fn create(self) -> Result<EventFd, EventFdCreateError> {
+        let msg = "Unable to create eventfd";
+        let raw_fd = unsafe { posix::eventfd(0, posix::EFD_NONBLOCK | posix::EFD_CLOEXEC) };
+
+        if raw_fd != -1 {
+            match FileDescriptor::new(raw_fd) {
+                Some(fd) => return Ok(EventFd { fd }),
+                None => {
+                    fail!(from self, with EventFdCreateError::FileDescriptorBroken,
+                        "This should never happen! {msg} since eventfd returned a broken file descriptor.");
+                }
+            }
+        }
+
+        handle_errno!(EventFdCreateError, from self,
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{msg} since the processes file descriptor limit was reached."),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{msg} since the system wide file descriptor limit was reached."),
+            Errno::ENOMEM => (InsufficientMemory, "{msg} due to insufficient memory."),
+            v => (UnknownError(v as i32), "{msg} since an unknown error occurred ({v}).")
+        )
+    }
+}
+
+/// Abstraction of the Linux eventfd. Every [`EventFd::notify()`] increments the counter and
+/// every successful wait call returns the counter and resets it to zero.
+#[derive(Debug)]
+pub struct EventFd {
+    fd: FileDescriptor,
+}
+
+impl FileDescriptorBased for EventFd {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.fd
+    }
+}
+
+impl SynchronousMultiplexing for EventFd {}
+
+impl EventFd {
+    /// Takes the ownership of a [`FileDescriptor`] that refers to an eventfd, for instance one
+    /// that was received from another process via
+    /// [`SocketAncillary`](crate::socket_ancillary::SocketAncillary).
+    pub fn from_file_descriptor(fd: FileDescriptor) -> Self {
+        Self { fd }
+    }
+
+    /// Increments the counter by one and wakes up all waiters. When the counter has reached
+    /// its maximum the waiters are already woken up and the call succeeds without incrementing
+    /// the counter.
+    pub fn notify(&self) -> Result<(), EventFdNotifyError> {
+        let msg = "Unable to notify eventfd";
+        let value: u64 = 1;
+        let bytes_written = unsafe {
+            posix::write(
+                self.fd.native_handle(),
+                (&value as *const u64).cast(),
+                core::mem::size_of::<u64>(),
+            )
+        };
+
+        if bytes_written == core::mem::size_of::<u64>() as _ {
+            return Ok(());
+        }
+
+        handle_errno!(EventFdNotifyError, from self,
+            success Errno::EAGAIN => (),
+            Errno::EINTR => (Interrupt, "{msg} since an interrupt signal was received."),
+            v => (UnknownError(v as i32), "{msg} since an unknown error occurred ({v}).")
+        )
+    }
+
+    /// Returns immediately. If the [`EventFd`] was notified it returns the number of
+    /// notifications since the last wait call, otherwise [`None`].
+    pub fn try_wait(&self) -> Result<Option<u64>, EventFdWaitError> {
+        let msg = "Unable to wait on eventfd";
+        let mut value: u64 = 0;
+        let bytes_read = unsafe {
+            posix::read(
+                self.fd.native_handle(),
+                (&mut value as *mut u64).cast(),
+                core::mem::size_of::<u64>(),
+            )
+        };
+
+        if bytes_read == core::mem::size_of::<u64>() as _ {
+            return Ok(Some(value));
+        }
+
+        handle_errno!(EventFdWaitError, from self,
+            success Errno::EAGAIN => None,
+            Errno::EINTR => (Interrupt, "{msg} since an interrupt signal was received."),
+            v => (UnknownError(v as i32), "{msg} since an unknown error occurred ({v}).")
+        )
+    }
+
+    /// Blocks until either the [`EventFd`] was notified or the timeout has passed. If the
+    /// [`EventFd`] was notified it returns the number of notifications since the last wait
+    /// call, otherwise [`None`].
+    pub fn timed_wait(&self, timeout: Duration) -> Result<Option<u64>, EventFdWaitError> {
+        if let Some(value) = self.try_wait()? {
+            return Ok(Some(value));
+        }
+
+        let start = Time::now_with_clock(ClockType::Monotonic);
+
+        // poll has a millisecond resolution and a limited range, therefore the wait is
+        // repeated until the full timeout has passed
+        let mut remaining = timeout;
+        loop {
+            let timeout_in_ms = remaining
+                .as_nanos()
+                .div_ceil(1_000_000)
+                .min(posix::int::MAX as u128) as posix::int;
+
+            self.wait_until_readable(timeout_in_ms)?;
+            // another waiter may have consumed the notification in the meantime
+            if let Some(value) = self.try_wait()? {
+                return Ok(Some(value));
+            }
+
+            let elapsed = match &start {
+                Ok(start) => start.elapsed().unwrap_or(timeout),
+                Err(_) => timeout,
+            };
+
+            if elapsed >= timeout {
+                return Ok(None);
+            }
+            remaining = timeout - elapsed;
+        }
+    }
+
+    /// Blocks until the [`EventFd`] was notified and returns the number of notifications since
+    /// the last wait call.
+    pub fn blocking_wait(&self) -> Result<u64, EventFdWaitError> {
+        loop {
+            if let Some(value) = self.try_wait()? {
+                return Ok(value);
+            }
+
+            self.wait_until_readable(-1)?;
+        }
+    }
+
+    // poll is used instead of an epoll since it does not require an additional file
+    // descriptor for every wait call
+    fn wait_until_readable(&self, timeout_in_ms: posix::int) -> Result<(), EventFdWaitError> {
+        let msg = "Unable to wait on eventfd";
+        let mut fds = [posix::pollfd_new(
+            unsafe { self.fd.native_handle() },
+            posix::POLLIN,
+        )];
+
+        if unsafe { posix::poll(fds.as_mut_ptr(), fds.len(), timeout_in_ms) } != -1 {
+            return Ok(());
+        }
+
+        handle_errno!(EventFdWaitError, from self,
+            Errno::EINTR => (Interrupt, "{msg} since an interrupt signal was received."),
+            Errno::ENOMEM => (InsufficientResources, "{msg} due to insufficient memory."),
+            v => (UnknownError(v as i32), "{msg} since an unknown error occurred ({v}).")
+        )
+    }
+}
@@ -93,6 +93,28 @@ impl<'fd> FileDescriptorSetGuard<'_, 'fd> {
     pub fn file_descriptor(&self) -> &'fd FileDescriptor {
         self.fd
     }
+
+    /// Masks the attached object so that it is no longer reported in the wait calls without
+    /// detaching it.
+    pub fn mask(&self) {
+        unsafe {
+            posix::FD_CLR(
+                self.fd.native_handle(),
+                &mut self.set.internals_mut().fd_set,
+            )
+        };
+    }
+
+    /// Reverts [`FileDescriptorSetGuard::mask()`] so that the attached object is reported
+    /// again.
+    pub fn unmask(&self) {
+        unsafe {
+            posix::FD_SET(
+                self.fd.native_handle(),
+                &mut self.set.internals_mut().fd_set,
+            )
+        };
+    }
 }
 
 impl Drop for FileDescriptorSetGuard<'_, '_> {
@@ -231,7 +253,10 @@ impl FileDescriptorSet {
     }
 
     fn contains_impl(&self, fd: &FileDescriptor) -> bool {
-        unsafe { posix::FD_ISSET(fd.native_handle(), &self.internals().fd_set) }
+        // masked file descriptors are not part of the fd_set but are still attached
+        self.internals()
+            .file_descriptors
+            .contains(&unsafe { fd.native_handle() })
     }
 
     /// Blocks until the specified event has occurred. It
@@ -46,6 +46,10 @@ pub mod udp_socket;
 pub mod handle_errno;
 pub mod deadline_queue;
 pub mod directory;
+#[cfg(target_os = "linux")]
+pub mod epoll;
+#[cfg(target_os = "linux")]
+pub mod eventfd;
 pub mod file;
 pub mod file_descriptor;
 pub mod file_descriptor_set;
@@ -13,6 +13,7 @@
 mod deadline_queue {
     use core::time::Duration;
     use iceoryx2_bb_elementary::CallbackProgression;
+    use iceoryx2_bb_posix::clock::{ClockType, Time, TimeBuilder};
     use iceoryx2_bb_posix::deadline_queue::*;
     use iceoryx2_bb_testing::assert_that;
 
@@ -224,4 +225,185 @@ mod deadline_queue {
         let next_deadline = sut.duration_until_next_deadline().unwrap();
         assert_that!(next_deadline, ne Duration::ZERO);
     }
+
+    fn time_from_duration(clock_type: ClockType, value: Duration) -> Time {
+        TimeBuilder::new()
+            .clock_type(clock_type)
+            .seconds(value.as_secs())
+            .nanoseconds(value.subsec_nanos())
+            .create()
+    }
+
+    fn collect_missed_deadlines(sut: &DeadlineQueue) -> Vec<DeadlineQueueIndex> {
+        let mut missed_deadlines = vec![];
+        sut.missed_deadlines(|idx| {
+            missed_deadlines.push(idx);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        missed_deadlines
+    }
+
+    #[test]
+    fn one_shot_timer_fires_only_once() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut
+            .add_timer(TimerSchedule::one_shot_after(Duration::from_millis(1)))
+            .unwrap();
+
+        std::thread::sleep(Duration::from_millis(10));
+
+        assert_that!(collect_missed_deadlines(&sut), eq vec![guard.index()]);
+        assert_that!(sut.duration_until_next_deadline().unwrap(), eq Duration::MAX);
+
+        std::thread::sleep(Duration::from_millis(10));
+
+        assert_that!(collect_missed_deadlines(&sut), len 0);
+        assert_that!(sut.len(), eq 1);
+    }
+
+    #[test]
+    fn reset_rearms_expired_one_shot_timer() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut
+            .add_timer(TimerSchedule::one_shot_after(Duration::from_millis(1)))
+            .unwrap();
+
+        std::thread::sleep(Duration::from_millis(10));
+        assert_that!(collect_missed_deadlines(&sut), len 1);
+
+        guard.reset().unwrap();
+        assert_that!(sut.duration_until_next_deadline().unwrap(), le Duration::from_millis(1));
+
+        std::thread::sleep(Duration::from_millis(10));
+        assert_that!(collect_missed_deadlines(&sut), eq vec![guard.index()]);
+    }
+
+    #[test]
+    fn one_shot_timer_at_time_of_other_clock_fires() {
+        let sut = DeadlineQueueBuilder::new()
+            .clock_type(ClockType::Monotonic)
+            .create()
+            .unwrap();
+
+        let now = Time::now_with_clock(ClockType::Realtime).unwrap();
+        let time = time_from_duration(
+            ClockType::Realtime,
+            now.as_duration() + Duration::from_millis(10),
+        );
+        let guard = sut.add_timer(TimerSchedule::one_shot_at(time)).unwrap();
+
+        assert_that!(sut.duration_until_next_deadline().unwrap(), le Duration::from_millis(10));
+        assert_that!(collect_missed_deadlines(&sut), len 0);
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_that!(collect_missed_deadlines(&sut), eq vec![guard.index()]);
+    }
+
+    #[test]
+    fn aligned_interval_of_realtime_deadline_queue_is_aligned_to_system_time() {
+        const PERIOD: Duration = Duration::from_millis(100);
+        let sut = DeadlineQueueBuilder::new()
+            .clock_type(ClockType::Realtime)
+            .create()
+            .unwrap();
+
+        let _guard = sut
+            .add_timer(TimerSchedule::aligned_interval(PERIOD))
+            .unwrap();
+
+        let before = Time::now_with_clock(ClockType::Realtime)
+            .unwrap()
+            .as_duration();
+        let duration_until_next_deadline = sut.duration_until_next_deadline().unwrap();
+        let after = Time::now_with_clock(ClockType::Realtime)
+            .unwrap()
+            .as_duration();
+
+        // the deadline lies between before and after shifted by the waiting time and must
+        // be a multiple of the period of the system time
+        let earliest = (before + duration_until_next_deadline).as_nanos();
+        let latest = (after + duration_until_next_deadline).as_nanos();
+        let aligned_deadline = latest / PERIOD.as_nanos() * PERIOD.as_nanos();
+        assert_that!(aligned_deadline, ge earliest);
+    }
+
+    #[test]
+    fn interval_starting_at_time_in_the_past_fires_immediately() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let now = Time::now_with_clock(ClockType::default()).unwrap();
+        let time = time_from_duration(
+            ClockType::default(),
+            now.as_duration() - Duration::from_millis(1),
+        );
+        let guard = sut
+            .add_timer(TimerSchedule::interval_starting_at(
+                time,
+                Duration::from_secs(100),
+            ))
+            .unwrap();
+
+        assert_that!(sut.duration_until_next_deadline().unwrap(), eq Duration::ZERO);
+        assert_that!(collect_missed_deadlines(&sut), eq vec![guard.index()]);
+        assert_that!(sut.duration_until_next_deadline().unwrap(), ge Duration::from_secs(99));
+    }
+
+    #[test]
+    fn aligned_interval_fires_on_period_boundaries() {
+        const PERIOD: Duration = Duration::from_millis(100);
+        const TOLERANCE: Duration = Duration::from_millis(5);
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let _guard = sut
+            .add_timer(TimerSchedule::aligned_interval(PERIOD))
+            .unwrap();
+
+        let now = Time::now_with_clock(ClockType::default()).unwrap();
+        let next_deadline = now.as_duration() + sut.duration_until_next_deadline().unwrap();
+        let misalignment = (next_deadline + TOLERANCE).as_nanos() % PERIOD.as_nanos();
+
+        assert_that!(misalignment, le(2 * TOLERANCE).as_nanos());
+    }
+
+    #[test]
+    fn missed_ticks_are_reported_once_with_skip_policy() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut
+            .add_timer(
+                TimerSchedule::interval(Duration::from_millis(5))
+                    .missed_tick_policy(MissedTickPolicy::Skip),
+            )
+            .unwrap();
+
+        std::thread::sleep(Duration::from_millis(30));
+
+        assert_that!(collect_missed_deadlines(&sut), eq vec![guard.index()]);
+        assert_that!(sut.duration_until_next_deadline().unwrap(), ne Duration::ZERO);
+    }
+
+    #[test]
+    fn missed_ticks_are_all_reported_with_catch_up_policy() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut
+            .add_timer(
+                TimerSchedule::interval(Duration::from_millis(5))
+                    .missed_tick_policy(MissedTickPolicy::CatchUp),
+            )
+            .unwrap();
+
+        std::thread::sleep(Duration::from_millis(30));
+
+        let missed_deadlines = collect_missed_deadlines(&sut);
+        assert_that!(missed_deadlines.len(), ge 6);
+        for idx in missed_deadlines {
+            assert_that!(idx, eq guard.index());
+        }
+        assert_that!(sut.duration_until_next_deadline().unwrap(), ne Duration::ZERO);
+    }
 }
@@ -0,0 +1,172 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(target_os = "linux")]
+mod epoll {
+    use core::time::Duration;
+    use iceoryx2_bb_posix::epoll::*;
+    use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+    use iceoryx2_bb_posix::socket_pair::StreamingSocket;
+    use iceoryx2_bb_posix::system_configuration::ProcessResourceLimit;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_pal_posix::posix;
+    use std::time::Instant;
+
+    static TIMEOUT: Duration = Duration::from_millis(10);
+
+    #[test]
+    fn epoll_timed_wait_blocks_at_least_timeout() {
+        let (sut_receiver, _sut_sender) = StreamingSocket::create_pair().unwrap();
+
+        let epoll = EpollBuilder::new().create().unwrap();
+        let _guard = epoll.add(&sut_receiver).unwrap();
+
+        let start = Instant::now();
+
+        let mut result = vec![];
+        epoll
+            .timed_wait(TIMEOUT, |fd| result.push(unsafe { fd.native_handle() }))
+            .unwrap();
+
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(result, len 0);
+    }
+
+    #[test]
+    fn epoll_add_and_remove_works() {
+        let epoll = EpollBuilder::new().create().unwrap();
+        let mut sockets = vec![];
+        let number_of_fds: usize = 128;
+
+        for _ in 0..number_of_fds {
+            sockets.push(StreamingSocket::create_pair().unwrap());
+        }
+
+        assert_that!(epoll.is_empty(), eq true);
+        let mut counter = 0;
+        let mut guards = vec![];
+        for (fd, _) in &sockets {
+            counter += 1;
+            let guard = epoll.add(fd);
+            assert_that!(guard, is_ok);
+            guards.push(guard);
+            assert_that!(epoll.len(), eq counter);
+        }
+
+        for counter in 1..=number_of_fds {
+            guards.pop();
+            assert_that!(epoll.len(), eq number_of_fds - counter);
+        }
+        assert_that!(epoll.is_empty(), eq true);
+    }
+
+    #[test]
+    fn epoll_add_same_fd_twice_fails() {
+        let (socket, _) = StreamingSocket::create_pair().unwrap();
+        let epoll = EpollBuilder::new().create().unwrap();
+
+        let _guard = epoll.add(&socket).unwrap();
+
+        let result = epoll.add(&socket);
+        assert_that!(result.err(), eq Some(EpollAttachError::AlreadyAttached));
+    }
+
+    #[test]
+    fn epoll_timed_wait_works() {
+        let (sut_receiver, sut_sender) = StreamingSocket::create_pair().unwrap();
+
+        let epoll = EpollBuilder::new().create().unwrap();
+        let _guard = epoll.add(&sut_receiver).unwrap();
+        sut_sender.try_send(&[1u8, 3u8, 3u8, 7u8]).unwrap();
+
+        let mut result = vec![];
+        let number_of_notifications = epoll
+            .timed_wait(TIMEOUT, |fd| result.push(unsafe { fd.native_handle() }))
+            .unwrap();
+
+        assert_that!(number_of_notifications, eq 1);
+        assert_that!(result, len 1);
+        assert_that!(result[0], eq unsafe{sut_receiver.file_descriptor().native_handle()});
+    }
+
+    #[test]
+    fn epoll_blocking_wait_immediately_returns_notifications() {
+        let (sut_receiver, sut_sender) = StreamingSocket::create_pair().unwrap();
+
+        let epoll = EpollBuilder::new().create().unwrap();
+        let _guard = epoll.add(&sut_receiver).unwrap();
+        sut_sender.try_send(&[1u8, 3u8, 3u8, 7u8]).unwrap();
+
+        let mut result = vec![];
+        let number_of_notifications = epoll
+            .blocking_wait(|fd| result.push(unsafe { fd.native_handle() }))
+            .unwrap();
+
+        assert_that!(number_of_notifications, eq 1);
+        assert_that!(result, len 1);
+        assert_that!(result[0], eq unsafe{sut_receiver.file_descriptor().native_handle()});
+    }
+
+    #[test]
+    fn epoll_try_wait_does_not_report_detached_fds() {
+        let (sut_receiver, sut_sender) = StreamingSocket::create_pair().unwrap();
+
+        let epoll = EpollBuilder::new().create().unwrap();
+        let guard = epoll.add(&sut_receiver).unwrap();
+        sut_sender.try_send(&[1u8, 3u8, 3u8, 7u8]).unwrap();
+        drop(guard);
+
+        let mut result = vec![];
+        let number_of_notifications = epoll
+            .try_wait(|fd| result.push(unsafe { fd.native_handle() }))
+            .unwrap();
+
+        assert_that!(number_of_notifications, eq 0);
+        assert_that!(result, len 0);
+    }
+
+    #[test]
+    fn epoll_supports_more_attachments_than_fd_setsize() {
+        let limit = ProcessResourceLimit::MaxNumberOfOpenFileDescriptors;
+        limit.set_soft_limit(limit.hard_limit());
+
+        // every socket pair consumes two file descriptors and some are already in use
+        let number_of_attachments = posix::FD_SETSIZE + 1;
+        if Epoll::capacity() < 2 * number_of_attachments + 64 {
+            return;
+        }
+
+        let epoll = EpollBuilder::new().create().unwrap();
+        let mut sockets = vec![];
+        for _ in 0..number_of_attachments {
+            sockets.push(StreamingSocket::create_pair().unwrap());
+        }
+
+        let mut guards = vec![];
+        for (receiver, _) in &sockets {
+            guards.push(epoll.add(receiver).unwrap());
+        }
+        assert_that!(epoll.len(), eq number_of_attachments);
+
+        let (last_receiver, last_sender) = sockets.last().unwrap();
+        last_sender.try_send(&[1u8]).unwrap();
+
+        let mut result = vec![];
+        let number_of_notifications = epoll
+            .try_wait(|fd| result.push(unsafe { fd.native_handle() }))
+            .unwrap();
+
+        assert_that!(number_of_notifications, eq 1);
+        assert_that!(result, len 1);
+        assert_that!(result[0], eq unsafe{last_receiver.file_descriptor().native_handle()});
+    }
+}
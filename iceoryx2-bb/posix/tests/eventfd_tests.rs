@@ -0,0 +1,96 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(target_os = "linux")]
+mod eventfd {
+    use core::time::Duration;
+    use iceoryx2_bb_posix::epoll::*;
+    use iceoryx2_bb_posix::eventfd::*;
+    use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+    use iceoryx2_bb_testing::assert_that;
+    use std::time::Instant;
+
+    static TIMEOUT: Duration = Duration::from_millis(10);
+
+    #[test]
+    fn eventfd_try_wait_without_notification_returns_none() {
+        let sut = EventFdBuilder::new().create().unwrap();
+
+        assert_that!(sut.try_wait().unwrap(), eq None);
+    }
+
+    #[test]
+    fn eventfd_try_wait_returns_number_of_notifications_and_resets_counter() {
+        let sut = EventFdBuilder::new().create().unwrap();
+
+        sut.notify().unwrap();
+        sut.notify().unwrap();
+        sut.notify().unwrap();
+
+        assert_that!(sut.try_wait().unwrap(), eq Some(3));
+        assert_that!(sut.try_wait().unwrap(), eq None);
+    }
+
+    #[test]
+    fn eventfd_timed_wait_blocks_at_least_timeout() {
+        let sut = EventFdBuilder::new().create().unwrap();
+
+        let start = Instant::now();
+        assert_that!(sut.timed_wait(TIMEOUT).unwrap(), eq None);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+    }
+
+    #[test]
+    fn eventfd_blocking_wait_is_woken_up_by_notification_from_other_thread() {
+        let sut = EventFdBuilder::new().create().unwrap();
+        let notifier = EventFd::from_file_descriptor(sut.file_descriptor().clone());
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                std::thread::sleep(TIMEOUT);
+                notifier.notify().unwrap();
+            });
+
+            let start = Instant::now();
+            assert_that!(sut.blocking_wait().unwrap(), eq 1);
+            assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        });
+    }
+
+    #[test]
+    fn eventfd_duplicated_file_descriptor_shares_counter() {
+        let sut = EventFdBuilder::new().create().unwrap();
+        let notifier = EventFd::from_file_descriptor(sut.file_descriptor().clone());
+
+        notifier.notify().unwrap();
+
+        assert_that!(sut.timed_wait(TIMEOUT).unwrap(), eq Some(1));
+    }
+
+    #[test]
+    fn eventfd_notification_is_reported_by_epoll() {
+        let sut = EventFdBuilder::new().create().unwrap();
+        let epoll = EpollBuilder::new().create().unwrap();
+        let _guard = epoll.add(&sut).unwrap();
+
+        sut.notify().unwrap();
+
+        let mut result = vec![];
+        let number_of_notifications = epoll
+            .timed_wait(TIMEOUT, |fd| result.push(unsafe { fd.native_handle() }))
+            .unwrap();
+
+        assert_that!(number_of_notifications, eq 1);
+        assert_that!(result, len 1);
+        assert_that!(result[0], eq unsafe{sut.file_descriptor().native_handle()});
+    }
+}
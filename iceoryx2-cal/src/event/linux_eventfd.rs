@@ -0,0 +1,675 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An [`Event`] whose [`Listener`](crate::event::Listener) is backed by a Linux
+//! [`EventFd`] so that it can be attached to any external event loop.
+//!
+//! The [`TriggerId`]s are tracked in shared memory like in
+//! [`sem_bitset_posix_shared_memory`](crate::event::sem_bitset_posix_shared_memory). The
+//! [`EventFd`] is owned by the [`Listener`](crate::event::Listener) process. Every
+//! [`Listener`](crate::event::Listener) runs a small broker thread that listens on a unix
+//! datagram socket and hands out a duplicate of the [`EventFd`] via [`SocketAncillary`] to
+//! every [`Notifier`](crate::event::Notifier) that is opened.
+//!
+//! **Resource usage:** the broker thread is not shared, so every
+//! [`Listener`](crate::event::Listener) costs one thread, one unix datagram socket and one
+//! [`EventFd`] for its whole lifetime. The thread sleeps in a blocking receive and only
+//! wakes up when a [`Notifier`](crate::event::Notifier) is opened or the
+//! [`Listener`](crate::event::Listener) is dropped. Processes that create many listeners
+//! should prefer an [`Event`] without a broker, like
+//! [`sem_bitset_posix_shared_memory`](crate::event::sem_bitset_posix_shared_memory).
+//!
+//! A [`Listener`](crate::event::Listener) keeps its [`EventFd`] readable as long as
+//! [`TriggerId`]s may be pending, so an external event loop is woken up again until all of
+//! them are consumed.
+
+use core::sync::atomic::Ordering;
+
+use alloc::sync::Arc;
+
+pub use crate::event::*;
+use crate::{
+    dynamic_storage::posix_shared_memory::Storage,
+    event::{
+        common::details::{self, Management},
+        signal_mechanism::SignalMechanism,
+    },
+    static_storage::file::NamedConceptConfiguration,
+};
+use iceoryx2_bb_lock_free::mpmc::bit_set::RelocatableBitSet;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::{
+    creation_mode::CreationMode,
+    eventfd::*,
+    file::File,
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::SynchronousMultiplexing,
+    socket_ancillary::SocketAncillary,
+    thread::{Thread, ThreadBuilder, ThreadName},
+    unique_system_id::UniqueSystemId,
+    unix_datagram_socket::*,
+};
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+/// The minimum time a [`Notifier`] waits for the [`Listener`] to hand out its [`EventFd`].
+const MIN_FD_REQUEST_TIMEOUT: Duration = Duration::from_secs(1);
+const FD_REPLY_SUFFIX: &[u8] = b".eventfd_reply";
+
+type SharedEvent = details::EventImpl<
+    RelocatableBitSet,
+    NoSignal,
+    Storage<Management<RelocatableBitSet, NoSignal>>,
+>;
+type SharedNotifier = <SharedEvent as Event>::Notifier;
+type SharedListener = <SharedEvent as Event>::Listener;
+
+/// The [`Listener`] is woken up by its [`EventFd`], therefore the shared memory requires no
+/// signal mechanism.
+#[derive(Debug)]
+struct NoSignal;
+
+impl SignalMechanism for NoSignal {
+    fn new() -> Self {
+        Self
+    }
+
+    unsafe fn init(&mut self) -> Result<(), ListenerCreateError> {
+        Ok(())
+    }
+
+    unsafe fn notify(&self) -> Result<(), NotifierNotifyError> {
+        Ok(())
+    }
+
+    unsafe fn try_wait(&self) -> Result<bool, ListenerWaitError> {
+        Ok(false)
+    }
+
+    unsafe fn timed_wait(&self, _timeout: Duration) -> Result<bool, ListenerWaitError> {
+        Ok(false)
+    }
+
+    unsafe fn blocking_wait(&self) -> Result<(), ListenerWaitError> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Configuration {
+    suffix: FileName,
+    prefix: FileName,
+    path: Path,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            path: EventImpl::default_path_hint(),
+            suffix: EventImpl::default_suffix(),
+            prefix: EventImpl::default_prefix(),
+        }
+    }
+}
+
+impl Configuration {
+    fn convert(&self) -> <SharedEvent as NamedConceptMgmt>::Configuration {
+        <SharedEvent as NamedConceptMgmt>::Configuration::default()
+            .prefix(&self.prefix)
+            .suffix(&self.suffix)
+            .path_hint(&self.path)
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn prefix(mut self, value: &FileName) -> Self {
+        self.prefix = value.clone();
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: &FileName) -> Self {
+        self.suffix = value.clone();
+        self
+    }
+
+    fn path_hint(mut self, value: &Path) -> Self {
+        self.path = value.clone();
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[derive(Debug)]
+pub struct EventImpl {}
+
+impl NamedConceptMgmt for EventImpl {
+    type Configuration = Configuration;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
+        SharedEvent::does_exist_cfg(name, &cfg.convert())
+    }
+
+    fn list_cfg(
+        cfg: &Self::Configuration,
+    ) -> Result<Vec<FileName>, crate::static_storage::file::NamedConceptListError> {
+        SharedEvent::list_cfg(&cfg.convert())
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptRemoveError> {
+        // the socket of the fd broker is owned by the listener process and remains only
+        // when the listener process died
+        let broker_path = cfg.path_for(name);
+        if let Err(e) = File::remove(&broker_path) {
+            warn!(from "linux_eventfd::EventImpl::remove_cfg()",
+                "Unable to remove the fd broker socket \"{}\" ({:?}).", broker_path, e);
+        }
+
+        SharedEvent::remove_cfg(name, &cfg.convert())
+    }
+
+    fn remove_path_hint(
+        value: &Path,
+    ) -> Result<(), crate::named_concept::NamedConceptPathHintRemoveError> {
+        crate::named_concept::remove_path_hint(value)
+    }
+}
+
+impl crate::event::Event for EventImpl {
+    type Notifier = Notifier;
+    type Listener = Listener;
+    type NotifierBuilder = NotifierBuilder;
+    type ListenerBuilder = ListenerBuilder;
+
+    fn has_trigger_id_limit() -> bool {
+        SharedEvent::has_trigger_id_limit()
+    }
+}
+
+#[derive(Debug)]
+pub struct Notifier {
+    shared: SharedNotifier,
+    event_fd: EventFd,
+}
+
+impl NamedConcept for Notifier {
+    fn name(&self) -> &FileName {
+        self.shared.name()
+    }
+}
+
+impl crate::event::Notifier for Notifier {
+    fn trigger_id_max(&self) -> TriggerId {
+        self.shared.trigger_id_max()
+    }
+
+    fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
+        self.shared.notify(id)?;
+
+        let msg = "Failed to notify event::linux_eventfd::Listener";
+        match self.event_fd.notify() {
+            Ok(()) => Ok(()),
+            Err(EventFdNotifyError::Interrupt) => {
+                fail!(from self, with NotifierNotifyError::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with NotifierNotifyError::InternalFailure,
+                    "{} due to an unknown failure ({:?}).", msg, v);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct NotifierBuilder {
+    name: FileName,
+    config: Configuration,
+    creation_timeout: Duration,
+}
+
+impl NamedConceptBuilder<EventImpl> for NotifierBuilder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: name.clone(),
+            config: Configuration::default(),
+            creation_timeout: Duration::ZERO,
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl NotifierBuilder {
+    fn request_event_fd(&self) -> Result<EventFd, NotifierCreateError> {
+        let msg = "Failed to open event::linux_eventfd::Notifier";
+
+        // a u128 in hex has at most 32 characters and is always a valid file name
+        let reply_path = match UniqueSystemId::new() {
+            Ok(id) => self
+                .config
+                .clone()
+                .suffix(&unsafe { FileName::new_unchecked(FD_REPLY_SUFFIX) })
+                .path_for(&unsafe {
+                    FileName::new_unchecked(format!("{:x}", id.value()).as_bytes())
+                }),
+            Err(e) => {
+                fail!(from self, with NotifierCreateError::InternalFailure,
+                    "{} since no unique name for the reply socket could be generated ({:?}).", msg, e);
+            }
+        };
+
+        let reply_receiver = match UnixDatagramReceiverBuilder::new(&reply_path)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .create()
+        {
+            Ok(receiver) => receiver,
+            Err(e) => {
+                fail!(from self, with NotifierCreateError::InternalFailure,
+                    "{} since the reply socket \"{}\" could not be created ({:?}).", msg, reply_path, e);
+            }
+        };
+
+        let request_sender = match UnixDatagramSenderBuilder::new(&self.config.path_for(&self.name))
+            .create()
+        {
+            Ok(sender) => sender,
+            Err(UnixDatagramSenderCreationError::DoesNotExist) => {
+                fail!(from self, with NotifierCreateError::DoesNotExist,
+                        "{} since the corresponding listener does not exist.", msg);
+            }
+            Err(UnixDatagramSenderCreationError::InsufficientPermissions) => {
+                fail!(from self, with NotifierCreateError::InsufficientPermissions,
+                        "{} due to insufficient permissions.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with NotifierCreateError::InternalFailure,
+                        "{} since the fd broker of the listener could not be reached ({:?}).", msg, e);
+            }
+        };
+
+        match request_sender.try_send(reply_path.as_bytes()) {
+            Ok(true) => (),
+            Err(UnixDatagramSendError::ConnectionRefused) => {
+                fail!(from self, with NotifierCreateError::DoesNotExist,
+                    "{} since the corresponding listener is no longer alive.", msg);
+            }
+            Ok(false) => {
+                fail!(from self, with NotifierCreateError::InternalFailure,
+                    "{} since the fd broker of the listener is flooded with requests.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with NotifierCreateError::InternalFailure,
+                    "{} since the eventfd could not be requested ({:?}).", msg, e);
+            }
+        }
+
+        let timeout = self.creation_timeout.max(MIN_FD_REQUEST_TIMEOUT);
+        let mut reply = SocketAncillary::new();
+        match reply_receiver.timed_receive_msg(&mut reply, timeout) {
+            Ok(true) => (),
+            Ok(false) => {
+                fail!(from self, with NotifierCreateError::InternalFailure,
+                    "{} since the listener did not hand out its eventfd within {:?}.", msg, timeout);
+            }
+            Err(e) => {
+                fail!(from self, with NotifierCreateError::InternalFailure,
+                    "{} since the eventfd could not be received ({:?}).", msg, e);
+            }
+        }
+
+        let mut fds = reply.extract_fds();
+        if fds.len() != 1 {
+            fail!(from self, with NotifierCreateError::InternalFailure,
+                "{} since the listener replied with {} instead of exactly one file descriptor.",
+                msg, fds.len());
+        }
+
+        Ok(EventFd::from_file_descriptor(fds.remove(0)))
+    }
+}
+
+impl crate::event::NotifierBuilder<EventImpl> for NotifierBuilder {
+    fn timeout(mut self, timeout: Duration) -> Self {
+        self.creation_timeout = timeout;
+        self
+    }
+
+    fn open(self) -> Result<Notifier, NotifierCreateError> {
+        let shared = <SharedEvent as Event>::NotifierBuilder::new(&self.name)
+            .config(&self.config.convert())
+            .timeout(self.creation_timeout)
+            .open()?;
+
+        Ok(Notifier {
+            shared,
+            event_fd: self.request_event_fd()?,
+        })
+    }
+}
+
+/// Hands out the [`EventFd`] of the [`Listener`] to every [`Notifier`] that requests it.
+/// The requests are answered in a background thread so that a [`Notifier`] can be opened
+/// while the [`Listener`] is not waiting.
+#[derive(Debug)]
+struct FdBroker {
+    keep_running: Arc<IoxAtomicBool>,
+    waker: UnixDatagramSender,
+    _thread: Thread,
+}
+
+impl Drop for FdBroker {
+    fn drop(&mut self) {
+        self.keep_running.store(false, Ordering::Relaxed);
+        if let Err(e) = self.waker.try_send(&[0u8]) {
+            warn!(from self, "Unable to wake up the fd broker thread ({:?}).", e);
+        }
+    }
+}
+
+impl FdBroker {
+    fn run(receiver: UnixDatagramReceiver, event_fd: FileDescriptor, keep_running: &IoxAtomicBool) {
+        let origin = "linux_eventfd::FdBroker::run()";
+        let mut buffer = [0u8; FilePath::max_len()];
+
+        loop {
+            let len = match receiver.blocking_receive(&mut buffer) {
+                Ok(len) => len as usize,
+                Err(e) => {
+                    warn!(from origin, "Stop handing out the eventfd since the request could not be received ({:?}).", e);
+                    return;
+                }
+            };
+
+            if !keep_running.load(Ordering::Relaxed) {
+                return;
+            }
+
+            let reply_path = match FilePath::new(&buffer[..len]) {
+                Ok(path) => path,
+                Err(_) => {
+                    warn!(from origin, "Ignoring request since it does not contain a valid reply socket.");
+                    continue;
+                }
+            };
+
+            let reply_sender = match UnixDatagramSenderBuilder::new(&reply_path).create() {
+                Ok(sender) => sender,
+                Err(e) => {
+                    warn!(from origin, "Unable to connect to the reply socket \"{}\" ({:?}).", reply_path, e);
+                    continue;
+                }
+            };
+
+            let mut reply = SocketAncillary::new();
+            reply.add_fd(event_fd.clone());
+            if let Err(e) = reply_sender.try_send_msg(&mut reply) {
+                warn!(from origin, "Unable to hand out the eventfd to \"{}\" ({:?}).", reply_path, e);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Listener {
+    shared: SharedListener,
+    event_fd: EventFd,
+    _broker: FdBroker,
+}
+
+impl FileDescriptorBased for Listener {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.event_fd.file_descriptor()
+    }
+}
+
+impl SynchronousMultiplexing for Listener {}
+
+impl NamedConcept for Listener {
+    fn name(&self) -> &FileName {
+        self.shared.name()
+    }
+}
+
+impl Listener {
+    fn consume_wakeup<F: FnOnce(&EventFd) -> Result<bool, EventFdWaitError>>(
+        &self,
+        error_msg: &str,
+        wait_call: F,
+    ) -> Result<bool, ListenerWaitError> {
+        match wait_call(&self.event_fd) {
+            Ok(v) => Ok(v),
+            Err(EventFdWaitError::Interrupt) => {
+                fail!(from self, with ListenerWaitError::InterruptSignal,
+                    "{} since an interrupt signal was received.", error_msg);
+            }
+            Err(v) => {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "{} due to an unknown failure ({:?}).", error_msg, v);
+            }
+        }
+    }
+
+    fn try_consume_wakeup(&self) -> Result<bool, ListenerWaitError> {
+        self.consume_wakeup(
+            "Unable to try wait for signal on event::linux_eventfd::Listener",
+            |event_fd| Ok(event_fd.try_wait()?.is_some()),
+        )
+    }
+
+    fn timed_consume_wakeup(&self, timeout: Duration) -> Result<bool, ListenerWaitError> {
+        self.consume_wakeup(
+            &format!("Unable to wait for signal with timeout {timeout:?} on event::linux_eventfd::Listener"),
+            |event_fd| Ok(event_fd.timed_wait(timeout)?.is_some()),
+        )
+    }
+
+    fn blocking_consume_wakeup(&self) -> Result<bool, ListenerWaitError> {
+        self.consume_wakeup(
+            "Unable to blocking wait for signal on event::linux_eventfd::Listener",
+            |event_fd| event_fd.blocking_wait().map(|_| true),
+        )
+    }
+
+    fn acquire_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        let id = self.shared.try_wait_one()?;
+
+        // the eventfd was already reset but further ids may still be pending, therefore it
+        // is re-armed so that an external event loop wakes up again until all ids are
+        // consumed. The wait call that returns no id resets it for good.
+        if id.is_some() {
+            if let Err(e) = self.event_fd.notify() {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "Unable to re-arm the eventfd after acquiring a trigger id ({:?}).", e);
+            }
+        }
+
+        Ok(id)
+    }
+}
+
+impl crate::event::Listener for Listener {
+    const IS_FILE_DESCRIPTOR_BASED: bool = true;
+
+    fn try_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        // the eventfd is reset first so that a notification that arrives afterwards is not
+        // lost for the next timed or blocking wait
+        self.try_consume_wakeup()?;
+        self.acquire_one()
+    }
+
+    fn timed_wait_one(&self, timeout: Duration) -> Result<Option<TriggerId>, ListenerWaitError> {
+        if let Some(id) = self.try_wait_one()? {
+            return Ok(Some(id));
+        }
+
+        self.timed_consume_wakeup(timeout)?;
+        self.acquire_one()
+    }
+
+    fn blocking_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        if let Some(id) = self.try_wait_one()? {
+            return Ok(Some(id));
+        }
+
+        self.blocking_consume_wakeup()?;
+        self.acquire_one()
+    }
+
+    fn try_wait_all<F: FnMut(TriggerId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
+        self.try_consume_wakeup()?;
+        self.shared.try_wait_all(callback)
+    }
+
+    fn timed_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        self.timed_consume_wakeup(timeout)?;
+        self.shared.try_wait_all(callback)
+    }
+
+    fn blocking_wait_all<F: FnMut(TriggerId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
+        self.blocking_consume_wakeup()?;
+        self.shared.try_wait_all(callback)
+    }
+}
+
+#[derive(Debug)]
+pub struct ListenerBuilder {
+    name: FileName,
+    config: Configuration,
+    trigger_id_max: Option<TriggerId>,
+}
+
+impl NamedConceptBuilder<EventImpl> for ListenerBuilder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: name.clone(),
+            config: Configuration::default(),
+            trigger_id_max: None,
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl ListenerBuilder {
+    fn start_broker(&self, event_fd: &EventFd) -> Result<FdBroker, ListenerCreateError> {
+        let msg = "Failed to create event::linux_eventfd::Listener";
+        let broker_path = self.config.path_for(&self.name);
+
+        // the shared memory was already created exclusively, therefore a remaining socket can
+        // only be a leftover of a dead listener
+        let receiver = match UnixDatagramReceiverBuilder::new(&broker_path)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .create()
+        {
+            Ok(receiver) => receiver,
+            Err(UnixDatagramReceiverCreationError::UnixDatagramCreationError(
+                UnixDatagramCreationError::InsufficientPermissions,
+            )) => {
+                fail!(from self, with ListenerCreateError::InsufficientPermissions,
+                    "{} since the fd broker socket could not be created due to insufficient permissions.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with ListenerCreateError::InternalFailure,
+                    "{} since the fd broker socket could not be created ({:?}).", msg, e);
+            }
+        };
+
+        let waker = match UnixDatagramSenderBuilder::new(&broker_path).create() {
+            Ok(waker) => waker,
+            Err(e) => {
+                fail!(from self, with ListenerCreateError::InternalFailure,
+                    "{} since the fd broker socket could not be connected ({:?}).", msg, e);
+            }
+        };
+
+        let keep_running = Arc::new(IoxAtomicBool::new(true));
+        let broker_keep_running = keep_running.clone();
+        let broker_event_fd = event_fd.file_descriptor().clone();
+        let thread = match ThreadBuilder::new()
+            .name(&ThreadName::from(b"iox2_fd_broker"))
+            .spawn(move || FdBroker::run(receiver, broker_event_fd, &broker_keep_running))
+        {
+            Ok(thread) => thread,
+            Err(e) => {
+                fail!(from self, with ListenerCreateError::InternalFailure,
+                    "{} since the fd broker thread could not be started ({:?}).", msg, e);
+            }
+        };
+
+        Ok(FdBroker {
+            keep_running,
+            waker,
+            _thread: thread,
+        })
+    }
+}
+
+impl crate::event::ListenerBuilder<EventImpl> for ListenerBuilder {
+    fn trigger_id_max(mut self, id: TriggerId) -> Self {
+        self.trigger_id_max = Some(id);
+        self
+    }
+
+    fn create(self) -> Result<Listener, ListenerCreateError> {
+        let msg = "Failed to create event::linux_eventfd::Listener";
+
+        let mut shared_builder =
+            <SharedEvent as Event>::ListenerBuilder::new(&self.name).config(&self.config.convert());
+        if let Some(id) = self.trigger_id_max {
+            shared_builder = shared_builder.trigger_id_max(id);
+        }
+        let shared = shared_builder.create()?;
+
+        let event_fd = match EventFdBuilder::new().create() {
+            Ok(event_fd) => event_fd,
+            Err(e) => {
+                fail!(from self, with ListenerCreateError::InternalFailure,
+                    "{} since the eventfd could not be created ({:?}).", msg, e);
+            }
+        };
+
+        let broker = self.start_broker(&event_fd)?;
+
+        Ok(Listener {
+            shared,
+            event_fd,
+            _broker: broker,
+        })
+    }
+}
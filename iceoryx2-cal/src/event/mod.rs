@@ -12,6 +12,8 @@
 
 pub mod common;
 pub mod id_tracker;
+#[cfg(target_os = "linux")]
+pub mod linux_eventfd;
 pub mod process_local_socketpair;
 pub mod recommended;
 pub mod sem_bitset_posix_shared_memory;
@@ -13,8 +13,17 @@
 use crate::dynamic_storage::posix_shared_memory::Storage;
 use crate::event::common::details::EventImpl;
 use crate::event::common::details::Management;
+#[cfg(target_os = "linux")]
+use crate::event::signal_mechanism::futex::Futex;
 use crate::event::signal_mechanism::semaphore::Semaphore;
 use iceoryx2_bb_lock_free::mpmc::bit_set::RelocatableBitSet;
 
 pub type Event =
     EventImpl<RelocatableBitSet, Semaphore, Storage<Management<RelocatableBitSet, Semaphore>>>;
+
+/// Variant of [`Event`] that signals with a Linux futex instead of a POSIX semaphore. The
+/// futex lives completely in the shared memory of the event so that no additional kernel
+/// object is required per listener and the notification latency is lower.
+#[cfg(target_os = "linux")]
+pub type FutexEvent =
+    EventImpl<RelocatableBitSet, Futex, Storage<Management<RelocatableBitSet, Futex>>>;
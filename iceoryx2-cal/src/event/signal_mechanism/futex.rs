@@ -0,0 +1,145 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`SignalMechanism`] based on a Linux futex. The counter lives completely inside the memory
+//! of the [`Futex`] so that no additional kernel object is required and the construct can be
+//! placed into shared memory.
+
+use core::{sync::atomic::Ordering, time::Duration};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::clock::{AsTimespec, ClockType, Time};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU32;
+use iceoryx2_pal_posix::posix::{self, Errno};
+
+use crate::event::{ListenerCreateError, ListenerWaitError, NotifierNotifyError};
+
+use super::SignalMechanism;
+
+#[derive(Debug)]
+pub struct Futex {
+    counter: IoxAtomicU32,
+    // number of threads that are about to wait or are waiting on the futex, the notifier issues
+    // the wake up system call only when it is not zero
+    waiters: IoxAtomicU32,
+}
+
+impl Futex {
+    fn wait(&self, timeout: Option<Duration>) -> Result<(), ListenerWaitError> {
+        let msg = "Failed to wait on underlying futex";
+        let timeout = timeout.map(|t| t.as_timespec());
+        let timeout_ptr = match &timeout {
+            Some(t) => t as *const posix::timespec,
+            None => core::ptr::null(),
+        };
+
+        // the waiter must be registered before the futex checks the counter, otherwise a
+        // notifier could increment the counter and skip the wake up in between
+        self.waiters.fetch_add(1, Ordering::SeqCst);
+        let result = unsafe { posix::futex_wait(self.counter.as_ptr(), 0, timeout_ptr) };
+        let errno = Errno::get();
+        self.waiters.fetch_sub(1, Ordering::Relaxed);
+
+        if result == 0 {
+            return Ok(());
+        }
+
+        match errno {
+            // the counter was already increased or the timeout passed, in both cases the caller
+            // checks the counter again
+            Errno::EAGAIN | Errno::ETIMEDOUT => Ok(()),
+            Errno::EINTR => {
+                fail!(from self, with ListenerWaitError::InterruptSignal,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            v => {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "{} due to an internal failure ({}).", msg, v);
+            }
+        }
+    }
+}
+
+impl SignalMechanism for Futex {
+    fn new() -> Self {
+        Self {
+            counter: IoxAtomicU32::new(0),
+            waiters: IoxAtomicU32::new(0),
+        }
+    }
+
+    unsafe fn init(&mut self) -> Result<(), ListenerCreateError> {
+        self.counter.store(0, Ordering::Relaxed);
+        self.waiters.store(0, Ordering::Relaxed);
+        Ok(())
+    }
+
+    unsafe fn notify(&self) -> Result<(), NotifierNotifyError> {
+        if self
+            .counter
+            .fetch_update(Ordering::SeqCst, Ordering::Relaxed, |v| v.checked_add(1))
+            .is_err()
+        {
+            fail!(from self, with NotifierNotifyError::InternalFailure,
+                "Failed to increment underlying futex since the maximum number of signals is reached.");
+        }
+
+        if self.waiters.load(Ordering::SeqCst) == 0 {
+            return Ok(());
+        }
+
+        if posix::futex_wake(self.counter.as_ptr(), 1) == -1 {
+            fail!(from self, with NotifierNotifyError::InternalFailure,
+                "Failed to wake up the waiter of the underlying futex ({}).", Errno::get());
+        }
+
+        Ok(())
+    }
+
+    unsafe fn try_wait(&self) -> Result<bool, ListenerWaitError> {
+        Ok(self
+            .counter
+            .fetch_update(Ordering::Acquire, Ordering::Relaxed, |v| v.checked_sub(1))
+            .is_ok())
+    }
+
+    unsafe fn timed_wait(&self, timeout: Duration) -> Result<bool, ListenerWaitError> {
+        let start = Time::now_with_clock(ClockType::Monotonic);
+
+        loop {
+            if self.try_wait()? {
+                return Ok(true);
+            }
+
+            let elapsed = match &start {
+                Ok(start) => start.elapsed().unwrap_or(timeout),
+                Err(_) => timeout,
+            };
+
+            if elapsed >= timeout {
+                return Ok(false);
+            }
+
+            self.wait(Some(timeout - elapsed))?;
+        }
+    }
+
+    unsafe fn blocking_wait(&self) -> Result<(), ListenerWaitError> {
+        loop {
+            if self.try_wait()? {
+                return Ok(());
+            }
+
+            self.wait(None)?;
+        }
+    }
+}
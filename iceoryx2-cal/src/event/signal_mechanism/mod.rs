@@ -14,6 +14,8 @@ use core::{fmt::Debug, time::Duration};
 
 use super::{ListenerCreateError, ListenerWaitError, NotifierNotifyError};
 
+#[cfg(target_os = "linux")]
+pub mod futex;
 pub mod semaphore;
 
 /// The [`SignalMechanism`] is a building block for [`crate::event::Event`]
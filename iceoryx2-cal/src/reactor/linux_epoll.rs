@@ -0,0 +1,172 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`Reactor`](crate::reactor::Reactor) based on the Linux epoll facility. In contrast to the
+//! [`posix_select`](crate::reactor::posix_select) implementation the number of attachments is
+//! not limited by `FD_SETSIZE` and the cost of a wait call does not grow with the number of
+//! attachments.
+
+use core::{fmt::Debug, time::Duration};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::{
+    epoll::{
+        Epoll, EpollAttachError, EpollBuilder, EpollCreateError, EpollGuard, EpollMaskError,
+        EpollWaitError,
+    },
+    file_descriptor::FileDescriptor,
+};
+use iceoryx2_pal_posix::posix::Errno;
+
+use crate::reactor::{ReactorAttachError, ReactorCreateError, ReactorMaskError, ReactorWaitError};
+
+fn to_reactor_mask_error(result: Result<(), EpollMaskError>) -> Result<(), ReactorMaskError> {
+    match result {
+        Ok(()) => Ok(()),
+        Err(EpollMaskError::InsufficientMemory) => {
+            Err(ReactorMaskError::UnknownError(Errno::ENOMEM as i32))
+        }
+        Err(EpollMaskError::UnknownError(v)) => Err(ReactorMaskError::UnknownError(v)),
+    }
+}
+
+impl crate::reactor::ReactorGuard<'_, '_> for EpollGuard<'_, '_> {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.file_descriptor()
+    }
+
+    fn mask(&self) -> Result<(), ReactorMaskError> {
+        to_reactor_mask_error(self.mask())
+    }
+
+    fn unmask(&self) -> Result<(), ReactorMaskError> {
+        to_reactor_mask_error(self.unmask())
+    }
+}
+
+#[derive(Debug)]
+pub struct Reactor {
+    epoll: Epoll,
+}
+
+impl Reactor {
+    fn handle_wait_result(
+        &self,
+        result: Result<usize, EpollWaitError>,
+    ) -> Result<usize, ReactorWaitError> {
+        let msg = "Unable to wait on Reactor";
+        match result {
+            Ok(number_of_notifications) => Ok(number_of_notifications),
+            Err(EpollWaitError::Interrupt) => {
+                fail!(from self, with ReactorWaitError::Interrupt,
+                    "{} since an interrupt signal was received while waiting.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ReactorWaitError::UnknownError,
+                    "{} since an unknown failure occurred in the underlying epoll ({:?}).", msg, v);
+            }
+        }
+    }
+}
+
+impl crate::reactor::Reactor for Reactor {
+    type Guard<'reactor, 'attachment> = EpollGuard<'reactor, 'attachment>;
+    type Builder = ReactorBuilder;
+
+    fn capacity(&self) -> usize {
+        Epoll::capacity()
+    }
+
+    fn len(&self) -> usize {
+        self.epoll.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.epoll.is_empty()
+    }
+
+    fn attach<
+        'reactor,
+        'attachment,
+        F: iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing + Debug,
+    >(
+        &'reactor self,
+        value: &'attachment F,
+    ) -> Result<Self::Guard<'reactor, 'attachment>, ReactorAttachError> {
+        let msg = format!("Unable to attach {value:?} to the reactor");
+        match self.epoll.add(value) {
+            Ok(guard) => Ok(guard),
+            Err(EpollAttachError::AlreadyAttached) => {
+                fail!(from self, with ReactorAttachError::AlreadyAttached,
+                    "{msg} since it is already attached.");
+            }
+            Err(EpollAttachError::WatchLimitReached) => {
+                fail!(from self, with ReactorAttachError::CapacityExceeded,
+                    "{msg} since the system wide limit of epoll watches was reached.");
+            }
+            Err(EpollAttachError::InsufficientMemory) => {
+                fail!(from self, with ReactorAttachError::UnknownError(Errno::ENOMEM as i32),
+                    "{msg} due to insufficient memory.");
+            }
+            Err(EpollAttachError::UnsupportedFileDescriptor) => {
+                fail!(from self, with ReactorAttachError::UnknownError(Errno::EPERM as i32),
+                    "{msg} since the underlying file descriptor does not support epoll.");
+            }
+            Err(EpollAttachError::UnknownError(v)) => {
+                fail!(from self, with ReactorAttachError::UnknownError(v),
+                    "{msg} since an unknown failure occurred in the underlying epoll ({v}).");
+            }
+        }
+    }
+
+    fn try_wait<F: FnMut(&FileDescriptor)>(&self, fn_call: F) -> Result<usize, ReactorWaitError> {
+        self.handle_wait_result(self.epoll.try_wait(fn_call))
+    }
+
+    fn timed_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fn_call: F,
+        timeout: Duration,
+    ) -> Result<usize, ReactorWaitError> {
+        self.handle_wait_result(self.epoll.timed_wait(timeout, fn_call))
+    }
+
+    fn blocking_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fn_call: F,
+    ) -> Result<usize, ReactorWaitError> {
+        self.handle_wait_result(self.epoll.blocking_wait(fn_call))
+    }
+}
+
+pub struct ReactorBuilder {}
+
+impl crate::reactor::ReactorBuilder<Reactor> for ReactorBuilder {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn create(self) -> Result<Reactor, ReactorCreateError> {
+        let origin = "linux_epoll::ReactorBuilder::create()";
+        match EpollBuilder::new().create() {
+            Ok(epoll) => Ok(Reactor { epoll }),
+            Err(EpollCreateError::UnknownError(v)) => {
+                fail!(from origin, with ReactorCreateError::UnknownError(v),
+                    "Unable to create reactor since an unknown failure occurred in the underlying epoll ({v}).");
+            }
+            Err(e) => {
+                fail!(from origin, with ReactorCreateError::UnknownError(0),
+                    "Unable to create reactor since the underlying epoll could not be created ({:?}).", e);
+            }
+        }
+    }
+}
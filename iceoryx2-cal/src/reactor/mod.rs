@@ -10,6 +10,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(target_os = "linux")]
+pub mod linux_epoll;
 pub mod posix_select;
 pub mod recommended;
 
@@ -31,6 +33,11 @@ pub enum ReactorAttachError {
     UnknownError(i32),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReactorMaskError {
+    UnknownError(i32),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ReactorWaitError {
     Interrupt,
@@ -40,6 +47,11 @@ pub enum ReactorWaitError {
 
 pub trait ReactorGuard<'reactor, 'attachment> {
     fn file_descriptor(&self) -> &FileDescriptor;
+
+    /// The attachment is no longer reported in the wait calls until it is unmasked but it
+    /// stays attached.
+    fn mask(&self) -> Result<(), ReactorMaskError>;
+    fn unmask(&self) -> Result<(), ReactorMaskError>;
 }
 
 pub trait Reactor: Sized + Debug + Send {
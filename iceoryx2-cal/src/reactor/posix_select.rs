@@ -22,12 +22,22 @@ use iceoryx2_bb_posix::{
     },
 };
 
-use crate::reactor::{ReactorAttachError, ReactorWaitError};
+use crate::reactor::{ReactorAttachError, ReactorMaskError, ReactorWaitError};
 
 impl crate::reactor::ReactorGuard<'_, '_> for FileDescriptorSetGuard<'_, '_> {
     fn file_descriptor(&self) -> &FileDescriptor {
         self.file_descriptor()
     }
+
+    fn mask(&self) -> Result<(), ReactorMaskError> {
+        self.mask();
+        Ok(())
+    }
+
+    fn unmask(&self) -> Result<(), ReactorMaskError> {
+        self.unmask();
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
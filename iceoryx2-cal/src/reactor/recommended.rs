@@ -13,9 +13,23 @@
 /// Provides the recommended inter-process
 /// [`Reactor`](crate::reactor::Reactor) concept
 /// implementation for the target.
+#[cfg(target_os = "linux")]
+pub type Ipc = crate::reactor::linux_epoll::Reactor;
+
+/// Provides the recommended inter-process
+/// [`Reactor`](crate::reactor::Reactor) concept
+/// implementation for the target.
+#[cfg(not(target_os = "linux"))]
 pub type Ipc = crate::reactor::posix_select::Reactor;
 
 /// Provides the recommended process-local
 /// [`Reactor`](crate::reactor::Reactor) concept
 /// implementation for the target.
+#[cfg(target_os = "linux")]
+pub type Local = crate::reactor::linux_epoll::Reactor;
+
+/// Provides the recommended process-local
+/// [`Reactor`](crate::reactor::Reactor) concept
+/// implementation for the target.
+#[cfg(not(target_os = "linux"))]
 pub type Local = crate::reactor::posix_select::Reactor;
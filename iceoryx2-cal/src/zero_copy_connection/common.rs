@@ -33,7 +33,7 @@ pub mod details {
         index_queue::RelocatableIndexQueue,
         safely_overflowing_index_queue::RelocatableSafelyOverflowingIndexQueue,
     };
-    use iceoryx2_bb_log::{fail, fatal_panic};
+    use iceoryx2_bb_log::{fail, fatal_panic, warn};
     use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 
     use self::used_chunk_list::RelocatableUsedChunkList;
@@ -131,7 +131,11 @@ pub mod details {
 
     #[derive(Debug)]
     struct SegmentDetails {
+        // chunks that were sent by the sender and not yet reclaimed
         used_chunk_list: RelocatableUsedChunkList,
+        // chunks that were received by the receiver and not yet released, required to return
+        // them to the sender when the receiver dies
+        borrowed_chunk_list: RelocatableUsedChunkList,
         sample_size: IoxAtomicUsize,
     }
 
@@ -139,16 +143,20 @@ pub mod details {
         fn new_uninit(number_of_samples: usize) -> Self {
             Self {
                 used_chunk_list: unsafe { RelocatableUsedChunkList::new_uninit(number_of_samples) },
+                borrowed_chunk_list: unsafe {
+                    RelocatableUsedChunkList::new_uninit(number_of_samples)
+                },
                 sample_size: IoxAtomicUsize::new(0),
             }
         }
 
         const fn const_memory_size(number_of_samples: usize) -> usize {
-            RelocatableUsedChunkList::const_memory_size(number_of_samples)
+            2 * RelocatableUsedChunkList::const_memory_size(number_of_samples)
         }
 
         unsafe fn init<T: BaseAllocator>(&mut self, allocator: &T) -> Result<(), AllocationError> {
-            self.used_chunk_list.init(allocator)
+            self.used_chunk_list.init(allocator)?;
+            self.borrowed_chunk_list.init(allocator)
         }
     }
 
@@ -158,6 +166,7 @@ pub mod details {
         submission_queue: RelocatableSafelyOverflowingIndexQueue,
         completion_queue: RelocatableIndexQueue,
         state: IoxAtomicU64,
+        missed_samples: IoxAtomicU64,
     }
 
     impl Channel {
@@ -170,6 +179,7 @@ pub mod details {
                     RelocatableIndexQueue::new_uninit(completion_queue_capacity)
                 },
                 state: IoxAtomicU64::new(INITIAL_CHANNEL_STATE),
+                missed_samples: IoxAtomicU64::new(0),
             }
         }
 
@@ -230,6 +240,47 @@ pub mod details {
             &self.segment_details[idx]
         }
 
+        /// Returns all chunks that are in the submission queue or borrowed by the receiver
+        /// back to the sender via the completion queue.
+        ///
+        /// # Safety
+        ///
+        ///  * the receiver must be dead, otherwise it competes with this call in the roles of
+        ///    the submission queue consumer and the completion queue producer
+        unsafe fn return_chunks_of_dead_receiver(&self) {
+            let origin = "SharedManagementData::return_chunks_of_dead_receiver()";
+
+            for (channel_id, channel) in self.channels.iter().enumerate() {
+                let return_chunk = |value: u64| {
+                    if !channel.completion_queue.push(value) {
+                        warn!(from origin,
+                            "Unable to return the chunk {:?} of the dead receiver since the completion queue is full. The chunk is recovered when the sender removes the connection.",
+                            PointerOffset::from_value(value));
+                    }
+                };
+
+                // chunks that were delivered but never received
+                while let Some(value) = channel.submission_queue.pop() {
+                    return_chunk(value);
+                }
+
+                // chunks that were received but never released
+                for segment_id in 0..self.number_of_segments {
+                    let segment_details = self.get_segment_details(segment_id as usize, channel_id);
+                    let sample_size = segment_details.sample_size.load(Ordering::Relaxed);
+                    segment_details.borrowed_chunk_list.remove_all(|index| {
+                        return_chunk(
+                            PointerOffset::from_offset_and_segment_id(
+                                index * sample_size,
+                                SegmentId::new(segment_id),
+                            )
+                            .as_value(),
+                        )
+                    });
+                }
+            }
+        }
+
         fn is_connected(&self) -> bool {
             self.state.load(Ordering::Relaxed) == State::Sender.value() | State::Receiver.value()
         }
@@ -650,6 +701,11 @@ pub mod details {
             debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
             &self.storage.get().channels[channel_id.value()].state
         }
+
+        fn missed_samples(&self, channel_id: ChannelId) -> &IoxAtomicU64 {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            &self.storage.get().channels[channel_id.value()].missed_samples
+        }
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopySender for Sender<Storage> {
@@ -782,6 +838,13 @@ pub mod details {
             }
         }
 
+        fn number_of_pending_samples(&self, channel_id: ChannelId) -> usize {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            self.storage.get().channels[channel_id.value()]
+                .submission_queue
+                .len()
+        }
+
         unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, mut callback: F) {
             for (n, segment_details) in self.storage.get().segment_details.iter().enumerate() {
                 segment_details.used_chunk_list.remove_all(|index| {
@@ -849,6 +912,32 @@ pub mod details {
                 &mut *self.borrow_counter[channel_id.value()].get()
             }
         }
+
+        // returns the list of borrowed chunks and the index of the chunk in it, or none if the
+        // chunk cannot be tracked since it was corrupted
+        fn borrowed_chunk(
+            &self,
+            ptr: PointerOffset,
+            channel_id: ChannelId,
+        ) -> Option<(&RelocatableUsedChunkList, usize)> {
+            let storage = self.storage.get();
+            let segment_id = ptr.segment_id().value();
+            if segment_id >= storage.number_of_segments {
+                return None;
+            }
+
+            let segment_details =
+                storage.get_segment_details(segment_id as usize, channel_id.value());
+            let sample_size = segment_details.sample_size.load(Ordering::Relaxed);
+            if sample_size == 0 {
+                return None;
+            }
+
+            Some((
+                &segment_details.borrowed_chunk_list,
+                ptr.offset() / sample_size,
+            ))
+        }
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> NamedConcept for Receiver<Storage> {
@@ -886,6 +975,11 @@ pub mod details {
             debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
             &self.storage.get().channels[channel_id.value()].state
         }
+
+        fn missed_samples(&self, channel_id: ChannelId) -> &IoxAtomicU64 {
+            debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
+            &self.storage.get().channels[channel_id.value()].missed_samples
+        }
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopyReceiver for Receiver<Storage> {
@@ -915,8 +1009,15 @@ pub mod details {
             } {
                 None => Ok(None),
                 Some(v) => {
+                    let ptr = PointerOffset::from_value(v);
+                    if let Some((borrowed_chunk_list, index)) = self.borrowed_chunk(ptr, channel_id)
+                    {
+                        let is_not_yet_borrowed = borrowed_chunk_list.insert(index);
+                        debug_assert!(is_not_yet_borrowed);
+                    }
+
                     *self.borrow_counter(channel_id) += 1;
-                    Ok(Some(PointerOffset::from_value(v)))
+                    Ok(Some(ptr))
                 }
             }
         }
@@ -932,6 +1033,13 @@ pub mod details {
         ) -> Result<(), ZeroCopyReleaseError> {
             debug_assert!(channel_id.value() < self.storage.get().channels.capacity());
 
+            // the chunk is removed from the borrowed chunks before it is returned, so that a
+            // crash in between can never lead to a chunk that is returned twice
+            let borrowed_chunk = self.borrowed_chunk(ptr, channel_id);
+            if let Some((borrowed_chunk_list, index)) = borrowed_chunk {
+                borrowed_chunk_list.remove(index);
+            }
+
             match unsafe {
                 self.storage.get().channels[channel_id.value()]
                     .completion_queue
@@ -942,6 +1050,10 @@ pub mod details {
                     Ok(())
                 }
                 false => {
+                    if let Some((borrowed_chunk_list, index)) = borrowed_chunk {
+                        borrowed_chunk_list.insert(index);
+                    }
+
                     fail!(from self, with ZeroCopyReleaseError::RetrieveBufferFull,
                     "Unable to release pointer since the retrieve buffer is full.");
                 }
@@ -1046,6 +1158,7 @@ pub mod details {
                 config,
                 "Unable to remove forcefully the receiver of the Zero Copy Connection",
             )?;
+            storage.get().return_chunks_of_dead_receiver();
             cleanup_shared_memory(&storage, State::Receiver);
             Ok(())
         }
@@ -164,6 +164,9 @@ pub trait ZeroCopyPortDetails {
     fn max_supported_shared_memory_segments(&self) -> u8;
     fn is_connected(&self) -> bool;
     fn channel_state(&self, channel_id: ChannelId) -> &IoxAtomicU64;
+    /// Counter of the samples of the channel that never reached the receiver, maintained by
+    /// the user of the connection. It is zero on creation.
+    fn missed_samples(&self, channel_id: ChannelId) -> &IoxAtomicU64;
 }
 
 pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept + Send {
@@ -184,6 +187,9 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept + Send {
     fn reclaim(&self, channel_id: ChannelId)
         -> Result<Option<PointerOffset>, ZeroCopyReclaimError>;
 
+    /// Returns the number of samples that were sent but not yet received by the receiver.
+    fn number_of_pending_samples(&self, channel_id: ChannelId) -> usize;
+
     /// # Safety
     ///
     /// * must ensure that no receiver is still holding data, otherwise data races may occur on
@@ -223,8 +229,10 @@ pub trait ZeroCopyConnection: Debug + Sized + NamedConceptMgmt {
     ) -> Result<(), ZeroCopyPortRemoveError>;
 
     /// Removes the [`ZeroCopyReceiver`] forcefully from the [`ZeroCopyConnection`]. This shall
-    /// only be called when the [`ZeroCopySender`] died and the connection shall be cleaned up
+    /// only be called when the [`ZeroCopyReceiver`] died and the connection shall be cleaned up
     /// without causing any problems on the living [`ZeroCopySender`] side.
+    /// All samples that were delivered to or borrowed by the [`ZeroCopyReceiver`] are returned
+    /// to the [`ZeroCopySender`] and can be reacquired with [`ZeroCopySender::reclaim()`].
     ///
     /// # Safety
     ///
@@ -0,0 +1,51 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(target_os = "linux")]
+mod event_linux_eventfd {
+    use core::time::Duration;
+
+    use iceoryx2_bb_posix::epoll::*;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::linux_eventfd::{EventImpl, ListenerBuilder, NotifierBuilder};
+    use iceoryx2_cal::event::{
+        Listener, ListenerBuilder as _, Notifier, NotifierBuilder as _, TriggerId,
+    };
+    use iceoryx2_cal::named_concept::*;
+    use iceoryx2_cal::testing::*;
+
+    const TIMEOUT: Duration = Duration::from_millis(25);
+
+    #[test]
+    fn listener_stays_readable_while_trigger_ids_are_pending() {
+        let name = generate_name();
+        let config = generate_isolated_config::<EventImpl>();
+
+        let sut = ListenerBuilder::new(&name)
+            .config(&config)
+            .create()
+            .unwrap();
+        let notifier = NotifierBuilder::new(&name).config(&config).open().unwrap();
+        let epoll = EpollBuilder::new().create().unwrap();
+        let _guard = epoll.add(&sut).unwrap();
+
+        notifier.notify(TriggerId::new(1)).unwrap();
+        notifier.notify(TriggerId::new(2)).unwrap();
+
+        assert_that!(sut.try_wait_one().unwrap(), is_some);
+        assert_that!(epoll.timed_wait(TIMEOUT, |_| {}).unwrap(), eq 1);
+
+        assert_that!(sut.try_wait_one().unwrap(), is_some);
+        assert_that!(sut.try_wait_one().unwrap(), is_none);
+        assert_that!(epoll.timed_wait(TIMEOUT, |_| {}).unwrap(), eq 0);
+    }
+}
@@ -106,4 +106,8 @@ mod signal_mechanism {
 
     #[instantiate_tests(<Semaphore>)]
     mod semaphore {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2_cal::event::signal_mechanism::futex::Futex>)]
+    mod futex {}
 }
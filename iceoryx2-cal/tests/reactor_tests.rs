@@ -100,6 +100,33 @@ mod reactor {
         assert_that!(result.err(), eq Some(ReactorAttachError::AlreadyAttached));
     }
 
+    #[test]
+    fn masked_attachment_is_not_reported_until_it_is_unmasked<Sut: Reactor>() {
+        let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
+
+        let attachment = NotifierListenerPair::new();
+        attachment.notifier.notify(TriggerId::new(123)).unwrap();
+
+        let guard = sut.attach(&attachment.listener).unwrap();
+        guard.mask().unwrap();
+
+        let mut triggered_fds = vec![];
+        assert_that!(
+            sut.try_wait(|fd| triggered_fds.push(unsafe { fd.native_handle() })),
+            eq Ok(0)
+        );
+        assert_that!(sut.len(), eq 1);
+        assert_that!(sut.attach(&attachment.listener).err(), eq Some(ReactorAttachError::AlreadyAttached));
+
+        guard.unmask().unwrap();
+        assert_that!(
+            sut.try_wait(|fd| triggered_fds.push(unsafe { fd.native_handle() })),
+            eq Ok(1)
+        );
+        assert_that!(triggered_fds, len 1);
+        assert_that!(triggered_fds[0], eq unsafe { attachment.listener.file_descriptor().native_handle() });
+    }
+
     #[test]
     fn try_wait_does_not_block_when_triggered_single<Sut: Reactor>() {
         let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
@@ -573,4 +600,8 @@ mod reactor {
 
     #[instantiate_tests(<iceoryx2_cal::reactor::posix_select::Reactor>)]
     mod posix_select {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2_cal::reactor::linux_epoll::Reactor>)]
+    mod linux_epoll {}
 }
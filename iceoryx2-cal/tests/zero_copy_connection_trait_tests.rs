@@ -410,6 +410,43 @@ mod zero_copy_connection {
         assert_that!(retrieval, is_none);
     }
 
+    #[test]
+    fn number_of_pending_samples_counts_unreceived_samples<Sut: ZeroCopyConnection>() {
+        let id = ChannelId::new(0);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(4)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(4)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        assert_that!(sut_sender.number_of_pending_samples(id), eq 0);
+
+        for n in 0..3 {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * n), SAMPLE_SIZE, id),
+                is_ok
+            );
+            assert_that!(sut_sender.number_of_pending_samples(id), eq n + 1);
+        }
+
+        let sample = sut_receiver.receive(id).unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(sut_sender.number_of_pending_samples(id), eq 2);
+
+        assert_that!(sut_receiver.release(sample.unwrap(), id), is_ok);
+        assert_that!(sut_sender.number_of_pending_samples(id), eq 2);
+    }
+
     #[test]
     fn send_receive_and_retrieval_works_for_multiple_channels<Sut: ZeroCopyConnection>() {
         const NUMBER_OF_CHANNELS: usize = 7;
@@ -1672,6 +1709,69 @@ mod zero_copy_connection {
         assert_that!(Sut::does_exist_cfg(&name, &config), eq Ok(false));
     }
 
+    #[test]
+    fn removing_dead_receiver_returns_its_samples_to_the_sender<Sut: ZeroCopyConnection>() {
+        const BUFFER_SIZE: usize = 10;
+        const NUMBER_OF_CHANNELS: usize = 2;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples_per_channel(BUFFER_SIZE)
+            .number_of_channels(NUMBER_OF_CHANNELS)
+            .config(&config)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples_per_segment(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples_per_channel(BUFFER_SIZE)
+            .number_of_channels(NUMBER_OF_CHANNELS)
+            .config(&config)
+            .create_receiver()
+            .unwrap();
+
+        for c in 0..NUMBER_OF_CHANNELS {
+            let id = ChannelId::new(c);
+            for i in 0..BUFFER_SIZE {
+                let sample_offset = SAMPLE_SIZE * (i + c * BUFFER_SIZE);
+                assert_that!(
+                    sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE, id),
+                    is_ok
+                );
+            }
+
+            // borrow some samples, release some and leave the rest in the buffer
+            for _ in 0..BUFFER_SIZE / 2 {
+                assert_that!(sut_receiver.receive(id).unwrap(), is_some);
+            }
+            let offset = sut_receiver.receive(id).unwrap().unwrap();
+            sut_receiver.release(offset, id).unwrap();
+        }
+
+        core::mem::forget(sut_receiver);
+        assert_that!(unsafe { Sut::remove_receiver(&name, &config) }, is_ok);
+
+        for c in 0..NUMBER_OF_CHANNELS {
+            let id = ChannelId::new(c);
+            let mut offsets = HashSet::new();
+            while let Some(offset) = sut_sender.reclaim(id).unwrap() {
+                assert_that!(offsets.insert(offset.offset()), eq true);
+            }
+
+            assert_that!(offsets, len BUFFER_SIZE);
+            for i in 0..BUFFER_SIZE {
+                assert_that!(offsets, contains SAMPLE_SIZE * (i + c * BUFFER_SIZE));
+            }
+        }
+
+        let mut sample_acquired = false;
+        unsafe { sut_sender.acquire_used_offsets(|_| sample_acquired = true) };
+        assert_that!(sample_acquired, eq false);
+    }
+
     #[cfg(debug_assertions)]
     #[should_panic]
     #[test]
@@ -1876,6 +1976,38 @@ mod zero_copy_connection {
         drop(sut_sender);
     }
 
+    #[test]
+    fn missed_samples_are_zero_on_creation_and_shared_between_sender_and_receiver<
+        Sut: ZeroCopyConnection,
+    >() {
+        const NUMBER_OF_CHANNELS: usize = 3;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .config(&config)
+            .number_of_channels(NUMBER_OF_CHANNELS)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .config(&config)
+            .number_of_channels(NUMBER_OF_CHANNELS)
+            .create_receiver()
+            .unwrap();
+
+        for id in 0..NUMBER_OF_CHANNELS {
+            assert_that!(sut_receiver.missed_samples(ChannelId::new(id)).load(Ordering::Relaxed), eq 0);
+        }
+
+        sut_sender
+            .missed_samples(ChannelId::new(1))
+            .fetch_add(5, Ordering::Relaxed);
+
+        assert_that!(sut_receiver.missed_samples(ChannelId::new(0)).load(Ordering::Relaxed), eq 0);
+        assert_that!(sut_receiver.missed_samples(ChannelId::new(1)).load(Ordering::Relaxed), eq 5);
+        assert_that!(sut_receiver.missed_samples(ChannelId::new(2)).load(Ordering::Relaxed), eq 0);
+    }
+
     #[test]
     fn initial_channel_state_can_be_defined_for_all_channels<Sut: ZeroCopyConnection>() {
         const NUMBER_OF_CHANNELS: usize = 11;
@@ -10,12 +10,20 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use std::net::Ipv4Addr;
+
 use clap::Parser;
 use clap::Subcommand;
 
 use iceoryx2_cli::help_template;
 use iceoryx2_cli::HelpOptions;
 
+use iceoryx2_tunnels_common::ServiceRemapping;
+use iceoryx2_tunnels_common::ServiceRule;
+use iceoryx2_tunnels_common::ThroughputRule;
+use iceoryx2_tunnels_socket::Peer;
+use iceoryx2_tunnels_socket::DEFAULT_MAX_FRAME_SIZE;
+
 #[derive(Parser)]
 #[command(
     name = "iox2 tunnel",
@@ -39,6 +47,38 @@ pub struct Cli {
     )]
     pub discovery_service: Option<String>,
 
+    #[clap(
+        long,
+        value_name = "RULE",
+        global = true,
+        help = "Only propagate services matching the rule, e.g. 'name=camera/*,pattern=Event,attribute:KEY=GLOB' (repeatable)"
+    )]
+    pub include: Vec<ServiceRule>,
+
+    #[clap(
+        long,
+        value_name = "RULE",
+        global = true,
+        help = "Never propagate services matching the rule, takes precedence over '--include' (repeatable)"
+    )]
+    pub exclude: Vec<ServiceRule>,
+
+    #[clap(
+        long,
+        value_name = "FROM=TO",
+        global = true,
+        help = "Announce local services whose name starts with FROM with the prefix TO instead, an empty FROM prefixes all services (repeatable)"
+    )]
+    pub remap: Vec<ServiceRemapping>,
+
+    #[clap(
+        long,
+        value_name = "RULE",
+        global = true,
+        help = "Limit the throughput of publish-subscribe services matching the rule, e.g. 'name=imu/*,max_rate=100,downsample=MS,byte_budget=BYTES' (repeatable)"
+    )]
+    pub limit: Vec<ThroughputRule>,
+
     #[clap(
         long,
         value_name = "RATE",
@@ -68,6 +108,48 @@ pub struct ZenohOptions {
     pub zenoh_config: Option<String>,
 }
 
+#[derive(Parser)]
+pub struct SocketOptions {
+    #[clap(
+        short,
+        long,
+        value_name = "ADDRESS",
+        default_value = "0.0.0.0",
+        help = "IPv4 address of the local interface the tunnel listens on"
+    )]
+    pub address: Ipv4Addr,
+
+    #[clap(
+        short,
+        long,
+        value_name = "PORT",
+        default_value_t = 7460,
+        help = "Port the tunnel listens on, must be reachable by the peers"
+    )]
+    pub port: u16,
+
+    #[clap(
+        long,
+        value_name = "ADDRESS:PORT",
+        help = "Address of a remote tunnel to exchange services with (repeatable)"
+    )]
+    pub peer: Vec<Peer>,
+
+    #[clap(
+        long,
+        value_name = "BYTES",
+        default_value_t = DEFAULT_MAX_FRAME_SIZE,
+        help = "Largest message received over tcp, the connection of a peer that sends a larger one is dropped"
+    )]
+    pub max_frame_size: usize,
+
+    #[clap(
+        long,
+        help = "Accept tcp connections only from the addresses of the peers"
+    )]
+    pub accept_only_peers: bool,
+}
+
 #[derive(Subcommand)]
 pub enum Transport {
     #[clap(
@@ -75,4 +157,14 @@ pub enum Transport {
         help_template = help_template(HelpOptions::DontPrintCommandSection)
     )]
     Zenoh(ZenohOptions),
+    #[clap(
+        about = "Use plain UDP sockets as the transport",
+        help_template = help_template(HelpOptions::DontPrintCommandSection)
+    )]
+    Udp(SocketOptions),
+    #[clap(
+        about = "Use plain TCP sockets as the transport",
+        help_template = help_template(HelpOptions::DontPrintCommandSection)
+    )]
+    Tcp(SocketOptions),
 }
@@ -39,9 +39,85 @@ mod supported_platform {
     use iceoryx2_bb_log::warn;
     use iceoryx2_bb_log::LogLevel;
 
-    use iceoryx2_tunnels_zenoh::Scope;
-    use iceoryx2_tunnels_zenoh::Tunnel;
-    use iceoryx2_tunnels_zenoh::TunnelConfig;
+    use iceoryx2_bb_system_types::ipv4_address::Ipv4Address;
+    use iceoryx2_bb_system_types::port::Port;
+
+    use iceoryx2_tunnels_common::ServiceFilter;
+    use iceoryx2_tunnels_common::ServiceRemapping;
+    use iceoryx2_tunnels_common::ThroughputRule;
+    use iceoryx2_tunnels_socket as socket_tunnel;
+    use iceoryx2_tunnels_zenoh as zenoh_tunnel;
+
+    fn socket_tunnel_config(
+        protocol: socket_tunnel::Protocol,
+        socket_options: cli::SocketOptions,
+        discovery_service: Option<String>,
+        service_filter: ServiceFilter,
+        service_remappings: Vec<ServiceRemapping>,
+        throughput_limits: Vec<ThroughputRule>,
+    ) -> socket_tunnel::TunnelConfig {
+        let [a, b, c, d] = socket_options.address.octets();
+        socket_tunnel::TunnelConfig {
+            discovery_service,
+            protocol,
+            address: Ipv4Address::new(a, b, c, d),
+            port: Port::new(socket_options.port),
+            peers: socket_options.peer,
+            service_filter,
+            service_remappings,
+            throughput_limits,
+            max_frame_size: socket_options.max_frame_size,
+            accept_only_peers: socket_options.accept_only_peers,
+            ..Default::default()
+        }
+    }
+
+    fn run_socket_tunnel(
+        tunnel_config: &socket_tunnel::TunnelConfig,
+        iox_config: &iceoryx2::config::Config,
+        poll: Option<u64>,
+        reactive: bool,
+    ) -> anyhow::Result<()> {
+        let mut tunnel = socket_tunnel::Tunnel::<ipc::Service>::create(tunnel_config, iox_config)?;
+
+        run(poll, reactive, || {
+            let _ = tunnel
+                .discover(socket_tunnel::Scope::Both)
+                .inspect_err(|e| {
+                    warn!("Error encountered whilst discoverying services: {}", e);
+                });
+            let _ = tunnel.propagate().inspect_err(|e| {
+                warn!("Error encountered whilst propagating between hosts: {e}");
+            });
+        })
+    }
+
+    /// Runs the provided step of the tunnel until the process is terminated.
+    fn run(poll: Option<u64>, reactive: bool, mut step: impl FnMut()) -> anyhow::Result<()> {
+        let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+
+        if reactive {
+            // TODO(functionality): Make tunnel (or its endpoints) attachable to waitset
+            unimplemented!("Reactive mode is not yet supported.");
+        } else {
+            let rate = poll.unwrap_or(100);
+            info!("Polling rate {}ms", rate);
+
+            let guard = waitset.attach_interval(core::time::Duration::from_millis(rate))?;
+            let tick = WaitSetAttachmentId::from_guard(&guard);
+
+            let on_event = |id: WaitSetAttachmentId<ipc::Service>| {
+                if id == tick {
+                    step();
+                }
+                CallbackProgression::Continue
+            };
+
+            waitset.wait_and_process(on_event)?;
+        }
+
+        Ok(())
+    }
 
     pub fn main() -> anyhow::Result<()> {
         #[cfg(not(debug_assertions))]
@@ -70,14 +146,21 @@ mod supported_platform {
         };
 
         if let Some(transport) = cli.transport {
+            let iox_config = iceoryx2::config::Config::default();
+            let service_filter = ServiceFilter {
+                include: cli.include,
+                exclude: cli.exclude,
+            };
+
             match transport {
                 Transport::Zenoh(zenoh_options) => {
-                    let tunnel_config = TunnelConfig {
+                    let tunnel_config = zenoh_tunnel::TunnelConfig {
                         discovery_service: cli.discovery_service,
+                        service_filter,
+                        service_remappings: cli.remap,
+                        throughput_limits: cli.limit,
                     };
 
-                    let iox_config = iceoryx2::config::Config::default();
-
                     let zenoh_config = match zenoh_options.zenoh_config {
                         Some(path) => zenoh::Config::from_file(&path).map_err(|e| {
                             anyhow::anyhow!("failed to read zenoh config file '{path}': {e}")
@@ -85,37 +168,42 @@ mod supported_platform {
                         None => zenoh::Config::default(),
                     };
 
-                    let mut tunnel =
-                        Tunnel::<ipc::Service>::create(&tunnel_config, &iox_config, &zenoh_config)?;
-                    let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
-
-                    if cli.reactive {
-                        // TODO(functionality): Make tunnel (or its endpoints) attachable to waitset
-                        unimplemented!("Reactive mode is not yet supported.");
-                    } else {
-                        let rate = cli.poll.unwrap_or(100);
-                        info!("Polling rate {}ms", rate);
-
-                        let guard =
-                            waitset.attach_interval(core::time::Duration::from_millis(rate))?;
-                        let tick = WaitSetAttachmentId::from_guard(&guard);
-
-                        let on_event = |id: WaitSetAttachmentId<ipc::Service>| {
-                            if id == tick {
-                                let _ = tunnel.discover(Scope::Both).inspect_err(|e| {
-                                    warn!("Error encountered whilst discoverying services: {}", e);
-                                });
-                                let _ = tunnel.propagate().inspect_err(|e| {
-                                    warn!(
-                                        "Error encountered whilst propagating between hosts: {e}"
-                                    );
-                                });
-                            }
-                            CallbackProgression::Continue
-                        };
-
-                        waitset.wait_and_process(on_event)?;
-                    }
+                    let mut tunnel = zenoh_tunnel::Tunnel::<ipc::Service>::create(
+                        &tunnel_config,
+                        &iox_config,
+                        &zenoh_config,
+                    )?;
+
+                    run(cli.poll, cli.reactive, || {
+                        let _ = tunnel.discover(zenoh_tunnel::Scope::Both).inspect_err(|e| {
+                            warn!("Error encountered whilst discoverying services: {}", e);
+                        });
+                        let _ = tunnel.propagate().inspect_err(|e| {
+                            warn!("Error encountered whilst propagating between hosts: {e}");
+                        });
+                    })?;
+                }
+                Transport::Udp(socket_options) => {
+                    let tunnel_config = socket_tunnel_config(
+                        socket_tunnel::Protocol::Udp,
+                        socket_options,
+                        cli.discovery_service,
+                        service_filter,
+                        cli.remap,
+                        cli.limit,
+                    );
+                    run_socket_tunnel(&tunnel_config, &iox_config, cli.poll, cli.reactive)?;
+                }
+                Transport::Tcp(socket_options) => {
+                    let tunnel_config = socket_tunnel_config(
+                        socket_tunnel::Protocol::Tcp,
+                        socket_options,
+                        cli.discovery_service,
+                        service_filter,
+                        cli.remap,
+                        cli.limit,
+                    );
+                    run_socket_tunnel(&tunnel_config, &iox_config, cli.poll, cli.reactive)?;
                 }
             }
         }
@@ -29,6 +29,10 @@ enum class ClientCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The [`Service`] has notify on send enabled but the underlying event
+    /// service, that is used to exchange signals with the [`Server`]s, could not
+    /// be opened or created.
+    UnableToCreateNotificationChannel,
 };
 } // namespace iox2
 #endif
@@ -20,6 +20,11 @@ namespace iox2 {
 enum class EntryHandleError : uint8_t {
     /// The entry with the given key and value type does not exist.
     EntryDoesNotExist,
+    /// The maximum number of entry listeners that receive update notifications of the entry is
+    /// exceeded.
+    ExceedsMaxSupportedListeners,
+    /// The resources to receive update notifications of the entry could not be created.
+    ResourceCreationFailed,
 };
 } // namespace iox2
 
@@ -22,6 +22,8 @@ enum class EntryHandleMutError : uint8_t {
     EntryDoesNotExist,
     /// The [`EntryHandleMut`] already exists.
     HandleAlreadyExists,
+    /// The resources to notify the readers about updates of the entry could not be created.
+    ResourceCreationFailed,
 };
 } // namespace iox2
 
@@ -245,6 +245,10 @@ constexpr auto from<iox2::MessagingPattern, iox2_messaging_pattern_e>(const iox2
         return iox2_messaging_pattern_e_REQUEST_RESPONSE;
     case iox2::MessagingPattern::Blackboard:
         return iox2_messaging_pattern_e_BLACKBOARD;
+    case iox2::MessagingPattern::Pipeline:
+        return iox2_messaging_pattern_e_PIPELINE;
+    case iox2::MessagingPattern::Log:
+        return iox2_messaging_pattern_e_LOG;
     }
 
     IOX_UNREACHABLE();
@@ -331,6 +335,8 @@ constexpr auto from<int, iox2::EventOpenOrCreateError>(const int value) noexcept
         return iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedMaxEventId;
     case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES:
         return iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfNodes;
+    case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_PAYLOAD_SIZE:
+        return iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedNotificationPayloadSize;
     case iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES:
         return iox2::EventOpenOrCreateError::OpenExceedsMaxNumberOfNodes;
     case iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
@@ -389,6 +395,8 @@ from<iox2::EventOpenOrCreateError, iox2_event_open_or_create_error_e>(const iox2
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID;
     case iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfNodes:
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
+    case iox2::EventOpenOrCreateError::OpenDoesNotSupportRequestedNotificationPayloadSize:
+        return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_PAYLOAD_SIZE;
     case iox2::EventOpenOrCreateError::OpenExceedsMaxNumberOfNodes:
         return iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::EventOpenOrCreateError::OpenIsMarkedForDestruction:
@@ -445,6 +453,8 @@ constexpr auto from<int, iox2::EventOpenError>(const int value) noexcept -> iox2
         return iox2::EventOpenError::DoesNotSupportRequestedMaxEventId;
     case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES:
         return iox2::EventOpenError::DoesNotSupportRequestedAmountOfNodes;
+    case iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_PAYLOAD_SIZE:
+        return iox2::EventOpenError::DoesNotSupportRequestedNotificationPayloadSize;
     case iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES:
         return iox2::EventOpenError::ExceedsMaxNumberOfNodes;
     case iox2_event_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
@@ -480,6 +490,8 @@ constexpr auto from<iox2::EventOpenError, iox2_event_open_or_create_error_e>(con
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID;
     case iox2::EventOpenError::DoesNotSupportRequestedAmountOfNodes:
         return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
+    case iox2::EventOpenError::DoesNotSupportRequestedNotificationPayloadSize:
+        return iox2_event_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_PAYLOAD_SIZE;
     case iox2::EventOpenError::ExceedsMaxNumberOfNodes:
         return iox2_event_open_or_create_error_e_O_EXCEEDS_MAX_NUMBER_OF_NODES;
     case iox2::EventOpenError::IsMarkedForDestruction:
@@ -573,6 +585,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleNotifyOnSendBehavior;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenOrCreateError::OpenInsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -635,6 +649,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenError::IncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR:
+        return iox2::PublishSubscribeOpenError::IncompatibleNotifyOnSendBehavior;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenError::InsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -678,6 +694,8 @@ constexpr auto from<iox2::PublishSubscribeOpenError, iox2_pub_sub_open_or_create
         return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::PublishSubscribeOpenError::IncompatibleOverflowBehavior:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR;
+    case iox2::PublishSubscribeOpenError::IncompatibleNotifyOnSendBehavior:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR;
     case iox2::PublishSubscribeOpenError::InsufficientPermissions:
         return iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::PublishSubscribeOpenError::ServiceInCorruptedState:
@@ -780,6 +798,8 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOverflowBehavior:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleNotifyOnSendBehavior:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR;
     case iox2::PublishSubscribeOpenOrCreateError::OpenInsufficientPermissions:
         return iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::PublishSubscribeOpenOrCreateError::OpenServiceInCorruptedState:
@@ -906,6 +926,8 @@ constexpr auto from<int, iox2::RequestResponseOpenError>(const int value) noexce
         return iox2::RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS:
         return iox2::RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR:
+        return iox2::RequestResponseOpenError::IncompatibleNotifyOnSendBehavior;
     case iox2_request_response_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::RequestResponseOpenError::InsufficientPermissions;
     case iox2_request_response_open_or_create_error_e_O_INTERNAL_FAILURE:
@@ -957,6 +979,8 @@ constexpr auto from<iox2::RequestResponseOpenError, iox2_request_response_open_o
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES;
     case iox2::RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS;
+    case iox2::RequestResponseOpenError::IncompatibleNotifyOnSendBehavior:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR;
     case iox2::RequestResponseOpenError::InsufficientPermissions:
         return iox2_request_response_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::RequestResponseOpenError::InternalFailure:
@@ -1017,6 +1041,8 @@ constexpr auto from<int, iox2::RequestResponseOpenOrCreateError>(const int value
         return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleOverflowBehaviorForResponses;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS:
         return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleBehaviorForFireAndForgetRequests;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR:
+        return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleNotifyOnSendBehavior;
     case iox2_request_response_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::RequestResponseOpenOrCreateError::OpenInsufficientPermissions;
     case iox2_request_response_open_or_create_error_e_O_INTERNAL_FAILURE:
@@ -1084,6 +1110,8 @@ constexpr auto from<iox2::RequestResponseOpenOrCreateError, iox2_request_respons
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES;
     case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleBehaviorForFireAndForgetRequests:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS;
+    case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleNotifyOnSendBehavior:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR;
     case iox2::RequestResponseOpenOrCreateError::OpenInsufficientPermissions:
         return iox2_request_response_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::RequestResponseOpenOrCreateError::OpenInternalFailure:
@@ -1285,6 +1313,8 @@ constexpr auto from<int, iox2::EntryHandleMutError>(const int value) noexcept ->
         return iox2::EntryHandleMutError::EntryDoesNotExist;
     case iox2_entry_handle_mut_error_e_HANDLE_ALREADY_EXISTS:
         return iox2::EntryHandleMutError::HandleAlreadyExists;
+    case iox2_entry_handle_mut_error_e_RESOURCE_CREATION_FAILED:
+        return iox2::EntryHandleMutError::ResourceCreationFailed;
     default:
         IOX_UNREACHABLE();
     }
@@ -1299,6 +1329,8 @@ from<iox2::EntryHandleMutError, iox2_entry_handle_mut_error_e>(const iox2::Entry
         return iox2_entry_handle_mut_error_e_ENTRY_DOES_NOT_EXIST;
     case iox2::EntryHandleMutError::HandleAlreadyExists:
         return iox2_entry_handle_mut_error_e_HANDLE_ALREADY_EXISTS;
+    case iox2::EntryHandleMutError::ResourceCreationFailed:
+        return iox2_entry_handle_mut_error_e_RESOURCE_CREATION_FAILED;
     default:
         IOX_UNREACHABLE();
     }
@@ -1343,6 +1375,10 @@ constexpr auto from<int, iox2::EntryHandleError>(const int value) noexcept -> io
     switch (error) {
     case iox2_entry_handle_error_e_ENTRY_DOES_NOT_EXIST:
         return iox2::EntryHandleError::EntryDoesNotExist;
+    case iox2_entry_handle_error_e_EXCEEDS_MAX_SUPPORTED_LISTENERS:
+        return iox2::EntryHandleError::ExceedsMaxSupportedListeners;
+    case iox2_entry_handle_error_e_RESOURCE_CREATION_FAILED:
+        return iox2::EntryHandleError::ResourceCreationFailed;
     default:
         IOX_UNREACHABLE();
     }
@@ -1354,6 +1390,10 @@ constexpr auto from<iox2::EntryHandleError, iox2_entry_handle_error_e>(const iox
     switch (value) {
     case iox2::EntryHandleError::EntryDoesNotExist:
         return iox2_entry_handle_error_e_ENTRY_DOES_NOT_EXIST;
+    case iox2::EntryHandleError::ExceedsMaxSupportedListeners:
+        return iox2_entry_handle_error_e_EXCEEDS_MAX_SUPPORTED_LISTENERS;
+    case iox2::EntryHandleError::ResourceCreationFailed:
+        return iox2_entry_handle_error_e_RESOURCE_CREATION_FAILED;
     default:
         IOX_UNREACHABLE();
     }
@@ -1374,6 +1414,8 @@ constexpr auto from<int, iox2::ClientCreateError>(const int value) noexcept -> i
         return iox2::ClientCreateError::UnableToCreateDataSegment;
     case iox2_client_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::ClientCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_client_create_error_e_UNABLE_TO_CREATE_NOTIFICATION_CHANNEL:
+        return iox2::ClientCreateError::UnableToCreateNotificationChannel;
     }
 
     IOX_UNREACHABLE();
@@ -1389,6 +1431,8 @@ constexpr auto from<iox2::ClientCreateError, iox2_client_create_error_e>(const i
         return iox2_client_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
     case iox2::ClientCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_client_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::ClientCreateError::UnableToCreateNotificationChannel:
+        return iox2_client_create_error_e_UNABLE_TO_CREATE_NOTIFICATION_CHANNEL;
     }
 
     IOX_UNREACHABLE();
@@ -1409,6 +1453,8 @@ constexpr auto from<int, iox2::ServerCreateError>(const int value) noexcept -> i
         return iox2::ServerCreateError::UnableToCreateDataSegment;
     case iox2_server_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::ServerCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_server_create_error_e_UNABLE_TO_CREATE_NOTIFICATION_CHANNEL:
+        return iox2::ServerCreateError::UnableToCreateNotificationChannel;
     }
 
     IOX_UNREACHABLE();
@@ -1424,6 +1470,8 @@ constexpr auto from<iox2::ServerCreateError, iox2_server_create_error_e>(const i
         return iox2_server_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
     case iox2::ServerCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_server_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::ServerCreateError::UnableToCreateNotificationChannel:
+        return iox2_server_create_error_e_UNABLE_TO_CREATE_NOTIFICATION_CHANNEL;
     }
 
     IOX_UNREACHABLE();
@@ -1514,6 +1562,8 @@ constexpr auto from<int, iox2::NotifierNotifyError>(const int value) noexcept ->
         return iox2::NotifierNotifyError::MissedDeadline;
     case iox2_notifier_notify_error_e_UNABLE_TO_ACQUIRE_ELAPSED_TIME:
         return iox2::NotifierNotifyError::UnableToAcquireElapsedTime;
+    case iox2_notifier_notify_error_e_PAYLOAD_TOO_LARGE:
+        return iox2::NotifierNotifyError::PayloadTooLarge;
     }
 
     IOX_UNREACHABLE();
@@ -1530,6 +1580,8 @@ from<iox2::NotifierNotifyError, iox2_notifier_notify_error_e>(const iox2::Notifi
         return iox2_notifier_notify_error_e_MISSED_DEADLINE;
     case iox2::NotifierNotifyError::UnableToAcquireElapsedTime:
         return iox2_notifier_notify_error_e_UNABLE_TO_ACQUIRE_ELAPSED_TIME;
+    case iox2::NotifierNotifyError::PayloadTooLarge:
+        return iox2_notifier_notify_error_e_PAYLOAD_TOO_LARGE;
     }
 
     IOX_UNREACHABLE();
@@ -1586,6 +1638,8 @@ constexpr auto from<int, iox2::PublisherCreateError>(const int value) noexcept -
         return iox2::PublisherCreateError::UnableToCreateDataSegment;
     case iox2_publisher_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::PublisherCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_publisher_create_error_e_UNABLE_TO_CREATE_NOTIFICATION_CHANNEL:
+        return iox2::PublisherCreateError::UnableToCreateNotificationChannel;
     }
 
     IOX_UNREACHABLE();
@@ -1602,6 +1656,8 @@ from<iox2::PublisherCreateError, iox2_publisher_create_error_e>(const iox2::Publ
         return iox2_publisher_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
     case iox2::PublisherCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_publisher_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::PublisherCreateError::UnableToCreateNotificationChannel:
+        return iox2_publisher_create_error_e_UNABLE_TO_CREATE_NOTIFICATION_CHANNEL;
     }
 
     IOX_UNREACHABLE();
@@ -1623,6 +1679,10 @@ constexpr auto from<int, iox2::SubscriberCreateError>(const int value) noexcept
         return iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers;
     case iox2_subscriber_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::SubscriberCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_subscriber_create_error_e_UNABLE_TO_CREATE_NOTIFICATION_CHANNEL:
+        return iox2::SubscriberCreateError::UnableToCreateNotificationChannel;
+    case iox2_subscriber_create_error_e_SAMPLE_FILTER_OUT_OF_BOUNDS:
+        return iox2::SubscriberCreateError::SampleFilterOutOfBounds;
     }
 
     IOX_UNREACHABLE();
@@ -1639,6 +1699,10 @@ from<iox2::SubscriberCreateError, iox2_subscriber_create_error_e>(const iox2::Su
         return iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS;
     case iox2::SubscriberCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_subscriber_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::SubscriberCreateError::UnableToCreateNotificationChannel:
+        return iox2_subscriber_create_error_e_UNABLE_TO_CREATE_NOTIFICATION_CHANNEL;
+    case iox2::SubscriberCreateError::SampleFilterOutOfBounds:
+        return iox2_subscriber_create_error_e_SAMPLE_FILTER_OUT_OF_BOUNDS;
     }
 
     IOX_UNREACHABLE();
@@ -1668,6 +1732,8 @@ constexpr auto from<int, iox2::SendError>(const int value) noexcept -> iox2::Sen
         return iox2::SendError::LoanErrorInternalFailure;
     case iox2_send_error_e_CONNECTION_ERROR:
         return iox2::SendError::ConnectionError;
+    case iox2_send_error_e_EXCEEDS_MAX_RESEND_HANDLES:
+        return iox2::SendError::ExceedsMaxResendHandles;
     }
 
     IOX_UNREACHABLE();
@@ -1690,6 +1756,8 @@ constexpr auto from<iox2::SendError, iox2_send_error_e>(const iox2::SendError va
         return iox2_send_error_e_LOAN_ERROR_INTERNAL_FAILURE;
     case iox2::SendError::ConnectionError:
         return iox2_send_error_e_CONNECTION_ERROR;
+    case iox2::SendError::ExceedsMaxResendHandles:
+        return iox2_send_error_e_EXCEEDS_MAX_RESEND_HANDLES;
     }
 
     IOX_UNREACHABLE();
@@ -1884,6 +1952,10 @@ constexpr auto from<int, iox2::MessagingPattern>(const int value) noexcept -> io
         return iox2::MessagingPattern::RequestResponse;
     case iox2_messaging_pattern_e_BLACKBOARD:
         return iox2::MessagingPattern::Blackboard;
+    case iox2_messaging_pattern_e_PIPELINE:
+        return iox2::MessagingPattern::Pipeline;
+    case iox2_messaging_pattern_e_LOG:
+        return iox2::MessagingPattern::Log;
     }
 
     IOX_UNREACHABLE();
@@ -13,6 +13,7 @@
 #ifndef IOX2_HEADER_PUBLISH_SUBSCRIBE_HPP
 #define IOX2_HEADER_PUBLISH_SUBSCRIBE_HPP
 
+#include "iox/duration.hpp"
 #include "iox2/internal/iceoryx2.hpp"
 #include "unique_port_id.hpp"
 
@@ -32,6 +33,14 @@ class HeaderPublishSubscribe {
     /// Returns the number of [`Payload`] elements in the received [`Sample`].
     auto number_of_elements() const -> uint64_t;
 
+    /// Returns the sequence number of the [`Sample`]. Every [`Publisher`] increments its
+    /// sequence number with every sent [`Sample`], starting at 0.
+    auto sequence_number() const -> uint64_t;
+
+    /// Returns the point in time when the [`Sample`] was sent as duration since the epoch
+    /// of the monotonic clock.
+    auto send_timestamp() const -> iox::units::Duration;
+
   private:
     template <ServiceType, typename, typename>
     friend class Sample;
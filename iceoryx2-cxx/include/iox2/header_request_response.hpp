@@ -13,6 +13,8 @@
 #ifndef IOX2_HEADER_REQUEST_RESPONSE_HPP
 #define IOX2_HEADER_REQUEST_RESPONSE_HPP
 
+#include "iox/duration.hpp"
+#include "iox/optional.hpp"
 #include "iox2/unique_port_id.hpp"
 
 namespace iox2 {
@@ -28,6 +30,14 @@ class RequestHeader {
     /// Returns the [`UniqueClientId`] of the source [`Client`].
     auto client_port_id() -> UniqueClientId;
 
+    /// Returns the number of elements of the payload.
+    auto number_of_elements() const -> uint64_t;
+
+    /// Returns the deadline until the [`Server`] must respond as duration since the epoch of
+    /// the monotonic clock. Returns [`iox::nullopt`] when the request was sent without a
+    /// deadline.
+    auto deadline() const -> iox::optional<iox::units::Duration>;
+
   private:
     template <ServiceType, typename, typename, typename, typename>
     friend class ActiveRequest;
@@ -41,6 +41,14 @@ enum class MessagingPattern : uint8_t {
     /// writes arbitrary data to a key-value store which can be read by many
     /// [`Reader`]s.
     Blackboard,
+
+    /// Unidirectional communication pattern where the [`Producer`]
+    /// hands every work item to exactly one of many [`Consumer`]s.
+    Pipeline,
+
+    /// Unidirectional communication pattern where many [`LogWriter`]s
+    /// write structured log records that are gathered by one or more [`LogCollector`]s.
+    Log,
 };
 } // namespace iox2
 
@@ -41,6 +41,9 @@ enum class NotifierNotifyError : uint8_t {
     /// The notification was delivered but the elapsed system time could not be acquired.
     /// Therefore, it is unknown if the deadline was missed or not.
     UnableToAcquireElapsedTime,
+    /// The provided payload is larger than the notification payload size of the
+    /// [`Service`] or the [`Service`] was created without notification details.
+    PayloadTooLarge,
 };
 
 } // namespace iox2
@@ -55,6 +55,9 @@ enum class SendError : uint8_t {
     LoanErrorInternalFailure,
     /// A failure occurred while establishing a connection to the ports counterpart port.
     ConnectionError,
+    /// The [`Publisher`] already holds the maximum number of resend handles it
+    /// was configured with.
+    ExceedsMaxResendHandles,
 };
 
 /// Defines the failure that can occur when receiving data with
@@ -27,6 +27,10 @@ enum class ServerCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`] as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The [`Service`] has notify on send enabled but the underlying event
+    /// service, that is used to exchange signals with the [`Client`]s, could not
+    /// be opened or created.
+    UnableToCreateNotificationChannel,
 };
 } // namespace iox2
 #endif
@@ -50,6 +50,9 @@ enum class EventOpenError : uint8_t {
     /// The [`Service`] supports less [`Node`]s than
     /// requested.
     DoesNotSupportRequestedAmountOfNodes,
+    /// The [`Service`] does not provide notification details or supports a
+    /// smaller notification payload than requested.
+    DoesNotSupportRequestedNotificationPayloadSize,
     /// The maximum number of [`Node`]s have already opened
     /// the [`Service`].
     ExceedsMaxNumberOfNodes,
@@ -134,6 +137,9 @@ enum class EventOpenOrCreateError : uint8_t {
     /// The [`Service`] supports less [`Node`]s than
     /// requested.
     OpenDoesNotSupportRequestedAmountOfNodes,
+    /// The [`Service`] does not provide notification details or supports a
+    /// smaller notification payload than requested.
+    OpenDoesNotSupportRequestedNotificationPayloadSize,
     /// The maximum number of [`Node`]s have already opened
     /// the [`Service`].
     OpenExceedsMaxNumberOfNodes,
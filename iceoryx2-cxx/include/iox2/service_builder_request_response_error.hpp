@@ -56,6 +56,8 @@ enum class RequestResponseOpenError : uint8_t {
     IncompatibleOverflowBehaviorForResponses,
     /// The [`Service`] does not support the required behavior for fire and forget requests.
     IncompatibleBehaviorForFireAndForgetRequests,
+    /// The [`Service`] required notify on send behavior is not compatible.
+    IncompatibleNotifyOnSendBehavior,
     /// The process has not enough permissions to open the [`Service`].
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
@@ -125,6 +127,8 @@ enum class RequestResponseOpenOrCreateError : uint8_t {
     OpenIncompatibleOverflowBehaviorForResponses,
     /// The [`Service`] does not support the required behavior for fire and forget requests.
     OpenIncompatibleBehaviorForFireAndForgetRequests,
+    /// The [`Service`] required notify on send behavior is not compatible.
+    OpenIncompatibleNotifyOnSendBehavior,
     /// The process has not enough permissions to open the [`Service`].
     OpenInsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
@@ -30,6 +30,13 @@ enum class SubscriberCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`] as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The [`Service`] has notify on send enabled but the underlying event
+    /// service, that is used to exchange signals with the [`Publisher`]s, could not
+    /// be opened or created.
+    UnableToCreateNotificationChannel,
+    /// The provided sample filter compares bytes beyond the end of the user
+    /// header or the fixed size payload of the [`Service`].
+    SampleFilterOutOfBounds,
 };
 
 } // namespace iox2
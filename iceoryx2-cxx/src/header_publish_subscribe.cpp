@@ -53,4 +53,15 @@ auto HeaderPublishSubscribe::publisher_id() const -> UniquePublisherId {
 auto HeaderPublishSubscribe::number_of_elements() const -> uint64_t {
     return iox2_publish_subscribe_header_number_of_elements(&m_handle);
 }
+
+auto HeaderPublishSubscribe::sequence_number() const -> uint64_t {
+    return iox2_publish_subscribe_header_sequence_number(&m_handle);
+}
+
+auto HeaderPublishSubscribe::send_timestamp() const -> iox::units::Duration {
+    uint64_t seconds = 0;
+    uint32_t nanoseconds = 0;
+    iox2_publish_subscribe_header_send_timestamp(&m_handle, &seconds, &nanoseconds);
+    return iox::units::Duration::fromSeconds(seconds) + iox::units::Duration::fromNanoseconds(nanoseconds);
+}
 } // namespace iox2
@@ -38,6 +38,21 @@ auto RequestHeader::client_port_id() -> UniqueClientId {
     return UniqueClientId { id_handle };
 }
 
+auto RequestHeader::number_of_elements() const -> uint64_t {
+    return iox2_request_header_number_of_elements(&m_handle);
+}
+
+auto RequestHeader::deadline() const -> iox::optional<iox::units::Duration> {
+    uint64_t seconds = 0;
+    uint32_t nanoseconds = 0;
+
+    if (iox2_request_header_deadline(&m_handle, &seconds, &nanoseconds)) {
+        return { iox::units::Duration::fromSeconds(seconds) + iox::units::Duration::fromNanoseconds(nanoseconds) };
+    }
+
+    return iox::nullopt;
+}
+
 RequestHeader::RequestHeader(iox2_request_header_h handle)
     : m_handle(handle) {
 }
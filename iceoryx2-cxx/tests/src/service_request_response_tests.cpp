@@ -399,6 +399,21 @@ TYPED_TEST(ServiceRequestResponseTest, loan_request_default_constructs_request_h
     ASSERT_THAT(sut.user_header(), Eq(UserHeader()));
 }
 
+TYPED_TEST(ServiceRequestResponseTest, request_header_has_no_deadline_by_default) {
+    constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
+
+    const auto service_name = iox2_testing::generate_service_name();
+
+    auto node = NodeBuilder().create<SERVICE_TYPE>().expect("");
+    auto service = node.service_builder(service_name).template request_response<uint64_t, uint64_t>().create().expect("");
+
+    auto client = service.client_builder().create().expect("");
+
+    auto sut = client.loan().expect("");
+    ASSERT_THAT(sut.header().deadline().has_value(), Eq(false));
+    ASSERT_THAT(sut.header().number_of_elements(), Eq(1));
+}
+
 TYPED_TEST(ServiceRequestResponseTest, loan_uninit_request_default_constructs_request_header) {
     constexpr uint64_t RAND_A = 1239;
     constexpr uint32_t RAND_B = 4569;
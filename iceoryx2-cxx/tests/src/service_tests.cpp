@@ -112,6 +112,12 @@ TYPED_TEST(ServiceTest, list_works) {
             EXPECT_THAT(details.static_details.name(), StrEq(service_name_4.to_string().c_str()));
             EXPECT_THAT(details.static_details.id(), StrEq(sut_4.service_id().c_str()));
             break;
+        case MessagingPattern::Pipeline:
+            ADD_FAILURE() << "unexpected pipeline service";
+            break;
+        case MessagingPattern::Log:
+            ADD_FAILURE() << "unexpected log service";
+            break;
         }
 
         return CallbackProgression::Continue;
@@ -222,6 +228,12 @@ TYPED_TEST(ServiceTest, list_works_with_attributes) {
             });
             EXPECT_THAT(counter, Eq(1));
             break;
+        case MessagingPattern::Pipeline:
+            ADD_FAILURE() << "unexpected pipeline service";
+            break;
+        case MessagingPattern::Log:
+            ADD_FAILURE() << "unexpected log service";
+            break;
         }
 
         return CallbackProgression::Continue;
@@ -82,6 +82,8 @@ mod signal_handling_mode;
 mod static_config;
 mod static_config_blackboard;
 mod static_config_event;
+mod static_config_log;
+mod static_config_pipeline;
 mod static_config_publish_subscribe;
 mod static_config_request_response;
 mod subscriber;
@@ -163,6 +165,8 @@ pub use signal_handling_mode::*;
 pub use static_config::*;
 pub use static_config_blackboard::*;
 pub use static_config_event::*;
+pub use static_config_log::*;
+pub use static_config_pipeline::*;
 pub use static_config_publish_subscribe::*;
 pub use static_config_request_response::*;
 pub use subscriber::*;
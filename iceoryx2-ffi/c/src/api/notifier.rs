@@ -34,6 +34,7 @@ pub enum iox2_notifier_notify_error_e {
     EVENT_ID_OUT_OF_BOUNDS = IOX2_OK as isize + 1,
     MISSED_DEADLINE,
     UNABLE_TO_ACQUIRE_ELAPSED_TIME,
+    PAYLOAD_TOO_LARGE,
 }
 
 impl IntoCInt for NotifierNotifyError {
@@ -46,6 +47,7 @@ impl IntoCInt for NotifierNotifyError {
             NotifierNotifyError::UnableToAcquireElapsedTime => {
                 iox2_notifier_notify_error_e::UNABLE_TO_ACQUIRE_ELAPSED_TIME
             }
+            NotifierNotifyError::PayloadTooLarge => iox2_notifier_notify_error_e::PAYLOAD_TOO_LARGE,
         }) as c_int
     }
 }
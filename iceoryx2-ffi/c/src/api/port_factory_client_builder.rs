@@ -34,6 +34,7 @@ pub enum iox2_client_create_error_e {
     UNABLE_TO_CREATE_DATA_SEGMENT = IOX2_OK as isize + 1,
     EXCEEDS_MAX_SUPPORTED_CLIENTS,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
+    UNABLE_TO_CREATE_NOTIFICATION_CHANNEL,
 }
 
 impl IntoCInt for ClientCreateError {
@@ -48,6 +49,9 @@ impl IntoCInt for ClientCreateError {
             ClientCreateError::FailedToDeployThreadsafetyPolicy => {
                 iox2_client_create_error_e::FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY
             }
+            ClientCreateError::UnableToCreateNotificationChannel => {
+                iox2_client_create_error_e::UNABLE_TO_CREATE_NOTIFICATION_CHANNEL
+            }
         }) as c_int
     }
 }
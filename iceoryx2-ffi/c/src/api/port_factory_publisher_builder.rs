@@ -96,6 +96,7 @@ pub enum iox2_publisher_create_error_e {
     EXCEEDS_MAX_SUPPORTED_PUBLISHERS = IOX2_OK as isize + 1,
     UNABLE_TO_CREATE_DATA_SEGMENT,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
+    UNABLE_TO_CREATE_NOTIFICATION_CHANNEL,
 }
 
 impl IntoCInt for PublisherCreateError {
@@ -110,6 +111,9 @@ impl IntoCInt for PublisherCreateError {
             PublisherCreateError::FailedToDeployThreadsafetyPolicy => {
                 iox2_publisher_create_error_e::FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY
             }
+            PublisherCreateError::UnableToCreateNotificationChannel => {
+                iox2_publisher_create_error_e::UNABLE_TO_CREATE_NOTIFICATION_CHANNEL
+            }
         }) as c_int
     }
 }
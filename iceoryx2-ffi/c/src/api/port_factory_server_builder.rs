@@ -35,6 +35,7 @@ pub enum iox2_server_create_error_e {
     EXCEEDS_MAX_SUPPORTED_SERVERS = IOX2_OK as isize + 1,
     UNABLE_TO_CREATE_DATA_SEGMENT,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
+    UNABLE_TO_CREATE_NOTIFICATION_CHANNEL,
 }
 
 impl IntoCInt for ServerCreateError {
@@ -49,6 +50,9 @@ impl IntoCInt for ServerCreateError {
             ServerCreateError::FailedToDeployThreadsafetyPolicy => {
                 iox2_server_create_error_e::FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY
             }
+            ServerCreateError::UnableToCreateNotificationChannel => {
+                iox2_server_create_error_e::UNABLE_TO_CREATE_NOTIFICATION_CHANNEL
+            }
         }) as c_int
     }
 }
@@ -35,6 +35,8 @@ pub enum iox2_subscriber_create_error_e {
     EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS = IOX2_OK as isize + 1,
     BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
+    UNABLE_TO_CREATE_NOTIFICATION_CHANNEL,
+    SAMPLE_FILTER_OUT_OF_BOUNDS,
 }
 
 impl IntoCInt for SubscriberCreateError {
@@ -49,6 +51,12 @@ impl IntoCInt for SubscriberCreateError {
             SubscriberCreateError::FailedToDeployThreadsafetyPolicy => {
                 iox2_subscriber_create_error_e::FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY
             }
+            SubscriberCreateError::UnableToCreateNotificationChannel => {
+                iox2_subscriber_create_error_e::UNABLE_TO_CREATE_NOTIFICATION_CHANNEL
+            }
+            SubscriberCreateError::SampleFilterOutOfBounds => {
+                iox2_subscriber_create_error_e::SAMPLE_FILTER_OUT_OF_BOUNDS
+            }
         }) as c_int
     }
 }
@@ -85,7 +93,7 @@ impl PortFactorySubscriberBuilderUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactorySubscriberBuilderUnion>
 pub struct iox2_port_factory_subscriber_builder_storage_t {
-    internal: [u8; 112], // magic number obtained with size_of::<Option<PortFactorySubscriberBuilderUnion>>()
+    internal: [u8; 176], // magic number obtained with size_of::<Option<PortFactorySubscriberBuilderUnion>>()
 }
 
 #[repr(C)]
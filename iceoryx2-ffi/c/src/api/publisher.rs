@@ -45,6 +45,7 @@ pub enum iox2_send_error_e {
     LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE,
     LOAN_ERROR_INTERNAL_FAILURE,
     CONNECTION_ERROR,
+    EXCEEDS_MAX_RESEND_HANDLES,
 }
 
 impl IntoCInt for SendError {
@@ -67,6 +68,7 @@ impl IntoCInt for SendError {
                 iox2_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
             }
             SendError::ConnectionError(_) => iox2_send_error_e::CONNECTION_ERROR,
+            SendError::ExceedsMaxResendHandles => iox2_send_error_e::EXCEEDS_MAX_RESEND_HANDLES,
         }) as c_int
     }
 }
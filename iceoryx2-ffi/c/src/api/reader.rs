@@ -35,12 +35,20 @@ use iceoryx2_ffi_macros::{iceoryx2_ffi, CStrRepr};
 #[derive(Copy, Clone, CStrRepr)]
 pub enum iox2_entry_handle_error_e {
     ENTRY_DOES_NOT_EXIST = IOX2_OK as isize + 1,
+    EXCEEDS_MAX_SUPPORTED_LISTENERS,
+    RESOURCE_CREATION_FAILED,
 }
 
 impl IntoCInt for EntryHandleError {
     fn into_c_int(self) -> c_int {
         (match self {
             EntryHandleError::EntryDoesNotExist => iox2_entry_handle_error_e::ENTRY_DOES_NOT_EXIST,
+            EntryHandleError::ExceedsMaxSupportedListeners => {
+                iox2_entry_handle_error_e::EXCEEDS_MAX_SUPPORTED_LISTENERS
+            }
+            EntryHandleError::ResourceCreationFailed => {
+                iox2_entry_handle_error_e::RESOURCE_CREATION_FAILED
+            }
         }) as c_int
     }
 }
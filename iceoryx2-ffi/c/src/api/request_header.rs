@@ -26,7 +26,7 @@ use crate::{
 #[repr(C)]
 #[repr(align(8))] // core::mem::align_of::<Option<RequestHeader>>()
 pub struct iox2_request_header_storage_t {
-    internal: [u8; 48], // core::mem::size_of::<Option<RequestHeader>>()
+    internal: [u8; 80], // core::mem::size_of::<Option<RequestHeader>>()
 }
 
 #[repr(C)]
@@ -170,4 +170,42 @@ pub unsafe extern "C" fn iox2_request_header_number_of_elements(
 
     header.value.as_ref().number_of_elements()
 }
+
+/// Returns the deadline of the request as duration since the epoch of the monotonic clock. If
+/// the request was sent with a deadline, the provided arguments `seconds` and `nanoseconds` are
+/// set and `true` is returned. Otherwise, `false` is returned and nothing is set.
+///
+/// # Arguments
+///
+/// * `header_handle` is valid, non-null and initialized
+/// * `seconds` - valid pointer where the seconds part of the deadline is stored
+/// * `nanoseconds` - valid pointer where the nanoseconds part of the deadline is stored
+///
+/// # Safety
+///
+/// * `header_handle` is valid and non-null
+/// * `seconds` is valid and non-null
+/// * `nanoseconds` is valid and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_request_header_deadline(
+    header_handle: iox2_request_header_h_ref,
+    seconds: *mut u64,
+    nanoseconds: *mut u32,
+) -> bool {
+    header_handle.assert_non_null();
+    debug_assert!(!seconds.is_null());
+    debug_assert!(!nanoseconds.is_null());
+
+    let header = &mut *header_handle.as_type();
+
+    header
+        .value
+        .as_ref()
+        .deadline()
+        .map(|v| {
+            *seconds = v.as_secs();
+            *nanoseconds = v.subsec_nanos();
+        })
+        .is_some()
+}
 // END C API
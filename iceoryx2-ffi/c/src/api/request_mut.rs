@@ -71,6 +71,10 @@ impl IntoCInt for RequestSendError {
             RequestSendError::SendError(SendError::ConnectionError(_)) => {
                 iox2_request_send_error_e::CONNECTION_ERROR
             }
+            // resend handles are acquired only by publishers
+            RequestSendError::SendError(SendError::ExceedsMaxResendHandles) => {
+                iox2_request_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
+            }
             RequestSendError::ExceedsMaxActiveRequests => {
                 iox2_request_send_error_e::EXCEEDS_MAX_ACTIVE_REQUESTS
             }
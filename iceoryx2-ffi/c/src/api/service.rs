@@ -46,6 +46,8 @@ pub enum iox2_messaging_pattern_e {
     EVENT,
     REQUEST_RESPONSE,
     BLACKBOARD,
+    PIPELINE,
+    LOG,
 }
 
 pub(crate) type IpcService = iceoryx2::prelude::ipc_threadsafe::Service;
@@ -58,6 +60,8 @@ impl From<iox2_messaging_pattern_e> for MessagingPattern {
             iox2_messaging_pattern_e::PUBLISH_SUBSCRIBE => MessagingPattern::PublishSubscribe,
             iox2_messaging_pattern_e::REQUEST_RESPONSE => MessagingPattern::RequestResponse,
             iox2_messaging_pattern_e::BLACKBOARD => MessagingPattern::Blackboard,
+            iox2_messaging_pattern_e::PIPELINE => MessagingPattern::Pipeline,
+            iox2_messaging_pattern_e::LOG => MessagingPattern::Log,
         }
     }
 }
@@ -79,6 +83,12 @@ impl From<&iceoryx2::service::static_config::messaging_pattern::MessagingPattern
             iceoryx2::service::static_config::messaging_pattern::MessagingPattern::Blackboard(_) => {
                 iox2_messaging_pattern_e::BLACKBOARD
             }
+            iceoryx2::service::static_config::messaging_pattern::MessagingPattern::Pipeline(_) => {
+                iox2_messaging_pattern_e::PIPELINE
+            }
+            iceoryx2::service::static_config::messaging_pattern::MessagingPattern::Log(_) => {
+                iox2_messaging_pattern_e::LOG
+            }
             _ => unreachable!()
         }
     }
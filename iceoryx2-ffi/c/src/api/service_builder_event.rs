@@ -67,6 +67,8 @@ pub enum iox2_event_open_or_create_error_e {
     O_DOES_NOT_SUPPORT_REQUESTED_MAX_EVENT_ID,
     #[CStr = "does not support requested amount of nodes"]
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
+    #[CStr = "does not support requested notification payload size"]
+    O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_PAYLOAD_SIZE,
     #[CStr = "exceeds max number of nodes"]
     O_EXCEEDS_MAX_NUMBER_OF_NODES,
     #[CStr = "is marked for destruction"]
@@ -123,6 +125,9 @@ impl IntoCInt for EventOpenError {
             EventOpenError::DoesNotSupportRequestedAmountOfNodes => {
                 iox2_event_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES
             }
+            EventOpenError::DoesNotSupportRequestedNotificationPayloadSize => {
+                iox2_event_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_NOTIFICATION_PAYLOAD_SIZE
+            }
             EventOpenError::ExceedsMaxNumberOfNodes => {
                 iox2_event_open_or_create_error_e::O_EXCEEDS_MAX_NUMBER_OF_NODES
             }
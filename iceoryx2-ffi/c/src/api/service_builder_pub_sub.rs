@@ -65,6 +65,8 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
     #[CStr = "incompatible overflow behavior"]
     O_INCOMPATIBLE_OVERFLOW_BEHAVIOR,
+    #[CStr = "incompatible notify on send behavior"]
+    O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR,
     #[CStr = "insufficient permissions"]
     O_INSUFFICIENT_PERMISSIONS,
     #[CStr = "service in corrupted state"]
@@ -132,6 +134,9 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IncompatibleOverflowBehavior => {
              iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR
          }
+         PublishSubscribeOpenError::IncompatibleNotifyOnSendBehavior => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR
+         }
          PublishSubscribeOpenError::InsufficientPermissions => {
              iox2_pub_sub_open_or_create_error_e::O_INSUFFICIENT_PERMISSIONS
          }
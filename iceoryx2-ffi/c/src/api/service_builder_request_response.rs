@@ -85,6 +85,8 @@ pub enum iox2_request_response_open_or_create_error_e {
     O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES,
     #[CStr = "incompatible behavior for fire and forget requests"]
     O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS,
+    #[CStr = "incompatible notify on send behavior"]
+    O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR,
     #[CStr = "insufficient permissions"]
     O_INSUFFICIENT_PERMISSIONS,
     #[CStr = "internal failure"]
@@ -129,6 +131,7 @@ impl IntoCInt for RequestResponseOpenError {
             RequestResponseOpenError::IncompatibleOverflowBehaviorForRequests => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_REQUESTS,
             RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES,
             RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS,
+            RequestResponseOpenError::IncompatibleNotifyOnSendBehavior => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_NOTIFY_ON_SEND_BEHAVIOR,
             RequestResponseOpenError::InsufficientPermissions => iox2_request_response_open_or_create_error_e::O_INSUFFICIENT_PERMISSIONS,
             RequestResponseOpenError::InternalFailure => iox2_request_response_open_or_create_error_e::O_INTERNAL_FAILURE,
             RequestResponseOpenError::IsMarkedForDestruction => iox2_request_response_open_or_create_error_e::O_IS_MARKED_FOR_DESTRUCTION,
@@ -20,6 +20,7 @@ use iceoryx2_bb_log::fatal_panic;
 
 use crate::{
     iox2_messaging_pattern_e, iox2_static_config_blackboard_t, iox2_static_config_event_t,
+    iox2_static_config_log_t, iox2_static_config_pipeline_t,
     iox2_static_config_publish_subscribe_t, iox2_static_config_request_response_t,
     IOX2_SERVICE_ID_LENGTH, IOX2_SERVICE_NAME_LENGTH,
 };
@@ -33,6 +34,8 @@ pub union iox2_static_config_details_t {
     pub publish_subscribe: iox2_static_config_publish_subscribe_t,
     pub request_response: iox2_static_config_request_response_t,
     pub blackboard: iox2_static_config_blackboard_t,
+    pub pipeline: iox2_static_config_pipeline_t,
+    pub log: iox2_static_config_log_t,
 }
 
 #[derive(Clone, Copy)]
@@ -94,6 +97,10 @@ impl From<&StaticConfig> for iox2_static_config_t {
                     MessagingPattern::Blackboard(blackboard) => iox2_static_config_details_t {
                         blackboard: blackboard.into(),
                     },
+                    MessagingPattern::Pipeline(pipeline) => iox2_static_config_details_t {
+                        pipeline: pipeline.into(),
+                    },
+                    MessagingPattern::Log(log) => iox2_static_config_details_t { log: log.into() },
                     _ => {
                         fatal_panic!(from "StaticConfig", "missing implementation for messaging pattern.")
                     }
@@ -0,0 +1,38 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use crate::iox2_message_type_details_t;
+use iceoryx2::service::static_config::log::StaticConfig;
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct iox2_static_config_log_t {
+    pub max_writers: usize,
+    pub max_collectors: usize,
+    pub max_nodes: usize,
+    pub collector_max_buffer_size: usize,
+    pub message_type_details: iox2_message_type_details_t,
+}
+
+impl From<&StaticConfig> for iox2_static_config_log_t {
+    fn from(c: &StaticConfig) -> Self {
+        Self {
+            max_writers: c.max_writers(),
+            max_collectors: c.max_collectors(),
+            max_nodes: c.max_nodes(),
+            collector_max_buffer_size: c.collector_max_buffer_size(),
+            message_type_details: c.message_type_details().into(),
+        }
+    }
+}
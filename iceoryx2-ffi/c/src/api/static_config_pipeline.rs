@@ -0,0 +1,40 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use crate::iox2_message_type_details_t;
+use iceoryx2::service::static_config::pipeline::StaticConfig;
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct iox2_static_config_pipeline_t {
+    pub max_producers: usize,
+    pub max_consumers: usize,
+    pub max_nodes: usize,
+    pub consumer_max_buffer_size: usize,
+    pub consumer_max_borrowed_items: usize,
+    pub message_type_details: iox2_message_type_details_t,
+}
+
+impl From<&StaticConfig> for iox2_static_config_pipeline_t {
+    fn from(c: &StaticConfig) -> Self {
+        Self {
+            max_producers: c.max_producers(),
+            max_consumers: c.max_consumers(),
+            max_nodes: c.max_nodes(),
+            consumer_max_buffer_size: c.consumer_max_buffer_size(),
+            consumer_max_borrowed_items: c.consumer_max_borrowed_items(),
+            message_type_details: c.message_type_details().into(),
+        }
+    }
+}
@@ -12,7 +12,7 @@
 
 use pyo3::prelude::*;
 
-use crate::{node_id::NodeId, unique_publisher_id::UniquePublisherId};
+use crate::{duration::Duration, node_id::NodeId, unique_publisher_id::UniquePublisherId};
 
 #[pyclass(eq)]
 #[derive(PartialEq, Eq)]
@@ -38,4 +38,18 @@ impl HeaderPublishSubscribe {
     pub fn number_of_elements(&self) -> u64 {
         self.0.number_of_elements()
     }
+
+    #[getter]
+    /// Returns the sequence number of the `Sample`. Every `Publisher` increments its sequence
+    /// number with every sent `Sample`, starting at 0.
+    pub fn sequence_number(&self) -> u64 {
+        self.0.sequence_number()
+    }
+
+    #[getter]
+    /// Returns the point in time when the `Sample` was sent as `Duration` since the epoch of
+    /// the monotonic clock.
+    pub fn send_timestamp(&self) -> Duration {
+        Duration(self.0.send_timestamp())
+    }
 }
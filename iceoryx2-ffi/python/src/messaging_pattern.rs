@@ -19,6 +19,8 @@ pub enum MessagingPattern {
     Event,
     RequestResponse,
     Blackboard,
+    Pipeline,
+    Log,
 }
 
 #[pymethods]
@@ -39,6 +41,8 @@ impl From<iceoryx2::prelude::MessagingPattern> for MessagingPattern {
                 MessagingPattern::PublishSubscribe
             }
             iceoryx2::prelude::MessagingPattern::Blackboard => MessagingPattern::Blackboard,
+            iceoryx2::prelude::MessagingPattern::Pipeline => MessagingPattern::Pipeline,
+            iceoryx2::prelude::MessagingPattern::Log => MessagingPattern::Log,
         }
     }
 }
@@ -54,6 +58,8 @@ impl From<MessagingPattern> for iceoryx2::prelude::MessagingPattern {
                 iceoryx2::prelude::MessagingPattern::PublishSubscribe
             }
             MessagingPattern::Blackboard => iceoryx2::prelude::MessagingPattern::Blackboard,
+            MessagingPattern::Pipeline => iceoryx2::prelude::MessagingPattern::Pipeline,
+            MessagingPattern::Log => iceoryx2::prelude::MessagingPattern::Log,
         }
     }
 }
@@ -136,6 +136,12 @@ fn static_config_messaging_pattern_to_python(
         iceoryx2::service::static_config::messaging_pattern::MessagingPattern::Blackboard(_) => {
             MessagingPattern::Blackboard
         }
+        iceoryx2::service::static_config::messaging_pattern::MessagingPattern::Pipeline(_) => {
+            MessagingPattern::Pipeline
+        }
+        iceoryx2::service::static_config::messaging_pattern::MessagingPattern::Log(_) => {
+            MessagingPattern::Log
+        }
         _ => {
             fatal_panic!(from "ServiceDetails::messaging_pattern()", "Unknown messaging pattern in translation." )
         }
@@ -26,6 +26,14 @@
 #include <mach-o/dyld.h>
 #endif
 
+#ifdef __linux__
+#include <linux/futex.h>
+#include <poll.h>
+#include <sys/epoll.h>
+#include <sys/eventfd.h>
+#include <sys/syscall.h>
+#endif
+
 #ifdef __QNXNTO__
 #include <sys/neutrino.h>
 #include <sys/syspage.h> // needed for affinity setting
@@ -0,0 +1,48 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types, non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+
+use crate::posix::types::*;
+
+pub type epoll_event = libc::epoll_event;
+
+pub const EPOLLIN: u32 = libc::EPOLLIN as _;
+pub const EPOLL_CTL_ADD: int = libc::EPOLL_CTL_ADD as _;
+pub const EPOLL_CTL_DEL: int = libc::EPOLL_CTL_DEL as _;
+pub const EPOLL_CTL_MOD: int = libc::EPOLL_CTL_MOD as _;
+pub const EPOLL_CLOEXEC: int = libc::EPOLL_CLOEXEC as _;
+
+pub fn epoll_event_new(events: u32, data: u64) -> epoll_event {
+    epoll_event { events, u64: data }
+}
+
+pub fn epoll_event_events(event: &epoll_event) -> u32 {
+    event.events
+}
+
+pub fn epoll_event_data(event: &epoll_event) -> u64 {
+    event.u64
+}
+
+pub unsafe fn epoll_create1(flags: int) -> int {
+    libc::epoll_create1(flags)
+}
+
+pub unsafe fn epoll_ctl(epfd: int, op: int, fd: int, event: *mut epoll_event) -> int {
+    libc::epoll_ctl(epfd, op, fd, event)
+}
+
+pub unsafe fn epoll_wait(epfd: int, events: *mut epoll_event, maxevents: int, timeout: int) -> int {
+    libc::epoll_wait(epfd, events, maxevents, timeout)
+}
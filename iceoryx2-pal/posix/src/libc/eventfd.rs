@@ -0,0 +1,24 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types, non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+
+use crate::posix::types::*;
+
+pub const EFD_CLOEXEC: int = libc::EFD_CLOEXEC as _;
+pub const EFD_NONBLOCK: int = libc::EFD_NONBLOCK as _;
+pub const EFD_SEMAPHORE: int = libc::EFD_SEMAPHORE as _;
+
+pub unsafe fn eventfd(initval: uint, flags: int) -> int {
+    libc::eventfd(initval, flags)
+}
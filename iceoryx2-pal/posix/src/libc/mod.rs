@@ -12,9 +12,17 @@
 
 pub mod constants;
 pub mod dirent;
+#[cfg(target_os = "linux")]
+pub mod epoll;
 pub mod errno;
+#[cfg(target_os = "linux")]
+pub mod eventfd;
 pub mod fcntl;
+#[cfg(target_os = "linux")]
+pub mod futex;
 pub mod mman;
+#[cfg(target_os = "linux")]
+pub mod poll;
 pub mod pthread;
 pub mod pwd;
 pub mod resource;
@@ -34,9 +42,17 @@ pub mod unistd;
 
 pub use constants::*;
 pub use dirent::*;
+#[cfg(target_os = "linux")]
+pub use epoll::*;
 pub use errno::*;
+#[cfg(target_os = "linux")]
+pub use eventfd::*;
 pub use fcntl::*;
+#[cfg(target_os = "linux")]
+pub use futex::*;
 pub use mman::*;
+#[cfg(target_os = "linux")]
+pub use poll::*;
 pub use pthread::*;
 pub use pwd::*;
 pub use resource::*;
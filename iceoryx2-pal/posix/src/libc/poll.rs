@@ -0,0 +1,36 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types, non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+
+use crate::posix::types::*;
+
+pub type pollfd = libc::pollfd;
+
+pub const POLLIN: short = libc::POLLIN as _;
+
+pub fn pollfd_new(fd: int, events: short) -> pollfd {
+    pollfd {
+        fd,
+        events,
+        revents: 0,
+    }
+}
+
+pub fn pollfd_revents(fd: &pollfd) -> short {
+    fd.revents
+}
+
+pub unsafe fn poll(fds: *mut pollfd, nfds: usize, timeout: int) -> int {
+    libc::poll(fds, nfds as _, timeout)
+}
@@ -0,0 +1,51 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types, non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+
+use crate::posix::types::*;
+
+pub type epoll_event = crate::internal::epoll_event;
+
+pub const EPOLLIN: u32 = crate::internal::EPOLL_EVENTS_EPOLLIN as _;
+pub const EPOLL_CTL_ADD: int = crate::internal::EPOLL_CTL_ADD as _;
+pub const EPOLL_CTL_DEL: int = crate::internal::EPOLL_CTL_DEL as _;
+pub const EPOLL_CTL_MOD: int = crate::internal::EPOLL_CTL_MOD as _;
+pub const EPOLL_CLOEXEC: int = crate::internal::EPOLL_CLOEXEC as _;
+
+pub fn epoll_event_new(events: u32, data: u64) -> epoll_event {
+    epoll_event {
+        events,
+        data: crate::internal::epoll_data_t { u64_: data },
+    }
+}
+
+pub fn epoll_event_events(event: &epoll_event) -> u32 {
+    event.events
+}
+
+pub fn epoll_event_data(event: &epoll_event) -> u64 {
+    unsafe { event.data.u64_ }
+}
+
+pub unsafe fn epoll_create1(flags: int) -> int {
+    crate::internal::epoll_create1(flags)
+}
+
+pub unsafe fn epoll_ctl(epfd: int, op: int, fd: int, event: *mut epoll_event) -> int {
+    crate::internal::epoll_ctl(epfd, op, fd, event)
+}
+
+pub unsafe fn epoll_wait(epfd: int, events: *mut epoll_event, maxevents: int, timeout: int) -> int {
+    crate::internal::epoll_wait(epfd, events, maxevents, timeout)
+}
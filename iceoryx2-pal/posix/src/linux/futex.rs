@@ -0,0 +1,42 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types, non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+
+use crate::posix::types::*;
+
+pub const FUTEX_WAIT: int = crate::internal::FUTEX_WAIT as _;
+pub const FUTEX_WAKE: int = crate::internal::FUTEX_WAKE as _;
+
+/// Blocks as long as the value at `uaddr` is equal to `expected` or until the relative
+/// `timeout` has passed. When `timeout` is null it blocks until it is woken up. The futex
+/// can be shared between processes.
+pub unsafe fn futex_wait(uaddr: *const u32, expected: u32, timeout: *const timespec) -> int {
+    crate::internal::syscall(
+        crate::internal::SYS_futex as _,
+        uaddr,
+        FUTEX_WAIT,
+        expected,
+        timeout,
+    ) as _
+}
+
+/// Wakes up at most `number_of_waiters` that are blocked in [`futex_wait()`] on `uaddr`.
+pub unsafe fn futex_wake(uaddr: *const u32, number_of_waiters: u32) -> int {
+    crate::internal::syscall(
+        crate::internal::SYS_futex as _,
+        uaddr,
+        FUTEX_WAKE,
+        number_of_waiters,
+    ) as _
+}
@@ -0,0 +1,200 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::service::service_name::ServiceName;
+use iceoryx2::service::static_config::StaticConfig as ServiceConfig;
+
+use core::str::FromStr;
+
+/// Matches `text` against a glob `pattern` where `*` matches any sequence of characters,
+/// including none, and `?` matches exactly one character.
+pub fn matches_glob(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` in the pattern and the text position it was matched against.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            // Let the last `*` consume one more character.
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, t));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// A rule that selects services by glob patterns. A service matches the rule when it matches
+/// all of the provided criteria; criteria that are not provided match every service.
+///
+/// A rule can be parsed from a comma-separated list of criteria, e.g.
+/// `name=camera/*,pattern=PublishSubscribe,attribute:origin=robot?`.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct ServiceRule {
+    /// Glob pattern for the service name.
+    pub name: Option<String>,
+    /// Glob pattern for the messaging pattern, e.g. `PublishSubscribe` or `Event`.
+    pub messaging_pattern: Option<String>,
+    /// Attribute keys together with a glob pattern one of their values must match.
+    pub attributes: Vec<(String, String)>,
+}
+
+impl ServiceRule {
+    /// Returns whether the service with the provided configuration matches the rule.
+    pub fn matches(&self, service_config: &ServiceConfig) -> bool {
+        if let Some(name) = &self.name {
+            if !matches_glob(name, service_config.name().as_str()) {
+                return false;
+            }
+        }
+
+        if let Some(messaging_pattern) = &self.messaging_pattern {
+            let actual = service_config.messaging_pattern().to_string();
+            if !matches_glob(messaging_pattern, &actual) {
+                return false;
+            }
+        }
+
+        self.attributes.iter().all(|(key, value)| {
+            service_config.attributes().iter().any(|attribute| {
+                attribute.key().to_string() == *key
+                    && matches_glob(value, &attribute.value().to_string())
+            })
+        })
+    }
+}
+
+impl FromStr for ServiceRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rule = ServiceRule::default();
+
+        for criterion in s.split(',').filter(|c| !c.is_empty()) {
+            let (field, value) = criterion
+                .split_once('=')
+                .ok_or_else(|| format!("criterion '{criterion}' is not of the form FIELD=GLOB"))?;
+
+            match field {
+                "name" => rule.name = Some(value.to_string()),
+                "pattern" => rule.messaging_pattern = Some(value.to_string()),
+                _ => match field.strip_prefix("attribute:") {
+                    Some(key) if !key.is_empty() => {
+                        rule.attributes.push((key.to_string(), value.to_string()))
+                    }
+                    _ => {
+                        return Err(format!(
+                            "unknown field '{field}', expected 'name', 'pattern' or 'attribute:KEY'"
+                        ))
+                    }
+                },
+            }
+        }
+
+        Ok(rule)
+    }
+}
+
+/// Decides which services are propagated by the tunnel.
+///
+/// Without any include rules all services are included. A service that matches any exclude rule
+/// is never propagated, even when it also matches an include rule.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct ServiceFilter {
+    /// Rules selecting the services to propagate.
+    pub include: Vec<ServiceRule>,
+    /// Rules selecting the services to never propagate.
+    pub exclude: Vec<ServiceRule>,
+}
+
+impl ServiceFilter {
+    /// Returns whether the service with the provided configuration may be propagated.
+    pub fn is_allowed(&self, service_config: &ServiceConfig) -> bool {
+        let is_included =
+            self.include.is_empty() || self.include.iter().any(|rule| rule.matches(service_config));
+        let is_excluded = self.exclude.iter().any(|rule| rule.matches(service_config));
+
+        is_included && !is_excluded
+    }
+}
+
+/// Replaces the prefix `from` of the names of local services with `to` when they are announced
+/// to remote hosts.
+///
+/// An empty `from` prefixes all service names with `to`, e.g. `camera/front` is announced as
+/// `robotA/camera/front` with `to` set to `robotA/`. Can be parsed from `FROM=TO`.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct ServiceRemapping {
+    /// The prefix of the local service name that is replaced.
+    pub from: String,
+    /// The prefix the remote hosts see instead.
+    pub to: String,
+}
+
+impl ServiceRemapping {
+    /// Returns the remapped name if the name starts with the prefix of the remapping.
+    pub fn apply(&self, name: &str) -> Option<String> {
+        name.strip_prefix(self.from.as_str())
+            .map(|remainder| format!("{}{}", self.to, remainder))
+    }
+
+    /// Returns the configuration under which a local service is announced to remote hosts.
+    /// The first matching remapping is applied and the service id is derived from the remapped
+    /// name, so that equally named services of different hosts do not clash. Returns `None` when
+    /// the remapped name is not a valid service name.
+    pub fn remap<ServiceType: iceoryx2::service::Service>(
+        remappings: &[ServiceRemapping],
+        service_config: &ServiceConfig,
+    ) -> Option<ServiceConfig> {
+        let name = service_config.name().as_str();
+        match remappings
+            .iter()
+            .find_map(|remapping| remapping.apply(name))
+        {
+            Some(remapped_name) => {
+                let remapped_name = ServiceName::new(&remapped_name).ok()?;
+                Some(
+                    service_config
+                        .__internal_with_name::<ServiceType::ServiceNameHasher>(&remapped_name),
+                )
+            }
+            None => Some(service_config.clone()),
+        }
+    }
+}
+
+impl FromStr for ServiceRemapping {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (from, to) = s
+            .split_once('=')
+            .ok_or_else(|| format!("remapping '{s}' is not of the form FROM=TO"))?;
+
+        Ok(ServiceRemapping {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+    }
+}
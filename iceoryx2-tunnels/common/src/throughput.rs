@@ -0,0 +1,180 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::ServiceRule;
+
+use iceoryx2::service::static_config::StaticConfig as ServiceConfig;
+
+use core::str::FromStr;
+use core::time::Duration;
+use std::time::Instant;
+
+/// The window in which the sample rate and the byte budget are accounted.
+const WINDOW: Duration = Duration::from_secs(1);
+
+/// Limits the throughput of the payloads a publisher channel propagates to remote hosts. Limits
+/// that are not provided are not enforced.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct ThroughputLimit {
+    /// The maximum number of samples propagated per second. Excess samples are dropped.
+    pub max_rate: Option<u64>,
+    /// Only the latest sample is kept and propagated at most once per period. All samples it
+    /// replaces are dropped.
+    pub downsample_period: Option<Duration>,
+    /// The maximum number of payload bytes propagated per second. Samples exceeding the
+    /// remaining budget are dropped.
+    pub byte_budget: Option<u64>,
+}
+
+impl ThroughputLimit {
+    /// Returns whether any limit is enforced.
+    pub fn is_limited(&self) -> bool {
+        self.max_rate.is_some() || self.downsample_period.is_some() || self.byte_budget.is_some()
+    }
+}
+
+/// Applies a [`ThroughputLimit`] to all services matching a [`ServiceRule`].
+///
+/// Can be parsed from a comma-separated list of the criteria of a [`ServiceRule`] and the
+/// limits `max_rate=SAMPLES`, `downsample=MILLISECONDS` and `byte_budget=BYTES`, e.g.
+/// `name=imu/*,max_rate=100`.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct ThroughputRule {
+    /// Selects the services the limit is applied to.
+    pub rule: ServiceRule,
+    /// The limit applied to the matching services.
+    pub limit: ThroughputLimit,
+}
+
+impl ThroughputRule {
+    /// Returns the limit of the first rule matching the service with the provided
+    /// configuration. Services without a matching rule are not limited.
+    pub fn limit_for(rules: &[ThroughputRule], service_config: &ServiceConfig) -> ThroughputLimit {
+        rules
+            .iter()
+            .find(|rule| rule.rule.matches(service_config))
+            .map(|rule| rule.limit)
+            .unwrap_or_default()
+    }
+}
+
+impl FromStr for ThroughputRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut limit = ThroughputLimit::default();
+        let mut criteria = Vec::new();
+
+        for criterion in s.split(',').filter(|c| !c.is_empty()) {
+            let parse = |value: &str| {
+                value
+                    .parse::<u64>()
+                    .map_err(|_| format!("limit '{criterion}' requires an unsigned integer value"))
+            };
+
+            match criterion.split_once('=') {
+                Some(("max_rate", value)) => limit.max_rate = Some(parse(value)?),
+                Some(("downsample", value)) => {
+                    limit.downsample_period = Some(Duration::from_millis(parse(value)?))
+                }
+                Some(("byte_budget", value)) => limit.byte_budget = Some(parse(value)?),
+                _ => criteria.push(criterion),
+            }
+        }
+
+        if !limit.is_limited() {
+            return Err(format!(
+                "rule '{s}' defines no limit, expected 'max_rate', 'downsample' or 'byte_budget'"
+            ));
+        }
+
+        Ok(ThroughputRule {
+            rule: criteria.join(",").parse()?,
+            limit,
+        })
+    }
+}
+
+/// Decides which samples are propagated to comply with a [`ThroughputLimit`]. The sample rate
+/// and the byte budget are accounted in consecutive windows of one second.
+#[derive(Debug, Default)]
+pub struct Throttle {
+    limit: ThroughputLimit,
+    window_start: Option<Instant>,
+    window_samples: u64,
+    window_bytes: u64,
+    last_admitted: Option<Instant>,
+}
+
+impl Throttle {
+    /// Creates a throttle enforcing the provided limit.
+    pub fn new(limit: ThroughputLimit) -> Self {
+        Self {
+            limit,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the enforced limit.
+    pub fn limit(&self) -> &ThroughputLimit {
+        &self.limit
+    }
+
+    /// Returns whether a sample with a payload of `size` bytes may be propagated at `now`.
+    /// Admitted samples are accounted against the limits.
+    pub fn admit(&mut self, now: Instant, size: usize) -> bool {
+        if let (Some(period), Some(last_admitted)) =
+            (self.limit.downsample_period, self.last_admitted)
+        {
+            if now.saturating_duration_since(last_admitted) < period {
+                return false;
+            }
+        }
+
+        let window_start = *self.window_start.get_or_insert(now);
+        if now.saturating_duration_since(window_start) >= WINDOW {
+            self.window_start = Some(now);
+            self.window_samples = 0;
+            self.window_bytes = 0;
+        }
+
+        if let Some(max_rate) = self.limit.max_rate {
+            if self.window_samples >= max_rate {
+                return false;
+            }
+        }
+
+        let size = size as u64;
+        if let Some(byte_budget) = self.limit.byte_budget {
+            if self.window_bytes + size > byte_budget {
+                return false;
+            }
+        }
+
+        self.window_samples += 1;
+        self.window_bytes += size;
+        self.last_admitted = Some(now);
+
+        true
+    }
+}
+
+/// Counters of the samples a publisher channel handled.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct PublisherStatistics {
+    /// The number of samples propagated to remote hosts.
+    pub propagated_samples: u64,
+    /// The number of payload bytes propagated to remote hosts.
+    pub propagated_bytes: u64,
+    /// The number of samples dropped due to the throughput limit of the service.
+    pub dropped_samples: u64,
+}
@@ -0,0 +1,369 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::middleware;
+use crate::transport::Transport;
+use crate::wire::Message;
+use crate::Channel;
+use crate::PropagationError;
+
+use iceoryx2::active_request::ActiveRequest as IceoryxActiveRequest;
+use iceoryx2::port::port_identifiers::UniqueClientId as IceoryxClientId;
+use iceoryx2::port::server::Server as IceoryxServer;
+use iceoryx2::service::builder::CustomHeaderMarker;
+use iceoryx2::service::builder::CustomPayloadMarker;
+use iceoryx2::service::port_factory::request_response::PortFactory as IceoryxRequestResponseService;
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::debug;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::info;
+use iceoryx2_bb_log::warn;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+
+use core::cell::Cell;
+use core::cell::RefCell;
+use core::time::Duration;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// A local request that was forwarded to the peers and whose responses are still awaited.
+#[derive(Debug)]
+struct ForwardedRequest<ServiceType: iceoryx2::service::Service> {
+    token: String,
+    iox_active_request: IceoryxActiveRequest<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    // Monotonic time after which the peers cannot deliver responses the client still expects.
+    expiry: Duration,
+    // Number of peers that did not yet finalize the request.
+    number_of_pending_peers: usize,
+    received_responses: Vec<Vec<u8>>,
+    has_forwarded_disconnect_hint: bool,
+}
+
+/// A channel for propagating `iceoryx2` requests of local clients to the peers and their
+/// responses back to the local clients.
+#[derive(Debug)]
+pub(crate) struct ClientChannel<ServiceType: iceoryx2::service::Service> {
+    iox_service_config: IceoryxServiceConfig,
+    iox_server: IceoryxServer<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    iox_tunnel_client_id: IceoryxClientId,
+    request_timeout: Duration,
+    forwarded_requests: RefCell<Vec<ForwardedRequest<ServiceType>>>,
+    request_counter: Cell<u64>,
+}
+
+impl<ServiceType: iceoryx2::service::Service> ClientChannel<ServiceType> {
+    // Creates an outbound channel to the peers for requests for a particular service.
+    //
+    // Requests sent by the client with the id `iox_tunnel_client_id` are ignored since they
+    // originate from the peers. Requests without a deadline are dropped after `request_timeout`
+    // when not all peers finalized them.
+    pub fn create(
+        iox_service_config: &IceoryxServiceConfig,
+        iox_service: &IceoryxRequestResponseService<
+            ServiceType,
+            [CustomPayloadMarker],
+            CustomHeaderMarker,
+            [CustomPayloadMarker],
+            CustomHeaderMarker,
+        >,
+        iox_tunnel_client_id: &IceoryxClientId,
+        request_timeout: Duration,
+    ) -> Result<Self, CreationError> {
+        info!(
+            "CREATE ClientChannel {} [{}]",
+            iox_service_config.service_id().as_str(),
+            iox_service_config.name()
+        );
+
+        let iox_server = fail!(
+            from "ClientChannel::create()",
+            when middleware::iceoryx::create_server::<ServiceType>(iox_service),
+            with CreationError::Error,
+            "failed to create iceoryx server to propagate local requests"
+        );
+
+        Ok(Self {
+            iox_service_config: iox_service_config.clone(),
+            iox_server,
+            iox_tunnel_client_id: *iox_tunnel_client_id,
+            request_timeout,
+            forwarded_requests: RefCell::new(Vec::new()),
+            request_counter: Cell::new(0),
+        })
+    }
+
+    // Stores a response received from a peer until the next propagation.
+    pub fn receive_response(&self, token: &str, payload: Vec<u8>) {
+        match self
+            .forwarded_requests
+            .borrow_mut()
+            .iter_mut()
+            .find(|request| request.token == token)
+        {
+            Some(request) => request.received_responses.push(payload),
+            // Responses are sent to all peers, hence most of them belong to other tunnels.
+            None => debug!(from self, "skipping response to unknown request {}", token),
+        }
+    }
+
+    // Records that a peer will not deliver further responses to the request.
+    pub fn receive_finalize(&self, token: &str) {
+        if let Some(request) = self
+            .forwarded_requests
+            .borrow_mut()
+            .iter_mut()
+            .find(|request| request.token == token)
+        {
+            request.number_of_pending_peers = request.number_of_pending_peers.saturating_sub(1);
+        }
+    }
+
+    fn now() -> Duration {
+        Time::now_with_clock(ClockType::Monotonic)
+            .map(|now| now.as_duration())
+            .unwrap_or_default()
+    }
+
+    fn forward_requests(&self, transport: &dyn Transport) -> Result<(), PropagationError> {
+        loop {
+            match unsafe { self.iox_server.receive_custom_payload() } {
+                Ok(Some(iox_active_request)) => {
+                    if iox_active_request.origin() == self.iox_tunnel_client_id {
+                        // Ignore requests sent by the tunnel itself to prevent loopback.
+                        continue;
+                    }
+
+                    let token = format!(
+                        "{}.{}",
+                        self.iox_server.id().value(),
+                        self.request_counter.get()
+                    );
+                    self.request_counter.set(self.request_counter.get() + 1);
+
+                    let ptr = iox_active_request.payload().as_ptr() as *const u8;
+                    let len = iox_active_request.payload().len();
+                    let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+
+                    let message = Message::Request {
+                        service_id: self.iox_service_config.service_id().as_str().to_string(),
+                        token: token.clone(),
+                        payload: bytes.to_vec(),
+                    };
+                    let frame = fail!(
+                        from self,
+                        when message.encode(),
+                        with PropagationError::OtherPort,
+                        "failed to encode local request for the peers"
+                    );
+                    fail!(
+                        from self,
+                        when transport.send(&frame),
+                        with PropagationError::OtherPort,
+                        "failed to propagate local request to the peers"
+                    );
+
+                    let expiry = iox_active_request
+                        .header()
+                        .deadline()
+                        .unwrap_or_else(|| Self::now() + self.request_timeout);
+                    self.forwarded_requests.borrow_mut().push(ForwardedRequest {
+                        token,
+                        iox_active_request,
+                        expiry,
+                        number_of_pending_peers: transport.number_of_peers(),
+                        received_responses: Vec::new(),
+                        has_forwarded_disconnect_hint: false,
+                    });
+
+                    info!(
+                        "PROPAGATE ClientChannel {} [{}]",
+                        self.iox_service_config.service_id().as_str(),
+                        self.iox_service_config.name()
+                    );
+                }
+                Ok(None) => break, // No more requests available
+                Err(e) => {
+                    fatal_panic!("failed to receive custom payload: {e}");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn send_connection_update(
+        &self,
+        transport: &dyn Transport,
+        message: Message,
+    ) -> Result<(), PropagationError> {
+        let frame = fail!(
+            from self,
+            when message.encode(),
+            with PropagationError::OtherPort,
+            "failed to encode connection update of local request for the peers"
+        );
+        fail!(
+            from self,
+            when transport.send(&frame),
+            with PropagationError::OtherPort,
+            "failed to propagate connection update of local request to the peers"
+        );
+
+        Ok(())
+    }
+
+    // Forwards all responses received so far for the request to the local client.
+    fn forward_responses(
+        &self,
+        request: &mut ForwardedRequest<ServiceType>,
+    ) -> Result<(), PropagationError> {
+        let iox_payload_size = self
+            .iox_service_config
+            .request_response()
+            .response_message_type_details()
+            .payload
+            .size();
+
+        for payload in core::mem::take(&mut request.received_responses) {
+            if iox_payload_size == 0 || payload.len() % iox_payload_size != 0 {
+                warn!(
+                    from self,
+                    "skipping response of {} bytes from peer since it does not match the payload type",
+                    payload.len()
+                );
+                continue;
+            }
+
+            let number_of_elements = payload.len() / iox_payload_size;
+            unsafe {
+                match request
+                    .iox_active_request
+                    .loan_custom_payload(number_of_elements)
+                {
+                    Ok(mut iox_response) => {
+                        core::ptr::copy_nonoverlapping(
+                            payload.as_ptr(),
+                            iox_response.payload_mut().as_mut_ptr() as *mut u8,
+                            payload.len(),
+                        );
+                        let iox_response = iox_response.assume_init();
+                        fail!(
+                            from self,
+                            when iox_response.send(),
+                            with PropagationError::IceoryxPort,
+                            "failed to send remote response to local client"
+                        );
+
+                        info!(
+                            "PROPAGATE ClientChannel {} [{}]",
+                            self.iox_service_config.service_id().as_str(),
+                            self.iox_service_config.name()
+                        );
+                    }
+                    Err(e) => {
+                        fatal_panic!("failed to loan custom payload: {e}");
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Channel for ClientChannel<ServiceType> {
+    /// Propagate local requests to the peers and the responses of the peers back to local
+    /// clients.
+    fn propagate(&self, transport: &dyn Transport) -> Result<(), PropagationError> {
+        self.forward_requests(transport)?;
+
+        let service_id = self.iox_service_config.service_id().as_str();
+        let now = Self::now();
+        let mut result = Ok(());
+        let mut forwarded_requests = self.forwarded_requests.borrow_mut();
+        forwarded_requests.retain_mut(|request| {
+            let disconnect = Message::Disconnect {
+                service_id: service_id.to_string(),
+                token: request.token.clone(),
+            };
+
+            if !request.iox_active_request.is_connected() {
+                // Release the request on the peers, the local client is gone.
+                if let Err(e) = self.send_connection_update(transport, disconnect) {
+                    result = Err(e);
+                }
+                return false;
+            }
+
+            if request.iox_active_request.has_disconnect_hint()
+                && !request.has_forwarded_disconnect_hint
+            {
+                let disconnect_hint = Message::DisconnectHint {
+                    service_id: service_id.to_string(),
+                    token: request.token.clone(),
+                };
+                match self.send_connection_update(transport, disconnect_hint) {
+                    Ok(()) => request.has_forwarded_disconnect_hint = true,
+                    Err(e) => result = Err(e),
+                }
+            }
+
+            if let Err(e) = self.forward_responses(request) {
+                result = Err(e);
+                return true;
+            }
+
+            if request.number_of_pending_peers == 0 {
+                // Dropping the active request signals the local client that no more
+                // responses will arrive.
+                return false;
+            }
+
+            if now >= request.expiry {
+                // Peers that are not running or finalizations that got lost would keep the
+                // request alive forever, release it on the peers that are still serving it.
+                if let Err(e) = self.send_connection_update(transport, disconnect) {
+                    result = Err(e);
+                }
+                return false;
+            }
+
+            true
+        });
+
+        result
+    }
+}
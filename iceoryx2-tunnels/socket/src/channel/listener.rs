@@ -0,0 +1,97 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::middleware;
+use crate::transport::Transport;
+use crate::Channel;
+use crate::PropagationError;
+
+use iceoryx2::port::notifier::Notifier as IceoryxNotifier;
+use iceoryx2::prelude::EventId;
+use iceoryx2::service::port_factory::event::PortFactory as IceoryxEventService;
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::info;
+
+use core::cell::RefCell;
+use std::collections::HashSet;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+#[derive(Debug)]
+pub(crate) struct ListenerChannel<ServiceType: iceoryx2::service::Service> {
+    iox_service_config: IceoryxServiceConfig,
+    iox_notifier: IceoryxNotifier<ServiceType>,
+    received_ids: RefCell<HashSet<usize>>,
+}
+
+impl<ServiceType: iceoryx2::service::Service> ListenerChannel<ServiceType> {
+    // Creates an inbound channel for notifications from the peers for a
+    // particular service.
+    pub fn create(
+        iox_service_config: &IceoryxServiceConfig,
+        iox_service: &IceoryxEventService<ServiceType>,
+    ) -> Result<Self, CreationError> {
+        info!(
+            "CREATE ListenerChannel {} [{}]",
+            iox_service_config.service_id().as_str(),
+            iox_service_config.name()
+        );
+
+        let iox_notifier = fail!(
+            from "ListenerChannel::create()",
+            when middleware::iceoryx::create_notifier(iox_service),
+            with CreationError::Error,
+            "failed to create iceoryx notifier to propagate remote notifications to local listeners"
+        );
+
+        Ok(Self {
+            iox_service_config: iox_service_config.clone(),
+            iox_notifier,
+            received_ids: RefCell::new(HashSet::new()),
+        })
+    }
+
+    // Stores a notification received from a peer until the next propagation.
+    pub fn receive(&self, event_id: usize) {
+        self.received_ids.borrow_mut().insert(event_id);
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Channel for ListenerChannel<ServiceType> {
+    /// Propagate notifications received from the peers for a particular service to local
+    /// listeners.
+    fn propagate(&self, _transport: &dyn Transport) -> Result<(), PropagationError> {
+        // Propagate notifications received - once per event id
+        let received_ids = self.received_ids.take();
+        for event_id in received_ids {
+            fail!(
+                from &self,
+                when self.iox_notifier.__internal_notify(EventId::new(event_id), true),
+                with PropagationError::IceoryxPort,
+                "failed to propagate remote notification to local listeners"
+            );
+
+            info!(
+                "PROPAGATE ListenerChannel(EventId={}) {} [{}]",
+                event_id,
+                self.iox_service_config.service_id().as_str(),
+                self.iox_service_config.name()
+            );
+        }
+
+        Ok(())
+    }
+}
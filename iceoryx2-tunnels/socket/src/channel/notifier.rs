@@ -0,0 +1,105 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::middleware;
+use crate::transport::Transport;
+use crate::wire::Message;
+use crate::Channel;
+use crate::PropagationError;
+
+use iceoryx2::port::listener::Listener as IceoryxListener;
+use iceoryx2::service::port_factory::event::PortFactory as IceoryxEventService;
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::info;
+
+use std::collections::HashSet;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+#[derive(Debug)]
+pub(crate) struct NotifierChannel<ServiceType: iceoryx2::service::Service> {
+    iox_service_config: IceoryxServiceConfig,
+    iox_listener: IceoryxListener<ServiceType>,
+}
+
+impl<ServiceType: iceoryx2::service::Service> NotifierChannel<ServiceType> {
+    // Creates an outbound channel for local notifications on a particular service
+    // to the peers.
+    pub fn create(
+        iox_service_config: &IceoryxServiceConfig,
+        iox_service: &IceoryxEventService<ServiceType>,
+    ) -> Result<Self, CreationError> {
+        info!(
+            "CREATE NotifierChannel {} [{}]",
+            iox_service_config.service_id().as_str(),
+            iox_service_config.name()
+        );
+
+        let iox_listener = fail!(
+            from "NotifierChannel::create()",
+            when middleware::iceoryx::create_listener(iox_service),
+            with CreationError::Error,
+            "failed to create iceoryx listener for local notifications"
+        );
+
+        Ok(Self {
+            iox_service_config: iox_service_config.clone(),
+            iox_listener,
+        })
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Channel for NotifierChannel<ServiceType> {
+    /// Propagate local notifications received on the service to the peers.
+    fn propagate(&self, transport: &dyn Transport) -> Result<(), PropagationError> {
+        // Propagate all notified ids once
+        let mut notified_ids: HashSet<usize> = HashSet::new();
+        while let Ok(sample) = self.iox_listener.try_wait_one() {
+            match sample {
+                Some(event_id) => {
+                    if !notified_ids.contains(&event_id.as_value()) {
+                        let message = Message::Notify {
+                            service_id: self.iox_service_config.service_id().as_str().to_string(),
+                            event_id: event_id.as_value() as u64,
+                        };
+                        let frame = fail!(
+                            from &self,
+                            when message.encode(),
+                            with PropagationError::OtherPort,
+                            "failed to encode local notification for the peers"
+                        );
+                        fail!(
+                            from &self,
+                            when transport.send(&frame),
+                            with PropagationError::OtherPort,
+                            "failed to propagate local notification to the peers"
+                        );
+                        info!(
+                            "PROPAGATE NotifierChannel(EventId={}) {} [{}]",
+                            event_id.as_value(),
+                            self.iox_service_config.service_id().as_str(),
+                            self.iox_service_config.name()
+                        );
+                        notified_ids.insert(event_id.as_value());
+                    }
+                }
+                None => break,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::middleware;
+use crate::transport::Transport;
+use crate::wire::Message;
+use crate::Channel;
+use crate::PropagationError;
+use crate::PublisherStatistics;
+use crate::Throttle;
+use crate::ThroughputLimit;
+
+use iceoryx2::node::NodeId as IceoryxNodeId;
+use iceoryx2::port::subscriber::Subscriber as IceoryxSubscriber;
+use iceoryx2::service::builder::CustomHeaderMarker;
+use iceoryx2::service::builder::CustomPayloadMarker;
+use iceoryx2::service::port_factory::publish_subscribe::PortFactory as IceoryxPublishSubscribeService;
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::info;
+
+use core::cell::Cell;
+use core::cell::RefCell;
+use std::time::Instant;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+#[derive(Debug)]
+pub(crate) struct PublisherChannel<ServiceType: iceoryx2::service::Service> {
+    iox_node_id: IceoryxNodeId,
+    iox_service_config: IceoryxServiceConfig,
+    iox_subscriber: IceoryxSubscriber<ServiceType, [CustomPayloadMarker], CustomHeaderMarker>,
+    throttle: RefCell<Throttle>,
+    // The latest sample held back until the downsampling period elapsed.
+    pending_payload: RefCell<Option<Vec<u8>>>,
+    statistics: Cell<PublisherStatistics>,
+}
+
+impl<ServiceType: iceoryx2::service::Service> PublisherChannel<ServiceType> {
+    // Creates an outbound channel to the peers for publish-subscribe payloads for a
+    // particular service.
+    pub fn create(
+        iox_node_id: &IceoryxNodeId,
+        iox_service_config: &IceoryxServiceConfig,
+        iox_service: &IceoryxPublishSubscribeService<
+            ServiceType,
+            [CustomPayloadMarker],
+            CustomHeaderMarker,
+        >,
+        throughput_limit: ThroughputLimit,
+    ) -> Result<Self, CreationError> {
+        info!(
+            "CREATE PublisherChannel  {} [{}]",
+            iox_service_config.service_id().as_str(),
+            iox_service_config.name()
+        );
+
+        let iox_subscriber = fail!(
+            from "PublisherChannel::create()",
+            when middleware::iceoryx::create_subscriber::<ServiceType>(iox_service),
+            with CreationError::Error,
+            "failed to create iceoryx subscriber to propagate local payloads"
+        );
+
+        Ok(Self {
+            iox_node_id: *iox_node_id,
+            iox_service_config: iox_service_config.clone(),
+            iox_subscriber,
+            throttle: RefCell::new(Throttle::new(throughput_limit)),
+            pending_payload: RefCell::new(None),
+            statistics: Cell::new(PublisherStatistics::default()),
+        })
+    }
+
+    // Returns the counters of the samples handled by the channel.
+    pub fn statistics(&self) -> PublisherStatistics {
+        self.statistics.get()
+    }
+
+    fn send(&self, transport: &dyn Transport, bytes: &[u8]) -> Result<(), PropagationError> {
+        let message = Message::Publish {
+            service_id: self.iox_service_config.service_id().as_str().to_string(),
+            payload: bytes.to_vec(),
+        };
+        let frame = fail!(
+            from self,
+            when message.encode(),
+            with PropagationError::OtherPort,
+            "failed to encode local payload for the peers"
+        );
+        fail!(
+            from self,
+            when transport.send(&frame),
+            with PropagationError::OtherPort,
+            "failed to propagate local payload to the peers"
+        );
+
+        let mut statistics = self.statistics.get();
+        statistics.propagated_samples += 1;
+        statistics.propagated_bytes += bytes.len() as u64;
+        self.statistics.set(statistics);
+
+        info!(
+            "PROPAGATE PublisherChannel {} [{}]",
+            self.iox_service_config.service_id().as_str(),
+            self.iox_service_config.name()
+        );
+
+        Ok(())
+    }
+
+    fn drop_sample(&self) {
+        let mut statistics = self.statistics.get();
+        statistics.dropped_samples += 1;
+        self.statistics.set(statistics);
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Channel for PublisherChannel<ServiceType> {
+    /// Propagate local publish-subscribe payloads to the peers.
+    fn propagate(&self, transport: &dyn Transport) -> Result<(), PropagationError> {
+        let is_downsampled = self.throttle.borrow().limit().downsample_period.is_some();
+
+        loop {
+            match unsafe { self.iox_subscriber.receive_custom_payload() } {
+                Ok(Some(sample)) => {
+                    if sample.header().node_id() == self.iox_node_id {
+                        // Ignore samples published by the tunnel itself to prevent loopback.
+                        continue;
+                    }
+
+                    let ptr = sample.payload().as_ptr() as *const u8;
+                    let len = sample.len();
+                    let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+
+                    if is_downsampled {
+                        // Only the latest sample is propagated once the period elapsed.
+                        if self.pending_payload.replace(Some(bytes.to_vec())).is_some() {
+                            self.drop_sample();
+                        }
+                    } else if self.throttle.borrow_mut().admit(Instant::now(), len) {
+                        self.send(transport, bytes)?;
+                    } else {
+                        self.drop_sample();
+                    }
+                }
+                Ok(None) => break, // No more samples available
+                Err(e) => {
+                    fatal_panic!("failed to receive custom payload: {e}");
+                }
+            }
+        }
+
+        let mut pending_payload = self.pending_payload.borrow_mut();
+        if let Some(bytes) = pending_payload.as_deref() {
+            if self
+                .throttle
+                .borrow_mut()
+                .admit(Instant::now(), bytes.len())
+            {
+                self.send(transport, bytes)?;
+                *pending_payload = None;
+            }
+        }
+
+        Ok(())
+    }
+}
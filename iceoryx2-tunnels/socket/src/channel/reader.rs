@@ -0,0 +1,250 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::middleware;
+use crate::transport::Transport;
+use crate::wire::BlackboardEntry;
+use crate::wire::BlackboardState;
+use crate::Channel;
+use crate::PropagationError;
+
+use iceoryx2::node::Node as IceoryxNode;
+use iceoryx2::port::writer::__InternalEntryHandleMut as IceoryxEntryHandleMut;
+use iceoryx2::port::writer::Writer as IceoryxWriter;
+use iceoryx2::service::port_factory::blackboard::PortFactory as IceoryxBlackboardService;
+use iceoryx2::service::static_config::message_type_details::TypeDetail;
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::info;
+
+use core::cell::RefCell;
+use std::collections::HashMap;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// A change of a remote blackboard entry received from a peer.
+#[derive(Debug)]
+enum EntryUpdate {
+    Update(u64, BlackboardEntry),
+    Remove(u64),
+}
+
+/// A local replica of a remote blackboard entry.
+#[derive(Debug)]
+struct ReplicaEntry<ServiceType: iceoryx2::service::Service> {
+    type_details: TypeDetail,
+    iox_entry_handle: IceoryxEntryHandleMut<ServiceType>,
+}
+
+/// A channel for replicating the entries of a remote `iceoryx2` blackboard to local readers.
+#[derive(Debug)]
+pub(crate) struct ReaderChannel<ServiceType: iceoryx2::service::Service> {
+    iox_service_config: IceoryxServiceConfig,
+    // Keeps the replicated blackboard alive.
+    _iox_service: IceoryxBlackboardService<ServiceType, u64>,
+    iox_writer: IceoryxWriter<ServiceType, u64>,
+    replica_entries: RefCell<HashMap<u64, ReplicaEntry<ServiceType>>>,
+    received_updates: RefCell<Vec<EntryUpdate>>,
+}
+
+impl<ServiceType: iceoryx2::service::Service> ReaderChannel<ServiceType> {
+    // Creates an inbound channel from the peers for the entries of a particular blackboard.
+    //
+    // The local replica of the blackboard is created with the state received from a peer.
+    // Returns `None` when the state has no entries (yet).
+    pub fn create(
+        iox_service_config: &IceoryxServiceConfig,
+        iox_node: &IceoryxNode<ServiceType>,
+        state: BlackboardState,
+    ) -> Result<Option<Self>, CreationError> {
+        if state.entries.is_empty() {
+            // A blackboard cannot be created without entries.
+            return Ok(None);
+        }
+
+        info!(
+            "CREATE ReaderChannel {} [{}]",
+            iox_service_config.service_id().as_str(),
+            iox_service_config.name()
+        );
+
+        let iox_service = fail!(
+            from "ReaderChannel::create()",
+            when middleware::iceoryx::create_blackboard_service::<ServiceType>(
+                iox_node,
+                iox_service_config,
+                &state
+            ),
+            with CreationError::Error,
+            "failed to create local replica of remote blackboard"
+        );
+        let iox_writer = fail!(
+            from "ReaderChannel::create()",
+            when middleware::iceoryx::create_writer::<ServiceType>(&iox_service),
+            with CreationError::Error,
+            "failed to create iceoryx writer to deliver remote blackboard entries"
+        );
+
+        let mut replica_entries = HashMap::new();
+        for (key, entry) in state.entries {
+            let iox_entry_handle = fail!(
+                from "ReaderChannel::create()",
+                when iox_writer.__internal_entry(&key, &entry.type_details),
+                with CreationError::Error,
+                "failed to acquire handle for replicated blackboard entry {}", key
+            );
+            replica_entries.insert(
+                key,
+                ReplicaEntry {
+                    type_details: entry.type_details,
+                    iox_entry_handle,
+                },
+            );
+        }
+
+        Ok(Some(Self {
+            iox_service_config: iox_service_config.clone(),
+            _iox_service: iox_service,
+            iox_writer,
+            replica_entries: RefCell::new(replica_entries),
+            received_updates: RefCell::new(Vec::new()),
+        }))
+    }
+
+    // Stores the entries of a state received from a peer until the next propagation.
+    pub fn receive_state(&self, state: BlackboardState) {
+        self.received_updates.borrow_mut().extend(
+            state
+                .entries
+                .into_iter()
+                .map(|(key, entry)| EntryUpdate::Update(key, entry)),
+        );
+    }
+
+    // Stores an entry received from a peer until the next propagation.
+    pub fn receive_update(&self, key: u64, entry: BlackboardEntry) {
+        self.received_updates
+            .borrow_mut()
+            .push(EntryUpdate::Update(key, entry));
+    }
+
+    // Stores the removal of an entry received from a peer until the next propagation.
+    pub fn receive_remove(&self, key: u64) {
+        self.received_updates
+            .borrow_mut()
+            .push(EntryUpdate::Remove(key));
+    }
+
+    fn remove_entry(&self, key: u64) -> Result<(), PropagationError> {
+        // The handle must be released before the entry can be removed.
+        if self.replica_entries.borrow_mut().remove(&key).is_some() {
+            fail!(
+                from self,
+                when self.iox_writer.remove(&key),
+                with PropagationError::IceoryxPort,
+                "failed to remove replicated blackboard entry {}", key
+            );
+        }
+
+        Ok(())
+    }
+
+    fn insert_entry(&self, key: u64, entry: BlackboardEntry) -> Result<(), PropagationError> {
+        let (internals, _, _) = middleware::iceoryx::blackboard_entry_internals(key, &entry);
+        fail!(
+            from self,
+            when self.iox_writer.__internal_insert(internals),
+            with PropagationError::IceoryxPort,
+            "failed to insert replicated blackboard entry {}", key
+        );
+
+        let iox_entry_handle = fail!(
+            from self,
+            when self.iox_writer.__internal_entry(&key, &entry.type_details),
+            with PropagationError::IceoryxPort,
+            "failed to acquire handle for replicated blackboard entry {}", key
+        );
+        self.replica_entries.borrow_mut().insert(
+            key,
+            ReplicaEntry {
+                type_details: entry.type_details,
+                iox_entry_handle,
+            },
+        );
+
+        Ok(())
+    }
+
+    fn update_entry(&self, key: u64, entry: BlackboardEntry) -> Result<(), PropagationError> {
+        let has_matching_type = self
+            .replica_entries
+            .borrow()
+            .get(&key)
+            .map(|replica| replica.type_details == entry.type_details);
+
+        match has_matching_type {
+            Some(true) => {
+                let replica_entries = self.replica_entries.borrow();
+                let handle = &replica_entries[&key].iox_entry_handle;
+                let size = entry.type_details.size();
+                let alignment = entry.type_details.alignment();
+                unsafe {
+                    let ptr = handle.__internal_get_ptr_to_write_cell(size, alignment);
+                    core::ptr::copy_nonoverlapping(
+                        entry.value.as_ptr(),
+                        ptr,
+                        entry.value.len().min(size),
+                    );
+                    handle.__internal_update_write_cell();
+                }
+                Ok(())
+            }
+            Some(false) => {
+                // The entry was replaced on the peer with a value of a different type.
+                self.remove_entry(key)?;
+                self.insert_entry(key, entry)
+            }
+            None => self.insert_entry(key, entry),
+        }
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Channel for ReaderChannel<ServiceType> {
+    /// Propagate the entries received from the peers to local readers.
+    fn propagate(&self, _transport: &dyn Transport) -> Result<(), PropagationError> {
+        for update in self.received_updates.take() {
+            match update {
+                EntryUpdate::Update(key, entry) => self.update_entry(key, entry)?,
+                EntryUpdate::Remove(key) => self.remove_entry(key)?,
+            }
+
+            info!(
+                "PROPAGATE ReaderChannel {} [{}]",
+                self.iox_service_config.service_id().as_str(),
+                self.iox_service_config.name()
+            );
+        }
+
+        Ok(())
+    }
+}
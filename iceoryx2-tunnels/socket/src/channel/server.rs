@@ -0,0 +1,319 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::middleware;
+use crate::transport::Transport;
+use crate::wire::Message;
+use crate::Channel;
+use crate::PropagationError;
+
+use iceoryx2::pending_response::PendingResponse as IceoryxPendingResponse;
+use iceoryx2::port::client::Client as IceoryxClient;
+use iceoryx2::port::port_identifiers::UniqueClientId as IceoryxClientId;
+use iceoryx2::service::builder::CustomHeaderMarker;
+use iceoryx2::service::builder::CustomPayloadMarker;
+use iceoryx2::service::port_factory::request_response::PortFactory as IceoryxRequestResponseService;
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::info;
+use iceoryx2_bb_log::warn;
+
+use core::cell::RefCell;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// Changes of the connection of a remote client to one of its requests.
+#[derive(Debug)]
+enum ConnectionUpdate {
+    DisconnectHint(String),
+    Disconnected(String),
+}
+
+/// A remote request that was delivered to local servers and whose responses are still
+/// propagated back to the peers.
+#[derive(Debug)]
+struct DeliveredRequest<ServiceType: iceoryx2::service::Service> {
+    token: String,
+    iox_pending_response: IceoryxPendingResponse<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+}
+
+/// A channel for propagating requests of the peers to local servers and their responses back
+/// to the peers.
+#[derive(Debug)]
+pub(crate) struct ServerChannel<ServiceType: iceoryx2::service::Service> {
+    iox_service_config: IceoryxServiceConfig,
+    iox_client: IceoryxClient<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    received_requests: RefCell<Vec<(String, Vec<u8>)>>,
+    received_connection_updates: RefCell<Vec<ConnectionUpdate>>,
+    delivered_requests: RefCell<Vec<DeliveredRequest<ServiceType>>>,
+}
+
+impl<ServiceType: iceoryx2::service::Service> ServerChannel<ServiceType> {
+    // Creates an inbound channel from the peers for requests for a particular service.
+    pub fn create(
+        iox_service_config: &IceoryxServiceConfig,
+        iox_service: &IceoryxRequestResponseService<
+            ServiceType,
+            [CustomPayloadMarker],
+            CustomHeaderMarker,
+            [CustomPayloadMarker],
+            CustomHeaderMarker,
+        >,
+    ) -> Result<Self, CreationError> {
+        info!(
+            "CREATE ServerChannel {} [{}]",
+            iox_service_config.service_id().as_str(),
+            iox_service_config.name()
+        );
+
+        let iox_client = fail!(
+            from "ServerChannel::create()",
+            when middleware::iceoryx::create_client::<ServiceType>(iox_service),
+            with CreationError::Error,
+            "failed to create iceoryx client to propagate remote requests to local servers"
+        );
+
+        Ok(Self {
+            iox_service_config: iox_service_config.clone(),
+            iox_client,
+            received_requests: RefCell::new(Vec::new()),
+            received_connection_updates: RefCell::new(Vec::new()),
+            delivered_requests: RefCell::new(Vec::new()),
+        })
+    }
+
+    /// Returns the id of the iceoryx client that delivers remote requests to local servers.
+    pub fn client_id(&self) -> IceoryxClientId {
+        self.iox_client.id()
+    }
+
+    // Stores a request received from a peer until the next propagation.
+    pub fn receive_request(&self, token: String, payload: Vec<u8>) {
+        self.received_requests.borrow_mut().push((token, payload));
+    }
+
+    // Stores that the client of a request intends to disconnect until the next propagation.
+    pub fn receive_disconnect_hint(&self, token: String) {
+        self.received_connection_updates
+            .borrow_mut()
+            .push(ConnectionUpdate::DisconnectHint(token));
+    }
+
+    // Stores that the client of a request disconnected until the next propagation.
+    pub fn receive_disconnect(&self, token: String) {
+        self.received_connection_updates
+            .borrow_mut()
+            .push(ConnectionUpdate::Disconnected(token));
+    }
+
+    fn send(&self, transport: &dyn Transport, message: Message) -> Result<(), PropagationError> {
+        let frame = fail!(
+            from self,
+            when message.encode(),
+            with PropagationError::OtherPort,
+            "failed to encode {:?} message for the peers", message.kind()
+        );
+        fail!(
+            from self,
+            when transport.send(&frame),
+            with PropagationError::OtherPort,
+            "failed to propagate {:?} message to the peers", message.kind()
+        );
+
+        Ok(())
+    }
+
+    fn finalize(&self, transport: &dyn Transport, token: &str) -> Result<(), PropagationError> {
+        self.send(
+            transport,
+            Message::Finalize {
+                service_id: self.iox_service_config.service_id().as_str().to_string(),
+                token: token.to_string(),
+            },
+        )
+    }
+
+    fn deliver_requests(&self, transport: &dyn Transport) -> Result<(), PropagationError> {
+        let iox_payload_size = self
+            .iox_service_config
+            .request_response()
+            .request_message_type_details()
+            .payload
+            .size();
+
+        for (token, payload) in self.received_requests.take() {
+            if iox_payload_size == 0 || payload.len() % iox_payload_size != 0 {
+                warn!(
+                    from self,
+                    "rejecting request of {} bytes from peer since it does not match the payload type",
+                    payload.len()
+                );
+                self.finalize(transport, &token)?;
+                continue;
+            }
+
+            let number_of_elements = payload.len() / iox_payload_size;
+            unsafe {
+                match self.iox_client.loan_custom_payload(number_of_elements) {
+                    Ok(mut iox_request) => {
+                        core::ptr::copy_nonoverlapping(
+                            payload.as_ptr(),
+                            iox_request.payload_mut().as_mut_ptr() as *mut u8,
+                            payload.len(),
+                        );
+                        let iox_request = iox_request.assume_init();
+                        let iox_pending_response = fail!(
+                            from self,
+                            when iox_request.send(),
+                            with PropagationError::IceoryxPort,
+                            "failed to send remote request to local servers"
+                        );
+
+                        self.delivered_requests.borrow_mut().push(DeliveredRequest {
+                            token,
+                            iox_pending_response,
+                        });
+
+                        info!(
+                            "PROPAGATE ServerChannel {} [{}]",
+                            self.iox_service_config.service_id().as_str(),
+                            self.iox_service_config.name()
+                        );
+                    }
+                    Err(e) => {
+                        // The local servers cannot take more requests, e.g. since the tunnel
+                        // client exceeds its maximum number of active requests. Reject the
+                        // remote request instead.
+                        warn!(from self, "rejecting remote request since it cannot be delivered to local servers: {e}");
+                        self.finalize(transport, &token)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apply_connection_updates(&self) {
+        let mut delivered_requests = self.delivered_requests.borrow_mut();
+
+        for update in self.received_connection_updates.take() {
+            match update {
+                ConnectionUpdate::DisconnectHint(token) => {
+                    if let Some(request) = delivered_requests
+                        .iter()
+                        .find(|request| request.token == token)
+                    {
+                        request.iox_pending_response.set_disconnect_hint();
+                    }
+                }
+                ConnectionUpdate::Disconnected(token) => {
+                    delivered_requests.retain(|request| request.token != token);
+                }
+            }
+        }
+    }
+
+    // Sends all responses received so far for the request to the peers and returns whether the
+    // local servers will not send any further responses.
+    fn send_responses(
+        &self,
+        transport: &dyn Transport,
+        request: &DeliveredRequest<ServiceType>,
+    ) -> Result<bool, PropagationError> {
+        // Checked before receiving to not miss responses that arrive in between.
+        let is_connected = request.iox_pending_response.is_connected();
+
+        loop {
+            match unsafe { request.iox_pending_response.receive_custom_payload() } {
+                Ok(Some(iox_response)) => {
+                    let ptr = iox_response.payload().as_ptr() as *const u8;
+                    let len = iox_response.payload().len();
+                    let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+
+                    // TODO(optimization): Only send the response to the peer of the request
+                    self.send(
+                        transport,
+                        Message::Response {
+                            service_id: self.iox_service_config.service_id().as_str().to_string(),
+                            token: request.token.clone(),
+                            payload: bytes.to_vec(),
+                        },
+                    )?;
+
+                    info!(
+                        "PROPAGATE ServerChannel {} [{}]",
+                        self.iox_service_config.service_id().as_str(),
+                        self.iox_service_config.name()
+                    );
+                }
+                Ok(None) => break, // No more responses available
+                Err(e) => {
+                    fatal_panic!("failed to receive custom payload: {e}");
+                }
+            }
+        }
+
+        Ok(!is_connected)
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Channel for ServerChannel<ServiceType> {
+    /// Propagate requests of the peers to local servers and the local responses back to the
+    /// peers.
+    fn propagate(&self, transport: &dyn Transport) -> Result<(), PropagationError> {
+        self.deliver_requests(transport)?;
+        self.apply_connection_updates();
+
+        let mut result = Ok(());
+        self.delivered_requests.borrow_mut().retain(|request| {
+            let is_finalized = match self.send_responses(transport, request) {
+                Ok(true) => self.finalize(transport, &request.token).map(|_| true),
+                other => other,
+            };
+
+            match is_finalized {
+                Ok(is_finalized) => !is_finalized,
+                Err(e) => {
+                    result = Err(e);
+                    true
+                }
+            }
+        });
+
+        result
+    }
+}
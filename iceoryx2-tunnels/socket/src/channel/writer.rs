@@ -0,0 +1,252 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::middleware;
+use crate::transport::Transport;
+use crate::wire::BlackboardEntry;
+use crate::wire::BlackboardState;
+use crate::wire::Message;
+use crate::Channel;
+use crate::PropagationError;
+
+use iceoryx2::port::reader::__InternalEntryHandle as IceoryxEntryHandle;
+use iceoryx2::port::reader::Reader as IceoryxReader;
+use iceoryx2::service::port_factory::blackboard::PortFactory as IceoryxBlackboardService;
+use iceoryx2::service::static_config::message_type_details::TypeDetail;
+use iceoryx2::service::static_config::message_type_details::TypeVariant;
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::info;
+use iceoryx2_bb_log::warn;
+
+use core::cell::RefCell;
+use std::collections::HashMap;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// A local blackboard entry whose value is replicated to the peers.
+#[derive(Debug)]
+struct ReplicatedEntry<ServiceType: iceoryx2::service::Service> {
+    type_details: TypeDetail,
+    iox_entry_handle: IceoryxEntryHandle<ServiceType>,
+    // The write generation of the last propagated value, `None` when it was not propagated yet.
+    write_generation: Option<u32>,
+}
+
+/// A channel for propagating the entries of a local `iceoryx2` blackboard to the peers.
+#[derive(Debug)]
+pub(crate) struct WriterChannel<ServiceType: iceoryx2::service::Service> {
+    iox_service_config: IceoryxServiceConfig,
+    iox_reader: IceoryxReader<ServiceType, u64>,
+    replicated_entries: RefCell<HashMap<u64, ReplicatedEntry<ServiceType>>>,
+    // The last propagated values, sent to the peers that query the state of the blackboard.
+    state: RefCell<BlackboardState>,
+}
+
+impl<ServiceType: iceoryx2::service::Service> WriterChannel<ServiceType> {
+    // Creates an outbound channel to the peers for the entries of a particular blackboard.
+    //
+    // Peers that join later receive the current entries via a query.
+    pub fn create(
+        iox_service_config: &IceoryxServiceConfig,
+        iox_service: &IceoryxBlackboardService<ServiceType, u64>,
+        transport: &dyn Transport,
+    ) -> Result<Self, CreationError> {
+        info!(
+            "CREATE WriterChannel {} [{}]",
+            iox_service_config.service_id().as_str(),
+            iox_service_config.name()
+        );
+
+        let iox_reader = fail!(
+            from "WriterChannel::create()",
+            when middleware::iceoryx::create_reader::<ServiceType>(iox_service),
+            with CreationError::Error,
+            "failed to create iceoryx reader to propagate local blackboard entries"
+        );
+        let state = RefCell::new(BlackboardState {
+            capacity: iox_reader.__internal_capacity(),
+            entries: HashMap::new(),
+        });
+
+        let channel = Self {
+            iox_service_config: iox_service_config.clone(),
+            iox_reader,
+            replicated_entries: RefCell::new(HashMap::new()),
+            state,
+        };
+        fail!(
+            from "WriterChannel::create()",
+            when channel.synchronize_key_set(transport),
+            with CreationError::Error,
+            "failed to acquire the entries of the local blackboard"
+        );
+        // Provides the current values to the peers that already replicate the blackboard.
+        fail!(
+            from "WriterChannel::create()",
+            when channel.propagate(transport),
+            with CreationError::Error,
+            "failed to propagate the entries of the local blackboard"
+        );
+
+        Ok(channel)
+    }
+
+    /// Sends the capacity and the last propagated values of all entries to the peers.
+    pub fn send_state(&self, transport: &dyn Transport) -> Result<(), PropagationError> {
+        self.send(
+            transport,
+            Message::BlackboardState {
+                service_id: self.iox_service_config.service_id().as_str().to_string(),
+                state: self.state.borrow().clone(),
+            },
+        )
+    }
+
+    fn send(&self, transport: &dyn Transport, message: Message) -> Result<(), PropagationError> {
+        let frame = fail!(
+            from self,
+            when message.encode(),
+            with PropagationError::OtherPort,
+            "failed to encode {:?} message for the peers", message.kind()
+        );
+        fail!(
+            from self,
+            when transport.send(&frame),
+            with PropagationError::OtherPort,
+            "failed to propagate {:?} message to the peers", message.kind()
+        );
+
+        Ok(())
+    }
+
+    // Acquires handles to newly inserted entries and informs the peers about removed entries.
+    fn synchronize_key_set(&self, transport: &dyn Transport) -> Result<(), PropagationError> {
+        let entries = self.iox_reader.__internal_entries();
+        let mut replicated_entries = self.replicated_entries.borrow_mut();
+
+        let removed_keys: Vec<u64> = replicated_entries
+            .keys()
+            .filter(|key| !entries.iter().any(|(k, _)| k == *key))
+            .copied()
+            .collect();
+        for key in removed_keys {
+            replicated_entries.remove(&key);
+            self.state.borrow_mut().entries.remove(&key);
+            self.send(
+                transport,
+                Message::BlackboardRemove {
+                    service_id: self.iox_service_config.service_id().as_str().to_string(),
+                    key,
+                },
+            )?;
+        }
+
+        for (key, type_details) in entries {
+            if replicated_entries
+                .get(&key)
+                .is_some_and(|entry| entry.type_details == type_details)
+            {
+                continue;
+            }
+
+            if type_details.variant() != TypeVariant::FixedSize {
+                // TODO(functionality): Support replication of slice entries
+                warn!(from self, "skipping blackboard entry {} with dynamically sized value", key);
+                continue;
+            }
+
+            let iox_entry_handle = fail!(
+                from self,
+                when self.iox_reader.__internal_entry(&key, &type_details),
+                with PropagationError::IceoryxPort,
+                "failed to acquire handle for local blackboard entry {}", key
+            );
+            replicated_entries.insert(
+                key,
+                ReplicatedEntry {
+                    type_details,
+                    iox_entry_handle,
+                    write_generation: None,
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Channel for WriterChannel<ServiceType> {
+    /// Propagate changed values of local blackboard entries to the peers.
+    ///
+    /// Only values whose write generation changed since the last propagation are sent.
+    fn propagate(&self, transport: &dyn Transport) -> Result<(), PropagationError> {
+        if self.iox_reader.has_key_set_changed() {
+            self.synchronize_key_set(transport)?;
+        }
+
+        for (key, entry) in self.replicated_entries.borrow_mut().iter_mut() {
+            // Acquired before the value is copied, so that an update in between is propagated
+            // again in the next iteration instead of being missed.
+            let write_generation = entry.iox_entry_handle.write_generation();
+            if entry.write_generation == Some(write_generation) {
+                continue;
+            }
+
+            let size = entry.type_details.size();
+            let alignment = entry.type_details.alignment();
+            let mut value = vec![0u8; size];
+            unsafe {
+                entry
+                    .iox_entry_handle
+                    .get(value.as_mut_ptr(), size, alignment)
+            };
+
+            let replicated_entry = BlackboardEntry {
+                type_details: entry.type_details.clone(),
+                value,
+            };
+            self.send(
+                transport,
+                Message::BlackboardUpdate {
+                    service_id: self.iox_service_config.service_id().as_str().to_string(),
+                    key: *key,
+                    entry: replicated_entry.clone(),
+                },
+            )?;
+            entry.write_generation = Some(write_generation);
+
+            let mut state = self.state.borrow_mut();
+            state.capacity = self.iox_reader.__internal_capacity();
+            state.entries.insert(*key, replicated_entry);
+
+            info!(
+                "PROPAGATE WriterChannel {} [{}]",
+                self.iox_service_config.service_id().as_str(),
+                self.iox_service_config.name()
+            );
+        }
+
+        Ok(())
+    }
+}
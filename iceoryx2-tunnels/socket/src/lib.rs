@@ -0,0 +1,30 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+pub(crate) mod channel;
+pub(crate) use channel::*;
+
+pub(crate) mod discovery;
+
+pub(crate) mod middleware;
+
+pub use iceoryx2_tunnels_common::*;
+
+mod transport;
+pub use transport::Peer;
+pub use transport::Protocol;
+pub use transport::TransportError;
+
+mod tunnel;
+pub use tunnel::*;
+
+pub mod wire;
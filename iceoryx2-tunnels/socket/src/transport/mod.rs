@@ -0,0 +1,107 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod tcp;
+mod udp;
+
+pub(crate) use tcp::*;
+pub(crate) use udp::*;
+
+use iceoryx2_bb_system_types::ipv4_address::Ipv4Address;
+use iceoryx2_bb_system_types::port::Port;
+
+use core::str::FromStr;
+use std::net::SocketAddrV4;
+
+/// The socket protocol used to exchange frames with the peers.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum Protocol {
+    /// Every frame is sent as a single datagram, hence frames are limited to the maximum
+    /// datagram size of about 64KiB. Frames may be lost or reordered.
+    #[default]
+    Udp,
+    /// Frames are sent over a connection to each peer that is established on demand. Suited
+    /// for large payloads.
+    Tcp,
+}
+
+/// The address of a remote tunnel. Can be parsed from `ADDRESS:PORT`, e.g. `192.168.0.2:7460`.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Peer {
+    pub address: Ipv4Address,
+    pub port: Port,
+}
+
+impl Peer {
+    pub(crate) fn socket_address(&self) -> SocketAddrV4 {
+        SocketAddrV4::new(self.address.octets().into(), self.port.as_u16())
+    }
+}
+
+impl core::fmt::Display for Peer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "{}:{}", self.address, self.port)
+    }
+}
+
+impl FromStr for Peer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let socket_address: SocketAddrV4 = s
+            .parse()
+            .map_err(|_| format!("peer '{s}' is not of the form ADDRESS:PORT"))?;
+        let [a, b, c, d] = socket_address.ip().octets();
+
+        Ok(Peer {
+            address: Ipv4Address::new(a, b, c, d),
+            port: Port::new(socket_address.port()),
+        })
+    }
+}
+
+/// Errors that can occur when exchanging frames with the peers.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum TransportError {
+    /// The frame exceeds the size the protocol is able to transmit.
+    MessageTooLarge,
+    /// Failed to send a frame to a peer.
+    Send,
+    /// Failed to receive frames from the peers.
+    Receive,
+}
+
+impl core::fmt::Display for TransportError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "TransportError::{self:?}")
+    }
+}
+
+impl core::error::Error for TransportError {}
+
+/// Exchanges frames of the wire format with the peers.
+pub(crate) trait Transport: core::fmt::Debug {
+    /// Returns the local port on which frames from the peers are received.
+    fn port(&self) -> Port;
+
+    /// Adds a peer to which all subsequent frames are sent.
+    fn add_peer(&mut self, peer: Peer);
+
+    /// Returns the number of peers that receive the frames sent via the transport.
+    fn number_of_peers(&self) -> usize;
+
+    /// Sends a complete frame to all peers.
+    fn send(&self, frame: &[u8]) -> Result<(), TransportError>;
+
+    /// Receives all frames that are currently available without blocking.
+    fn receive(&self, on_frame: &mut dyn FnMut(&[u8])) -> Result<(), TransportError>;
+}
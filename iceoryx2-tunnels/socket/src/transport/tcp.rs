@@ -0,0 +1,356 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::transport::Peer;
+use crate::transport::Transport;
+use crate::transport::TransportError;
+use crate::wire;
+
+use iceoryx2_bb_log::debug;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::info;
+use iceoryx2_bb_log::warn;
+use iceoryx2_bb_system_types::ipv4_address::Ipv4Address;
+use iceoryx2_bb_system_types::port::Port;
+
+use core::cell::RefCell;
+use core::time::Duration;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::net::IpAddr;
+use std::net::SocketAddrV4;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::time::Instant;
+
+/// Upper bound for establishing a connection to a peer, keeps unreachable peers from stalling
+/// the tunnel.
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// The interval after which a failed connection to a peer is retried for the first time.
+const INITIAL_RECONNECT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Upper bound for the interval between connection attempts, it is doubled with every failed
+/// attempt.
+const MAX_RECONNECT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Upper bound for the bytes queued for a peer that does not read fast enough, further frames
+/// to the peer are dropped.
+const MAX_PENDING_BYTES: usize = 64 * 1024 * 1024;
+
+// TODO: More granularity in errors
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+/// A connection to a peer together with the frames that are not yet completely written.
+#[derive(Debug)]
+struct OutgoingConnection {
+    stream: Option<TcpStream>,
+    pending: Vec<u8>,
+    // The earliest point in time at which a failed connection is established again.
+    reconnect_at: Option<Instant>,
+    reconnect_interval: Duration,
+}
+
+impl OutgoingConnection {
+    fn new() -> Self {
+        Self {
+            stream: None,
+            pending: Vec::new(),
+            reconnect_at: None,
+            reconnect_interval: INITIAL_RECONNECT_INTERVAL,
+        }
+    }
+
+    fn connected(&mut self, stream: TcpStream) {
+        self.stream = Some(stream);
+        self.reconnect_at = None;
+        self.reconnect_interval = INITIAL_RECONNECT_INTERVAL;
+    }
+
+    // Drops the connection and the pending frames, the connection is established again after
+    // the reconnect interval.
+    fn disconnected(&mut self) {
+        self.stream = None;
+        self.pending.clear();
+        self.reconnect_at = Some(Instant::now() + self.reconnect_interval);
+        self.reconnect_interval = (self.reconnect_interval * 2).min(MAX_RECONNECT_INTERVAL);
+    }
+
+    fn may_reconnect(&self) -> bool {
+        match self.reconnect_at {
+            Some(reconnect_at) => Instant::now() >= reconnect_at,
+            None => true,
+        }
+    }
+}
+
+/// A connection accepted from a peer together with the bytes of the frame that is not yet
+/// completely received.
+#[derive(Debug)]
+struct IncomingConnection {
+    stream: TcpStream,
+    buffer: Vec<u8>,
+}
+
+/// Exchanges frames over connections to each peer. Frames are sent over connections that are
+/// established on demand and received over the connections accepted from the peers.
+///
+/// Frames are written without blocking, the part that does not fit into the socket buffer is
+/// queued and written with the next send or receive.
+#[derive(Debug)]
+pub(crate) struct TcpTransport {
+    listener: TcpListener,
+    max_frame_size: usize,
+    accept_only_peers: bool,
+    peers: Vec<Peer>,
+    // Index-aligned with `peers`.
+    outgoing: RefCell<Vec<OutgoingConnection>>,
+    incoming: RefCell<Vec<IncomingConnection>>,
+}
+
+impl TcpTransport {
+    pub fn create(
+        address: Ipv4Address,
+        port: Port,
+        max_frame_size: usize,
+        accept_only_peers: bool,
+    ) -> Result<Self, CreationError> {
+        let listener = fail!(
+            from "TcpTransport::create()",
+            when TcpListener::bind(SocketAddrV4::new(address.octets().into(), port.as_u16())),
+            with CreationError::Error,
+            "failed to listen on {}:{}", address, port
+        );
+        fail!(
+            from "TcpTransport::create()",
+            when listener.set_nonblocking(true),
+            with CreationError::Error,
+            "failed to make listener non-blocking"
+        );
+
+        let tcp_transport = Self {
+            listener,
+            max_frame_size,
+            accept_only_peers,
+            peers: Vec::new(),
+            outgoing: RefCell::new(Vec::new()),
+            incoming: RefCell::new(Vec::new()),
+        };
+        info!("LISTEN TcpTransport {}:{}", address, tcp_transport.port());
+
+        Ok(tcp_transport)
+    }
+
+    fn connect(&self, peer: &Peer) -> Option<TcpStream> {
+        let stream =
+            match TcpStream::connect_timeout(&peer.socket_address().into(), CONNECT_TIMEOUT) {
+                Ok(stream) => stream,
+                Err(e) => {
+                    // The peer might not be running yet, the connection is retried after the
+                    // reconnect interval.
+                    debug!(from self, "unable to connect to {} ({})", peer, e);
+                    return None;
+                }
+            };
+
+        if let Err(e) = stream
+            .set_nonblocking(true)
+            .and_then(|_| stream.set_nodelay(true))
+        {
+            warn!(from self, "failed to configure connection to {} ({})", peer, e);
+            return None;
+        }
+
+        info!("CONNECT TcpTransport {}", peer);
+        Some(stream)
+    }
+
+    // Writes as many pending bytes as the socket accepts without blocking.
+    fn flush(&self, peer: &Peer, connection: &mut OutgoingConnection) {
+        let Some(stream) = connection.stream.as_mut() else {
+            return;
+        };
+
+        let mut written = 0;
+        let result = loop {
+            if written == connection.pending.len() {
+                break Ok(());
+            }
+            match stream.write(&connection.pending[written..]) {
+                Ok(0) => break Err(std::io::Error::from(ErrorKind::WriteZero)),
+                Ok(n) => written += n,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break Ok(()),
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => break Err(e),
+            }
+        };
+
+        match result {
+            Ok(()) => {
+                connection.pending.drain(..written);
+            }
+            Err(e) => {
+                // The pending frames are lost, the connection is re-established after the
+                // reconnect interval.
+                warn!(from self, "dropping connection to {} since sending failed ({})", peer, e);
+                connection.disconnected();
+            }
+        }
+    }
+
+    fn accept(&self) -> Result<(), TransportError> {
+        loop {
+            match self.listener.accept() {
+                Ok((stream, address)) => {
+                    if self.accept_only_peers
+                        && !self
+                            .peers
+                            .iter()
+                            .any(|peer| address.ip() == IpAddr::from(peer.address.octets()))
+                    {
+                        warn!(from self, "rejecting connection from {} since it is not a peer", address);
+                        continue;
+                    }
+
+                    fail!(
+                        from self,
+                        when stream.set_nonblocking(true),
+                        with TransportError::Receive,
+                        "failed to make connection from {} non-blocking", address
+                    );
+                    info!("ACCEPT TcpTransport {}", address);
+                    self.incoming.borrow_mut().push(IncomingConnection {
+                        stream,
+                        buffer: Vec::new(),
+                    });
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return Ok(()),
+                Err(e) => {
+                    fail!(
+                        from self,
+                        with TransportError::Receive,
+                        "failed to accept connection ({})", e
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl Transport for TcpTransport {
+    fn port(&self) -> Port {
+        let port = self
+            .listener
+            .local_addr()
+            .map(|address| address.port())
+            .unwrap_or_default();
+        Port::new(port)
+    }
+
+    fn add_peer(&mut self, peer: Peer) {
+        if !self.peers.contains(&peer) {
+            self.peers.push(peer);
+            self.outgoing.borrow_mut().push(OutgoingConnection::new());
+        }
+    }
+
+    fn number_of_peers(&self) -> usize {
+        self.peers.len()
+    }
+
+    fn send(&self, frame: &[u8]) -> Result<(), TransportError> {
+        let mut outgoing = self.outgoing.borrow_mut();
+        for (peer, connection) in self.peers.iter().zip(outgoing.iter_mut()) {
+            if connection.stream.is_none() {
+                if !connection.may_reconnect() {
+                    // The frame is lost, the peer was unreachable recently.
+                    continue;
+                }
+                match self.connect(peer) {
+                    Some(stream) => connection.connected(stream),
+                    None => {
+                        connection.disconnected();
+                        continue;
+                    }
+                }
+            }
+
+            if connection.pending.len() + frame.len() > MAX_PENDING_BYTES {
+                warn!(from self,
+                    "dropping frame of {} bytes to {} since {} bytes are still pending",
+                    frame.len(), peer, connection.pending.len());
+                continue;
+            }
+            connection.pending.extend_from_slice(frame);
+            self.flush(peer, connection);
+        }
+
+        Ok(())
+    }
+
+    fn receive(&self, on_frame: &mut dyn FnMut(&[u8])) -> Result<(), TransportError> {
+        for (peer, connection) in self.peers.iter().zip(self.outgoing.borrow_mut().iter_mut()) {
+            self.flush(peer, connection);
+        }
+
+        self.accept()?;
+
+        let mut chunk = [0u8; 4096];
+        self.incoming.borrow_mut().retain_mut(|connection| {
+            // Read everything that is available, a length of zero means the peer disconnected.
+            let is_connected = loop {
+                match connection.stream.read(&mut chunk) {
+                    Ok(0) => break false,
+                    Ok(n) => connection.buffer.extend_from_slice(&chunk[..n]),
+                    Err(e) if e.kind() == ErrorKind::WouldBlock => break true,
+                    Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                    Err(e) => {
+                        warn!(from self, "dropping connection since receiving failed ({})", e);
+                        break false;
+                    }
+                }
+            };
+
+            // Deliver all complete frames.
+            let mut consumed = 0;
+            loop {
+                match wire::frame_length(&connection.buffer[consumed..]) {
+                    Ok(Some(length)) if length > self.max_frame_size => {
+                        warn!(from self,
+                            "dropping connection since a frame of {} bytes exceeds the maximum frame size of {} bytes",
+                            length, self.max_frame_size);
+                        return false;
+                    }
+                    Ok(Some(length)) if connection.buffer.len() - consumed >= length => {
+                        on_frame(&connection.buffer[consumed..consumed + length]);
+                        consumed += length;
+                    }
+                    Ok(_) => break,
+                    Err(e) => {
+                        // The stream cannot be resynchronized once a corrupt header was received.
+                        warn!(from self, "dropping connection since a corrupt frame was received ({})", e);
+                        return false;
+                    }
+                }
+            }
+            connection.buffer.drain(..consumed);
+
+            is_connected
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::transport::Peer;
+use crate::transport::Transport;
+use crate::transport::TransportError;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::info;
+use iceoryx2_bb_log::warn;
+use iceoryx2_bb_posix::udp_socket::UdpSendError;
+use iceoryx2_bb_posix::udp_socket::UdpServer;
+use iceoryx2_bb_posix::udp_socket::UdpServerBuilder;
+use iceoryx2_bb_system_types::ipv4_address::Ipv4Address;
+use iceoryx2_bb_system_types::port::Port;
+
+use core::cell::RefCell;
+
+/// The largest frame that fits into a single UDP datagram.
+const MAX_DATAGRAM_SIZE: usize = 65507;
+
+// TODO: More granularity in errors
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+/// Exchanges every frame as a single datagram.
+pub(crate) struct UdpTransport {
+    server: UdpServer,
+    peers: Vec<Peer>,
+    receive_buffer: RefCell<Vec<u8>>,
+}
+
+impl core::fmt::Debug for UdpTransport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // The receive buffer is omitted since it is too large to be logged.
+        f.debug_struct("UdpTransport")
+            .field("server", &self.server)
+            .field("peers", &self.peers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl UdpTransport {
+    pub fn create(address: Ipv4Address, port: Port) -> Result<Self, CreationError> {
+        let server = fail!(
+            from "UdpTransport::create()",
+            when UdpServerBuilder::new().address(address).port(port).listen(),
+            with CreationError::Error,
+            "failed to listen on {}:{}", address, port
+        );
+
+        info!("LISTEN UdpTransport {}:{}", address, server.port());
+
+        Ok(Self {
+            server,
+            peers: Vec::new(),
+            receive_buffer: RefCell::new(vec![0; MAX_DATAGRAM_SIZE]),
+        })
+    }
+}
+
+impl Transport for UdpTransport {
+    fn port(&self) -> Port {
+        self.server.port()
+    }
+
+    fn add_peer(&mut self, peer: Peer) {
+        if !self.peers.contains(&peer) {
+            self.peers.push(peer);
+        }
+    }
+
+    fn number_of_peers(&self) -> usize {
+        self.peers.len()
+    }
+
+    fn send(&self, frame: &[u8]) -> Result<(), TransportError> {
+        if frame.len() > MAX_DATAGRAM_SIZE {
+            fail!(
+                from self,
+                with TransportError::MessageTooLarge,
+                "failed to send frame of {} bytes since it exceeds the maximum datagram size, consider using tcp instead",
+                frame.len()
+            );
+        }
+
+        // A failing peer must not keep the frame from the remaining peers.
+        let mut result = Ok(());
+        let mut number_of_failed_peers = 0;
+        for peer in &self.peers {
+            let error = match self.server.send_to(frame, peer.address, peer.port) {
+                Ok(_) => continue,
+                Err(UdpSendError::MessageTooLarge) => {
+                    warn!(from self,
+                        "failed to send frame of {} bytes to {} since it is too large", frame.len(), peer);
+                    TransportError::MessageTooLarge
+                }
+                Err(e) => {
+                    warn!(from self, "failed to send frame to {} ({:?})", peer, e);
+                    TransportError::Send
+                }
+            };
+            number_of_failed_peers += 1;
+            if result.is_ok() {
+                result = Err(error);
+            }
+        }
+
+        if let Err(e) = result {
+            fail!(
+                from self,
+                with e,
+                "failed to send frame to {} of {} peers", number_of_failed_peers, self.peers.len()
+            );
+        }
+
+        Ok(())
+    }
+
+    fn receive(&self, on_frame: &mut dyn FnMut(&[u8])) -> Result<(), TransportError> {
+        let mut buffer = self.receive_buffer.borrow_mut();
+        loop {
+            match self.server.try_receive_from(&mut buffer) {
+                Ok(Some(details)) => on_frame(&buffer[..details.number_of_bytes]),
+                Ok(None) => break,
+                Err(e) => {
+                    fail!(
+                        from self,
+                        with TransportError::Receive,
+                        "failed to receive frame ({:?})", e
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1098 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::channel::Channel;
+use crate::channel::ClientChannel;
+use crate::channel::ListenerChannel;
+use crate::channel::NotifierChannel;
+use crate::channel::PropagationError;
+use crate::channel::PublisherChannel;
+use crate::channel::ReaderChannel;
+use crate::channel::ServerChannel;
+use crate::channel::SubscriberChannel;
+use crate::channel::WriterChannel;
+use crate::discovery::Discovery;
+use crate::discovery::DiscoveryError;
+use crate::discovery::IceoryxDiscovery;
+use crate::middleware;
+use crate::transport::Peer;
+use crate::transport::Protocol;
+use crate::transport::TcpTransport;
+use crate::transport::Transport;
+use crate::transport::TransportError;
+use crate::transport::UdpTransport;
+use crate::wire::BlackboardState;
+use crate::wire::Message;
+use crate::PublisherStatistics;
+use crate::ServiceFilter;
+use crate::ServiceRemapping;
+use crate::ThroughputRule;
+
+use iceoryx2::config::Config as IceoryxConfig;
+use iceoryx2::node::Node as IceoryxNode;
+use iceoryx2::node::NodeBuilder;
+use iceoryx2::service::builder::blackboard::BlackboardOpenError;
+use iceoryx2::service::service_id::ServiceId;
+use iceoryx2::service::static_config::messaging_pattern::MessagingPattern;
+use iceoryx2::service::static_config::StaticConfig as ServiceConfig;
+use iceoryx2_bb_log::debug;
+use iceoryx2_bb_log::error;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::warn;
+use iceoryx2_bb_system_types::ipv4_address;
+use iceoryx2_bb_system_types::ipv4_address::Ipv4Address;
+use iceoryx2_bb_system_types::port::Port;
+
+use core::cell::Cell;
+use core::cell::RefCell;
+use core::time::Duration;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// The port the tunnel listens on when no other port is configured.
+pub const DEFAULT_PORT: Port = Port::new(7460);
+
+/// The time after which requests without a deadline are dropped when not all peers finalized
+/// them, unless another timeout is configured.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The largest frame received over tcp when no other maximum is configured.
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 64 * 1024 * 1024;
+
+pub struct TunnelConfig {
+    pub discovery_service: Option<String>,
+    /// The socket protocol used to exchange frames with the peers.
+    pub protocol: Protocol,
+    /// The local address the tunnel listens on.
+    pub address: Ipv4Address,
+    /// The local port the tunnel listens on. When unspecified, the operating system chooses a
+    /// free port that can be acquired with [`Tunnel::port()`].
+    pub port: Port,
+    /// The remote tunnels to exchange services with.
+    pub peers: Vec<Peer>,
+    /// Decides which local and remote services are propagated.
+    pub service_filter: ServiceFilter,
+    /// Remaps the names under which local services are announced to the peers.
+    pub service_remappings: Vec<ServiceRemapping>,
+    /// Limits the throughput of the publish-subscribe services propagated to the peers.
+    pub throughput_limits: Vec<ThroughputRule>,
+    /// The time after which requests without a deadline are dropped when not all peers
+    /// finalized them, e.g. since a peer is not running.
+    pub request_timeout: Duration,
+    /// The largest frame that is received over tcp. The connection of a peer that sends a
+    /// larger frame is dropped.
+    pub max_frame_size: usize,
+    /// When set, connections over tcp are only accepted from the addresses of the peers.
+    pub accept_only_peers: bool,
+}
+
+impl Default for TunnelConfig {
+    fn default() -> Self {
+        Self {
+            discovery_service: None,
+            protocol: Protocol::default(),
+            address: ipv4_address::UNSPECIFIED,
+            port: DEFAULT_PORT,
+            peers: Vec::new(),
+            service_filter: ServiceFilter::default(),
+            service_remappings: Vec::new(),
+            throughput_limits: Vec::new(),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            accept_only_peers: false,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// Defines the operational scope for tunnel services.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Scope {
+    Iceoryx,
+    Peers,
+    Both,
+}
+
+/// Represents information about an active communication channel in the tunnel.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ChannelInfo {
+    Publisher(String),
+    Subscriber(String),
+    Notifier(String),
+    Listener(String),
+    Client(String),
+    Server(String),
+    Writer(String),
+    Reader(String),
+}
+
+/// A tunnel for propagating iceoryx2 payloads across hosts via plain UDP or TCP sockets.
+#[derive(Debug)]
+pub struct Tunnel<ServiceType: iceoryx2::service::Service> {
+    transport: Box<dyn Transport>,
+    iox_node: IceoryxNode<ServiceType>,
+    iox_discovery: IceoryxDiscovery<ServiceType>,
+    publisher_channels: HashMap<ServiceId, PublisherChannel<ServiceType>>,
+    subscriber_channels: HashMap<ServiceId, SubscriberChannel<ServiceType>>,
+    notifier_channels: HashMap<ServiceId, NotifierChannel<ServiceType>>,
+    listener_channels: HashMap<ServiceId, ListenerChannel<ServiceType>>,
+    client_channels: HashMap<ServiceId, ClientChannel<ServiceType>>,
+    server_channels: HashMap<ServiceId, ServerChannel<ServiceType>>,
+    writer_channels: HashMap<ServiceId, WriterChannel<ServiceType>>,
+    reader_channels: HashMap<ServiceId, ReaderChannel<ServiceType>>,
+    // Services announced to the peers, announced again whenever a peer queries for them.
+    announced_services: HashMap<ServiceId, ServiceConfig>,
+    // Services announced by the peers that are processed with the next discovery.
+    remote_announcements: RefCell<Vec<ServiceConfig>>,
+    is_query_pending: Cell<bool>,
+    // States of remote blackboards received from the peers, keyed by their service id. The
+    // local replica is created from the state with the next discovery.
+    remote_blackboard_states: RefCell<HashMap<String, BlackboardState>>,
+    service_filter: ServiceFilter,
+    service_remappings: Vec<ServiceRemapping>,
+    throughput_limits: Vec<ThroughputRule>,
+    request_timeout: Duration,
+    // Names and messaging patterns of services announced by the peers. The local services
+    // created for them must not be announced again as local services.
+    remote_services: HashSet<(String, String)>,
+}
+
+impl<Service: iceoryx2::service::Service> Tunnel<Service> {
+    /// Creates a new tunnel with the provided configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `tunnel_config` - Tunnel configuration
+    /// * `iox_config` - Iceoryx configuration to be used
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - A new tunnel instance if creation was successful
+    /// * `Err(CreationError)` - If any part of the tunnel creation failed
+    pub fn create(
+        tunnel_config: &TunnelConfig,
+        iox_config: &IceoryxConfig,
+    ) -> Result<Self, CreationError> {
+        let mut transport: Box<dyn Transport> = match tunnel_config.protocol {
+            Protocol::Udp => Box::new(fail!(
+                from "Tunnel::create()",
+                when UdpTransport::create(tunnel_config.address, tunnel_config.port),
+                with CreationError::Error,
+                "failed to create udp transport"
+            )),
+            Protocol::Tcp => Box::new(fail!(
+                from "Tunnel::create()",
+                when TcpTransport::create(
+                    tunnel_config.address,
+                    tunnel_config.port,
+                    tunnel_config.max_frame_size,
+                    tunnel_config.accept_only_peers
+                ),
+                with CreationError::Error,
+                "failed to create tcp transport"
+            )),
+        };
+        for peer in &tunnel_config.peers {
+            transport.add_peer(*peer);
+        }
+
+        let iox_node = NodeBuilder::new().config(iox_config).create::<Service>();
+        let iox_node = fail!(
+            from "Tunnel::create()",
+            when iox_node,
+            with CreationError::Error,
+            "failed to create node"
+        );
+
+        let iox_discovery =
+            IceoryxDiscovery::create(iox_config, &iox_node, &tunnel_config.discovery_service);
+        let iox_discovery = fail!(
+            from "Tunnel::create()",
+            when iox_discovery,
+            with CreationError::Error,
+            "failed to create iceoryx discovery"
+        );
+
+        let tunnel = Self {
+            transport,
+            iox_node,
+            iox_discovery,
+            publisher_channels: HashMap::new(),
+            subscriber_channels: HashMap::new(),
+            notifier_channels: HashMap::new(),
+            listener_channels: HashMap::new(),
+            client_channels: HashMap::new(),
+            server_channels: HashMap::new(),
+            writer_channels: HashMap::new(),
+            reader_channels: HashMap::new(),
+            announced_services: HashMap::new(),
+            remote_announcements: RefCell::new(Vec::new()),
+            is_query_pending: Cell::new(false),
+            remote_blackboard_states: RefCell::new(HashMap::new()),
+            service_filter: tunnel_config.service_filter.clone(),
+            service_remappings: tunnel_config.service_remappings.clone(),
+            throughput_limits: tunnel_config.throughput_limits.clone(),
+            request_timeout: tunnel_config.request_timeout,
+            remote_services: HashSet::new(),
+        };
+
+        // Query immediately - announcements are processed in the first `discover()` call
+        fail!(
+            from "Tunnel::create()",
+            when tunnel.query(),
+            with CreationError::Error,
+            "failed to query the peers for their services"
+        );
+
+        Ok(tunnel)
+    }
+
+    /// Returns the local port on which the tunnel receives frames from its peers.
+    pub fn port(&self) -> Port {
+        self.transport.port()
+    }
+
+    /// Adds a remote tunnel to exchange services with.
+    pub fn add_peer(&mut self, peer: Peer) {
+        self.transport.add_peer(peer);
+    }
+
+    /// Discover iceoryx services across all connected hosts.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Determines the discovery scope
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If discovery was successful
+    /// * `Err(DiscoveryError)` - If discovery failed
+    pub fn discover(&mut self, scope: Scope) -> Result<(), DiscoveryError> {
+        if scope == Scope::Iceoryx || scope == Scope::Both {
+            fail!(
+                from &self,
+                when self.iox_discovery.discover(&mut |iox_service_config| {
+                    if !self.service_filter.is_allowed(iox_service_config)
+                        || self.remote_services.contains(&service_key(iox_service_config))
+                    {
+                        return Ok(());
+                    }
+
+                    let iox_announced_config = match ServiceRemapping::remap::<Service>(
+                        &self.service_remappings,
+                        iox_service_config,
+                    ) {
+                        Some(iox_announced_config) => iox_announced_config,
+                        None => {
+                            warn!(
+                                from "Tunnel::discover()",
+                                "skipping service {} since its remapped name is invalid",
+                                iox_service_config.name()
+                            );
+                            return Ok(());
+                        }
+                    };
+
+                    on_discovery(
+                        iox_service_config,
+                        &iox_announced_config,
+                        &self.iox_node,
+                        self.transport.as_ref(),
+                        &self.throughput_limits,
+                        self.request_timeout,
+                        &mut self.publisher_channels,
+                        &mut self.subscriber_channels,
+                        &mut self.notifier_channels,
+                        &mut self.listener_channels,
+                        &mut self.client_channels,
+                        &mut self.server_channels,
+                        &mut self.writer_channels,
+                        &mut self.reader_channels,
+                        self.remote_blackboard_states.get_mut(),
+                        &mut self.announced_services,
+                    )
+                }),
+                "failed to discover services via iceoryx"
+            );
+        }
+
+        if scope == Scope::Peers || scope == Scope::Both {
+            fail!(
+                from &self,
+                when self.receive(),
+                with DiscoveryError::UpdateFromRemotePort,
+                "failed to receive service announcements from the peers"
+            );
+
+            for iox_service_config in self.remote_announcements.take() {
+                if !self.service_filter.is_allowed(&iox_service_config) {
+                    continue;
+                }
+                self.remote_services
+                    .insert(service_key(&iox_service_config));
+
+                fail!(
+                    from &self,
+                    when on_discovery(
+                        &iox_service_config,
+                        &iox_service_config,
+                        &self.iox_node,
+                        self.transport.as_ref(),
+                        &self.throughput_limits,
+                        self.request_timeout,
+                        &mut self.publisher_channels,
+                        &mut self.subscriber_channels,
+                        &mut self.notifier_channels,
+                        &mut self.listener_channels,
+                        &mut self.client_channels,
+                        &mut self.server_channels,
+                        &mut self.writer_channels,
+                        &mut self.reader_channels,
+                        self.remote_blackboard_states.get_mut(),
+                        &mut self.announced_services,
+                    ),
+                    "failed to discover services via the peers"
+                );
+            }
+
+            // Query again for the next `discover()` call
+            // NOTE: This results in all services being announced again - not optimal
+            // TODO(optimization): Only request the announcements of services that are new
+            fail!(
+                from &self,
+                when self.query(),
+                with DiscoveryError::UpdateFromRemotePort,
+                "failed to query the peers for their services"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Propagates payloads between all connected hosts.
+    pub fn propagate(&self) -> Result<(), PropagationError> {
+        // Attempted to propagate all channels. Continue to next channel if error encountered.
+        let mut propagation_failure = false;
+        let _ = self.receive().inspect_err(|e| {
+            error!("Failed to receive data from the peers: {e}");
+            propagation_failure = true;
+        });
+
+        let transport = self.transport.as_ref();
+        for (id, channel) in &self.subscriber_channels {
+            let _ = channel.propagate(transport).inspect_err(|e| {
+                error!("Failed to propagate data through subscriber channel with id {id:?}: {e}");
+                propagation_failure = true;
+            });
+        }
+        for (id, channel) in &self.publisher_channels {
+            let _ = channel.propagate(transport).inspect_err(|e| {
+                error!("Failed to propagate data through publisher channel with id {id:?}: {e}");
+                propagation_failure = true;
+            });
+        }
+        for (id, channel) in &self.notifier_channels {
+            let _ = channel.propagate(transport).inspect_err(|e| {
+                error!("Failed to propagate data through notifier channel with id {id:?}: {e}");
+                propagation_failure = true;
+            });
+        }
+        for (id, channel) in &self.listener_channels {
+            let _ = channel.propagate(transport).inspect_err(|e| {
+                error!("Failed to propagate data through listener channel with id {id:?}: {e}");
+                propagation_failure = true;
+            });
+        }
+        for (id, channel) in &self.server_channels {
+            let _ = channel.propagate(transport).inspect_err(|e| {
+                error!("Failed to propagate data through server channel with id {id:?}: {e}");
+                propagation_failure = true;
+            });
+        }
+        for (id, channel) in &self.client_channels {
+            let _ = channel.propagate(transport).inspect_err(|e| {
+                error!("Failed to propagate data through client channel with id {id:?}: {e}");
+                propagation_failure = true;
+            });
+        }
+        for (id, channel) in &self.writer_channels {
+            let _ = channel.propagate(transport).inspect_err(|e| {
+                error!("Failed to propagate data through writer channel with id {id:?}: {e}");
+                propagation_failure = true;
+            });
+        }
+        for (id, channel) in &self.reader_channels {
+            let _ = channel.propagate(transport).inspect_err(|e| {
+                error!("Failed to propagate data through reader channel with id {id:?}: {e}");
+                propagation_failure = true;
+            });
+        }
+
+        if propagation_failure {
+            fail!(from self,
+                with PropagationError::Incomplete,
+                "failure to propagate over all channels");
+        }
+
+        Ok(())
+    }
+
+    /// Returns all currently active channels in the tunnel.
+    pub fn active_channels(&self) -> Vec<ChannelInfo> {
+        let mut ports = Vec::new();
+
+        for id in self.publisher_channels.keys() {
+            ports.push(ChannelInfo::Publisher(id.as_str().to_string()));
+        }
+        for id in self.subscriber_channels.keys() {
+            ports.push(ChannelInfo::Subscriber(id.as_str().to_string()));
+        }
+        for id in self.notifier_channels.keys() {
+            ports.push(ChannelInfo::Notifier(id.as_str().to_string()));
+        }
+        for id in self.listener_channels.keys() {
+            ports.push(ChannelInfo::Listener(id.as_str().to_string()));
+        }
+        for id in self.client_channels.keys() {
+            ports.push(ChannelInfo::Client(id.as_str().to_string()));
+        }
+        for id in self.server_channels.keys() {
+            ports.push(ChannelInfo::Server(id.as_str().to_string()));
+        }
+        for id in self.writer_channels.keys() {
+            ports.push(ChannelInfo::Writer(id.as_str().to_string()));
+        }
+        for id in self.reader_channels.keys() {
+            ports.push(ChannelInfo::Reader(id.as_str().to_string()));
+        }
+
+        ports
+    }
+
+    /// Returns the counters of all publisher channels in the tunnel, keyed by the id of their
+    /// service.
+    pub fn publisher_statistics(&self) -> HashMap<String, PublisherStatistics> {
+        self.publisher_channels
+            .iter()
+            .map(|(id, channel)| (id.as_str().to_string(), channel.statistics()))
+            .collect()
+    }
+
+    /// Requests all peers to announce their services.
+    fn query(&self) -> Result<(), TransportError> {
+        let frame = fail!(
+            from self,
+            when Message::Query.encode(),
+            with TransportError::Send,
+            "failed to encode the query"
+        );
+
+        self.transport.send(&frame)
+    }
+
+    /// Receives all frames that are currently available from the peers and dispatches them.
+    ///
+    /// Payloads, notifications, requests, responses, connection updates and blackboard entries
+    /// are stored in their channels until the next propagation, announcements and blackboard
+    /// states until the next discovery. Queries and requests for services without a server
+    /// channel are answered right away.
+    fn receive(&self) -> Result<(), TransportError> {
+        // Requests that are finalized since no local servers can answer them.
+        let mut unanswerable_requests = Vec::new();
+        // Service ids of local blackboards whose state was requested by the peers.
+        let mut queried_blackboards = Vec::new();
+
+        self.transport.receive(&mut |frame| match Message::decode(frame) {
+            Ok(Message::Announce(iox_service_config)) => {
+                self.remote_announcements
+                    .borrow_mut()
+                    .push(iox_service_config);
+            }
+            Ok(Message::Query) => self.is_query_pending.set(true),
+            Ok(Message::Publish {
+                service_id,
+                payload,
+            }) => match find_channel(&self.subscriber_channels, &service_id) {
+                Some(channel) => channel.receive(payload),
+                None => debug!(from self, "skipping payload for unknown service {}", service_id),
+            },
+            Ok(Message::Notify {
+                service_id,
+                event_id,
+            }) => match find_channel(&self.listener_channels, &service_id) {
+                Some(channel) => channel.receive(event_id as usize),
+                None => {
+                    debug!(from self, "skipping notification for unknown service {}", service_id)
+                }
+            },
+            Ok(Message::Request {
+                service_id,
+                token,
+                payload,
+            }) => match find_channel(&self.server_channels, &service_id) {
+                Some(channel) => channel.receive_request(token, payload),
+                None => unanswerable_requests.push(Message::Finalize { service_id, token }),
+            },
+            Ok(Message::Response {
+                service_id,
+                token,
+                payload,
+            }) => match find_channel(&self.client_channels, &service_id) {
+                Some(channel) => channel.receive_response(&token, payload),
+                None => debug!(from self, "skipping response for unknown service {}", service_id),
+            },
+            Ok(Message::Finalize { service_id, token }) => {
+                match find_channel(&self.client_channels, &service_id) {
+                    Some(channel) => channel.receive_finalize(&token),
+                    None => {
+                        debug!(from self, "skipping finalization for unknown service {}", service_id)
+                    }
+                }
+            }
+            Ok(Message::Disconnect { service_id, token }) => {
+                match find_channel(&self.server_channels, &service_id) {
+                    Some(channel) => channel.receive_disconnect(token),
+                    None => {
+                        debug!(from self, "skipping disconnect for unknown service {}", service_id)
+                    }
+                }
+            }
+            Ok(Message::DisconnectHint { service_id, token }) => {
+                match find_channel(&self.server_channels, &service_id) {
+                    Some(channel) => channel.receive_disconnect_hint(token),
+                    None => {
+                        debug!(from self, "skipping disconnect hint for unknown service {}", service_id)
+                    }
+                }
+            }
+            Ok(Message::BlackboardQuery { service_id }) => {
+                queried_blackboards.push(service_id);
+            }
+            Ok(Message::BlackboardState { service_id, state }) => {
+                if let Some(channel) = find_channel(&self.reader_channels, &service_id) {
+                    channel.receive_state(state);
+                } else if find_channel(&self.writer_channels, &service_id).is_none() {
+                    self.remote_blackboard_states
+                        .borrow_mut()
+                        .insert(service_id, state);
+                }
+            }
+            Ok(Message::BlackboardUpdate {
+                service_id,
+                key,
+                entry,
+            }) => {
+                if let Some(channel) = find_channel(&self.reader_channels, &service_id) {
+                    channel.receive_update(key, entry);
+                } else if let Some(state) =
+                    self.remote_blackboard_states.borrow_mut().get_mut(&service_id)
+                {
+                    // Keeps the state up-to-date until the local replica is created from it.
+                    state.entries.insert(key, entry);
+                } else {
+                    debug!(from self, "skipping blackboard entry for unknown service {}", service_id)
+                }
+            }
+            Ok(Message::BlackboardRemove { service_id, key }) => {
+                if let Some(channel) = find_channel(&self.reader_channels, &service_id) {
+                    channel.receive_remove(key);
+                } else if let Some(state) =
+                    self.remote_blackboard_states.borrow_mut().get_mut(&service_id)
+                {
+                    state.entries.remove(&key);
+                } else {
+                    debug!(from self, "skipping blackboard entry removal for unknown service {}", service_id)
+                }
+            }
+            Err(e) => warn!(from self, "skipping malformed frame from peer ({})", e),
+        })?;
+
+        for message in unanswerable_requests {
+            let frame = fail!(
+                from self,
+                when message.encode(),
+                with TransportError::Send,
+                "failed to encode the finalization of an unanswerable request"
+            );
+            self.transport.send(&frame)?;
+        }
+
+        for service_id in queried_blackboards {
+            match find_channel(&self.writer_channels, &service_id) {
+                Some(channel) => fail!(
+                    from self,
+                    when channel.send_state(self.transport.as_ref()),
+                    with TransportError::Send,
+                    "failed to send the state of blackboard {} to the peers", service_id
+                ),
+                None => {
+                    debug!(from self, "skipping query for unknown blackboard {}", service_id)
+                }
+            }
+        }
+
+        if self.is_query_pending.replace(false) {
+            for iox_service_config in self.announced_services.values() {
+                announce_service(self.transport.as_ref(), iox_service_config)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// TODO(correctness): Proper clean-up in error cases
+
+/// Handles the discovery of a service and creates appropriate channels for it.
+///
+/// This function is called whenever a new service is discovered, either from the local Iceoryx
+/// environment or from the peers. It creates the necessary channels based on the messaging
+/// pattern of the discovered service.
+///
+/// # Arguments
+///
+/// * `iox_service_config` - Configuration of the discovered Iceoryx service
+/// * `iox_announced_config` - Configuration under which the service is announced to the peers,
+///   its service id keys the channels and the data exchanged with the peers
+/// * `iox_node` - The local Iceoryx node to create services on
+/// * `transport` - The transport to announce the service to the peers
+/// * `throughput_limits` - Rules limiting the throughput of publisher channels
+/// * `request_timeout` - Time after which client channels drop requests without a deadline
+/// * `publisher_channels` - Map of existing publisher channels, updated if a new one is created
+/// * `subscriber_channels` - Map of existing subscriber channels, updated if a new one is created
+/// * `notifier_channels` - Map of existing notifier channels, updated if a new one is created
+/// * `listener_channels` - Map of existing listener channels, updated if a new one is created
+/// * `client_channels` - Map of existing client channels, updated if a new one is created
+/// * `server_channels` - Map of existing server channels, updated if a new one is created
+/// * `writer_channels` - Map of existing writer channels, updated if a new one is created
+/// * `reader_channels` - Map of existing reader channels, updated if a new one is created
+/// * `remote_blackboard_states` - States of remote blackboards received from the peers, consumed
+///   when a reader channel is created from them
+/// * `announced_services` - Map of services announced to the peers, updated if a new one is
+///   announced
+#[allow(clippy::too_many_arguments)]
+fn on_discovery<ServiceType: iceoryx2::service::Service>(
+    iox_service_config: &ServiceConfig,
+    iox_announced_config: &ServiceConfig,
+    iox_node: &IceoryxNode<ServiceType>,
+    transport: &dyn Transport,
+    throughput_limits: &[ThroughputRule],
+    request_timeout: Duration,
+    publisher_channels: &mut HashMap<ServiceId, PublisherChannel<ServiceType>>,
+    subscriber_channels: &mut HashMap<ServiceId, SubscriberChannel<ServiceType>>,
+    notifier_channels: &mut HashMap<ServiceId, NotifierChannel<ServiceType>>,
+    listener_channels: &mut HashMap<ServiceId, ListenerChannel<ServiceType>>,
+    client_channels: &mut HashMap<ServiceId, ClientChannel<ServiceType>>,
+    server_channels: &mut HashMap<ServiceId, ServerChannel<ServiceType>>,
+    writer_channels: &mut HashMap<ServiceId, WriterChannel<ServiceType>>,
+    reader_channels: &mut HashMap<ServiceId, ReaderChannel<ServiceType>>,
+    remote_blackboard_states: &mut HashMap<String, BlackboardState>,
+    announced_services: &mut HashMap<ServiceId, ServiceConfig>,
+) -> Result<(), DiscoveryError> {
+    match iox_service_config.messaging_pattern() {
+        MessagingPattern::PublishSubscribe(_) => {
+            fail!(
+                from "on_discovery()",
+                when on_publish_subscribe_service(
+                    iox_node,
+                    iox_service_config,
+                    iox_announced_config,
+                    transport,
+                    throughput_limits,
+                    publisher_channels,
+                    subscriber_channels,
+                    announced_services,
+                ),
+                "failed to process discovered publish-subscribe service"
+            );
+        }
+        MessagingPattern::Event(_) => {
+            fail!(
+                from "on_discovery()",
+                when on_event_service(
+                    iox_node,
+                    iox_service_config,
+                    iox_announced_config,
+                    transport,
+                    notifier_channels,
+                    listener_channels,
+                    announced_services,
+                ),
+                "failed to process discovered event service"
+            );
+        }
+        MessagingPattern::RequestResponse(_) => {
+            fail!(
+                from "on_discovery()",
+                when on_request_response_service(
+                    iox_node,
+                    iox_service_config,
+                    iox_announced_config,
+                    transport,
+                    request_timeout,
+                    client_channels,
+                    server_channels,
+                    announced_services,
+                ),
+                "failed to process discovered request-response service"
+            );
+        }
+        MessagingPattern::Blackboard(_) => {
+            fail!(
+                from "on_discovery()",
+                when on_blackboard_service(
+                    iox_node,
+                    iox_service_config,
+                    iox_announced_config,
+                    transport,
+                    writer_channels,
+                    reader_channels,
+                    remote_blackboard_states,
+                    announced_services,
+                ),
+                "failed to process discovered blackboard service"
+            );
+        }
+        _ => { /* Not supported. Nothing to do. */ }
+    }
+
+    Ok(())
+}
+
+/// Handles the publish-subscribe messaging pattern during service discovery.
+fn on_publish_subscribe_service<ServiceType: iceoryx2::service::Service>(
+    iox_node: &IceoryxNode<ServiceType>,
+    iox_service_config: &ServiceConfig,
+    iox_announced_config: &ServiceConfig,
+    transport: &dyn Transport,
+    throughput_limits: &[ThroughputRule],
+    publisher_channels: &mut HashMap<ServiceId, PublisherChannel<ServiceType>>,
+    subscriber_channels: &mut HashMap<ServiceId, SubscriberChannel<ServiceType>>,
+    announced_services: &mut HashMap<ServiceId, ServiceConfig>,
+) -> Result<(), DiscoveryError> {
+    let iox_service_id = iox_announced_config.service_id();
+    let needs_publisher = !publisher_channels.contains_key(iox_service_id);
+    let needs_subscriber = !subscriber_channels.contains_key(iox_service_id);
+
+    if needs_publisher || needs_subscriber {
+        let iox_service = fail!(
+            from "on_publish_subscribe_service()",
+            when middleware::iceoryx::create_publish_subscribe_service::<ServiceType>(
+                    iox_node,
+                    iox_service_config,
+                ),
+            with DiscoveryError::ServiceCreation,
+            "failed to open or create discovered publish-subscribe service"
+        );
+
+        if needs_publisher {
+            let publisher_channel = fail!(
+                from "on_publish_subscribe_service()",
+                when PublisherChannel::create(
+                    iox_node.id(),
+                    iox_announced_config,
+                    &iox_service,
+                    ThroughputRule::limit_for(throughput_limits, iox_service_config),
+                ),
+                with DiscoveryError::PortCreation,
+                "failed to create publisher channel for discovered service"
+            );
+
+            publisher_channels.insert(iox_service_id.clone(), publisher_channel);
+        }
+        if needs_subscriber {
+            let subscriber_channel = fail!(
+                from "on_publish_subscribe_service()",
+                when SubscriberChannel::create(iox_announced_config, &iox_service),
+                with DiscoveryError::PortCreation,
+                "failed to create subscriber channel for discovered service"
+            );
+
+            subscriber_channels.insert(iox_service_id.clone(), subscriber_channel);
+        }
+
+        announced_services.insert(iox_service_id.clone(), iox_announced_config.clone());
+        fail!(
+            from "on_publish_subscribe_service()",
+            when announce_service(transport, iox_announced_config),
+            with DiscoveryError::ServiceAnnouncement,
+            "failed to announce discovered publish-subscribe service to the peers"
+        );
+    }
+
+    Ok(())
+}
+
+/// Handles the event messaging pattern during service discovery.
+fn on_event_service<ServiceType: iceoryx2::service::Service>(
+    iox_node: &IceoryxNode<ServiceType>,
+    iox_service_config: &ServiceConfig,
+    iox_announced_config: &ServiceConfig,
+    transport: &dyn Transport,
+    notifier_channels: &mut HashMap<ServiceId, NotifierChannel<ServiceType>>,
+    listener_channels: &mut HashMap<ServiceId, ListenerChannel<ServiceType>>,
+    announced_services: &mut HashMap<ServiceId, ServiceConfig>,
+) -> Result<(), DiscoveryError> {
+    let iox_service_id = iox_announced_config.service_id();
+    let needs_notifier = !notifier_channels.contains_key(iox_service_id);
+    let needs_listener = !listener_channels.contains_key(iox_service_id);
+
+    if needs_notifier || needs_listener {
+        let iox_service = fail!(
+            from "on_event_service()",
+            when middleware::iceoryx::create_event_service::<ServiceType>(iox_node, iox_service_config),
+            with DiscoveryError::ServiceCreation,
+            "failed to open or create discovered event service"
+        );
+        if needs_notifier {
+            let notifier_channel = fail!(
+                from "on_event_service()",
+                when NotifierChannel::create(iox_announced_config, &iox_service),
+                with DiscoveryError::PortCreation,
+                "failed to create notifier channel for discovered service"
+            );
+            notifier_channels.insert(iox_service_id.clone(), notifier_channel);
+        }
+        if needs_listener {
+            let listener_channel = fail!(
+                from "on_event_service()",
+                when ListenerChannel::create(iox_announced_config, &iox_service),
+                with DiscoveryError::PortCreation,
+                "failed to create listener channel for discovered service"
+            );
+            listener_channels.insert(iox_service_id.clone(), listener_channel);
+        }
+
+        announced_services.insert(iox_service_id.clone(), iox_announced_config.clone());
+        fail!(
+            from "on_event_service()",
+            when announce_service(transport, iox_announced_config),
+            with DiscoveryError::ServiceAnnouncement,
+            "failed to announce discovered event service to the peers"
+        );
+    }
+
+    Ok(())
+}
+
+/// Handles the request-response messaging pattern during service discovery.
+#[allow(clippy::too_many_arguments)]
+fn on_request_response_service<ServiceType: iceoryx2::service::Service>(
+    iox_node: &IceoryxNode<ServiceType>,
+    iox_service_config: &ServiceConfig,
+    iox_announced_config: &ServiceConfig,
+    transport: &dyn Transport,
+    request_timeout: Duration,
+    client_channels: &mut HashMap<ServiceId, ClientChannel<ServiceType>>,
+    server_channels: &mut HashMap<ServiceId, ServerChannel<ServiceType>>,
+    announced_services: &mut HashMap<ServiceId, ServiceConfig>,
+) -> Result<(), DiscoveryError> {
+    let iox_service_id = iox_announced_config.service_id();
+
+    // The client channel ignores requests of the server channel to prevent loopback, hence
+    // both channels are always created together.
+    if !client_channels.contains_key(iox_service_id) {
+        let iox_service = fail!(
+            from "on_request_response_service()",
+            when middleware::iceoryx::create_request_response_service::<ServiceType>(
+                    iox_node,
+                    iox_service_config,
+                ),
+            with DiscoveryError::ServiceCreation,
+            "failed to open or create discovered request-response service"
+        );
+
+        let server_channel = fail!(
+            from "on_request_response_service()",
+            when ServerChannel::create(iox_announced_config, &iox_service),
+            with DiscoveryError::PortCreation,
+            "failed to create server channel for discovered service"
+        );
+        let client_channel = fail!(
+            from "on_request_response_service()",
+            when ClientChannel::create(
+                iox_announced_config,
+                &iox_service,
+                &server_channel.client_id(),
+                request_timeout,
+            ),
+            with DiscoveryError::PortCreation,
+            "failed to create client channel for discovered service"
+        );
+
+        server_channels.insert(iox_service_id.clone(), server_channel);
+        client_channels.insert(iox_service_id.clone(), client_channel);
+
+        announced_services.insert(iox_service_id.clone(), iox_announced_config.clone());
+        fail!(
+            from "on_request_response_service()",
+            when announce_service(transport, iox_announced_config),
+            with DiscoveryError::ServiceAnnouncement,
+            "failed to announce discovered request-response service to the peers"
+        );
+    }
+
+    Ok(())
+}
+
+/// Handles the blackboard messaging pattern during service discovery.
+///
+/// Local blackboards are propagated to the peers via a writer channel. Remote blackboards are
+/// replicated via a reader channel once a peer provided their state, which is queried otherwise.
+#[allow(clippy::too_many_arguments)]
+fn on_blackboard_service<ServiceType: iceoryx2::service::Service>(
+    iox_node: &IceoryxNode<ServiceType>,
+    iox_service_config: &ServiceConfig,
+    iox_announced_config: &ServiceConfig,
+    transport: &dyn Transport,
+    writer_channels: &mut HashMap<ServiceId, WriterChannel<ServiceType>>,
+    reader_channels: &mut HashMap<ServiceId, ReaderChannel<ServiceType>>,
+    remote_blackboard_states: &mut HashMap<String, BlackboardState>,
+    announced_services: &mut HashMap<ServiceId, ServiceConfig>,
+) -> Result<(), DiscoveryError> {
+    let iox_service_id = iox_announced_config.service_id();
+    if writer_channels.contains_key(iox_service_id) || reader_channels.contains_key(iox_service_id)
+    {
+        return Ok(());
+    }
+
+    // TODO [#817] replace u64 with CustomKeyMarker
+    let key_type_details = iox_service_config.blackboard().type_details();
+    if key_type_details.size() != core::mem::size_of::<u64>()
+        || key_type_details.alignment() != core::mem::align_of::<u64>()
+    {
+        warn!(
+            from "on_blackboard_service()",
+            "skipping blackboard {} [{}] since only keys with the layout of u64 are supported",
+            iox_service_id.as_str(),
+            iox_service_config.name()
+        );
+        return Ok(());
+    }
+
+    match middleware::iceoryx::open_blackboard_service::<ServiceType>(iox_node, iox_service_config)
+    {
+        Ok(iox_service) => {
+            let writer_channel = fail!(
+                from "on_blackboard_service()",
+                when WriterChannel::create(iox_announced_config, &iox_service, transport),
+                with DiscoveryError::PortCreation,
+                "failed to create writer channel for discovered service"
+            );
+            writer_channels.insert(iox_service_id.clone(), writer_channel);
+
+            announced_services.insert(iox_service_id.clone(), iox_announced_config.clone());
+            fail!(
+                from "on_blackboard_service()",
+                when announce_service(transport, iox_announced_config),
+                with DiscoveryError::ServiceAnnouncement,
+                "failed to announce discovered blackboard service to the peers"
+            );
+        }
+        Err(BlackboardOpenError::DoesNotExist) => {
+            match remote_blackboard_states.remove(iox_service_id.as_str()) {
+                Some(state) => {
+                    let reader_channel = fail!(
+                        from "on_blackboard_service()",
+                        when ReaderChannel::create(iox_service_config, iox_node, state),
+                        with DiscoveryError::PortCreation,
+                        "failed to create reader channel for discovered service"
+                    );
+
+                    // The replica is created on a later discovery once a peer provides entries.
+                    if let Some(reader_channel) = reader_channel {
+                        reader_channels.insert(iox_service_id.clone(), reader_channel);
+                    }
+                }
+                None => {
+                    // The replica is created on a later discovery once a peer answered.
+                    fail!(
+                        from "on_blackboard_service()",
+                        when query_blackboard(transport, iox_service_id),
+                        with DiscoveryError::UpdateFromRemotePort,
+                        "failed to query the peers for the state of the discovered blackboard"
+                    );
+                }
+            }
+        }
+        Err(_) => {
+            fail!(
+                from "on_blackboard_service()",
+                with DiscoveryError::ServiceCreation,
+                "failed to open discovered blackboard service"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Requests the peers providing a blackboard to send its state.
+fn query_blackboard(
+    transport: &dyn Transport,
+    iox_service_id: &ServiceId,
+) -> Result<(), TransportError> {
+    let frame = fail!(
+        from "query_blackboard()",
+        when Message::BlackboardQuery {
+            service_id: iox_service_id.as_str().to_string(),
+        }
+        .encode(),
+        with TransportError::Send,
+        "failed to encode the blackboard query"
+    );
+
+    transport.send(&frame)
+}
+
+/// Sends the configuration of a service to all peers.
+fn announce_service(
+    transport: &dyn Transport,
+    iox_service_config: &ServiceConfig,
+) -> Result<(), TransportError> {
+    let frame = fail!(
+        from "announce_service()",
+        when Message::Announce(iox_service_config.clone()).encode(),
+        with TransportError::Send,
+        "failed to encode the service config"
+    );
+
+    transport.send(&frame)
+}
+
+/// Looks up the channel of the service with the provided id as received from a peer.
+fn find_channel<'a, C>(channels: &'a HashMap<ServiceId, C>, service_id: &str) -> Option<&'a C> {
+    channels
+        .iter()
+        .find(|(id, _)| id.as_str() == service_id)
+        .map(|(_, channel)| channel)
+}
+
+/// Identifies a service by its name and messaging pattern, independent of its service id.
+fn service_key(iox_service_config: &ServiceConfig) -> (String, String) {
+    (
+        iox_service_config.name().to_string(),
+        iox_service_config.messaging_pattern().to_string(),
+    )
+}
@@ -0,0 +1,554 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The wire format exchanged between socket tunnels.
+//!
+//! Every message is sent as a single frame consisting of a fixed-size header followed by a
+//! body. All integers are encoded in little endian.
+//!
+//! ```text
+//! +-------+---------+------+-------------+----------------+
+//! | magic | version | kind | body length | body           |
+//! | 4     | 1       | 1    | 4 (u32)     | body length    |
+//! +-------+---------+------+-------------+----------------+
+//! ```
+//!
+//! * `magic` - always `IOX2`
+//! * `version` - [`VERSION`], frames of other versions are rejected
+//! * `kind` - the [`MessageKind`] which defines the layout of the body
+//!
+//! The bodies of the message kinds are:
+//!
+//! * [`MessageKind::Announce`] - the JSON serialized static configuration of a service
+//! * [`MessageKind::Query`] - empty, requests all peers to announce their services again
+//! * [`MessageKind::Publish`] - the service id followed by the raw payload of a sample
+//! * [`MessageKind::Notify`] - the service id followed by the event id as `u64`
+//! * [`MessageKind::Request`] - the service id and the token of the request followed by the
+//!   raw payload of the request
+//! * [`MessageKind::Response`] - the service id and the token of the request followed by the
+//!   raw payload of the response
+//! * [`MessageKind::Finalize`] - the service id and the token of a request the sender will
+//!   not deliver further responses for
+//! * [`MessageKind::Disconnect`] - the service id and the token of a request whose client
+//!   disconnected
+//! * [`MessageKind::DisconnectHint`] - the service id and the token of a request whose client
+//!   wants to disconnect
+//! * [`MessageKind::BlackboardQuery`] - the service id of a blackboard whose state is requested
+//! * [`MessageKind::BlackboardState`] - the service id, the capacity of the blackboard as `u64`
+//!   and the number of entries as `u32` followed by the entries
+//! * [`MessageKind::BlackboardUpdate`] - the service id followed by a single entry
+//! * [`MessageKind::BlackboardRemove`] - the service id followed by the key of the removed entry
+//!   as `u64`
+//!
+//! Service ids and tokens are encoded as their length in bytes as `u16` followed by their UTF-8
+//! representation. Blackboard entries are encoded as their key as `u64`, their JSON serialized
+//! type details as string and their value as its length in bytes as `u32` followed by the raw
+//! value.
+
+use iceoryx2::service::static_config::message_type_details::TypeDetail;
+use iceoryx2::service::static_config::StaticConfig as ServiceConfig;
+
+use std::collections::HashMap;
+
+/// Identifies frames of the socket tunnel.
+pub const MAGIC: [u8; 4] = *b"IOX2";
+
+/// The version of the wire format.
+pub const VERSION: u8 = 1;
+
+/// The size of the frame header in bytes.
+pub const HEADER_SIZE: usize = 10;
+
+/// Errors that can occur when encoding a message.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum EncodeError {
+    /// The static configuration of the announced service or the type details of a blackboard
+    /// entry could not be serialized.
+    Serialization,
+    /// The service id does not fit into the `u16` length field.
+    ServiceIdTooLong,
+    /// The token of the request does not fit into the `u16` length field.
+    TokenTooLong,
+    /// The body does not fit into the `u32` length field of the header.
+    BodyTooLarge,
+}
+
+impl core::fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "EncodeError::{self:?}")
+    }
+}
+
+impl core::error::Error for EncodeError {}
+
+/// Errors that can occur when decoding a frame.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum DecodeError {
+    /// The frame does not start with [`MAGIC`].
+    InvalidMagic,
+    /// The frame was encoded with a different version of the wire format.
+    UnsupportedVersion,
+    /// The kind of the frame is unknown.
+    UnknownKind,
+    /// The frame is shorter than announced by its header.
+    Truncated,
+    /// The body of the frame does not match the layout of its kind.
+    MalformedBody,
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "DecodeError::{self:?}")
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// The kinds of messages exchanged between socket tunnels.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[repr(u8)]
+pub enum MessageKind {
+    Announce = 1,
+    Query = 2,
+    Publish = 3,
+    Notify = 4,
+    Request = 5,
+    Response = 6,
+    Finalize = 7,
+    Disconnect = 8,
+    DisconnectHint = 9,
+    BlackboardQuery = 10,
+    BlackboardState = 11,
+    BlackboardUpdate = 12,
+    BlackboardRemove = 13,
+}
+
+impl MessageKind {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(MessageKind::Announce),
+            2 => Some(MessageKind::Query),
+            3 => Some(MessageKind::Publish),
+            4 => Some(MessageKind::Notify),
+            5 => Some(MessageKind::Request),
+            6 => Some(MessageKind::Response),
+            7 => Some(MessageKind::Finalize),
+            8 => Some(MessageKind::Disconnect),
+            9 => Some(MessageKind::DisconnectHint),
+            10 => Some(MessageKind::BlackboardQuery),
+            11 => Some(MessageKind::BlackboardState),
+            12 => Some(MessageKind::BlackboardUpdate),
+            13 => Some(MessageKind::BlackboardRemove),
+            _ => None,
+        }
+    }
+}
+
+/// The replicated value of a single blackboard entry.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BlackboardEntry {
+    pub type_details: TypeDetail,
+    pub value: Vec<u8>,
+}
+
+/// The replicated state of a blackboard, consisting of its capacity and all of its entries.
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct BlackboardState {
+    pub capacity: usize,
+    pub entries: HashMap<u64, BlackboardEntry>,
+}
+
+/// A message exchanged between socket tunnels.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Message {
+    /// Makes a local service known to the peers.
+    Announce(ServiceConfig),
+    /// Requests the peers to announce all of their services.
+    Query,
+    /// Carries the payload of a sample published on a service.
+    Publish {
+        service_id: String,
+        payload: Vec<u8>,
+    },
+    /// Carries a notification emitted on a service.
+    Notify { service_id: String, event_id: u64 },
+    /// Carries the payload of a request sent on a service. The token identifies the request in
+    /// all subsequent messages.
+    Request {
+        service_id: String,
+        token: String,
+        payload: Vec<u8>,
+    },
+    /// Carries the payload of a response to a request.
+    Response {
+        service_id: String,
+        token: String,
+        payload: Vec<u8>,
+    },
+    /// Signals that the sender will not deliver further responses to a request.
+    Finalize { service_id: String, token: String },
+    /// Signals that the client of a request disconnected, hence its responses are no longer
+    /// required.
+    Disconnect { service_id: String, token: String },
+    /// Signals that the client of a request intends to disconnect.
+    DisconnectHint { service_id: String, token: String },
+    /// Requests the peers providing a blackboard to send its state.
+    BlackboardQuery { service_id: String },
+    /// Carries the capacity and all entries of a blackboard.
+    BlackboardState {
+        service_id: String,
+        state: BlackboardState,
+    },
+    /// Carries the value of a single blackboard entry that was inserted or updated.
+    BlackboardUpdate {
+        service_id: String,
+        key: u64,
+        entry: BlackboardEntry,
+    },
+    /// Signals that a blackboard entry was removed.
+    BlackboardRemove { service_id: String, key: u64 },
+}
+
+impl Message {
+    /// Returns the [`MessageKind`] of the message.
+    pub fn kind(&self) -> MessageKind {
+        match self {
+            Message::Announce(_) => MessageKind::Announce,
+            Message::Query => MessageKind::Query,
+            Message::Publish { .. } => MessageKind::Publish,
+            Message::Notify { .. } => MessageKind::Notify,
+            Message::Request { .. } => MessageKind::Request,
+            Message::Response { .. } => MessageKind::Response,
+            Message::Finalize { .. } => MessageKind::Finalize,
+            Message::Disconnect { .. } => MessageKind::Disconnect,
+            Message::DisconnectHint { .. } => MessageKind::DisconnectHint,
+            Message::BlackboardQuery { .. } => MessageKind::BlackboardQuery,
+            Message::BlackboardState { .. } => MessageKind::BlackboardState,
+            Message::BlackboardUpdate { .. } => MessageKind::BlackboardUpdate,
+            Message::BlackboardRemove { .. } => MessageKind::BlackboardRemove,
+        }
+    }
+
+    /// Encodes the message into a complete frame.
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        let body = match self {
+            Message::Announce(service_config) => {
+                serde_json::to_vec(service_config).map_err(|_| EncodeError::Serialization)?
+            }
+            Message::Query => Vec::new(),
+            Message::Publish {
+                service_id,
+                payload,
+            } => {
+                let mut body = encode_service_id(service_id)?;
+                body.extend_from_slice(payload);
+                body
+            }
+            Message::Notify {
+                service_id,
+                event_id,
+            } => {
+                let mut body = encode_service_id(service_id)?;
+                body.extend_from_slice(&event_id.to_le_bytes());
+                body
+            }
+            Message::Request {
+                service_id,
+                token,
+                payload,
+            }
+            | Message::Response {
+                service_id,
+                token,
+                payload,
+            } => {
+                let mut body = encode_service_id(service_id)?;
+                body.extend_from_slice(&encode_token(token)?);
+                body.extend_from_slice(payload);
+                body
+            }
+            Message::Finalize { service_id, token }
+            | Message::Disconnect { service_id, token }
+            | Message::DisconnectHint { service_id, token } => {
+                let mut body = encode_service_id(service_id)?;
+                body.extend_from_slice(&encode_token(token)?);
+                body
+            }
+            Message::BlackboardQuery { service_id } => encode_service_id(service_id)?,
+            Message::BlackboardState { service_id, state } => {
+                let number_of_entries =
+                    u32::try_from(state.entries.len()).map_err(|_| EncodeError::BodyTooLarge)?;
+
+                let mut body = encode_service_id(service_id)?;
+                body.extend_from_slice(&(state.capacity as u64).to_le_bytes());
+                body.extend_from_slice(&number_of_entries.to_le_bytes());
+                for (key, entry) in &state.entries {
+                    body.extend_from_slice(&encode_blackboard_entry(*key, entry)?);
+                }
+                body
+            }
+            Message::BlackboardUpdate {
+                service_id,
+                key,
+                entry,
+            } => {
+                let mut body = encode_service_id(service_id)?;
+                body.extend_from_slice(&encode_blackboard_entry(*key, entry)?);
+                body
+            }
+            Message::BlackboardRemove { service_id, key } => {
+                let mut body = encode_service_id(service_id)?;
+                body.extend_from_slice(&key.to_le_bytes());
+                body
+            }
+        };
+        let body_length = u32::try_from(body.len()).map_err(|_| EncodeError::BodyTooLarge)?;
+
+        let mut frame = Vec::with_capacity(HEADER_SIZE + body.len());
+        frame.extend_from_slice(&MAGIC);
+        frame.push(VERSION);
+        frame.push(self.kind() as u8);
+        frame.extend_from_slice(&body_length.to_le_bytes());
+        frame.extend_from_slice(&body);
+        Ok(frame)
+    }
+
+    /// Decodes a message from a complete frame.
+    pub fn decode(frame: &[u8]) -> Result<Self, DecodeError> {
+        let length = match frame_length(frame)? {
+            Some(length) if frame.len() >= length => length,
+            _ => return Err(DecodeError::Truncated),
+        };
+
+        let kind = MessageKind::from_u8(frame[5]).ok_or(DecodeError::UnknownKind)?;
+        let body = &frame[HEADER_SIZE..length];
+
+        match kind {
+            MessageKind::Announce => serde_json::from_slice(body)
+                .map(Message::Announce)
+                .map_err(|_| DecodeError::MalformedBody),
+            MessageKind::Query => Ok(Message::Query),
+            MessageKind::Publish => {
+                let (service_id, payload) = decode_service_id(body)?;
+                Ok(Message::Publish {
+                    service_id,
+                    payload: payload.to_vec(),
+                })
+            }
+            MessageKind::Notify => {
+                let (service_id, event_id) = decode_service_id(body)?;
+                let event_id: [u8; 8] = event_id
+                    .try_into()
+                    .map_err(|_| DecodeError::MalformedBody)?;
+                Ok(Message::Notify {
+                    service_id,
+                    event_id: u64::from_le_bytes(event_id),
+                })
+            }
+            MessageKind::Request => {
+                let (service_id, body) = decode_service_id(body)?;
+                let (token, payload) = decode_string(body)?;
+                Ok(Message::Request {
+                    service_id,
+                    token,
+                    payload: payload.to_vec(),
+                })
+            }
+            MessageKind::Response => {
+                let (service_id, body) = decode_service_id(body)?;
+                let (token, payload) = decode_string(body)?;
+                Ok(Message::Response {
+                    service_id,
+                    token,
+                    payload: payload.to_vec(),
+                })
+            }
+            MessageKind::Finalize => {
+                let (service_id, token) = decode_service_id_and_token(body)?;
+                Ok(Message::Finalize { service_id, token })
+            }
+            MessageKind::Disconnect => {
+                let (service_id, token) = decode_service_id_and_token(body)?;
+                Ok(Message::Disconnect { service_id, token })
+            }
+            MessageKind::DisconnectHint => {
+                let (service_id, token) = decode_service_id_and_token(body)?;
+                Ok(Message::DisconnectHint { service_id, token })
+            }
+            MessageKind::BlackboardQuery => {
+                let (service_id, rest) = decode_service_id(body)?;
+                if !rest.is_empty() {
+                    return Err(DecodeError::MalformedBody);
+                }
+                Ok(Message::BlackboardQuery { service_id })
+            }
+            MessageKind::BlackboardState => {
+                let (service_id, body) = decode_service_id(body)?;
+                let (capacity, body) = decode_u64(body)?;
+                let (number_of_entries, mut body) = decode_u32(body)?;
+
+                let mut entries = HashMap::new();
+                for _ in 0..number_of_entries {
+                    let (key, entry, rest) = decode_blackboard_entry(body)?;
+                    entries.insert(key, entry);
+                    body = rest;
+                }
+                if !body.is_empty() {
+                    return Err(DecodeError::MalformedBody);
+                }
+
+                Ok(Message::BlackboardState {
+                    service_id,
+                    state: BlackboardState {
+                        capacity: capacity as usize,
+                        entries,
+                    },
+                })
+            }
+            MessageKind::BlackboardUpdate => {
+                let (service_id, body) = decode_service_id(body)?;
+                let (key, entry, rest) = decode_blackboard_entry(body)?;
+                if !rest.is_empty() {
+                    return Err(DecodeError::MalformedBody);
+                }
+                Ok(Message::BlackboardUpdate {
+                    service_id,
+                    key,
+                    entry,
+                })
+            }
+            MessageKind::BlackboardRemove => {
+                let (service_id, body) = decode_service_id(body)?;
+                let (key, rest) = decode_u64(body)?;
+                if !rest.is_empty() {
+                    return Err(DecodeError::MalformedBody);
+                }
+                Ok(Message::BlackboardRemove { service_id, key })
+            }
+        }
+    }
+}
+
+/// Returns the total length of the frame at the start of the buffer, including the header, or
+/// [`None`] when the buffer does not yet contain the complete header.
+///
+/// Used to split a byte stream into frames.
+pub fn frame_length(buffer: &[u8]) -> Result<Option<usize>, DecodeError> {
+    if buffer.len() < HEADER_SIZE {
+        return Ok(None);
+    }
+    if buffer[0..4] != MAGIC {
+        return Err(DecodeError::InvalidMagic);
+    }
+    if buffer[4] != VERSION {
+        return Err(DecodeError::UnsupportedVersion);
+    }
+
+    let body_length = u32::from_le_bytes([buffer[6], buffer[7], buffer[8], buffer[9]]);
+    Ok(Some(HEADER_SIZE + body_length as usize))
+}
+
+fn encode_service_id(service_id: &str) -> Result<Vec<u8>, EncodeError> {
+    encode_string(service_id).ok_or(EncodeError::ServiceIdTooLong)
+}
+
+fn encode_token(token: &str) -> Result<Vec<u8>, EncodeError> {
+    encode_string(token).ok_or(EncodeError::TokenTooLong)
+}
+
+fn encode_string(value: &str) -> Option<Vec<u8>> {
+    let length = u16::try_from(value.len()).ok()?;
+
+    let mut body = Vec::new();
+    body.extend_from_slice(&length.to_le_bytes());
+    body.extend_from_slice(value.as_bytes());
+    Some(body)
+}
+
+fn encode_blackboard_entry(key: u64, entry: &BlackboardEntry) -> Result<Vec<u8>, EncodeError> {
+    let type_details =
+        serde_json::to_string(&entry.type_details).map_err(|_| EncodeError::Serialization)?;
+    let type_details = encode_string(&type_details).ok_or(EncodeError::Serialization)?;
+    let value_length = u32::try_from(entry.value.len()).map_err(|_| EncodeError::BodyTooLarge)?;
+
+    let mut body = Vec::new();
+    body.extend_from_slice(&key.to_le_bytes());
+    body.extend_from_slice(&type_details);
+    body.extend_from_slice(&value_length.to_le_bytes());
+    body.extend_from_slice(&entry.value);
+    Ok(body)
+}
+
+fn decode_service_id(body: &[u8]) -> Result<(String, &[u8]), DecodeError> {
+    decode_string(body)
+}
+
+// Decodes the body of messages that consist of a service id and a token only.
+fn decode_service_id_and_token(body: &[u8]) -> Result<(String, String), DecodeError> {
+    let (service_id, body) = decode_service_id(body)?;
+    let (token, rest) = decode_string(body)?;
+    if !rest.is_empty() {
+        return Err(DecodeError::MalformedBody);
+    }
+
+    Ok((service_id, token))
+}
+
+fn decode_string(body: &[u8]) -> Result<(String, &[u8]), DecodeError> {
+    if body.len() < 2 {
+        return Err(DecodeError::MalformedBody);
+    }
+
+    let length = u16::from_le_bytes([body[0], body[1]]) as usize;
+    let value = body.get(2..2 + length).ok_or(DecodeError::MalformedBody)?;
+    let value = String::from_utf8(value.to_vec()).map_err(|_| DecodeError::MalformedBody)?;
+
+    Ok((value, &body[2 + length..]))
+}
+
+fn decode_u32(body: &[u8]) -> Result<(u32, &[u8]), DecodeError> {
+    let value: [u8; 4] = body
+        .get(..4)
+        .and_then(|value| value.try_into().ok())
+        .ok_or(DecodeError::MalformedBody)?;
+
+    Ok((u32::from_le_bytes(value), &body[4..]))
+}
+
+fn decode_u64(body: &[u8]) -> Result<(u64, &[u8]), DecodeError> {
+    let value: [u8; 8] = body
+        .get(..8)
+        .and_then(|value| value.try_into().ok())
+        .ok_or(DecodeError::MalformedBody)?;
+
+    Ok((u64::from_le_bytes(value), &body[8..]))
+}
+
+fn decode_blackboard_entry(body: &[u8]) -> Result<(u64, BlackboardEntry, &[u8]), DecodeError> {
+    let (key, body) = decode_u64(body)?;
+    let (type_details, body) = decode_string(body)?;
+    let type_details =
+        serde_json::from_str(&type_details).map_err(|_| DecodeError::MalformedBody)?;
+    let (value_length, body) = decode_u32(body)?;
+    let value = body
+        .get(..value_length as usize)
+        .ok_or(DecodeError::MalformedBody)?;
+
+    Ok((
+        key,
+        BlackboardEntry {
+            type_details,
+            value: value.to_vec(),
+        },
+        &body[value_length as usize..],
+    ))
+}
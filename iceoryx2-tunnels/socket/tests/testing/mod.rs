@@ -0,0 +1,86 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::time::Duration;
+
+use iceoryx2::config::Config;
+use iceoryx2::service::Service;
+use iceoryx2_bb_system_types::ipv4_address;
+use iceoryx2_bb_system_types::port;
+use iceoryx2_bb_testing::test_fail;
+use iceoryx2_tunnels_socket::*;
+
+/// Repeatedly attempts to execute a function until it succeeds or reaches the maximum number of attempts.
+///
+/// Required for operations that involve the network as frames are delivered
+/// asynchronously.
+///
+/// # Arguments
+///
+/// * `f` - A function that returns `Result<(), &'static str>`. The function is considered successful when it returns `Ok(())`.
+/// * `period` - The duration to wait between retry attempts.
+/// * `max_attempts` - An optional maximum number of retry attempts. If `None`, the function will retry indefinitely.
+///
+/// # Behavior
+///
+/// If the function succeeds (returns `Ok(())`), this function returns immediately.
+/// If the function fails and `max_attempts` is reached, this function will call `test_fail!` with the error message.
+/// Otherwise, it will sleep for the specified period and try again.
+pub fn retry<F>(mut f: F, period: Duration, max_attempts: Option<usize>)
+where
+    F: FnMut() -> Result<(), &'static str>,
+{
+    let mut attempt = 0;
+
+    loop {
+        match f() {
+            Ok(_) => return,
+            Err(failure) => {
+                if let Some(max_attempts) = max_attempts {
+                    if attempt >= max_attempts {
+                        test_fail!("{}, after {} attempts", failure, attempt);
+                    }
+                }
+            }
+        }
+
+        std::thread::sleep(period);
+        attempt += 1;
+    }
+}
+
+/// Returns the configuration of a tunnel that listens on a free port of the loopback interface.
+pub fn loopback_tunnel_config(protocol: Protocol) -> TunnelConfig {
+    TunnelConfig {
+        protocol,
+        address: ipv4_address::LOCALHOST,
+        port: port::UNSPECIFIED,
+        ..Default::default()
+    }
+}
+
+/// Creates a tunnel that listens on a free port of the loopback interface.
+pub fn create_loopback_tunnel<S: Service>(protocol: Protocol, iox_config: &Config) -> Tunnel<S> {
+    Tunnel::<S>::create(&loopback_tunnel_config(protocol), iox_config).unwrap()
+}
+
+/// Makes both tunnels peers of each other.
+pub fn connect<S: Service>(tunnel_a: &mut Tunnel<S>, tunnel_b: &mut Tunnel<S>) {
+    tunnel_a.add_peer(Peer {
+        address: ipv4_address::LOCALHOST,
+        port: tunnel_b.port(),
+    });
+    tunnel_b.add_peer(Peer {
+        address: ipv4_address::LOCALHOST,
+        port: tunnel_a.port(),
+    });
+}
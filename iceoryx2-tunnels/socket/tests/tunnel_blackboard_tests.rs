@@ -0,0 +1,266 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod testing;
+
+#[generic_tests::define]
+mod socket_tunnel_blackboard {
+
+    use crate::testing::*;
+
+    use core::cell::Cell;
+    use std::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnels_socket::*;
+
+    const MAX_RETRIES: usize = 25;
+    const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+    fn mock_service_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "test_socket_tunnel_blackboard_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn discover_remote_service<S: Service>(
+        tunnel_a: &mut Tunnel<S>,
+        tunnel_b: &mut Tunnel<S>,
+        service_id: &str,
+    ) {
+        retry(
+            || {
+                // Host A answers the queries of host B
+                tunnel_a.discover(Scope::Peers).unwrap();
+                tunnel_b.discover(Scope::Peers).unwrap();
+
+                if tunnel_b
+                    .active_channels()
+                    .contains(&ChannelInfo::Reader(String::from(service_id)))
+                {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    fn replicates_current_entries_to_late_joining_host<S: Service>(protocol: Protocol) {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a = create_loopback_tunnel::<S>(protocol, &iox_config_a);
+
+        // Service
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 7)
+            .add::<u64>(2, 13)
+            .create()
+            .unwrap();
+        let iox_writer_a = iox_service_a.writer_builder().create().unwrap();
+        iox_writer_a.entry::<u32>(&1).unwrap().update_with_copy(8);
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.active_channels().len(), eq 1);
+
+        // [[ HOST B ]]
+        // Tunnel
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b = create_loopback_tunnel::<S>(protocol, &iox_config_b);
+
+        connect(&mut tunnel_a, &mut tunnel_b);
+
+        // ==================== TEST =====================
+
+        // [[ HOST B ]]
+        // Discover - blackboard should be replicated with the current entries
+        discover_remote_service(
+            &mut tunnel_a,
+            &mut tunnel_b,
+            iox_service_a.service_id().as_str(),
+        );
+
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+        let iox_reader_b = iox_service_b.reader_builder().create().unwrap();
+
+        assert_that!(iox_reader_b.entry::<u32>(&1).unwrap().get(), eq 8);
+        assert_that!(iox_reader_b.entry::<u64>(&2).unwrap().get(), eq 13);
+    }
+
+    fn propagates_entry_updates_to_peer<S: Service>(protocol: Protocol) {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a = create_loopback_tunnel::<S>(protocol, &iox_config_a);
+
+        // Service
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 7)
+            .create()
+            .unwrap();
+        let iox_writer_a = iox_service_a.writer_builder().create().unwrap();
+        let iox_entry_a = iox_writer_a.entry::<u32>(&1).unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b = create_loopback_tunnel::<S>(protocol, &iox_config_b);
+
+        connect(&mut tunnel_a, &mut tunnel_b);
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        discover_remote_service(
+            &mut tunnel_a,
+            &mut tunnel_b,
+            iox_service_a.service_id().as_str(),
+        );
+
+        // Reader
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+        let iox_reader_b = iox_service_b.reader_builder().create().unwrap();
+        let iox_entry_b = iox_reader_b.entry::<u32>(&1).unwrap();
+        assert_that!(iox_entry_b.get(), eq 7);
+
+        // ==================== TEST =====================
+
+        // Updates are repeated with new values since frames are delivered asynchronously.
+        let next_value = Cell::new(100);
+        retry(
+            || {
+                // [[ HOST B ]]
+                // Propagate - update should be received from the peer
+                tunnel_b.propagate().unwrap();
+                if iox_entry_b.get() >= 100 {
+                    return Ok(());
+                }
+
+                // [[ HOST A ]]
+                // Update
+                iox_entry_a.update_with_copy(next_value.get());
+                next_value.set(next_value.get() + 1);
+
+                // Propagate
+                tunnel_a.propagate().unwrap();
+
+                Err("failed to receive update from the peer")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[test]
+    fn discovers_local_services_via_tracker<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let mut tunnel = create_loopback_tunnel::<S>(Protocol::Udp, &iox_config);
+        assert_that!(tunnel.active_channels().len(), eq 0);
+
+        // Service
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 7)
+            .create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Discover
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.active_channels().len(), eq 1);
+        assert_that!(tunnel
+            .active_channels()
+            .contains(&ChannelInfo::Writer(String::from(iox_service.service_id().as_str()))), eq true);
+    }
+
+    #[test]
+    fn replicates_current_entries_to_late_joining_host_over_udp<S: Service>() {
+        replicates_current_entries_to_late_joining_host::<S>(Protocol::Udp);
+    }
+
+    #[test]
+    fn replicates_current_entries_to_late_joining_host_over_tcp<S: Service>() {
+        replicates_current_entries_to_late_joining_host::<S>(Protocol::Tcp);
+    }
+
+    #[test]
+    fn propagates_entry_updates_to_peer_over_udp<S: Service>() {
+        propagates_entry_updates_to_peer::<S>(Protocol::Udp);
+    }
+
+    #[test]
+    fn propagates_entry_updates_to_peer_over_tcp<S: Service>() {
+        propagates_entry_updates_to_peer::<S>(Protocol::Tcp);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
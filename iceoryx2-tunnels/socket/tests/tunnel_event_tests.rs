@@ -0,0 +1,191 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod testing;
+
+#[generic_tests::define]
+mod socket_tunnel_events {
+
+    use crate::testing::*;
+
+    use std::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnels_socket::*;
+
+    const MAX_RETRIES: usize = 25;
+    const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+    fn mock_service_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "test_socket_tunnel_event_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn discover_remote_service<S: Service>(
+        tunnel_a: &mut Tunnel<S>,
+        tunnel_b: &mut Tunnel<S>,
+        service_id: &str,
+    ) {
+        retry(
+            || {
+                // Host A answers the queries of host B
+                tunnel_a.discover(Scope::Peers).unwrap();
+                tunnel_b.discover(Scope::Peers).unwrap();
+
+                let tunneled_ports = tunnel_b.active_channels();
+                let tunneled_notifier =
+                    tunneled_ports.contains(&ChannelInfo::Notifier(String::from(service_id)));
+                let tunneled_listener =
+                    tunneled_ports.contains(&ChannelInfo::Listener(String::from(service_id)));
+
+                if tunneled_notifier && tunneled_listener {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    fn propagates_one_event<S: Service>(protocol: Protocol) {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a = create_loopback_tunnel::<S>(protocol, &iox_config_a);
+
+        // Notifier
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_notifier_a = iox_service_a.notifier_builder().create().unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b = create_loopback_tunnel::<S>(protocol, &iox_config_b);
+
+        connect(&mut tunnel_a, &mut tunnel_b);
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        discover_remote_service(
+            &mut tunnel_a,
+            &mut tunnel_b,
+            iox_service_a.service_id().as_str(),
+        );
+
+        // Listener
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .event()
+            .open_or_create()
+            .unwrap();
+        let iox_listener_b = iox_service_b.listener_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Send notification
+        iox_notifier_a
+            .notify_with_custom_event_id(EventId::new(7))
+            .unwrap();
+
+        // [[ HOST B ]]
+        // Receive with retry
+        retry(
+            || {
+                tunnel_a.propagate().unwrap();
+                tunnel_b.propagate().unwrap();
+
+                match iox_listener_b.try_wait_one().unwrap() {
+                    Some(event_id) if event_id == EventId::new(7) => Ok(()),
+                    Some(_) => Err("received unexpected event"),
+                    None => Err("failed to receive expected event"),
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[test]
+    fn discovers_local_services_via_tracker<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let mut tunnel = create_loopback_tunnel::<S>(Protocol::Udp, &iox_config);
+        assert_that!(tunnel.active_channels().len(), eq 0);
+
+        // Service
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Discover
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        let tunneled_ports = tunnel.active_channels();
+        assert_that!(tunneled_ports.len(), eq 2);
+        assert_that!(tunneled_ports
+            .contains(&ChannelInfo::Notifier(String::from(iox_service.service_id().as_str()))), eq true);
+        assert_that!(tunneled_ports
+            .contains(&ChannelInfo::Listener(String::from(iox_service.service_id().as_str()))), eq true);
+    }
+
+    #[test]
+    fn propagates_one_event_over_udp<S: Service>() {
+        propagates_one_event::<S>(Protocol::Udp);
+    }
+
+    #[test]
+    fn propagates_one_event_over_tcp<S: Service>() {
+        propagates_one_event::<S>(Protocol::Tcp);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,261 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod testing;
+
+#[generic_tests::define]
+mod socket_tunnel_filter {
+
+    use crate::testing::*;
+
+    use std::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnels_socket::*;
+
+    const MAX_RETRIES: usize = 25;
+    const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+    fn mock_service_name(prefix: &str) -> ServiceName {
+        ServiceName::new(&format!(
+            "{}test_socket_tunnel_filter_{}",
+            prefix,
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn excluded_local_services_are_not_propagated<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            service_filter: ServiceFilter {
+                include: vec![],
+                exclude: vec!["name=diagnostics/*".parse().unwrap()],
+            },
+            ..loopback_tunnel_config(Protocol::Udp)
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config).unwrap();
+
+        // Services
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_excluded_service = iox_node
+            .service_builder(&mock_service_name("diagnostics/"))
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_included_service = iox_node
+            .service_builder(&mock_service_name("camera/"))
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Discover - only the included service should be tunneled
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.active_channels().len(), eq 2);
+        assert_that!(tunnel.active_channels().contains(&ChannelInfo::Publisher(String::from(
+            iox_included_service.service_id().as_str()
+        ))), eq true);
+        assert_that!(tunnel.active_channels().contains(&ChannelInfo::Publisher(String::from(
+            iox_excluded_service.service_id().as_str()
+        ))), eq false);
+    }
+
+    #[test]
+    fn excluded_remote_services_are_not_propagated<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a = create_loopback_tunnel::<S>(Protocol::Udp, &iox_config_a);
+
+        // Services
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_excluded_service_a = iox_node_a
+            .service_builder(&mock_service_name("diagnostics/"))
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_included_service_a = iox_node_a
+            .service_builder(&mock_service_name("camera/"))
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel
+        let iox_config_b = generate_isolated_config();
+        let tunnel_config_b = TunnelConfig {
+            service_filter: ServiceFilter {
+                include: vec![],
+                exclude: vec!["name=diagnostics/*".parse().unwrap()],
+            },
+            ..loopback_tunnel_config(Protocol::Udp)
+        };
+        let mut tunnel_b = Tunnel::<S>::create(&tunnel_config_b, &iox_config_b).unwrap();
+
+        connect(&mut tunnel_a, &mut tunnel_b);
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.active_channels().len(), eq 4);
+
+        // [[ HOST B ]]
+        // Discover - only the included service should be tunneled
+        retry(
+            || {
+                // Host A answers the queries of host B
+                tunnel_a.discover(Scope::Peers).unwrap();
+                tunnel_b.discover(Scope::Peers).unwrap();
+
+                if tunnel_b.active_channels().len() == 2 {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        assert_that!(tunnel_b.active_channels().contains(&ChannelInfo::Subscriber(String::from(
+            iox_included_service_a.service_id().as_str()
+        ))), eq true);
+        assert_that!(tunnel_b.active_channels().contains(&ChannelInfo::Subscriber(String::from(
+            iox_excluded_service_a.service_id().as_str()
+        ))), eq false);
+    }
+
+    #[test]
+    fn remapped_services_are_propagated_under_prefixed_name<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name("camera/");
+        let iox_remapped_service_name =
+            ServiceName::new(&format!("robotA/{}", iox_service_name.as_str())).unwrap();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig {
+            service_remappings: vec!["=robotA/".parse().unwrap()],
+            ..loopback_tunnel_config(Protocol::Udp)
+        };
+        let mut tunnel_a = Tunnel::<S>::create(&tunnel_config_a, &iox_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b = create_loopback_tunnel::<S>(Protocol::Udp, &iox_config_b);
+
+        connect(&mut tunnel_a, &mut tunnel_b);
+
+        // [[ HOST A ]]
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.active_channels().len(), eq 2);
+
+        // [[ HOST B ]]
+        // Discover
+        retry(
+            || {
+                // Host A answers the queries of host B
+                tunnel_a.discover(Scope::Peers).unwrap();
+                tunnel_b.discover(Scope::Peers).unwrap();
+
+                if tunnel_b.active_channels().len() == 2 {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        // Subscriber - the remote service is only available under the remapped name
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_remapped_service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        assert_that!(iox_service_b.service_id(), ne iox_service_a.service_id());
+        let iox_subscriber_b = iox_service_b.subscriber_builder().create().unwrap();
+
+        // Discover - the local service for the remote service must not be tunneled again
+        tunnel_b.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_b.active_channels().len(), eq 2);
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Publish
+        iox_publisher_a.send_copy(42).unwrap();
+
+        // [[ HOST B ]]
+        // Receive
+        retry(
+            || {
+                tunnel_a.propagate().unwrap();
+                tunnel_b.propagate().unwrap();
+
+                match iox_subscriber_b.receive().unwrap() {
+                    Some(iox_sample_received_b) if *iox_sample_received_b == 42 => Ok(()),
+                    Some(_) => Err("received unexpected sample"),
+                    None => Err("failed to receive expected sample"),
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
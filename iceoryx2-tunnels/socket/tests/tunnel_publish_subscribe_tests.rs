@@ -0,0 +1,526 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod testing;
+
+#[generic_tests::define]
+mod socket_tunnel_publish_subscribe {
+
+    use crate::testing::*;
+
+    use std::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnels_socket::*;
+
+    const MAX_RETRIES: usize = 25;
+    const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+    // Exceeds the maximum size of a UDP datagram.
+    const LARGE_PAYLOAD_LENGTH: usize = 16 * 1024;
+
+    fn mock_service_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "test_socket_tunnel_publish_subscribe_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn discover_remote_service<S: Service>(
+        tunnel_a: &mut Tunnel<S>,
+        tunnel_b: &mut Tunnel<S>,
+        service_id: &str,
+    ) {
+        retry(
+            || {
+                // Host A answers the queries of host B
+                tunnel_a.discover(Scope::Peers).unwrap();
+                tunnel_b.discover(Scope::Peers).unwrap();
+
+                let tunneled_ports = tunnel_b.active_channels();
+                let tunneled_publisher =
+                    tunneled_ports.contains(&ChannelInfo::Publisher(String::from(service_id)));
+                let tunneled_subscriber =
+                    tunneled_ports.contains(&ChannelInfo::Subscriber(String::from(service_id)));
+
+                if tunneled_publisher && tunneled_subscriber {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    fn propagates_payload<S: Service>(protocol: Protocol) {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a = create_loopback_tunnel::<S>(protocol, &iox_config_a);
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b = create_loopback_tunnel::<S>(protocol, &iox_config_b);
+
+        connect(&mut tunnel_a, &mut tunnel_b);
+
+        // [[ HOST A ]]
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.active_channels().len(), eq 2);
+
+        // [[ HOST B ]]
+        // Discover
+        discover_remote_service(
+            &mut tunnel_a,
+            &mut tunnel_b,
+            iox_service_a.service_id().as_str(),
+        );
+
+        // Subscriber
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let iox_subscriber_b = iox_service_b.subscriber_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Publish
+        iox_publisher_a.send_copy(42).unwrap();
+
+        // [[ HOST B ]]
+        // Receive
+        retry(
+            || {
+                tunnel_a.propagate().unwrap();
+                tunnel_b.propagate().unwrap();
+
+                match iox_subscriber_b.receive().unwrap() {
+                    Some(iox_sample_received_b) if *iox_sample_received_b == 42 => Ok(()),
+                    Some(_) => Err("received unexpected sample"),
+                    None => Err("failed to receive expected sample"),
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[test]
+    fn discovers_local_services_via_tracker<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let mut tunnel = create_loopback_tunnel::<S>(Protocol::Udp, &iox_config);
+        assert_that!(tunnel.active_channels().len(), eq 0);
+
+        // Service
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Discover
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        let tunneled_ports = tunnel.active_channels();
+        assert_that!(tunneled_ports.len(), eq 2);
+        assert_that!(tunneled_ports
+            .contains(&ChannelInfo::Publisher(String::from(iox_service.service_id().as_str()))), eq true);
+        assert_that!(tunneled_ports
+            .contains(&ChannelInfo::Subscriber(String::from(iox_service.service_id().as_str()))), eq true);
+    }
+
+    #[test]
+    fn propagates_payload_over_udp<S: Service>() {
+        propagates_payload::<S>(Protocol::Udp);
+    }
+
+    #[test]
+    fn propagates_payload_over_tcp<S: Service>() {
+        propagates_payload::<S>(Protocol::Tcp);
+    }
+
+    #[test]
+    fn propagated_payloads_do_not_loop_back<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a = create_loopback_tunnel::<S>(Protocol::Udp, &iox_config_a);
+
+        // Publisher and Subscriber
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+        let iox_subscriber_a = iox_service_a.subscriber_builder().create().unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b = create_loopback_tunnel::<S>(Protocol::Udp, &iox_config_b);
+
+        connect(&mut tunnel_a, &mut tunnel_b);
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        discover_remote_service(
+            &mut tunnel_a,
+            &mut tunnel_b,
+            iox_service_a.service_id().as_str(),
+        );
+
+        // Subscriber
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let iox_subscriber_b = iox_service_b.subscriber_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Publish
+        iox_publisher_a.send_copy(42).unwrap();
+        assert_that!(*iox_subscriber_a.receive().unwrap().unwrap(), eq 42);
+
+        // [[ HOST B ]]
+        // Receive
+        retry(
+            || {
+                tunnel_a.propagate().unwrap();
+                tunnel_b.propagate().unwrap();
+
+                match iox_subscriber_b.receive().unwrap() {
+                    Some(iox_sample_received_b) if *iox_sample_received_b == 42 => Ok(()),
+                    Some(_) => Err("received unexpected sample"),
+                    None => Err("failed to receive expected sample"),
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        // [[ HOST A ]]
+        // The sample must not be received again via host B
+        tunnel_b.propagate().unwrap();
+        std::thread::sleep(TIME_BETWEEN_RETRIES);
+        tunnel_a.propagate().unwrap();
+        let iox_sample_received_a = iox_subscriber_a.receive().unwrap();
+        assert_that!(iox_sample_received_a, is_none);
+    }
+
+    #[test]
+    fn propagates_large_payload_over_tcp<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a = create_loopback_tunnel::<S>(Protocol::Tcp, &iox_config_a);
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<[u64; LARGE_PAYLOAD_LENGTH]>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b = create_loopback_tunnel::<S>(Protocol::Tcp, &iox_config_b);
+
+        connect(&mut tunnel_a, &mut tunnel_b);
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        discover_remote_service(
+            &mut tunnel_a,
+            &mut tunnel_b,
+            iox_service_a.service_id().as_str(),
+        );
+
+        // Subscriber
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<[u64; LARGE_PAYLOAD_LENGTH]>()
+            .open()
+            .unwrap();
+        let iox_subscriber_b = iox_service_b.subscriber_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Publish
+        let iox_sample_a = iox_publisher_a.loan_uninit().unwrap();
+        let iox_sample_a = iox_sample_a.write_payload([42; LARGE_PAYLOAD_LENGTH]);
+        iox_sample_a.send().unwrap();
+
+        // [[ HOST B ]]
+        // Receive
+        retry(
+            || {
+                tunnel_a.propagate().unwrap();
+                tunnel_b.propagate().unwrap();
+
+                match iox_subscriber_b.receive().unwrap() {
+                    Some(iox_sample_received_b)
+                        if iox_sample_received_b.iter().all(|value| *value == 42) =>
+                    {
+                        Ok(())
+                    }
+                    Some(_) => Err("received unexpected sample"),
+                    None => Err("failed to receive expected sample"),
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[test]
+    fn payload_exceeding_max_frame_size_is_dropped_over_tcp<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a = create_loopback_tunnel::<S>(Protocol::Tcp, &iox_config_a);
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<[u64; LARGE_PAYLOAD_LENGTH]>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel - accepts announcements but no large payloads
+        let iox_config_b = generate_isolated_config();
+        let tunnel_config_b = TunnelConfig {
+            max_frame_size: LARGE_PAYLOAD_LENGTH,
+            ..loopback_tunnel_config(Protocol::Tcp)
+        };
+        let mut tunnel_b = Tunnel::<S>::create(&tunnel_config_b, &iox_config_b).unwrap();
+
+        connect(&mut tunnel_a, &mut tunnel_b);
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        discover_remote_service(
+            &mut tunnel_a,
+            &mut tunnel_b,
+            iox_service_a.service_id().as_str(),
+        );
+
+        // Subscriber
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<[u64; LARGE_PAYLOAD_LENGTH]>()
+            .open()
+            .unwrap();
+        let iox_subscriber_b = iox_service_b.subscriber_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Publish
+        let iox_sample_a = iox_publisher_a.loan_uninit().unwrap();
+        let iox_sample_a = iox_sample_a.write_payload([42; LARGE_PAYLOAD_LENGTH]);
+        iox_sample_a.send().unwrap();
+
+        // [[ HOST B ]]
+        // Receive - the frame exceeds the maximum frame size of host B
+        for _ in 0..3 {
+            tunnel_a.propagate().unwrap();
+            std::thread::sleep(TIME_BETWEEN_RETRIES);
+            tunnel_b.propagate().unwrap();
+        }
+        let iox_sample_received_b = iox_subscriber_b.receive().unwrap();
+        assert_that!(iox_sample_received_b, is_none);
+    }
+
+    #[test]
+    fn large_payload_is_rejected_over_udp<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config_a = generate_isolated_config();
+        let mut tunnel_a = create_loopback_tunnel::<S>(Protocol::Udp, &iox_config_a);
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<[u64; LARGE_PAYLOAD_LENGTH]>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel
+        let iox_config_b = generate_isolated_config();
+        let mut tunnel_b = create_loopback_tunnel::<S>(Protocol::Udp, &iox_config_b);
+
+        connect(&mut tunnel_a, &mut tunnel_b);
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Publish
+        let iox_sample_a = iox_publisher_a.loan_uninit().unwrap();
+        let iox_sample_a = iox_sample_a.write_payload([42; LARGE_PAYLOAD_LENGTH]);
+        iox_sample_a.send().unwrap();
+
+        // Propagate - the payload does not fit into a datagram
+        let result = tunnel_a.propagate();
+        assert_that!(result, is_err);
+    }
+
+    #[test]
+    fn samples_exceeding_throughput_limit_are_dropped<S: Service>() {
+        const NUMBER_OF_SAMPLES: usize = 8;
+
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            throughput_limits: vec!["max_rate=3".parse().unwrap()],
+            ..loopback_tunnel_config(Protocol::Udp)
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config).unwrap();
+
+        // Publisher
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Publish more samples than the limit allows
+        for n in 0..NUMBER_OF_SAMPLES {
+            iox_publisher.send_copy(n as u64).unwrap();
+        }
+        tunnel.propagate().unwrap();
+
+        let statistics = tunnel.publisher_statistics()[iox_service.service_id().as_str()];
+        assert_that!(statistics.propagated_samples, eq 3);
+        assert_that!(statistics.propagated_bytes, eq 3 * core::mem::size_of::<u64>() as u64);
+        assert_that!(statistics.dropped_samples, eq NUMBER_OF_SAMPLES as u64 - 3);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
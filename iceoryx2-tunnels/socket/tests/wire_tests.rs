@@ -0,0 +1,195 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod socket_tunnel_wire {
+    use std::collections::HashMap;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::static_config::message_type_details::TypeDetail;
+    use iceoryx2::service::static_config::message_type_details::TypeVariant;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnels_socket::wire::*;
+
+    fn round_trip(message: &Message) -> Message {
+        let frame = message.encode().unwrap();
+        assert_that!(frame_length(&frame), eq Ok(Some(frame.len())));
+        Message::decode(&frame).unwrap()
+    }
+
+    #[test]
+    fn messages_survive_round_trip() {
+        let query = Message::Query;
+        assert_that!(round_trip(&query), eq query);
+
+        let publish = Message::Publish {
+            service_id: String::from("some_service_id"),
+            payload: vec![1, 2, 3, 4],
+        };
+        assert_that!(round_trip(&publish), eq publish);
+
+        let notify = Message::Notify {
+            service_id: String::from("some_service_id"),
+            event_id: 42,
+        };
+        assert_that!(round_trip(&notify), eq notify);
+    }
+
+    #[test]
+    fn request_response_messages_survive_round_trip() {
+        let service_id = String::from("some_service_id");
+        let token = String::from("some_token");
+
+        let request = Message::Request {
+            service_id: service_id.clone(),
+            token: token.clone(),
+            payload: vec![1, 2, 3, 4],
+        };
+        assert_that!(round_trip(&request), eq request);
+
+        let response = Message::Response {
+            service_id: service_id.clone(),
+            token: token.clone(),
+            payload: vec![5, 6, 7, 8],
+        };
+        assert_that!(round_trip(&response), eq response);
+
+        let finalize = Message::Finalize {
+            service_id: service_id.clone(),
+            token: token.clone(),
+        };
+        assert_that!(round_trip(&finalize), eq finalize);
+
+        let disconnect = Message::Disconnect {
+            service_id: service_id.clone(),
+            token: token.clone(),
+        };
+        assert_that!(round_trip(&disconnect), eq disconnect);
+
+        let disconnect_hint = Message::DisconnectHint { service_id, token };
+        assert_that!(round_trip(&disconnect_hint), eq disconnect_hint);
+    }
+
+    #[test]
+    fn blackboard_messages_survive_round_trip() {
+        let service_id = String::from("some_service_id");
+        let entry = BlackboardEntry {
+            type_details: TypeDetail::new::<u32>(TypeVariant::FixedSize),
+            value: 7u32.to_le_bytes().to_vec(),
+        };
+
+        let query = Message::BlackboardQuery {
+            service_id: service_id.clone(),
+        };
+        assert_that!(round_trip(&query), eq query);
+
+        let state = Message::BlackboardState {
+            service_id: service_id.clone(),
+            state: BlackboardState {
+                capacity: 3,
+                entries: HashMap::from([
+                    (1, entry.clone()),
+                    (
+                        2,
+                        BlackboardEntry {
+                            type_details: TypeDetail::new::<u64>(TypeVariant::FixedSize),
+                            value: 13u64.to_le_bytes().to_vec(),
+                        },
+                    ),
+                ]),
+            },
+        };
+        assert_that!(round_trip(&state), eq state);
+
+        let update = Message::BlackboardUpdate {
+            service_id: service_id.clone(),
+            key: 1,
+            entry,
+        };
+        assert_that!(round_trip(&update), eq update);
+
+        let remove = Message::BlackboardRemove { service_id, key: 1 };
+        assert_that!(round_trip(&remove), eq remove);
+    }
+
+    #[test]
+    fn announcement_survives_round_trip() {
+        let iox_config = generate_isolated_config();
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<local::Service>()
+            .unwrap();
+        let iox_service_name = ServiceName::new(&format!(
+            "test_socket_tunnel_wire_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap();
+        let iox_service = iox_node
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        let announce = Message::Announce(iox_service.static_config().clone());
+        assert_that!(round_trip(&announce), eq announce);
+    }
+
+    #[test]
+    fn frame_length_requires_complete_header() {
+        let frame = Message::Query.encode().unwrap();
+
+        assert_that!(frame_length(&frame[..HEADER_SIZE - 1]), eq Ok(None));
+        assert_that!(frame_length(&frame[..HEADER_SIZE]), eq Ok(Some(HEADER_SIZE)));
+    }
+
+    #[test]
+    fn frames_with_invalid_header_are_rejected() {
+        let mut frame = Message::Query.encode().unwrap();
+        frame[0] = b'X';
+        assert_that!(Message::decode(&frame), eq Err(DecodeError::InvalidMagic));
+
+        let mut frame = Message::Query.encode().unwrap();
+        frame[4] = VERSION + 1;
+        assert_that!(Message::decode(&frame), eq Err(DecodeError::UnsupportedVersion));
+
+        let mut frame = Message::Query.encode().unwrap();
+        frame[5] = 0;
+        assert_that!(Message::decode(&frame), eq Err(DecodeError::UnknownKind));
+    }
+
+    #[test]
+    fn truncated_frames_are_rejected() {
+        let frame = Message::Notify {
+            service_id: String::from("some_service_id"),
+            event_id: 42,
+        }
+        .encode()
+        .unwrap();
+
+        assert_that!(Message::decode(&frame[..frame.len() - 1]), eq Err(DecodeError::Truncated));
+    }
+
+    #[test]
+    fn frames_with_malformed_body_are_rejected() {
+        let mut frame = Message::Notify {
+            service_id: String::from("some_service_id"),
+            event_id: 42,
+        }
+        .encode()
+        .unwrap();
+        // Announce a service id that is longer than the body
+        frame[HEADER_SIZE] = 0xff;
+
+        assert_that!(Message::decode(&frame), eq Err(DecodeError::MalformedBody));
+    }
+}
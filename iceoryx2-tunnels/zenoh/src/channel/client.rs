@@ -0,0 +1,332 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::middleware;
+use crate::middleware::zenoh::ConnectionUpdate;
+use crate::Channel;
+use crate::PropagationError;
+
+use iceoryx2::active_request::ActiveRequest as IceoryxActiveRequest;
+use iceoryx2::port::port_identifiers::UniqueClientId as IceoryxClientId;
+use iceoryx2::port::server::Server as IceoryxServer;
+use iceoryx2::service::builder::CustomHeaderMarker;
+use iceoryx2::service::builder::CustomPayloadMarker;
+use iceoryx2::service::port_factory::request_response::PortFactory as IceoryxRequestResponseService;
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::info;
+use iceoryx2_bb_log::warn;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+
+use zenoh::bytes::ZBytes;
+use zenoh::handlers::FifoChannelHandler;
+use zenoh::pubsub::Publisher as ZenohPublisher;
+use zenoh::query::Reply;
+use zenoh::Session as ZenohSession;
+use zenoh::Wait;
+
+use core::cell::Cell;
+use core::cell::RefCell;
+use core::time::Duration;
+
+// TODO: More granularity in errors
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// A local request that was forwarded to remote hosts and whose responses are still awaited.
+#[derive(Debug)]
+struct ForwardedRequest<ServiceType: iceoryx2::service::Service> {
+    token: String,
+    iox_active_request: IceoryxActiveRequest<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    z_replies: FifoChannelHandler<Reply>,
+    has_forwarded_disconnect_hint: bool,
+}
+
+/// A channel for propagating `iceoryx2` requests of local clients to remote servers and their
+/// responses back to the local clients.
+#[derive(Debug)]
+pub(crate) struct ClientChannel<'a, ServiceType: iceoryx2::service::Service> {
+    iox_service_config: IceoryxServiceConfig,
+    iox_server: IceoryxServer<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    iox_tunnel_client_id: IceoryxClientId,
+    z_session: ZenohSession,
+    z_connection_publisher: ZenohPublisher<'a>,
+    forwarded_requests: RefCell<Vec<ForwardedRequest<ServiceType>>>,
+    request_counter: Cell<u64>,
+}
+
+impl<ServiceType: iceoryx2::service::Service> ClientChannel<'_, ServiceType> {
+    // Creates an outbound channel to remote hosts for requests for a particular service.
+    //
+    // Requests sent by the client with the id `iox_tunnel_client_id` are ignored since they
+    // originate from remote hosts.
+    pub fn create(
+        iox_service_config: &IceoryxServiceConfig,
+        iox_service: &IceoryxRequestResponseService<
+            ServiceType,
+            [CustomPayloadMarker],
+            CustomHeaderMarker,
+            [CustomPayloadMarker],
+            CustomHeaderMarker,
+        >,
+        iox_tunnel_client_id: &IceoryxClientId,
+        z_session: &ZenohSession,
+    ) -> Result<Self, CreationError> {
+        info!(
+            "CREATE ClientChannel {} [{}]",
+            iox_service_config.service_id().as_str(),
+            iox_service_config.name()
+        );
+
+        let iox_server = fail!(
+            from "ClientChannel::create()",
+            when middleware::iceoryx::create_server::<ServiceType>(iox_service),
+            with CreationError::Error,
+            "failed to create iceoryx server to propagate local requests"
+        );
+        let z_connection_publisher = fail!(
+            from "ClientChannel::create()",
+            when middleware::zenoh::create_connection_publisher(z_session, iox_service_config),
+            with CreationError::Error,
+            "failed to create zenoh publisher for connection updates of local requests"
+        );
+
+        Ok(Self {
+            iox_service_config: iox_service_config.clone(),
+            iox_server,
+            iox_tunnel_client_id: *iox_tunnel_client_id,
+            z_session: z_session.clone(),
+            z_connection_publisher,
+            forwarded_requests: RefCell::new(Vec::new()),
+            request_counter: Cell::new(0),
+        })
+    }
+
+    // Returns the time until the deadline of the request, the remote servers cannot deliver
+    // responses the client still expects after it.
+    fn time_until_deadline_of(
+        iox_active_request: &IceoryxActiveRequest<
+            ServiceType,
+            [CustomPayloadMarker],
+            CustomHeaderMarker,
+            [CustomPayloadMarker],
+            CustomHeaderMarker,
+        >,
+    ) -> Option<Duration> {
+        iox_active_request.header().deadline().map(|deadline| {
+            let now = Time::now_with_clock(ClockType::Monotonic)
+                .map(|now| now.as_duration())
+                .unwrap_or_default();
+            deadline.saturating_sub(now)
+        })
+    }
+
+    fn forward_requests(&self) -> Result<(), PropagationError> {
+        loop {
+            match unsafe { self.iox_server.receive_custom_payload() } {
+                Ok(Some(iox_active_request)) => {
+                    if iox_active_request.origin() == self.iox_tunnel_client_id {
+                        // Ignore requests sent by the tunnel itself to prevent loopback.
+                        continue;
+                    }
+
+                    let token = format!(
+                        "{}.{}",
+                        self.iox_server.id().value(),
+                        self.request_counter.get()
+                    );
+                    self.request_counter.set(self.request_counter.get() + 1);
+
+                    let ptr = iox_active_request.payload().as_ptr() as *const u8;
+                    let len = iox_active_request.payload().len();
+                    let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+
+                    // TODO(optimization): Is it possible to create the ZBytes struct without copy?
+                    let z_payload = ZBytes::from(bytes);
+                    let z_replies = fail!(
+                        from self,
+                        when middleware::zenoh::send_request(
+                            &self.z_session,
+                            &self.iox_service_config,
+                            &token,
+                            z_payload,
+                            Self::time_until_deadline_of(&iox_active_request),
+                        ),
+                        with PropagationError::OtherPort,
+                        "failed to propagate local request to remote hosts"
+                    );
+
+                    self.forwarded_requests.borrow_mut().push(ForwardedRequest {
+                        token,
+                        iox_active_request,
+                        z_replies,
+                        has_forwarded_disconnect_hint: false,
+                    });
+
+                    info!(
+                        "PROPAGATE ClientChannel {} [{}]",
+                        self.iox_service_config.service_id().as_str(),
+                        self.iox_service_config.name()
+                    );
+                }
+                Ok(None) => break, // No more requests available
+                Err(e) => {
+                    fatal_panic!("failed to receive custom payload: {e}");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn publish_connection_update(&self, update: ConnectionUpdate) -> Result<(), PropagationError> {
+        fail!(
+            from self,
+            when self.z_connection_publisher.put(update.to_bytes()).wait(),
+            with PropagationError::OtherPort,
+            "failed to propagate connection update of local request to remote hosts"
+        );
+
+        Ok(())
+    }
+
+    // Forwards all responses received so far for the request and returns whether the remote
+    // hosts have finalized the request.
+    fn forward_responses(
+        &self,
+        request: &ForwardedRequest<ServiceType>,
+    ) -> Result<bool, PropagationError> {
+        let iox_payload_size = self
+            .iox_service_config
+            .request_response()
+            .response_message_type_details()
+            .payload
+            .size();
+
+        loop {
+            match request.z_replies.try_recv() {
+                Ok(Some(z_reply)) => {
+                    let z_sample = match z_reply.result() {
+                        Ok(z_sample) => z_sample,
+                        Err(e) => {
+                            warn!(from self, "skipping erroneous response from remote host: {e:?}");
+                            continue;
+                        }
+                    };
+
+                    // TODO(correctness): verify size and alignment
+                    let z_payload = z_sample.payload();
+                    let number_of_elements = z_payload.len() / iox_payload_size;
+                    unsafe {
+                        match request
+                            .iox_active_request
+                            .loan_custom_payload(number_of_elements)
+                        {
+                            Ok(mut iox_response) => {
+                                core::ptr::copy_nonoverlapping(
+                                    z_payload.to_bytes().as_ptr(),
+                                    iox_response.payload_mut().as_mut_ptr() as *mut u8,
+                                    z_payload.len(),
+                                );
+                                let iox_response = iox_response.assume_init();
+                                fail!(
+                                    from self,
+                                    when iox_response.send(),
+                                    with PropagationError::IceoryxPort,
+                                    "failed to send remote response to local client"
+                                );
+
+                                info!(
+                                    "PROPAGATE ClientChannel {} [{}]",
+                                    self.iox_service_config.service_id().as_str(),
+                                    self.iox_service_config.name()
+                                );
+                            }
+                            Err(e) => {
+                                fatal_panic!("failed to loan custom payload: {e}");
+                            }
+                        }
+                    }
+                }
+                Ok(None) => return Ok(false), // No more responses available
+                Err(_) => return Ok(true),    // All remote hosts finalized the request
+            }
+        }
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Channel for ClientChannel<'_, ServiceType> {
+    /// Propagate local requests to remote hosts and the remote responses back to local clients.
+    fn propagate(&self) -> Result<(), PropagationError> {
+        self.forward_requests()?;
+
+        let mut result = Ok(());
+        let mut forwarded_requests = self.forwarded_requests.borrow_mut();
+        forwarded_requests.retain_mut(|request| {
+            if !request.iox_active_request.is_connected() {
+                // Release the request on the remote hosts, the local client is gone.
+                if let Err(e) = self.publish_connection_update(ConnectionUpdate::Disconnected(
+                    request.token.clone(),
+                )) {
+                    result = Err(e);
+                }
+                return false;
+            }
+
+            if request.iox_active_request.has_disconnect_hint()
+                && !request.has_forwarded_disconnect_hint
+            {
+                match self.publish_connection_update(ConnectionUpdate::DisconnectHint(
+                    request.token.clone(),
+                )) {
+                    Ok(()) => request.has_forwarded_disconnect_hint = true,
+                    Err(e) => result = Err(e),
+                }
+            }
+
+            match self.forward_responses(request) {
+                // Dropping the active request signals the local client that no more
+                // responses will arrive.
+                Ok(is_finalized) => !is_finalized,
+                Err(e) => {
+                    result = Err(e);
+                    true
+                }
+            }
+        });
+
+        result
+    }
+}
@@ -22,6 +22,18 @@ pub use listener::*;
 mod notifier;
 pub use notifier::*;
 
+mod client;
+pub use client::*;
+
+mod server;
+pub use server::*;
+
+mod writer;
+pub use writer::*;
+
+mod reader;
+pub use reader::*;
+
 /// Represents errors that can occur during the propagation process in a channel.
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub enum PropagationError {
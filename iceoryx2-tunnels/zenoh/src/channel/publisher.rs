@@ -13,6 +13,9 @@
 use crate::middleware;
 use crate::Channel;
 use crate::PropagationError;
+use crate::PublisherStatistics;
+use crate::Throttle;
+use crate::ThroughputLimit;
 
 use iceoryx2::node::NodeId as IceoryxNodeId;
 use iceoryx2::port::subscriber::Subscriber as IceoryxSubscriber;
@@ -29,6 +32,10 @@ use zenoh::pubsub::Publisher as ZenohPublisher;
 use zenoh::Session as ZenohSession;
 use zenoh::Wait;
 
+use core::cell::Cell;
+use core::cell::RefCell;
+use std::time::Instant;
+
 // TODO: More granularity in errors
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub enum CreationError {
@@ -50,6 +57,10 @@ pub(crate) struct PublisherChannel<'a, ServiceType: iceoryx2::service::Service>
     iox_service_config: IceoryxServiceConfig,
     iox_subscriber: IceoryxSubscriber<ServiceType, [CustomPayloadMarker], CustomHeaderMarker>,
     z_publisher: ZenohPublisher<'a>,
+    throttle: RefCell<Throttle>,
+    // The latest sample held back until the downsampling period elapsed.
+    pending_payload: RefCell<Option<Vec<u8>>>,
+    statistics: Cell<PublisherStatistics>,
 }
 
 impl<ServiceType: iceoryx2::service::Service> PublisherChannel<'_, ServiceType> {
@@ -64,6 +75,7 @@ impl<ServiceType: iceoryx2::service::Service> PublisherChannel<'_, ServiceType>
             CustomHeaderMarker,
         >,
         z_session: &ZenohSession,
+        throughput_limit: ThroughputLimit,
     ) -> Result<Self, CreationError> {
         info!(
             "CREATE PublisherChannel  {} [{}]",
@@ -89,13 +101,53 @@ impl<ServiceType: iceoryx2::service::Service> PublisherChannel<'_, ServiceType>
             iox_service_config: iox_service_config.clone(),
             iox_subscriber,
             z_publisher,
+            throttle: RefCell::new(Throttle::new(throughput_limit)),
+            pending_payload: RefCell::new(None),
+            statistics: Cell::new(PublisherStatistics::default()),
         })
     }
+
+    // Returns the counters of the samples handled by the channel.
+    pub fn statistics(&self) -> PublisherStatistics {
+        self.statistics.get()
+    }
+
+    fn put(&self, bytes: &[u8]) -> Result<(), PropagationError> {
+        // TODO(optimization): Is it possible to create the ZBytes struct without copy?
+        let z_payload = ZBytes::from(bytes);
+        fail!(
+            from self,
+            when self.z_publisher.put(z_payload).wait(),
+            with PropagationError::OtherPort,
+            "failed to propagate local payload to remote hosts"
+        );
+
+        let mut statistics = self.statistics.get();
+        statistics.propagated_samples += 1;
+        statistics.propagated_bytes += bytes.len() as u64;
+        self.statistics.set(statistics);
+
+        info!(
+            "PROPAGATE PublisherChannel {} [{}]",
+            self.iox_service_config.service_id().as_str(),
+            self.iox_service_config.name()
+        );
+
+        Ok(())
+    }
+
+    fn drop_sample(&self) {
+        let mut statistics = self.statistics.get();
+        statistics.dropped_samples += 1;
+        self.statistics.set(statistics);
+    }
 }
 
 impl<ServiceType: iceoryx2::service::Service> Channel for PublisherChannel<'_, ServiceType> {
     /// Propagate local publish-subscribe payloads to remote hosts.
     fn propagate(&self) -> Result<(), PropagationError> {
+        let is_downsampled = self.throttle.borrow().limit().downsample_period.is_some();
+
         loop {
             match unsafe { self.iox_subscriber.receive_custom_payload() } {
                 Ok(Some(sample)) => {
@@ -108,20 +160,16 @@ impl<ServiceType: iceoryx2::service::Service> Channel for PublisherChannel<'_, S
                     let len = sample.len();
                     let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
 
-                    // TODO(optimization): Is it possible to create the ZBytes struct without copy?
-                    let z_payload = ZBytes::from(bytes);
-                    fail!(
-                        from &self,
-                        when self.z_publisher.put(z_payload).wait(),
-                        with PropagationError::OtherPort,
-                        "failed to propagate local payload to remote hosts"
-                    );
-
-                    info!(
-                        "PROPAGATE PublisherChannel {} [{}]",
-                        self.iox_service_config.service_id().as_str(),
-                        self.iox_service_config.name()
-                    );
+                    if is_downsampled {
+                        // Only the latest sample is propagated once the period elapsed.
+                        if self.pending_payload.replace(Some(bytes.to_vec())).is_some() {
+                            self.drop_sample();
+                        }
+                    } else if self.throttle.borrow_mut().admit(Instant::now(), len) {
+                        self.put(bytes)?;
+                    } else {
+                        self.drop_sample();
+                    }
                 }
                 Ok(None) => break, // No more samples available
                 Err(e) => {
@@ -130,6 +178,18 @@ impl<ServiceType: iceoryx2::service::Service> Channel for PublisherChannel<'_, S
             }
         }
 
+        let mut pending_payload = self.pending_payload.borrow_mut();
+        if let Some(bytes) = pending_payload.as_deref() {
+            if self
+                .throttle
+                .borrow_mut()
+                .admit(Instant::now(), bytes.len())
+            {
+                self.put(bytes)?;
+                *pending_payload = None;
+            }
+        }
+
         Ok(())
     }
 }
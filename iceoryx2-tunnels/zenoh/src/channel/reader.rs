@@ -0,0 +1,254 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::middleware;
+use crate::middleware::zenoh::BlackboardEntry;
+use crate::Channel;
+use crate::PropagationError;
+
+use iceoryx2::node::Node as IceoryxNode;
+use iceoryx2::port::writer::__InternalEntryHandleMut as IceoryxEntryHandleMut;
+use iceoryx2::port::writer::Writer as IceoryxWriter;
+use iceoryx2::service::port_factory::blackboard::PortFactory as IceoryxBlackboardService;
+use iceoryx2::service::static_config::message_type_details::TypeDetail;
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::info;
+use iceoryx2_bb_log::warn;
+
+use zenoh::handlers::FifoChannelHandler;
+use zenoh::pubsub::Subscriber as ZenohSubscriber;
+use zenoh::sample::Sample;
+use zenoh::sample::SampleKind;
+use zenoh::Session as ZenohSession;
+
+use core::cell::RefCell;
+use std::collections::HashMap;
+
+// TODO: More granularity in errors
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// A local replica of a remote blackboard entry.
+#[derive(Debug)]
+struct ReplicaEntry<ServiceType: iceoryx2::service::Service> {
+    type_details: TypeDetail,
+    iox_entry_handle: IceoryxEntryHandleMut<ServiceType>,
+}
+
+/// A channel for replicating the entries of a remote `iceoryx2` blackboard to local readers.
+#[derive(Debug)]
+pub(crate) struct ReaderChannel<ServiceType: iceoryx2::service::Service> {
+    iox_service_config: IceoryxServiceConfig,
+    // Keeps the replicated blackboard alive.
+    _iox_service: IceoryxBlackboardService<ServiceType, u64>,
+    iox_writer: IceoryxWriter<ServiceType, u64>,
+    z_subscriber: ZenohSubscriber<FifoChannelHandler<Sample>>,
+    replica_entries: RefCell<HashMap<u64, ReplicaEntry<ServiceType>>>,
+}
+
+impl<ServiceType: iceoryx2::service::Service> ReaderChannel<ServiceType> {
+    // Creates an inbound channel from remote hosts for the entries of a particular blackboard.
+    //
+    // The local replica of the blackboard is created with the current entries of the remote
+    // hosts. Returns `None` when no remote host provides entries (yet).
+    pub fn create(
+        iox_service_config: &IceoryxServiceConfig,
+        iox_node: &IceoryxNode<ServiceType>,
+        z_session: &ZenohSession,
+    ) -> Result<Option<Self>, CreationError> {
+        // Subscribe before querying the state to not miss updates that are sent in between.
+        let z_subscriber = fail!(
+            from "ReaderChannel::create()",
+            when middleware::zenoh::create_blackboard_subscriber(z_session, iox_service_config),
+            with CreationError::Error,
+            "failed to create zenoh subscriber to receive remote blackboard entries"
+        );
+        let state = fail!(
+            from "ReaderChannel::create()",
+            when middleware::zenoh::query_blackboard_state(z_session, iox_service_config),
+            with CreationError::Error,
+            "failed to query the state of the remote blackboard"
+        );
+        if state.entries.is_empty() {
+            // A blackboard cannot be created without entries.
+            return Ok(None);
+        }
+
+        info!(
+            "CREATE ReaderChannel {} [{}]",
+            iox_service_config.service_id().as_str(),
+            iox_service_config.name()
+        );
+
+        let iox_service = fail!(
+            from "ReaderChannel::create()",
+            when middleware::iceoryx::create_blackboard_service::<ServiceType>(
+                iox_node,
+                iox_service_config,
+                &state
+            ),
+            with CreationError::Error,
+            "failed to create local replica of remote blackboard"
+        );
+        let iox_writer = fail!(
+            from "ReaderChannel::create()",
+            when middleware::iceoryx::create_writer::<ServiceType>(&iox_service),
+            with CreationError::Error,
+            "failed to create iceoryx writer to deliver remote blackboard entries"
+        );
+
+        let mut replica_entries = HashMap::new();
+        for (key, entry) in state.entries {
+            let iox_entry_handle = fail!(
+                from "ReaderChannel::create()",
+                when iox_writer.__internal_entry(&key, &entry.type_details),
+                with CreationError::Error,
+                "failed to acquire handle for replicated blackboard entry {}", key
+            );
+            replica_entries.insert(
+                key,
+                ReplicaEntry {
+                    type_details: entry.type_details,
+                    iox_entry_handle,
+                },
+            );
+        }
+
+        Ok(Some(Self {
+            iox_service_config: iox_service_config.clone(),
+            _iox_service: iox_service,
+            iox_writer,
+            z_subscriber,
+            replica_entries: RefCell::new(replica_entries),
+        }))
+    }
+
+    fn remove_entry(&self, key: u64) -> Result<(), PropagationError> {
+        // The handle must be released before the entry can be removed.
+        if self.replica_entries.borrow_mut().remove(&key).is_some() {
+            fail!(
+                from self,
+                when self.iox_writer.remove(&key),
+                with PropagationError::IceoryxPort,
+                "failed to remove replicated blackboard entry {}", key
+            );
+        }
+
+        Ok(())
+    }
+
+    fn insert_entry(&self, key: u64, entry: BlackboardEntry) -> Result<(), PropagationError> {
+        let (internals, _, _) = middleware::iceoryx::blackboard_entry_internals(key, &entry);
+        fail!(
+            from self,
+            when self.iox_writer.__internal_insert(internals),
+            with PropagationError::IceoryxPort,
+            "failed to insert replicated blackboard entry {}", key
+        );
+
+        let iox_entry_handle = fail!(
+            from self,
+            when self.iox_writer.__internal_entry(&key, &entry.type_details),
+            with PropagationError::IceoryxPort,
+            "failed to acquire handle for replicated blackboard entry {}", key
+        );
+        self.replica_entries.borrow_mut().insert(
+            key,
+            ReplicaEntry {
+                type_details: entry.type_details,
+                iox_entry_handle,
+            },
+        );
+
+        Ok(())
+    }
+
+    fn update_entry(&self, key: u64, entry: BlackboardEntry) -> Result<(), PropagationError> {
+        if !entry.has_matching_size() {
+            warn!(from self,
+                "skipping blackboard entry {} from remote host since its value has {} bytes but its type requires {} bytes",
+                key, entry.value.len(), entry.type_details.size());
+            return Ok(());
+        }
+
+        let has_matching_type = self
+            .replica_entries
+            .borrow()
+            .get(&key)
+            .map(|replica| replica.type_details == entry.type_details);
+
+        match has_matching_type {
+            Some(true) => {
+                let replica_entries = self.replica_entries.borrow();
+                let handle = &replica_entries[&key].iox_entry_handle;
+                let size = entry.type_details.size();
+                let alignment = entry.type_details.alignment();
+                unsafe {
+                    let ptr = handle.__internal_get_ptr_to_write_cell(size, alignment);
+                    core::ptr::copy_nonoverlapping(entry.value.as_ptr(), ptr, size);
+                    handle.__internal_update_write_cell();
+                }
+                Ok(())
+            }
+            Some(false) => {
+                // The entry was replaced on the remote host with a value of a different type.
+                self.remove_entry(key)?;
+                self.insert_entry(key, entry)
+            }
+            None => self.insert_entry(key, entry),
+        }
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Channel for ReaderChannel<ServiceType> {
+    /// Propagate remote blackboard entries to local readers.
+    fn propagate(&self) -> Result<(), PropagationError> {
+        for z_sample in self.z_subscriber.drain() {
+            match z_sample.kind() {
+                SampleKind::Delete => match middleware::zenoh::blackboard_entry_key(&z_sample) {
+                    Some(key) => self.remove_entry(key)?,
+                    None => {
+                        warn!(from self, "skipping malformed blackboard entry removal from remote host");
+                        continue;
+                    }
+                },
+                SampleKind::Put => match middleware::zenoh::deserialize_blackboard_entry(&z_sample)
+                {
+                    Some((key, _, entry)) => self.update_entry(key, entry)?,
+                    None => {
+                        warn!(from self, "skipping malformed blackboard entry from remote host");
+                        continue;
+                    }
+                },
+            }
+
+            info!(
+                "PROPAGATE ReaderChannel {} [{}]",
+                self.iox_service_config.service_id().as_str(),
+                self.iox_service_config.name()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,305 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::middleware;
+use crate::middleware::zenoh::ConnectionUpdate;
+use crate::Channel;
+use crate::PropagationError;
+
+use iceoryx2::pending_response::PendingResponse as IceoryxPendingResponse;
+use iceoryx2::port::client::Client as IceoryxClient;
+use iceoryx2::port::port_identifiers::UniqueClientId as IceoryxClientId;
+use iceoryx2::service::builder::CustomHeaderMarker;
+use iceoryx2::service::builder::CustomPayloadMarker;
+use iceoryx2::service::port_factory::request_response::PortFactory as IceoryxRequestResponseService;
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::info;
+use iceoryx2_bb_log::warn;
+
+use zenoh::bytes::ZBytes;
+use zenoh::handlers::FifoChannelHandler;
+use zenoh::pubsub::Subscriber as ZenohSubscriber;
+use zenoh::query::Query;
+use zenoh::query::Queryable as ZenohQueryable;
+use zenoh::sample::Sample;
+use zenoh::Session as ZenohSession;
+use zenoh::Wait;
+
+use core::cell::RefCell;
+
+// TODO: More granularity in errors
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// A remote request that was delivered to local servers and whose responses are still
+/// streamed back to the remote host.
+#[derive(Debug)]
+struct DeliveredRequest<ServiceType: iceoryx2::service::Service> {
+    token: Option<String>,
+    iox_pending_response: IceoryxPendingResponse<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    // Dropping the query finalizes the request on the remote host.
+    z_query: Query,
+}
+
+/// A channel for propagating remote `iceoryx2` requests to local servers and their responses
+/// back to the remote clients.
+#[derive(Debug)]
+pub(crate) struct ServerChannel<ServiceType: iceoryx2::service::Service> {
+    iox_service_config: IceoryxServiceConfig,
+    iox_client: IceoryxClient<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    z_queryable: ZenohQueryable<FifoChannelHandler<Query>>,
+    z_connection_subscriber: ZenohSubscriber<FifoChannelHandler<Sample>>,
+    delivered_requests: RefCell<Vec<DeliveredRequest<ServiceType>>>,
+}
+
+impl<ServiceType: iceoryx2::service::Service> ServerChannel<ServiceType> {
+    // Creates an inbound channel from remote hosts for requests for a particular service.
+    pub fn create(
+        iox_service_config: &IceoryxServiceConfig,
+        iox_service: &IceoryxRequestResponseService<
+            ServiceType,
+            [CustomPayloadMarker],
+            CustomHeaderMarker,
+            [CustomPayloadMarker],
+            CustomHeaderMarker,
+        >,
+        z_session: &ZenohSession,
+    ) -> Result<Self, CreationError> {
+        info!(
+            "CREATE ServerChannel {} [{}]",
+            iox_service_config.service_id().as_str(),
+            iox_service_config.name()
+        );
+
+        let iox_client = fail!(
+            from "ServerChannel::create()",
+            when middleware::iceoryx::create_client::<ServiceType>(iox_service),
+            with CreationError::Error,
+            "failed to create iceoryx client to propagate remote requests to local servers"
+        );
+        let z_queryable = fail!(
+            from "ServerChannel::create()",
+            when middleware::zenoh::create_queryable(z_session, iox_service_config),
+            with CreationError::Error,
+            "failed to create zenoh queryable to receive remote requests"
+        );
+        let z_connection_subscriber = fail!(
+            from "ServerChannel::create()",
+            when middleware::zenoh::create_connection_subscriber(z_session, iox_service_config),
+            with CreationError::Error,
+            "failed to create zenoh subscriber to receive connection updates of remote requests"
+        );
+
+        Ok(Self {
+            iox_service_config: iox_service_config.clone(),
+            iox_client,
+            z_queryable,
+            z_connection_subscriber,
+            delivered_requests: RefCell::new(Vec::new()),
+        })
+    }
+
+    /// Returns the id of the iceoryx client that delivers remote requests to local servers.
+    pub fn client_id(&self) -> IceoryxClientId {
+        self.iox_client.id()
+    }
+
+    fn deliver_requests(&self) -> Result<(), PropagationError> {
+        let iox_payload_size = self
+            .iox_service_config
+            .request_response()
+            .request_message_type_details()
+            .payload
+            .size();
+
+        for z_query in self.z_queryable.drain() {
+            let z_payload = match z_query.payload() {
+                Some(z_payload) => z_payload.to_bytes(),
+                None => {
+                    warn!(from self, "skipping remote request without payload");
+                    continue;
+                }
+            };
+            let token = z_query
+                .attachment()
+                .and_then(|z_attachment| z_attachment.try_to_string().ok())
+                .map(|token| token.to_string());
+
+            // TODO(correctness): verify size and alignment
+            let number_of_elements = z_payload.len() / iox_payload_size;
+            unsafe {
+                match self.iox_client.loan_custom_payload(number_of_elements) {
+                    Ok(mut iox_request) => {
+                        core::ptr::copy_nonoverlapping(
+                            z_payload.as_ptr(),
+                            iox_request.payload_mut().as_mut_ptr() as *mut u8,
+                            z_payload.len(),
+                        );
+                        let iox_request = iox_request.assume_init();
+                        // The local servers shall see the deadline of the remote client.
+                        let send_result = match middleware::zenoh::time_until_deadline_of(&z_query)
+                        {
+                            Some(timeout) => iox_request.send_with_deadline(timeout),
+                            None => iox_request.send(),
+                        };
+                        let iox_pending_response = fail!(
+                            from self,
+                            when send_result,
+                            with PropagationError::IceoryxPort,
+                            "failed to send remote request to local servers"
+                        );
+
+                        self.delivered_requests.borrow_mut().push(DeliveredRequest {
+                            token,
+                            iox_pending_response,
+                            z_query,
+                        });
+
+                        info!(
+                            "PROPAGATE ServerChannel {} [{}]",
+                            self.iox_service_config.service_id().as_str(),
+                            self.iox_service_config.name()
+                        );
+                    }
+                    Err(e) => {
+                        // The local servers cannot take more requests, e.g. since the tunnel
+                        // client exceeds its maximum number of active requests. Reject the
+                        // remote request instead, dropping the query finalizes it.
+                        warn!(from self, "rejecting remote request since it cannot be delivered to local servers: {e}");
+                        if let Err(e) = z_query
+                            .reply_err(ZBytes::from(format!(
+                                "request rejected by remote host: {e}"
+                            )))
+                            .wait()
+                        {
+                            warn!(from self, "failed to reject remote request: {e}");
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apply_connection_updates(&self) {
+        let mut delivered_requests = self.delivered_requests.borrow_mut();
+
+        for z_sample in self.z_connection_subscriber.drain() {
+            match ConnectionUpdate::from_bytes(&z_sample.payload().to_bytes()) {
+                Some(ConnectionUpdate::DisconnectHint(token)) => {
+                    if let Some(request) = delivered_requests
+                        .iter()
+                        .find(|request| request.token.as_ref() == Some(&token))
+                    {
+                        request.iox_pending_response.set_disconnect_hint();
+                    }
+                }
+                Some(ConnectionUpdate::Disconnected(token)) => {
+                    delivered_requests.retain(|request| request.token.as_ref() != Some(&token));
+                }
+                None => {
+                    warn!(from self, "skipping malformed connection update from remote host");
+                }
+            }
+        }
+    }
+
+    // Replies with all responses received so far for the request and returns whether the local
+    // servers will not send any further responses.
+    fn reply_responses(
+        &self,
+        request: &DeliveredRequest<ServiceType>,
+    ) -> Result<bool, PropagationError> {
+        // Checked before receiving to not miss responses that arrive in between.
+        let is_connected = request.iox_pending_response.is_connected();
+
+        loop {
+            match unsafe { request.iox_pending_response.receive_custom_payload() } {
+                Ok(Some(iox_response)) => {
+                    let ptr = iox_response.payload().as_ptr() as *const u8;
+                    let len = iox_response.payload().len();
+                    let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+
+                    // TODO(optimization): Is it possible to create the ZBytes struct without copy?
+                    let z_payload = ZBytes::from(bytes);
+                    fail!(
+                        from self,
+                        when request
+                            .z_query
+                            .reply(request.z_query.key_expr().clone(), z_payload)
+                            .wait(),
+                        with PropagationError::OtherPort,
+                        "failed to propagate local response to remote client"
+                    );
+
+                    info!(
+                        "PROPAGATE ServerChannel {} [{}]",
+                        self.iox_service_config.service_id().as_str(),
+                        self.iox_service_config.name()
+                    );
+                }
+                Ok(None) => break, // No more responses available
+                Err(e) => {
+                    fatal_panic!("failed to receive custom payload: {e}");
+                }
+            }
+        }
+
+        Ok(!is_connected)
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Channel for ServerChannel<ServiceType> {
+    /// Propagate remote requests to local servers and the local responses back to remote hosts.
+    fn propagate(&self) -> Result<(), PropagationError> {
+        self.deliver_requests()?;
+        self.apply_connection_updates();
+
+        let mut result = Ok(());
+        self.delivered_requests.borrow_mut().retain(|request| {
+            match self.reply_responses(request) {
+                Ok(is_finalized) => !is_finalized,
+                Err(e) => {
+                    result = Err(e);
+                    true
+                }
+            }
+        });
+
+        result
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::middleware;
+use crate::middleware::zenoh::BlackboardEntry;
+use crate::middleware::zenoh::BlackboardState;
+use crate::Channel;
+use crate::PropagationError;
+
+use iceoryx2::port::reader::__InternalEntryHandle as IceoryxEntryHandle;
+use iceoryx2::port::reader::Reader as IceoryxReader;
+use iceoryx2::service::port_factory::blackboard::PortFactory as IceoryxBlackboardService;
+use iceoryx2::service::static_config::message_type_details::TypeDetail;
+use iceoryx2::service::static_config::message_type_details::TypeVariant;
+use iceoryx2::service::static_config::StaticConfig as IceoryxServiceConfig;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::info;
+use iceoryx2_bb_log::warn;
+
+use zenoh::query::Queryable as ZenohQueryable;
+use zenoh::Session as ZenohSession;
+
+use core::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+// TODO: More granularity in errors
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CreationError {
+    Error,
+}
+
+impl core::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> std::fmt::Result {
+        core::write!(f, "CreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for CreationError {}
+
+/// A local blackboard entry whose value is replicated to remote hosts.
+#[derive(Debug)]
+struct ReplicatedEntry<ServiceType: iceoryx2::service::Service> {
+    type_details: TypeDetail,
+    iox_entry_handle: IceoryxEntryHandle<ServiceType>,
+    // The write generation of the last propagated value, `None` when it was not propagated yet.
+    write_generation: Option<u32>,
+}
+
+/// A channel for propagating the entries of a local `iceoryx2` blackboard to remote hosts.
+#[derive(Debug)]
+pub(crate) struct WriterChannel<ServiceType: iceoryx2::service::Service> {
+    iox_service_config: IceoryxServiceConfig,
+    iox_reader: IceoryxReader<ServiceType, u64>,
+    z_session: ZenohSession,
+    _z_queryable: ZenohQueryable<()>,
+    replicated_entries: RefCell<HashMap<u64, ReplicatedEntry<ServiceType>>>,
+    state: Arc<Mutex<BlackboardState>>,
+}
+
+impl<ServiceType: iceoryx2::service::Service> WriterChannel<ServiceType> {
+    // Creates an outbound channel to remote hosts for the entries of a particular blackboard.
+    //
+    // Remote hosts that join later receive the current entries via a query.
+    pub fn create(
+        iox_service_config: &IceoryxServiceConfig,
+        iox_service: &IceoryxBlackboardService<ServiceType, u64>,
+        z_session: &ZenohSession,
+    ) -> Result<Self, CreationError> {
+        info!(
+            "CREATE WriterChannel {} [{}]",
+            iox_service_config.service_id().as_str(),
+            iox_service_config.name()
+        );
+
+        let iox_reader = fail!(
+            from "WriterChannel::create()",
+            when middleware::iceoryx::create_reader::<ServiceType>(iox_service),
+            with CreationError::Error,
+            "failed to create iceoryx reader to propagate local blackboard entries"
+        );
+        let state = Arc::new(Mutex::new(BlackboardState {
+            capacity: iox_reader.__internal_capacity(),
+            entries: HashMap::new(),
+        }));
+        let z_queryable = fail!(
+            from "WriterChannel::create()",
+            when middleware::zenoh::create_blackboard_queryable(
+                z_session,
+                iox_service_config,
+                state.clone()
+            ),
+            with CreationError::Error,
+            "failed to create zenoh queryable to share blackboard entries with remote hosts"
+        );
+
+        let channel = Self {
+            iox_service_config: iox_service_config.clone(),
+            iox_reader,
+            z_session: z_session.clone(),
+            _z_queryable: z_queryable,
+            replicated_entries: RefCell::new(HashMap::new()),
+            state,
+        };
+        fail!(
+            from "WriterChannel::create()",
+            when channel.synchronize_key_set(),
+            with CreationError::Error,
+            "failed to acquire the entries of the local blackboard"
+        );
+        // Provides the current values to remote hosts that already joined.
+        fail!(
+            from "WriterChannel::create()",
+            when channel.propagate(),
+            with CreationError::Error,
+            "failed to propagate the entries of the local blackboard"
+        );
+
+        Ok(channel)
+    }
+
+    // Acquires handles to newly inserted entries and informs remote hosts about removed entries.
+    fn synchronize_key_set(&self) -> Result<(), PropagationError> {
+        let entries = self.iox_reader.__internal_entries();
+        let mut replicated_entries = self.replicated_entries.borrow_mut();
+
+        let removed_keys: Vec<u64> = replicated_entries
+            .keys()
+            .filter(|key| !entries.iter().any(|(k, _)| k == *key))
+            .copied()
+            .collect();
+        for key in removed_keys {
+            replicated_entries.remove(&key);
+            if let Ok(mut state) = self.state.lock() {
+                state.entries.remove(&key);
+            }
+            fail!(
+                from self,
+                when middleware::zenoh::delete_blackboard_entry(
+                    &self.z_session,
+                    &self.iox_service_config,
+                    key
+                ),
+                with PropagationError::OtherPort,
+                "failed to propagate removal of local blackboard entry to remote hosts"
+            );
+        }
+
+        for (key, type_details) in entries {
+            if replicated_entries
+                .get(&key)
+                .is_some_and(|entry| entry.type_details == type_details)
+            {
+                continue;
+            }
+
+            if type_details.variant() != TypeVariant::FixedSize {
+                // TODO(functionality): Support replication of slice entries
+                warn!(from self, "skipping blackboard entry {} with dynamically sized value", key);
+                continue;
+            }
+
+            let iox_entry_handle = fail!(
+                from self,
+                when self.iox_reader.__internal_entry(&key, &type_details),
+                with PropagationError::IceoryxPort,
+                "failed to acquire handle for local blackboard entry {}", key
+            );
+            replicated_entries.insert(
+                key,
+                ReplicatedEntry {
+                    type_details,
+                    iox_entry_handle,
+                    write_generation: None,
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl<ServiceType: iceoryx2::service::Service> Channel for WriterChannel<ServiceType> {
+    /// Propagate changed values of local blackboard entries to remote hosts.
+    ///
+    /// Only values whose write generation changed since the last propagation are copied.
+    fn propagate(&self) -> Result<(), PropagationError> {
+        if self.iox_reader.has_key_set_changed() {
+            self.synchronize_key_set()?;
+        }
+
+        let capacity = self.iox_reader.__internal_capacity();
+        for (key, entry) in self.replicated_entries.borrow_mut().iter_mut() {
+            // Acquired before the value is copied, so that an update in between is propagated
+            // again in the next iteration instead of being missed.
+            let write_generation = entry.iox_entry_handle.write_generation();
+            if entry.write_generation == Some(write_generation) {
+                continue;
+            }
+
+            let size = entry.type_details.size();
+            let alignment = entry.type_details.alignment();
+            let mut value = vec![0u8; size];
+            unsafe {
+                entry
+                    .iox_entry_handle
+                    .get(value.as_mut_ptr(), size, alignment)
+            };
+
+            let replicated_entry = BlackboardEntry {
+                type_details: entry.type_details.clone(),
+                value,
+            };
+            fail!(
+                from self,
+                when middleware::zenoh::put_blackboard_entry(
+                    &self.z_session,
+                    &self.iox_service_config,
+                    capacity,
+                    *key,
+                    &replicated_entry
+                ),
+                with PropagationError::OtherPort,
+                "failed to propagate local blackboard entry to remote hosts"
+            );
+            entry.write_generation = Some(write_generation);
+
+            if let Ok(mut state) = self.state.lock() {
+                state.capacity = capacity;
+                state.entries.insert(*key, replicated_entry);
+            }
+
+            info!(
+                "PROPAGATE WriterChannel {} [{}]",
+                self.iox_service_config.service_id().as_str(),
+                self.iox_service_config.name()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -112,7 +112,10 @@ fn discover_via_subscriber<
             Ok(Some(sample)) => {
                 if let DiscoveryUpdate::Added(service_config) = sample.payload() {
                     match service_config.messaging_pattern() {
-                        MessagingPattern::PublishSubscribe(_) | MessagingPattern::Event(_) => {
+                        MessagingPattern::PublishSubscribe(_)
+                        | MessagingPattern::Event(_)
+                        | MessagingPattern::RequestResponse(_)
+                        | MessagingPattern::Blackboard(_) => {
                             fail!(
                                 from "discovery_via_subscriber()",
                                 when on_discovered(service_config),
@@ -157,7 +160,10 @@ fn discover_via_tracker<
         if let Some(service_details) = tracker.get(&service_id) {
             let service_config = &service_details.static_details;
             match service_config.messaging_pattern() {
-                MessagingPattern::PublishSubscribe(_) | MessagingPattern::Event(_) => {
+                MessagingPattern::PublishSubscribe(_)
+                | MessagingPattern::Event(_)
+                | MessagingPattern::RequestResponse(_)
+                | MessagingPattern::Blackboard(_) => {
                     fail!(
                         from "discovery_via_tracker()",
                         when on_discovered(service_config),
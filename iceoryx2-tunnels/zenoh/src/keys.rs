@@ -31,3 +31,24 @@ pub fn publish_subscribe(service_id: &ServiceId) -> String {
 pub fn event(service_id: &ServiceId) -> String {
     format!("iox2/event/{}", service_id.as_str())
 }
+
+/// The zenoh key at which requests for a given request-response service can be queried.
+pub fn request_response(service_id: &ServiceId) -> String {
+    format!("iox2/request_response/{}", service_id.as_str())
+}
+
+/// The zenoh key at which connection updates of tunnelled requests for a given
+/// request-response service are exchanged.
+pub fn request_response_connection(service_id: &ServiceId) -> String {
+    format!("iox2/request_response_connection/{}", service_id.as_str())
+}
+
+/// The zenoh key matching all entries of a given blackboard service.
+pub fn blackboard(service_id: &ServiceId) -> String {
+    format!("iox2/blackboard/{}/*", service_id.as_str())
+}
+
+/// The zenoh key at which the value of a given blackboard entry can be received.
+pub fn blackboard_entry(service_id: &ServiceId, key: u64) -> String {
+    format!("iox2/blackboard/{}/{}", service_id.as_str(), key)
+}
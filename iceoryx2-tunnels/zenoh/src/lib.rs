@@ -19,5 +19,7 @@ pub mod keys;
 
 pub(crate) mod middleware;
 
+pub use iceoryx2_tunnels_common::*;
+
 mod tunnel;
 pub use tunnel::*;
@@ -11,24 +11,43 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use iceoryx2::node::Node;
+use iceoryx2::port::client::Client;
 use iceoryx2::port::listener::Listener;
 use iceoryx2::port::listener::ListenerCreateError;
 use iceoryx2::port::notifier::Notifier;
 use iceoryx2::port::notifier::NotifierCreateError;
 use iceoryx2::port::publisher::Publisher;
 use iceoryx2::port::publisher::PublisherCreateError;
+use iceoryx2::port::reader::Reader;
+use iceoryx2::port::reader::ReaderCreateError;
+use iceoryx2::port::server::Server;
 use iceoryx2::port::subscriber::Subscriber;
 use iceoryx2::port::subscriber::SubscriberCreateError;
+use iceoryx2::port::writer::Writer;
+use iceoryx2::port::writer::WriterCreateError;
 use iceoryx2::prelude::AllocationStrategy;
+use iceoryx2::service::builder::blackboard::BlackboardCreateError;
+use iceoryx2::service::builder::blackboard::BlackboardOpenError;
+use iceoryx2::service::builder::blackboard::BuilderInternals;
 use iceoryx2::service::builder::event::EventOpenOrCreateError;
 use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenOrCreateError;
+use iceoryx2::service::builder::request_response::RequestResponseOpenOrCreateError;
 use iceoryx2::service::builder::CustomHeaderMarker;
 use iceoryx2::service::builder::CustomPayloadMarker;
+use iceoryx2::service::port_factory::blackboard::PortFactory as BlackboardService;
+use iceoryx2::service::port_factory::client::ClientCreateError;
 use iceoryx2::service::port_factory::event::PortFactory as EventService;
 use iceoryx2::service::port_factory::publish_subscribe::PortFactory as PublishSubscribeService;
+use iceoryx2::service::port_factory::request_response::PortFactory as RequestResponseService;
+use iceoryx2::service::port_factory::server::ServerCreateError;
 use iceoryx2::service::static_config::StaticConfig as ServiceConfig;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::__internal_calculate_atomic_mgmt_and_payload_ptr;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::UnrestrictedAtomicMgmt;
 use iceoryx2_bb_log::fail;
 
+use crate::middleware::zenoh::BlackboardEntry;
+use crate::middleware::zenoh::BlackboardState;
+
 /// Creates an iceoryx2 publish-subscribe service matching the provided service configuration.
 pub(crate) fn create_publish_subscribe_service<ServiceType: iceoryx2::service::Service>(
     node: &Node<ServiceType>,
@@ -89,6 +108,176 @@ pub(crate) fn create_event_service<ServiceType: iceoryx2::service::Service>(
     Ok(service)
 }
 
+/// Creates an iceoryx request-response service matching the provided service configuration.
+pub(crate) fn create_request_response_service<ServiceType: iceoryx2::service::Service>(
+    node: &Node<ServiceType>,
+    service_config: &ServiceConfig,
+) -> Result<
+    RequestResponseService<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    RequestResponseOpenOrCreateError,
+> {
+    let request_response_config = service_config.request_response();
+    let service = unsafe {
+        fail!(
+            from "create_request_response_service()",
+            when node.service_builder(service_config.name())
+                    .request_response::<[CustomPayloadMarker], [CustomPayloadMarker]>()
+                    .request_user_header::<CustomHeaderMarker>()
+                    .response_user_header::<CustomHeaderMarker>()
+                    .__internal_set_request_header_type_details(
+                        &request_response_config.request_message_type_details().user_header,
+                    )
+                    .__internal_set_request_payload_type_details(
+                        &request_response_config.request_message_type_details().payload,
+                    )
+                    .__internal_set_response_header_type_details(
+                        &request_response_config.response_message_type_details().user_header,
+                    )
+                    .__internal_set_response_payload_type_details(
+                        &request_response_config.response_message_type_details().payload,
+                    )
+                    .enable_safe_overflow_for_requests(
+                        request_response_config.has_safe_overflow_for_requests(),
+                    )
+                    .enable_safe_overflow_for_responses(
+                        request_response_config.has_safe_overflow_for_responses(),
+                    )
+                    .enable_fire_and_forget_requests(
+                        request_response_config.does_support_fire_and_forget_requests(),
+                    )
+                    .enable_notify_on_send(request_response_config.has_notify_on_send())
+                    .max_active_requests_per_client(
+                        request_response_config.max_active_requests_per_client(),
+                    )
+                    .max_loaned_requests(request_response_config.max_loaned_requests())
+                    .max_response_buffer_size(request_response_config.max_response_buffer_size())
+                    .max_borrowed_responses_per_pending_response(
+                        request_response_config.max_borrowed_responses_per_pending_response(),
+                    )
+                    .max_servers(request_response_config.max_servers())
+                    .max_clients(request_response_config.max_clients())
+                    .max_nodes(request_response_config.max_nodes())
+                    .open_or_create(),
+            "failed to open or create request-response service"
+        )
+    };
+
+    Ok(service)
+}
+
+/// Opens an existing iceoryx blackboard service matching the provided service configuration.
+///
+/// The keys are accessed as `u64`, hence only blackboards with keys of the same size and
+/// alignment are supported.
+pub(crate) fn open_blackboard_service<ServiceType: iceoryx2::service::Service>(
+    node: &Node<ServiceType>,
+    service_config: &ServiceConfig,
+) -> Result<BlackboardService<ServiceType, u64>, BlackboardOpenError> {
+    let blackboard_config = service_config.blackboard();
+    let service = unsafe {
+        fail!(
+            from "open_blackboard_service()",
+            when node.service_builder(service_config.name())
+                    .blackboard_opener::<u64>()
+                    .__internal_set_key_type_details(blackboard_config.type_details())
+                    .open(),
+            "failed to open blackboard service"
+        )
+    };
+
+    Ok(service)
+}
+
+/// Creates an iceoryx blackboard service matching the provided service configuration that is
+/// initialized with the provided state. Capacity that is not occupied by the entries of the state
+/// is reserved for entries with the largest value of the state.
+pub(crate) fn create_blackboard_service<ServiceType: iceoryx2::service::Service>(
+    node: &Node<ServiceType>,
+    service_config: &ServiceConfig,
+    state: &BlackboardState,
+) -> Result<BlackboardService<ServiceType, u64>, BlackboardCreateError> {
+    let blackboard_config = service_config.blackboard();
+    let mut builder = unsafe {
+        node.service_builder(service_config.name())
+            .blackboard_creator::<u64>()
+            .__internal_set_key_type_details(blackboard_config.type_details())
+            .max_readers(blackboard_config.max_readers())
+            .max_nodes(blackboard_config.max_nodes())
+    };
+
+    let mut largest_value_size = 0;
+    let mut largest_value_alignment = 1;
+    for (key, entry) in &state.entries {
+        let internals = blackboard_entry_internals(*key, entry);
+        largest_value_size = largest_value_size.max(internals.1);
+        largest_value_alignment = largest_value_alignment.max(internals.2);
+        builder = builder.__internal_add(internals.0);
+    }
+
+    let number_of_reserved_entries = state.capacity.saturating_sub(state.entries.len());
+    if number_of_reserved_entries != 0 && largest_value_size != 0 {
+        builder = builder.__internal_reserve(
+            number_of_reserved_entries,
+            largest_value_size,
+            largest_value_alignment,
+        );
+    }
+
+    let service = fail!(
+        from "create_blackboard_service()",
+        when builder.create(),
+        "failed to create blackboard service"
+    );
+
+    Ok(service)
+}
+
+/// Prepares a blackboard entry for insertion. Returns the entry together with the size and the
+/// alignment it occupies in the blackboard.
+pub(crate) fn blackboard_entry_internals(
+    key: u64,
+    entry: &BlackboardEntry,
+) -> (BuilderInternals<u64>, usize, usize) {
+    let value = entry.value.clone();
+    let value_size = entry.type_details.size();
+    let value_alignment = entry.type_details.alignment();
+
+    let value_writer = Box::new(move |raw_memory_ptr: *mut u8| unsafe {
+        let ptrs =
+            __internal_calculate_atomic_mgmt_and_payload_ptr(raw_memory_ptr, value_alignment);
+        core::ptr::copy_nonoverlapping(
+            value.as_ptr(),
+            ptrs.atomic_payload_ptr,
+            value.len().min(value_size),
+        );
+    });
+    let internal_value_size = UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_size(
+        value_size,
+        value_alignment,
+    );
+    let internal_value_alignment =
+        UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_alignment(value_alignment);
+
+    (
+        BuilderInternals::new(
+            key,
+            entry.type_details.clone(),
+            value_writer,
+            internal_value_size,
+            internal_value_alignment,
+            Box::new(|| {}),
+        ),
+        internal_value_size,
+        internal_value_alignment,
+    )
+}
+
 /// Creates an iceoryx publisher to the provided service.
 pub(crate) fn create_publisher<ServiceType: iceoryx2::service::Service>(
     service: &PublishSubscribeService<ServiceType, [CustomPayloadMarker], CustomHeaderMarker>,
@@ -141,3 +330,87 @@ pub(crate) fn create_listener<ServiceType: iceoryx2::service::Service>(
     );
     Ok(listener)
 }
+
+/// Creates an iceoryx client for the provided service.
+pub(crate) fn create_client<ServiceType: iceoryx2::service::Service>(
+    service: &RequestResponseService<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+) -> Result<
+    Client<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    ClientCreateError,
+> {
+    let client = fail!(
+        from "create_client()",
+        when service
+            .client_builder()
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create(),
+        "failed to create client"
+    );
+    Ok(client)
+}
+
+/// Creates an iceoryx server for the provided service.
+pub(crate) fn create_server<ServiceType: iceoryx2::service::Service>(
+    service: &RequestResponseService<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+) -> Result<
+    Server<
+        ServiceType,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+        [CustomPayloadMarker],
+        CustomHeaderMarker,
+    >,
+    ServerCreateError,
+> {
+    let server = fail!(
+        from "create_server()",
+        when service
+            .server_builder()
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create(),
+        "failed to create server"
+    );
+    Ok(server)
+}
+
+/// Creates an iceoryx blackboard reader for the provided service.
+pub(crate) fn create_reader<ServiceType: iceoryx2::service::Service>(
+    service: &BlackboardService<ServiceType, u64>,
+) -> Result<Reader<ServiceType, u64>, ReaderCreateError> {
+    let reader = fail!(
+        from "create_reader()",
+        when service.reader_builder().create(),
+        "failed to create reader"
+    );
+    Ok(reader)
+}
+
+/// Creates an iceoryx blackboard writer for the provided service.
+pub(crate) fn create_writer<ServiceType: iceoryx2::service::Service>(
+    service: &BlackboardService<ServiceType, u64>,
+) -> Result<Writer<ServiceType, u64>, WriterCreateError> {
+    let writer = fail!(
+        from "create_writer()",
+        when service.writer_builder().create(),
+        "failed to create writer"
+    );
+    Ok(writer)
+}
@@ -12,19 +12,40 @@
 
 use crate::keys;
 
+use iceoryx2::service::static_config::message_type_details::TypeDetail;
 use iceoryx2::service::static_config::StaticConfig as ServiceConfig;
-use iceoryx2_bb_log::{error, fail};
+use iceoryx2_bb_log::{error, fail, warn};
 
+use zenoh::bytes::ZBytes;
 use zenoh::handlers::FifoChannel;
 use zenoh::handlers::FifoChannelHandler;
 use zenoh::pubsub::Publisher;
 use zenoh::pubsub::Subscriber;
 use zenoh::qos::Reliability;
+use zenoh::query::ConsolidationMode;
+use zenoh::query::Query;
+use zenoh::query::QueryTarget;
+use zenoh::query::Queryable;
+use zenoh::query::Reply;
 use zenoh::sample::Locality;
 use zenoh::sample::Sample;
 use zenoh::Session;
 use zenoh::Wait;
 
+use core::time::Duration;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+/// Lifetime of a forwarded request without a deadline. Such requests are not bound by a timeout
+/// but finalized via connection updates once the local client disconnects or the remote servers
+/// have delivered all responses.
+const LIFETIME_OF_REQUESTS_WITHOUT_DEADLINE: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+
+/// Selector parameter of a forwarded request with the time in nanoseconds until its deadline.
+const DEADLINE_PARAMETER: &str = "iox2_time_until_deadline_ns";
+
 /// Creates a Zenoh publisher to send payloads from iceoryx2 services to remote hosts.
 pub(crate) fn create_publisher<'a>(
     session: &Session,
@@ -109,6 +130,395 @@ pub(crate) fn create_listener(
     Ok(listener)
 }
 
+/// An update on the connection state of a request that was tunnelled to remote hosts,
+/// identified by the token that was attached to the request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ConnectionUpdate {
+    /// The origin of the request hinted that it is no longer interested in responses.
+    DisconnectHint(String),
+    /// The origin of the request is no longer connected.
+    Disconnected(String),
+}
+
+impl ConnectionUpdate {
+    const DISCONNECT_HINT: u8 = 0;
+    const DISCONNECTED: u8 = 1;
+
+    /// Serializes the update into the payload exchanged between hosts.
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let (kind, token) = match self {
+            ConnectionUpdate::DisconnectHint(token) => (Self::DISCONNECT_HINT, token),
+            ConnectionUpdate::Disconnected(token) => (Self::DISCONNECTED, token),
+        };
+
+        let mut bytes = Vec::with_capacity(1 + token.len());
+        bytes.push(kind);
+        bytes.extend_from_slice(token.as_bytes());
+        bytes
+    }
+
+    /// Deserializes an update from a payload received from a remote host. Returns `None`
+    /// when the payload is malformed.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (kind, token) = bytes.split_first()?;
+        let token = String::from_utf8(token.to_vec()).ok()?;
+
+        match *kind {
+            Self::DISCONNECT_HINT => Some(ConnectionUpdate::DisconnectHint(token)),
+            Self::DISCONNECTED => Some(ConnectionUpdate::Disconnected(token)),
+            _ => None,
+        }
+    }
+}
+
+/// Creates a Zenoh queryable to receive requests from remote hosts for a particular iceoryx2 service.
+pub(crate) fn create_queryable(
+    session: &Session,
+    service_config: &ServiceConfig,
+) -> Result<Queryable<FifoChannelHandler<Query>>, zenoh::Error> {
+    let key = keys::request_response(service_config.service_id());
+
+    // TODO(correctness): Make handler type and properties configurable
+    let queryable = fail!(
+        from "create_queryable()",
+        when session
+            .declare_queryable(key)
+            .with(FifoChannel::new(10))
+            .allowed_origin(Locality::Remote)
+            .wait(),
+        "failed to create zenoh queryable for requests"
+    );
+
+    Ok(queryable)
+}
+
+/// Sends a request from an iceoryx2 service to remote hosts. All responses streamed back by the
+/// remote hosts are received via the returned handler, which is disconnected once every remote
+/// host has finalized the request or the `timeout` has elapsed. The `timeout` shall cover the
+/// whole lifetime of the request since the query is finalized locally when it elapses.
+pub(crate) fn send_request(
+    session: &Session,
+    service_config: &ServiceConfig,
+    token: &str,
+    payload: ZBytes,
+    time_until_deadline: Option<Duration>,
+) -> Result<FifoChannelHandler<Reply>, zenoh::Error> {
+    let key = keys::request_response(service_config.service_id());
+    let (parameters, timeout) = match time_until_deadline {
+        Some(time_until_deadline) => (
+            format!("{}={}", DEADLINE_PARAMETER, time_until_deadline.as_nanos()),
+            time_until_deadline,
+        ),
+        None => (String::new(), LIFETIME_OF_REQUESTS_WITHOUT_DEADLINE),
+    };
+
+    // TODO(correctness): Make handler type and properties configurable
+    let replies = fail!(
+        from "send_request()",
+        when session
+            .get((key, parameters))
+            .payload(payload)
+            .attachment(token)
+            .target(QueryTarget::All)
+            .consolidation(ConsolidationMode::None)
+            .allowed_destination(Locality::Remote)
+            .timeout(timeout)
+            .with(FifoChannel::new(10))
+            .wait(),
+        "failed to send request to remote hosts"
+    );
+
+    Ok(replies)
+}
+
+/// Returns the time until the deadline of a request received from a remote host, [`None`] when
+/// the request has no deadline.
+///
+/// The time the query spent in transit is not subtracted since the clocks of the hosts are not
+/// synchronized.
+pub(crate) fn time_until_deadline_of(query: &Query) -> Option<Duration> {
+    query
+        .parameters()
+        .get(DEADLINE_PARAMETER)
+        .and_then(|value| value.parse::<u64>().ok())
+        .map(Duration::from_nanos)
+}
+
+/// Creates a Zenoh publisher to send connection updates of requests from iceoryx2 services to
+/// remote hosts.
+pub(crate) fn create_connection_publisher<'a>(
+    session: &Session,
+    service_config: &ServiceConfig,
+) -> Result<Publisher<'a>, zenoh::Error> {
+    let key = keys::request_response_connection(service_config.service_id());
+
+    let publisher = fail!(
+        from "create_connection_publisher()",
+        when session
+            .declare_publisher(key)
+            .allowed_destination(Locality::Remote)
+            .reliability(Reliability::Reliable)
+            .wait(),
+        "failed to create zenoh publisher for connection updates"
+    );
+
+    Ok(publisher)
+}
+
+/// Creates a Zenoh subscriber to receive connection updates of requests from remote hosts for a
+/// particular iceoryx2 service.
+pub(crate) fn create_connection_subscriber(
+    session: &Session,
+    service_config: &ServiceConfig,
+) -> Result<Subscriber<FifoChannelHandler<Sample>>, zenoh::Error> {
+    let key = keys::request_response_connection(service_config.service_id());
+
+    // TODO(correctness): Make handler type and properties configurable
+    let subscriber = fail!(
+        from "create_connection_subscriber()",
+        when session
+            .declare_subscriber(key)
+            .with(FifoChannel::new(10))
+            .allowed_origin(Locality::Remote)
+            .wait(),
+        "failed to create zenoh subscriber for connection updates"
+    );
+
+    Ok(subscriber)
+}
+
+/// The replicated value of a single blackboard entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BlackboardEntry {
+    pub(crate) type_details: TypeDetail,
+    pub(crate) value: Vec<u8>,
+}
+
+impl BlackboardEntry {
+    /// Returns `true` when the size of the value matches the size of its type. Entries of remote
+    /// hosts that violate this are malformed and must not be replicated.
+    pub(crate) fn has_matching_size(&self) -> bool {
+        self.value.len() == self.type_details.size()
+    }
+}
+
+/// The replicated state of a blackboard, consisting of its capacity and all of its entries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct BlackboardState {
+    pub(crate) capacity: usize,
+    pub(crate) entries: HashMap<u64, BlackboardEntry>,
+}
+
+fn serialize_blackboard_entry_details(
+    capacity: usize,
+    type_details: &TypeDetail,
+) -> Result<String, serde_json::Error> {
+    let mut details = serde_json::Map::new();
+    details.insert("capacity".into(), serde_json::to_value(capacity)?);
+    details.insert("type_details".into(), serde_json::to_value(type_details)?);
+    Ok(serde_json::Value::Object(details).to_string())
+}
+
+/// Extracts the blackboard key from the key expression of a sample received from a remote host.
+pub(crate) fn blackboard_entry_key(sample: &Sample) -> Option<u64> {
+    sample.key_expr().as_str().rsplit('/').next()?.parse().ok()
+}
+
+/// Extracts the key, the capacity of the blackboard and the entry from a sample received from a
+/// remote host. Returns `None` when the sample is malformed.
+pub(crate) fn deserialize_blackboard_entry(
+    sample: &Sample,
+) -> Option<(u64, usize, BlackboardEntry)> {
+    let key = blackboard_entry_key(sample)?;
+    let details: serde_json::Value =
+        serde_json::from_slice(&sample.attachment()?.to_bytes()).ok()?;
+    let capacity = serde_json::from_value(details.get("capacity")?.clone()).ok()?;
+    let type_details = serde_json::from_value(details.get("type_details")?.clone()).ok()?;
+
+    Some((
+        key,
+        capacity,
+        BlackboardEntry {
+            type_details,
+            value: sample.payload().to_bytes().to_vec(),
+        },
+    ))
+}
+
+/// Sends the value of a blackboard entry to remote hosts.
+pub(crate) fn put_blackboard_entry(
+    session: &Session,
+    service_config: &ServiceConfig,
+    capacity: usize,
+    key: u64,
+    entry: &BlackboardEntry,
+) -> Result<(), zenoh::Error> {
+    let details = fail!(
+        from "put_blackboard_entry()",
+        when serialize_blackboard_entry_details(capacity, &entry.type_details),
+        "failed to serialize blackboard entry details"
+    );
+
+    fail!(
+        from "put_blackboard_entry()",
+        when session
+            .put(keys::blackboard_entry(service_config.service_id(), key), entry.value.clone())
+            .attachment(details)
+            .allowed_destination(Locality::Remote)
+            .reliability(Reliability::Reliable)
+            .wait(),
+        "failed to send blackboard entry to remote hosts"
+    );
+
+    Ok(())
+}
+
+/// Informs remote hosts about the removal of a blackboard entry.
+pub(crate) fn delete_blackboard_entry(
+    session: &Session,
+    service_config: &ServiceConfig,
+    key: u64,
+) -> Result<(), zenoh::Error> {
+    fail!(
+        from "delete_blackboard_entry()",
+        when session
+            .delete(keys::blackboard_entry(service_config.service_id(), key))
+            .allowed_destination(Locality::Remote)
+            .reliability(Reliability::Reliable)
+            .wait(),
+        "failed to send removal of blackboard entry to remote hosts"
+    );
+
+    Ok(())
+}
+
+/// Creates a Zenoh queryable that provides the current state of a blackboard to remote hosts
+/// that join later.
+pub(crate) fn create_blackboard_queryable(
+    session: &Session,
+    service_config: &ServiceConfig,
+    state: Arc<Mutex<BlackboardState>>,
+) -> Result<Queryable<()>, zenoh::Error> {
+    let service_id = service_config.service_id().clone();
+
+    let queryable = fail!(
+        from "create_blackboard_queryable()",
+        when session
+            .declare_queryable(keys::blackboard(&service_id))
+            .callback(move |query| {
+                let state = match state.lock() {
+                    Ok(state) => state,
+                    Err(_) => {
+                        error!("Failed to access state of blackboard {}", service_id.as_str());
+                        return;
+                    }
+                };
+
+                for (key, entry) in &state.entries {
+                    let details =
+                        match serialize_blackboard_entry_details(state.capacity, &entry.type_details) {
+                            Ok(details) => details,
+                            Err(e) => {
+                                error!("Failed to serialize blackboard entry details: {}", e);
+                                continue;
+                            }
+                        };
+
+                    let _ = query
+                        .reply(keys::blackboard_entry(&service_id, *key), entry.value.clone())
+                        .attachment(details)
+                        .wait()
+                        .inspect_err(|e| {
+                            error!(
+                                "Failed to share entry {} of blackboard {}: {}",
+                                key,
+                                service_id.as_str(),
+                                e
+                            );
+                        });
+                }
+            })
+            .allowed_origin(Locality::Remote)
+            .wait(),
+        "failed to set up queryable to share blackboard state with remote hosts"
+    );
+
+    Ok(queryable)
+}
+
+/// Creates a Zenoh subscriber to receive blackboard updates from remote hosts for a particular
+/// iceoryx2 service.
+pub(crate) fn create_blackboard_subscriber(
+    session: &Session,
+    service_config: &ServiceConfig,
+) -> Result<Subscriber<FifoChannelHandler<Sample>>, zenoh::Error> {
+    let key = keys::blackboard(service_config.service_id());
+
+    // TODO(correctness): Make handler type and properties configurable
+    let subscriber = fail!(
+        from "create_blackboard_subscriber()",
+        when session
+            .declare_subscriber(key)
+            .with(FifoChannel::new(10))
+            .allowed_origin(Locality::Remote)
+            .wait(),
+        "failed to create zenoh subscriber for blackboard updates"
+    );
+
+    Ok(subscriber)
+}
+
+/// Retrieves the current state of a blackboard from remote hosts. Blocks until all remote hosts
+/// have replied.
+pub(crate) fn query_blackboard_state(
+    session: &Session,
+    service_config: &ServiceConfig,
+) -> Result<BlackboardState, zenoh::Error> {
+    let key = keys::blackboard(service_config.service_id());
+
+    let replies = fail!(
+        from "query_blackboard_state()",
+        when session
+            .get(key)
+            .consolidation(ConsolidationMode::None)
+            .allowed_destination(Locality::Remote)
+            .wait(),
+        "failed to query blackboard state from remote hosts"
+    );
+
+    let mut state = BlackboardState::default();
+    // The channel is disconnected once all remote hosts finalized the query.
+    while let Ok(reply) = replies.recv() {
+        match reply.result() {
+            Ok(sample) => match deserialize_blackboard_entry(sample) {
+                Some((key, _, entry)) if !entry.has_matching_size() => {
+                    warn!(
+                        "skipping blackboard entry {} received from remote host since its value has {} bytes but its type requires {} bytes",
+                        key,
+                        entry.value.len(),
+                        entry.type_details.size()
+                    );
+                }
+                Some((key, capacity, entry)) => {
+                    state.capacity = capacity;
+                    state.entries.insert(key, entry);
+                }
+                None => {
+                    warn!("skipping malformed blackboard entry received from remote host");
+                }
+            },
+            Err(e) => {
+                warn!(
+                    "skipping erroneous reply to blackboard state query: {:?}",
+                    e
+                );
+            }
+        }
+    }
+
+    Ok(state)
+}
+
 /// Announces an iceoryx service over Zenoh to make it discoverable by remote hosts.
 pub(crate) fn announce_service(
     session: &Session,
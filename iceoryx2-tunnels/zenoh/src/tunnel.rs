@@ -11,35 +11,52 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::channel::Channel;
+use crate::channel::ClientChannel;
 use crate::channel::ListenerChannel;
 use crate::channel::NotifierChannel;
 use crate::channel::PropagationError;
 use crate::channel::PublisherChannel;
+use crate::channel::ReaderChannel;
+use crate::channel::ServerChannel;
 use crate::channel::SubscriberChannel;
+use crate::channel::WriterChannel;
 use crate::discovery::Discovery;
 use crate::discovery::DiscoveryError;
 use crate::discovery::IceoryxDiscovery;
 use crate::discovery::ZenohDiscovery;
 use crate::middleware;
+use crate::PublisherStatistics;
+use crate::ServiceFilter;
+use crate::ServiceRemapping;
+use crate::ThroughputRule;
 
 use iceoryx2::config::Config as IceoryxConfig;
 use iceoryx2::node::Node as IceoryxNode;
 use iceoryx2::node::NodeBuilder;
+use iceoryx2::service::builder::blackboard::BlackboardOpenError;
 use iceoryx2::service::service_id::ServiceId;
 use iceoryx2::service::static_config::messaging_pattern::MessagingPattern;
 use iceoryx2::service::static_config::StaticConfig as ServiceConfig;
 use iceoryx2_bb_log::error;
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::warn;
 
 use zenoh::Config as ZenohConfig;
 use zenoh::Session as ZenohSession;
 use zenoh::Wait;
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 #[derive(Default)]
 pub struct TunnelConfig {
     pub discovery_service: Option<String>,
+    /// Decides which local and remote services are propagated.
+    pub service_filter: ServiceFilter,
+    /// Remaps the names under which local services are announced to remote hosts.
+    pub service_remappings: Vec<ServiceRemapping>,
+    /// Limits the throughput of the publish-subscribe services propagated to remote hosts.
+    pub throughput_limits: Vec<ThroughputRule>,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
@@ -70,6 +87,10 @@ pub enum ChannelInfo {
     Subscriber(String),
     Notifier(String),
     Listener(String),
+    Client(String),
+    Server(String),
+    Writer(String),
+    Reader(String),
 }
 
 /// A tunnel for propagating iceoryx2 payloads across hosts via the Zenoh network middleware.
@@ -83,6 +104,16 @@ pub struct Tunnel<'a, ServiceType: iceoryx2::service::Service> {
     subscriber_channels: HashMap<ServiceId, SubscriberChannel<ServiceType>>,
     notifier_channels: HashMap<ServiceId, NotifierChannel<'a, ServiceType>>,
     listener_channels: HashMap<ServiceId, ListenerChannel<ServiceType>>,
+    client_channels: HashMap<ServiceId, ClientChannel<'a, ServiceType>>,
+    server_channels: HashMap<ServiceId, ServerChannel<ServiceType>>,
+    writer_channels: HashMap<ServiceId, WriterChannel<ServiceType>>,
+    reader_channels: HashMap<ServiceId, ReaderChannel<ServiceType>>,
+    service_filter: ServiceFilter,
+    service_remappings: Vec<ServiceRemapping>,
+    throughput_limits: Vec<ThroughputRule>,
+    // Names and messaging patterns of services discovered on remote hosts. The local services
+    // created for them must not be announced again as local services.
+    remote_services: HashSet<(String, String)>,
 }
 
 impl<Service: iceoryx2::service::Service> Tunnel<'_, Service> {
@@ -144,6 +175,14 @@ impl<Service: iceoryx2::service::Service> Tunnel<'_, Service> {
             subscriber_channels: HashMap::new(),
             notifier_channels: HashMap::new(),
             listener_channels: HashMap::new(),
+            client_channels: HashMap::new(),
+            server_channels: HashMap::new(),
+            writer_channels: HashMap::new(),
+            reader_channels: HashMap::new(),
+            service_filter: tunnel_config.service_filter.clone(),
+            service_remappings: tunnel_config.service_remappings.clone(),
+            throughput_limits: tunnel_config.throughput_limits.clone(),
+            remote_services: HashSet::new(),
         })
     }
 
@@ -162,14 +201,41 @@ impl<Service: iceoryx2::service::Service> Tunnel<'_, Service> {
             fail!(
                 from &self,
                 when self.iox_discovery.discover(&mut |iox_service_config| {
+                    if !self.service_filter.is_allowed(iox_service_config)
+                        || self.remote_services.contains(&service_key(iox_service_config))
+                    {
+                        return Ok(());
+                    }
+
+                    let iox_announced_config = match ServiceRemapping::remap::<Service>(
+                        &self.service_remappings,
+                        iox_service_config,
+                    ) {
+                        Some(iox_announced_config) => iox_announced_config,
+                        None => {
+                            warn!(
+                                from "Tunnel::discover()",
+                                "skipping service {} since its remapped name is invalid",
+                                iox_service_config.name()
+                            );
+                            return Ok(());
+                        }
+                    };
+
                     on_discovery(
                         iox_service_config,
+                        &iox_announced_config,
                         &self.iox_node,
                         &self.z_session,
+                        &self.throughput_limits,
                         &mut self.publisher_channels,
                         &mut self.subscriber_channels,
                         &mut self.notifier_channels,
                         &mut self.listener_channels,
+                        &mut self.client_channels,
+                        &mut self.server_channels,
+                        &mut self.writer_channels,
+                        &mut self.reader_channels,
                     )
                 }),
                 "failed to discover services via iceoryx"
@@ -180,14 +246,25 @@ impl<Service: iceoryx2::service::Service> Tunnel<'_, Service> {
             fail!(
                 from &self,
                 when self.z_discovery.discover(&mut |iox_service_config| {
+                    if !self.service_filter.is_allowed(iox_service_config) {
+                        return Ok(());
+                    }
+                    self.remote_services.insert(service_key(iox_service_config));
+
                     on_discovery(
+                        iox_service_config,
                         iox_service_config,
                         &self.iox_node,
                         &self.z_session,
+                        &self.throughput_limits,
                         &mut self.publisher_channels,
                         &mut self.subscriber_channels,
                         &mut self.notifier_channels,
                         &mut self.listener_channels,
+                        &mut self.client_channels,
+                        &mut self.server_channels,
+                        &mut self.writer_channels,
+                        &mut self.reader_channels,
                     )
                 }),
                 "failed to discover services via zenoh"
@@ -225,6 +302,30 @@ impl<Service: iceoryx2::service::Service> Tunnel<'_, Service> {
                 propagation_failure = true;
             });
         }
+        for (id, channel) in &self.server_channels {
+            let _ = channel.propagate().inspect_err(|e| {
+                error!("Failed to propagate data through server channel with id {id:?}: {e}");
+                propagation_failure = true;
+            });
+        }
+        for (id, channel) in &self.client_channels {
+            let _ = channel.propagate().inspect_err(|e| {
+                error!("Failed to propagate data through client channel with id {id:?}: {e}");
+                propagation_failure = true;
+            });
+        }
+        for (id, channel) in &self.writer_channels {
+            let _ = channel.propagate().inspect_err(|e| {
+                error!("Failed to propagate data through writer channel with id {id:?}: {e}");
+                propagation_failure = true;
+            });
+        }
+        for (id, channel) in &self.reader_channels {
+            let _ = channel.propagate().inspect_err(|e| {
+                error!("Failed to propagate data through reader channel with id {id:?}: {e}");
+                propagation_failure = true;
+            });
+        }
 
         if propagation_failure {
             fail!(from self,
@@ -251,9 +352,30 @@ impl<Service: iceoryx2::service::Service> Tunnel<'_, Service> {
         for id in self.listener_channels.keys() {
             ports.push(ChannelInfo::Listener(id.as_str().to_string()));
         }
+        for id in self.client_channels.keys() {
+            ports.push(ChannelInfo::Client(id.as_str().to_string()));
+        }
+        for id in self.server_channels.keys() {
+            ports.push(ChannelInfo::Server(id.as_str().to_string()));
+        }
+        for id in self.writer_channels.keys() {
+            ports.push(ChannelInfo::Writer(id.as_str().to_string()));
+        }
+        for id in self.reader_channels.keys() {
+            ports.push(ChannelInfo::Reader(id.as_str().to_string()));
+        }
 
         ports
     }
+
+    /// Returns the counters of all publisher channels in the tunnel, keyed by the id of their
+    /// service.
+    pub fn publisher_statistics(&self) -> HashMap<String, PublisherStatistics> {
+        self.publisher_channels
+            .iter()
+            .map(|(id, channel)| (id.as_str().to_string(), channel.statistics()))
+            .collect()
+    }
 }
 
 // TODO(correctness): Proper clean-up in error cases
@@ -267,20 +389,34 @@ impl<Service: iceoryx2::service::Service> Tunnel<'_, Service> {
 /// # Arguments
 ///
 /// * `iox_service_config` - Configuration of the discovered Iceoryx service
+/// * `iox_announced_config` - Configuration under which the service is announced to remote hosts,
+///   its service id keys the channels and the data exchanged with remote hosts
 /// * `iox_node` - The local Iceoryx node to create services on
 /// * `z_session` - The Zenoh session for network communication
+/// * `throughput_limits` - Rules limiting the throughput of publisher channels
 /// * `publisher_channels` - Map of existing publisher channels, updated if a new one is created
 /// * `subscriber_channels` - Map of existing subscriber channels, updated if a new one is created
 /// * `notifier_channels` - Map of existing notifier channels, updated if a new one is created
 /// * `listener_channels` - Map of existing listener channels, updated if a new one is created
+/// * `client_channels` - Map of existing client channels, updated if a new one is created
+/// * `server_channels` - Map of existing server channels, updated if a new one is created
+/// * `writer_channels` - Map of existing writer channels, updated if a new one is created
+/// * `reader_channels` - Map of existing reader channels, updated if a new one is created
+#[allow(clippy::too_many_arguments)]
 fn on_discovery<'a, ServiceType: iceoryx2::service::Service>(
     iox_service_config: &ServiceConfig,
+    iox_announced_config: &ServiceConfig,
     iox_node: &IceoryxNode<ServiceType>,
     z_session: &ZenohSession,
+    throughput_limits: &[ThroughputRule],
     publisher_channels: &mut HashMap<ServiceId, PublisherChannel<'a, ServiceType>>,
     subscriber_channels: &mut HashMap<ServiceId, SubscriberChannel<ServiceType>>,
     notifier_channels: &mut HashMap<ServiceId, NotifierChannel<'a, ServiceType>>,
     listener_channels: &mut HashMap<ServiceId, ListenerChannel<ServiceType>>,
+    client_channels: &mut HashMap<ServiceId, ClientChannel<'a, ServiceType>>,
+    server_channels: &mut HashMap<ServiceId, ServerChannel<ServiceType>>,
+    writer_channels: &mut HashMap<ServiceId, WriterChannel<ServiceType>>,
+    reader_channels: &mut HashMap<ServiceId, ReaderChannel<ServiceType>>,
 ) -> Result<(), DiscoveryError> {
     match iox_service_config.messaging_pattern() {
         MessagingPattern::PublishSubscribe(_) => {
@@ -289,7 +425,9 @@ fn on_discovery<'a, ServiceType: iceoryx2::service::Service>(
                 when on_publish_subscribe_service(
                     iox_node,
                     iox_service_config,
+                    iox_announced_config,
                     z_session,
+                    throughput_limits,
                     publisher_channels,
                     subscriber_channels,
                 ),
@@ -302,6 +440,7 @@ fn on_discovery<'a, ServiceType: iceoryx2::service::Service>(
                 when on_event_service(
                     iox_node,
                     iox_service_config,
+                    iox_announced_config,
                     z_session,
                     notifier_channels,
                     listener_channels,
@@ -309,6 +448,34 @@ fn on_discovery<'a, ServiceType: iceoryx2::service::Service>(
                 "failed to process discovered event service"
             );
         }
+        MessagingPattern::RequestResponse(_) => {
+            fail!(
+                from "on_discovery()",
+                when on_request_response_service(
+                    iox_node,
+                    iox_service_config,
+                    iox_announced_config,
+                    z_session,
+                    client_channels,
+                    server_channels,
+                ),
+                "failed to process discovered request-response service"
+            );
+        }
+        MessagingPattern::Blackboard(_) => {
+            fail!(
+                from "on_discovery()",
+                when on_blackboard_service(
+                    iox_node,
+                    iox_service_config,
+                    iox_announced_config,
+                    z_session,
+                    writer_channels,
+                    reader_channels,
+                ),
+                "failed to process discovered blackboard service"
+            );
+        }
         _ => { /* Not supported. Nothing to do. */ }
     }
 
@@ -319,11 +486,13 @@ fn on_discovery<'a, ServiceType: iceoryx2::service::Service>(
 fn on_publish_subscribe_service<'a, ServiceType: iceoryx2::service::Service>(
     iox_node: &IceoryxNode<ServiceType>,
     iox_service_config: &ServiceConfig,
+    iox_announced_config: &ServiceConfig,
     z_session: &ZenohSession,
+    throughput_limits: &[ThroughputRule],
     publisher_channels: &mut HashMap<ServiceId, PublisherChannel<'a, ServiceType>>,
     subscriber_channels: &mut HashMap<ServiceId, SubscriberChannel<ServiceType>>,
 ) -> Result<(), DiscoveryError> {
-    let iox_service_id = iox_service_config.service_id();
+    let iox_service_id = iox_announced_config.service_id();
     let needs_publisher = !publisher_channels.contains_key(iox_service_id);
     let needs_subscriber = !subscriber_channels.contains_key(iox_service_id);
 
@@ -343,9 +512,10 @@ fn on_publish_subscribe_service<'a, ServiceType: iceoryx2::service::Service>(
                 from "on_publish_subscribe_service()",
                 when PublisherChannel::create(
                     iox_node.id(),
-                    iox_service_config,
+                    iox_announced_config,
                     &iox_service,
                     z_session,
+                    ThroughputRule::limit_for(throughput_limits, iox_service_config),
                 ),
                 with DiscoveryError::PortCreation,
                 "failed to create publisher channel for discovered service"
@@ -357,7 +527,7 @@ fn on_publish_subscribe_service<'a, ServiceType: iceoryx2::service::Service>(
             let subscriber_channel = fail!(
                 from "on_publish_subscribe_service()",
                 when SubscriberChannel::create(
-                    iox_service_config, &iox_service, z_session
+                    iox_announced_config, &iox_service, z_session
                 ),
                 with DiscoveryError::PortCreation,
                 "failed to create subscriber channel for discovered service"
@@ -368,7 +538,7 @@ fn on_publish_subscribe_service<'a, ServiceType: iceoryx2::service::Service>(
 
         fail!(
             from "on_publish_subscribe_service()",
-            when middleware::zenoh::announce_service(z_session, iox_service_config),
+            when middleware::zenoh::announce_service(z_session, iox_announced_config),
             with DiscoveryError::ServiceAnnouncement,
             "failed to announce discovered publish-subscribe service to zenoh network"
         );
@@ -381,11 +551,12 @@ fn on_publish_subscribe_service<'a, ServiceType: iceoryx2::service::Service>(
 fn on_event_service<'a, ServiceType: iceoryx2::service::Service>(
     iox_node: &IceoryxNode<ServiceType>,
     iox_service_config: &ServiceConfig,
+    iox_announced_config: &ServiceConfig,
     z_session: &ZenohSession,
     notifier_channels: &mut HashMap<ServiceId, NotifierChannel<'a, ServiceType>>,
     listener_channels: &mut HashMap<ServiceId, ListenerChannel<ServiceType>>,
 ) -> Result<(), DiscoveryError> {
-    let iox_service_id = iox_service_config.service_id();
+    let iox_service_id = iox_announced_config.service_id();
     let needs_notifier = !notifier_channels.contains_key(iox_service_id);
     let needs_listener = !listener_channels.contains_key(iox_service_id);
 
@@ -399,7 +570,7 @@ fn on_event_service<'a, ServiceType: iceoryx2::service::Service>(
         if needs_notifier {
             let notifier_channel = fail!(
                 from "on_event_service()",
-                when NotifierChannel::create(iox_service_config, &iox_service, z_session),
+                when NotifierChannel::create(iox_announced_config, &iox_service, z_session),
                 with DiscoveryError::PortCreation,
                 "failed to create notifier channel for discovered service"
             );
@@ -408,7 +579,7 @@ fn on_event_service<'a, ServiceType: iceoryx2::service::Service>(
         if needs_listener {
             let listener_channel = fail!(
                 from "on_event_service()",
-                when ListenerChannel::create(iox_service_config, &iox_service, z_session),
+                when ListenerChannel::create(iox_announced_config, &iox_service, z_session),
                 with DiscoveryError::PortCreation,
                 "failed to create listener channel for discovered service"
             );
@@ -416,7 +587,7 @@ fn on_event_service<'a, ServiceType: iceoryx2::service::Service>(
         }
         fail!(
             from "on_event_service()",
-            when middleware::zenoh::announce_service(z_session, iox_service_config),
+            when middleware::zenoh::announce_service(z_session, iox_announced_config),
             with DiscoveryError::ServiceAnnouncement,
             "failed to announce discovered event service to zenoh network"
         );
@@ -424,3 +595,145 @@ fn on_event_service<'a, ServiceType: iceoryx2::service::Service>(
 
     Ok(())
 }
+
+/// Handles the request-response messaging pattern during service discovery.
+fn on_request_response_service<'a, ServiceType: iceoryx2::service::Service>(
+    iox_node: &IceoryxNode<ServiceType>,
+    iox_service_config: &ServiceConfig,
+    iox_announced_config: &ServiceConfig,
+    z_session: &ZenohSession,
+    client_channels: &mut HashMap<ServiceId, ClientChannel<'a, ServiceType>>,
+    server_channels: &mut HashMap<ServiceId, ServerChannel<ServiceType>>,
+) -> Result<(), DiscoveryError> {
+    let iox_service_id = iox_announced_config.service_id();
+
+    // The client channel ignores requests of the server channel to prevent loopback, hence
+    // both channels are always created together.
+    if !client_channels.contains_key(iox_service_id) {
+        let iox_service = fail!(
+            from "on_request_response_service()",
+            when middleware::iceoryx::create_request_response_service::<ServiceType>(
+                    iox_node,
+                    iox_service_config,
+                ),
+            with DiscoveryError::ServiceCreation,
+            "failed to open or create discovered request-response service"
+        );
+
+        let server_channel = fail!(
+            from "on_request_response_service()",
+            when ServerChannel::create(iox_announced_config, &iox_service, z_session),
+            with DiscoveryError::PortCreation,
+            "failed to create server channel for discovered service"
+        );
+        let client_channel = fail!(
+            from "on_request_response_service()",
+            when ClientChannel::create(
+                iox_announced_config,
+                &iox_service,
+                &server_channel.client_id(),
+                z_session,
+            ),
+            with DiscoveryError::PortCreation,
+            "failed to create client channel for discovered service"
+        );
+
+        server_channels.insert(iox_service_id.clone(), server_channel);
+        client_channels.insert(iox_service_id.clone(), client_channel);
+
+        fail!(
+            from "on_request_response_service()",
+            when middleware::zenoh::announce_service(z_session, iox_announced_config),
+            with DiscoveryError::ServiceAnnouncement,
+            "failed to announce discovered request-response service to zenoh network"
+        );
+    }
+
+    Ok(())
+}
+
+/// Handles the blackboard messaging pattern during service discovery.
+///
+/// The host that owns the blackboard propagates its entries via a writer channel. All other hosts
+/// create a local replica of the blackboard that is kept up to date via a reader channel.
+///
+/// Until generic keys are supported, only blackboards whose keys have the layout of `u64` are
+/// replicated, all others are skipped with a warning.
+fn on_blackboard_service<ServiceType: iceoryx2::service::Service>(
+    iox_node: &IceoryxNode<ServiceType>,
+    iox_service_config: &ServiceConfig,
+    iox_announced_config: &ServiceConfig,
+    z_session: &ZenohSession,
+    writer_channels: &mut HashMap<ServiceId, WriterChannel<ServiceType>>,
+    reader_channels: &mut HashMap<ServiceId, ReaderChannel<ServiceType>>,
+) -> Result<(), DiscoveryError> {
+    let iox_service_id = iox_announced_config.service_id();
+    if writer_channels.contains_key(iox_service_id) || reader_channels.contains_key(iox_service_id)
+    {
+        return Ok(());
+    }
+
+    // TODO [#817] replace u64 with CustomKeyMarker
+    let key_type_details = iox_service_config.blackboard().type_details();
+    if key_type_details.size() != core::mem::size_of::<u64>()
+        || key_type_details.alignment() != core::mem::align_of::<u64>()
+    {
+        warn!(
+            from "on_blackboard_service()",
+            "skipping blackboard {} [{}] since only keys with the layout of u64 are supported",
+            iox_service_id.as_str(),
+            iox_service_config.name()
+        );
+        return Ok(());
+    }
+
+    match middleware::iceoryx::open_blackboard_service::<ServiceType>(iox_node, iox_service_config)
+    {
+        Ok(iox_service) => {
+            let writer_channel = fail!(
+                from "on_blackboard_service()",
+                when WriterChannel::create(iox_announced_config, &iox_service, z_session),
+                with DiscoveryError::PortCreation,
+                "failed to create writer channel for discovered service"
+            );
+            writer_channels.insert(iox_service_id.clone(), writer_channel);
+
+            fail!(
+                from "on_blackboard_service()",
+                when middleware::zenoh::announce_service(z_session, iox_announced_config),
+                with DiscoveryError::ServiceAnnouncement,
+                "failed to announce discovered blackboard service to zenoh network"
+            );
+        }
+        Err(BlackboardOpenError::DoesNotExist) => {
+            let reader_channel = fail!(
+                from "on_blackboard_service()",
+                when ReaderChannel::create(iox_service_config, iox_node, z_session),
+                with DiscoveryError::PortCreation,
+                "failed to create reader channel for discovered service"
+            );
+
+            // The replica is created on a later discovery once a remote host provides entries.
+            if let Some(reader_channel) = reader_channel {
+                reader_channels.insert(iox_service_id.clone(), reader_channel);
+            }
+        }
+        Err(_) => {
+            fail!(
+                from "on_blackboard_service()",
+                with DiscoveryError::ServiceCreation,
+                "failed to open discovered blackboard service"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Identifies a service by its name and messaging pattern, independent of its service id.
+fn service_key(iox_service_config: &ServiceConfig) -> (String, String) {
+    (
+        iox_service_config.name().to_string(),
+        iox_service_config.messaging_pattern().to_string(),
+    )
+}
@@ -0,0 +1,250 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod testing;
+
+#[generic_tests::define]
+mod zenoh_tunnel_blackboard {
+
+    use crate::testing::*;
+
+    use core::cell::Cell;
+    use std::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnels_zenoh::*;
+
+    const MAX_RETRIES: usize = 25;
+    const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+    fn mock_service_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "test_zenoh_tunnel_blackboard_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn discover_remote_service<S: Service>(tunnel: &mut Tunnel<S>, service_id: &str) {
+        retry(
+            || {
+                tunnel.discover(Scope::Zenoh).unwrap();
+
+                if tunnel
+                    .active_channels()
+                    .contains(&ChannelInfo::Reader(String::from(service_id)))
+                {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[test]
+    fn discovers_local_services_via_tracker<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig::default();
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+        assert_that!(tunnel.active_channels().len(), eq 0);
+
+        // Service
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&iox_service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 7)
+            .create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Discover
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.active_channels().len(), eq 1);
+        assert_that!(tunnel
+            .active_channels()
+            .contains(&ChannelInfo::Writer(String::from(iox_service.service_id().as_str()))), eq true);
+    }
+
+    #[test]
+    fn replicates_current_entries_to_late_joining_host<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig::default();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Service
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 7)
+            .add::<u64>(2, 13)
+            .create()
+            .unwrap();
+        let iox_writer_a = iox_service_a.writer_builder().create().unwrap();
+        iox_writer_a.entry::<u32>(&1).unwrap().update_with_copy(8);
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.active_channels().len(), eq 1);
+
+        // [[ HOST B ]]
+        // Tunnel
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let tunnel_config_b = TunnelConfig::default();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST B ]]
+        // Discover - blackboard should be replicated with the current entries
+        discover_remote_service(&mut tunnel_b, iox_service_a.service_id().as_str());
+
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+        let iox_reader_b = iox_service_b.reader_builder().create().unwrap();
+
+        assert_that!(iox_reader_b.entry::<u32>(&1).unwrap().get(), eq 8);
+        assert_that!(iox_reader_b.entry::<u64>(&2).unwrap().get(), eq 13);
+    }
+
+    #[test]
+    fn propagates_entry_updates_to_remote_host<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig::default();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Service
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(1, 7)
+            .create()
+            .unwrap();
+        let iox_writer_a = iox_service_a.writer_builder().create().unwrap();
+        let iox_entry_a = iox_writer_a.entry::<u32>(&1).unwrap();
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let tunnel_config_b = TunnelConfig::default();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
+
+        // Discover
+        discover_remote_service(&mut tunnel_b, iox_service_a.service_id().as_str());
+
+        // Reader
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+        let iox_reader_b = iox_service_b.reader_builder().create().unwrap();
+        let iox_entry_b = iox_reader_b.entry::<u32>(&1).unwrap();
+        assert_that!(iox_entry_b.get(), eq 7);
+
+        // ==================== TEST =====================
+
+        // Updates are repeated with new values since the remote subscriber might not yet be
+        // matched when the first update is propagated.
+        let next_value = Cell::new(100);
+        retry(
+            || {
+                // [[ HOST B ]]
+                // Propagate - update should be received from remote host
+                tunnel_b.propagate().unwrap();
+                if iox_entry_b.get() >= 100 {
+                    return Ok(());
+                }
+
+                // [[ HOST A ]]
+                // Update
+                iox_entry_a.update_with_copy(next_value.get());
+                next_value.set(next_value.get() + 1);
+
+                // Propagate
+                tunnel_a.propagate().unwrap();
+
+                Err("failed to receive update from remote host")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
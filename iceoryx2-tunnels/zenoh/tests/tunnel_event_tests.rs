@@ -59,6 +59,7 @@ mod zenoh_tunnel_events {
         let z_config_a = zenoh::Config::default();
         let tunnel_config = TunnelConfig {
             discovery_service: Some("iox2://discovery/services/".into()),
+            ..Default::default()
         };
 
         let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config_a).unwrap();
@@ -0,0 +1,435 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod testing;
+
+mod zenoh_tunnel_filter_rules {
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnels_zenoh::*;
+
+    #[test]
+    fn glob_matches_wildcards() {
+        assert_that!(matches_glob("camera/*", "camera/front"), eq true);
+        assert_that!(matches_glob("camera/*", "camera/"), eq true);
+        assert_that!(matches_glob("*/front", "camera/front"), eq true);
+        assert_that!(matches_glob("camera/fr?nt", "camera/front"), eq true);
+        assert_that!(matches_glob("*a*a*", "banana"), eq true);
+        assert_that!(matches_glob("camera/*", "lidar/front"), eq false);
+        assert_that!(matches_glob("camera/?", "camera/front"), eq false);
+        assert_that!(matches_glob("camera", "camera/front"), eq false);
+    }
+
+    #[test]
+    fn rule_is_parsed_from_criteria() {
+        let rule: ServiceRule = "name=diagnostics/*,pattern=Event,attribute:origin=robot?"
+            .parse()
+            .unwrap();
+
+        assert_that!(rule.name, eq Some(String::from("diagnostics/*")));
+        assert_that!(rule.messaging_pattern, eq Some(String::from("Event")));
+        assert_that!(rule.attributes, eq vec![(String::from("origin"), String::from("robot?"))]);
+    }
+
+    #[test]
+    fn rule_with_unknown_field_is_rejected() {
+        assert_that!("color=red".parse::<ServiceRule>().is_err(), eq true);
+        assert_that!("name".parse::<ServiceRule>().is_err(), eq true);
+        assert_that!("attribute:=value".parse::<ServiceRule>().is_err(), eq true);
+    }
+
+    #[test]
+    fn remapping_replaces_prefix() {
+        let remapping: ServiceRemapping = "=robotA/".parse().unwrap();
+        assert_that!(remapping.apply("camera/front"), eq Some(String::from("robotA/camera/front")));
+
+        let remapping: ServiceRemapping = "camera/=robotA/camera/".parse().unwrap();
+        assert_that!(remapping.apply("camera/front"), eq Some(String::from("robotA/camera/front")));
+        assert_that!(remapping.apply("lidar/front"), eq None);
+    }
+}
+
+#[generic_tests::define]
+mod zenoh_tunnel_filter {
+
+    use crate::testing::*;
+
+    use std::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnels_zenoh::*;
+
+    const MAX_RETRIES: usize = 25;
+    const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+    fn mock_service_name(prefix: &str) -> ServiceName {
+        ServiceName::new(&format!(
+            "{}test_zenoh_tunnel_filter_{}",
+            prefix,
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn excluded_local_services_are_not_propagated<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            service_filter: ServiceFilter {
+                include: vec![],
+                exclude: vec!["name=diagnostics/*".parse().unwrap()],
+            },
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        // Services
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_excluded_service = iox_node
+            .service_builder(&mock_service_name("diagnostics/"))
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_included_service = iox_node
+            .service_builder(&mock_service_name("camera/"))
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Discover - only the included service should be tunneled
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.active_channels().len(), eq 2);
+        assert_that!(tunnel.active_channels().contains(&ChannelInfo::Publisher(String::from(
+            iox_included_service.service_id().as_str()
+        ))), eq true);
+        assert_that!(tunnel.active_channels().contains(&ChannelInfo::Publisher(String::from(
+            iox_excluded_service.service_id().as_str()
+        ))), eq false);
+    }
+
+    #[test]
+    fn only_included_local_services_are_propagated<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            service_filter: ServiceFilter {
+                include: vec!["pattern=Event".parse().unwrap()],
+                exclude: vec![],
+            },
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        // Services
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let _iox_publish_subscribe_service = iox_node
+            .service_builder(&mock_service_name(""))
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_event_service = iox_node
+            .service_builder(&mock_service_name(""))
+            .event()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Discover - only the event service should be tunneled
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.active_channels().len(), eq 2);
+        assert_that!(tunnel.active_channels().contains(&ChannelInfo::Notifier(String::from(
+            iox_event_service.service_id().as_str()
+        ))), eq true);
+    }
+
+    #[test]
+    fn remapped_services_are_propagated_under_prefixed_name<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name("camera/");
+        let iox_remapped_service_name =
+            ServiceName::new(&format!("robotA/{}", iox_service_name.as_str())).unwrap();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig {
+            service_remappings: vec!["=robotA/".parse().unwrap()],
+            ..Default::default()
+        };
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Publisher
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_publisher_a = iox_service_a.publisher_builder().create().unwrap();
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.active_channels().len(), eq 2);
+
+        // [[ HOST B ]]
+        // Tunnel
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let tunnel_config_b = TunnelConfig::default();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
+
+        // Discover
+        retry(
+            || {
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+
+                if tunnel_b.active_channels().len() == 2 {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        // Subscriber - the remote service is only available under the remapped name
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_remapped_service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        assert_that!(iox_service_b.service_id(), ne iox_service_a.service_id());
+
+        // Wait for Zenoh's background thread to establish match...
+        let matched = wait_for_zenoh_match(
+            keys::publish_subscribe(iox_service_b.service_id()),
+            Duration::from_millis(1000),
+        );
+        assert_that!(matched, eq true);
+
+        let iox_subscriber_b = iox_service_b.subscriber_builder().create().unwrap();
+
+        // Discover - the local service for the remote service must not be tunneled again
+        tunnel_b.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_b.active_channels().len(), eq 2);
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Publish
+        iox_publisher_a.send_copy(42).unwrap();
+
+        // [[ HOST B ]]
+        // Receive
+        retry(
+            || {
+                tunnel_a.propagate().unwrap();
+                tunnel_b.propagate().unwrap();
+
+                match iox_subscriber_b.receive().unwrap() {
+                    Some(iox_sample_received_b) if *iox_sample_received_b == 42 => Ok(()),
+                    Some(_) => Err("received unexpected sample"),
+                    None => Err("failed to receive expected sample"),
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[test]
+    fn equally_named_services_of_different_hosts_are_separated_by_remapping<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name("camera/");
+        let iox_remapped_service_name_a =
+            ServiceName::new(&format!("robotA/{}", iox_service_name.as_str())).unwrap();
+        let iox_remapped_service_name_b =
+            ServiceName::new(&format!("robotB/{}", iox_service_name.as_str())).unwrap();
+
+        // [[ HOST A & HOST B ]]
+        // Tunnels and Publishers
+        let create_robot = |prefix: &str| {
+            let z_config = zenoh::Config::default();
+            let iox_config = generate_isolated_config();
+            let tunnel_config = TunnelConfig {
+                service_remappings: vec![format!("={prefix}/").parse().unwrap()],
+                ..Default::default()
+            };
+            let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+            let iox_node = NodeBuilder::new()
+                .config(&iox_config)
+                .create::<S>()
+                .unwrap();
+            let iox_service = iox_node
+                .service_builder(&iox_service_name)
+                .publish_subscribe::<u64>()
+                .open_or_create()
+                .unwrap();
+            let iox_publisher = iox_service.publisher_builder().create().unwrap();
+            let iox_subscriber = iox_service.subscriber_builder().create().unwrap();
+
+            tunnel.discover(Scope::Iceoryx).unwrap();
+            assert_that!(tunnel.active_channels().len(), eq 2);
+
+            (tunnel, iox_node, iox_service, iox_publisher, iox_subscriber)
+        };
+        let (mut tunnel_a, _iox_node_a, iox_service_a, iox_publisher_a, iox_subscriber_a) =
+            create_robot("robotA");
+        let (mut tunnel_b, _iox_node_b, iox_service_b, iox_publisher_b, _iox_subscriber_b) =
+            create_robot("robotB");
+
+        // Both robots use the same service locally
+        assert_that!(iox_service_a.service_id(), eq iox_service_b.service_id());
+
+        // [[ HOST C ]]
+        // Tunnel
+        let z_config_c = zenoh::Config::default();
+        let iox_config_c = generate_isolated_config();
+        let tunnel_config_c = TunnelConfig::default();
+        let mut tunnel_c =
+            Tunnel::<S>::create(&tunnel_config_c, &iox_config_c, &z_config_c).unwrap();
+
+        // Discover - each robot provides a separate service
+        retry(
+            || {
+                tunnel_a.discover(Scope::Zenoh).unwrap();
+                tunnel_b.discover(Scope::Zenoh).unwrap();
+                tunnel_c.discover(Scope::Zenoh).unwrap();
+
+                if tunnel_c.active_channels().len() == 4 {
+                    return Ok(());
+                }
+                Err("failed to discover remote services")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        // Subscribers
+        let iox_node_c = NodeBuilder::new()
+            .config(&iox_config_c)
+            .create::<S>()
+            .unwrap();
+        let iox_service_c_from_a = iox_node_c
+            .service_builder(&iox_remapped_service_name_a)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let iox_service_c_from_b = iox_node_c
+            .service_builder(&iox_remapped_service_name_b)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        assert_that!(iox_service_c_from_a.service_id(), ne iox_service_c_from_b.service_id());
+        let iox_subscriber_c_from_a = iox_service_c_from_a.subscriber_builder().create().unwrap();
+        let iox_subscriber_c_from_b = iox_service_c_from_b.subscriber_builder().create().unwrap();
+
+        // Wait for Zenoh's background thread to establish match...
+        for iox_service_id in [
+            iox_service_c_from_a.service_id(),
+            iox_service_c_from_b.service_id(),
+        ] {
+            let matched = wait_for_zenoh_match(
+                keys::publish_subscribe(iox_service_id),
+                Duration::from_millis(1000),
+            );
+            assert_that!(matched, eq true);
+        }
+
+        // ==================== TEST =====================
+
+        // [[ HOST A & HOST B ]]
+        // Publish
+        iox_publisher_a.send_copy(1).unwrap();
+        iox_publisher_b.send_copy(2).unwrap();
+
+        // [[ HOST C ]]
+        // Receive - the samples of each robot only arrive at the service of the robot
+        let mut received_from_a = vec![];
+        let mut received_from_b = vec![];
+        retry(
+            || {
+                tunnel_a.propagate().unwrap();
+                tunnel_b.propagate().unwrap();
+                tunnel_c.propagate().unwrap();
+
+                while let Some(sample) = iox_subscriber_c_from_a.receive().unwrap() {
+                    received_from_a.push(*sample);
+                }
+                while let Some(sample) = iox_subscriber_c_from_b.receive().unwrap() {
+                    received_from_b.push(*sample);
+                }
+
+                if !received_from_a.is_empty() && !received_from_b.is_empty() {
+                    return Ok(());
+                }
+                Err("failed to receive expected samples")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        assert_that!(received_from_a, eq vec![1]);
+        assert_that!(received_from_b, eq vec![2]);
+
+        // [[ HOST A ]]
+        // Receive - the sample of robot B does not leak into the local service of robot A
+        tunnel_a.propagate().unwrap();
+        let mut received_on_a = vec![];
+        while let Some(sample) = iox_subscriber_a.receive().unwrap() {
+            received_on_a.push(*sample);
+        }
+        assert_that!(received_on_a, eq vec![1]);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
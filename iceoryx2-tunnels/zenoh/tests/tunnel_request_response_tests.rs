@@ -0,0 +1,548 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod testing;
+
+#[generic_tests::define]
+mod zenoh_tunnel_request_response {
+
+    use crate::testing::*;
+
+    use std::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::{assert_that, test_fail};
+    use iceoryx2_tunnels_zenoh::*;
+
+    const MAX_RETRIES: usize = 25;
+    const TIME_BETWEEN_RETRIES: Duration = Duration::from_millis(250);
+
+    fn mock_service_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "test_zenoh_tunnel_request_response_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn discover_remote_service<S: Service>(tunnel: &mut Tunnel<S>, service_id: &str) {
+        retry(
+            || {
+                tunnel.discover(Scope::Zenoh).unwrap();
+
+                let tunneled_ports = tunnel.active_channels();
+                let tunneled_client =
+                    tunneled_ports.contains(&ChannelInfo::Client(String::from(service_id)));
+                let tunneled_server =
+                    tunneled_ports.contains(&ChannelInfo::Server(String::from(service_id)));
+
+                if tunneled_client && tunneled_server {
+                    return Ok(());
+                }
+                Err("failed to discover remote service")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[test]
+    fn discovers_local_services_via_tracker<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig::default();
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+        assert_that!(tunnel.active_channels().len(), eq 0);
+
+        // Service
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&iox_service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Discover
+        tunnel.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel.active_channels().len(), eq 2);
+        assert_that!(tunnel
+            .active_channels()
+            .contains(&ChannelInfo::Client(String::from(iox_service.service_id().as_str()))), eq true);
+        assert_that!(tunnel
+            .active_channels()
+            .contains(&ChannelInfo::Server(String::from(iox_service.service_id().as_str()))), eq true);
+    }
+
+    #[test]
+    fn discovers_remote_services_via_zenoh<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig::default();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+        assert_that!(tunnel_a.active_channels().len(), eq 0);
+
+        // [[ HOST B ]]
+        // Tunnel
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let tunnel_config_b = TunnelConfig::default();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
+        assert_that!(tunnel_b.active_channels().len(), eq 0);
+
+        // Service
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST B ]]
+        // Discover - service should be announced
+        tunnel_b.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_b.active_channels().len(), eq 2);
+
+        // [[ HOST A ]]
+        // Discover - announced service should be discovered via Zenoh
+        discover_remote_service(&mut tunnel_a, iox_service_b.service_id().as_str());
+    }
+
+    #[test]
+    fn propagates_streamed_responses_to_remote_client<S: Service>() {
+        const NUMBER_OF_RESPONSES: u64 = 3;
+
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig::default();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Client
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_client_a = iox_service_a.client_builder().create().unwrap();
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.active_channels().len(), eq 2);
+
+        // [[ HOST B ]]
+        // Tunnel
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let tunnel_config_b = TunnelConfig::default();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
+
+        // Discover
+        discover_remote_service(&mut tunnel_b, iox_service_a.service_id().as_str());
+
+        // Wait for Zenoh's background thread to establish match...
+        let matched = wait_for_zenoh_match(
+            keys::request_response_connection(iox_service_a.service_id()),
+            Duration::from_millis(1000),
+        );
+        assert_that!(matched, eq true);
+
+        // Server
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_server_b = iox_service_b.server_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Request
+        let iox_pending_response_a = iox_client_a.send_copy(42).unwrap();
+        tunnel_a.propagate().unwrap();
+
+        // [[ HOST B ]]
+        // Respond
+        let mut iox_active_request_b = None;
+        retry(
+            || {
+                tunnel_b.propagate().unwrap();
+                match iox_server_b.receive().unwrap() {
+                    Some(iox_active_request) => {
+                        if *iox_active_request.payload() == 42 {
+                            iox_active_request_b = Some(iox_active_request);
+                            Ok(())
+                        } else {
+                            Err("received unexpected request")
+                        }
+                    }
+                    None => {
+                        tunnel_a.propagate().unwrap();
+                        Err("failed to receive expected request")
+                    }
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        let iox_active_request_b = iox_active_request_b.unwrap();
+        for i in 0..NUMBER_OF_RESPONSES {
+            iox_active_request_b.send_copy(i).unwrap();
+        }
+        drop(iox_active_request_b);
+
+        // [[ HOST A ]]
+        // Receive
+        let mut received_responses = Vec::new();
+        retry(
+            || {
+                tunnel_b.propagate().unwrap();
+                tunnel_a.propagate().unwrap();
+                while let Some(iox_response) = iox_pending_response_a.receive().unwrap() {
+                    received_responses.push(*iox_response.payload());
+                }
+
+                if received_responses.len() == NUMBER_OF_RESPONSES as usize
+                    && !iox_pending_response_a.is_connected()
+                {
+                    return Ok(());
+                }
+                Err("failed to receive all responses")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        for (i, response) in received_responses.iter().enumerate() {
+            assert_that!(*response, eq i as u64);
+        }
+    }
+
+    #[test]
+    fn propagates_disconnect_hint_to_remote_server<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig::default();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Client
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_client_a = iox_service_a.client_builder().create().unwrap();
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let tunnel_config_b = TunnelConfig::default();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
+
+        // Discover
+        discover_remote_service(&mut tunnel_b, iox_service_a.service_id().as_str());
+
+        // Wait for Zenoh's background thread to establish match...
+        let matched = wait_for_zenoh_match(
+            keys::request_response_connection(iox_service_a.service_id()),
+            Duration::from_millis(1000),
+        );
+        assert_that!(matched, eq true);
+
+        // Server
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_server_b = iox_service_b.server_builder().create().unwrap();
+
+        // [[ HOST A ]]
+        // Request
+        let iox_pending_response_a = iox_client_a.send_copy(7).unwrap();
+        tunnel_a.propagate().unwrap();
+
+        // [[ HOST B ]]
+        // Receive
+        let mut iox_active_request_b = None;
+        retry(
+            || {
+                tunnel_b.propagate().unwrap();
+                match iox_server_b.receive().unwrap() {
+                    Some(iox_active_request) => {
+                        iox_active_request_b = Some(iox_active_request);
+                        Ok(())
+                    }
+                    None => {
+                        tunnel_a.propagate().unwrap();
+                        Err("failed to receive expected request")
+                    }
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        let iox_active_request_b = iox_active_request_b.unwrap();
+        assert_that!(iox_active_request_b.has_disconnect_hint(), eq false);
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Hint
+        iox_pending_response_a.set_disconnect_hint();
+
+        // [[ HOST B ]]
+        // Hint should be propagated to the active request
+        retry(
+            || {
+                tunnel_a.propagate().unwrap();
+                tunnel_b.propagate().unwrap();
+
+                if iox_active_request_b.has_disconnect_hint() {
+                    return Ok(());
+                }
+                Err("failed to receive disconnect hint")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+
+        // [[ HOST A ]]
+        // Disconnect
+        drop(iox_pending_response_a);
+
+        // [[ HOST B ]]
+        // Disconnect should be propagated to the active request
+        retry(
+            || {
+                tunnel_a.propagate().unwrap();
+                tunnel_b.propagate().unwrap();
+
+                if !iox_active_request_b.is_connected() {
+                    return Ok(());
+                }
+                Err("failed to receive disconnect")
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+    }
+
+    #[test]
+    fn propagates_deadline_to_remote_server<S: Service>() {
+        const TIMEOUT: Duration = Duration::from_secs(60);
+
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig::default();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Client
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_client_a = iox_service_a.client_builder().create().unwrap();
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+
+        // [[ HOST B ]]
+        // Tunnel
+        let z_config_b = zenoh::Config::default();
+        let iox_config_b = generate_isolated_config();
+        let tunnel_config_b = TunnelConfig::default();
+        let mut tunnel_b =
+            Tunnel::<S>::create(&tunnel_config_b, &iox_config_b, &z_config_b).unwrap();
+
+        // Discover
+        discover_remote_service(&mut tunnel_b, iox_service_a.service_id().as_str());
+
+        // Server
+        let iox_node_b = NodeBuilder::new()
+            .config(&iox_config_b)
+            .create::<S>()
+            .unwrap();
+        let iox_service_b = iox_node_b
+            .service_builder(&iox_service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_server_b = iox_service_b.server_builder().create().unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Request with deadline
+        let _iox_pending_response_a = iox_client_a.send_copy_with_deadline(7, TIMEOUT).unwrap();
+        tunnel_a.propagate().unwrap();
+
+        // [[ HOST B ]]
+        // Receive - request should carry a deadline
+        let mut iox_active_request_b = None;
+        retry(
+            || {
+                tunnel_b.propagate().unwrap();
+                match iox_server_b.receive().unwrap() {
+                    Some(iox_active_request) => {
+                        iox_active_request_b = Some(iox_active_request);
+                        Ok(())
+                    }
+                    None => {
+                        tunnel_a.propagate().unwrap();
+                        Err("failed to receive expected request")
+                    }
+                }
+            },
+            TIME_BETWEEN_RETRIES,
+            Some(MAX_RETRIES),
+        );
+        let iox_active_request_b = iox_active_request_b.unwrap();
+        assert_that!(*iox_active_request_b, eq 7);
+        assert_that!(iox_active_request_b.header().deadline(), is_some);
+    }
+
+    #[test]
+    fn propagated_requests_do_not_loop_back<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ COMMON ]]
+        let iox_service_name = mock_service_name();
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config_a = zenoh::Config::default();
+        let iox_config_a = generate_isolated_config();
+        let tunnel_config_a = TunnelConfig::default();
+        let mut tunnel_a =
+            Tunnel::<S>::create(&tunnel_config_a, &iox_config_a, &z_config_a).unwrap();
+
+        // Client and Server
+        let iox_node_a = NodeBuilder::new()
+            .config(&iox_config_a)
+            .create::<S>()
+            .unwrap();
+        let iox_service_a = iox_node_a
+            .service_builder(&iox_service_name)
+            .request_response::<u64, u64>()
+            .open_or_create()
+            .unwrap();
+        let iox_client_a = iox_service_a.client_builder().create().unwrap();
+        let iox_server_a = iox_service_a.server_builder().create().unwrap();
+
+        // Discover
+        tunnel_a.discover(Scope::Iceoryx).unwrap();
+        assert_that!(tunnel_a.active_channels().len(), eq 2);
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Request
+        let _iox_pending_response_a = iox_client_a.send_copy(13).unwrap();
+
+        // Receive - Request should be received from local client
+        while let Ok(Some(_)) = iox_server_a.receive() {}
+
+        // Propagate
+        tunnel_a.propagate().unwrap();
+
+        // Receive - Request should not loop back and be received again
+        if iox_server_a.receive().unwrap().is_some() {
+            test_fail!("request looped back")
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,246 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod zenoh_tunnel_throughput_limits {
+    use core::time::Duration;
+    use std::time::Instant;
+
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnels_zenoh::*;
+
+    #[test]
+    fn rule_is_parsed_from_criteria_and_limits() {
+        let rule: ThroughputRule = "name=imu/*,max_rate=100,downsample=10,byte_budget=4096"
+            .parse()
+            .unwrap();
+
+        assert_that!(rule.rule.name, eq Some(String::from("imu/*")));
+        assert_that!(rule.limit.max_rate, eq Some(100));
+        assert_that!(rule.limit.downsample_period, eq Some(Duration::from_millis(10)));
+        assert_that!(rule.limit.byte_budget, eq Some(4096));
+    }
+
+    #[test]
+    fn rule_without_limit_is_rejected() {
+        assert_that!("name=imu/*".parse::<ThroughputRule>().is_err(), eq true);
+        assert_that!("name=imu/*,max_rate=fast".parse::<ThroughputRule>().is_err(), eq true);
+        assert_that!("color=red,max_rate=1".parse::<ThroughputRule>().is_err(), eq true);
+    }
+
+    #[test]
+    fn max_rate_is_enforced_per_second() {
+        let mut throttle = Throttle::new(ThroughputLimit {
+            max_rate: Some(2),
+            ..Default::default()
+        });
+        let now = Instant::now();
+
+        assert_that!(throttle.admit(now, 8), eq true);
+        assert_that!(throttle.admit(now, 8), eq true);
+        assert_that!(throttle.admit(now + Duration::from_millis(500), 8), eq false);
+        assert_that!(throttle.admit(now + Duration::from_millis(1000), 8), eq true);
+    }
+
+    #[test]
+    fn byte_budget_is_enforced_per_second() {
+        let mut throttle = Throttle::new(ThroughputLimit {
+            byte_budget: Some(16),
+            ..Default::default()
+        });
+        let now = Instant::now();
+
+        assert_that!(throttle.admit(now, 10), eq true);
+        assert_that!(throttle.admit(now, 10), eq false);
+        assert_that!(throttle.admit(now, 6), eq true);
+        assert_that!(throttle.admit(now + Duration::from_millis(1000), 10), eq true);
+        assert_that!(throttle.admit(now + Duration::from_millis(1000), 17), eq false);
+    }
+
+    #[test]
+    fn downsample_period_is_enforced() {
+        let mut throttle = Throttle::new(ThroughputLimit {
+            downsample_period: Some(Duration::from_millis(10)),
+            ..Default::default()
+        });
+        let now = Instant::now();
+
+        assert_that!(throttle.admit(now, 8), eq true);
+        assert_that!(throttle.admit(now + Duration::from_millis(5), 8), eq false);
+        assert_that!(throttle.admit(now + Duration::from_millis(10), 8), eq true);
+    }
+}
+
+#[generic_tests::define]
+mod zenoh_tunnel_throughput {
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_tunnels_zenoh::*;
+
+    const NUMBER_OF_SAMPLES: usize = 8;
+
+    fn mock_service_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "imu/test_zenoh_tunnel_throughput_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn samples_exceeding_max_rate_are_dropped<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            throughput_limits: vec!["name=imu/*,max_rate=3".parse().unwrap()],
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        // Publisher
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // Publish more samples than the limit allows
+        for n in 0..NUMBER_OF_SAMPLES {
+            iox_publisher.send_copy(n as u64).unwrap();
+        }
+        tunnel.propagate().unwrap();
+
+        let statistics = tunnel.publisher_statistics()[iox_service.service_id().as_str()];
+        assert_that!(statistics.propagated_samples, eq 3);
+        assert_that!(statistics.propagated_bytes, eq 3 * core::mem::size_of::<u64>() as u64);
+        assert_that!(statistics.dropped_samples, eq NUMBER_OF_SAMPLES as u64 - 3);
+    }
+
+    #[test]
+    fn downsampling_propagates_only_latest_sample<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            throughput_limits: vec!["name=imu/*,downsample=60000".parse().unwrap()],
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        // Publisher
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // The latest sample is propagated immediately
+        for n in 0..NUMBER_OF_SAMPLES {
+            iox_publisher.send_copy(n as u64).unwrap();
+        }
+        tunnel.propagate().unwrap();
+
+        let statistics = tunnel.publisher_statistics()[iox_service.service_id().as_str()];
+        assert_that!(statistics.propagated_samples, eq 1);
+        assert_that!(statistics.dropped_samples, eq NUMBER_OF_SAMPLES as u64 - 1);
+
+        // Subsequent samples are held back until the period elapsed
+        for n in 0..NUMBER_OF_SAMPLES {
+            iox_publisher.send_copy(n as u64).unwrap();
+        }
+        tunnel.propagate().unwrap();
+
+        let statistics = tunnel.publisher_statistics()[iox_service.service_id().as_str()];
+        assert_that!(statistics.propagated_samples, eq 1);
+        assert_that!(statistics.dropped_samples, eq 2 * NUMBER_OF_SAMPLES as u64 - 2);
+    }
+
+    #[test]
+    fn services_without_matching_rule_are_not_limited<S: Service>() {
+        // ==================== SETUP ====================
+
+        // [[ HOST A ]]
+        // Tunnel
+        let z_config = zenoh::Config::default();
+        let iox_config = generate_isolated_config();
+        let tunnel_config = TunnelConfig {
+            throughput_limits: vec!["name=camera/*,max_rate=1".parse().unwrap()],
+            ..Default::default()
+        };
+        let mut tunnel = Tunnel::<S>::create(&tunnel_config, &iox_config, &z_config).unwrap();
+
+        // Publisher
+        let iox_node = NodeBuilder::new()
+            .config(&iox_config)
+            .create::<S>()
+            .unwrap();
+        let iox_service = iox_node
+            .service_builder(&mock_service_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .open_or_create()
+            .unwrap();
+        let iox_publisher = iox_service.publisher_builder().create().unwrap();
+
+        tunnel.discover(Scope::Iceoryx).unwrap();
+
+        // ==================== TEST =====================
+
+        // [[ HOST A ]]
+        // All samples are propagated
+        for n in 0..NUMBER_OF_SAMPLES {
+            iox_publisher.send_copy(n as u64).unwrap();
+        }
+        tunnel.propagate().unwrap();
+
+        let statistics = tunnel.publisher_statistics()[iox_service.service_id().as_str()];
+        assert_that!(statistics.propagated_samples, eq NUMBER_OF_SAMPLES as u64);
+        assert_that!(statistics.dropped_samples, eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -42,7 +42,7 @@
 use alloc::sync::Arc;
 use core::{
     any::TypeId, fmt::Debug, marker::PhantomData, mem::MaybeUninit, ops::Deref,
-    sync::atomic::Ordering,
+    sync::atomic::Ordering, time::Duration,
 };
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -239,6 +239,21 @@ impl<
         self.ptr.as_header_ref()
     }
 
+    /// Returns the remaining time until the deadline of the
+    /// [`RequestMut`](crate::request_mut::RequestMut) is reached. When the deadline was already
+    /// missed [`Duration::ZERO`] is returned and when the request was sent without a deadline
+    /// [`None`] is returned.
+    pub fn time_until_deadline(&self) -> Option<Duration> {
+        self.header().time_until_deadline()
+    }
+
+    /// Returns [`true`] when the deadline of the [`RequestMut`](crate::request_mut::RequestMut)
+    /// was missed. The [`Client`](crate::port::client::Client) no longer waits for a
+    /// [`ResponseMut`] and the [`ActiveRequest`] can be considered stale.
+    pub fn has_missed_deadline(&self) -> bool {
+        self.header().has_missed_deadline()
+    }
+
     /// Returns the [`UniqueClientId`] of the [`Client`](crate::port::client::Client)
     pub fn origin(&self) -> UniqueClientId {
         UniqueClientId(UniqueSystemId::from(self.details.origin))
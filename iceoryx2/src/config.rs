@@ -84,6 +84,7 @@ use serde::{Deserialize, Serialize};
 
 use iceoryx2_bb_log::{debug, fail, fatal_panic, info, trace, warn};
 
+use crate::port::distribution_policy::DistributionPolicy;
 use crate::port::unable_to_deliver_strategy::UnableToDeliverStrategy;
 
 use iceoryx2_pal_configuration::settings::ICEORYX2_ROOT_PATH;
@@ -269,6 +270,10 @@ pub struct Defaults {
     pub request_response: RequestResonse,
     /// Default settings for the messaging pattern blackboard
     pub blackboard: Blackboard,
+    /// Default settings for the messaging pattern pipeline
+    pub pipeline: Pipeline,
+    /// Default settings for the messaging pattern log
+    pub log: Log,
 }
 
 /// Default settings for the publish-subscribe messaging pattern. These settings are used unless
@@ -488,6 +493,96 @@ impl Default for Blackboard {
     }
 }
 
+/// Default settings for the pipeline messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[serde(default)]
+pub struct Pipeline {
+    /// The maximum amount of supported [`Producer`](crate::port::producer::Producer)s
+    pub max_producers: usize,
+    /// The maximum amount of supported [`Consumer`](crate::port::consumer::Consumer)s
+    pub max_consumers: usize,
+    /// The maximum amount of supported [`Node`](crate::node::Node)s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    pub max_nodes: usize,
+    /// The maximum buffer size a [`Consumer`](crate::port::consumer::Consumer) can have
+    pub consumer_max_buffer_size: usize,
+    /// The maximum amount of [`WorkItem`](crate::work_item::WorkItem)s a
+    /// [`Consumer`](crate::port::consumer::Consumer) can hold at the same time.
+    pub consumer_max_borrowed_items: usize,
+    /// The maximum amount of [`WorkItemMut`](crate::work_item_mut::WorkItemMut)s a
+    /// [`Producer`](crate::port::producer::Producer) can loan at the same time.
+    pub producer_max_loaned_items: usize,
+    /// Defines how a [`Producer`](crate::port::producer::Producer) selects the
+    /// [`Consumer`](crate::port::consumer::Consumer) that receives the next work item.
+    pub distribution_policy: DistributionPolicy,
+    /// Defines the strategy of the [`Producer`](crate::port::producer::Producer) when the
+    /// buffers of all [`Consumer`](crate::port::consumer::Consumer)s are full.
+    pub unable_to_deliver_strategy: UnableToDeliverStrategy,
+    /// Defines the size of the internal [`Consumer`](crate::port::consumer::Consumer)
+    /// buffer that contains expired connections. A
+    /// connection is expired when the [`Producer`](crate::port::producer::Producer)
+    /// disconnected from a service and the connection
+    /// still contains unconsumed [`WorkItem`](crate::work_item::WorkItem)s.
+    pub consumer_expired_connection_buffer: usize,
+}
+
+impl Default for Pipeline {
+    fn default() -> Self {
+        Self {
+            max_producers: 2,
+            max_consumers: 8,
+            max_nodes: 20,
+            consumer_max_buffer_size: 2,
+            consumer_max_borrowed_items: 2,
+            producer_max_loaned_items: 2,
+            distribution_policy: DistributionPolicy::RoundRobin,
+            unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
+            consumer_expired_connection_buffer: 128,
+        }
+    }
+}
+
+/// Default settings for the log messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[serde(default)]
+pub struct Log {
+    /// The maximum amount of supported [`LogWriter`](crate::port::log_writer::LogWriter)s
+    pub max_writers: usize,
+    /// The maximum amount of supported [`LogCollector`](crate::port::log_collector::LogCollector)s
+    pub max_collectors: usize,
+    /// The maximum amount of supported [`Node`](crate::node::Node)s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    pub max_nodes: usize,
+    /// The maximum amount of log records a
+    /// [`LogCollector`](crate::port::log_collector::LogCollector) can buffer. When the buffer
+    /// is full the oldest log record is overridden.
+    pub collector_max_buffer_size: usize,
+    /// Defines the size of the internal [`LogCollector`](crate::port::log_collector::LogCollector)
+    /// buffer that contains expired connections. A
+    /// connection is expired when the [`LogWriter`](crate::port::log_writer::LogWriter)
+    /// disconnected from a service and the connection
+    /// still contains undrained log records.
+    pub collector_expired_connection_buffer: usize,
+}
+
+impl Default for Log {
+    fn default() -> Self {
+        Self {
+            max_writers: 32,
+            max_collectors: 2,
+            max_nodes: 32,
+            collector_max_buffer_size: 32,
+            collector_expired_connection_buffer: 128,
+        }
+    }
+}
+
 /// Represents the configuration that iceoryx2 will utilize. It is divided into two sections:
 /// the [`Global`] settings, which must align with the iceoryx2 instance the application intends to
 /// join, and the [`Defaults`] for communication within that iceoryx2 instance. The user has the
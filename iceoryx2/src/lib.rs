@@ -493,6 +493,9 @@
 //!
 //! # Feature Flags
 //!
+//!  * `async` - Enables the async/await API of the receiving ports, see
+//!    [`AsyncListener`](crate::port::async_listener::AsyncListener). Requires a tokio runtime
+//!    and is only available on unix platforms.
 //!  * `dev_permissions` - The permissions of all resources will be set to read, write, execute
 //!    for everyone. This shall not be used in production and is meant to be enabled in a docker
 //!    environment with inconsistent user configuration.
@@ -525,6 +528,9 @@ pub mod port;
 
 pub(crate) mod raw_sample;
 
+/// The structured log record that is transferred by the log messaging pattern
+pub mod log_record;
+
 /// Represents a "connection" to a [`Client`](crate::port::client::Client) that corresponds to a
 /// previously received [`RequestMut`](crate::request_mut::RequestMut).
 pub mod active_request;
@@ -541,6 +547,10 @@ pub mod request_mut;
 /// [`Server`](crate::port::server::Server).
 pub mod request_mut_uninit;
 
+/// Allows to deliver a sent [`SampleMut`](crate::sample_mut::SampleMut) again to all
+/// [`Subscriber`](crate::port::subscriber::Subscriber)s that did not receive it.
+pub mod resend_handle;
+
 /// The answer a [`Client`](crate::port::client::Client) receives from a
 /// [`Server`](crate::port::server::Server) on a [`RequestMut`](crate::request_mut::RequestMut).
 pub mod response;
@@ -568,6 +578,10 @@ pub mod sample_mut_uninit;
 /// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
 pub mod service;
 
+/// A [`Log`](iceoryx2_bb_log::Log) backend that forwards the log messages of the process into
+/// a log service.
+pub mod service_logger;
+
 /// Defines how constructs like the [`Node`](crate::node::Node) or the
 /// [`WaitSet`](crate::waitset::WaitSet) shall handle system signals.
 pub mod signal_handling_mode;
@@ -581,3 +595,16 @@ pub mod testing;
 /// Event handling mechanism to wait on multiple [`Listener`](crate::port::listener::Listener)s
 /// in one call, realizing the reactor pattern. (Event multiplexer)
 pub mod waitset;
+
+/// Runs the callbacks of the [`WaitSet`](crate::waitset::WaitSet) attachments in a pool of
+/// worker threads.
+pub mod waitset_executor;
+
+/// The work item that is received by a [`Consumer`](crate::port::consumer::Consumer).
+pub mod work_item;
+
+/// The work item that is sent by a [`Producer`](crate::port::producer::Producer).
+pub mod work_item_mut;
+
+/// The uninitialized work item that is sent by a [`Producer`](crate::port::producer::Producer).
+pub mod work_item_mut_uninit;
@@ -0,0 +1,167 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The structured log record that is transferred by the
+//! [`MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log).
+//! It has a fixed size so that it can be stored in shared memory. Strings that exceed the
+//! capacity are truncated.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::log_record::LogRecord;
+//! use iceoryx2_bb_log::LogLevel;
+//!
+//! let record = LogRecord::new(LogLevel::Warn, "MyApp::run()", "temperature is rising")
+//!     .with_field("sensor", "front-left")
+//!     .with_field("celsius", "87");
+//!
+//! assert_eq!(record.level(), LogLevel::Warn);
+//! assert_eq!(record.origin(), "MyApp::run()");
+//! assert_eq!(record.message(), "temperature is rising");
+//! assert_eq!(record.fields().len(), 2);
+//! ```
+
+use core::time::Duration;
+
+use iceoryx2_bb_container::byte_string::FixedSizeByteString;
+use iceoryx2_bb_container::vec::FixedSizeVec;
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::LogLevel;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+
+/// The maximum length of the origin of a [`LogRecord`].
+pub const LOG_RECORD_ORIGIN_CAPACITY: usize = 128;
+/// The maximum length of the message of a [`LogRecord`].
+pub const LOG_RECORD_MESSAGE_CAPACITY: usize = 512;
+/// The maximum number of [`LogField`]s a [`LogRecord`] can contain.
+pub const LOG_RECORD_MAX_FIELDS: usize = 8;
+/// The maximum length of the key of a [`LogField`].
+pub const LOG_FIELD_KEY_CAPACITY: usize = 32;
+/// The maximum length of the value of a [`LogField`].
+pub const LOG_FIELD_VALUE_CAPACITY: usize = 64;
+
+fn truncated<const CAPACITY: usize>(value: &str) -> FixedSizeByteString<CAPACITY> {
+    // never split a multi-byte character so that the content stays valid UTF-8
+    let mut len = value.len().min(CAPACITY);
+    while !value.is_char_boundary(len) {
+        len -= 1;
+    }
+
+    FixedSizeByteString::from_bytes_truncated(&value.as_bytes()[..len])
+}
+
+fn as_str<const CAPACITY: usize>(value: &FixedSizeByteString<CAPACITY>) -> &str {
+    value.as_str().unwrap_or_default()
+}
+
+/// A key-value pair that attaches structured context to a [`LogRecord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+#[repr(C)]
+pub struct LogField {
+    key: FixedSizeByteString<LOG_FIELD_KEY_CAPACITY>,
+    value: FixedSizeByteString<LOG_FIELD_VALUE_CAPACITY>,
+}
+
+impl LogField {
+    /// Creates a new [`LogField`]. Key and value are truncated when they exceed
+    /// [`LOG_FIELD_KEY_CAPACITY`] or [`LOG_FIELD_VALUE_CAPACITY`].
+    pub fn new(key: &str, value: &str) -> Self {
+        Self {
+            key: truncated(key),
+            value: truncated(value),
+        }
+    }
+
+    /// Returns the key of the [`LogField`].
+    pub fn key(&self) -> &str {
+        as_str(&self.key)
+    }
+
+    /// Returns the value of the [`LogField`].
+    pub fn value(&self) -> &str {
+        as_str(&self.value)
+    }
+}
+
+/// A structured log record consisting of the [`LogLevel`], the origin, the time it was
+/// created, the message and up to [`LOG_RECORD_MAX_FIELDS`] [`LogField`]s.
+#[derive(Debug, Clone, PartialEq, Eq, ZeroCopySend)]
+#[repr(C)]
+pub struct LogRecord {
+    timestamp: u64,
+    level: u8,
+    origin: FixedSizeByteString<LOG_RECORD_ORIGIN_CAPACITY>,
+    message: FixedSizeByteString<LOG_RECORD_MESSAGE_CAPACITY>,
+    fields: FixedSizeVec<LogField, LOG_RECORD_MAX_FIELDS>,
+}
+
+impl LogRecord {
+    /// Creates a new [`LogRecord`] with the current realtime clock as timestamp. Origin and
+    /// message are truncated when they exceed [`LOG_RECORD_ORIGIN_CAPACITY`] or
+    /// [`LOG_RECORD_MESSAGE_CAPACITY`].
+    pub fn new(level: LogLevel, origin: &str, message: &str) -> Self {
+        // when the realtime clock is not supported the timestamp is zero
+        let timestamp = Time::now_with_clock(ClockType::Realtime)
+            .map(|now| now.as_duration().as_nanos() as u64)
+            .unwrap_or_default();
+
+        Self {
+            timestamp,
+            level: level as u8,
+            origin: truncated(origin),
+            message: truncated(message),
+            fields: FixedSizeVec::new(),
+        }
+    }
+
+    /// Adds a [`LogField`] to the [`LogRecord`]. When the [`LogRecord`] contains already
+    /// [`LOG_RECORD_MAX_FIELDS`] fields, the field is discarded.
+    pub fn with_field(mut self, key: &str, value: &str) -> Self {
+        self.fields.push(LogField::new(key, value));
+        self
+    }
+
+    /// Returns the [`LogLevel`] of the [`LogRecord`].
+    pub fn level(&self) -> LogLevel {
+        match self.level {
+            0 => LogLevel::Trace,
+            1 => LogLevel::Debug,
+            2 => LogLevel::Info,
+            3 => LogLevel::Warn,
+            4 => LogLevel::Error,
+            _ => LogLevel::Fatal,
+        }
+    }
+
+    /// Returns the time the [`LogRecord`] was created as duration since the unix epoch.
+    pub fn timestamp(&self) -> Duration {
+        Duration::from_nanos(self.timestamp)
+    }
+
+    /// Returns the origin of the [`LogRecord`].
+    pub fn origin(&self) -> &str {
+        as_str(&self.origin)
+    }
+
+    /// Returns the message of the [`LogRecord`].
+    pub fn message(&self) -> &str {
+        as_str(&self.message)
+    }
+
+    /// Returns all [`LogField`]s of the [`LogRecord`].
+    pub fn fields(&self) -> &[LogField] {
+        &self.fields
+    }
+}
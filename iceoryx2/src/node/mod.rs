@@ -201,6 +201,10 @@ use std::sync::Mutex;
 pub struct NodeId(UniqueSystemId);
 
 impl NodeId {
+    pub(crate) fn from_value(value: u128) -> Self {
+        Self(value.into())
+    }
+
     pub(crate) fn as_file_name(&self) -> FileName {
         fatal_panic!(from self, when FileName::new(self.0.to_string().as_bytes()),
                         "This should never happen! The NodeId shall be always a valid FileName.")
@@ -507,7 +511,9 @@ impl<Service: service::Service> DeadNodeView<Service> {
         .remove_stale_resources()
     }
 
-    /// Removes all stale resources of a dead [`Node`].
+    /// Removes all stale resources of a dead [`Node`]. Samples that were delivered to or
+    /// borrowed by the receiving ports of the dead [`Node`] are returned to the sending ports
+    /// of the living [`Node`]s.
     pub fn remove_stale_resources(self) -> Result<bool, NodeCleanupFailure> {
         let msg = "Unable to remove stale resources";
         let monitor_name = fatal_panic!(from self, when FileName::new(self.id().0.value().to_string().as_bytes()),
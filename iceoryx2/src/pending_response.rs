@@ -57,18 +57,38 @@
 
 use core::ops::Deref;
 use core::sync::atomic::Ordering;
+use core::time::Duration;
 use core::{fmt::Debug, marker::PhantomData};
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 
 use crate::port::client::ClientSharedState;
 use crate::port::details::chunk::Chunk;
 use crate::port::details::chunk_details::ChunkDetails;
+use crate::port::SignalListener;
 use crate::raw_sample::RawSample;
 use crate::service::builder::CustomPayloadMarker;
-use crate::{port::ReceiveError, request_mut::RequestMut, response::Response, service};
+use crate::{
+    port::{ReceiveError, TimedReceiveError},
+    request_mut::RequestMut,
+    response::Response,
+    service,
+};
+
+// the maximum time a timed receive waits for the response signal before it checks for
+// responses again
+const MAX_RESPONSE_SIGNAL_WAIT_SLICE: Duration = Duration::from_millis(10);
+
+fn monotonic_now() -> Duration {
+    // when the monotonic clock is not supported the time is zero
+    Time::now_with_clock(ClockType::Monotonic)
+        .map(|now| now.as_duration())
+        .unwrap_or_default()
+}
 
 /// Represents an active connection to all [`Server`](crate::port::server::Server)
 /// that received the [`RequestMut`]. The
@@ -231,6 +251,24 @@ impl<
         self.number_of_server_connections
     }
 
+    /// Returns the remaining time until the deadline of the corresponding [`RequestMut`] is
+    /// reached. When the deadline was already missed [`Duration::ZERO`] is returned and when
+    /// the [`RequestMut`] was sent without a deadline [`None`] is returned.
+    ///
+    /// The remaining time can be used to attach the [`PendingResponse`] with
+    /// [`WaitSet::attach_deadline()`](crate::waitset::WaitSet::attach_deadline()) when the
+    /// [`Service`](crate::service::Service) was created with
+    /// [`enable_notify_on_send()`](crate::service::builder::request_response::Builder::enable_notify_on_send).
+    pub fn time_until_deadline(&self) -> Option<Duration> {
+        self.request.header().time_until_deadline()
+    }
+
+    /// Returns [`true`] when the deadline of the corresponding [`RequestMut`] was missed,
+    /// otherwise [`false`]. A [`RequestMut`] without a deadline never misses it.
+    pub fn has_missed_deadline(&self) -> bool {
+        self.request.header().has_missed_deadline()
+    }
+
     /// Returns [`true`] when a [`Server`](crate::port::server::Server) has sent a [`Response`]
     /// otherwise [`false`].
     pub fn has_response(&self) -> bool {
@@ -247,10 +285,133 @@ impl<
         fail!(from self, when client_shared_state.update_connections(),
                 "{msg} since the connections could not be updated.");
 
+        if let Some(listener) = &client_shared_state.response_listener {
+            if let Err(e) = listener.consume_signals() {
+                warn!(from self, "Unable to consume the pending send notifications ({:?}).", e);
+            }
+        }
+
         client_shared_state
             .response_receiver
             .receive(self.request.channel_id)
     }
+
+    fn timed_receive_impl<T, F: FnMut() -> Result<Option<T>, ReceiveError>>(
+        &self,
+        timeout: Duration,
+        receive: F,
+    ) -> Result<Option<T>, TimedReceiveError> {
+        match self.response_listener() {
+            Some(listener) => self.timed_receive_with_listener(listener, timeout, receive),
+            None => self.timed_receive_with_adaptive_wait(timeout, receive),
+        }
+    }
+
+    // Waits on the response signal of the client. Since all pending responses of the client
+    // share the signal, another pending response may consume it. Therefore, the wait is split
+    // into slices of at most MAX_RESPONSE_SIGNAL_WAIT_SLICE.
+    fn timed_receive_with_listener<T, F: FnMut() -> Result<Option<T>, ReceiveError>>(
+        &self,
+        listener: &SignalListener<Service>,
+        timeout: Duration,
+        mut receive: F,
+    ) -> Result<Option<T>, TimedReceiveError> {
+        let msg = "Unable to receive response with timeout";
+        let start = monotonic_now();
+        loop {
+            if let Some(response) = receive()? {
+                return Ok(Some(response));
+            }
+
+            if self.has_missed_deadline() {
+                fail!(from self, with TimedReceiveError::DeadlineMissed,
+                    "{msg} since the deadline of the request was missed.");
+            }
+
+            let elapsed = monotonic_now().saturating_sub(start);
+            if timeout <= elapsed {
+                return Ok(None);
+            }
+
+            let mut wait_time = (timeout - elapsed).min(MAX_RESPONSE_SIGNAL_WAIT_SLICE);
+            if let Some(time_until_deadline) = self.time_until_deadline() {
+                wait_time = wait_time.min(time_until_deadline);
+            }
+
+            if let Err(e) = listener.timed_wait(wait_time) {
+                warn!(from self, "{} since waiting for the response signal failed ({:?}).", msg, e);
+                return Ok(None);
+            }
+        }
+    }
+
+    fn timed_receive_with_adaptive_wait<T, F: FnMut() -> Result<Option<T>, ReceiveError>>(
+        &self,
+        timeout: Duration,
+        mut receive: F,
+    ) -> Result<Option<T>, TimedReceiveError> {
+        let msg = "Unable to receive response with timeout";
+        let mut adaptive_wait = None;
+        loop {
+            if let Some(response) = receive()? {
+                return Ok(Some(response));
+            }
+
+            if self.has_missed_deadline() {
+                fail!(from self, with TimedReceiveError::DeadlineMissed,
+                    "{msg} since the deadline of the request was missed.");
+            }
+
+            let wait = adaptive_wait.get_or_insert_with(|| {
+                fatal_panic!(from self, when AdaptiveWaitBuilder::new().create(),
+                    "{} since the adaptive wait could not be created.", msg)
+            });
+
+            match wait.wait() {
+                Ok(elapsed) if elapsed < timeout => (),
+                Ok(_) => return Ok(None),
+                Err(e) => {
+                    warn!(from self, "{} since the adaptive wait failed ({:?}).", msg, e);
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    fn response_listener(&self) -> Option<&SignalListener<Service>> {
+        let client_shared_state = self.request.client_shared_state.lock();
+        client_shared_state
+            .response_listener
+            .as_ref()
+            .map(|listener| {
+                let listener = listener as *const SignalListener<Service>;
+                // the shared state and its listener live at least as long as the pending response
+                unsafe { &*listener }
+            })
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ZeroCopySend + ?Sized,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend + ?Sized,
+        ResponseHeader: Debug + ZeroCopySend,
+    > PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Returns the [`SignalListener`] that is signaled whenever a
+    /// [`Server`](crate::port::server::Server) sent a [`Response`] to the
+    /// [`Client`](crate::port::client::Client). It can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet). All [`PendingResponse`]s of a
+    /// [`Client`](crate::port::client::Client) share the same [`SignalListener`] and every
+    /// receive call consumes the pending signals, therefore all [`PendingResponse`]s shall be
+    /// checked for [`Response`]s after a wakeup.
+    ///
+    /// Returns [`None`] when the [`Service`](crate::service::Service) was created without
+    /// [`enable_notify_on_send()`](crate::service::builder::request_response::Builder::enable_notify_on_send).
+    pub fn signal_listener(&self) -> Option<&SignalListener<Service>> {
+        self.response_listener()
+    }
 }
 
 impl<
@@ -320,6 +481,75 @@ impl<
             }
         }
     }
+
+    /// Receives a [`Response`] from one of the [`Server`](crate::port::server::Server)s that
+    /// received the [`RequestMut`] and waits at most for the provided `timeout`. When the
+    /// deadline of the [`RequestMut`] passes before a [`Response`] was received
+    /// [`TimedReceiveError::DeadlineMissed`] is returned. When the `timeout` passes first
+    /// [`None`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::TimedReceiveError;
+    /// use core::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node
+    /// #    .service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #    .request_response::<u64, u64>()
+    /// #    .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// # let request = client.loan_uninit()?;
+    /// # let request = request.write_payload(0);
+    ///
+    /// let pending_response = request.send_with_deadline(Duration::from_millis(100))?;
+    ///
+    /// match pending_response.timed_receive(Duration::from_millis(10)) {
+    ///     Ok(Some(response)) => println!("received response: {:?}", response),
+    ///     Ok(None) => println!("no response yet"),
+    ///     Err(TimedReceiveError::DeadlineMissed) => println!("deadline missed"),
+    ///     Err(e) => println!("failed to receive response: {e:?}"),
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn timed_receive(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Response<Service, ResponsePayload, ResponseHeader>>, TimedReceiveError> {
+        self.timed_receive_impl(timeout, || self.receive())
+    }
+
+    /// Waits asynchronously until a [`Response`] from one of the
+    /// [`Server`](crate::port::server::Server)s that received the [`RequestMut`] was received.
+    /// The task is woken up by the [`SignalListener`] of the
+    /// [`Client`](crate::port::client::Client), therefore the service must be created with
+    /// notify on send enabled, otherwise
+    /// [`AsyncReceiveError::NotifyOnSendDisabled`](crate::port::AsyncReceiveError::NotifyOnSendDisabled)
+    /// is returned.
+    #[cfg(feature = "async")]
+    pub async fn receive_async(
+        &self,
+    ) -> Result<Response<Service, ResponsePayload, ResponseHeader>, crate::port::AsyncReceiveError>
+    where
+        <Service::Event as iceoryx2_cal::event::Event>::Listener:
+            iceoryx2_bb_posix::file_descriptor::FileDescriptorBased,
+    {
+        match self.signal_listener() {
+            Some(listener) => listener.receive_async(|| self.receive()).await,
+            None => {
+                fail!(from self, with crate::port::AsyncReceiveError::NotifyOnSendDisabled,
+                    "Unable to receive asynchronously since the service was created without notify on send.");
+            }
+        }
+    }
 }
 
 impl<
@@ -396,6 +626,76 @@ impl<
             }
         }
     }
+
+    /// Receives a [`Response`] from one of the [`Server`](crate::port::server::Server)s that
+    /// received the [`RequestMut`] and waits at most for the provided `timeout`. When the
+    /// deadline of the [`RequestMut`] passes before a [`Response`] was received
+    /// [`TimedReceiveError::DeadlineMissed`] is returned. When the `timeout` passes first
+    /// [`None`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::TimedReceiveError;
+    /// use core::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node
+    /// #    .service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #    .request_response::<u64, [usize]>()
+    /// #    .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// # let request = client.loan_uninit()?;
+    /// # let request = request.write_payload(0);
+    ///
+    /// let pending_response = request.send_with_deadline(Duration::from_millis(100))?;
+    ///
+    /// match pending_response.timed_receive(Duration::from_millis(10)) {
+    ///     Ok(Some(response)) => println!("received response: {:?}", response),
+    ///     Ok(None) => println!("no response yet"),
+    ///     Err(TimedReceiveError::DeadlineMissed) => println!("deadline missed"),
+    ///     Err(e) => println!("failed to receive response: {e:?}"),
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn timed_receive(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Response<Service, [ResponsePayload], ResponseHeader>>, TimedReceiveError>
+    {
+        self.timed_receive_impl(timeout, || self.receive())
+    }
+
+    /// Waits asynchronously until a [`Response`] from one of the
+    /// [`Server`](crate::port::server::Server)s that received the [`RequestMut`] was received.
+    /// The task is woken up by the [`SignalListener`] of the
+    /// [`Client`](crate::port::client::Client), therefore the service must be created with
+    /// notify on send enabled, otherwise
+    /// [`AsyncReceiveError::NotifyOnSendDisabled`](crate::port::AsyncReceiveError::NotifyOnSendDisabled)
+    /// is returned.
+    #[cfg(feature = "async")]
+    pub async fn receive_async(
+        &self,
+    ) -> Result<Response<Service, [ResponsePayload], ResponseHeader>, crate::port::AsyncReceiveError>
+    where
+        <Service::Event as iceoryx2_cal::event::Event>::Listener:
+            iceoryx2_bb_posix::file_descriptor::FileDescriptorBased,
+    {
+        match self.signal_listener() {
+            Some(listener) => listener.receive_async(|| self.receive()).await,
+            None => {
+                fail!(from self, with crate::port::AsyncReceiveError::NotifyOnSendDisabled,
+                    "Unable to receive asynchronously since the service was created without notify on send.");
+            }
+        }
+    }
 }
 
 impl<
@@ -0,0 +1,202 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`AsyncListener`] wraps a [`Listener`] and registers its file descriptor at the
+//! reactor of the tokio runtime. Whenever a [`Notifier`](crate::port::notifier::Notifier)
+//! signals the [`Listener`], the waiting task is woken up - no busy polling is involved.
+//!
+//! The receiving ports of publish-subscribe and request-response do not require an
+//! [`AsyncListener`]. Their async receive methods, like
+//! [`Subscriber::receive_async()`](crate::port::subscriber::Subscriber::receive_async()),
+//! wait on the [`SignalListener`](crate::port::SignalListener) of the port which is available
+//! when the service was created with notify on send enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::async_listener::AsyncListener;
+//!
+//! # async fn example() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node.service_builder(&"MyEventName".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let listener = AsyncListener::new(event.listener_builder().create()?)?;
+//!
+//! loop {
+//!     let event_id = listener.wait_one().await?;
+//!     println!("event was triggered with id: {:?}", event_id);
+//! }
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::os::fd::{AsRawFd, RawFd};
+
+use futures_core::Stream;
+use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_cal::event::ListenerWaitError;
+use tokio::io::unix::AsyncFd;
+use tokio::io::Interest;
+
+use crate::port::event_id::EventId;
+use crate::port::listener::Listener;
+use crate::service;
+
+/// Defines the failures that can occur when an [`AsyncListener`] is created.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AsyncListenerCreateError {
+    /// The file descriptor of the [`Listener`] could not be registered at the reactor of
+    /// the tokio runtime.
+    UnableToRegisterAtReactor,
+}
+
+impl core::fmt::Display for AsyncListenerCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AsyncListenerCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for AsyncListenerCreateError {}
+
+/// Raw file descriptor of a listener that is registered at the reactor of the tokio runtime.
+/// It does not own the file descriptor, the listener closes it.
+#[derive(Debug)]
+pub(crate) struct ListenerFd(pub(crate) RawFd);
+
+impl AsRawFd for ListenerFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0
+    }
+}
+
+/// Asynchronous receiving endpoint of an event based communication. It wraps a
+/// [`Listener`] and wakes up the waiting task as soon as the underlying file
+/// descriptor becomes readable.
+#[derive(Debug)]
+pub struct AsyncListener<Service: service::Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    // must be dropped before the listener since it deregisters the file descriptor owned by the
+    // listener
+    fd: AsyncFd<ListenerFd>,
+    listener: Listener<Service>,
+}
+
+impl<Service: service::Service> AsyncListener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    /// Creates a new [`AsyncListener`] from a [`Listener`]. Must be called from within the
+    /// context of a tokio runtime.
+    pub fn new(listener: Listener<Service>) -> Result<Self, AsyncListenerCreateError> {
+        // the file descriptor and its value never changes during the lifetime of the listener
+        let raw_fd = unsafe { listener.file_descriptor().native_handle() };
+        let fd = match AsyncFd::with_interest(ListenerFd(raw_fd), Interest::READABLE) {
+            Ok(fd) => fd,
+            Err(e) => {
+                fail!(from listener, with AsyncListenerCreateError::UnableToRegisterAtReactor,
+                    "Unable to create async listener since the file descriptor could not be registered at the reactor ({e:?}).");
+            }
+        };
+
+        Ok(Self { fd, listener })
+    }
+
+    /// Returns a reference to the underlying [`Listener`].
+    pub fn listener(&self) -> &Listener<Service> {
+        &self.listener
+    }
+
+    /// Releases the [`AsyncListener`] and returns the underlying [`Listener`].
+    pub fn into_inner(self) -> Listener<Service> {
+        let Self { fd, listener } = self;
+        drop(fd);
+        listener
+    }
+
+    /// Polls for a new [`EventId`]. Registers the current task to be woken up when no
+    /// [`EventId`] is available.
+    pub fn poll_wait_one(&self, cx: &mut Context<'_>) -> Poll<Result<EventId, ListenerWaitError>> {
+        loop {
+            let mut guard = match self.fd.poll_read_ready(cx) {
+                Poll::Ready(Ok(guard)) => guard,
+                Poll::Ready(Err(e)) => {
+                    debug!(from self,
+                        "Unable to wait on the listener since the reactor of the tokio runtime failed ({e:?}).");
+                    return Poll::Ready(Err(ListenerWaitError::InternalFailure));
+                }
+                Poll::Pending => return Poll::Pending,
+            };
+
+            match self.listener.try_wait_one() {
+                Ok(Some(event_id)) => return Poll::Ready(Ok(event_id)),
+                // the readiness can only be cleared when the underlying listener has no more
+                // events, otherwise the next wakeup may be lost
+                Ok(None) => guard.clear_ready(),
+                Err(e) => return Poll::Ready(Err(e)),
+            }
+        }
+    }
+
+    /// Waits asynchronously for a new [`EventId`].
+    pub async fn wait_one(&self) -> Result<EventId, ListenerWaitError> {
+        core::future::poll_fn(|cx| self.poll_wait_one(cx)).await
+    }
+
+    /// Waits asynchronously until at least one [`EventId`] was received and then collects all
+    /// [`EventId`]s that were received and calls the provided callback with the [`EventId`]
+    /// as input argument.
+    pub async fn wait_all<F: FnMut(EventId)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        callback(self.wait_one().await?);
+        while let Some(event_id) = self.listener.try_wait_one()? {
+            callback(event_id);
+        }
+
+        Ok(())
+    }
+
+    /// Returns an infinite [`Stream`] of all received [`EventId`]s.
+    pub fn events(&self) -> EventStream<'_, Service> {
+        EventStream { listener: self }
+    }
+}
+
+/// [`Stream`] of all [`EventId`]s received by an [`AsyncListener`]. Acquired via
+/// [`AsyncListener::events()`].
+#[derive(Debug)]
+pub struct EventStream<'listener, Service: service::Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    listener: &'listener AsyncListener<Service>,
+}
+
+impl<Service: service::Service> Stream for EventStream<'_, Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    type Item = Result<EventId, ListenerWaitError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.listener.poll_wait_one(cx).map(Some)
+    }
+}
@@ -74,7 +74,7 @@
 
 use core::{
     any::TypeId, cell::UnsafeCell, fmt::Debug, marker::PhantomData, mem::MaybeUninit,
-    sync::atomic::Ordering,
+    sync::atomic::Ordering, time::Duration,
 };
 use iceoryx2_bb_container::{queue::Queue, slotmap::SlotMap, vec::Vec};
 
@@ -93,7 +93,9 @@ use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, Iox
 use crate::{
     pending_response::PendingResponse,
     port::{
-        details::data_segment::DataSegment, update_connections::UpdateConnections, UniqueClientId,
+        details::data_segment::DataSegment, distribution_policy::DistributionPolicy,
+        request_delivery_policy::RequestDeliveryPolicy, update_connections::UpdateConnections,
+        UniqueClientId,
     },
     prelude::{PortFactory, UnableToDeliverStrategy},
     raw_sample::RawSampleMut,
@@ -116,6 +118,7 @@ use super::{
         receiver::{Receiver, SenderDetails},
         segment_state::SegmentState,
         sender::{ReceiverDetails, Sender},
+        signal::{SignalChannel, SignalListener, SignalNotifier},
     },
     update_connections::ConnectionFailure,
     LoanError, SendError,
@@ -168,6 +171,9 @@ pub(crate) struct ClientSharedState<Service: service::Service> {
     server_list_state: UnsafeCell<ContainerState<ServerDetails>>,
     pub(crate) active_request_counter: IoxAtomicUsize,
     pub(crate) available_channel_ids: UnsafeCell<Queue<ChannelId>>,
+    next_server_connection: IoxAtomicUsize,
+    request_notifier: Option<SignalNotifier<Service>>,
+    pub(crate) response_listener: Option<SignalListener<Service>>,
 }
 
 impl<Service: service::Service> Drop for ClientSharedState<Service> {
@@ -217,13 +223,107 @@ impl<Service: service::Service> ClientSharedState<Service> {
         self.prepare_channel_to_receive_responses(channel_id, request_id);
 
         self.active_request_counter.fetch_add(1, Ordering::Relaxed);
-        Ok(self.request_sender.deliver_offset(
+        let number_of_recipients = match self.config.delivery_policy {
+            RequestDeliveryPolicy::Broadcast => self.request_sender.deliver_offset(
+                offset,
+                sample_size,
+                // All requests are delivered on the same channel, therefore we can use
+                // ChannelId::new(0).
+                ChannelId::new(0),
+            )?,
+            RequestDeliveryPolicy::AnyOne(distribution_policy) => {
+                let recipient =
+                    self.deliver_to_any_one_server(offset, sample_size, distribution_policy)?;
+                self.restrict_response_channel_to(channel_id, request_id, recipient)
+            }
+            RequestDeliveryPolicy::Addressed(server_id) => {
+                let recipient = match self.request_sender.get_connection_id_of(server_id.value()) {
+                    Some(connection_id) => {
+                        self.deliver_to_server_connection(offset, sample_size, connection_id)?
+                    }
+                    None => None,
+                };
+                self.restrict_response_channel_to(channel_id, request_id, recipient)
+            }
+        };
+
+        if number_of_recipients > 0 {
+            if let Some(notifier) = &self.request_notifier {
+                notifier.notify();
+            }
+        }
+
+        Ok(number_of_recipients)
+    }
+
+    /// Returns the port id of the server that received the request.
+    fn deliver_to_server_connection(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        connection_id: usize,
+    ) -> Result<Option<u128>, SendError> {
+        let server_port_id = match self.request_sender.receiver_port_id_of(connection_id) {
+            Some(port_id) => port_id,
+            None => return Ok(None),
+        };
+
+        match self.request_sender.deliver_offset_to_connection(
+            offset,
+            sample_size,
+            ChannelId::new(0),
+            connection_id,
+        )? {
+            0 => Ok(None),
+            _ => Ok(Some(server_port_id)),
+        }
+    }
+
+    /// Returns the port id of the server that received the request.
+    fn deliver_to_any_one_server(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        distribution_policy: DistributionPolicy,
+    ) -> Result<Option<u128>, SendError> {
+        match self.request_sender.deliver_to_one(
             offset,
             sample_size,
-            // All requests are delivered on the same channel, therefore we can use
-            // ChannelId::new(0).
             ChannelId::new(0),
-        )?)
+            self.next_server_connection.load(Ordering::Relaxed),
+            distribution_policy,
+        )? {
+            Some((connection_id, server_port_id)) => {
+                self.next_server_connection
+                    .store(connection_id + 1, Ordering::Relaxed);
+                Ok(Some(server_port_id))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// The response channel was prepared for all servers before the request was sent. When
+    /// only one server received the request, the channel state of all other servers is
+    /// invalidated so that the [`PendingResponse`] is disconnected as soon as the receiving
+    /// server drops the request.
+    fn restrict_response_channel_to(
+        &self,
+        channel_id: ChannelId,
+        request_id: u64,
+        recipient: Option<u128>,
+    ) -> usize {
+        match recipient {
+            Some(server_port_id) => {
+                self.response_receiver
+                    .invalidate_channel_state_of_all_except(channel_id, request_id, server_port_id);
+                1
+            }
+            None => {
+                self.response_receiver
+                    .invalidate_channel_state(channel_id, request_id);
+                0
+            }
+        }
     }
 
     pub(crate) fn update_connections(
@@ -466,8 +566,24 @@ impl<
             connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
         };
 
+        let (request_notifier, response_listener) = match static_config.enable_notify_on_send {
+            true => (
+                Some(fail!(from origin,
+                    when SignalNotifier::create(service, SignalChannel::Requests),
+                    with ClientCreateError::UnableToCreateNotificationChannel,
+                    "{} since the notification channel for the requests could not be created.", msg)),
+                Some(fail!(from origin,
+                    when SignalListener::create(service, SignalChannel::Responses(client_id.value())),
+                    with ClientCreateError::UnableToCreateNotificationChannel,
+                    "{} since the notification channel for the responses could not be created.", msg)),
+            ),
+            false => (None, None),
+        };
+
         let client_shared_state = Service::ArcThreadSafetyPolicy::new(ClientSharedState {
             config: client_factory.config,
+            request_notifier,
+            response_listener,
             client_handle: UnsafeCell::new(None),
             available_channel_ids: {
                 let mut queue = Queue::new(number_of_requests);
@@ -480,6 +596,7 @@ impl<
             response_receiver,
             server_list_state: UnsafeCell::new(unsafe { server_list.get_state() }),
             active_request_counter: IoxAtomicUsize::new(0),
+            next_server_connection: IoxAtomicUsize::new(0),
         });
 
         let client_shared_state = match client_shared_state {
@@ -665,6 +782,8 @@ impl<
                 channel_id,
                 request_id: self.request_id_counter.fetch_add(1, Ordering::Relaxed),
                 number_of_elements: 1,
+                delivery_policy: client_shared_state.config.delivery_policy,
+                deadline_in_ns: 0,
             })
         };
         unsafe { user_header_ptr.write(RequestHeader::default()) };
@@ -709,6 +828,26 @@ impl<
 
         request.write_payload(value).send()
     }
+
+    /// Copies the input value into a [`RequestMut`] and sends it with a deadline, see
+    /// [`RequestMut::send_with_deadline()`]. On success it returns a [`PendingResponse`] that
+    /// can be used to receive a stream of [`Response`](crate::response::Response)s from the
+    /// [`Server`](crate::port::server::Server).
+    pub fn send_copy_with_deadline(
+        &self,
+        value: RequestPayload,
+        timeout: Duration,
+    ) -> Result<
+        PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        RequestSendError,
+    > {
+        let msg = "Unable to send copy of request with deadline";
+        let request = fail!(from self,
+                            when self.loan_uninit(),
+                            "{} since the loan of the request failed.", msg);
+
+        request.write_payload(value).send_with_deadline(timeout)
+    }
 }
 
 impl<
@@ -943,6 +1082,8 @@ impl<
                 channel_id,
                 request_id: self.request_id_counter.fetch_add(1, Ordering::Relaxed),
                 number_of_elements: slice_len as _,
+                delivery_policy: client_shared_state.config.delivery_policy,
+                deadline_in_ns: 0,
             })
         };
         unsafe { user_header_ptr.write(RequestHeader::default()) };
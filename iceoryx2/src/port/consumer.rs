@@ -0,0 +1,343 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .pipeline::<u64>()
+//!     .open_or_create()?;
+//!
+//! let consumer = service.consumer_builder().create()?;
+//!
+//! while let Some(work_item) = consumer.receive()? {
+//!     println!("received: {:?}", *work_item);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::cell::UnsafeCell;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::sync::atomic::Ordering;
+
+use iceoryx2_bb_container::slotmap::SlotMap;
+use iceoryx2_bb_container::vec::Vec;
+use iceoryx2_bb_elementary::cyclic_tagger::CyclicTagger;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::zero_copy_connection::ChannelId;
+
+use crate::port::update_connections::UpdateConnections;
+use crate::service::dynamic_config::pipeline::{ConsumerDetails, ProducerDetails};
+use crate::service::port_factory::consumer::ConsumerConfig;
+use crate::service::static_config::pipeline::StaticConfig;
+use crate::service::{NoResource, ServiceState};
+use crate::{raw_sample::RawSample, service, work_item::WorkItem};
+
+use super::details::receiver::*;
+use super::port_identifiers::UniqueConsumerId;
+use super::update_connections::ConnectionFailure;
+use super::ReceiveError;
+
+use alloc::sync::Arc;
+
+/// Describes the failures when a new [`Consumer`] is created via the
+/// [`crate::service::port_factory::consumer::PortFactoryConsumer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ConsumerCreateError {
+    /// The maximum amount of [`Consumer`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Consumer`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedConsumers,
+    /// When the [`Consumer`] requires a larger buffer size than the
+    /// [`Service`](crate::service::Service) offers the creation will fail.
+    BufferSizeExceedsMaxSupportedBufferSizeOfService,
+    /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
+    /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
+    FailedToDeployThreadsafetyPolicy,
+}
+
+impl core::fmt::Display for ConsumerCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ConsumerCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for ConsumerCreateError {}
+
+#[derive(Debug)]
+pub(crate) struct ConsumerSharedState<Service: service::Service> {
+    pub(crate) receiver: Receiver<Service>,
+    pub(crate) producer_list_state: UnsafeCell<ContainerState<ProducerDetails>>,
+}
+
+/// The receiving endpoint of a pipeline based communication. It receives only the work items
+/// that the [`Producer`](crate::port::producer::Producer)s handed to it.
+#[derive(Debug)]
+pub struct Consumer<Service: service::Service, Payload: Debug + ZeroCopySend + 'static> {
+    dynamic_consumer_handle: Option<ContainerHandle>,
+    consumer_shared_state: Service::ArcThreadSafetyPolicy<ConsumerSharedState<Service>>,
+    _payload: PhantomData<Payload>,
+}
+
+unsafe impl<Service: service::Service, Payload: Debug + ZeroCopySend> Send
+    for Consumer<Service, Payload>
+where
+    Service::ArcThreadSafetyPolicy<ConsumerSharedState<Service>>: Send + Sync,
+{
+}
+
+unsafe impl<Service: service::Service, Payload: Debug + ZeroCopySend> Sync
+    for Consumer<Service, Payload>
+where
+    Service::ArcThreadSafetyPolicy<ConsumerSharedState<Service>>: Send + Sync,
+{
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend> Drop for Consumer<Service, Payload> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_consumer_handle {
+            self.consumer_shared_state
+                .lock()
+                .receiver
+                .service_state
+                .dynamic_storage
+                .get()
+                .pipeline()
+                .release_consumer_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend> Consumer<Service, Payload> {
+    pub(crate) fn new(
+        service: Arc<ServiceState<Service, NoResource>>,
+        static_config: &StaticConfig,
+        config: ConsumerConfig,
+    ) -> Result<Self, ConsumerCreateError> {
+        let msg = "Failed to create Consumer port";
+        let origin = "Consumer::new()";
+        let consumer_id = UniqueConsumerId::new();
+
+        let producer_list = &service.dynamic_storage.get().pipeline().producers;
+
+        let buffer_size = match config.buffer_size {
+            Some(buffer_size) => {
+                if static_config.consumer_max_buffer_size < buffer_size {
+                    fail!(from origin, with ConsumerCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService,
+                        "{} since the requested buffer size {} exceeds the maximum supported buffer size {} of the service.",
+                        msg, buffer_size, static_config.consumer_max_buffer_size);
+                }
+                buffer_size
+            }
+            None => static_config.consumer_max_buffer_size,
+        };
+
+        let number_of_to_be_removed_connections = service
+            .shared_node
+            .config()
+            .defaults
+            .pipeline
+            .consumer_expired_connection_buffer;
+        let number_of_active_connections = producer_list.capacity();
+        let number_of_connections =
+            number_of_to_be_removed_connections + number_of_active_connections;
+
+        let consumer_shared_state = Service::ArcThreadSafetyPolicy::new(ConsumerSharedState {
+            producer_list_state: UnsafeCell::new(unsafe { producer_list.get_state() }),
+            receiver: Receiver {
+                connections: Vec::from_fn(number_of_active_connections, |_| UnsafeCell::new(None)),
+                receiver_port_id: consumer_id.value(),
+                service_state: service.clone(),
+                message_type_details: static_config.message_type_details.clone(),
+                receiver_max_borrowed_samples: static_config.consumer_max_borrowed_items,
+                enable_safe_overflow: false,
+                buffer_size,
+                tagger: CyclicTagger::new(),
+                to_be_removed_connections: Some(UnsafeCell::new(Vec::new(
+                    number_of_to_be_removed_connections,
+                ))),
+                degradation_callback: config.degradation_callback,
+                number_of_channels: 1,
+                connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
+            },
+        });
+
+        let consumer_shared_state = match consumer_shared_state {
+            Ok(v) => v,
+            Err(e) => {
+                fail!(from origin,
+                            with ConsumerCreateError::FailedToDeployThreadsafetyPolicy,
+                            "{msg} since the threadsafety policy could not be instantiated ({e:?}).");
+            }
+        };
+
+        let mut new_self = Self {
+            consumer_shared_state,
+            dynamic_consumer_handle: None,
+            _payload: PhantomData,
+        };
+
+        if let Err(e) = new_self.force_update_connections(&new_self.consumer_shared_state.lock()) {
+            warn!(from new_self, "The new consumer is unable to connect to every producer, caused by {:?}.", e);
+        }
+
+        core::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a consumer is added to the dynamic config without
+        // the creation of all required channels
+        let dynamic_consumer_handle = match service
+            .dynamic_storage
+            .get()
+            .pipeline()
+            .add_consumer_id(ConsumerDetails {
+                consumer_id,
+                buffer_size,
+                node_id: *service.shared_node.id(),
+            }) {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from new_self, with ConsumerCreateError::ExceedsMaxSupportedConsumers,
+                                "{} since it would exceed the maximum supported amount of consumers of {}.",
+                                msg, static_config.max_consumers);
+            }
+        };
+
+        new_self.dynamic_consumer_handle = Some(dynamic_consumer_handle);
+
+        Ok(new_self)
+    }
+
+    fn force_update_connections(
+        &self,
+        consumer_shared_state: &ConsumerSharedState<Service>,
+    ) -> Result<(), ConnectionFailure> {
+        consumer_shared_state
+            .receiver
+            .start_update_connection_cycle();
+
+        let mut result = Ok(());
+        unsafe {
+            (*consumer_shared_state.producer_list_state.get()).for_each(|h, details| {
+                let inner_result = consumer_shared_state.receiver.update_connection(
+                    h.index() as usize,
+                    SenderDetails {
+                        port_id: details.producer_id.value(),
+                        number_of_samples: details.number_of_items,
+                        max_number_of_segments: details.max_number_of_segments,
+                        data_segment_type: details.data_segment_type,
+                    },
+                );
+
+                if result.is_ok() {
+                    result = inner_result;
+                }
+                CallbackProgression::Continue
+            })
+        };
+
+        consumer_shared_state
+            .receiver
+            .finish_update_connection_cycle();
+
+        result
+    }
+
+    /// Returns the [`UniqueConsumerId`] of the [`Consumer`]
+    pub fn id(&self) -> UniqueConsumerId {
+        UniqueConsumerId(UniqueSystemId::from(
+            self.consumer_shared_state
+                .lock()
+                .receiver
+                .receiver_port_id(),
+        ))
+    }
+
+    /// Returns the internal buffer size of the [`Consumer`].
+    pub fn buffer_size(&self) -> usize {
+        self.consumer_shared_state.lock().receiver.buffer_size
+    }
+
+    /// Returns true if the [`Consumer`] has work items in the buffer that can be received
+    /// with [`Consumer::receive()`].
+    pub fn has_work_items(&self) -> Result<bool, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+                "Some work items are not being received since not all connections to producers could be established.");
+        Ok(self
+            .consumer_shared_state
+            .lock()
+            .receiver
+            .has_samples(ChannelId::new(0)))
+    }
+
+    /// Receives a [`WorkItem`] from a [`Producer`](crate::port::producer::Producer). If no
+    /// work item could be received [`None`] is returned. If a failure occurs [`ReceiveError`]
+    /// is returned.
+    pub fn receive(&self) -> Result<Option<WorkItem<Service, Payload>>, ReceiveError> {
+        fail!(from self, when self.update_connections(),
+                "Some work items are not being received since not all connections to producers could be established.");
+
+        let data = self
+            .consumer_shared_state
+            .lock()
+            .receiver
+            .receive(ChannelId::new(0))?;
+
+        Ok(data.map(|(details, chunk)| WorkItem {
+            consumer_shared_state: self.consumer_shared_state.clone(),
+            details,
+            ptr: unsafe {
+                RawSample::new_unchecked(
+                    chunk.header.cast(),
+                    chunk.user_header.cast(),
+                    chunk.payload.cast(),
+                )
+            },
+        }))
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend> UpdateConnections
+    for Consumer<Service, Payload>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        let consumer_shared_state = self.consumer_shared_state.lock();
+        if unsafe {
+            consumer_shared_state
+                .receiver
+                .service_state
+                .dynamic_storage
+                .get()
+                .pipeline()
+                .producers
+                .update_state(&mut *consumer_shared_state.producer_list_state.get())
+        } {
+            fail!(from self, when self.force_update_connections(&consumer_shared_state),
+                "Connections were updated only partially since at least one connection to a producer failed.");
+        }
+
+        Ok(())
+    }
+}
@@ -11,6 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use core::cell::UnsafeCell;
+use core::sync::atomic::Ordering;
 
 use super::channel_management::ChannelManagement;
 use super::channel_management::INVALID_CHANNEL_STATE;
@@ -143,6 +144,19 @@ impl<Service: service::Service> Receiver<Service> {
         }
     }
 
+    pub(crate) fn number_of_missed_samples(&self, sender_port_id: u128) -> Option<u64> {
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        connection_storage
+            .iter()
+            .find(|(_, connection)| connection.sender_port_id == sender_port_id)
+            .map(|(_, connection)| {
+                connection
+                    .receiver
+                    .missed_samples(ChannelId::new(0))
+                    .load(Ordering::Relaxed)
+            })
+    }
+
     pub(crate) fn set_channel_state(&self, channel_id: ChannelId, state: u64) -> bool {
         let mut ret_val = true;
         let connection_storage = unsafe { &mut *self.connection_storage.get() };
@@ -184,6 +198,22 @@ impl<Service: service::Service> Receiver<Service> {
         }
     }
 
+    pub(crate) fn invalidate_channel_state_of_all_except(
+        &self,
+        channel_id: ChannelId,
+        expected_state: u64,
+        sender_port_id: u128,
+    ) {
+        let connection_storage = unsafe { &mut *self.connection_storage.get() };
+        for (_, connection) in connection_storage.iter() {
+            if connection.sender_port_id != sender_port_id {
+                connection
+                    .receiver
+                    .invalidate_channel_state(channel_id, expected_state);
+            }
+        }
+    }
+
     pub(crate) fn receiver_port_id(&self) -> u128 {
         self.receiver_port_id
     }
@@ -21,11 +21,12 @@ use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::shm_allocator::{AllocationError, PointerOffset, ShmAllocationError};
 use iceoryx2_cal::zero_copy_connection::{
     ChannelId, ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
-    ZeroCopySendError, ZeroCopySender,
+    ZeroCopyPortDetails, ZeroCopySendError, ZeroCopySender,
 };
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 
 use crate::node::SharedNode;
+use crate::port::distribution_policy::DistributionPolicy;
 use crate::port::{DegradationAction, DegradationCallback, LoanError, SendError};
 use crate::prelude::UnableToDeliverStrategy;
 use crate::service::config_scheme::connection_config;
@@ -201,6 +202,11 @@ impl<Service: service::Service> Sender<Service> {
                     number_of_recipients += 1;
 
                     if let Some(old) = overflow {
+                        // the receiver never got the overflown sample
+                        connection
+                            .sender
+                            .missed_samples(channel_id)
+                            .fetch_add(1, Ordering::Relaxed);
                         self.release_sample(old)
                     }
                 }
@@ -209,6 +215,18 @@ impl<Service: service::Service> Sender<Service> {
         Ok(number_of_recipients)
     }
 
+    /// Takes back one missed sample of the connection, for instance when a previously
+    /// undelivered sample was delivered later on.
+    pub(crate) fn recover_missed_sample(&self, channel_id: ChannelId, connection_id: usize) {
+        if let Some(ref connection) = self.get(connection_id) {
+            let _ = connection.sender.missed_samples(channel_id).fetch_update(
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+                |v| v.checked_sub(1),
+            );
+        }
+    }
+
     pub(crate) fn has_disconnect_hint(
         &self,
         channel_id: ChannelId,
@@ -259,18 +277,113 @@ impl<Service: service::Service> Sender<Service> {
         self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, connection_id)
     }
 
+    /// Returns the connection with the fewest pending samples. The search begins at `start`
+    /// so that equally loaded connections are chosen in turn.
+    pub(crate) fn least_loaded_connection(
+        &self,
+        channel_id: ChannelId,
+        start: usize,
+    ) -> Option<usize> {
+        let number_of_connections = self.len();
+        let mut least_loaded: Option<(usize, usize)> = None;
+        for n in 0..number_of_connections {
+            let connection_id = (start + n) % number_of_connections;
+            if let Some(pending) = self.number_of_pending_samples(channel_id, connection_id) {
+                match least_loaded {
+                    Some((_, min_pending)) if min_pending <= pending => (),
+                    _ => least_loaded = Some((connection_id, pending)),
+                }
+            }
+        }
+
+        least_loaded.map(|(connection_id, _)| connection_id)
+    }
+
+    /// Delivers the offset to exactly one connection that is selected with the
+    /// [`DistributionPolicy`], round robin begins at `start`. Returns the connection id and
+    /// the port id of the receiver that got the offset.
+    pub(crate) fn deliver_to_one(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        channel_id: ChannelId,
+        start: usize,
+        distribution_policy: DistributionPolicy,
+    ) -> Result<Option<(usize, u128)>, SendError> {
+        let number_of_connections = self.len();
+        if number_of_connections == 0 {
+            return Ok(None);
+        }
+
+        let start = start % number_of_connections;
+        let least_loaded = match distribution_policy {
+            DistributionPolicy::LeastLoaded => self.least_loaded_connection(channel_id, start),
+            DistributionPolicy::RoundRobin => None,
+        };
+
+        // round robin, also the fallback when the least loaded receiver became full in the
+        // meantime
+        let candidates = least_loaded
+            .into_iter()
+            .chain((0..number_of_connections).map(|n| (start + n) % number_of_connections));
+
+        for connection_id in candidates {
+            let receiver_port_id = match self.receiver_port_id_of(connection_id) {
+                Some(port_id) => port_id,
+                None => continue,
+            };
+
+            if self.deliver_offset_to_connection(offset, sample_size, channel_id, connection_id)?
+                > 0
+            {
+                return Ok(Some((connection_id, receiver_port_id)));
+            }
+        }
+
+        Ok(None)
+    }
+
     pub(crate) fn deliver_offset(
         &self,
         offset: PointerOffset,
         sample_size: usize,
         channel_id: ChannelId,
+    ) -> Result<usize, SendError> {
+        self.deliver_offset_and_track_undelivered(offset, sample_size, channel_id, |_| true, |_| {})
+    }
+
+    /// Delivers the offset to all connections for which `is_accepted` returns true and calls
+    /// `on_undelivered` with the port id of every accepting receiver that did not get the
+    /// offset. The offset counts as missed sample for these receivers, connections that are not
+    /// accepting are skipped on purpose and do not miss it.
+    pub(crate) fn deliver_offset_and_track_undelivered<A: Fn(usize) -> bool, F: FnMut(u128)>(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        channel_id: ChannelId,
+        is_accepted: A,
+        mut on_undelivered: F,
     ) -> Result<usize, SendError> {
         self.retrieve_returned_samples();
 
         let mut number_of_recipients = 0;
         for i in 0..self.len() {
-            number_of_recipients +=
+            if self.get(i).is_none() || !is_accepted(i) {
+                continue;
+            }
+
+            let delivered =
                 self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, i)?;
+            if delivered == 0 {
+                if let Some(connection) = self.get(i) {
+                    connection
+                        .sender
+                        .missed_samples(channel_id)
+                        .fetch_add(1, Ordering::Relaxed);
+                    on_undelivered(connection.receiver_port_id);
+                }
+            }
+            number_of_recipients += delivered;
         }
         Ok(number_of_recipients)
     }
@@ -296,10 +409,26 @@ impl<Service: service::Service> Sender<Service> {
         Ok(())
     }
 
-    fn len(&self) -> usize {
+    pub(crate) fn len(&self) -> usize {
         self.connections.len()
     }
 
+    pub(crate) fn receiver_port_id_of(&self, connection_id: usize) -> Option<u128> {
+        self.get(connection_id)
+            .as_ref()
+            .map(|connection| connection.receiver_port_id)
+    }
+
+    pub(crate) fn number_of_pending_samples(
+        &self,
+        channel_id: ChannelId,
+        connection_id: usize,
+    ) -> Option<usize> {
+        self.get(connection_id)
+            .as_ref()
+            .map(|connection| connection.sender.number_of_pending_samples(channel_id))
+    }
+
     pub(crate) fn allocate(&self, layout: Layout) -> Result<ChunkMut, LoanError> {
         self.retrieve_returned_samples();
         let msg = "Unable to allocate data";
@@ -0,0 +1,378 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::sync::Arc;
+use core::time::Duration;
+use std::sync::Mutex;
+#[cfg(feature = "async")]
+use std::sync::OnceLock;
+
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_cal::event::ListenerWaitError;
+#[cfg(feature = "async")]
+use tokio::io::{unix::AsyncFd, Interest};
+
+#[cfg(feature = "async")]
+use crate::port::async_listener::ListenerFd;
+use crate::port::listener::Listener;
+use crate::port::notifier::Notifier;
+#[cfg(feature = "async")]
+use crate::port::{AsyncReceiveError, ReceiveError};
+use crate::service::builder::event::EventOpenOrCreateError;
+use crate::service::naming_scheme::{blackboard_key_set_event_name, notify_on_send_event_name};
+use crate::service::port_factory::event;
+use crate::service::service_id::ServiceId;
+use crate::service::service_name::ServiceName;
+use crate::service::static_config::messaging_pattern::MessagingPattern;
+use crate::service::{self, builder, ServiceResource, ServiceState};
+
+/// The channels of the event service that is created alongside a publish-subscribe or
+/// request-response service with notify on send enabled or alongside a blackboard service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SignalChannel {
+    /// Signaled by the [`Publisher`](crate::port::publisher::Publisher) whenever a sample was
+    /// sent.
+    Samples,
+    /// Signaled by the [`Client`](crate::port::client::Client) whenever a request was sent.
+    Requests,
+    /// Signaled by the [`Server`](crate::port::server::Server) whenever a response was sent to
+    /// the [`Client`](crate::port::client::Client) with the provided id. Every client has its
+    /// own channel so that a response wakes up only the client it is addressed to.
+    Responses(u128),
+    /// Signaled by the [`Writer`](crate::port::writer::Writer) whenever a key was inserted into
+    /// or removed from the blackboard.
+    KeySet,
+}
+
+impl SignalChannel {
+    fn event_service_name(&self, service_id: &ServiceId) -> ServiceName {
+        match self {
+            SignalChannel::Samples => notify_on_send_event_name(service_id, "samples"),
+            SignalChannel::Requests => notify_on_send_event_name(service_id, "requests"),
+            SignalChannel::Responses(client_id) => {
+                notify_on_send_event_name(service_id, &format!("responses/{client_id:x}"))
+            }
+            SignalChannel::KeySet => blackboard_key_set_event_name(service_id),
+        }
+    }
+
+    fn open_or_create_event_service<Service: service::Service, R: ServiceResource>(
+        &self,
+        service_state: &Arc<ServiceState<Service, R>>,
+    ) -> Result<event::PortFactory<Service>, EventOpenOrCreateError> {
+        let static_config = &service_state.static_config;
+        let (max_notifiers, max_listeners, max_nodes) = match (
+            self,
+            &static_config.messaging_pattern,
+        ) {
+            (SignalChannel::Samples, MessagingPattern::PublishSubscribe(c)) => {
+                (c.max_publishers(), c.max_subscribers(), c.max_nodes())
+            }
+            (SignalChannel::Requests, MessagingPattern::RequestResponse(c)) => {
+                (c.max_clients(), c.max_servers(), c.max_nodes())
+            }
+            (SignalChannel::Responses(_), MessagingPattern::RequestResponse(c)) => {
+                (c.max_servers(), 1, c.max_nodes())
+            }
+            (SignalChannel::KeySet, MessagingPattern::Blackboard(c)) => {
+                (c.max_writers, c.max_readers(), c.max_nodes())
+            }
+            (_, m) => {
+                fatal_panic!(from self, "This should never happen! The signal channel is not available for the messaging pattern {:?}.", m)
+            }
+        };
+
+        builder::Builder::new(
+            &self.event_service_name(static_config.service_id()),
+            service_state.shared_node.clone(),
+        )
+        .event()
+        .max_notifiers(max_notifiers)
+        .max_listeners(max_listeners)
+        .max_nodes(max_nodes)
+        .disable_deadline()
+        .disable_notifier_created_event()
+        .disable_notifier_dropped_event()
+        .disable_notifier_dead_event()
+        .open_or_create()
+    }
+}
+
+/// Defines a failure that can occur when a [`SignalNotifier`] or [`SignalListener`] is created
+/// for a [`SignalChannel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SignalCreateError {
+    UnableToOpenOrCreateEventService,
+    UnableToCreatePort,
+}
+
+/// Signals that the state of a resource has changed, like a sent sample or an updated
+/// blackboard entry, to all [`SignalListener`]s of the same event service. Listeners that
+/// still have a pending signal are skipped since another signal carries no additional
+/// information.
+#[derive(Debug)]
+pub(crate) struct SignalNotifier<Service: service::Service> {
+    notifier: Mutex<Notifier<Service>>,
+}
+
+// Safe since the notifier is exclusively owned by the SignalNotifier and every access is
+// serialized by the mutex.
+unsafe impl<Service: service::Service> Send for SignalNotifier<Service> {}
+unsafe impl<Service: service::Service> Sync for SignalNotifier<Service> {}
+
+impl<Service: service::Service> SignalNotifier<Service> {
+    pub(crate) fn new(notifier: Notifier<Service>) -> Self {
+        Self {
+            notifier: Mutex::new(notifier.skip_undeliverable_listeners()),
+        }
+    }
+
+    pub(crate) fn create<R: ServiceResource>(
+        service_state: &Arc<ServiceState<Service, R>>,
+        channel: SignalChannel,
+    ) -> Result<Self, SignalCreateError> {
+        let msg = "Unable to create the signal notifier";
+        let event_service = fail!(from channel, when channel.open_or_create_event_service(service_state),
+            with SignalCreateError::UnableToOpenOrCreateEventService,
+            "{} since the event service of the {:?} channel could not be opened or created.", msg, channel);
+
+        let notifier = fail!(from channel, when event_service.notifier_builder().create(),
+            with SignalCreateError::UnableToCreatePort,
+            "{} since the underlying notifier of the {:?} channel could not be created.", msg, channel);
+
+        Ok(Self::new(notifier))
+    }
+
+    pub(crate) fn notify(&self) {
+        let notifier = match self.notifier.lock() {
+            Ok(notifier) => notifier,
+            Err(e) => e.into_inner(),
+        };
+
+        if let Err(e) = notifier.notify() {
+            warn!(from self, "Unable to signal the listeners ({:?}).", e);
+        }
+    }
+}
+
+/// Signals the [`SignalListener`]s of individual [`Client`](crate::port::client::Client)s. The
+/// [`SignalNotifier`] of a client is created on its first signal and released as soon as the
+/// client is disconnected.
+#[derive(Debug)]
+pub(crate) struct ClientSignalNotifiers<Service: service::Service> {
+    notifiers: Mutex<Vec<(u128, SignalNotifier<Service>)>>,
+}
+
+impl<Service: service::Service> ClientSignalNotifiers<Service> {
+    pub(crate) fn new() -> Self {
+        Self {
+            notifiers: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub(crate) fn notify<R: ServiceResource>(
+        &self,
+        service_state: &Arc<ServiceState<Service, R>>,
+        client_id: u128,
+    ) {
+        let mut notifiers = match self.notifiers.lock() {
+            Ok(notifiers) => notifiers,
+            Err(e) => e.into_inner(),
+        };
+
+        if let Some((_, notifier)) = notifiers.iter().find(|(id, _)| *id == client_id) {
+            notifier.notify();
+            return;
+        }
+
+        match SignalNotifier::create(service_state, SignalChannel::Responses(client_id)) {
+            Ok(notifier) => {
+                notifier.notify();
+                notifiers.push((client_id, notifier));
+            }
+            Err(e) => {
+                warn!(from self, "Unable to signal the client {:x} ({:?}).", client_id, e);
+            }
+        }
+    }
+
+    /// Releases the [`SignalNotifier`]s of all clients that are no longer connected.
+    pub(crate) fn retain_connected<F: Fn(u128) -> bool>(&self, is_connected: F) {
+        let mut notifiers = match self.notifiers.lock() {
+            Ok(notifiers) => notifiers,
+            Err(e) => e.into_inner(),
+        };
+
+        notifiers.retain(|(id, _)| is_connected(*id));
+    }
+}
+
+/// Receives the signals that a port, like a
+/// [`Subscriber`](crate::port::subscriber::Subscriber) or a
+/// [`Server`](crate::port::server::Server), has new data available when the
+/// [`Service`](crate::service::Service) was created with notify on send. It can be attached to
+/// a [`WaitSet`](crate::waitset::WaitSet) to wait for the data.
+///
+/// Receiving data from the port consumes the pending signals, therefore all available data
+/// shall be received after a wakeup.
+#[derive(Debug)]
+pub struct SignalListener<Service: service::Service> {
+    // registered at the reactor of the tokio runtime with the first async receive call, must be
+    // dropped before the listener since it deregisters the file descriptor owned by the listener
+    #[cfg(feature = "async")]
+    async_fd: OnceLock<AsyncFd<ListenerFd>>,
+    listener: Mutex<Listener<Service>>,
+}
+
+// Safe since the listener is exclusively owned by the SignalListener and every access is
+// serialized by the mutex.
+unsafe impl<Service: service::Service> Send for SignalListener<Service> {}
+unsafe impl<Service: service::Service> Sync for SignalListener<Service> {}
+
+impl<Service: service::Service> SignalListener<Service> {
+    pub(crate) fn new(listener: Listener<Service>) -> Self {
+        Self {
+            #[cfg(feature = "async")]
+            async_fd: OnceLock::new(),
+            listener: Mutex::new(listener),
+        }
+    }
+
+    pub(crate) fn create<R: ServiceResource>(
+        service_state: &Arc<ServiceState<Service, R>>,
+        channel: SignalChannel,
+    ) -> Result<Self, SignalCreateError> {
+        let msg = "Unable to create the signal listener";
+        let event_service = fail!(from channel, when channel.open_or_create_event_service(service_state),
+            with SignalCreateError::UnableToOpenOrCreateEventService,
+            "{} since the event service of the {:?} channel could not be opened or created.", msg, channel);
+
+        let listener = fail!(from channel, when event_service.listener_builder().create(),
+            with SignalCreateError::UnableToCreatePort,
+            "{} since the underlying listener of the {:?} channel could not be created.", msg, channel);
+
+        Ok(Self::new(listener))
+    }
+
+    /// Consumes all pending signals and returns `true` when at least one was received.
+    pub(crate) fn consume_signals(&self) -> Result<bool, ListenerWaitError> {
+        let listener = match self.listener.lock() {
+            Ok(listener) => listener,
+            Err(e) => e.into_inner(),
+        };
+
+        let mut has_signals = false;
+        listener.try_wait_all(|_| has_signals = true)?;
+        Ok(has_signals)
+    }
+
+    /// Blocks until a signal was received or the timeout has passed. Consumes all pending
+    /// signals and returns `true` when at least one was received.
+    pub(crate) fn timed_wait(&self, timeout: Duration) -> Result<bool, ListenerWaitError> {
+        let listener = match self.listener.lock() {
+            Ok(listener) => listener,
+            Err(e) => e.into_inner(),
+        };
+
+        let mut has_signals = false;
+        listener.timed_wait_all(|_| has_signals = true, timeout)?;
+        Ok(has_signals)
+    }
+}
+
+impl<Service: service::Service> FileDescriptorBased for SignalListener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        let listener = match self.listener.lock() {
+            Ok(listener) => listener,
+            Err(e) => e.into_inner(),
+        };
+        let fd = listener.file_descriptor() as *const FileDescriptor;
+        // the listener and its file descriptor never change during the lifetime of the
+        // SignalListener
+        unsafe { &*fd }
+    }
+}
+
+impl<Service: service::Service> SynchronousMultiplexing for SignalListener<Service> where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing
+{
+}
+
+#[cfg(feature = "async")]
+impl<Service: service::Service> SignalListener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    /// Acquires data with the provided receive call. When no data is available, the file
+    /// descriptor of the listener is registered at the reactor of the tokio runtime and the
+    /// task waits until the sending side signals new data. The receive call must consume the
+    /// pending signals.
+    pub(crate) async fn receive_async<T, F: FnMut() -> Result<Option<T>, ReceiveError>>(
+        &self,
+        mut receive: F,
+    ) -> Result<T, AsyncReceiveError> {
+        if let Some(value) = receive()? {
+            return Ok(value);
+        }
+
+        let fd = self.async_fd()?;
+        loop {
+            let mut guard = match fd.readable().await {
+                Ok(guard) => guard,
+                Err(e) => {
+                    fail!(from self, with AsyncReceiveError::ListenerWaitError(ListenerWaitError::InternalFailure),
+                        "Unable to wait for data since the reactor of the tokio runtime failed ({e:?}).");
+                }
+            };
+
+            match receive()? {
+                Some(value) => return Ok(value),
+                // the receive call consumed all signals, the readiness is only cleared when no
+                // new signal arrived in the meantime
+                None => guard.clear_ready(),
+            }
+        }
+    }
+
+    // Registers the file descriptor of the listener once at the reactor of the tokio runtime.
+    fn async_fd(&self) -> Result<&AsyncFd<ListenerFd>, AsyncReceiveError> {
+        if let Some(fd) = self.async_fd.get() {
+            return Ok(fd);
+        }
+
+        // the lock serializes the registration, a file descriptor can be registered only once
+        let listener = match self.listener.lock() {
+            Ok(listener) => listener,
+            Err(e) => e.into_inner(),
+        };
+        if let Some(fd) = self.async_fd.get() {
+            return Ok(fd);
+        }
+
+        // the file descriptor and its value never changes during the lifetime of the listener
+        let raw_fd = unsafe { listener.file_descriptor().native_handle() };
+        let fd = match AsyncFd::with_interest(ListenerFd(raw_fd), Interest::READABLE) {
+            Ok(fd) => fd,
+            Err(e) => {
+                fail!(from self, with AsyncReceiveError::ListenerWaitError(ListenerWaitError::InternalFailure),
+                    "Unable to wait for data since the file descriptor could not be registered at the reactor ({e:?}).");
+            }
+        };
+
+        Ok(self.async_fd.get_or_init(|| fd))
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use serde::{de::Visitor, Deserialize, Serialize};
+
+/// Defines how a [`Producer`](crate::port::producer::Producer) selects the
+/// [`Consumer`](crate::port::consumer::Consumer) that receives the next work item.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, ZeroCopySend)]
+#[repr(C)]
+pub enum DistributionPolicy {
+    /// The work items are handed to the consumers one after another.
+    RoundRobin,
+    /// The work item is handed to the consumer with the least amount of
+    /// unconsumed work items in its buffer. Consumers with the same load
+    /// are served in round robin order.
+    LeastLoaded,
+}
+
+impl Serialize for DistributionPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&alloc::format!("{self:?}"))
+    }
+}
+
+struct DistributionPolicyVisitor;
+
+impl Visitor<'_> for DistributionPolicyVisitor {
+    type Value = DistributionPolicy;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a string containing either 'RoundRobin' or 'LeastLoaded'")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "RoundRobin" => Ok(DistributionPolicy::RoundRobin),
+            "LeastLoaded" => Ok(DistributionPolicy::LeastLoaded),
+            v => Err(E::custom(alloc::format!(
+                "Invalid DistributionPolicy provided: \"{v:?}\"."
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DistributionPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(DistributionPolicyVisitor)
+    }
+}
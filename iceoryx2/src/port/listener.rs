@@ -57,6 +57,32 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Process Notification Details
+//!
+//! Requires a [`Service`](crate::service::Service) that was created with
+//! [`notification_payload_size()`](crate::service::builder::event::Builder::notification_payload_size).
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node.service_builder(&"MyEventWithDetails".try_into()?)
+//!     .event()
+//!     .notification_payload_size(16)
+//!     .open_or_create()?;
+//!
+//! let mut listener = event.listener_builder().create()?;
+//!
+//! listener.try_wait_all_with_details(|details| {
+//!     println!("notifier {:?} triggered {} times, last event id: {:?}, last payload: {:?}",
+//!         details.notifier_id, details.number_of_notifications,
+//!         details.event_id, details.payload);
+//! })?;
+//!
+//! # Ok(())
+//! # }
+//! ```
 
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_log::fail;
@@ -72,8 +98,12 @@ use crate::service::config_scheme::event_config;
 use crate::service::dynamic_config::event::ListenerDetails;
 use crate::service::naming_scheme::event_concept_name;
 use crate::service::{NoResource, ServiceState};
-use crate::{port::port_identifiers::UniqueListenerId, service};
+use crate::{
+    port::port_identifiers::{UniqueListenerId, UniqueNotifierId},
+    service,
+};
 use alloc::sync::Arc;
+use core::cell::UnsafeCell;
 use core::sync::atomic::Ordering;
 use core::time::Duration;
 
@@ -103,6 +133,24 @@ impl core::fmt::Display for ListenerCreateError {
 
 impl core::error::Error for ListenerCreateError {}
 
+/// The details of all notifications a [`Listener`] received from a single
+/// [`Notifier`](crate::port::notifier::Notifier) since the last wait call. Only available when
+/// the [`Service`](crate::service::Service) was created with
+/// [`notification_payload_size()`](crate::service::builder::event::Builder::notification_payload_size).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationDetails<'a> {
+    /// The [`UniqueNotifierId`] of the [`Notifier`](crate::port::notifier::Notifier) that sent
+    /// the notifications.
+    pub notifier_id: UniqueNotifierId,
+    /// The [`EventId`] of the last notification.
+    pub event_id: EventId,
+    /// How often the [`Notifier`](crate::port::notifier::Notifier) notified the [`Listener`]
+    /// since the last wait call.
+    pub number_of_notifications: u64,
+    /// The payload of the last notification.
+    pub payload: &'a [u8],
+}
+
 /// Represents the receiving endpoint of an event based communication.
 #[derive(Debug)]
 pub struct Listener<Service: service::Service> {
@@ -111,6 +159,8 @@ pub struct Listener<Service: service::Service> {
         Service::ArcThreadSafetyPolicy<<Service::Event as iceoryx2_cal::event::Event>::Listener>,
     service_state: Arc<ServiceState<Service, NoResource>>,
     listener_id: UniqueListenerId,
+    // only accessed while the lock of the listener is held
+    notification_details_buffer: UnsafeCell<Vec<u8>>,
 }
 
 unsafe impl<Service: service::Service> Send for Listener<Service> where
@@ -180,11 +230,19 @@ impl<Service: service::Service> Listener<Service> {
             }
         };
 
+        let dynamic_config = service.dynamic_storage.get().event();
+        let notification_details_buffer = if dynamic_config.has_notification_details() {
+            vec![0u8; dynamic_config.notification_payload_capacity()]
+        } else {
+            vec![]
+        };
+
         let mut new_self = Self {
             service_state: service.clone(),
             dynamic_listener_handle: None,
             listener,
             listener_id,
+            notification_details_buffer: UnsafeCell::new(notification_details_buffer),
         };
 
         core::sync::atomic::compiler_fence(Ordering::SeqCst);
@@ -205,6 +263,11 @@ impl<Service: service::Service> Listener<Service> {
             }
         };
 
+        service
+            .dynamic_storage
+            .get()
+            .event()
+            .reset_listener_notifications(dynamic_listener_handle.index() as usize);
         new_self.dynamic_listener_handle = Some(dynamic_listener_handle);
 
         Ok(new_self)
@@ -288,6 +351,102 @@ impl<Service: service::Service> Listener<Service> {
         )
     }
 
+    /// Non-blocking wait for new notifications. Calls the provided callback once for every
+    /// [`Notifier`](crate::port::notifier::Notifier) that notified the [`Listener`] since the
+    /// last wait call with the corresponding [`NotificationDetails`]. Fails with
+    /// [`ListenerWaitError::ContractViolation`] when the [`Service`](crate::service::Service)
+    /// was created without notification details.
+    pub fn try_wait_all_with_details<F: FnMut(NotificationDetails)>(
+        &self,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let listener = self.listener.lock();
+        self.verify_notification_details_are_supported()?;
+        fail!(from self, when listener.try_wait_all(|_| {}),
+            "Failed to while calling try_wait on underlying event::Listener");
+        self.acquire_notification_details(callback);
+        Ok(())
+    }
+
+    /// Blocking wait for new notifications until the provided timeout has passed. Unblocks as
+    /// soon as a notification was received and then calls the provided callback once for every
+    /// [`Notifier`](crate::port::notifier::Notifier) that notified the [`Listener`] since the
+    /// last wait call with the corresponding [`NotificationDetails`]. Fails with
+    /// [`ListenerWaitError::ContractViolation`] when the [`Service`](crate::service::Service)
+    /// was created without notification details.
+    pub fn timed_wait_all_with_details<F: FnMut(NotificationDetails)>(
+        &self,
+        callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let listener = self.listener.lock();
+        self.verify_notification_details_are_supported()?;
+        fail!(from self, when listener.timed_wait_all(|_| {}, timeout),
+            "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout);
+        self.acquire_notification_details(callback);
+        Ok(())
+    }
+
+    /// Blocking wait for new notifications. Unblocks as soon as a notification was received and
+    /// then calls the provided callback once for every
+    /// [`Notifier`](crate::port::notifier::Notifier) that notified the [`Listener`] since the
+    /// last wait call with the corresponding [`NotificationDetails`]. Fails with
+    /// [`ListenerWaitError::ContractViolation`] when the [`Service`](crate::service::Service)
+    /// was created without notification details.
+    pub fn blocking_wait_all_with_details<F: FnMut(NotificationDetails)>(
+        &self,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let listener = self.listener.lock();
+        self.verify_notification_details_are_supported()?;
+        fail!(from self, when listener.blocking_wait_all(|_| {}),
+            "Failed to while calling blocking_wait on underlying event::Listener");
+        self.acquire_notification_details(callback);
+        Ok(())
+    }
+
+    fn verify_notification_details_are_supported(&self) -> Result<(), ListenerWaitError> {
+        if !self
+            .service_state
+            .dynamic_storage
+            .get()
+            .event()
+            .has_notification_details()
+        {
+            fail!(from self, with ListenerWaitError::ContractViolation,
+                "Unable to acquire notification details since the service was created without notification details.");
+        }
+
+        Ok(())
+    }
+
+    fn acquire_notification_details<F: FnMut(NotificationDetails)>(&self, mut callback: F) {
+        let listener_index = match self.dynamic_listener_handle {
+            Some(handle) => handle.index() as usize,
+            None => return,
+        };
+
+        let dynamic_config = self.service_state.dynamic_storage.get().event();
+        // all callers hold the lock of the listener, therefore the buffer is never accessed
+        // concurrently
+        let buffer = unsafe { &mut *self.notification_details_buffer.get() };
+        dynamic_config.acquire_notifications(
+            listener_index,
+            buffer,
+            |notifier_id, event_id, number_of_notifications, payload| {
+                callback(NotificationDetails {
+                    notifier_id,
+                    event_id,
+                    number_of_notifications,
+                    payload,
+                })
+            },
+        );
+    }
+
     /// Returns the [`UniqueListenerId`] of the [`Listener`]
     pub fn id(&self) -> UniqueListenerId {
         self.listener_id
@@ -0,0 +1,333 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .log()
+//!     .open_or_create()?;
+//!
+//! let collector = service.collector_builder().create()?;
+//!
+//! while let Some((header, record)) = collector.receive()? {
+//!     println!("{:?} [{:?}] {}: {}", header.node_id(), record.level(),
+//!              record.origin(), record.message());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::cell::UnsafeCell;
+use core::fmt::Debug;
+use core::sync::atomic::Ordering;
+
+use iceoryx2_bb_container::slotmap::SlotMap;
+use iceoryx2_bb_container::vec::Vec;
+use iceoryx2_bb_elementary::cyclic_tagger::CyclicTagger;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::zero_copy_connection::ChannelId;
+
+use crate::log_record::LogRecord;
+use crate::port::update_connections::UpdateConnections;
+use crate::service;
+use crate::service::dynamic_config::log::{LogCollectorDetails, LogWriterDetails};
+use crate::service::header::log::Header;
+use crate::service::port_factory::log_collector::LogCollectorConfig;
+use crate::service::static_config::log::StaticConfig;
+use crate::service::{NoResource, ServiceState};
+
+use super::details::receiver::*;
+use super::port_identifiers::UniqueLogCollectorId;
+use super::update_connections::ConnectionFailure;
+use super::ReceiveError;
+
+use alloc::sync::Arc;
+
+/// Describes the failures when a new [`LogCollector`] is created via the
+/// [`crate::service::port_factory::log_collector::PortFactoryLogCollector`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum LogCollectorCreateError {
+    /// The maximum amount of [`LogCollector`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`LogCollector`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedCollectors,
+    /// When the [`LogCollector`] requires a larger buffer size than the
+    /// [`Service`](crate::service::Service) offers the creation will fail.
+    BufferSizeExceedsMaxSupportedBufferSizeOfService,
+    /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
+    /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
+    FailedToDeployThreadsafetyPolicy,
+}
+
+impl core::fmt::Display for LogCollectorCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "LogCollectorCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for LogCollectorCreateError {}
+
+#[derive(Debug)]
+pub(crate) struct LogCollectorSharedState<Service: service::Service> {
+    pub(crate) receiver: Receiver<Service>,
+    pub(crate) writer_list_state: UnsafeCell<ContainerState<LogWriterDetails>>,
+}
+
+/// The receiving endpoint of a log based communication. It receives the [`LogRecord`]s of all
+/// [`LogWriter`](crate::port::log_writer::LogWriter)s. The [`LogRecord`]s are copied out of
+/// the shared memory on reception, therefore the memory a [`LogCollector`] requires is bounded
+/// by its buffer size.
+#[derive(Debug)]
+pub struct LogCollector<Service: service::Service> {
+    dynamic_collector_handle: Option<ContainerHandle>,
+    collector_shared_state: Service::ArcThreadSafetyPolicy<LogCollectorSharedState<Service>>,
+}
+
+unsafe impl<Service: service::Service> Send for LogCollector<Service> where
+    Service::ArcThreadSafetyPolicy<LogCollectorSharedState<Service>>: Send + Sync
+{
+}
+
+unsafe impl<Service: service::Service> Sync for LogCollector<Service> where
+    Service::ArcThreadSafetyPolicy<LogCollectorSharedState<Service>>: Send + Sync
+{
+}
+
+impl<Service: service::Service> Drop for LogCollector<Service> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_collector_handle {
+            self.collector_shared_state
+                .lock()
+                .receiver
+                .service_state
+                .dynamic_storage
+                .get()
+                .log()
+                .release_collector_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service> LogCollector<Service> {
+    pub(crate) fn new(
+        service: Arc<ServiceState<Service, NoResource>>,
+        static_config: &StaticConfig,
+        config: LogCollectorConfig,
+    ) -> Result<Self, LogCollectorCreateError> {
+        let msg = "Failed to create LogCollector port";
+        let origin = "LogCollector::new()";
+        let collector_id = UniqueLogCollectorId::new();
+
+        let writer_list = &service.dynamic_storage.get().log().writers;
+
+        let buffer_size = match config.buffer_size {
+            Some(buffer_size) => {
+                if static_config.collector_max_buffer_size < buffer_size {
+                    fail!(from origin, with LogCollectorCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService,
+                        "{} since the requested buffer size {} exceeds the maximum supported buffer size {} of the service.",
+                        msg, buffer_size, static_config.collector_max_buffer_size);
+                }
+                buffer_size
+            }
+            None => static_config.collector_max_buffer_size,
+        };
+
+        let number_of_to_be_removed_connections = service
+            .shared_node
+            .config()
+            .defaults
+            .log
+            .collector_expired_connection_buffer;
+        let number_of_active_connections = writer_list.capacity();
+        let number_of_connections =
+            number_of_to_be_removed_connections + number_of_active_connections;
+
+        let collector_shared_state = Service::ArcThreadSafetyPolicy::new(LogCollectorSharedState {
+            writer_list_state: UnsafeCell::new(unsafe { writer_list.get_state() }),
+            receiver: Receiver {
+                connections: Vec::from_fn(number_of_active_connections, |_| UnsafeCell::new(None)),
+                receiver_port_id: collector_id.value(),
+                service_state: service.clone(),
+                message_type_details: static_config.message_type_details.clone(),
+                receiver_max_borrowed_samples: 1,
+                enable_safe_overflow: true,
+                buffer_size,
+                tagger: CyclicTagger::new(),
+                to_be_removed_connections: Some(UnsafeCell::new(Vec::new(
+                    number_of_to_be_removed_connections,
+                ))),
+                degradation_callback: config.degradation_callback,
+                number_of_channels: 1,
+                connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
+            },
+        });
+
+        let collector_shared_state = match collector_shared_state {
+            Ok(v) => v,
+            Err(e) => {
+                fail!(from origin,
+                            with LogCollectorCreateError::FailedToDeployThreadsafetyPolicy,
+                            "{msg} since the threadsafety policy could not be instantiated ({e:?}).");
+            }
+        };
+
+        let mut new_self = Self {
+            collector_shared_state,
+            dynamic_collector_handle: None,
+        };
+
+        if let Err(e) = new_self.force_update_connections(&new_self.collector_shared_state.lock()) {
+            warn!(from new_self, "The new log collector is unable to connect to every log writer, caused by {:?}.", e);
+        }
+
+        core::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a collector is added to the dynamic config without
+        // the creation of all required channels
+        let dynamic_collector_handle = match service.dynamic_storage.get().log().add_collector_id(
+            LogCollectorDetails {
+                collector_id,
+                buffer_size,
+                node_id: *service.shared_node.id(),
+            },
+        ) {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from new_self, with LogCollectorCreateError::ExceedsMaxSupportedCollectors,
+                                "{} since it would exceed the maximum supported amount of log collectors of {}.",
+                                msg, static_config.max_collectors);
+            }
+        };
+
+        new_self.dynamic_collector_handle = Some(dynamic_collector_handle);
+
+        Ok(new_self)
+    }
+
+    fn force_update_connections(
+        &self,
+        collector_shared_state: &LogCollectorSharedState<Service>,
+    ) -> Result<(), ConnectionFailure> {
+        collector_shared_state
+            .receiver
+            .start_update_connection_cycle();
+
+        let mut result = Ok(());
+        unsafe {
+            (*collector_shared_state.writer_list_state.get()).for_each(|h, details| {
+                let inner_result = collector_shared_state.receiver.update_connection(
+                    h.index() as usize,
+                    SenderDetails {
+                        port_id: details.writer_id.value(),
+                        number_of_samples: details.number_of_records,
+                        max_number_of_segments: details.max_number_of_segments,
+                        data_segment_type: details.data_segment_type,
+                    },
+                );
+
+                if result.is_ok() {
+                    result = inner_result;
+                }
+                CallbackProgression::Continue
+            })
+        };
+
+        collector_shared_state
+            .receiver
+            .finish_update_connection_cycle();
+
+        result
+    }
+
+    /// Returns the [`UniqueLogCollectorId`] of the [`LogCollector`]
+    pub fn id(&self) -> UniqueLogCollectorId {
+        UniqueLogCollectorId(UniqueSystemId::from(
+            self.collector_shared_state
+                .lock()
+                .receiver
+                .receiver_port_id(),
+        ))
+    }
+
+    /// Returns the internal buffer size of the [`LogCollector`].
+    pub fn buffer_size(&self) -> usize {
+        self.collector_shared_state.lock().receiver.buffer_size
+    }
+
+    /// Returns true if the [`LogCollector`] has [`LogRecord`]s in the buffer that can be
+    /// received with [`LogCollector::receive()`].
+    pub fn has_records(&self) -> Result<bool, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+                "Some log records are not being received since not all connections to log writers could be established.");
+        Ok(self
+            .collector_shared_state
+            .lock()
+            .receiver
+            .has_samples(ChannelId::new(0)))
+    }
+
+    /// Receives a [`LogRecord`] together with its [`Header`] from a
+    /// [`LogWriter`](crate::port::log_writer::LogWriter). The [`LogRecord`] is copied out of
+    /// the shared memory and the memory is returned immediately to the
+    /// [`LogWriter`](crate::port::log_writer::LogWriter). If no [`LogRecord`] could be
+    /// received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
+    pub fn receive(&self) -> Result<Option<(Header, LogRecord)>, ReceiveError> {
+        fail!(from self, when self.update_connections(),
+                "Some log records are not being received since not all connections to log writers could be established.");
+
+        let collector_shared_state = self.collector_shared_state.lock();
+        let data = collector_shared_state.receiver.receive(ChannelId::new(0))?;
+
+        Ok(data.map(|(details, chunk)| {
+            let header = unsafe { *(chunk.header as *const Header) };
+            let record = unsafe { (*(chunk.payload as *const LogRecord)).clone() };
+            collector_shared_state
+                .receiver
+                .release_offset(&details, ChannelId::new(0));
+
+            (header, record)
+        }))
+    }
+}
+
+impl<Service: service::Service> UpdateConnections for LogCollector<Service> {
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        let collector_shared_state = self.collector_shared_state.lock();
+        if unsafe {
+            collector_shared_state
+                .receiver
+                .service_state
+                .dynamic_storage
+                .get()
+                .log()
+                .writers
+                .update_state(&mut *collector_shared_state.writer_list_state.get())
+        } {
+            fail!(from self, when self.force_update_connections(&collector_shared_state),
+                "Connections were updated only partially since at least one connection to a log writer failed.");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::log_record::LogRecord;
+//! use iceoryx2_bb_log::LogLevel;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .log()
+//!     .open_or_create()?;
+//!
+//! let writer = service.writer_builder().create()?;
+//!
+//! let record = LogRecord::new(LogLevel::Info, "main()", "hello collector")
+//!     .with_field("attempt", "1");
+//! let number_of_collectors = writer.write(&record)?;
+//! println!("record was delivered to {} collectors", number_of_collectors);
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use super::details::data_segment::{DataSegment, DataSegmentType};
+use super::details::segment_state::SegmentState;
+use super::port_identifiers::UniqueLogWriterId;
+use super::SendError;
+use crate::log_record::LogRecord;
+use crate::port::details::sender::*;
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::prelude::UnableToDeliverStrategy;
+use crate::service::dynamic_config::log::{LogCollectorDetails, LogWriterDetails};
+use crate::service::header::log::Header;
+use crate::service::naming_scheme::data_segment_name;
+use crate::service::static_config::log;
+use crate::service::{self, NoResource, ServiceState};
+use alloc::sync::Arc;
+use core::cell::UnsafeCell;
+use core::fmt::Debug;
+use core::sync::atomic::Ordering;
+use iceoryx2_bb_elementary::cyclic_tagger::CyclicTagger;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::zero_copy_connection::{ChannelId, ZeroCopyCreationError};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+
+/// Defines a failure that can occur when a [`LogWriter`] is created with
+/// [`crate::service::port_factory::log_writer::PortFactoryLogWriter`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum LogWriterCreateError {
+    /// The maximum amount of [`LogWriter`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`LogWriter`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedWriters,
+    /// The datasegment in which the [`LogRecord`]s of the [`LogWriter`] are stored, could not
+    /// be created.
+    UnableToCreateDataSegment,
+    /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
+    /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
+    FailedToDeployThreadsafetyPolicy,
+}
+
+impl core::fmt::Display for LogWriterCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "LogWriterCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for LogWriterCreateError {}
+
+#[derive(Debug)]
+pub(crate) struct LogWriterSharedState<Service: service::Service> {
+    pub(crate) sender: Sender<Service>,
+    collector_list_state: UnsafeCell<ContainerState<LogCollectorDetails>>,
+}
+
+impl<Service: service::Service> LogWriterSharedState<Service> {
+    fn force_update_connections(&self) -> Result<(), ZeroCopyCreationError> {
+        let mut result = Ok(());
+        self.sender.start_update_connection_cycle();
+        unsafe {
+            (*self.collector_list_state.get()).for_each(|h, port| {
+                let inner_result = self.sender.update_connection(
+                    h.index() as usize,
+                    ReceiverDetails {
+                        port_id: port.collector_id.value(),
+                        buffer_size: port.buffer_size,
+                    },
+                    |_| {},
+                );
+
+                if result.is_ok() {
+                    result = inner_result;
+                }
+
+                CallbackProgression::Continue
+            })
+        };
+
+        self.sender.finish_update_connection_cycle();
+
+        result
+    }
+
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.sender
+                .service_state
+                .dynamic_storage
+                .get()
+                .log()
+                .collectors
+                .update_state(&mut *self.collector_list_state.get())
+        } {
+            fail!(from self, when self.force_update_connections(),
+                "Connections were updated only partially since at least one connection to a LogCollector port failed.");
+        }
+
+        Ok(())
+    }
+
+    fn write(&self, record: &LogRecord) -> Result<usize, SendError> {
+        let msg = "Unable to write log record";
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        let chunk = fail!(from self, when self.sender.allocate(self.sender.sample_layout(1)),
+            "{} since the log record could not be loaned.", msg);
+
+        unsafe {
+            (chunk.header as *mut Header).write(Header::new(
+                *self.sender.service_state.shared_node.id(),
+                UniqueLogWriterId(UniqueSystemId::from(self.sender.sender_port_id)),
+            ));
+            (chunk.payload as *mut LogRecord).write(record.clone());
+        }
+
+        let result = self
+            .sender
+            .deliver_offset(chunk.offset, chunk.size, ChannelId::new(0));
+        self.sender.return_loaned_sample(chunk.offset);
+
+        result
+    }
+}
+
+/// Sending endpoint of a log based communication. Every [`LogRecord`] is delivered to all
+/// [`LogCollector`](crate::port::log_collector::LogCollector)s. When the buffer of a
+/// [`LogCollector`](crate::port::log_collector::LogCollector) is full, its oldest
+/// [`LogRecord`] is overridden so that the [`LogWriter`] never blocks.
+#[derive(Debug)]
+pub struct LogWriter<Service: service::Service> {
+    pub(crate) writer_shared_state: Service::ArcThreadSafetyPolicy<LogWriterSharedState<Service>>,
+    dynamic_writer_handle: Option<ContainerHandle>,
+}
+
+unsafe impl<Service: service::Service> Send for LogWriter<Service> where
+    Service::ArcThreadSafetyPolicy<LogWriterSharedState<Service>>: Send + Sync
+{
+}
+
+unsafe impl<Service: service::Service> Sync for LogWriter<Service> where
+    Service::ArcThreadSafetyPolicy<LogWriterSharedState<Service>>: Send + Sync
+{
+}
+
+impl<Service: service::Service> Drop for LogWriter<Service> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_writer_handle {
+            self.writer_shared_state
+                .lock()
+                .sender
+                .service_state
+                .dynamic_storage
+                .get()
+                .log()
+                .release_writer_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service> LogWriter<Service> {
+    pub(crate) fn new(
+        service: Arc<ServiceState<Service, NoResource>>,
+        static_config: &log::StaticConfig,
+    ) -> Result<Self, LogWriterCreateError> {
+        let msg = "Unable to create LogWriter port";
+        let origin = "LogWriter::new()";
+        let port_id = UniqueLogWriterId::new();
+        let collector_list = &service.dynamic_storage.get().log().collectors;
+
+        let number_of_records = static_config.required_amount_of_records_per_data_segment();
+        let data_segment_type = DataSegmentType::Static;
+        let max_number_of_segments =
+            DataSegment::<Service>::max_number_of_segments(data_segment_type);
+        let writer_details = LogWriterDetails {
+            writer_id: port_id,
+            node_id: *service.shared_node.id(),
+            number_of_records,
+            data_segment_type,
+            max_number_of_segments,
+        };
+
+        let segment_name = data_segment_name(port_id.value());
+        let data_segment = fail!(from origin,
+                when DataSegment::create_static_segment(
+                    &segment_name,
+                    static_config.message_type_details.sample_layout(1),
+                    service.shared_node.config(),
+                    number_of_records,
+                ),
+                with LogWriterCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        let writer_shared_state =
+            <Service as service::Service>::ArcThreadSafetyPolicy::new(LogWriterSharedState {
+                sender: Sender {
+                    data_segment,
+                    segment_states: (0..max_number_of_segments)
+                        .map(|_| SegmentState::new(number_of_records))
+                        .collect(),
+                    connections: (0..collector_list.capacity())
+                        .map(|_| UnsafeCell::new(None))
+                        .collect(),
+                    sender_port_id: port_id.value(),
+                    shared_node: service.shared_node.clone(),
+                    receiver_max_buffer_size: static_config.collector_max_buffer_size,
+                    receiver_max_borrowed_samples: 1,
+                    // the log records are bounded by the collectors buffer, the oldest record
+                    // is overridden so that a slow collector never blocks a writer
+                    enable_safe_overflow: true,
+                    number_of_samples: number_of_records,
+                    max_number_of_segments,
+                    degradation_callback: None,
+                    service_state: service.clone(),
+                    tagger: CyclicTagger::new(),
+                    loan_counter: IoxAtomicUsize::new(0),
+                    sender_max_borrowed_samples: 1,
+                    unable_to_deliver_strategy: UnableToDeliverStrategy::DiscardSample,
+                    message_type_details: static_config.message_type_details.clone(),
+                    number_of_channels: 1,
+                },
+                collector_list_state: UnsafeCell::new(unsafe { collector_list.get_state() }),
+            });
+
+        let writer_shared_state = match writer_shared_state {
+            Ok(v) => v,
+            Err(e) => {
+                fail!(from origin,
+                            with LogWriterCreateError::FailedToDeployThreadsafetyPolicy,
+                            "{msg} since the threadsafety policy could not be instantiated ({e:?}).");
+            }
+        };
+
+        let mut new_self = Self {
+            writer_shared_state,
+            dynamic_writer_handle: None,
+        };
+
+        if let Err(e) = new_self
+            .writer_shared_state
+            .lock()
+            .force_update_connections()
+        {
+            warn!(from new_self,
+                "The new LogWriter port is unable to connect to every LogCollector port, caused by {:?}.", e);
+        }
+
+        core::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a writer is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_writer_handle = match service
+            .dynamic_storage
+            .get()
+            .log()
+            .add_writer_id(writer_details)
+        {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with LogWriterCreateError::ExceedsMaxSupportedWriters,
+                            "{} since it would exceed the maximum supported amount of log writers of {}.",
+                            msg, static_config.max_writers);
+            }
+        };
+
+        new_self.dynamic_writer_handle = Some(dynamic_writer_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueLogWriterId`] of the [`LogWriter`]
+    pub fn id(&self) -> UniqueLogWriterId {
+        UniqueLogWriterId(UniqueSystemId::from(
+            self.writer_shared_state.lock().sender.sender_port_id,
+        ))
+    }
+
+    /// Copies the [`LogRecord`] into the shared memory and delivers it to all
+    /// [`LogCollector`](crate::port::log_collector::LogCollector)s.
+    /// On success it returns the number of
+    /// [`LogCollector`](crate::port::log_collector::LogCollector)s that received the
+    /// [`LogRecord`], otherwise a [`SendError`] describing the failure.
+    pub fn write(&self, record: &LogRecord) -> Result<usize, SendError> {
+        self.writer_shared_state.lock().write(record)
+    }
+}
+
+impl<Service: service::Service> UpdateConnections for LogWriter<Service> {
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.writer_shared_state.lock().update_connections()
+    }
+}
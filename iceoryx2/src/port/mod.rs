@@ -16,22 +16,45 @@ use tiny_fn::tiny_fn;
 use update_connections::ConnectionFailure;
 
 pub(crate) mod details;
+
 pub use details::data_segment::DataSegmentType;
+pub use details::signal::SignalListener;
+#[cfg(feature = "async")]
+use iceoryx2_cal::event::ListenerWaitError;
 
+/// Asynchronous receiving endpoint (port) for event based communication
+#[cfg(feature = "async")]
+pub mod async_listener;
 /// Sends requests to a [`Server`](crate::port::server::Server) and receives responses.
 pub mod client;
+/// Receiving endpoint (port) for pipeline based communication
+pub mod consumer;
+/// Defines how a [`Producer`](crate::port::producer::Producer) distributes work items
+/// between the connected [`Consumer`](crate::port::consumer::Consumer)s.
+pub mod distribution_policy;
 /// Defines the event id used to identify the source of an event.
 pub mod event_id;
 /// Receiving endpoint (port) for event based communication
 pub mod listener;
+/// Receiving endpoint (port) for log based communication
+pub mod log_collector;
+/// Sending endpoint (port) for log based communication
+pub mod log_writer;
 /// Sending endpoint (port) for event based communication
 pub mod notifier;
 /// Defines port specific unique ids. Used to identify source/destination while communicating.
 pub mod port_identifiers;
+/// Sending endpoint (port) for pipeline based communication
+pub mod producer;
 /// Sending endpoint (port) for publish-subscribe based communication
 pub mod publisher;
 /// Reading endpoint (port) for blackboard based communication
 pub mod reader;
+/// Defines to which [`Server`](crate::port::server::Server)s a
+/// [`Client`](crate::port::client::Client) delivers its requests.
+pub mod request_delivery_policy;
+/// Defines which samples are delivered to a [`Subscriber`](crate::port::subscriber::Subscriber).
+pub mod sample_filter;
 /// Receives requests from a [`Client`](crate::port::client::Client) port and sends back responses.
 pub mod server;
 /// Receiving endpoint (port) for publish-subscribe based communication
@@ -115,6 +138,9 @@ pub enum SendError {
     LoanError(LoanError),
     /// A failure occurred while establishing a connection to the ports counterpart port.
     ConnectionError(ConnectionFailure),
+    /// The [`Publisher`](crate::port::publisher::Publisher) already holds the maximum number
+    /// of [`ResendHandle`](crate::resend_handle::ResendHandle)s it was configured with.
+    ExceedsMaxResendHandles,
 }
 
 impl From<LoanError> for SendError {
@@ -163,3 +189,67 @@ impl core::fmt::Display for ReceiveError {
 }
 
 impl core::error::Error for ReceiveError {}
+
+/// Defines the failure that can occur when receiving data asynchronously with
+/// [`Subscriber::receive_async()`](crate::port::subscriber::Subscriber::receive_async()).
+#[cfg(feature = "async")]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AsyncReceiveError {
+    /// A failure occurred while receiving the data.
+    ReceiveError(ReceiveError),
+    /// A failure occurred while waiting on the [`SignalListener`] of the port.
+    ListenerWaitError(ListenerWaitError),
+    /// The service was created without notify on send, therefore the port has no
+    /// [`SignalListener`] that could wake up the waiting task.
+    NotifyOnSendDisabled,
+}
+
+#[cfg(feature = "async")]
+impl From<ReceiveError> for AsyncReceiveError {
+    fn from(value: ReceiveError) -> Self {
+        AsyncReceiveError::ReceiveError(value)
+    }
+}
+
+#[cfg(feature = "async")]
+impl From<ListenerWaitError> for AsyncReceiveError {
+    fn from(value: ListenerWaitError) -> Self {
+        AsyncReceiveError::ListenerWaitError(value)
+    }
+}
+
+#[cfg(feature = "async")]
+impl core::fmt::Display for AsyncReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AsyncReceiveError::{self:?}")
+    }
+}
+
+#[cfg(feature = "async")]
+impl core::error::Error for AsyncReceiveError {}
+
+/// Defines the failure that can occur when receiving a
+/// [`Response`](crate::response::Response) with a timeout via
+/// [`PendingResponse::timed_receive()`](crate::pending_response::PendingResponse::timed_receive()).
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum TimedReceiveError {
+    /// A failure occurred while receiving the data.
+    ReceiveError(ReceiveError),
+    /// The deadline of the corresponding [`RequestMut`](crate::request_mut::RequestMut)
+    /// passed before a [`Response`](crate::response::Response) was received.
+    DeadlineMissed,
+}
+
+impl From<ReceiveError> for TimedReceiveError {
+    fn from(value: ReceiveError) -> Self {
+        TimedReceiveError::ReceiveError(value)
+    }
+}
+
+impl core::fmt::Display for TimedReceiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "TimedReceiveError::{self:?}")
+    }
+}
+
+impl core::error::Error for TimedReceiveError {}
@@ -34,6 +34,27 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Notification Details
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node.service_builder(&"MyEventWithDetails".try_into()?)
+//!     .event()
+//!     .notification_payload_size(16)
+//!     .open_or_create()?;
+//!
+//! let notifier = event.notifier_builder().create()?;
+//!
+//! // the listener receives the payload together with the notifier id and the
+//! // number of notifications
+//! notifier.notify_with_payload(b"temperature: 42")?;
+//!
+//! # Ok(())
+//! # }
+//! ```
 
 use super::{event_id::EventId, port_identifiers::UniqueListenerId};
 use crate::{
@@ -94,6 +115,10 @@ pub enum NotifierNotifyError {
     /// The notification was delivered but the elapsed system time could not be acquired.
     /// Therefore, it is unknown if the deadline was missed or not.
     UnableToAcquireElapsedTime,
+    /// The provided payload is larger than the notification payload size of the
+    /// [`Service`](crate::service::Service) or the [`Service`](crate::service::Service) was
+    /// created without notification details.
+    PayloadTooLarge,
 }
 
 impl core::fmt::Display for NotifierNotifyError {
@@ -252,6 +277,8 @@ pub struct Notifier<Service: service::Service> {
     notifier_id: UniqueNotifierId,
     on_drop_notification: Option<EventId>,
     node_id: NodeId,
+    skip_undeliverable_listeners: bool,
+    notification_payload_size: Option<usize>,
 }
 
 unsafe impl<Service: service::Service> Send for Notifier<Service> where
@@ -355,6 +382,8 @@ impl<Service: service::Service> Notifier<Service> {
             notifier_id,
             on_drop_notification: None,
             node_id,
+            skip_undeliverable_listeners: false,
+            notification_payload_size: static_config.notification_payload_size,
         };
 
         new_self
@@ -384,11 +413,24 @@ impl<Service: service::Service> Notifier<Service> {
                             msg, service.static_config.event().max_notifiers);
             }
         };
+        service
+            .dynamic_storage
+            .get()
+            .event()
+            .reset_notifier_notifications(dynamic_notifier_handle.index() as usize);
         new_self.dynamic_notifier_handle = Some(dynamic_notifier_handle);
 
         Ok(new_self)
     }
 
+    /// Listeners that cannot receive a signal since they still have pending notifications are
+    /// skipped without a warning. Used when a notification only signals that a state has changed
+    /// and additional pending notifications carry no additional information.
+    pub(crate) fn skip_undeliverable_listeners(mut self) -> Self {
+        self.skip_undeliverable_listeners = true;
+        self
+    }
+
     /// Returns the [`UniqueNotifierId`] of the [`Notifier`]
     pub fn id(&self) -> UniqueNotifierId {
         self.notifier_id
@@ -426,6 +468,30 @@ impl<Service: service::Service> Notifier<Service> {
         self.__internal_notify(value, false)
     }
 
+    /// Notifies all [`crate::port::listener::Listener`] connected to the service with the default
+    /// event id provided on creation and attaches the `payload` to the notification. The
+    /// [`crate::port::listener::Listener`] receives it together with the [`UniqueNotifierId`]
+    /// in its `*_wait_all_with_details()` calls.
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    pub fn notify_with_payload(&self, payload: &[u8]) -> Result<usize, NotifierNotifyError> {
+        self.notify_with_custom_event_id_and_payload(self.default_event_id, payload)
+    }
+
+    /// Notifies all [`crate::port::listener::Listener`] connected to the service with a custom
+    /// [`EventId`] and attaches the `payload` to the notification.
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`].
+    pub fn notify_with_custom_event_id_and_payload(
+        &self,
+        value: EventId,
+        payload: &[u8],
+    ) -> Result<usize, NotifierNotifyError> {
+        self.notify_impl(value, payload, false)
+    }
+
     /// Notifies all [`crate::port::listener::Listener`] connected to the service with a custom
     /// [`EventId`].
     /// On success the number of
@@ -440,6 +506,15 @@ impl<Service: service::Service> Notifier<Service> {
         &self,
         value: EventId,
         skip_self_deliver: bool,
+    ) -> Result<usize, NotifierNotifyError> {
+        self.notify_impl(value, &[], skip_self_deliver)
+    }
+
+    fn notify_impl(
+        &self,
+        value: EventId,
+        payload: &[u8],
+        skip_self_deliver: bool,
     ) -> Result<usize, NotifierNotifyError> {
         let msg = "Unable to notify event";
         let listener_connections = self.listener_connections.lock();
@@ -454,13 +529,44 @@ impl<Service: service::Service> Notifier<Service> {
                             msg, value, self.event_id_max_value);
         }
 
+        let payload_size = self.notification_payload_size.unwrap_or(0);
+        if payload_size < payload.len() {
+            fail!(from self, with NotifierNotifyError::PayloadTooLarge,
+                            "{} since the payload size of {} exceeds the supported notification payload size of {}.",
+                            msg, payload.len(), payload_size);
+        }
+
+        let dynamic_config = listener_connections
+            .service_state
+            .dynamic_storage
+            .get()
+            .event();
+        let notifier_index = match self.dynamic_notifier_handle {
+            Some(handle) if self.notification_payload_size.is_some() => {
+                Some(handle.index() as usize)
+            }
+            _ => None,
+        };
+
         for i in 0..listener_connections.len() {
             if let Some(ref connection) = listener_connections.get(i) {
                 if !(skip_self_deliver && connection.node_id == self.node_id) {
+                    if let Some(notifier_index) = notifier_index {
+                        dynamic_config.store_notification(
+                            i,
+                            notifier_index,
+                            self.notifier_id,
+                            value,
+                            payload,
+                        );
+                    }
+
                     match connection.notifier.notify(value) {
                         Err(iceoryx2_cal::event::NotifierNotifyError::Disconnected) => {
                             listener_connections.remove(i);
                         }
+                        Err(iceoryx2_cal::event::NotifierNotifyError::FailedToDeliverSignal)
+                            if self.skip_undeliverable_listeners => {}
                         Err(e) => {
                             warn!(from self, "Unable to send notification via connection {:?} due to {:?}.",
                                     connection, e)
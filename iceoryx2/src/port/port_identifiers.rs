@@ -78,6 +78,22 @@ generate_id! {
     /// The system-wide unique id of a [`Writer`](crate::port::writer::Writer).
     UniqueWriterId
 }
+generate_id! {
+    /// The system-wide unique id of a [`Producer`](crate::port::producer::Producer).
+    UniqueProducerId
+}
+generate_id! {
+    /// The system-wide unique id of a [`Consumer`](crate::port::consumer::Consumer).
+    UniqueConsumerId
+}
+generate_id! {
+    /// The system-wide unique id of a [`LogWriter`](crate::port::log_writer::LogWriter).
+    UniqueLogWriterId
+}
+generate_id! {
+    /// The system-wide unique id of a [`LogCollector`](crate::port::log_collector::LogCollector).
+    UniqueLogCollectorId
+}
 
 /// Enum that contains the unique port id
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -98,4 +114,12 @@ pub enum UniquePortId {
     Reader(UniqueReaderId),
     /// The system-wide unique id of a [`Writer`](crate::port::writer::Writer).
     Writer(UniqueWriterId),
+    /// The system-wide unique id of a [`Producer`](crate::port::producer::Producer).
+    Producer(UniqueProducerId),
+    /// The system-wide unique id of a [`Consumer`](crate::port::consumer::Consumer).
+    Consumer(UniqueConsumerId),
+    /// The system-wide unique id of a [`LogWriter`](crate::port::log_writer::LogWriter).
+    LogWriter(UniqueLogWriterId),
+    /// The system-wide unique id of a [`LogCollector`](crate::port::log_collector::LogCollector).
+    LogCollector(UniqueLogCollectorId),
 }
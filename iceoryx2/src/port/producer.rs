@@ -0,0 +1,539 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .pipeline::<u64>()
+//!     .open_or_create()?;
+//!
+//! let producer = service
+//!     .producer_builder()
+//!     // defines how many work items can be loaned in parallel
+//!     .max_loaned_items(5)
+//!     // hand every work item to the consumer with the fewest pending work items
+//!     .distribution_policy(DistributionPolicy::LeastLoaded)
+//!     // defines behavior when the buffers of all consumers are full
+//!     .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+//!     .create()?;
+//!
+//! // loan some uninitialized memory and send it
+//! let work_item = producer.loan_uninit()?;
+//! let work_item = work_item.write_payload(1337);
+//! match work_item.send()? {
+//!     Some(consumer_id) => println!("work item was handed to {:?}", consumer_id),
+//!     None => println!("no consumer was able to take the work item"),
+//! }
+//!
+//! // send a copy of the value
+//! producer.send_copy(313)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use super::details::data_segment::{DataSegment, DataSegmentType};
+use super::details::segment_state::SegmentState;
+use super::distribution_policy::DistributionPolicy;
+use super::port_identifiers::{UniqueConsumerId, UniqueProducerId};
+use super::{LoanError, SendError};
+use crate::port::details::sender::*;
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::prelude::UnableToDeliverStrategy;
+use crate::raw_sample::RawSampleMut;
+use crate::service::dynamic_config::pipeline::{ConsumerDetails, ProducerDetails};
+use crate::service::header::pipeline::Header;
+use crate::service::naming_scheme::data_segment_name;
+use crate::service::port_factory::producer::LocalProducerConfig;
+use crate::service::static_config::pipeline;
+use crate::service::{self, NoResource, ServiceState};
+use crate::work_item_mut::WorkItemMut;
+use crate::work_item_mut_uninit::WorkItemMutUninit;
+use alloc::sync::Arc;
+use core::cell::UnsafeCell;
+use core::fmt::Debug;
+use core::sync::atomic::Ordering;
+use core::{marker::PhantomData, mem::MaybeUninit};
+use iceoryx2_bb_elementary::cyclic_tagger::CyclicTagger;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::shm_allocator::PointerOffset;
+use iceoryx2_cal::zero_copy_connection::{ChannelId, ZeroCopyCreationError};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
+
+/// Defines a failure that can occur when a [`Producer`] is created with
+/// [`crate::service::port_factory::producer::PortFactoryProducer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ProducerCreateError {
+    /// The maximum amount of [`Producer`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Producer`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedProducers,
+    /// The datasegment in which the payload of the [`Producer`] is stored, could not be created.
+    UnableToCreateDataSegment,
+    /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
+    /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
+    FailedToDeployThreadsafetyPolicy,
+}
+
+impl core::fmt::Display for ProducerCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ProducerCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for ProducerCreateError {}
+
+#[derive(Debug)]
+pub(crate) struct ProducerSharedState<Service: service::Service> {
+    pub(crate) sender: Sender<Service>,
+    consumer_list_state: UnsafeCell<ContainerState<ConsumerDetails>>,
+    distribution_policy: DistributionPolicy,
+    unable_to_deliver_strategy: UnableToDeliverStrategy,
+    next_connection: IoxAtomicUsize,
+    is_active: IoxAtomicBool,
+    sequence_number: IoxAtomicU64,
+}
+
+impl<Service: service::Service> ProducerSharedState<Service> {
+    fn force_update_connections(&self) -> Result<(), ZeroCopyCreationError> {
+        let mut result = Ok(());
+        self.sender.start_update_connection_cycle();
+        unsafe {
+            (*self.consumer_list_state.get()).for_each(|h, port| {
+                let inner_result = self.sender.update_connection(
+                    h.index() as usize,
+                    ReceiverDetails {
+                        port_id: port.consumer_id.value(),
+                        buffer_size: port.buffer_size,
+                    },
+                    |_| {},
+                );
+
+                if result.is_ok() {
+                    result = inner_result;
+                }
+
+                CallbackProgression::Continue
+            })
+        };
+
+        self.sender.finish_update_connection_cycle();
+
+        result
+    }
+
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.sender
+                .service_state
+                .dynamic_storage
+                .get()
+                .pipeline()
+                .consumers
+                .update_state(&mut *self.consumer_list_state.get())
+        } {
+            fail!(from self, when self.force_update_connections(),
+                "Connections were updated only partially since at least one connection to a Consumer port failed.");
+        }
+
+        Ok(())
+    }
+
+    fn number_of_connected_consumers(&self) -> usize {
+        (0..self.sender.len())
+            .filter(|n| self.sender.receiver_port_id_of(*n).is_some())
+            .count()
+    }
+
+    fn try_deliver(
+        &self,
+        offset: PointerOffset,
+        item_size: usize,
+    ) -> Result<Option<UniqueConsumerId>, SendError> {
+        match self.sender.deliver_to_one(
+            offset,
+            item_size,
+            ChannelId::new(0),
+            self.next_connection.load(Ordering::Relaxed),
+            self.distribution_policy,
+        )? {
+            Some((connection_id, consumer_port_id)) => {
+                self.next_connection
+                    .store(connection_id + 1, Ordering::Relaxed);
+                Ok(Some(UniqueConsumerId(UniqueSystemId::from(
+                    consumer_port_id,
+                ))))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn send_item(
+        &self,
+        header: &mut Header,
+        offset: PointerOffset,
+        item_size: usize,
+    ) -> Result<Option<UniqueConsumerId>, SendError> {
+        let msg = "Unable to send work item";
+        if !self.is_active.load(Ordering::Relaxed) {
+            fail!(from self, with SendError::ConnectionBrokenSinceSenderNoLongerExists,
+                "{} since the corresponding producer is already disconnected.", msg);
+        }
+
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        // the header must be complete before the work item becomes visible to a consumer
+        header.set_sequence_number(self.sequence_number.load(Ordering::Relaxed));
+
+        let mut adaptive_wait = None;
+        loop {
+            if let Some(consumer_id) = self.try_deliver(offset, item_size)? {
+                self.sequence_number.fetch_add(1, Ordering::Relaxed);
+                return Ok(Some(consumer_id));
+            }
+
+            if self.unable_to_deliver_strategy == UnableToDeliverStrategy::DiscardSample
+                || self.number_of_connected_consumers() == 0
+            {
+                return Ok(None);
+            }
+
+            let wait = adaptive_wait.get_or_insert_with(|| {
+                fatal_panic!(from self, when AdaptiveWaitBuilder::new().create(),
+                    "{} since the adaptive wait could not be created.", msg)
+            });
+            if let Err(e) = wait.wait() {
+                warn!(from self, "{} since the adaptive wait failed ({:?}). Discarding the work item.", msg, e);
+                return Ok(None);
+            }
+
+            // consumers may have disconnected or new ones connected while waiting
+            fail!(from self, when self.update_connections(),
+                "{} since the connections could not be updated.", msg);
+        }
+    }
+}
+
+/// Sending endpoint of a pipeline based communication. Every work item is handed to exactly one
+/// [`Consumer`](crate::port::consumer::Consumer), selected by the configured
+/// [`DistributionPolicy`].
+#[derive(Debug)]
+pub struct Producer<Service: service::Service, Payload: Debug + ZeroCopySend + 'static> {
+    pub(crate) producer_shared_state: Service::ArcThreadSafetyPolicy<ProducerSharedState<Service>>,
+    dynamic_producer_handle: Option<ContainerHandle>,
+    _payload: PhantomData<Payload>,
+}
+
+unsafe impl<Service: service::Service, Payload: Debug + ZeroCopySend> Send
+    for Producer<Service, Payload>
+where
+    Service::ArcThreadSafetyPolicy<ProducerSharedState<Service>>: Send + Sync,
+{
+}
+
+unsafe impl<Service: service::Service, Payload: Debug + ZeroCopySend> Sync
+    for Producer<Service, Payload>
+where
+    Service::ArcThreadSafetyPolicy<ProducerSharedState<Service>>: Send + Sync,
+{
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend> Drop for Producer<Service, Payload> {
+    fn drop(&mut self) {
+        let shared_state = self.producer_shared_state.lock();
+        shared_state.is_active.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.dynamic_producer_handle {
+            shared_state
+                .sender
+                .service_state
+                .dynamic_storage
+                .get()
+                .pipeline()
+                .release_producer_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend> Producer<Service, Payload> {
+    pub(crate) fn new(
+        service: Arc<ServiceState<Service, NoResource>>,
+        static_config: &pipeline::StaticConfig,
+        config: LocalProducerConfig,
+    ) -> Result<Self, ProducerCreateError> {
+        let msg = "Unable to create Producer port";
+        let origin = "Producer::new()";
+        let port_id = UniqueProducerId::new();
+        let consumer_list = &service.dynamic_storage.get().pipeline().consumers;
+
+        let number_of_items =
+            static_config.required_amount_of_items_per_data_segment(config.max_loaned_items);
+        let data_segment_type = DataSegmentType::Static;
+        let max_number_of_segments =
+            DataSegment::<Service>::max_number_of_segments(data_segment_type);
+        let producer_details = ProducerDetails {
+            producer_id: port_id,
+            node_id: *service.shared_node.id(),
+            number_of_items,
+            data_segment_type,
+            max_number_of_segments,
+        };
+
+        let segment_name = data_segment_name(port_id.value());
+        let data_segment = fail!(from origin,
+                when DataSegment::create_static_segment(
+                    &segment_name,
+                    static_config.message_type_details.sample_layout(1),
+                    service.shared_node.config(),
+                    number_of_items,
+                ),
+                with ProducerCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        let producer_shared_state =
+            <Service as service::Service>::ArcThreadSafetyPolicy::new(ProducerSharedState {
+                is_active: IoxAtomicBool::new(true),
+                sequence_number: IoxAtomicU64::new(0),
+                next_connection: IoxAtomicUsize::new(0),
+                distribution_policy: config.distribution_policy,
+                unable_to_deliver_strategy: config.unable_to_deliver_strategy,
+                sender: Sender {
+                    data_segment,
+                    segment_states: (0..max_number_of_segments)
+                        .map(|_| SegmentState::new(number_of_items))
+                        .collect(),
+                    connections: (0..consumer_list.capacity())
+                        .map(|_| UnsafeCell::new(None))
+                        .collect(),
+                    sender_port_id: port_id.value(),
+                    shared_node: service.shared_node.clone(),
+                    receiver_max_buffer_size: static_config.consumer_max_buffer_size,
+                    receiver_max_borrowed_samples: static_config.consumer_max_borrowed_items,
+                    enable_safe_overflow: false,
+                    number_of_samples: number_of_items,
+                    max_number_of_segments,
+                    degradation_callback: None,
+                    service_state: service.clone(),
+                    tagger: CyclicTagger::new(),
+                    loan_counter: IoxAtomicUsize::new(0),
+                    sender_max_borrowed_samples: config.max_loaned_items,
+                    // the producer never blocks on a single consumer, it tries all consumers
+                    // and applies its unable to deliver strategy only when all of them are full
+                    unable_to_deliver_strategy: UnableToDeliverStrategy::DiscardSample,
+                    message_type_details: static_config.message_type_details.clone(),
+                    number_of_channels: 1,
+                },
+                consumer_list_state: UnsafeCell::new(unsafe { consumer_list.get_state() }),
+            });
+
+        let producer_shared_state = match producer_shared_state {
+            Ok(v) => v,
+            Err(e) => {
+                fail!(from origin,
+                            with ProducerCreateError::FailedToDeployThreadsafetyPolicy,
+                            "{msg} since the threadsafety policy could not be instantiated ({e:?}).");
+            }
+        };
+
+        let mut new_self = Self {
+            producer_shared_state,
+            dynamic_producer_handle: None,
+            _payload: PhantomData,
+        };
+
+        if let Err(e) = new_self
+            .producer_shared_state
+            .lock()
+            .force_update_connections()
+        {
+            warn!(from new_self,
+                "The new Producer port is unable to connect to every Consumer port, caused by {:?}.", e);
+        }
+
+        core::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a producer is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_producer_handle = match service
+            .dynamic_storage
+            .get()
+            .pipeline()
+            .add_producer_id(producer_details)
+        {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with ProducerCreateError::ExceedsMaxSupportedProducers,
+                            "{} since it would exceed the maximum supported amount of producers of {}.",
+                            msg, static_config.max_producers);
+            }
+        };
+
+        new_self.dynamic_producer_handle = Some(dynamic_producer_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueProducerId`] of the [`Producer`]
+    pub fn id(&self) -> UniqueProducerId {
+        UniqueProducerId(UniqueSystemId::from(
+            self.producer_shared_state.lock().sender.sender_port_id,
+        ))
+    }
+
+    /// Returns the [`DistributionPolicy`] the [`Producer`] uses to select the
+    /// [`Consumer`](crate::port::consumer::Consumer) of the next work item.
+    pub fn distribution_policy(&self) -> DistributionPolicy {
+        self.producer_shared_state.lock().distribution_policy
+    }
+
+    /// Returns the strategy the [`Producer`] follows when a [`WorkItemMut`] cannot be delivered
+    /// since the buffers of all [`Consumer`](crate::port::consumer::Consumer)s are full.
+    pub fn unable_to_deliver_strategy(&self) -> UnableToDeliverStrategy {
+        self.producer_shared_state.lock().unable_to_deliver_strategy
+    }
+
+    /// Copies the input `value` into a [`WorkItemMut`] and hands it to one
+    /// [`Consumer`](crate::port::consumer::Consumer).
+    /// On success it returns the [`UniqueConsumerId`] of the
+    /// [`Consumer`](crate::port::consumer::Consumer) that received the work item or [`None`]
+    /// when no [`Consumer`](crate::port::consumer::Consumer) was able to take it. On failure
+    /// a [`SendError`] describing the failure is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .pipeline::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let producer = service.producer_builder().create()?;
+    ///
+    /// producer.send_copy(1234)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy(&self, value: Payload) -> Result<Option<UniqueConsumerId>, SendError> {
+        let msg = "Unable to send copy of payload";
+        let work_item = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a work item failed.", msg);
+
+        work_item.write_payload(value).send()
+    }
+
+    /// Loans/allocates a [`WorkItemMutUninit`] from the underlying data segment of the
+    /// [`Producer`]. The user has to initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .pipeline::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let producer = service.producer_builder().create()?;
+    ///
+    /// let work_item = producer.loan_uninit()?;
+    /// let work_item = work_item.write_payload(42);
+    ///
+    /// work_item.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<WorkItemMutUninit<Service, MaybeUninit<Payload>>, LoanError> {
+        let shared_state = self.producer_shared_state.lock();
+        let chunk = shared_state
+            .sender
+            .allocate(shared_state.sender.sample_layout(1))?;
+        let node_id = shared_state.sender.service_state.shared_node.id();
+        let header_ptr = chunk.header as *mut Header;
+        unsafe { header_ptr.write(Header::new(*node_id, self.id())) };
+
+        let work_item = unsafe {
+            RawSampleMut::new_unchecked(header_ptr, chunk.user_header.cast(), chunk.payload.cast())
+        };
+        Ok(WorkItemMutUninit::new(
+            &self.producer_shared_state,
+            work_item,
+            chunk.offset,
+            chunk.size,
+        ))
+    }
+}
+
+impl<Service: service::Service, Payload: Default + Debug + ZeroCopySend>
+    Producer<Service, Payload>
+{
+    /// Loans/allocates a [`WorkItemMut`] from the underlying data segment of the [`Producer`]
+    /// and initialize it with the default value. This can be a performance hit and
+    /// [`Producer::loan_uninit()`] can be used to loan a [`core::mem::MaybeUninit<Payload>`].
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .pipeline::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let producer = service.producer_builder().create()?;
+    ///
+    /// let mut work_item = producer.loan()?;
+    /// *work_item.payload_mut() = 42;
+    ///
+    /// work_item.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan(&self) -> Result<WorkItemMut<Service, Payload>, LoanError> {
+        Ok(self.loan_uninit()?.write_payload(Payload::default()))
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend> UpdateConnections
+    for Producer<Service, Payload>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.producer_shared_state.lock().update_connections()
+    }
+}
@@ -103,7 +103,9 @@
 
 use super::details::data_segment::{DataSegment, DataSegmentType};
 use super::details::segment_state::SegmentState;
-use super::port_identifiers::UniquePublisherId;
+use super::details::signal::{SignalChannel, SignalNotifier};
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use super::sample_filter::SampleFilter;
 use super::{LoanError, SendError};
 use crate::port::details::sender::*;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
@@ -131,6 +133,7 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
@@ -138,7 +141,7 @@ use iceoryx2_cal::shm_allocator::{AllocationStrategy, PointerOffset};
 use iceoryx2_cal::zero_copy_connection::{
     ChannelId, ZeroCopyCreationError, ZeroCopyPortDetails, ZeroCopySender,
 };
-use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
 
 /// Defines a failure that can occur when a [`Publisher`] is created with
 /// [`crate::service::port_factory::publisher::PortFactoryPublisher`].
@@ -154,6 +157,10 @@ pub enum PublisherCreateError {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The [`Service`](crate::service::Service) has notify on send enabled but the
+    /// underlying event service, that is used to signal the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s, could not be opened or created.
+    UnableToCreateNotificationChannel,
 }
 
 impl core::fmt::Display for PublisherCreateError {
@@ -168,6 +175,9 @@ impl core::error::Error for PublisherCreateError {}
 struct OffsetAndSize {
     offset: u64,
     size: usize,
+    // address of the header in the data segment of this publisher, required to evaluate the
+    // sample filter of late joining subscribers
+    header_address: usize,
 }
 
 #[derive(Debug)]
@@ -175,12 +185,17 @@ pub(crate) struct PublisherSharedState<Service: service::Service> {
     config: LocalPublisherConfig,
     pub(crate) sender: Sender<Service>,
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
+    // the sample filter of the subscriber of the corresponding connection
+    subscriber_filters: UnsafeCell<Vec<SampleFilter>>,
     history: Option<UnsafeCell<Queue<OffsetAndSize>>>,
     is_active: IoxAtomicBool,
+    sequence_number: IoxAtomicU64,
+    pub(crate) resend_handle_counter: IoxAtomicUsize,
+    sample_notifier: Option<SignalNotifier<Service>>,
 }
 
 impl<Service: service::Service> PublisherSharedState<Service> {
-    fn add_sample_to_history(&self, offset: PointerOffset, sample_size: usize) {
+    fn add_sample_to_history(&self, header: &Header, offset: PointerOffset, sample_size: usize) {
         match &self.history {
             None => (),
             Some(history) => {
@@ -189,6 +204,7 @@ impl<Service: service::Service> PublisherSharedState<Service> {
                 match history.push_with_overflow(OffsetAndSize {
                     offset: offset.as_value(),
                     size: sample_size,
+                    header_address: header as *const Header as usize,
                 }) {
                     None => (),
                     Some(old) => self
@@ -204,13 +220,14 @@ impl<Service: service::Service> PublisherSharedState<Service> {
         self.sender.start_update_connection_cycle();
         unsafe {
             (*self.subscriber_list_state.get()).for_each(|h, port| {
+                (*self.subscriber_filters.get())[h.index() as usize] = port.filter;
                 let inner_result = self.sender.update_connection(
                     h.index() as usize,
                     ReceiverDetails {
                         port_id: port.subscriber_id.value(),
                         buffer_size: port.buffer_size,
                     },
-                    |connection| self.deliver_sample_history(connection),
+                    |connection| self.deliver_sample_history(connection, &port.filter),
                 );
 
                 if result.is_ok() {
@@ -243,15 +260,27 @@ impl<Service: service::Service> PublisherSharedState<Service> {
         Ok(())
     }
 
-    fn deliver_sample_history(&self, connection: &Connection<Service>) {
+    fn deliver_sample_history(&self, connection: &Connection<Service>, filter: &SampleFilter) {
         match &self.history {
             None => (),
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
                 let buffer_size = connection.sender.buffer_size();
-                let history_start = history.len().saturating_sub(buffer_size);
+                // the newest matching samples are delivered, at most as many as fit into the
+                // buffer of the subscriber
+                let mut matching_samples = (0..history.len())
+                    .rev()
+                    .filter(|i| {
+                        let old_sample = unsafe { history.get_unchecked(*i) };
+                        // the history owns the sample, therefore the header is still valid
+                        let header = unsafe { &*(old_sample.header_address as *const Header) };
+                        self.matches_filter(filter, header)
+                    })
+                    .take(buffer_size)
+                    .collect::<Vec<_>>();
+                matching_samples.reverse();
 
-                for i in history_start..history.len() {
+                for i in matching_samples {
                     let old_sample = unsafe { history.get_unchecked(i) };
                     self.sender.retrieve_returned_samples();
 
@@ -276,10 +305,57 @@ impl<Service: service::Service> PublisherSharedState<Service> {
         }
     }
 
+    /// Returns true when the sample with the provided header matches the [`SampleFilter`].
+    fn matches_filter(&self, filter: &SampleFilter, header: &Header) -> bool {
+        if filter.is_pass_through() {
+            return true;
+        }
+
+        let type_details = &self.sender.message_type_details;
+        let header_ptr = (header as *const Header).cast::<u8>();
+        let payload_len = type_details.payload.size * header.number_of_elements() as usize;
+        // the user header and the payload are located in the same chunk as the header
+        let (user_header, payload) = unsafe {
+            (
+                core::slice::from_raw_parts(
+                    type_details.user_header_ptr_from_header(header_ptr),
+                    type_details.user_header.size,
+                ),
+                core::slice::from_raw_parts(
+                    type_details.payload_ptr_from_header(header_ptr),
+                    payload_len,
+                ),
+            )
+        };
+
+        filter.matches(user_header, payload)
+    }
+
     pub(crate) fn send_sample(
         &self,
+        header: &mut Header,
         offset: PointerOffset,
         sample_size: usize,
+    ) -> Result<usize, SendError> {
+        self.send_sample_and_track_undelivered(header, offset, sample_size, |_| {})
+    }
+
+    fn notify_on_delivery(&self, number_of_recipients: usize) {
+        if number_of_recipients == 0 {
+            return;
+        }
+
+        if let Some(notifier) = &self.sample_notifier {
+            notifier.notify();
+        }
+    }
+
+    pub(crate) fn send_sample_and_track_undelivered<F: FnMut(u128)>(
+        &self,
+        header: &mut Header,
+        offset: PointerOffset,
+        sample_size: usize,
+        on_undelivered: F,
     ) -> Result<usize, SendError> {
         let msg = "Unable to send sample";
         if !self.is_active.load(Ordering::Relaxed) {
@@ -290,9 +366,101 @@ impl<Service: service::Service> PublisherSharedState<Service> {
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
-        self.add_sample_to_history(offset, sample_size);
-        self.sender
-            .deliver_offset(offset, sample_size, ChannelId::new(0))
+        // when the monotonic clock is not supported the timestamp is zero
+        let send_timestamp = Time::now_with_clock(ClockType::Monotonic)
+            .map(|now| now.as_duration())
+            .unwrap_or_default();
+        header.set_send_details(
+            self.sequence_number.fetch_add(1, Ordering::Relaxed),
+            send_timestamp,
+        );
+
+        self.add_sample_to_history(header, offset, sample_size);
+        let subscriber_filters = unsafe { &*self.subscriber_filters.get() };
+        let number_of_recipients = self.sender.deliver_offset_and_track_undelivered(
+            offset,
+            sample_size,
+            ChannelId::new(0),
+            |connection_id| self.matches_filter(&subscriber_filters[connection_id], header),
+            on_undelivered,
+        )?;
+
+        self.notify_on_delivery(number_of_recipients);
+        Ok(number_of_recipients)
+    }
+
+    /// Reserves a slot for a new [`ResendHandle`](crate::resend_handle::ResendHandle). Fails
+    /// when the [`Publisher`] already holds the maximum number of resend handles.
+    pub(crate) fn acquire_resend_handle(&self) -> Result<(), SendError> {
+        let max_resend_handles = self.config.max_resend_handles;
+        if self
+            .resend_handle_counter
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| {
+                (v < max_resend_handles).then_some(v + 1)
+            })
+            .is_err()
+        {
+            fail!(from self, with SendError::ExceedsMaxResendHandles,
+                "Unable to acquire a resend handle since it would exceed the maximum of {} resend handles.",
+                max_resend_handles);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn release_resend_handle(&self) {
+        self.resend_handle_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn resend_sample(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        undelivered_subscribers: &mut alloc::vec::Vec<UniqueSubscriberId>,
+    ) -> Result<usize, SendError> {
+        let msg = "Unable to resend sample";
+        if !self.is_active.load(Ordering::Relaxed) {
+            fail!(from self, with SendError::ConnectionBrokenSinceSenderNoLongerExists,
+                "{} since the corresponding publisher is already disconnected.", msg);
+        }
+
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        let mut number_of_recipients = 0;
+        let mut result = Ok(());
+        undelivered_subscribers.retain(|subscriber_id| {
+            let connection_id = match self.sender.get_connection_id_of(subscriber_id.value()) {
+                Some(connection_id) => connection_id,
+                // the subscriber is no longer connected
+                None => return false,
+            };
+
+            match self.sender.deliver_offset_to_connection(
+                offset,
+                sample_size,
+                ChannelId::new(0),
+                connection_id,
+            ) {
+                Ok(0) => true,
+                Ok(delivered) => {
+                    self.sender
+                        .recover_missed_sample(ChannelId::new(0), connection_id);
+                    number_of_recipients += delivered;
+                    false
+                }
+                Err(e) => {
+                    if result.is_ok() {
+                        result = Err(e);
+                    }
+                    true
+                }
+            }
+        });
+
+        result?;
+        self.notify_on_delivery(number_of_recipients);
+        Ok(number_of_recipients)
     }
 }
 
@@ -373,7 +541,9 @@ impl<
 
         let number_of_samples =
             unsafe { service.static_config.messaging_pattern.publish_subscribe() }
-                .required_amount_of_samples_per_data_segment(config.max_loaned_samples);
+                .required_amount_of_samples_per_data_segment(
+                    config.max_loaned_samples + config.max_resend_handles,
+                );
 
         let data_segment_type =
             DataSegmentType::new_from_allocation_strategy(config.allocation_strategy);
@@ -417,9 +587,20 @@ impl<
                 with PublisherCreateError::UnableToCreateDataSegment,
                 "{} since the data segment could not be acquired.", msg);
 
+        let sample_notifier = match static_config.enable_notify_on_send {
+            true => Some(fail!(from origin,
+                when SignalNotifier::create(&service, SignalChannel::Samples),
+                with PublisherCreateError::UnableToCreateNotificationChannel,
+                "{} since the notification channel for the subscribers could not be created.", msg)),
+            false => None,
+        };
+
         let publisher_shared_state =
             <Service as service::Service>::ArcThreadSafetyPolicy::new(PublisherSharedState {
                 is_active: IoxAtomicBool::new(true),
+                sequence_number: IoxAtomicU64::new(0),
+                resend_handle_counter: IoxAtomicUsize::new(0),
+                sample_notifier,
                 sender: Sender {
                     data_segment,
                     segment_states: {
@@ -451,6 +632,11 @@ impl<
                 },
                 config,
                 subscriber_list_state: UnsafeCell::new(unsafe { subscriber_list.get_state() }),
+                subscriber_filters: UnsafeCell::new(
+                    (0..subscriber_list.capacity())
+                        .map(|_| SampleFilter::default())
+                        .collect(),
+                ),
                 history: match static_config.history_size == 0 {
                     true => None,
                     false => Some(UnsafeCell::new(Queue::new(static_config.history_size))),
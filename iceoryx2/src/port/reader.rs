@@ -34,15 +34,43 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Slice Entries
+//!
+//! ```
+//! # use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! type KeyType = u64;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard_creator::<KeyType>()
+//!     .add_slice::<u8>(1, 64, b"some config")
+//!     .create()?;
+//!
+//! let reader = service.reader_builder().create()?;
+//!
+//! // create a handle for direct read access to the slice
+//! let entry_handle = reader.slice_entry::<u8>(&1)?;
+//!
+//! // get a consistent copy of the current slice
+//! let value = entry_handle.get();
+//! assert_eq!(value.as_slice(), b"some config");
+//!
+//! # Ok(())
+//! # }
+//! ```
 
+use crate::port::details::signal::{SignalChannel, SignalListener};
 use crate::prelude::EventId;
-use crate::service::builder::blackboard::BlackboardResources;
+use crate::service::builder::blackboard::{BlackboardResources, EntryReference, SliceEntryLayout};
 use crate::service::dynamic_config::blackboard::ReaderDetails;
 use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
 use crate::service::{self, ServiceState};
 use core::fmt::Debug;
 use core::hash::Hash;
+use core::marker::PhantomData;
 use core::sync::atomic::Ordering;
+use iceoryx2_bb_container::vec;
 use iceoryx2_bb_elementary::math::align;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
@@ -50,8 +78,12 @@ use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::{
     UnrestrictedAtomic, UnrestrictedAtomicMgmt,
 };
 use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::ListenerWaitError;
 use iceoryx2_cal::shared_memory::SharedMemory;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 extern crate alloc;
 use alloc::sync::Arc;
@@ -110,6 +142,7 @@ pub struct Reader<
 > {
     shared_state: Arc<ReaderSharedState<Service, KeyType>>,
     reader_id: UniqueReaderId,
+    key_set_generation: IoxAtomicU64,
 }
 
 impl<
@@ -130,6 +163,9 @@ impl<
                 service_state: service.clone(),
             }),
             reader_id,
+            key_set_generation: IoxAtomicU64::new(
+                service.additional_resource.mgmt.get().key_set_generation(),
+            ),
         };
 
         core::sync::atomic::compiler_fence(Ordering::SeqCst);
@@ -165,6 +201,117 @@ impl<
         self.reader_id
     }
 
+    /// Returns all keys that are currently stored in the blackboard. Keys can be added and
+    /// removed at runtime with [`Writer::insert()`](crate::port::writer::Writer::insert()) and
+    /// [`Writer::remove()`](crate::port::writer::Writer::remove()).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add::<i32>(1, -1)
+    /// #     .add::<u32>(9, 17)
+    /// #     .create()?;
+    /// #
+    /// # let reader = service.reader_builder().create()?;
+    /// let mut keys = reader.keys();
+    /// keys.sort();
+    /// assert_eq!(keys, [1, 9]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn keys(&self) -> Vec<KeyType> {
+        let service_state = &self.shared_state.service_state;
+        let (keys, generation) = service_state
+            .additional_resource
+            .mgmt
+            .get()
+            .keys::<Service>(service_state.shared_node.config());
+        self.key_set_generation.store(generation, Ordering::Relaxed);
+        keys
+    }
+
+    /// Returns `true` when keys were added to or removed from the blackboard since the
+    /// [`Reader`] was created or since the last call of [`Reader::keys()`] or
+    /// [`Reader::has_key_set_changed()`]. To wait for key set changes instead of polling, use
+    /// [`Reader::key_set_listener()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add::<i32>(1, -1)
+    /// #     .reserve::<i32>(1)
+    /// #     .create()?;
+    /// #
+    /// # let writer = service.writer_builder().create()?;
+    /// let reader = service.reader_builder().create()?;
+    /// assert!(!reader.has_key_set_changed());
+    ///
+    /// writer.insert::<i32>(2, 5)?;
+    /// assert!(reader.has_key_set_changed());
+    /// assert!(!reader.has_key_set_changed());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn has_key_set_changed(&self) -> bool {
+        let generation = self
+            .shared_state
+            .service_state
+            .additional_resource
+            .mgmt
+            .get()
+            .key_set_generation();
+        self.key_set_generation.swap(generation, Ordering::Relaxed) != generation
+    }
+
+    /// Creates a [`KeySetListener`] that is signaled whenever a key is inserted into or removed
+    /// from the blackboard. It can be attached to a [`WaitSet`](crate::waitset::WaitSet).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add::<i32>(1, -1)
+    /// #     .reserve::<i32>(1)
+    /// #     .create()?;
+    /// #
+    /// # let writer = service.writer_builder().create()?;
+    /// # let reader = service.reader_builder().create()?;
+    /// let key_set_listener = reader.key_set_listener()?;
+    /// assert!(!key_set_listener.has_changed()?);
+    ///
+    /// writer.insert::<i32>(2, 5)?;
+    /// assert!(key_set_listener.has_changed()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn key_set_listener(
+        &self,
+    ) -> Result<KeySetListener<Service, KeyType>, KeySetListenerCreateError> {
+        let listener = fail!(from self,
+            when SignalListener::create(&self.shared_state.service_state, SignalChannel::KeySet),
+            with KeySetListenerCreateError::ResourceCreationFailed,
+            "Unable to create the key set listener since the underlying listener could not be created.");
+
+        Ok(KeySetListener {
+            listener,
+            _shared_state: self.shared_state.clone(),
+        })
+    }
+
     /// Creates a [`EntryHandle`] for direct read access to the value.
     ///
     /// # Example
@@ -189,11 +336,12 @@ impl<
     ) -> Result<EntryHandle<Service, KeyType, ValueType>, EntryHandleError> {
         let msg = "Unable to create entry handle";
 
-        let offset = self.get_entry_offset(
+        let entry = self.acquire_entry(
             key,
             &TypeDetail::new::<ValueType>(TypeVariant::FixedSize),
             msg,
         )?;
+        let offset = entry.offset();
 
         let atomic = (self
             .shared_state
@@ -203,57 +351,146 @@ impl<
             .payload_start_address() as u64
             + offset) as *const UnrestrictedAtomic<ValueType>;
 
-        Ok(EntryHandle::new(self.shared_state.clone(), atomic, offset))
+        Ok(EntryHandle::new(self.shared_state.clone(), atomic, entry))
+    }
+
+    /// Creates a [`SliceEntryHandle`] for direct read access to a slice entry that was added
+    /// with [`Creator::add_slice()`](crate::service::builder::blackboard::Creator::add_slice()).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<i32>(1, 8, &[1, 2, 3])
+    /// #     .create()?;
+    /// #
+    /// # let reader = service.reader_builder().create()?;
+    /// let entry_handle = reader.slice_entry::<i32>(&1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn slice_entry<ValueType: Copy + ZeroCopySend>(
+        &self,
+        key: &KeyType,
+    ) -> Result<SliceEntryHandle<Service, KeyType, ValueType>, EntryHandleError> {
+        let msg = "Unable to create slice entry handle";
+
+        let entry = self.acquire_entry(
+            key,
+            &TypeDetail::new::<ValueType>(TypeVariant::Dynamic),
+            msg,
+        )?;
+        let offset = entry.offset();
+        let max_slice_len = entry.max_slice_len();
+
+        let atomic_mgmt_ptr = (self
+            .shared_state
+            .service_state
+            .additional_resource
+            .data
+            .payload_start_address() as u64
+            + offset) as *const UnrestrictedAtomicMgmt;
+
+        Ok(SliceEntryHandle::new(
+            self.shared_state.clone(),
+            atomic_mgmt_ptr,
+            max_slice_len,
+            entry,
+        ))
     }
 
-    fn get_entry_offset(
+    fn acquire_entry(
         &self,
         key: &KeyType,
         type_details: &TypeDetail,
         msg: &str,
-    ) -> Result<u64, EntryHandleError> {
-        // check if key exists
-        let index = match unsafe {
-            self.shared_state
-                .service_state
-                .additional_resource
-                .mgmt
-                .get()
-                .map
-                .get(key)
-        } {
-            Some(i) => i,
+    ) -> Result<EntryReference<Service, KeyType>, EntryHandleError> {
+        match EntryReference::acquire(&self.shared_state.service_state, key, type_details) {
+            Some(entry) => Ok(entry),
             None => {
                 fail!(from self, with EntryHandleError::EntryDoesNotExist,
-                "{} since no entry with the given key exists.", msg);
+                    "{} since no entry with the given key and value type exists.", msg);
             }
-        };
+        }
+    }
+}
 
-        let entry = &self
-            .shared_state
-            .service_state
-            .additional_resource
-            .mgmt
-            .get()
-            .entries[index];
+/// Defines a failure that can occur when a [`KeySetListener`] is created with
+/// [`Reader::key_set_listener()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum KeySetListenerCreateError {
+    /// The resources to receive key set change notifications could not be created, for instance
+    /// because the maximum number of [`KeySetListener`]s is exceeded.
+    ResourceCreationFailed,
+}
 
-        // check if ValueType matches
-        if *type_details != entry.type_details {
-            fail!(from self, with EntryHandleError::EntryDoesNotExist,
-                "{} since no entry with the given key and value type exists.", msg);
-        }
+impl core::fmt::Display for KeySetListenerCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "KeySetListenerCreateError::{self:?}")
+    }
+}
 
-        let offset = entry.offset.load(core::sync::atomic::Ordering::Relaxed);
+impl core::error::Error for KeySetListenerCreateError {}
 
-        Ok(offset)
+/// Receives a notification whenever a key is inserted into or removed from the blackboard.
+/// Created with [`Reader::key_set_listener()`].
+#[derive(Debug)]
+pub struct KeySetListener<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+> {
+    listener: SignalListener<Service>,
+    _shared_state: Arc<ReaderSharedState<Service, KeyType>>,
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    > KeySetListener<Service, KeyType>
+{
+    /// Returns `true` when keys were inserted or removed since the last call of
+    /// [`KeySetListener::has_changed()`] or since the creation of the [`KeySetListener`]. All
+    /// pending notifications are consumed.
+    pub fn has_changed(&self) -> Result<bool, ListenerWaitError> {
+        self.listener.consume_signals()
     }
 }
 
-/// Defines a failure that can occur when a [`EntryHandle`] is created with [`Reader::entry()`].
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    > FileDescriptorBased for KeySetListener<Service, KeyType>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    > SynchronousMultiplexing for KeySetListener<Service, KeyType>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+{
+}
+
+/// Defines a failure that can occur when a [`EntryHandle`] is created with [`Reader::entry()`]
+/// or when an [`EntryListener`] is created with [`EntryHandle::entry_listener()`].
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum EntryHandleError {
     /// The entry with the given key and value type does not exist.
     EntryDoesNotExist,
+    /// The maximum number of [`EntryListener`]s of the entry is exceeded.
+    ExceedsMaxSupportedListeners,
+    /// The resources to receive update notifications of the entry could not be created.
+    ResourceCreationFailed,
 }
 
 impl core::fmt::Display for EntryHandleError {
@@ -272,6 +509,7 @@ pub struct EntryHandle<
 > {
     atomic: *const UnrestrictedAtomic<ValueType>,
     entry_id: EventId,
+    entry: EntryReference<Service, KeyType>,
     _shared_state: Arc<ReaderSharedState<Service, KeyType>>,
 }
 
@@ -302,11 +540,12 @@ impl<
     fn new(
         reader_state: Arc<ReaderSharedState<Service, KeyType>>,
         atomic: *const UnrestrictedAtomic<ValueType>,
-        offset: u64,
+        entry: EntryReference<Service, KeyType>,
     ) -> Self {
         Self {
             atomic,
-            entry_id: EventId::new(offset as _),
+            entry_id: entry.entry_id(),
+            entry,
             _shared_state: reader_state.clone(),
         }
     }
@@ -334,6 +573,42 @@ impl<
         unsafe { (*self.atomic).load() }
     }
 
+    /// Creates an [`EntryListener`] that receives a notification whenever the entry is updated.
+    /// It can be attached to a [`WaitSet`](crate::waitset::WaitSet) to wait for updates of the
+    /// entry. The number of [`EntryListener`]s per entry is limited by the `max_listeners`
+    /// setting of the event defaults in the [`Config`](crate::config::Config).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add::<i32>(1, -1)
+    /// #     .create()?;
+    /// #
+    /// # let reader = service.reader_builder().create()?;
+    /// # let writer = service.writer_builder().create()?;
+    /// # let entry_handle_mut = writer.entry::<i32>(&1)?;
+    /// let entry_handle = reader.entry::<i32>(&1)?;
+    /// let entry_listener = entry_handle.entry_listener()?;
+    /// assert!(!entry_listener.has_changed()?);
+    ///
+    /// entry_handle_mut.update_with_copy(8);
+    /// assert!(entry_listener.has_changed()?);
+    /// assert!(!entry_listener.has_changed()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn entry_listener(&self) -> Result<EntryListener<Service>, EntryHandleError> {
+        Ok(EntryListener {
+            listener: self.entry.create_listener()?,
+            entry_id: self.entry_id,
+        })
+    }
+
     /// Returns an ID corresponding to the entry which can be used in an event based communication
     /// setup.
     pub fn entry_id(&self) -> EventId {
@@ -341,8 +616,252 @@ impl<
     }
 }
 
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy,
+    > Debug for EntryHandle<Service, KeyType, ValueType>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "EntryHandle { entry_id: {:?}, value_type: {} }",
+            self.entry_id,
+            core::any::type_name::<ValueType>()
+        )
+    }
+}
+
+/// A handle for direct read access to a specific blackboard slice entry.
+pub struct SliceEntryHandle<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: Copy,
+> {
+    atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
+    data_ptr: *const u8,
+    layout: SliceEntryLayout,
+    max_slice_len: usize,
+    entry_id: EventId,
+    entry: EntryReference<Service, KeyType>,
+    _shared_state: Arc<ReaderSharedState<Service, KeyType>>,
+    _value: PhantomData<ValueType>,
+}
+
+// Safe since the pointers to the UnrestrictedAtomicMgmt and its data cells don't change, the
+// UnrestrictedAtomicMgmt supports concurrent loads, and shared_state ensures the lifetime of the
+// underlying memory (struct fields are dropped in the same order as declared)
+unsafe impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy + 'static,
+    > Send for SliceEntryHandle<Service, KeyType, ValueType>
+{
+}
+unsafe impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy + 'static,
+    > Sync for SliceEntryHandle<Service, KeyType, ValueType>
+{
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy,
+    > SliceEntryHandle<Service, KeyType, ValueType>
+{
+    fn new(
+        reader_state: Arc<ReaderSharedState<Service, KeyType>>,
+        atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
+        max_slice_len: usize,
+        entry: EntryReference<Service, KeyType>,
+    ) -> Self {
+        let layout = SliceEntryLayout::new::<ValueType>(max_slice_len);
+        Self {
+            atomic_mgmt_ptr,
+            data_ptr: layout.data_ptr(atomic_mgmt_ptr),
+            layout,
+            max_slice_len,
+            entry_id: entry.entry_id(),
+            entry,
+            _shared_state: reader_state,
+            _value: PhantomData,
+        }
+    }
+
+    /// Returns a consistent copy of the current slice. The returned
+    /// [`Vec`](iceoryx2_bb_container::vec::Vec) has a capacity of
+    /// [`SliceEntryHandle::max_slice_len()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<i32>(1, 8, &[1, 2, 3])
+    /// #     .create()?;
+    /// #
+    /// # let reader = service.reader_builder().create()?;
+    /// # let entry_handle = reader.slice_entry::<i32>(&1)?;
+    /// let value = entry_handle.get();
+    /// assert_eq!(value.as_slice(), &[1, 2, 3]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(&self) -> vec::Vec<ValueType> {
+        let mut value = vec::Vec::new(self.max_slice_len);
+        unsafe {
+            (*self.atomic_mgmt_ptr).__internal_load_with(
+                self.layout.cell_size,
+                self.layout.cell_alignment,
+                self.data_ptr,
+                |cell_ptr| {
+                    value.clear();
+                    // the length can be torn when the cell is written concurrently, the
+                    // snapshot is discarded in this case but it must never exceed the cell
+                    let len = ((cell_ptr as *const u64).read() as usize).min(self.max_slice_len);
+                    let elements = core::slice::from_raw_parts(
+                        cell_ptr.add(self.layout.header_size) as *const ValueType,
+                        len,
+                    );
+                    value.extend_from_slice(elements);
+                },
+            )
+        };
+        value
+    }
+
+    /// Returns the maximum number of elements the slice entry can hold.
+    pub fn max_slice_len(&self) -> usize {
+        self.max_slice_len
+    }
+
+    /// Creates an [`EntryListener`] that receives a notification whenever the entry is updated.
+    /// It can be attached to a [`WaitSet`](crate::waitset::WaitSet) to wait for updates of the
+    /// entry. The number of [`EntryListener`]s per entry is limited by the `max_listeners`
+    /// setting of the event defaults in the [`Config`](crate::config::Config).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<i32>(1, 8, &[1, 2, 3])
+    /// #     .create()?;
+    /// #
+    /// # let reader = service.reader_builder().create()?;
+    /// # let writer = service.writer_builder().create()?;
+    /// # let entry_handle_mut = writer.slice_entry::<i32>(&1)?;
+    /// let entry_handle = reader.slice_entry::<i32>(&1)?;
+    /// let entry_listener = entry_handle.entry_listener()?;
+    /// assert!(!entry_listener.has_changed()?);
+    ///
+    /// entry_handle_mut.update_with_copy(&[4, 5])?;
+    /// assert!(entry_listener.has_changed()?);
+    /// assert!(!entry_listener.has_changed()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn entry_listener(&self) -> Result<EntryListener<Service>, EntryHandleError> {
+        Ok(EntryListener {
+            listener: self.entry.create_listener()?,
+            entry_id: self.entry_id,
+        })
+    }
+
+    /// Returns an ID corresponding to the entry which can be used in an event based communication
+    /// setup.
+    pub fn entry_id(&self) -> EventId {
+        self.entry_id
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy,
+    > Debug for SliceEntryHandle<Service, KeyType, ValueType>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SliceEntryHandle { entry_id: {:?}, value_type: {} }",
+            self.entry_id,
+            core::any::type_name::<ValueType>()
+        )
+    }
+}
+
+/// Receives a notification whenever the [`Writer`](crate::port::writer::Writer) updates the
+/// entry it was created for. Created with [`EntryHandle::entry_listener()`] or
+/// [`SliceEntryHandle::entry_listener()`].
+#[derive(Debug)]
+pub struct EntryListener<Service: service::Service> {
+    listener: SignalListener<Service>,
+    entry_id: EventId,
+}
+
+impl<Service: service::Service> EntryListener<Service> {
+    /// Returns `true` when the entry was updated since the last call of
+    /// [`EntryListener::has_changed()`] or since the creation of the [`EntryListener`]. All
+    /// pending update notifications are consumed.
+    pub fn has_changed(&self) -> Result<bool, ListenerWaitError> {
+        self.listener.consume_signals()
+    }
+
+    /// Returns the id of the entry the [`EntryListener`] belongs to. It is equal to the
+    /// [`EntryHandle::entry_id()`] of the corresponding handle.
+    pub fn entry_id(&self) -> EventId {
+        self.entry_id
+    }
+}
+
+impl<Service: service::Service> FileDescriptorBased for EntryListener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+impl<Service: service::Service> SynchronousMultiplexing for EntryListener<Service> where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing
+{
+}
+
 // TODO [#817] replace u64 with CustomKeyMarker
 impl<Service: service::Service> Reader<Service, u64> {
+    #[doc(hidden)]
+    pub fn __internal_entries(&self) -> Vec<(u64, TypeDetail)> {
+        let service_state = &self.shared_state.service_state;
+        let (entries, generation) = service_state
+            .additional_resource
+            .mgmt
+            .get()
+            .entries::<Service>(service_state.shared_node.config());
+        self.key_set_generation.store(generation, Ordering::Relaxed);
+        entries
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_capacity(&self) -> usize {
+        self.shared_state
+            .service_state
+            .additional_resource
+            .mgmt
+            .get()
+            .capacity()
+    }
+
     #[doc(hidden)]
     pub fn __internal_entry(
         &self,
@@ -350,7 +869,8 @@ impl<Service: service::Service> Reader<Service, u64> {
         type_details: &TypeDetail,
     ) -> Result<__InternalEntryHandle<Service>, EntryHandleError> {
         let msg = "Unable to create entry handle";
-        let offset = self.get_entry_offset(key, type_details, msg)?;
+        let entry = self.acquire_entry(key, type_details, msg)?;
+        let offset = entry.offset();
 
         let atomic_mgmt_ptr = (self
             .shared_state
@@ -366,7 +886,8 @@ impl<Service: service::Service> Reader<Service, u64> {
         Ok(__InternalEntryHandle {
             atomic_mgmt_ptr,
             data_ptr: data_ptr as *const u8,
-            entry_id: EventId::new(offset as _),
+            entry_id: entry.entry_id(),
+            _entry: entry,
             _shared_state: self.shared_state.clone(),
         })
     }
@@ -375,10 +896,12 @@ impl<Service: service::Service> Reader<Service, u64> {
 /// A handle for direct read access to a specific blackboard value. Used for the language bindings
 /// where key and value type cannot be passed as generic.
 #[doc(hidden)]
+#[derive(Debug)]
 pub struct __InternalEntryHandle<Service: service::Service> {
     atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
     data_ptr: *const u8,
     entry_id: EventId,
+    _entry: EntryReference<Service, u64>,
     _shared_state: Arc<ReaderSharedState<Service, u64>>,
 }
 
@@ -392,6 +915,13 @@ impl<Service: service::Service> __InternalEntryHandle<Service> {
         (&*self.atomic_mgmt_ptr).load(value_ptr, value_size, value_alignment, self.data_ptr);
     }
 
+    /// Returns a counter that is incremented with every update of the value. When it is acquired
+    /// before [`__InternalEntryHandle::get()`] is called, an unchanged counter guarantees that
+    /// the value was not updated in the meantime.
+    pub fn write_generation(&self) -> u32 {
+        unsafe { (*self.atomic_mgmt_ptr).__internal_get_write_generation() }
+    }
+
     /// Returns an ID corresponding to the entry which can be used in an event based communication
     /// setup.
     pub fn entry_id(&self) -> EventId {
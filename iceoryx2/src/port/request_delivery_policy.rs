@@ -0,0 +1,41 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+
+use super::distribution_policy::DistributionPolicy;
+use super::port_identifiers::UniqueServerId;
+
+/// Defines to which [`Server`](crate::port::server::Server)s a
+/// [`Client`](crate::port::client::Client) delivers a
+/// [`RequestMut`](crate::request_mut::RequestMut). The policy that delivered a request
+/// is available on the server side via
+/// [`RequestHeader::delivery_policy()`](crate::service::header::request_response::RequestHeader::delivery_policy).
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, ZeroCopySend)]
+#[repr(C)]
+pub enum RequestDeliveryPolicy {
+    /// The request is delivered to every connected [`Server`](crate::port::server::Server).
+    #[default]
+    Broadcast,
+    /// The request is delivered to exactly one connected
+    /// [`Server`](crate::port::server::Server) that is selected with the provided
+    /// [`DistributionPolicy`]. When the selected server cannot receive the request, since
+    /// its buffer is full, the next server is tried. With
+    /// [`UnableToDeliverStrategy::Block`](crate::port::unable_to_deliver_strategy::UnableToDeliverStrategy::Block)
+    /// the client waits for the selected server instead.
+    AnyOne(DistributionPolicy),
+    /// The request is delivered only to the [`Server`](crate::port::server::Server) with the
+    /// provided [`UniqueServerId`]. If the server is not connected, the request is not
+    /// delivered at all.
+    Addressed(UniqueServerId),
+}
@@ -0,0 +1,237 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`SampleFilter`] is provided to a [`Subscriber`](crate::port::subscriber::Subscriber)
+//! when it is created and defines which [`Sample`](crate::sample::Sample)s it receives. It is
+//! stored in the dynamic config of the service and evaluated by the
+//! [`Publisher`](crate::port::publisher::Publisher) before the sample is delivered. Therefore,
+//! a sample that does not match is never enqueued and does not occupy the buffer of the
+//! [`Subscriber`](crate::port::subscriber::Subscriber).
+//!
+//! Since the [`Publisher`](crate::port::publisher::Publisher) may live in another process, the
+//! filter is not an arbitrary closure but a byte pattern. A sample matches when the bytes at
+//! the given offset of the user header or the payload, combined with the mask, are equal to the
+//! provided value.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::sample_filter::SampleFilter;
+//!
+//! #[derive(Debug, Default, Clone, Copy, ZeroCopySend)]
+//! #[repr(C)]
+//! pub struct CustomHeader {
+//!     topic: u32,
+//!     priority: u8,
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .user_header::<CustomHeader>()
+//!     .open_or_create()?;
+//!
+//! // receives only samples where the topic in the user header is 7
+//! let topic_subscriber = service.subscriber_builder()
+//!     .filter(SampleFilter::user_header(
+//!         core::mem::offset_of!(CustomHeader, topic),
+//!         &7u32.to_ne_bytes(),
+//!     )?)
+//!     .create()?;
+//!
+//! // receives only even numbers
+//! let even_subscriber = service.subscriber_builder()
+//!     .filter(SampleFilter::payload(0, &[0])?.with_mask(&[1])?)
+//!     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_log::fail;
+
+/// The maximum number of bytes a [`SampleFilter`] can compare.
+pub const MAX_SAMPLE_FILTER_LEN: usize = 16;
+
+/// Defines the failures that can occur when a [`SampleFilter`] is created.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SampleFilterCreateError {
+    /// The provided value or mask is longer than [`MAX_SAMPLE_FILTER_LEN`].
+    ExceedsMaxLength,
+    /// The provided mask does not have the same length as the value.
+    MaskLengthMismatch,
+}
+
+impl core::fmt::Display for SampleFilterCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SampleFilterCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for SampleFilterCreateError {}
+
+/// Defines the part of the [`Sample`](crate::sample::Sample) a [`SampleFilter`] inspects.
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SampleFilterTarget {
+    /// The user header of the [`Sample`](crate::sample::Sample).
+    UserHeader,
+    /// The payload of the [`Sample`](crate::sample::Sample).
+    Payload,
+}
+
+/// Byte pattern that decides whether a [`Sample`](crate::sample::Sample) is delivered to a
+/// [`Subscriber`](crate::port::subscriber::Subscriber). See the
+/// [module documentation](crate::port::sample_filter) for an example.
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct SampleFilter {
+    target: SampleFilterTarget,
+    offset: usize,
+    len: usize,
+    value: [u8; MAX_SAMPLE_FILTER_LEN],
+    mask: [u8; MAX_SAMPLE_FILTER_LEN],
+}
+
+impl Default for SampleFilter {
+    /// Creates a [`SampleFilter`] that matches every [`Sample`](crate::sample::Sample).
+    fn default() -> Self {
+        Self {
+            target: SampleFilterTarget::Payload,
+            offset: 0,
+            len: 0,
+            value: [0; MAX_SAMPLE_FILTER_LEN],
+            mask: [0; MAX_SAMPLE_FILTER_LEN],
+        }
+    }
+}
+
+impl core::fmt::Debug for SampleFilter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SampleFilter {{ target: {:?}, offset: {}, value: {:?}, mask: {:?} }}",
+            self.target,
+            self.offset,
+            self.value(),
+            self.mask()
+        )
+    }
+}
+
+impl SampleFilter {
+    /// Creates a [`SampleFilter`] that matches all [`Sample`](crate::sample::Sample)s whose
+    /// user header contains the provided value at the provided byte offset.
+    pub fn user_header(offset: usize, value: &[u8]) -> Result<Self, SampleFilterCreateError> {
+        Self::new(SampleFilterTarget::UserHeader, offset, value)
+    }
+
+    /// Creates a [`SampleFilter`] that matches all [`Sample`](crate::sample::Sample)s whose
+    /// payload contains the provided value at the provided byte offset.
+    pub fn payload(offset: usize, value: &[u8]) -> Result<Self, SampleFilterCreateError> {
+        Self::new(SampleFilterTarget::Payload, offset, value)
+    }
+
+    /// Restricts the comparison to the bits that are set in the provided mask. The mask must
+    /// have the same length as the value.
+    pub fn with_mask(mut self, mask: &[u8]) -> Result<Self, SampleFilterCreateError> {
+        if mask.len() != self.len {
+            fail!(from self, with SampleFilterCreateError::MaskLengthMismatch,
+                "Unable to set the mask of the sample filter since the mask length {} differs from the value length {}.",
+                mask.len(), self.len);
+        }
+
+        self.mask[..self.len].copy_from_slice(mask);
+        for i in 0..self.len {
+            self.value[i] &= self.mask[i];
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the [`SampleFilterTarget`].
+    pub fn target(&self) -> SampleFilterTarget {
+        self.target
+    }
+
+    /// Returns the byte offset in the [`SampleFilterTarget`] at which the comparison starts.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the value, already combined with the mask, the bytes are compared to.
+    pub fn value(&self) -> &[u8] {
+        &self.value[..self.len]
+    }
+
+    /// Returns the mask that is applied to the bytes before they are compared.
+    pub fn mask(&self) -> &[u8] {
+        &self.mask[..self.len]
+    }
+
+    fn new(
+        target: SampleFilterTarget,
+        offset: usize,
+        value: &[u8],
+    ) -> Result<Self, SampleFilterCreateError> {
+        if MAX_SAMPLE_FILTER_LEN < value.len() {
+            fail!(from "SampleFilter::new()", with SampleFilterCreateError::ExceedsMaxLength,
+                "Unable to create sample filter since the value length {} exceeds the maximum supported length of {}.",
+                value.len(), MAX_SAMPLE_FILTER_LEN);
+        }
+
+        let mut new_self = Self {
+            target,
+            offset,
+            len: value.len(),
+            ..Self::default()
+        };
+        new_self.value[..value.len()].copy_from_slice(value);
+        new_self.mask[..value.len()].fill(u8::MAX);
+
+        Ok(new_self)
+    }
+
+    /// Returns true when the filter matches every sample.
+    pub(crate) fn is_pass_through(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bytes the [`SampleFilterTarget`] must provide at least so that
+    /// the filter can be evaluated.
+    pub(crate) fn required_len(&self) -> usize {
+        self.offset.saturating_add(self.len)
+    }
+
+    /// Returns true when the provided user header and payload bytes match the filter. If the
+    /// target is too small to contain the compared bytes, the sample does not match.
+    pub(crate) fn matches(&self, user_header: &[u8], payload: &[u8]) -> bool {
+        if self.is_pass_through() {
+            return true;
+        }
+
+        let target = match self.target {
+            SampleFilterTarget::UserHeader => user_header,
+            SampleFilterTarget::Payload => payload,
+        };
+
+        match target.get(self.offset..self.required_len()) {
+            Some(bytes) => bytes
+                .iter()
+                .zip(self.mask().iter().zip(self.value().iter()))
+                .all(|(byte, (mask, value))| byte & mask == *value),
+            None => false,
+        }
+    }
+}
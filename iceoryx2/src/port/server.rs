@@ -110,6 +110,7 @@ use crate::{
 use super::details::data_segment::DataSegment;
 use super::details::segment_state::SegmentState;
 use super::details::sender::{ReceiverDetails, Sender};
+use super::details::signal::{ClientSignalNotifiers, SignalChannel, SignalListener};
 use super::{
     details::{
         chunk::Chunk,
@@ -132,7 +133,9 @@ pub(crate) struct SharedServerState<Service: service::Service> {
     server_handle: UnsafeCell<Option<ContainerHandle>>,
     pub(crate) request_receiver: Receiver<Service>,
     client_list_state: UnsafeCell<ContainerState<ClientDetails>>,
-    service_state: Arc<ServiceState<Service, NoResource>>,
+    pub(crate) service_state: Arc<ServiceState<Service, NoResource>>,
+    request_listener: Option<SignalListener<Service>>,
+    pub(crate) response_notifiers: Option<ClientSignalNotifiers<Service>>,
 }
 
 impl<Service: service::Service> Drop for SharedServerState<Service> {
@@ -205,6 +208,14 @@ impl<Service: service::Service> SharedServerState<Service> {
         self.response_sender.finish_update_connection_cycle();
         self.request_receiver.finish_update_connection_cycle();
 
+        if let Some(response_notifiers) = &self.response_notifiers {
+            response_notifiers.retain_connected(|client_id| {
+                self.response_sender
+                    .get_connection_id_of(client_id)
+                    .is_some()
+            });
+        }
+
         result
     }
 }
@@ -400,8 +411,22 @@ impl<
             number_of_channels: number_of_requests_per_client,
         };
 
+        let (request_listener, response_notifiers) = match static_config.enable_notify_on_send {
+            true => (
+                Some(fail!(from origin,
+                    when SignalListener::create(service, SignalChannel::Requests),
+                    with ServerCreateError::UnableToCreateNotificationChannel,
+                    "{} since the notification channel for the requests could not be created.", msg)),
+                // the notification channels of the clients are opened on the first response
+                Some(ClientSignalNotifiers::new()),
+            ),
+            false => (None, None),
+        };
+
         let shared_state = Service::ArcThreadSafetyPolicy::new(SharedServerState {
             config: server_factory.config,
+            request_listener,
+            response_notifiers,
             request_receiver,
             client_list_state: UnsafeCell::new(unsafe { client_list.get_state() }),
             server_handle: UnsafeCell::new(None),
@@ -506,10 +531,42 @@ impl<
                   "Some requests are not being received since not all connections to the clients could be established.");
         }
 
+        if let Some(listener) = &shared_state.request_listener {
+            if let Err(e) = listener.consume_signals() {
+                warn!(from self, "Unable to consume the pending send notifications ({:?}).", e);
+            }
+        }
+
         shared_state.request_receiver.receive(REQUEST_CHANNEL_ID)
     }
 }
 
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ZeroCopySend + ?Sized,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend + ?Sized,
+        ResponseHeader: Debug + ZeroCopySend,
+    > Server<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+{
+    /// Returns the [`SignalListener`] that is signaled whenever a
+    /// [`Client`](crate::port::client::Client) sent a
+    /// [`RequestMut`](crate::request_mut::RequestMut). It can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet). Every call to [`Server::receive()`] consumes the
+    /// pending signals, therefore all available requests shall be received after a wakeup.
+    ///
+    /// Returns [`None`] when the [`Service`](crate::service::Service) was created without
+    /// [`enable_notify_on_send()`](crate::service::builder::request_response::Builder::enable_notify_on_send).
+    pub fn signal_listener(&self) -> Option<&SignalListener<Service>> {
+        let shared_state = self.shared_state.lock();
+        shared_state.request_listener.as_ref().map(|listener| {
+            let listener = listener as *const SignalListener<Service>;
+            // the shared state and its listener live as long as the server
+            unsafe { &*listener }
+        })
+    }
+}
+
 impl<
         Service: service::Service,
         RequestPayload: Debug + ZeroCopySend,
@@ -616,6 +673,34 @@ impl<
             }
         }
     }
+
+    /// Waits asynchronously until a [`RequestMut`](crate::request_mut::RequestMut) that was
+    /// sent by a [`Client`](crate::port::client::Client) was received and returns an
+    /// [`ActiveRequest`] which can be used to respond. The task is woken up by the
+    /// [`SignalListener`] of the [`Server`], therefore the service must be created with
+    /// notify on send enabled, otherwise
+    /// [`AsyncReceiveError::NotifyOnSendDisabled`](crate::port::AsyncReceiveError::NotifyOnSendDisabled)
+    /// is returned.
+    #[cfg(feature = "async")]
+    #[allow(clippy::type_complexity)] // type alias would require 5 generic parameters which hardly reduces complexity
+    pub async fn receive_async(
+        &self,
+    ) -> Result<
+        ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        crate::port::AsyncReceiveError,
+    >
+    where
+        <Service::Event as iceoryx2_cal::event::Event>::Listener:
+            iceoryx2_bb_posix::file_descriptor::FileDescriptorBased,
+    {
+        match self.signal_listener() {
+            Some(listener) => listener.receive_async(|| self.receive()).await,
+            None => {
+                fail!(from self, with crate::port::AsyncReceiveError::NotifyOnSendDisabled,
+                    "Unable to receive asynchronously since the service was created without notify on send.");
+            }
+        }
+    }
 }
 
 impl<
@@ -733,6 +818,34 @@ impl<
             }
         }
     }
+
+    /// Waits asynchronously until a [`RequestMut`](crate::request_mut::RequestMut) that was
+    /// sent by a [`Client`](crate::port::client::Client) was received and returns an
+    /// [`ActiveRequest`] which can be used to respond. The task is woken up by the
+    /// [`SignalListener`] of the [`Server`], therefore the service must be created with
+    /// notify on send enabled, otherwise
+    /// [`AsyncReceiveError::NotifyOnSendDisabled`](crate::port::AsyncReceiveError::NotifyOnSendDisabled)
+    /// is returned.
+    #[cfg(feature = "async")]
+    #[allow(clippy::type_complexity)] // type alias would require 5 generic parameters which hardly reduces complexity
+    pub async fn receive_async(
+        &self,
+    ) -> Result<
+        ActiveRequest<Service, [RequestPayload], RequestHeader, ResponsePayload, ResponseHeader>,
+        crate::port::AsyncReceiveError,
+    >
+    where
+        <Service::Event as iceoryx2_cal::event::Event>::Listener:
+            iceoryx2_bb_posix::file_descriptor::FileDescriptorBased,
+    {
+        match self.signal_listener() {
+            Some(listener) => listener.receive_async(|| self.receive()).await,
+            None => {
+                fail!(from self, with crate::port::AsyncReceiveError::NotifyOnSendDisabled,
+                    "Unable to receive asynchronously since the service was created without notify on send.");
+            }
+        }
+    }
 }
 
 impl<
@@ -54,6 +54,7 @@ use crate::service::builder::CustomPayloadMarker;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::port_factory::subscriber::SubscriberConfig;
+use crate::service::static_config::message_type_details::TypeVariant;
 use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::service::{NoResource, ServiceState};
 use crate::{raw_sample::RawSample, sample::Sample, service};
@@ -61,7 +62,9 @@ use crate::{raw_sample::RawSample, sample::Sample, service};
 use super::details::chunk::Chunk;
 use super::details::chunk_details::ChunkDetails;
 use super::details::receiver::*;
-use super::port_identifiers::UniqueSubscriberId;
+use super::details::signal::{SignalChannel, SignalListener};
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use super::sample_filter::{SampleFilter, SampleFilterTarget};
 use super::update_connections::ConnectionFailure;
 use super::ReceiveError;
 
@@ -82,6 +85,13 @@ pub enum SubscriberCreateError {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The [`Service`](crate::service::Service) has notify on send enabled but the
+    /// underlying event service, that is used to receive the signals of the
+    /// [`Publisher`](crate::port::publisher::Publisher)s, could not be opened or created.
+    UnableToCreateNotificationChannel,
+    /// The provided [`SampleFilter`] compares bytes beyond the end of the user header or the
+    /// fixed size payload of the [`Service`](crate::service::Service).
+    SampleFilterOutOfBounds,
 }
 
 impl core::fmt::Display for SubscriberCreateError {
@@ -96,6 +106,7 @@ impl core::error::Error for SubscriberCreateError {}
 pub(crate) struct SubscriberSharedState<Service: service::Service> {
     pub(crate) receiver: Receiver<Service>,
     pub(crate) publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    pub(crate) sample_listener: Option<SignalListener<Service>>,
 }
 
 /// The receiving endpoint of a publish-subscribe communication.
@@ -181,6 +192,25 @@ impl<
             None => static_config.subscriber_max_buffer_size,
         };
 
+        let filter = config.filter;
+        let type_details = &static_config.message_type_details;
+        let available_len = match filter.target() {
+            SampleFilterTarget::UserHeader => Some(type_details.user_header.size),
+            SampleFilterTarget::Payload => match type_details.payload.variant {
+                TypeVariant::FixedSize => Some(type_details.payload.size),
+                // slices are checked by the publisher for every sample since their length varies
+                TypeVariant::Dynamic => None,
+            },
+        };
+
+        if let Some(available_len) = available_len {
+            if !filter.is_pass_through() && available_len < filter.required_len() {
+                fail!(from origin, with SubscriberCreateError::SampleFilterOutOfBounds,
+                    "{} since the sample filter {:?} requires {} bytes but the {:?} has only {} bytes.",
+                    msg, filter, filter.required_len(), filter.target(), available_len);
+            }
+        }
+
         let number_of_to_be_removed_connections = service
             .shared_node
             .config()
@@ -191,7 +221,16 @@ impl<
         let number_of_connections =
             number_of_to_be_removed_connections + number_of_active_connections;
 
+        let sample_listener = match static_config.enable_notify_on_send {
+            true => Some(fail!(from origin,
+                when SignalListener::create(&service, SignalChannel::Samples),
+                with SubscriberCreateError::UnableToCreateNotificationChannel,
+                "{} since the notification channel for the publishers could not be created.", msg)),
+            false => None,
+        };
+
         let subscriber_shared_state = Service::ArcThreadSafetyPolicy::new(SubscriberSharedState {
+            sample_listener,
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
             receiver: Receiver {
                 connections: Vec::from_fn(number_of_active_connections, |_| UnsafeCell::new(None)),
@@ -244,6 +283,7 @@ impl<
                 subscriber_id,
                 buffer_size,
                 node_id: *service.shared_node.id(),
+                filter,
             }) {
             Some(unique_index) => unique_index,
             None => {
@@ -319,14 +359,34 @@ impl<
             .has_samples(ChannelId::new(0)))
     }
 
+    /// Returns the number of [`Sample`]s the [`Subscriber`] has missed from the
+    /// [`Publisher`](crate::port::publisher::Publisher) with the provided
+    /// [`UniquePublisherId`]. Samples are missed when they were never delivered, for instance
+    /// when the buffer of the [`Subscriber`] overflowed or the
+    /// [`Publisher`](crate::port::publisher::Publisher) discarded them. Samples that did not
+    /// match the [`SampleFilter`] of the [`Subscriber`] are not missed.
+    ///
+    /// Returns [`None`] when the [`Subscriber`] is not connected to the
+    /// [`Publisher`](crate::port::publisher::Publisher).
+    pub fn number_of_missed_samples(&self, publisher_id: &UniquePublisherId) -> Option<u64> {
+        self.subscriber_shared_state
+            .lock()
+            .receiver
+            .number_of_missed_samples(publisher_id.value())
+    }
+
     fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
 
-        self.subscriber_shared_state
-            .lock()
-            .receiver
-            .receive(ChannelId::new(0))
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        if let Some(listener) = &subscriber_shared_state.sample_listener {
+            if let Err(e) = listener.consume_signals() {
+                warn!(from self, "Unable to consume the pending send notifications ({:?}).", e);
+            }
+        }
+
+        subscriber_shared_state.receiver.receive(ChannelId::new(0))
     }
 }
 
@@ -356,6 +416,33 @@ impl<
     }
 }
 
+impl<
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend + ?Sized,
+        UserHeader: Debug + ZeroCopySend,
+    > Subscriber<Service, Payload, UserHeader>
+{
+    /// Returns the [`SignalListener`] that is signaled whenever a
+    /// [`Publisher`](crate::port::publisher::Publisher) sent a [`Sample`]. It can be attached
+    /// to a [`WaitSet`](crate::waitset::WaitSet). Every call to [`Subscriber::receive()`]
+    /// consumes the pending signals, therefore all available [`Sample`]s shall be received
+    /// after a wakeup.
+    ///
+    /// Returns [`None`] when the [`Service`](crate::service::Service) was created without
+    /// [`enable_notify_on_send()`](crate::service::builder::publish_subscribe::Builder::enable_notify_on_send).
+    pub fn signal_listener(&self) -> Option<&SignalListener<Service>> {
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        subscriber_shared_state
+            .sample_listener
+            .as_ref()
+            .map(|listener| {
+                let listener = listener as *const SignalListener<Service>;
+                // the shared state and its listener live as long as the subscriber
+                unsafe { &*listener }
+            })
+    }
+}
+
 impl<
         Service: service::Service,
         Payload: Debug + ZeroCopySend,
@@ -377,6 +464,30 @@ impl<
             },
         }))
     }
+
+    /// Waits asynchronously until a [`crate::sample::Sample`] from a
+    /// [`crate::port::publisher::Publisher`] was received. The task is woken up by the
+    /// [`SignalListener`] of the [`Subscriber`], therefore the service must be created with
+    /// notify on send enabled, otherwise
+    /// [`AsyncReceiveError::NotifyOnSendDisabled`](crate::port::AsyncReceiveError::NotifyOnSendDisabled)
+    /// is returned.
+    /// If a failure occurs [`AsyncReceiveError`](crate::port::AsyncReceiveError) is returned.
+    #[cfg(feature = "async")]
+    pub async fn receive_async(
+        &self,
+    ) -> Result<Sample<Service, Payload, UserHeader>, crate::port::AsyncReceiveError>
+    where
+        <Service::Event as iceoryx2_cal::event::Event>::Listener:
+            iceoryx2_bb_posix::file_descriptor::FileDescriptorBased,
+    {
+        match self.signal_listener() {
+            Some(listener) => listener.receive_async(|| self.receive()).await,
+            None => {
+                fail!(from self, with crate::port::AsyncReceiveError::NotifyOnSendDisabled,
+                    "Unable to receive asynchronously since the service was created without notify on send.");
+            }
+        }
+    }
 }
 
 impl<
@@ -407,6 +518,30 @@ impl<
             }
         }))
     }
+
+    /// Waits asynchronously until a [`crate::sample::Sample`] from a
+    /// [`crate::port::publisher::Publisher`] was received. The task is woken up by the
+    /// [`SignalListener`] of the [`Subscriber`], therefore the service must be created with
+    /// notify on send enabled, otherwise
+    /// [`AsyncReceiveError::NotifyOnSendDisabled`](crate::port::AsyncReceiveError::NotifyOnSendDisabled)
+    /// is returned.
+    /// If a failure occurs [`AsyncReceiveError`](crate::port::AsyncReceiveError) is returned.
+    #[cfg(feature = "async")]
+    pub async fn receive_async(
+        &self,
+    ) -> Result<Sample<Service, [Payload], UserHeader>, crate::port::AsyncReceiveError>
+    where
+        <Service::Event as iceoryx2_cal::event::Event>::Listener:
+            iceoryx2_bb_posix::file_descriptor::FileDescriptorBased,
+    {
+        match self.signal_listener() {
+            Some(listener) => listener.receive_async(|| self.receive()).await,
+            None => {
+                fail!(from self, with crate::port::AsyncReceiveError::NotifyOnSendDisabled,
+                    "Unable to receive asynchronously since the service was created without notify on send.");
+            }
+        }
+    }
 }
 
 impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
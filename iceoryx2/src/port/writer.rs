@@ -39,14 +39,48 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Slice Entries
+//!
+//! ```
+//! # use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! type KeyType = u64;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard_creator::<KeyType>()
+//!     .add_slice::<u8>(1, 64, b"some config")
+//!     .create()?;
+//!
+//! let writer = service.writer_builder().create()?;
+//!
+//! // create a handle for direct write access to the slice
+//! let entry_handle_mut = writer.slice_entry::<u8>(&1)?;
+//!
+//! // update the slice with a copy
+//! entry_handle_mut.update_with_copy(b"another config")?;
+//!
+//! // loan an uninitialized slice and write to it without copying
+//! let entry_value_uninit = entry_handle_mut.loan_slice_uninit(3)?;
+//! let entry_value = entry_value_uninit.write_from_fn(|i| b'a' + i as u8);
+//! let entry_handle_mut = entry_value.update();
+//!
+//! # Ok(())
+//! # }
+//! ```
 
+use crate::port::details::signal::{SignalChannel, SignalNotifier};
 use crate::prelude::EventId;
-use crate::service::builder::blackboard::BlackboardResources;
+use crate::service::builder::blackboard::{
+    BlackboardResources, BuilderInternals, EntryReference, SliceEntryLayout,
+};
 use crate::service::dynamic_config::blackboard::WriterDetails;
 use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
 use crate::service::{self, ServiceState};
 use core::fmt::Debug;
 use core::hash::Hash;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
 use core::sync::atomic::Ordering;
 use iceoryx2_bb_elementary::math::align;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -116,6 +150,7 @@ pub struct Writer<
     KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
 > {
     shared_state: Arc<WriterSharedState<Service, KeyType>>,
+    key_set_notifier: SignalNotifier<Service>,
     writer_id: UniqueWriterId,
 }
 
@@ -131,11 +166,17 @@ impl<
         let msg = "Unable to create Writer port";
 
         let writer_id = UniqueWriterId::new();
+        let key_set_notifier = fail!(from origin,
+            when SignalNotifier::create(&service, SignalChannel::KeySet),
+            with WriterCreateError::InternalFailure,
+            "{} since the notifier for the key set changes could not be created.", msg);
+
         let mut new_self = Self {
             shared_state: Arc::new(WriterSharedState {
                 service_state: service.clone(),
                 dynamic_writer_handle: None,
             }),
+            key_set_notifier,
             writer_id,
         };
 
@@ -197,64 +238,215 @@ impl<
     ) -> Result<EntryHandleMut<Service, KeyType, ValueType>, EntryHandleMutError> {
         let msg = "Unable to create entry handle";
 
-        let offset = self.get_entry_offset(
+        let entry = self.acquire_entry(
             key,
             &TypeDetail::new::<ValueType>(TypeVariant::FixedSize),
             msg,
         )?;
 
-        match EntryHandleMut::new(self.shared_state.clone(), offset) {
+        match EntryHandleMut::new(self.shared_state.clone(), entry) {
             Ok(handle) => Ok(handle),
+            Err(EntryHandleMutError::HandleAlreadyExists) => {
+                fail!(from self, with EntryHandleMutError::HandleAlreadyExists,
+                    "{} since a handle for the passed key and value type already exists.", msg);
+            }
             Err(e) => {
                 fail!(from self, with e,
-                    "{} since a handle for the passed key and value type already exists.", msg);
+                    "{} since the update notifications of the entry could not be set up.", msg);
             }
         }
     }
 
-    fn get_entry_offset(
+    /// Creates a [`SliceEntryHandleMut`] for direct write access to a slice entry that was added
+    /// with [`Creator::add_slice()`](crate::service::builder::blackboard::Creator::add_slice()).
+    /// There can be only one [`SliceEntryHandleMut`] per entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<i32>(1, 8, &[1, 2, 3])
+    /// #     .create()?;
+    /// #
+    /// # let writer = service.writer_builder().create()?;
+    /// let entry_handle_mut = writer.slice_entry::<i32>(&1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn slice_entry<ValueType: Copy + ZeroCopySend>(
         &self,
         key: &KeyType,
-        type_details: &TypeDetail,
-        msg: &str,
-    ) -> Result<u64, EntryHandleMutError> {
-        // check if key exists
-        let index = match unsafe {
-            self.shared_state
-                .service_state
-                .additional_resource
-                .mgmt
-                .get()
-                .map
-                .get(key)
-        } {
-            Some(i) => i,
-            None => {
-                fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
-                "{} since no entry with the given key exists.", msg);
+    ) -> Result<SliceEntryHandleMut<Service, KeyType, ValueType>, EntryHandleMutError> {
+        let msg = "Unable to create slice entry handle";
+
+        let entry = self.acquire_entry(
+            key,
+            &TypeDetail::new::<ValueType>(TypeVariant::Dynamic),
+            msg,
+        )?;
+
+        match SliceEntryHandleMut::new(self.shared_state.clone(), entry) {
+            Ok(handle) => Ok(handle),
+            Err(EntryHandleMutError::HandleAlreadyExists) => {
+                fail!(from self, with EntryHandleMutError::HandleAlreadyExists,
+                    "{} since a handle for the passed key and value type already exists.", msg);
             }
-        };
+            Err(e) => {
+                fail!(from self, with e,
+                    "{} since the update notifications of the entry could not be set up.", msg);
+            }
+        }
+    }
+
+    /// Inserts a new key-value pair into the blackboard. The capacity for the entry must have
+    /// been reserved with
+    /// [`Creator::reserve()`](crate::service::builder::blackboard::Creator::reserve()) or
+    /// become available by removing another entry with [`Writer::remove()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add::<i32>(1, -1)
+    /// #     .reserve::<u32>(1)
+    /// #     .create()?;
+    /// #
+    /// # let writer = service.writer_builder().create()?;
+    /// writer.insert::<u32>(2, 42)?;
+    /// let entry_handle_mut = writer.entry::<u32>(&2)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn insert<ValueType: ZeroCopySend + Copy + 'static>(
+        &self,
+        key: KeyType,
+        value: ValueType,
+    ) -> Result<(), EntryInsertError> {
+        self.insert_impl(BuilderInternals::fixed_size(key, value))
+    }
+
+    /// Inserts a new slice entry with at most `max_slice_len` elements into the blackboard
+    /// and initializes it with `value`. The capacity for the entry must have been reserved with
+    /// [`Creator::reserve_slice()`](crate::service::builder::blackboard::Creator::reserve_slice())
+    /// or become available by removing another entry with [`Writer::remove()`]. The elements
+    /// must be [`Copy`], see
+    /// [`Creator::add_slice()`](crate::service::builder::blackboard::Creator::add_slice()).
+    pub fn insert_slice<ValueType: ZeroCopySend + Copy + 'static>(
+        &self,
+        key: KeyType,
+        max_slice_len: usize,
+        value: &[ValueType],
+    ) -> Result<(), EntryInsertError> {
+        self.insert_impl(BuilderInternals::slice(key, max_slice_len, value))
+    }
+
+    fn insert_impl(
+        &self,
+        mut internals: BuilderInternals<KeyType>,
+    ) -> Result<(), EntryInsertError> {
+        let service_state = &self.shared_state.service_state;
+        let resources = &service_state.additional_resource;
+        fail!(from self, when resources.mgmt.get().insert(&service_state.shared_node, &resources.data, &mut internals),
+            "Unable to insert the entry with the key {:?}.", internals.key());
+        self.key_set_notifier.notify();
+        Ok(())
+    }
 
-        let entry = &self
+    /// Removes the entry with the given key from the blackboard. Existing handles to the entry
+    /// stay valid but are no longer updated or readable by newly created handles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add::<i32>(1, -1)
+    /// #     .create()?;
+    /// #
+    /// # let writer = service.writer_builder().create()?;
+    /// writer.remove(&1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove(&self, key: &KeyType) -> Result<(), EntryRemoveError> {
+        if !self
             .shared_state
             .service_state
             .additional_resource
             .mgmt
             .get()
-            .entries[index];
+            .remove(&self.shared_state.service_state.shared_node, key)
+        {
+            fail!(from self, with EntryRemoveError::EntryDoesNotExist,
+                "Unable to remove the entry since no entry with the key {:?} exists.", key);
+        }
+
+        self.key_set_notifier.notify();
+        Ok(())
+    }
 
-        // check if ValueType matches
-        if *type_details != entry.type_details {
-            fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
-                "{} since no entry with the given key and value type exists.", msg);
+    fn acquire_entry(
+        &self,
+        key: &KeyType,
+        type_details: &TypeDetail,
+        msg: &str,
+    ) -> Result<EntryReference<Service, KeyType>, EntryHandleMutError> {
+        match EntryReference::acquire(&self.shared_state.service_state, key, type_details) {
+            Some(entry) => Ok(entry),
+            None => {
+                fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
+                    "{} since no entry with the given key and value type exists.", msg);
+            }
         }
+    }
+}
+
+/// Defines a failure that can occur when an entry is inserted with [`Writer::insert()`] or
+/// [`Writer::insert_slice()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EntryInsertError {
+    /// An entry with the same key already exists.
+    KeyAlreadyExists,
+    /// The blackboard already contains the maximum number of entries.
+    ExceedsMaxNumberOfEntries,
+    /// The reserved memory of the blackboard is insufficient to store the value.
+    OutOfMemory,
+}
 
-        let offset = entry.offset.load(core::sync::atomic::Ordering::Relaxed);
+impl core::fmt::Display for EntryInsertError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "EntryInsertError::{self:?}")
+    }
+}
+
+impl core::error::Error for EntryInsertError {}
 
-        Ok(offset)
+/// Defines a failure that can occur when an entry is removed with [`Writer::remove()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EntryRemoveError {
+    /// No entry with the given key exists.
+    EntryDoesNotExist,
+}
+
+impl core::fmt::Display for EntryRemoveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "EntryRemoveError::{self:?}")
     }
 }
 
+impl core::error::Error for EntryRemoveError {}
+
 /// Defines a failure that can occur when a [`EntryHandleMut`] is created with [`Writer::entry()`].
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum EntryHandleMutError {
@@ -262,6 +454,9 @@ pub enum EntryHandleMutError {
     EntryDoesNotExist,
     /// The [`EntryHandleMut`] already exists.
     HandleAlreadyExists,
+    /// The resources to notify the [`EntryHandle`](crate::port::reader::EntryHandle)s about
+    /// updates of the entry could not be created.
+    ResourceCreationFailed,
 }
 
 impl core::fmt::Display for EntryHandleMutError {
@@ -280,6 +475,8 @@ pub struct EntryHandleMut<
 > {
     producer: Producer<'static, ValueType>,
     entry_id: EventId,
+    notifier: SignalNotifier<Service>,
+    _entry: EntryReference<Service, KeyType>,
     _shared_state: Arc<WriterSharedState<Service, KeyType>>,
 }
 
@@ -308,8 +505,9 @@ impl<
 {
     fn new(
         writer_state: Arc<WriterSharedState<Service, KeyType>>,
-        offset: u64,
+        entry: EntryReference<Service, KeyType>,
     ) -> Result<Self, EntryHandleMutError> {
+        let offset = entry.offset();
         let atomic = (writer_state
             .service_state
             .additional_resource
@@ -325,8 +523,10 @@ impl<
                 let p: Producer<'static, ValueType> = unsafe { core::mem::transmute(producer) };
                 Ok(Self {
                     producer: p,
+                    entry_id: entry.entry_id(),
+                    notifier: entry.create_notifier()?,
+                    _entry: entry,
                     _shared_state: writer_state.clone(),
-                    entry_id: EventId::new(offset as _),
                 })
             }
         }
@@ -353,6 +553,7 @@ impl<
     /// ```
     pub fn update_with_copy(&self, value: ValueType) {
         self.producer.store(value);
+        self.notifier.notify();
     }
 
     /// Consumes the [`EntryHandleMut`] and loans an uninitialized entry value that can be used to update without copy.
@@ -548,6 +749,7 @@ impl<
                 .producer
                 .__internal_update_write_cell()
         };
+        self.entry_handle_mut.notifier.notify();
         self.entry_handle_mut
     }
 
@@ -577,8 +779,465 @@ impl<
     }
 }
 
+/// Defines a failure that can occur when a slice entry is updated with a
+/// [`SliceEntryHandleMut`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SliceEntryUpdateError {
+    /// The provided slice length exceeds the maximum slice length of the entry.
+    ExceedsMaxSliceLen,
+}
+
+impl core::fmt::Display for SliceEntryUpdateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "SliceEntryUpdateError::{self:?}")
+    }
+}
+
+impl core::error::Error for SliceEntryUpdateError {}
+
+/// A handle for direct write access to a specific blackboard slice entry.
+pub struct SliceEntryHandleMut<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: Copy + 'static,
+> {
+    atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
+    data_ptr: *mut u8,
+    layout: SliceEntryLayout,
+    max_slice_len: usize,
+    entry_id: EventId,
+    notifier: SignalNotifier<Service>,
+    _entry: EntryReference<Service, KeyType>,
+    _shared_state: Arc<WriterSharedState<Service, KeyType>>,
+    _value: PhantomData<ValueType>,
+}
+
+// Safe since the SliceEntryHandleMut is the only producer of the underlying
+// UnrestrictedAtomicMgmt and shared_state ensures the lifetime of the underlying memory (struct
+// fields are dropped in the same order as declared)
+unsafe impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy + 'static,
+    > Send for SliceEntryHandleMut<Service, KeyType, ValueType>
+{
+}
+unsafe impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy + 'static,
+    > Sync for SliceEntryHandleMut<Service, KeyType, ValueType>
+{
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy + 'static,
+    > Drop for SliceEntryHandleMut<Service, KeyType, ValueType>
+{
+    fn drop(&mut self) {
+        unsafe { (*self.atomic_mgmt_ptr).__internal_release_producer() };
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy + 'static,
+    > SliceEntryHandleMut<Service, KeyType, ValueType>
+{
+    fn new(
+        writer_state: Arc<WriterSharedState<Service, KeyType>>,
+        entry: EntryReference<Service, KeyType>,
+    ) -> Result<Self, EntryHandleMutError> {
+        let offset = entry.offset();
+        let max_slice_len = entry.max_slice_len();
+        let atomic_mgmt_ptr = (writer_state
+            .service_state
+            .additional_resource
+            .data
+            .payload_start_address() as u64
+            + offset) as *const UnrestrictedAtomicMgmt;
+        let layout = SliceEntryLayout::new::<ValueType>(max_slice_len);
+
+        if unsafe { (*atomic_mgmt_ptr).__internal_acquire_producer() }.is_err() {
+            return Err(EntryHandleMutError::HandleAlreadyExists);
+        }
+
+        let notifier = match entry.create_notifier() {
+            Ok(notifier) => notifier,
+            Err(e) => {
+                unsafe { (*atomic_mgmt_ptr).__internal_release_producer() };
+                return Err(e);
+            }
+        };
+
+        Ok(Self {
+            atomic_mgmt_ptr,
+            data_ptr: layout.data_ptr(atomic_mgmt_ptr),
+            layout,
+            max_slice_len,
+            entry_id: entry.entry_id(),
+            notifier,
+            _entry: entry,
+            _shared_state: writer_state,
+            _value: PhantomData,
+        })
+    }
+
+    fn write_cell(&self) -> *mut u8 {
+        unsafe {
+            (*self.atomic_mgmt_ptr).__internal_get_ptr_to_write_cell(
+                self.layout.cell_size,
+                self.layout.cell_alignment,
+                self.data_ptr,
+            )
+        }
+    }
+
+    fn elements_of(&self, write_cell: *mut u8) -> *mut ValueType {
+        unsafe { write_cell.add(self.layout.header_size) as *mut ValueType }
+    }
+
+    fn publish(&self, write_cell: *mut u8, slice_len: usize) {
+        unsafe {
+            (write_cell as *mut u64).write(slice_len as u64);
+            (*self.atomic_mgmt_ptr).__internal_update_write_cell();
+        }
+        self.notifier.notify();
+    }
+
+    /// Updates the slice entry by copying the passed slice into it. Fails when the length of
+    /// `value` exceeds [`SliceEntryHandleMut::max_slice_len()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<i32>(1, 8, &[1, 2, 3])
+    /// #     .create()?;
+    ///
+    /// # let writer = service.writer_builder().create()?;
+    /// # let entry_handle_mut = writer.slice_entry::<i32>(&1)?;
+    /// entry_handle_mut.update_with_copy(&[4, 5, 6, 7])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_with_copy(&self, value: &[ValueType]) -> Result<(), SliceEntryUpdateError> {
+        if self.max_slice_len < value.len() {
+            fail!(from self, with SliceEntryUpdateError::ExceedsMaxSliceLen,
+                "Unable to update the slice entry since the slice length of {} exceeds the maximum slice length of {}.",
+                value.len(), self.max_slice_len);
+        }
+
+        let write_cell = self.write_cell();
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                value.as_ptr(),
+                self.elements_of(write_cell),
+                value.len(),
+            )
+        };
+        self.publish(write_cell, value.len());
+
+        Ok(())
+    }
+
+    /// Consumes the [`SliceEntryHandleMut`] and loans an uninitialized slice with `slice_len`
+    /// elements that can be used to update without copy. Fails when `slice_len` exceeds
+    /// [`SliceEntryHandleMut::max_slice_len()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<i32>(1, 8, &[1, 2, 3])
+    /// #     .create()?;
+    ///
+    /// # let writer = service.writer_builder().create()?;
+    /// # let entry_handle_mut = writer.slice_entry::<i32>(&1)?;
+    /// let entry_value_uninit = entry_handle_mut.loan_slice_uninit(5)?;
+    /// let entry_value = entry_value_uninit.write_from_fn(|i| i as i32 * 2);
+    /// entry_value.update();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_slice_uninit(
+        self,
+        slice_len: usize,
+    ) -> Result<SliceEntryValueUninit<Service, KeyType, ValueType>, SliceEntryUpdateError> {
+        if self.max_slice_len < slice_len {
+            fail!(from self, with SliceEntryUpdateError::ExceedsMaxSliceLen,
+                "Unable to loan a slice with {} elements since it exceeds the maximum slice length of {}.",
+                slice_len, self.max_slice_len);
+        }
+
+        Ok(SliceEntryValueUninit::new(self, slice_len))
+    }
+
+    /// Returns the maximum number of elements the slice entry can hold.
+    pub fn max_slice_len(&self) -> usize {
+        self.max_slice_len
+    }
+
+    /// Returns an ID corresponding to the entry which can be used in an event based communication
+    /// setup.
+    pub fn entry_id(&self) -> EventId {
+        self.entry_id
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy + 'static,
+    > Debug for SliceEntryHandleMut<Service, KeyType, ValueType>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SliceEntryHandleMut<{}> {{ max_slice_len: {}, entry_id: {:?} }}",
+            core::any::type_name::<ValueType>(),
+            self.max_slice_len,
+            self.entry_id
+        )
+    }
+}
+
+/// Wrapper around an uninitialized slice entry value that can be used for a zero-copy update.
+pub struct SliceEntryValueUninit<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: Copy + 'static,
+> {
+    write_cell: *mut u8,
+    slice_len: usize,
+    entry_handle_mut: SliceEntryHandleMut<Service, KeyType, ValueType>,
+}
+
+// Safe since the SliceEntryHandleMut implements Send + Sync and ensures that the memory
+// write_cell is pointing to remains valid, and all methods of SliceEntryValueUninit that grant
+// access to the memory require a mutable reference or are consuming.
+unsafe impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy + 'static,
+    > Send for SliceEntryValueUninit<Service, KeyType, ValueType>
+{
+}
+unsafe impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy + 'static,
+    > Sync for SliceEntryValueUninit<Service, KeyType, ValueType>
+{
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy + 'static,
+    > SliceEntryValueUninit<Service, KeyType, ValueType>
+{
+    fn new(
+        entry_handle_mut: SliceEntryHandleMut<Service, KeyType, ValueType>,
+        slice_len: usize,
+    ) -> Self {
+        Self {
+            write_cell: entry_handle_mut.write_cell(),
+            slice_len,
+            entry_handle_mut,
+        }
+    }
+
+    /// Returns a mutable reference to the uninitialized slice.
+    pub fn payload_mut(&mut self) -> &mut [MaybeUninit<ValueType>] {
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                self.entry_handle_mut.elements_of(self.write_cell) as *mut MaybeUninit<ValueType>,
+                self.slice_len,
+            )
+        }
+    }
+
+    /// Consumes the [`SliceEntryValueUninit`], initializes every element with the result of
+    /// `initializer` and returns the initialized [`SliceEntryValue`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<i32>(1, 8, &[1, 2, 3])
+    /// #     .create()?;
+    ///
+    /// # let writer = service.writer_builder().create()?;
+    /// # let entry_handle_mut = writer.slice_entry::<i32>(&1)?;
+    /// let entry_value_uninit = entry_handle_mut.loan_slice_uninit(4)?;
+    /// let entry_value = entry_value_uninit.write_from_fn(|i| i as i32 + 10);
+    /// # entry_value.update();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_fn<F: FnMut(usize) -> ValueType>(
+        mut self,
+        mut initializer: F,
+    ) -> SliceEntryValue<Service, KeyType, ValueType> {
+        for (i, element) in self.payload_mut().iter_mut().enumerate() {
+            element.write(initializer(i));
+        }
+
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Consumes the [`SliceEntryValueUninit`], copies `value` into it and returns the
+    /// initialized [`SliceEntryValue`]. Panics when the length of `value` differs from the
+    /// loaned slice length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<i32>(1, 8, &[1, 2, 3])
+    /// #     .create()?;
+    ///
+    /// # let writer = service.writer_builder().create()?;
+    /// # let entry_handle_mut = writer.slice_entry::<i32>(&1)?;
+    /// let entry_value_uninit = entry_handle_mut.loan_slice_uninit(2)?;
+    /// let entry_value = entry_value_uninit.write_from_slice(&[-1, -2]);
+    /// # entry_value.update();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_slice(
+        mut self,
+        value: &[ValueType],
+    ) -> SliceEntryValue<Service, KeyType, ValueType> {
+        self.payload_mut().copy_from_slice(unsafe {
+            core::mem::transmute::<&[ValueType], &[MaybeUninit<ValueType>]>(value)
+        });
+
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Consumes the [`SliceEntryValueUninit`] and returns the initialized [`SliceEntryValue`].
+    ///
+    /// # Safety
+    ///
+    ///   * every element of [`SliceEntryValueUninit::payload_mut()`] must have been initialized
+    pub unsafe fn assume_init(self) -> SliceEntryValue<Service, KeyType, ValueType> {
+        SliceEntryValue { entry_value: self }
+    }
+
+    /// Discards the [`SliceEntryValueUninit`] and returns the original [`SliceEntryHandleMut`].
+    pub fn discard(self) -> SliceEntryHandleMut<Service, KeyType, ValueType> {
+        self.entry_handle_mut
+    }
+}
+
+/// Wrapper around an initialized slice entry value that can be used for a zero-copy update.
+pub struct SliceEntryValue<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+    ValueType: Copy + 'static,
+> {
+    entry_value: SliceEntryValueUninit<Service, KeyType, ValueType>,
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + Hash + ZeroCopySend,
+        ValueType: Copy + 'static,
+    > SliceEntryValue<Service, KeyType, ValueType>
+{
+    /// Returns a reference to the initialized slice.
+    pub fn payload(&self) -> &[ValueType] {
+        unsafe {
+            core::slice::from_raw_parts(
+                self.entry_value
+                    .entry_handle_mut
+                    .elements_of(self.entry_value.write_cell),
+                self.entry_value.slice_len,
+            )
+        }
+    }
+
+    /// Returns a mutable reference to the initialized slice.
+    pub fn payload_mut(&mut self) -> &mut [ValueType] {
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                self.entry_value
+                    .entry_handle_mut
+                    .elements_of(self.entry_value.write_cell),
+                self.entry_value.slice_len,
+            )
+        }
+    }
+
+    /// Makes the new slice readable for [`Reader`](crate::port::reader::Reader)s, consumes the
+    /// [`SliceEntryValue`] and returns the original [`SliceEntryHandleMut`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .blackboard_creator::<u64>()
+    /// #     .add_slice::<i32>(1, 8, &[1, 2, 3])
+    /// #     .create()?;
+    ///
+    /// # let writer = service.writer_builder().create()?;
+    /// # let entry_handle_mut = writer.slice_entry::<i32>(&1)?;
+    /// let entry_value_uninit = entry_handle_mut.loan_slice_uninit(2)?;
+    /// let entry_value = entry_value_uninit.write_from_slice(&[-1, -2]);
+    /// let entry_handle_mut = entry_value.update();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(self) -> SliceEntryHandleMut<Service, KeyType, ValueType> {
+        let entry_value = self.entry_value;
+        entry_value
+            .entry_handle_mut
+            .publish(entry_value.write_cell, entry_value.slice_len);
+        entry_value.entry_handle_mut
+    }
+
+    /// Discards the [`SliceEntryValue`] and returns the original [`SliceEntryHandleMut`].
+    pub fn discard(self) -> SliceEntryHandleMut<Service, KeyType, ValueType> {
+        self.entry_value.entry_handle_mut
+    }
+}
+
 // TODO [#817] replace u64 with CustomKeyMarker
 impl<Service: service::Service> Writer<Service, u64> {
+    #[doc(hidden)]
+    pub fn __internal_insert(
+        &self,
+        internals: BuilderInternals<u64>,
+    ) -> Result<(), EntryInsertError> {
+        self.insert_impl(internals)
+    }
+
     #[doc(hidden)]
     pub fn __internal_entry(
         &self,
@@ -586,7 +1245,8 @@ impl<Service: service::Service> Writer<Service, u64> {
         type_details: &TypeDetail,
     ) -> Result<__InternalEntryHandleMut<Service>, EntryHandleMutError> {
         let msg = "Unable to create entry handle";
-        let offset = self.get_entry_offset(key, type_details, msg)?;
+        let entry = self.acquire_entry(key, type_details, msg)?;
+        let offset = entry.offset();
 
         let atomic_mgmt_ptr = (self
             .shared_state
@@ -602,13 +1262,18 @@ impl<Service: service::Service> Writer<Service, u64> {
         match __InternalEntryHandleMut::new(
             atomic_mgmt_ptr,
             data_ptr as *mut u8,
-            EventId::new(offset as _),
+            entry.entry_id(),
+            entry,
             self.shared_state.clone(),
         ) {
             Ok(handle) => Ok(handle),
+            Err(EntryHandleMutError::HandleAlreadyExists) => {
+                fail!(from self, with EntryHandleMutError::HandleAlreadyExists,
+                    "{} since a handle for the passed key and value type already exists.", msg);
+            }
             Err(e) => {
                 fail!(from self, with e,
-                    "{} since a handle for the passed key and value type already exists.", msg);
+                    "{} since the update notifications of the entry could not be set up.", msg);
             }
         }
     }
@@ -617,10 +1282,13 @@ impl<Service: service::Service> Writer<Service, u64> {
 /// A handle for direct write access to a specific blackboard value. Used for the language bindings
 /// where key and value type cannot be passed as generic.
 #[doc(hidden)]
+#[derive(Debug)]
 pub struct __InternalEntryHandleMut<Service: service::Service> {
     atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
     data_ptr: *mut u8,
     entry_id: EventId,
+    notifier: SignalNotifier<Service>,
+    _entry: EntryReference<Service, u64>,
     _shared_state: Arc<WriterSharedState<Service, u64>>,
 }
 
@@ -635,17 +1303,29 @@ impl<Service: service::Service> __InternalEntryHandleMut<Service> {
         atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt,
         data_ptr: *mut u8,
         entry_id: EventId,
+        entry: EntryReference<Service, u64>,
         writer_state: Arc<WriterSharedState<Service, u64>>,
     ) -> Result<Self, EntryHandleMutError> {
-        match unsafe { (*atomic_mgmt_ptr).__internal_acquire_producer() } {
-            Ok(_) => Ok(Self {
-                atomic_mgmt_ptr,
-                data_ptr,
-                entry_id,
-                _shared_state: writer_state.clone(),
-            }),
-            Err(_) => Err(EntryHandleMutError::HandleAlreadyExists),
+        if unsafe { (*atomic_mgmt_ptr).__internal_acquire_producer() }.is_err() {
+            return Err(EntryHandleMutError::HandleAlreadyExists);
         }
+
+        let notifier = match entry.create_notifier() {
+            Ok(notifier) => notifier,
+            Err(e) => {
+                unsafe { (*atomic_mgmt_ptr).__internal_release_producer() };
+                return Err(e);
+            }
+        };
+
+        Ok(Self {
+            atomic_mgmt_ptr,
+            data_ptr,
+            entry_id,
+            notifier,
+            _entry: entry,
+            _shared_state: writer_state.clone(),
+        })
     }
 
     /// Consumes the [`__InternalEntryHandleMut`] and loans an uninitialized entry value that can be
@@ -693,6 +1373,7 @@ impl<Service: service::Service> __InternalEntryHandleMut<Service> {
     ///   __internal_get_ptr_to_write_cell
     pub unsafe fn __internal_update_write_cell(&self) {
         unsafe { (*self.atomic_mgmt_ptr).__internal_update_write_cell() };
+        self.notifier.notify();
     }
 }
 
@@ -735,6 +1416,7 @@ impl<Service: service::Service> __InternalEntryValueUninit<Service> {
         unsafe {
             (*self.entry_handle_mut.atomic_mgmt_ptr).__internal_update_write_cell();
         }
+        self.entry_handle_mut.notifier.notify();
         self.entry_handle_mut
     }
 
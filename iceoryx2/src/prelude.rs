@@ -12,7 +12,11 @@
 
 pub use crate::config::Config;
 pub use crate::node::{node_name::NodeName, Node, NodeBuilder, NodeState};
-pub use crate::port::{event_id::EventId, unable_to_deliver_strategy::UnableToDeliverStrategy};
+pub use crate::port::{
+    distribution_policy::DistributionPolicy, event_id::EventId,
+    request_delivery_policy::RequestDeliveryPolicy,
+    unable_to_deliver_strategy::UnableToDeliverStrategy,
+};
 pub use crate::service::messaging_pattern::MessagingPattern;
 pub use crate::service::{
     attribute::AttributeSet, attribute::AttributeSpecifier, attribute::AttributeVerifier, ipc,
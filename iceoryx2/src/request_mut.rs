@@ -39,6 +39,7 @@ use core::{fmt::Debug, marker::PhantomData};
 use core::{
     ops::{Deref, DerefMut},
     sync::atomic::Ordering,
+    time::Duration,
 };
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fatal_panic;
@@ -210,9 +211,11 @@ impl<
         self.ptr.as_payload_mut()
     }
 
-    /// Sends the [`RequestMut`] to all connected
+    /// Sends the [`RequestMut`] to the connected
     /// [`Server`](crate::port::server::Server)s of the
-    /// [`Service`](crate::service::Service).
+    /// [`Service`](crate::service::Service) that are selected by the
+    /// [`RequestDeliveryPolicy`](crate::port::request_delivery_policy::RequestDeliveryPolicy)
+    /// of the [`Client`](crate::port::client::Client).
     pub fn send(
         self,
     ) -> Result<
@@ -245,4 +248,20 @@ impl<
             Err(e) => Err(e),
         }
     }
+
+    /// Sends the [`RequestMut`] like [`RequestMut::send()`] and attaches a deadline that is
+    /// reached when the provided `timeout` has passed. The
+    /// [`Server`](crate::port::server::Server) can read the deadline from the
+    /// [`ActiveRequest`](crate::active_request::ActiveRequest) to skip requests that are
+    /// already stale and the [`PendingResponse`] reports when the deadline was missed.
+    pub fn send_with_deadline(
+        mut self,
+        timeout: Duration,
+    ) -> Result<
+        PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
+        RequestSendError,
+    > {
+        self.ptr.as_header_mut().set_deadline(timeout);
+        self.send()
+    }
 }
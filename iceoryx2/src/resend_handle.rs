@@ -0,0 +1,145 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! #
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .publish_subscribe::<u64>()
+//! #     .enable_safe_overflow(false)
+//! #     .open_or_create()?;
+//! #
+//! let publisher = service
+//!     .publisher_builder()
+//!     .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+//!     .max_resend_handles(1)
+//!     .create()?;
+//!
+//! let sample = publisher.loan_uninit()?.write_payload(1234);
+//! let mut resend_handle = sample.send_with_resend_handle()?;
+//!
+//! for subscriber_id in resend_handle.undelivered_subscribers() {
+//!     println!("subscriber {:?} did not receive the sample", subscriber_id);
+//! }
+//!
+//! // later, try to deliver the sample again to the subscribers that did not receive it
+//! if !resend_handle.is_delivered_to_all() {
+//!     resend_handle.resend()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_cal::shared_memory::PointerOffset;
+
+use crate::port::port_identifiers::UniqueSubscriberId;
+use crate::port::publisher::PublisherSharedState;
+use crate::port::SendError;
+
+/// Acquired with
+/// [`SampleMut::send_with_resend_handle()`](crate::sample_mut::SampleMut::send_with_resend_handle()).
+/// It contains all [`Subscriber`](crate::port::subscriber::Subscriber)s that did not receive the
+/// sent sample, for instance because their buffer was full and the
+/// [`Publisher`](crate::port::publisher::Publisher) uses
+/// [`UnableToDeliverStrategy::DiscardSample`](crate::prelude::UnableToDeliverStrategy::DiscardSample).
+/// The sample can be delivered to exactly those
+/// [`Subscriber`](crate::port::subscriber::Subscriber)s with [`ResendHandle::resend()`] without
+/// copying the payload again.
+///
+/// The [`ResendHandle`] keeps the memory of the sample alive. It is released back to the
+/// [`Publisher`](crate::port::publisher::Publisher) when the [`ResendHandle`] goes out of scope.
+/// How many [`ResendHandle`]s can exist in parallel is defined with
+/// [`PortFactoryPublisher::max_resend_handles()`](crate::service::port_factory::publisher::PortFactoryPublisher::max_resend_handles()).
+pub struct ResendHandle<Service: crate::service::Service> {
+    pub(crate) publisher_shared_state:
+        Service::ArcThreadSafetyPolicy<PublisherSharedState<Service>>,
+    pub(crate) offset_to_chunk: PointerOffset,
+    pub(crate) sample_size: usize,
+    pub(crate) undelivered_subscribers: Vec<UniqueSubscriberId>,
+    pub(crate) number_of_recipients: usize,
+}
+
+unsafe impl<Service: crate::service::Service> Send for ResendHandle<Service> where
+    Service::ArcThreadSafetyPolicy<PublisherSharedState<Service>>: Send + Sync
+{
+}
+
+impl<Service: crate::service::Service> Debug for ResendHandle<Service> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ResendHandle<{}> {{ offset_to_chunk: {:?}, sample_size: {}, undelivered_subscribers: {:?}, number_of_recipients: {} }}",
+            core::any::type_name::<Service>(),
+            self.offset_to_chunk,
+            self.sample_size,
+            self.undelivered_subscribers,
+            self.number_of_recipients
+        )
+    }
+}
+
+impl<Service: crate::service::Service> Drop for ResendHandle<Service> {
+    fn drop(&mut self) {
+        let publisher_shared_state = self.publisher_shared_state.lock();
+        publisher_shared_state
+            .sender
+            .release_sample(self.offset_to_chunk);
+        publisher_shared_state.release_resend_handle();
+    }
+}
+
+impl<Service: crate::service::Service> ResendHandle<Service> {
+    /// Returns the [`UniqueSubscriberId`]s of all
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s that did not yet receive the sample.
+    pub fn undelivered_subscribers(&self) -> &[UniqueSubscriberId] {
+        &self.undelivered_subscribers
+    }
+
+    /// Returns true when every [`Subscriber`](crate::port::subscriber::Subscriber) that was
+    /// connected when the sample was sent has received it.
+    pub fn is_delivered_to_all(&self) -> bool {
+        self.undelivered_subscribers.is_empty()
+    }
+
+    /// Returns the total number of [`Subscriber`](crate::port::subscriber::Subscriber)s that
+    /// received the sample, either when it was sent or resent.
+    pub fn number_of_recipients(&self) -> usize {
+        self.number_of_recipients
+    }
+
+    /// Tries to deliver the sample to all
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s returned by
+    /// [`ResendHandle::undelivered_subscribers()`]. Every
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) that received the sample or that is
+    /// no longer connected is removed from the list. On success, it returns the number of
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s that received the sample with this
+    /// call, otherwise a [`SendError`].
+    pub fn resend(&mut self) -> Result<usize, SendError> {
+        let number_of_recipients = self.publisher_shared_state.lock().resend_sample(
+            self.offset_to_chunk,
+            self.sample_size,
+            &mut self.undelivered_subscribers,
+        )?;
+
+        self.number_of_recipients += number_of_recipients;
+        Ok(number_of_recipients)
+    }
+}
@@ -344,6 +344,15 @@ impl<
                 self.channel_id,
                 self.connection_id,
             )?;
+
+            if let Some(response_notifiers) = &shared_state.response_notifiers {
+                if let Some(client_id) = shared_state
+                    .response_sender
+                    .receiver_port_id_of(self.connection_id)
+                {
+                    response_notifiers.notify(&shared_state.service_state, client_id);
+                }
+            }
         }
 
         Ok(())
@@ -24,13 +24,14 @@
 //! while let Some(sample) = subscriber.receive()? {
 //!     println!("received: {:?}", *sample);
 //!     println!("header publisher id {:?}", sample.header().publisher_id());
+//!     println!("sequence number {}", sample.sequence_number());
 //! }
 //!
 //! # Ok(())
 //! # }
 //! ```
 
-use core::{fmt::Debug, ops::Deref};
+use core::{fmt::Debug, ops::Deref, time::Duration};
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
@@ -137,4 +138,16 @@ impl<
     pub fn origin(&self) -> UniquePublisherId {
         UniquePublisherId(UniqueSystemId::from(self.details.origin))
     }
+
+    /// Returns the sequence number the [`Publisher`](crate::port::publisher::Publisher)
+    /// assigned to the [`Sample`], see [`Header::sequence_number()`].
+    pub fn sequence_number(&self) -> u64 {
+        self.header().sequence_number()
+    }
+
+    /// Returns the point in time when the [`Sample`] was sent, see
+    /// [`Header::send_timestamp()`].
+    pub fn send_timestamp(&self) -> Duration {
+        self.header().send_timestamp()
+    }
 }
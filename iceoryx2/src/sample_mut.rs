@@ -64,10 +64,12 @@
 //! ```
 
 use crate::{
-    port::publisher::PublisherSharedState, port::SendError, raw_sample::RawSampleMut,
+    port::port_identifiers::UniqueSubscriberId, port::publisher::PublisherSharedState,
+    port::SendError, raw_sample::RawSampleMut, resend_handle::ResendHandle,
     service::header::publish_subscribe::Header,
 };
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::shared_memory::*;
 
@@ -331,9 +333,80 @@ impl<
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send(self) -> Result<usize, SendError> {
-        self.publisher_shared_state
-            .lock()
-            .send_sample(self.offset_to_chunk, self.sample_size)
+    pub fn send(mut self) -> Result<usize, SendError> {
+        self.publisher_shared_state.lock().send_sample(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            self.sample_size,
+        )
+    }
+
+    /// Send a previously loaned [`crate::port::publisher::Publisher::loan_uninit()`] or
+    /// [`crate::port::publisher::Publisher::loan()`] [`SampleMut`] to all connected
+    /// [`crate::port::subscriber::Subscriber`]s of the service.
+    ///
+    /// In contrast to [`SampleMut::send()`], it returns a [`ResendHandle`] that contains all
+    /// [`crate::port::subscriber::Subscriber`]s that did not receive the sample and that can be
+    /// used to deliver the sample to them later without copying the payload again.
+    /// The [`Publisher`](crate::port::publisher::Publisher) must be created with
+    /// [`PortFactoryPublisher::max_resend_handles()`](crate::service::port_factory::publisher::PortFactoryPublisher::max_resend_handles())
+    /// otherwise [`SendError::ExceedsMaxResendHandles`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().max_resend_handles(1).create()?;
+    ///
+    /// let sample = publisher.loan()?;
+    /// let resend_handle = sample.send_with_resend_handle()?;
+    /// println!("undelivered: {:?}", resend_handle.undelivered_subscribers());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_with_resend_handle(mut self) -> Result<ResendHandle<Service>, SendError> {
+        let mut undelivered_subscribers = alloc::vec::Vec::new();
+        let number_of_recipients = {
+            let publisher_shared_state = self.publisher_shared_state.lock();
+            publisher_shared_state.acquire_resend_handle()?;
+            let number_of_recipients = match publisher_shared_state
+                .send_sample_and_track_undelivered(
+                    self.ptr.as_header_mut(),
+                    self.offset_to_chunk,
+                    self.sample_size,
+                    |subscriber_id| {
+                        undelivered_subscribers
+                            .push(UniqueSubscriberId(UniqueSystemId::from(subscriber_id)))
+                    },
+                ) {
+                Ok(number_of_recipients) => number_of_recipients,
+                Err(e) => {
+                    publisher_shared_state.release_resend_handle();
+                    return Err(e);
+                }
+            };
+
+            // the resend handle owns a reference to the sample so that it stays alive when
+            // this sample goes out of scope
+            publisher_shared_state
+                .sender
+                .borrow_sample(self.offset_to_chunk);
+            number_of_recipients
+        };
+
+        Ok(ResendHandle {
+            publisher_shared_state: self.publisher_shared_state.clone(),
+            offset_to_chunk: self.offset_to_chunk,
+            sample_size: self.sample_size,
+            undelivered_subscribers,
+            number_of_recipients,
+        })
     }
 }
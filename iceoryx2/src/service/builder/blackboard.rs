@@ -15,30 +15,47 @@
 //! See [`crate::service`]
 //!
 use self::attribute::{AttributeSpecifier, AttributeVerifier};
+use super::event::EventOpenOrCreateError;
 use super::{OpenDynamicStorageFailure, ServiceState};
+use crate::config::Config;
+use crate::node::{NodeId, NodeState, SharedNode};
+use crate::port::details::signal::{SignalListener, SignalNotifier};
+use crate::port::event_id::EventId;
+use crate::port::listener::ListenerCreateError;
+use crate::port::reader::EntryHandleError;
+use crate::port::writer::{EntryHandleMutError, EntryInsertError};
 use crate::service;
 use crate::service::config_scheme::{blackboard_data_config, blackboard_mgmt_config};
 use crate::service::dynamic_config::blackboard::DynamicConfigSettings;
 use crate::service::dynamic_config::MessagingPatternSettings;
-use crate::service::naming_scheme::blackboard_name;
+use crate::service::naming_scheme::{blackboard_entry_event_name, blackboard_name};
 use crate::service::port_factory::blackboard;
 use crate::service::static_config::message_type_details::TypeDetail;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
 use builder::RETRY_LIMIT;
 use core::alloc::Layout;
+use core::cell::UnsafeCell;
 use core::hash::Hash;
-use iceoryx2_bb_container::flatmap::RelocatableFlatMap;
+use core::mem::MaybeUninit;
+use core::sync::atomic::Ordering;
 use iceoryx2_bb_container::queue::RelocatableContainer;
 use iceoryx2_bb_container::vec::RelocatableVec;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary::math::{align, max};
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
-use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::UnrestrictedAtomic;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::{
+    UnrestrictedAtomic, UnrestrictedAtomicMgmt,
+};
 use iceoryx2_bb_log::{error, fatal_panic};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::adaptive_wait::{AdaptiveWait, AdaptiveWaitBuilder};
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryBuilder};
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8, IoxAtomicUsize};
+
+extern crate alloc;
+use alloc::sync::Arc;
 
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 enum ServiceAvailabilityState {
@@ -122,7 +139,8 @@ pub enum BlackboardCreateError {
     /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     HangsInCreation,
-    /// No key-value pairs have been provided. At least one is required.
+    /// No key-value pairs have been provided and no entries have been reserved. At least one is
+    /// required.
     NoEntriesProvided,
 }
 
@@ -162,6 +180,7 @@ pub struct BuilderInternals<KeyType> {
     internal_value_size: usize,
     internal_value_alignment: usize,
     internal_value_cleanup_callback: Box<dyn FnMut()>,
+    max_slice_len: usize,
 }
 
 impl<KeyType> Debug for BuilderInternals<KeyType> {
@@ -192,22 +211,666 @@ impl<KeyType> BuilderInternals<KeyType> {
             internal_value_size: value_size,
             internal_value_alignment: value_alignment,
             internal_value_cleanup_callback: vale_cleanup_callback,
+            max_slice_len: 1,
+        }
+    }
+}
+
+impl<KeyType: Debug> BuilderInternals<KeyType> {
+    pub(crate) fn key(&self) -> &KeyType {
+        &self.key
+    }
+
+    pub(crate) fn fixed_size<ValueType: ZeroCopySend + Copy + 'static>(
+        key: KeyType,
+        value: ValueType,
+    ) -> Self {
+        Self {
+            key,
+            value_type_details: TypeDetail::new::<ValueType>(
+                message_type_details::TypeVariant::FixedSize,
+            ),
+            value_writer: Box::new(move |mem: *mut u8| {
+                let mem: *mut UnrestrictedAtomic<ValueType> =
+                    mem as *mut UnrestrictedAtomic<ValueType>;
+                unsafe { mem.write(UnrestrictedAtomic::<ValueType>::new(value)) };
+            }),
+            internal_value_size: core::mem::size_of::<UnrestrictedAtomic<ValueType>>(),
+            internal_value_alignment: core::mem::align_of::<UnrestrictedAtomic<ValueType>>(),
+            internal_value_cleanup_callback: Box::new(|| {}),
+            max_slice_len: 1,
+        }
+    }
+
+    pub(crate) fn slice<ValueType: ZeroCopySend + Copy + 'static>(
+        key: KeyType,
+        max_slice_len: usize,
+        value: &[ValueType],
+    ) -> Self {
+        let origin = "BuilderInternals::slice()";
+        let mut max_slice_len = max_slice_len;
+        if max_slice_len < value.len() {
+            warn!(from origin,
+                "The initial value of the slice entry {:?} has {} elements but the maximum slice length is {}. Adjust the maximum slice length to {}.",
+                key, value.len(), max_slice_len, value.len());
+            max_slice_len = value.len();
+        }
+        if max_slice_len == 0 {
+            warn!(from origin,
+                "Setting the maximum slice length of the entry {:?} to 0 is not supported. Adjust it to 1, the smallest supported value.",
+                key);
+            max_slice_len = 1;
+        }
+
+        let layout = SliceEntryLayout::new::<ValueType>(max_slice_len);
+        let value = value.to_vec();
+        Self {
+            key,
+            value_type_details: TypeDetail::new::<ValueType>(
+                message_type_details::TypeVariant::Dynamic,
+            ),
+            value_writer: Box::new(move |mem: *mut u8| {
+                let atomic_mgmt_ptr = mem as *mut UnrestrictedAtomicMgmt;
+                unsafe { atomic_mgmt_ptr.write(UnrestrictedAtomicMgmt::new()) };
+                // the initial value is stored in the first data cell which is the one readers
+                // access until the first update
+                let cell_ptr = layout.data_ptr(atomic_mgmt_ptr);
+                unsafe {
+                    (cell_ptr as *mut u64).write(value.len() as u64);
+                    core::ptr::copy_nonoverlapping(
+                        value.as_ptr(),
+                        cell_ptr.add(layout.header_size) as *mut ValueType,
+                        value.len(),
+                    );
+                }
+            }),
+            internal_value_size: layout.entry_size(),
+            internal_value_alignment: layout.entry_alignment(),
+            internal_value_cleanup_callback: Box::new(|| {}),
+            max_slice_len,
         }
     }
 }
 
+/// Memory layout of a slice entry. The entry consists of an [`UnrestrictedAtomicMgmt`] followed
+/// by its data cells where every cell starts with the current slice length as `u64` followed by
+/// `max_slice_len` elements.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SliceEntryLayout {
+    pub(crate) header_size: usize,
+    pub(crate) cell_size: usize,
+    pub(crate) cell_alignment: usize,
+}
+
+impl SliceEntryLayout {
+    pub(crate) fn new<ValueType>(max_slice_len: usize) -> Self {
+        let cell_alignment = max(
+            core::mem::align_of::<u64>(),
+            core::mem::align_of::<ValueType>(),
+        );
+        let header_size = align(
+            core::mem::size_of::<u64>(),
+            core::mem::align_of::<ValueType>(),
+        );
+        let cell_size = align(
+            header_size + core::mem::size_of::<ValueType>() * max_slice_len,
+            cell_alignment,
+        );
+
+        Self {
+            header_size,
+            cell_size,
+            cell_alignment,
+        }
+    }
+
+    pub(crate) fn entry_size(&self) -> usize {
+        UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_size(
+            self.cell_size,
+            self.cell_alignment,
+        )
+    }
+
+    pub(crate) fn entry_alignment(&self) -> usize {
+        UnrestrictedAtomicMgmt::__internal_get_unrestricted_atomic_alignment(self.cell_alignment)
+    }
+
+    /// Returns the start of the data cells of the entry located at `atomic_mgmt_ptr`.
+    pub(crate) fn data_ptr(&self, atomic_mgmt_ptr: *const UnrestrictedAtomicMgmt) -> *mut u8 {
+        align(
+            atomic_mgmt_ptr as usize + core::mem::size_of::<UnrestrictedAtomicMgmt>(),
+            self.cell_alignment,
+        ) as *mut u8
+    }
+}
+
+const ENTRY_IS_FREE: u8 = 0;
+const ENTRY_IS_ACTIVE: u8 = 1;
+const ENTRY_IS_REMOVED: u8 = 2;
+
+// number of waits after which the liveness of the node that modifies the key set is checked
+const DEAD_KEY_SET_OWNER_CHECK_INTERVAL: u64 = 128;
+
+/// A slot of the blackboard management segment. The key and the type details are only modified
+/// by the [`Writer`](crate::port::writer::Writer) while [`Mgmt::key_set_generation`] is odd and
+/// are read under the protection of the generation.
 #[repr(C)]
 #[derive(Debug, ZeroCopySend)]
-pub(crate) struct Entry {
-    pub(crate) type_details: TypeDetail,
-    pub(crate) offset: IoxAtomicU64,
+pub(crate) struct Entry<KeyType: ZeroCopySend> {
+    state: IoxAtomicU8,
+    number_of_handles: IoxAtomicU64,
+    // incremented whenever the slot is reused for a new key so that the handles of the new key
+    // do not share the event service with the ones of the removed key
+    generation: IoxAtomicU64,
+    key: UnsafeCell<MaybeUninit<KeyType>>,
+    type_details: UnsafeCell<TypeDetail>,
+    offset: IoxAtomicU64,
+    max_slice_len: IoxAtomicUsize,
+    size: IoxAtomicUsize,
+}
+
+impl<KeyType: ZeroCopySend> Entry<KeyType> {
+    fn new() -> Self {
+        Self {
+            state: IoxAtomicU8::new(ENTRY_IS_FREE),
+            number_of_handles: IoxAtomicU64::new(0),
+            generation: IoxAtomicU64::new(0),
+            key: UnsafeCell::new(MaybeUninit::uninit()),
+            type_details: UnsafeCell::new(TypeDetail::default()),
+            offset: IoxAtomicU64::new(0),
+            max_slice_len: IoxAtomicUsize::new(0),
+            size: IoxAtomicUsize::new(0),
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.state.load(Ordering::Relaxed) == ENTRY_IS_ACTIVE
+    }
+
+    fn is_unreferenced(&self) -> bool {
+        self.state.load(Ordering::Relaxed) == ENTRY_IS_REMOVED
+            && self.number_of_handles.load(Ordering::SeqCst) == 0
+    }
+
+    // only valid when the entry is active
+    unsafe fn key(&self) -> &KeyType {
+        unsafe { (*self.key.get()).assume_init_ref() }
+    }
+
+    unsafe fn type_details(&self) -> &TypeDetail {
+        unsafe { &*self.type_details.get() }
+    }
 }
 
+// Safe since the non-atomic members are only modified by the single writer while the key set
+// generation signals an ongoing modification, see Mgmt.
+unsafe impl<KeyType: ZeroCopySend + Send> Send for Entry<KeyType> {}
+unsafe impl<KeyType: ZeroCopySend + Sync> Sync for Entry<KeyType> {}
+
 #[repr(C)]
 #[derive(Debug, ZeroCopySend)]
 pub(crate) struct Mgmt<KeyType: Eq + Clone + Debug + ZeroCopySend> {
-    pub(crate) map: RelocatableFlatMap<KeyType, usize>,
-    pub(crate) entries: RelocatableVec<Entry>,
+    // is odd while the key set is modified, works as a sequence lock for the readers
+    key_set_generation: IoxAtomicU64,
+    // the node id of the writer that modifies or last modified the key set, split into the
+    // upper and lower 64 bits, required to recover from a writer that died during the
+    // modification
+    key_set_owner_high: IoxAtomicU64,
+    key_set_owner_low: IoxAtomicU64,
+    entries: RelocatableVec<Entry<KeyType>>,
+}
+
+impl<KeyType: Eq + Clone + Debug + ZeroCopySend> Mgmt<KeyType> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            key_set_generation: IoxAtomicU64::new(0),
+            key_set_owner_high: IoxAtomicU64::new(0),
+            key_set_owner_low: IoxAtomicU64::new(0),
+            entries: unsafe { RelocatableVec::<Entry<KeyType>>::new_uninit(capacity) },
+        }
+    }
+
+    fn key_set_owner(&self) -> NodeId {
+        let high = self.key_set_owner_high.load(Ordering::Relaxed) as u128;
+        let low = self.key_set_owner_low.load(Ordering::Relaxed) as u128;
+        NodeId::from_value((high << 64) | low)
+    }
+
+    fn begin_key_set_modification<ServiceType: service::Service>(
+        &self,
+        node: &SharedNode<ServiceType>,
+    ) {
+        let mut adaptive_wait = None;
+        loop {
+            let generation = self.key_set_generation.load(Ordering::Acquire);
+            if generation % 2 == 1 {
+                self.wait_for_key_set_owner::<ServiceType>(
+                    generation,
+                    &mut adaptive_wait,
+                    node.config(),
+                );
+                continue;
+            }
+
+            // Every modification is performed by the single writer of the blackboard, so
+            // concurrent modifications store the same owner.
+            let owner = node.id().value();
+            self.key_set_owner_high
+                .store((owner >> 64) as u64, Ordering::Relaxed);
+            self.key_set_owner_low
+                .store(owner as u64, Ordering::Relaxed);
+
+            if self
+                .key_set_generation
+                .compare_exchange_weak(
+                    generation,
+                    generation + 1,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                )
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
+
+    fn end_key_set_modification(&self) {
+        self.key_set_generation.fetch_add(1, Ordering::Release);
+    }
+
+    // waits while the key set is modified and checks regularly whether the modifying node died
+    fn wait_for_key_set_owner<ServiceType: service::Service>(
+        &self,
+        generation: u64,
+        adaptive_wait: &mut Option<AdaptiveWait>,
+        config: &Config,
+    ) {
+        let adaptive_wait = adaptive_wait.get_or_insert_with(|| {
+            fatal_panic!(from self, when AdaptiveWaitBuilder::new().create(),
+                "Unable to wait for the key set modification since the adaptive wait could not be created.")
+        });
+        if let Err(e) = adaptive_wait.wait() {
+            warn!(from self, "The adaptive wait for the key set modification failed ({:?}).", e);
+        }
+
+        if adaptive_wait.yield_count() % DEAD_KEY_SET_OWNER_CHECK_INTERVAL == 0 {
+            self.recover_from_dead_key_set_owner::<ServiceType>(generation, config);
+        }
+    }
+
+    // A writer that died while it modified the key set leaves the generation odd. Since an
+    // entry becomes active only after it was fully initialized and a removal is a single store,
+    // the key set is consistent even then and only the modification has to be completed.
+    fn recover_from_dead_key_set_owner<ServiceType: service::Service>(
+        &self,
+        generation: u64,
+        config: &Config,
+    ) {
+        let owner = self.key_set_owner();
+        match NodeState::<ServiceType>::new(&owner, config) {
+            Ok(None) | Ok(Some(NodeState::Dead(_))) => (),
+            _ => return,
+        }
+
+        // fails when the modification was completed or a new one began in the meantime
+        if self
+            .key_set_generation
+            .compare_exchange(
+                generation,
+                generation + 1,
+                Ordering::SeqCst,
+                Ordering::Relaxed,
+            )
+            .is_ok()
+        {
+            warn!(from self,
+                "The writer of the node {:?} died while it modified the key set of the blackboard. Completed the modification.",
+                owner);
+        }
+    }
+
+    // calls `reader` until it was able to read the key set without a concurrent modification
+    fn read_key_set<ServiceType: service::Service, R, F: FnMut() -> R>(
+        &self,
+        config: &Config,
+        mut reader: F,
+    ) -> (R, u64) {
+        let mut adaptive_wait = None;
+        loop {
+            let generation = self.key_set_generation.load(Ordering::Acquire);
+            if generation % 2 == 1 {
+                self.wait_for_key_set_owner::<ServiceType>(generation, &mut adaptive_wait, config);
+                continue;
+            }
+
+            let result = reader();
+
+            core::sync::atomic::fence(Ordering::Acquire);
+            if self.key_set_generation.load(Ordering::Relaxed) == generation {
+                return (result, generation);
+            }
+        }
+    }
+
+    fn find_active(&self, key: &KeyType) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|e| e.is_active() && unsafe { e.key() } == key)
+    }
+
+    /// Returns the current generation of the key set. It changes whenever a key is inserted or
+    /// removed.
+    pub(crate) fn key_set_generation(&self) -> u64 {
+        self.key_set_generation.load(Ordering::Acquire) & !1
+    }
+
+    /// Returns the maximum number of entries the blackboard can hold.
+    pub(crate) fn capacity(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns a consistent copy of all keys and the generation of the key set they belong to.
+    pub(crate) fn keys<ServiceType: service::Service>(
+        &self,
+        config: &Config,
+    ) -> (Vec<KeyType>, u64) {
+        self.read_key_set::<ServiceType, _, _>(config, || {
+            self.entries
+                .iter()
+                .filter(|e| e.is_active())
+                .map(|e| unsafe { e.key() }.clone())
+                .collect()
+        })
+    }
+
+    /// Returns a consistent copy of all keys together with the type details of their values
+    /// and the generation of the key set they belong to.
+    pub(crate) fn entries<ServiceType: service::Service>(
+        &self,
+        config: &Config,
+    ) -> (Vec<(KeyType, TypeDetail)>, u64) {
+        self.read_key_set::<ServiceType, _, _>(config, || {
+            self.entries
+                .iter()
+                .filter(|e| e.is_active())
+                .map(|e| unsafe { (e.key().clone(), e.type_details().clone()) })
+                .collect()
+        })
+    }
+
+    fn acquire_entry<ServiceType: service::Service>(
+        &self,
+        config: &Config,
+        key: &KeyType,
+        type_details: &TypeDetail,
+    ) -> Option<usize> {
+        loop {
+            let (index, generation) = self.read_key_set::<ServiceType, _, _>(config, || {
+                self.find_active(key)
+                    .filter(|i| unsafe { self.entries[*i].type_details() } == type_details)
+            });
+            let entry = &self.entries[index?];
+
+            entry.number_of_handles.fetch_add(1, Ordering::SeqCst);
+            // the entry could have been removed and reused before the handle was registered
+            if self.key_set_generation.load(Ordering::SeqCst) == generation {
+                return index;
+            }
+            entry.number_of_handles.fetch_sub(1, Ordering::Release);
+        }
+    }
+
+    fn release_entry(&self, index: usize) {
+        self.entries[index]
+            .number_of_handles
+            .fetch_sub(1, Ordering::Release);
+    }
+
+    /// Adds a new entry and initializes its value. The memory of removed entries is reused when
+    /// no handle refers to them anymore and the value fits into it.
+    pub(crate) fn insert<
+        ServiceType: service::Service,
+        Payload: SharedMemory<iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator>,
+    >(
+        &self,
+        node: &SharedNode<ServiceType>,
+        payload: &Payload,
+        internals: &mut BuilderInternals<KeyType>,
+    ) -> Result<(), EntryInsertError> {
+        self.begin_key_set_modification(node);
+        let result = self.insert_impl(payload, internals);
+        self.end_key_set_modification();
+        result
+    }
+
+    fn insert_impl<
+        Payload: SharedMemory<iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator>,
+    >(
+        &self,
+        payload: &Payload,
+        internals: &mut BuilderInternals<KeyType>,
+    ) -> Result<(), EntryInsertError> {
+        let msg = "Unable to insert the entry into the blackboard";
+        if self.find_active(&internals.key).is_some() {
+            fail!(from self, with EntryInsertError::KeyAlreadyExists,
+                "{} since the key {:?} already exists.", msg, internals.key);
+        }
+
+        let size = internals.internal_value_size;
+        let alignment = internals.internal_value_alignment;
+        let fits = |e: &Entry<KeyType>| {
+            e.size.load(Ordering::Relaxed) >= size
+                && (payload.payload_start_address() as u64 + e.offset.load(Ordering::Relaxed))
+                    % alignment as u64
+                    == 0
+        };
+
+        let (index, offset, size) = match self
+            .entries
+            .iter()
+            .position(|e| e.is_unreferenced() && fits(e))
+        {
+            Some(index) => (
+                index,
+                self.entries[index].offset.load(Ordering::Relaxed),
+                self.entries[index].size.load(Ordering::Relaxed),
+            ),
+            None => {
+                let index = match self
+                    .entries
+                    .iter()
+                    .position(|e| e.state.load(Ordering::Relaxed) == ENTRY_IS_FREE)
+                    .or_else(|| self.entries.iter().position(|e| e.is_unreferenced()))
+                {
+                    Some(index) => index,
+                    None => {
+                        fail!(from self, with EntryInsertError::ExceedsMaxNumberOfEntries,
+                            "{} since the maximum number of entries of {} is exceeded.", msg, self.capacity());
+                    }
+                };
+
+                let mem = match payload
+                    .allocate(unsafe { Layout::from_size_align_unchecked(size, alignment) })
+                {
+                    Ok(mem) => mem,
+                    Err(_) => {
+                        fail!(from self, with EntryInsertError::OutOfMemory,
+                            "{} since the reserved blackboard payload memory is exhausted.", msg);
+                    }
+                };
+                (index, mem.offset.offset() as u64, size)
+            }
+        };
+
+        let entry = &self.entries[index];
+        unsafe {
+            (*entry.key.get()).write(internals.key.clone());
+            *entry.type_details.get() = internals.value_type_details.clone();
+        }
+        entry.offset.store(offset, Ordering::Relaxed);
+        entry.size.store(size, Ordering::Relaxed);
+        entry
+            .max_slice_len
+            .store(internals.max_slice_len, Ordering::Relaxed);
+        (*internals.value_writer)((payload.payload_start_address() as u64 + offset) as *mut u8);
+        entry.generation.fetch_add(1, Ordering::Relaxed);
+        entry.state.store(ENTRY_IS_ACTIVE, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    /// Removes the entry with the given key. Returns `false` when no such entry exists.
+    pub(crate) fn remove<ServiceType: service::Service>(
+        &self,
+        node: &SharedNode<ServiceType>,
+        key: &KeyType,
+    ) -> bool {
+        self.begin_key_set_modification(node);
+        let index = self.find_active(key);
+        if let Some(index) = index {
+            self.entries[index]
+                .state
+                .store(ENTRY_IS_REMOVED, Ordering::Relaxed);
+        }
+        self.end_key_set_modification();
+        index.is_some()
+    }
+}
+
+/// Registers a handle at an [`Entry`] of the blackboard. As long as it exists, the memory of the
+/// entry is not reused for other keys, even when the entry was removed.
+#[derive(Debug)]
+pub(crate) struct EntryReference<
+    ServiceType: service::Service,
+    KeyType: Send + Sync + Eq + Clone + Debug + 'static + ZeroCopySend,
+> {
+    index: usize,
+    generation: u64,
+    offset: u64,
+    max_slice_len: usize,
+    service_state:
+        Arc<service::ServiceState<ServiceType, BlackboardResources<ServiceType, KeyType>>>,
+}
+
+impl<
+        ServiceType: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + ZeroCopySend,
+    > EntryReference<ServiceType, KeyType>
+{
+    /// Returns [`None`] when no active entry with the given key and type details exists.
+    pub(crate) fn acquire(
+        service_state: &Arc<
+            service::ServiceState<ServiceType, BlackboardResources<ServiceType, KeyType>>,
+        >,
+        key: &KeyType,
+        type_details: &TypeDetail,
+    ) -> Option<Self> {
+        let mgmt = service_state.additional_resource.mgmt.get();
+        let index = mgmt.acquire_entry::<ServiceType>(
+            service_state.shared_node.config(),
+            key,
+            type_details,
+        )?;
+        let entry = &mgmt.entries[index];
+
+        Some(Self {
+            index,
+            generation: entry.generation.load(Ordering::Relaxed),
+            offset: entry.offset.load(Ordering::Relaxed),
+            max_slice_len: entry.max_slice_len.load(Ordering::Relaxed),
+            service_state: service_state.clone(),
+        })
+    }
+
+    /// The offset of the value relative to the start of the payload segment.
+    pub(crate) fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub(crate) fn max_slice_len(&self) -> usize {
+        self.max_slice_len
+    }
+
+    /// The id of the entry, corresponds to the index of the entry in the management segment.
+    pub(crate) fn entry_id(&self) -> EventId {
+        EventId::new(self.index)
+    }
+
+    /// Creates the [`SignalNotifier`] that signals every update of the entry to its
+    /// [`EntryHandle`](crate::port::reader::EntryHandle)s.
+    pub(crate) fn create_notifier(
+        &self,
+    ) -> Result<SignalNotifier<ServiceType>, EntryHandleMutError> {
+        let msg = "Unable to create the notifier for the entry updates";
+        let event_service = fail!(from self, when self.open_or_create_event_service(),
+            with EntryHandleMutError::ResourceCreationFailed,
+            "{} since the event service of the entry could not be opened or created.", msg);
+
+        let notifier = fail!(from self, when event_service.notifier_builder().default_event_id(self.entry_id()).create(),
+            with EntryHandleMutError::ResourceCreationFailed,
+            "{} since the underlying notifier could not be created.", msg);
+
+        Ok(SignalNotifier::new(notifier))
+    }
+
+    /// Creates the [`SignalListener`] that receives a notification on every update of the entry.
+    /// The number of listeners per entry is limited by the `max_listeners` setting of the event
+    /// defaults in the [`Config`](crate::config::Config).
+    pub(crate) fn create_listener(&self) -> Result<SignalListener<ServiceType>, EntryHandleError> {
+        let msg = "Unable to create the listener for the entry updates";
+        let event_service = fail!(from self, when self.open_or_create_event_service(),
+            with EntryHandleError::ResourceCreationFailed,
+            "{} since the event service of the entry could not be opened or created.", msg);
+
+        match event_service.listener_builder().create() {
+            Ok(listener) => Ok(SignalListener::new(listener)),
+            Err(ListenerCreateError::ExceedsMaxSupportedListeners) => {
+                fail!(from self, with EntryHandleError::ExceedsMaxSupportedListeners,
+                    "{} since it would exceed the maximum supported amount of entry listeners of {}.",
+                    msg, event_service.service.static_config.event().max_listeners());
+            }
+            Err(e) => {
+                fail!(from self, with EntryHandleError::ResourceCreationFailed,
+                    "{} since the underlying listener could not be created ({:?}).", msg, e);
+            }
+        }
+    }
+
+    fn open_or_create_event_service(
+        &self,
+    ) -> Result<crate::service::port_factory::event::PortFactory<ServiceType>, EventOpenOrCreateError>
+    {
+        builder::Builder::new(
+            &blackboard_entry_event_name(
+                self.service_state.static_config.service_id(),
+                self.index,
+                self.generation,
+            ),
+            self.service_state.shared_node.clone(),
+        )
+        .event()
+        .event_id_max_value(self.service_state.additional_resource.mgmt.get().capacity())
+        .disable_deadline()
+        .disable_notifier_created_event()
+        .disable_notifier_dropped_event()
+        .disable_notifier_dead_event()
+        .open_or_create()
+    }
+}
+
+impl<
+        ServiceType: service::Service,
+        KeyType: Send + Sync + Eq + Clone + Debug + 'static + ZeroCopySend,
+    > Drop for EntryReference<ServiceType, KeyType>
+{
+    fn drop(&mut self) {
+        self.service_state
+            .additional_resource
+            .mgmt
+            .get()
+            .release_entry(self.index);
+    }
 }
 
 #[derive(Debug)]
@@ -240,6 +903,8 @@ struct Builder<
     verify_max_nodes: bool,
     internals: Vec<BuilderInternals<KeyType>>,
     override_key_type: Option<TypeDetail>,
+    reserved_entries: usize,
+    reserved_payload_size: usize,
 }
 
 impl<
@@ -254,6 +919,8 @@ impl<
             verify_max_nodes: false,
             internals: Vec::<BuilderInternals<KeyType>>::new(),
             override_key_type: None,
+            reserved_entries: 0,
+            reserved_payload_size: 0,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::Blackboard(
@@ -328,6 +995,11 @@ impl<
         self.config_details_mut().max_nodes = value;
         self.verify_max_nodes = true;
     }
+
+    fn reserve(&mut self, number_of_entries: usize, value_size: usize, value_alignment: usize) {
+        self.reserved_entries += number_of_entries;
+        self.reserved_payload_size += number_of_entries * (value_size + value_alignment - 1);
+    }
 }
 
 /// Builder to create a new [`MessagingPattern::Blackboard`] based [`Service`]s
@@ -372,21 +1044,7 @@ impl<
         key: KeyType,
         value: ValueType,
     ) -> Self {
-        let internals = BuilderInternals {
-            key,
-            value_type_details: TypeDetail::new::<ValueType>(
-                message_type_details::TypeVariant::FixedSize,
-            ),
-            value_writer: Box::new(move |mem: *mut u8| {
-                let mem: *mut UnrestrictedAtomic<ValueType> =
-                    mem as *mut UnrestrictedAtomic<ValueType>;
-                unsafe { mem.write(UnrestrictedAtomic::<ValueType>::new(value)) };
-            }),
-            internal_value_size: core::mem::size_of::<UnrestrictedAtomic<ValueType>>(),
-            internal_value_alignment: core::mem::align_of::<UnrestrictedAtomic<ValueType>>(),
-            internal_value_cleanup_callback: Box::new(|| {}),
-        };
-        self.__internal_add(internals)
+        self.__internal_add(BuilderInternals::fixed_size(key, value))
     }
 
     #[doc(hidden)]
@@ -397,23 +1055,94 @@ impl<
 
     /// Adds key-value pairs to the blackboard where value is a default value.
     pub fn add_with_default<ValueType: ZeroCopySend + Copy + 'static + Default>(
-        mut self,
+        self,
         key: KeyType,
     ) -> Self {
-        self.builder.internals.push(BuilderInternals {
-            key,
-            value_type_details: TypeDetail::new::<ValueType>(
-                message_type_details::TypeVariant::FixedSize,
-            ),
-            value_writer: Box::new(move |mem: *mut u8| {
-                let mem: *mut UnrestrictedAtomic<ValueType> =
-                    mem as *mut UnrestrictedAtomic<ValueType>;
-                unsafe { mem.write(UnrestrictedAtomic::<ValueType>::new(ValueType::default())) };
-            }),
-            internal_value_size: core::mem::size_of::<UnrestrictedAtomic<ValueType>>(),
-            internal_value_alignment: core::mem::align_of::<UnrestrictedAtomic<ValueType>>(),
-            internal_value_cleanup_callback: Box::new(|| {}),
-        });
+        self.__internal_add(BuilderInternals::fixed_size(key, ValueType::default()))
+    }
+
+    /// Adds a key-value pair to the blackboard where the value is a slice of `ValueType` that can
+    /// hold at most `max_slice_len` elements. The entry is initialized with `value`, afterwards
+    /// the [`Writer`](crate::port::writer::Writer) can store slices of any length up to
+    /// `max_slice_len`.
+    ///
+    /// Like the values of [`Creator::add()`], the elements must be [`Copy`]. Readers take a
+    /// bitwise snapshot of the slice and retry when the writer updated it concurrently, so a
+    /// discarded or duplicated copy must never own resources. Strings can be stored as slices of
+    /// `u8` or as fixed size entries of
+    /// [`FixedSizeByteString`](iceoryx2_bb_container::byte_string::FixedSizeByteString).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .blackboard_creator::<u64>()
+    ///     .add_slice::<u8>(0, 256, b"initial configuration")
+    ///     .add_slice::<i32>(1, 16, &[1, 2, 3])
+    ///     .create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_slice<ValueType: ZeroCopySend + Copy + 'static>(
+        self,
+        key: KeyType,
+        max_slice_len: usize,
+        value: &[ValueType],
+    ) -> Self {
+        self.__internal_add(BuilderInternals::slice(key, max_slice_len, value))
+    }
+
+    /// Reserves capacity for `number_of_entries` additional entries of `ValueType` that can be
+    /// inserted with [`Writer::insert()`](crate::port::writer::Writer::insert()) after the
+    /// [`Service`] was created.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .blackboard_creator::<u64>()
+    ///     .add::<i32>(0, -1)
+    ///     .reserve::<u64>(4)
+    ///     .create()?;
+    ///
+    /// let writer = service.writer_builder().create()?;
+    /// writer.insert::<u64>(1, 123)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reserve<ValueType: ZeroCopySend + Copy + 'static>(
+        mut self,
+        number_of_entries: usize,
+    ) -> Self {
+        self.builder.reserve(
+            number_of_entries,
+            core::mem::size_of::<UnrestrictedAtomic<ValueType>>(),
+            core::mem::align_of::<UnrestrictedAtomic<ValueType>>(),
+        );
+        self
+    }
+
+    /// Reserves capacity for `number_of_entries` additional slice entries of `ValueType` with at
+    /// most `max_slice_len` elements that can be inserted with
+    /// [`Writer::insert_slice()`](crate::port::writer::Writer::insert_slice()) after the
+    /// [`Service`] was created. The elements must be [`Copy`], see [`Creator::add_slice()`].
+    pub fn reserve_slice<ValueType: ZeroCopySend + Copy + 'static>(
+        mut self,
+        number_of_entries: usize,
+        max_slice_len: usize,
+    ) -> Self {
+        let layout = SliceEntryLayout::new::<ValueType>(max_slice_len.max(1));
+        self.builder.reserve(
+            number_of_entries,
+            layout.entry_size(),
+            layout.entry_alignment(),
+        );
         self
     }
 
@@ -524,10 +1253,10 @@ impl<
                 let name = blackboard_name(self.builder.base.service_config.service_id().as_str());
                 let shm_config =
                     blackboard_data_config::<ServiceType>(self.builder.base.shared_node.config());
-                let mut payload_size = 0;
-                if self.builder.internals.is_empty() {
+                let mut payload_size = self.builder.reserved_payload_size;
+                if self.builder.internals.is_empty() && self.builder.reserved_entries == 0 {
                     fail!(from self,  with BlackboardCreateError::NoEntriesProvided,
-                        "{} without entries. At least one key-value pair is required.", msg);
+                        "{} without entries. At least one key-value pair or reserved entry is required.", msg);
                 }
                 for i in &self.builder.internals {
                     payload_size += i.internal_value_size + i.internal_value_alignment - 1;
@@ -551,7 +1280,7 @@ impl<
                 };
 
                 // create the management segment
-                let capacity = self.builder.internals.len();
+                let capacity = self.builder.internals.len() + self.builder.reserved_entries;
 
                 let mut mgmt_config = blackboard_mgmt_config::<ServiceType, Mgmt<KeyType>>(
                     self.builder.base.shared_node.config(),
@@ -583,37 +1312,26 @@ impl<
                     >>::Builder::new(&name)
                         .config(&mgmt_config)
                         .has_ownership(false)
-                        .supplementary_size(RelocatableFlatMap::<KeyType, usize>::const_memory_size(capacity)+RelocatableVec::<Entry>::const_memory_size(capacity))
-                        .initializer(|entry: &mut Mgmt<KeyType>, allocator: &mut BumpAllocator| {
-                            if unsafe {entry.map.init(allocator)}.is_err() || unsafe {entry.entries.init(allocator).is_err()} {
+                        .supplementary_size(RelocatableVec::<Entry<KeyType>>::const_memory_size(capacity))
+                        .initializer(|mgmt: &mut Mgmt<KeyType>, allocator: &mut BumpAllocator| {
+                            if unsafe { mgmt.entries.init(allocator) }.is_err() {
                                 return false
                             }
-                            for i in 0..capacity {
-                                // write value passed to add() to payload_shm
-                                let mem = match payload_shm.allocate(unsafe { Layout::from_size_align_unchecked(self.builder.internals[i].internal_value_size, self.builder.internals[i].internal_value_alignment) })
-                                {
-                                    Ok(m) => m,
-                                    Err(_) => {
-                                        error!(from self, "Writing the value to the blackboard data segment failed.");
-                                        return false
-                                    }
-                                };
-                                (*self.builder.internals[i].value_writer)(mem.data_ptr);
-                                // write offset to value in payload_shm to entries vector
-                                let res = unsafe {entry.entries.push(Entry{type_details: self.builder.internals[i].value_type_details.clone(), offset: IoxAtomicU64::new(mem.offset.offset() as u64)})};
-                                if !res {
-                                    error!(from self, "Writing the value offset to the blackboard management segment failed.");
+                            for _ in 0..capacity {
+                                if !unsafe { mgmt.entries.push(Entry::new()) } {
+                                    error!(from self, "Writing the entries to the blackboard management segment failed.");
                                     return false
                                 }
-                                // write offset index to map
-                                let res = unsafe {entry.map.insert(self.builder.internals[i].key.clone(), entry.entries.len() - 1)};
-                                if res.is_err() {
+                            }
+                            // write the values passed to add() to payload_shm
+                            for i in 0..self.builder.internals.len() {
+                                if mgmt.insert(&payload_shm, &mut self.builder.internals[i]).is_err() {
                                     error!(from self, "Inserting the key-value pair into the blackboard management segment failed.");
                                     return false
                                 }
                             }
                             true})
-                        .create(Mgmt{ map: unsafe { RelocatableFlatMap::<KeyType, usize>::new_uninit(capacity) }, entries: unsafe {RelocatableVec::<Entry>::new_uninit(capacity)}}),
+                        .create(Mgmt::new(capacity)),
                             with BlackboardCreateError::ServiceInCorruptedState, "{} since the blackboard management segment could not be created. This could indicate a corrupted system.",
                             msg);
 
@@ -651,6 +1369,18 @@ impl<ServiceType: service::Service> Creator<u64, ServiceType> {
         self.builder.override_key_type = Some(value.clone());
         self
     }
+
+    #[doc(hidden)]
+    pub fn __internal_reserve(
+        mut self,
+        number_of_entries: usize,
+        value_size: usize,
+        value_alignment: usize,
+    ) -> Self {
+        self.builder
+            .reserve(number_of_entries, value_size, value_alignment);
+        self
+    }
 }
 
 /// Builder to open a [`MessagingPattern::Blackboard`] based [`Service`]s
@@ -69,6 +69,9 @@ pub enum EventOpenError {
     DoesNotSupportRequestedMaxEventId,
     /// The [`Service`] supports less [`Node`](crate::node::Node)s than requested.
     DoesNotSupportRequestedAmountOfNodes,
+    /// The [`Service`] does not provide notification details or supports a smaller
+    /// notification payload than requested.
+    DoesNotSupportRequestedNotificationPayloadSize,
     /// The maximum number of [`Node`](crate::node::Node)s have already opened the [`Service`].
     ExceedsMaxNumberOfNodes,
     /// The [`Service`] is marked for destruction and currently cleaning up since no one is using it anymore.
@@ -190,6 +193,7 @@ pub struct Builder<ServiceType: service::Service> {
     verify_notifier_created_event: bool,
     verify_notifier_dropped_event: bool,
     verify_notifier_dead_event: bool,
+    verify_notification_payload_size: bool,
 }
 
 impl<ServiceType: service::Service> Builder<ServiceType> {
@@ -204,6 +208,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             verify_notifier_dead_event: false,
             verify_notifier_created_event: false,
             verify_notifier_dropped_event: false,
+            verify_notification_payload_size: false,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::Event(
@@ -325,6 +330,17 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// If the [`Service`] is created it enables notification details. Every notification then
+    /// carries the [`UniqueNotifierId`](crate::port::port_identifiers::UniqueNotifierId) of the
+    /// sender, how often it was triggered and a user payload of at most `value` bytes. If an
+    /// existing [`Service`] is opened it defines the payload size that must be at least
+    /// supported.
+    pub fn notification_payload_size(mut self, value: usize) -> Self {
+        self.config_details().notification_payload_size = Some(value);
+        self.verify_notification_payload_size = true;
+        self
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
     pub fn open_or_create(self) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
@@ -510,6 +526,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 let dynamic_config_setting = DynamicConfigSettings {
                     number_of_listeners: event_config.max_listeners,
                     number_of_notifiers: event_config.max_notifiers,
+                    notification_payload_size: event_config.notification_payload_size,
                 };
 
                 let dynamic_config = match self.base.create_dynamic_config_storage(
@@ -657,6 +674,15 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 msg, existing_settings.notifier_dead_event, required_settings.notifier_dead_event);
         }
 
+        if self.verify_notification_payload_size
+            && existing_settings.notification_payload_size
+                < required_settings.notification_payload_size
+        {
+            fail!(from self, with EventOpenError::DoesNotSupportRequestedNotificationPayloadSize,
+                "{} since the event supports a notification payload size of {:?} but {:?} is required.",
+                msg, existing_settings.notification_payload_size, required_settings.notification_payload_size);
+        }
+
         if self.verify_deadline
             && existing_settings.deadline.map(|v| v.value)
                 != required_settings.deadline.map(|v| v.value)
@@ -0,0 +1,655 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! See [`crate::service`]
+//!
+use crate::log_record::LogRecord;
+use crate::service::dynamic_config::log::DynamicConfigSettings;
+use crate::service::header::log::Header;
+use crate::service::port_factory::log;
+use crate::service::static_config::messaging_pattern::MessagingPattern;
+use crate::service::*;
+use crate::service::{self, dynamic_config::MessagingPatternSettings};
+use builder::RETRY_LIMIT;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::static_storage::StaticStorageLocked;
+
+use self::{
+    attribute::{AttributeSpecifier, AttributeVerifier},
+    message_type_details::{MessageTypeDetails, TypeVariant},
+};
+
+use super::{OpenDynamicStorageFailure, ServiceState};
+
+/// Errors that can occur when an existing [`MessagingPattern::Log`] [`Service`] shall be opened.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum LogOpenError {
+    /// Service could not be openen since it does not exist
+    DoesNotExist,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+    /// The [`Service`] has the wrong payload type.
+    IncompatibleTypes,
+    /// The [`Service`] has the wrong messaging pattern.
+    IncompatibleMessagingPattern,
+    /// The [`AttributeVerifier`] required attributes that the [`Service`] does not satisfy.
+    IncompatibleAttributes,
+    /// The [`Service`] has a lower minimum buffer size than requested.
+    DoesNotSupportRequestedMinBufferSize,
+    /// The [`Service`] supports less [`LogWriter`](crate::port::log_writer::LogWriter)s than requested.
+    DoesNotSupportRequestedAmountOfWriters,
+    /// The [`Service`] supports less [`LogCollector`](crate::port::log_collector::LogCollector)s than requested.
+    DoesNotSupportRequestedAmountOfCollectors,
+    /// The [`Service`] supports less [`Node`](crate::node::Node)s than requested.
+    DoesNotSupportRequestedAmountOfNodes,
+    /// The process has not enough permissions to open the [`Service`]
+    InsufficientPermissions,
+    /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
+    ServiceInCorruptedState,
+    /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
+    /// by a process that crashed during [`Service`] creation.
+    HangsInCreation,
+    /// The maximum number of [`Node`](crate::node::Node)s have already opened the [`Service`].
+    ExceedsMaxNumberOfNodes,
+    /// The [`Service`] is marked for destruction and currently cleaning up since no one is using it anymore.
+    /// When the call creation call is repeated with a little delay the [`Service`] should be
+    /// recreatable.
+    IsMarkedForDestruction,
+}
+
+impl core::fmt::Display for LogOpenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "LogOpenError::{self:?}")
+    }
+}
+
+impl core::error::Error for LogOpenError {}
+
+impl From<ServiceAvailabilityState> for LogOpenError {
+    fn from(value: ServiceAvailabilityState) -> Self {
+        match value {
+            ServiceAvailabilityState::IncompatibleTypes => LogOpenError::IncompatibleTypes,
+            ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
+                LogOpenError::IncompatibleMessagingPattern
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::InsufficientPermissions) => {
+                LogOpenError::InsufficientPermissions
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::HangsInCreation) => {
+                LogOpenError::HangsInCreation
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::Corrupted) => {
+                LogOpenError::ServiceInCorruptedState
+            }
+        }
+    }
+}
+
+/// Errors that can occur when a new [`MessagingPattern::Log`] [`Service`] shall be created.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum LogCreateError {
+    /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
+    ServiceInCorruptedState,
+    /// The [`Service`] already exists.
+    AlreadyExists,
+    /// The process has insufficient permissions to create the [`Service`].
+    InsufficientPermissions,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+    /// Multiple processes are trying to create the same [`Service`].
+    IsBeingCreatedByAnotherInstance,
+    /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
+    /// by a process that crashed during [`Service`] creation.
+    HangsInCreation,
+}
+
+impl core::fmt::Display for LogCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "LogCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for LogCreateError {}
+
+impl From<ServiceAvailabilityState> for LogCreateError {
+    fn from(value: ServiceAvailabilityState) -> Self {
+        match value {
+            ServiceAvailabilityState::IncompatibleTypes
+            | ServiceAvailabilityState::ServiceState(ServiceState::IncompatibleMessagingPattern) => {
+                LogCreateError::AlreadyExists
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::InsufficientPermissions) => {
+                LogCreateError::InsufficientPermissions
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::HangsInCreation) => {
+                LogCreateError::HangsInCreation
+            }
+            ServiceAvailabilityState::ServiceState(ServiceState::Corrupted) => {
+                LogCreateError::ServiceInCorruptedState
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum ServiceAvailabilityState {
+    ServiceState(ServiceState),
+    IncompatibleTypes,
+}
+
+/// Errors that can occur when a [`MessagingPattern::Log`] [`Service`] shall be
+/// created or opened.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum LogOpenOrCreateError {
+    /// Failures that can occur when an existing [`Service`] could not be opened.
+    LogOpenError(LogOpenError),
+    /// Failures that can occur when a [`Service`] could not be created.
+    LogCreateError(LogCreateError),
+    /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
+    /// high frequency.
+    SystemInFlux,
+}
+
+impl From<ServiceAvailabilityState> for LogOpenOrCreateError {
+    fn from(value: ServiceAvailabilityState) -> Self {
+        LogOpenOrCreateError::LogOpenError(value.into())
+    }
+}
+
+impl From<LogOpenError> for LogOpenOrCreateError {
+    fn from(value: LogOpenError) -> Self {
+        Self::LogOpenError(value)
+    }
+}
+
+impl From<LogCreateError> for LogOpenOrCreateError {
+    fn from(value: LogCreateError) -> Self {
+        Self::LogCreateError(value)
+    }
+}
+
+impl core::fmt::Display for LogOpenOrCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "LogOpenOrCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for LogOpenOrCreateError {}
+
+/// Builder to create new [`MessagingPattern::Log`] based [`Service`]s
+///
+/// # Example
+///
+/// See [`crate::service`]
+#[derive(Debug)]
+pub struct Builder<ServiceType: service::Service> {
+    base: builder::BuilderWithServiceType<ServiceType>,
+    verify_number_of_writers: bool,
+    verify_number_of_collectors: bool,
+    verify_collector_max_buffer_size: bool,
+    verify_max_nodes: bool,
+}
+
+impl<ServiceType: service::Service> Clone for Builder<ServiceType> {
+    fn clone(&self) -> Self {
+        Self {
+            base: self.base.clone(),
+            verify_number_of_writers: self.verify_number_of_writers,
+            verify_number_of_collectors: self.verify_number_of_collectors,
+            verify_collector_max_buffer_size: self.verify_collector_max_buffer_size,
+            verify_max_nodes: self.verify_max_nodes,
+        }
+    }
+}
+
+impl<ServiceType: service::Service> Builder<ServiceType> {
+    pub(crate) fn new(base: builder::BuilderWithServiceType<ServiceType>) -> Self {
+        let mut new_self = Self {
+            base,
+            verify_number_of_writers: false,
+            verify_number_of_collectors: false,
+            verify_collector_max_buffer_size: false,
+            verify_max_nodes: false,
+        };
+
+        new_self.base.service_config.messaging_pattern = MessagingPattern::Log(
+            static_config::log::StaticConfig::new(new_self.base.shared_node.config()),
+        );
+
+        new_self
+    }
+
+    fn config_details_mut(&mut self) -> &mut static_config::log::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::Log(ref mut v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Log builder!");
+            }
+        }
+    }
+
+    fn config_details(&self) -> &static_config::log::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::Log(ref v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Log builder!");
+            }
+        }
+    }
+
+    // triggers the underlying is_service_available method to check whether the service described in base is available.
+    fn is_service_available(
+        &mut self,
+        error_msg: &str,
+    ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
+        match self.base.is_service_available(error_msg) {
+            Ok(Some((config, storage))) => {
+                if !self
+                    .config_details()
+                    .message_type_details
+                    .is_compatible_to(&config.log().message_type_details)
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
+                        "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
+                        error_msg, &config.log().message_type_details , self.config_details().message_type_details);
+                }
+
+                Ok(Some((config, storage)))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => Err(ServiceAvailabilityState::ServiceState(e)),
+        }
+    }
+
+    /// If the [`Service`] is created it defines how many [`LogRecord`](crate::log_record::LogRecord)s a
+    /// [`crate::port::log_collector::LogCollector`] can store in its internal buffer. If an existing
+    /// [`Service`] is opened it defines the minimum required.
+    pub fn collector_max_buffer_size(mut self, value: usize) -> Self {
+        self.config_details_mut().collector_max_buffer_size = value;
+        self.verify_collector_max_buffer_size = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::log_collector::LogCollector`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::log_collector::LogCollector`] must be at least supported.
+    pub fn max_collectors(mut self, value: usize) -> Self {
+        self.config_details_mut().max_collectors = value;
+        self.verify_number_of_collectors = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::log_writer::LogWriter`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::log_writer::LogWriter`] must be at least supported.
+    pub fn max_writers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_writers = value;
+        self.verify_number_of_writers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`Node`](crate::node::Node)s shall
+    /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
+    /// [`Node`](crate::node::Node)s must be at least supported.
+    pub fn max_nodes(mut self, value: usize) -> Self {
+        self.config_details_mut().max_nodes = value;
+        self.verify_max_nodes = true;
+        self
+    }
+
+    /// Validates configuration and overrides the invalid setting with meaningful values.
+    fn adjust_configuration_to_meaningful_values(&mut self) {
+        let origin = format!("{self:?}");
+        let settings = self.base.service_config.log_mut();
+
+        if settings.collector_max_buffer_size == 0 {
+            warn!(from origin,
+                "Setting the collectors buffer size to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.collector_max_buffer_size = 1;
+        }
+
+        if settings.max_collectors == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of collectors to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_collectors = 1;
+        }
+
+        if settings.max_writers == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of writers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_writers = 1;
+        }
+
+        if settings.max_nodes == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_nodes = 1;
+        }
+    }
+
+    fn verify_service_configuration(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+        verifier: &AttributeVerifier,
+    ) -> Result<static_config::log::StaticConfig, LogOpenError> {
+        let msg = "Unable to open log service";
+
+        let existing_attributes = existing_settings.attributes();
+        if let Err(incompatible_key) = verifier.verify_requirements(existing_attributes) {
+            fail!(from self, with LogOpenError::IncompatibleAttributes,
+                "{} due to incompatible service attribute key \"{}\". The following attributes {:?} are required but the service has the attributes {:?}.",
+                msg, incompatible_key, verifier, existing_attributes);
+        }
+
+        let required_settings = self.base.service_config.log();
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::Log(ref v) => v,
+            p => {
+                fail!(from self, with LogOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::Log is required.", msg, p);
+            }
+        };
+
+        if self.verify_number_of_writers
+            && existing_settings.max_writers < required_settings.max_writers
+        {
+            fail!(from self, with LogOpenError::DoesNotSupportRequestedAmountOfWriters,
+                                "{} since the service supports only {} writers but a support of {} writers was requested.",
+                                msg, existing_settings.max_writers, required_settings.max_writers);
+        }
+
+        if self.verify_number_of_collectors
+            && existing_settings.max_collectors < required_settings.max_collectors
+        {
+            fail!(from self, with LogOpenError::DoesNotSupportRequestedAmountOfCollectors,
+                                "{} since the service supports only {} collectors but a support of {} collectors was requested.",
+                                msg, existing_settings.max_collectors, required_settings.max_collectors);
+        }
+
+        if self.verify_collector_max_buffer_size
+            && existing_settings.collector_max_buffer_size
+                < required_settings.collector_max_buffer_size
+        {
+            fail!(from self, with LogOpenError::DoesNotSupportRequestedMinBufferSize,
+                                "{} since the service supports only a collector buffer size of {} but a buffer size of {} was requested.",
+                                msg, existing_settings.collector_max_buffer_size, required_settings.collector_max_buffer_size);
+        }
+
+        if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
+            fail!(from self, with LogOpenError::DoesNotSupportRequestedAmountOfNodes,
+                                "{} since the service supports only {} nodes but {} are required.",
+                                msg, existing_settings.max_nodes, required_settings.max_nodes);
+        }
+
+        Ok(existing_settings.clone())
+    }
+
+    fn create_impl(
+        &mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<log::PortFactory<ServiceType>, LogCreateError> {
+        self.adjust_configuration_to_meaningful_values();
+
+        let msg = "Unable to create log service";
+
+        match self.is_service_available(msg)? {
+            None => {
+                let service_tag = self
+                    .base
+                    .create_node_service_tag(msg, LogCreateError::InternalFailure)?;
+
+                // create static config
+                let static_config = match self.base.create_static_config_storage() {
+                    Ok(c) => c,
+                    Err(StaticStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with LogCreateError::AlreadyExists,
+                           "{} since the service already exists.", msg);
+                    }
+                    Err(StaticStorageCreateError::Creation) => {
+                        fail!(from self, with LogCreateError::IsBeingCreatedByAnotherInstance,
+                            "{} since the service is being created by another instance.", msg);
+                    }
+                    Err(StaticStorageCreateError::InsufficientPermissions) => {
+                        fail!(from self, with LogCreateError::InsufficientPermissions,
+                            "{} since the static service information could not be created due to insufficient permissions.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with LogCreateError::InternalFailure,
+                            "{} since the static service information could not be created due to an internal failure ({:?}).", msg, e);
+                    }
+                };
+
+                let log_config = self.base.service_config.log();
+
+                // create dynamic config
+                let dynamic_config_setting = DynamicConfigSettings {
+                    number_of_writers: log_config.max_writers,
+                    number_of_collectors: log_config.max_collectors,
+                };
+
+                let dynamic_config = match self.base.create_dynamic_config_storage(
+                    &MessagingPatternSettings::Log(dynamic_config_setting),
+                    dynamic_config::log::DynamicConfig::memory_size(&dynamic_config_setting),
+                    log_config.max_nodes,
+                ) {
+                    Ok(dynamic_config) => dynamic_config,
+                    Err(DynamicStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with LogCreateError::ServiceInCorruptedState,
+                            "{} since the dynamic config of a previous instance of the service still exists.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with LogCreateError::InternalFailure,
+                            "{} since the dynamic service segment could not be created ({:?}).", msg, e);
+                    }
+                };
+
+                self.base.service_config.attributes = attributes.0.clone();
+                let service_config = fail!(from self,
+                            when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                            with LogCreateError::ServiceInCorruptedState,
+                            "{} since the configuration could not be serialized.", msg);
+
+                // only unlock the static details when the service is successfully created
+                let unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                            with LogCreateError::ServiceInCorruptedState,
+                            "{} since the configuration could not be written to the static storage.", msg);
+
+                unlocked_static_details.release_ownership();
+                if let Some(service_tag) = service_tag {
+                    service_tag.release_ownership();
+                }
+
+                Ok(log::PortFactory::new(service::ServiceState::new(
+                    self.base.service_config.clone(),
+                    self.base.shared_node.clone(),
+                    dynamic_config,
+                    unlocked_static_details,
+                    NoResource,
+                )))
+            }
+            Some(_) => {
+                fail!(from self, with LogCreateError::AlreadyExists,
+                    "{} since the service already exists.", msg);
+            }
+        }
+    }
+
+    fn open_impl(
+        &mut self,
+        attributes: &AttributeVerifier,
+    ) -> Result<log::PortFactory<ServiceType>, LogOpenError> {
+        let msg = "Unable to open log service";
+
+        let mut service_open_retry_count = 0;
+        loop {
+            match self.is_service_available(msg)? {
+                None => {
+                    fail!(from self, with LogOpenError::DoesNotExist,
+                        "{} since the service does not exist.", msg);
+                }
+                Some((static_config, static_storage)) => {
+                    let log_static_config =
+                        self.verify_service_configuration(&static_config, attributes)?;
+
+                    let service_tag = self
+                        .base
+                        .create_node_service_tag(msg, LogOpenError::InternalFailure)?;
+
+                    let dynamic_config = match self.base.open_dynamic_config_storage() {
+                        Ok(v) => v,
+                        Err(OpenDynamicStorageFailure::IsMarkedForDestruction) => {
+                            fail!(from self, with LogOpenError::IsMarkedForDestruction,
+                                "{} since the service is marked for destruction.", msg);
+                        }
+                        Err(OpenDynamicStorageFailure::ExceedsMaxNumberOfNodes) => {
+                            fail!(from self, with LogOpenError::ExceedsMaxNumberOfNodes,
+                                "{} since it would exceed the maximum number of supported nodes.", msg);
+                        }
+                        Err(OpenDynamicStorageFailure::DynamicStorageOpenError(
+                            DynamicStorageOpenError::DoesNotExist,
+                        )) => {
+                            fail!(from self, with LogOpenError::ServiceInCorruptedState,
+                                "{} since the dynamic segment of the service is missing.", msg);
+                        }
+                        Err(e) => {
+                            if self.is_service_available(msg)?.is_none() {
+                                fail!(from self, with LogOpenError::DoesNotExist,
+                                    "{} since the service does not exist.", msg);
+                            }
+
+                            service_open_retry_count += 1;
+
+                            if RETRY_LIMIT < service_open_retry_count {
+                                fail!(from self, with LogOpenError::ServiceInCorruptedState,
+                                "{} since the dynamic service information could not be opened ({:?}). This could indicate a corrupted system or a misconfigured system where services are created/removed with a high frequency.",
+                                msg, e);
+                            }
+
+                            continue;
+                        }
+                    };
+
+                    self.base.service_config.messaging_pattern =
+                        MessagingPattern::Log(log_static_config.clone());
+
+                    if let Some(service_tag) = service_tag {
+                        service_tag.release_ownership();
+                    }
+
+                    return Ok(log::PortFactory::new(service::ServiceState::new(
+                        static_config,
+                        self.base.shared_node.clone(),
+                        dynamic_config,
+                        static_storage,
+                        NoResource,
+                    )));
+                }
+            }
+        }
+    }
+
+    fn open_or_create_impl(
+        mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<log::PortFactory<ServiceType>, LogOpenOrCreateError> {
+        let msg = "Unable to open or create log service";
+
+        let mut retry_count = 0;
+        loop {
+            if RETRY_LIMIT < retry_count {
+                fail!(from self,
+                      with LogOpenOrCreateError::SystemInFlux,
+                      "{} since an instance is creating and removing the same service repeatedly.",
+                      msg);
+            }
+            retry_count += 1;
+
+            match self.is_service_available(msg)? {
+                Some(_) => match self.open_impl(verifier) {
+                    Ok(factory) => return Ok(factory),
+                    Err(LogOpenError::DoesNotExist) => continue,
+                    Err(e) => return Err(e.into()),
+                },
+                None => {
+                    match self
+                        .create_impl(&AttributeSpecifier(verifier.required_attributes().clone()))
+                    {
+                        Ok(factory) => return Ok(factory),
+                        Err(LogCreateError::AlreadyExists)
+                        | Err(LogCreateError::IsBeingCreatedByAnotherInstance) => {
+                            continue;
+                        }
+                        Err(e) => return Err(e.into()),
+                    }
+                }
+            }
+        }
+    }
+
+    fn prepare_config_details(&mut self) {
+        self.config_details_mut().message_type_details =
+            MessageTypeDetails::from::<Header, (), LogRecord>(TypeVariant::FixedSize);
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(self) -> Result<log::PortFactory<ServiceType>, LogOpenOrCreateError> {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. It defines a set of attributes.
+    ///
+    /// If the [`Service`] already exists all attribute requirements must be satisfied,
+    /// and service payload type must be the same, otherwise the open process will fail.
+    /// If the [`Service`] does not exist the required attributes will be defined in the [`Service`].
+    pub fn open_or_create_with_attributes(
+        mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<log::PortFactory<ServiceType>, LogOpenOrCreateError> {
+        self.prepare_config_details();
+        self.open_or_create_impl(verifier)
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(self) -> Result<log::PortFactory<ServiceType>, LogOpenError> {
+        self.open_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
+    /// requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes(
+        mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<log::PortFactory<ServiceType>, LogOpenError> {
+        self.prepare_config_details();
+        self.open_impl(verifier)
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(self) -> Result<log::PortFactory<ServiceType>, LogCreateError> {
+        self.create_with_attributes(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`] with a set of attributes.
+    pub fn create_with_attributes(
+        mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<log::PortFactory<ServiceType>, LogCreateError> {
+        self.prepare_config_details();
+        self.create_impl(attributes)
+    }
+}
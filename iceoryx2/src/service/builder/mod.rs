@@ -26,6 +26,12 @@ pub mod request_response;
 /// Builder for [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
 pub mod blackboard;
 
+/// Builder for [`MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log)
+pub mod log;
+
+/// Builder for [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+pub mod pipeline;
+
 use crate::node::SharedNode;
 use crate::service;
 use crate::service::dynamic_config::DynamicConfig;
@@ -186,6 +192,29 @@ impl<S: Service> Builder<S> {
         .event()
     }
 
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log) [`Service`].
+    pub fn log(self) -> log::Builder<S> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_log::<S::ServiceNameHasher>(&self.name, self.shared_node.config()),
+            self.shared_node,
+        )
+        .log()
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) [`Service`].
+    pub fn pipeline<PayloadType: Debug + ZeroCopySend>(self) -> pipeline::Builder<PayloadType, S> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_pipeline::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            ),
+            self.shared_node,
+        )
+        .pipeline()
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) [`Service`].
     pub fn blackboard_creator<
@@ -257,6 +286,16 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         event::Builder::new(self)
     }
 
+    fn log(self) -> log::Builder<ServiceType> {
+        log::Builder::new(self)
+    }
+
+    fn pipeline<PayloadType: Debug + ZeroCopySend>(
+        self,
+    ) -> pipeline::Builder<PayloadType, ServiceType> {
+        pipeline::Builder::new(self)
+    }
+
     fn blackboard_creator<
         KeyType: Send + Sync + Eq + Clone + Debug + 'static + ZeroCopySend + Hash,
     >(
@@ -64,6 +64,8 @@ pub enum PublishSubscribeOpenError {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`] required notify on send behavior is not compatible.
+    IncompatibleNotifyOnSendBehavior,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
@@ -225,6 +227,7 @@ pub struct Builder<
     verify_subscriber_max_borrowed_samples: bool,
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
+    verify_enable_notify_on_send: bool,
     verify_max_nodes: bool,
     _data: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
@@ -248,6 +251,7 @@ impl<
             verify_subscriber_max_borrowed_samples: self.verify_subscriber_max_borrowed_samples,
             verify_publisher_history_size: self.verify_publisher_history_size,
             verify_enable_safe_overflow: self.verify_enable_safe_overflow,
+            verify_enable_notify_on_send: self.verify_enable_notify_on_send,
             verify_max_nodes: self.verify_max_nodes,
             _data: PhantomData,
             _user_header: PhantomData,
@@ -270,6 +274,7 @@ impl<
             verify_publisher_history_size: false,
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
+            verify_enable_notify_on_send: false,
             verify_max_nodes: false,
             override_alignment: None,
             override_payload_type: None,
@@ -349,6 +354,20 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created, defines if every
+    /// [`Publisher`](crate::port::publisher::Publisher) signals the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s whenever it sent a
+    /// [`Sample`](crate::sample::Sample). Only then a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) provides a
+    /// [`SignalListener`](crate::port::SignalListener) that can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet). If an existing [`Service`] is opened it requires
+    /// the service to have the defined notify on send behavior.
+    pub fn enable_notify_on_send(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_notify_on_send = value;
+        self.verify_enable_notify_on_send = true;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
     /// [`crate::port::subscriber::Subscriber`] can borrow at most in parallel. If an existing
     /// [`Service`] is opened it defines the minimum required.
@@ -512,6 +531,14 @@ impl<
                                 msg);
         }
 
+        if self.verify_enable_notify_on_send
+            && existing_settings.enable_notify_on_send != required_settings.enable_notify_on_send
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleNotifyOnSendBehavior,
+                                "{} since the service has an incompatible notify on send behavior.",
+                                msg);
+        }
+
         if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
             fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes,
                                 "{} since the service supports only {} nodes but {} are required.",
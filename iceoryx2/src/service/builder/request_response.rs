@@ -72,6 +72,8 @@ pub enum RequestResponseOpenError {
     IncompatibleOverflowBehaviorForResponses,
     /// The [`Service`] does not support the required behavior for fire and forget requests.
     IncompatibleBehaviorForFireAndForgetRequests,
+    /// The [`Service`] required notify on send behavior is not compatible.
+    IncompatibleNotifyOnSendBehavior,
     /// The process has not enough permissions to open the [`Service`].
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
@@ -240,6 +242,7 @@ pub struct Builder<
     verify_max_nodes: bool,
     verify_max_borrowed_responses_per_pending_response: bool,
     verify_enable_fire_and_forget_requests: bool,
+    verify_enable_notify_on_send: bool,
 
     _request_payload: PhantomData<RequestPayload>,
     _request_header: PhantomData<RequestHeader>,
@@ -277,6 +280,7 @@ impl<
             verify_max_borrowed_responses_per_pending_response: self
                 .verify_max_borrowed_responses_per_pending_response,
             verify_enable_fire_and_forget_requests: self.verify_enable_fire_and_forget_requests,
+            verify_enable_notify_on_send: self.verify_enable_notify_on_send,
             _request_payload: PhantomData,
             _request_header: PhantomData,
             _response_payload: PhantomData,
@@ -312,6 +316,7 @@ impl<
             verify_max_nodes: false,
             verify_max_borrowed_responses_per_pending_response: false,
             verify_enable_fire_and_forget_requests: false,
+            verify_enable_notify_on_send: false,
             _request_payload: PhantomData,
             _request_header: PhantomData,
             _response_payload: PhantomData,
@@ -406,6 +411,21 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created, defines if every [`Client`](crate::port::client::Client)
+    /// signals the [`Server`](crate::port::server::Server)s whenever it sent a request and if
+    /// every [`Server`](crate::port::server::Server) signals the
+    /// [`Client`](crate::port::client::Client) whenever it sent a response to it. Only then a
+    /// [`Server`](crate::port::server::Server) or a
+    /// [`PendingResponse`](crate::pending_response::PendingResponse) provides a
+    /// [`SignalListener`](crate::port::SignalListener) that can be attached to a
+    /// [`WaitSet`](crate::waitset::WaitSet). If an existing [`Service`] is opened it requires
+    /// the service to have the defined notify on send behavior.
+    pub fn enable_notify_on_send(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_notify_on_send = value;
+        self.verify_enable_notify_on_send = true;
+        self
+    }
+
     /// Defines how many active requests a [`Server`](crate::port::server::Server) can hold in
     /// parallel per [`Client`](crate::port::client::Client). The objects are used to send answers to a request that was received earlier
     /// from a [`Client`](crate::port::client::Client)
@@ -566,6 +586,15 @@ impl<
                 msg);
         }
 
+        if self.verify_enable_notify_on_send
+            && existing_configuration.enable_notify_on_send
+                != required_configuration.enable_notify_on_send
+        {
+            fail!(from self, with RequestResponseOpenError::IncompatibleNotifyOnSendBehavior,
+                "{} since the service has an incompatible notify on send behavior.",
+                msg);
+        }
+
         if self.verify_max_active_requests_per_client
             && existing_configuration.max_active_requests_per_client
                 < required_configuration.max_active_requests_per_client
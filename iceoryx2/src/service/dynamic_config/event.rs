@@ -26,24 +26,59 @@
 //! # Ok(())
 //! # }
 //! ```
+use core::sync::atomic::Ordering;
+
+use iceoryx2_bb_container::vec::RelocatableVec;
 use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 use crate::{
     node::NodeId,
-    port::port_identifiers::{UniqueListenerId, UniqueNotifierId, UniquePortId},
+    port::{
+        event_id::EventId,
+        port_identifiers::{UniqueListenerId, UniqueNotifierId, UniquePortId},
+    },
 };
 
 use super::PortCleanupAction;
 
+// Layout of a notification slot. Every slot consists of `SLOT_HEADER_SIZE` words followed by
+// the payload words and is written by exactly one notifier and read by exactly one listener.
+// The content is protected by a sequence lock, the count is maintained separately so that
+// a listener can acquire it without blocking the notifier.
+const SEQUENCE: usize = 0;
+const COUNT: usize = 1;
+const EVENT_ID: usize = 2;
+const NOTIFIER_ID_LOW: usize = 3;
+const NOTIFIER_ID_HIGH: usize = 4;
+const PAYLOAD_LEN: usize = 5;
+const SLOT_HEADER_SIZE: usize = 6;
+const WORD_SIZE: usize = core::mem::size_of::<u64>();
+// A notifier that died while writing a slot leaves it in an inconsistent state forever.
+// The listener gives up after this many attempts and skips the slot.
+const MAX_READ_ATTEMPTS: usize = 1024;
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DynamicConfigSettings {
     pub number_of_listeners: usize,
     pub number_of_notifiers: usize,
+    pub notification_payload_size: Option<usize>,
+}
+
+impl DynamicConfigSettings {
+    fn notification_slot_size(&self) -> usize {
+        self.notification_payload_size
+            .map_or(0, |size| SLOT_HEADER_SIZE + size.div_ceil(WORD_SIZE))
+    }
+
+    fn number_of_notification_words(&self) -> usize {
+        self.notification_slot_size() * self.number_of_listeners * self.number_of_notifiers
+    }
 }
 
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
@@ -54,6 +89,8 @@ pub struct DynamicConfig {
     pub(crate) listeners: Container<ListenerDetails>,
     pub(crate) notifiers: Container<NotifierDetails>,
     pub(crate) elapsed_time_since_last_notification: IoxAtomicU64,
+    notifications: RelocatableVec<IoxAtomicU64>,
+    notification_slot_size: usize,
 }
 
 /// Contains the communication settings of the connected
@@ -86,6 +123,10 @@ impl DynamicConfig {
             listeners: unsafe { Container::new_uninit(config.number_of_listeners) },
             notifiers: unsafe { Container::new_uninit(config.number_of_notifiers) },
             elapsed_time_since_last_notification: IoxAtomicU64::new(0),
+            notifications: unsafe {
+                RelocatableVec::new_uninit(config.number_of_notification_words())
+            },
+            notification_slot_size: config.notification_slot_size(),
         }
     }
 
@@ -96,11 +137,20 @@ impl DynamicConfig {
         fatal_panic!(from "event::DynamicConfig::init",
             when self.notifiers.init(allocator),
             "This should never happen! Unable to initialize notifier port id container.");
+        if self.notifications.capacity() != 0 {
+            fatal_panic!(from "event::DynamicConfig::init",
+                when self.notifications.init(allocator),
+                "This should never happen! Unable to initialize notification slots.");
+            self.notifications.fill_with(|| IoxAtomicU64::new(0));
+        }
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<ListenerDetails>::memory_size(config.number_of_listeners)
             + Container::<NotifierDetails>::memory_size(config.number_of_notifiers)
+            + RelocatableVec::<IoxAtomicU64>::const_memory_size(
+                config.number_of_notification_words(),
+            )
     }
 
     /// Returns how many [`Listener`](crate::port::listener::Listener) ports are currently connected.
@@ -188,4 +238,132 @@ impl DynamicConfig {
     pub(crate) fn release_notifier_handle(&self, handle: ContainerHandle) {
         unsafe { self.notifiers.remove(handle, ReleaseMode::Default) };
     }
+
+    pub(crate) fn has_notification_details(&self) -> bool {
+        self.notification_slot_size != 0
+    }
+
+    pub(crate) fn notification_payload_capacity(&self) -> usize {
+        self.notification_slot_size.saturating_sub(SLOT_HEADER_SIZE) * WORD_SIZE
+    }
+
+    fn notification_slot(&self, listener_index: usize, notifier_index: usize) -> &[IoxAtomicU64] {
+        let start = (listener_index * self.notifiers.capacity() + notifier_index)
+            * self.notification_slot_size;
+        &self.notifications[start..start + self.notification_slot_size]
+    }
+
+    /// Discards all notification details that were stored for a listener that previously owned
+    /// the same index.
+    pub(crate) fn reset_listener_notifications(&self, listener_index: usize) {
+        if !self.has_notification_details() {
+            return;
+        }
+
+        for notifier_index in 0..self.notifiers.capacity() {
+            self.notification_slot(listener_index, notifier_index)[COUNT]
+                .store(0, Ordering::Relaxed);
+        }
+    }
+
+    /// Discards all notification details that were stored by a notifier that previously owned
+    /// the same index.
+    pub(crate) fn reset_notifier_notifications(&self, notifier_index: usize) {
+        if !self.has_notification_details() {
+            return;
+        }
+
+        for listener_index in 0..self.listeners.capacity() {
+            self.notification_slot(listener_index, notifier_index)[COUNT]
+                .store(0, Ordering::Relaxed);
+        }
+    }
+
+    /// Must be called only by the notifier that owns `notifier_index` and never concurrently.
+    pub(crate) fn store_notification(
+        &self,
+        listener_index: usize,
+        notifier_index: usize,
+        notifier_id: UniqueNotifierId,
+        event_id: EventId,
+        payload: &[u8],
+    ) {
+        let slot = self.notification_slot(listener_index, notifier_index);
+        let sequence = slot[SEQUENCE].load(Ordering::Relaxed);
+        slot[SEQUENCE].store(sequence.wrapping_add(1), Ordering::Relaxed);
+        core::sync::atomic::fence(Ordering::Release);
+
+        let notifier_id = notifier_id.value();
+        slot[EVENT_ID].store(event_id.as_value() as u64, Ordering::Relaxed);
+        slot[NOTIFIER_ID_LOW].store(notifier_id as u64, Ordering::Relaxed);
+        slot[NOTIFIER_ID_HIGH].store((notifier_id >> 64) as u64, Ordering::Relaxed);
+        slot[PAYLOAD_LEN].store(payload.len() as u64, Ordering::Relaxed);
+        for (word, chunk) in slot[SLOT_HEADER_SIZE..]
+            .iter()
+            .zip(payload.chunks(WORD_SIZE))
+        {
+            let mut bytes = [0u8; WORD_SIZE];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            word.store(u64::from_ne_bytes(bytes), Ordering::Relaxed);
+        }
+
+        slot[SEQUENCE].store(sequence.wrapping_add(2), Ordering::Release);
+        slot[COUNT].fetch_add(1, Ordering::Release);
+    }
+
+    /// Calls the callback for every notifier that notified the listener since the last call
+    /// with the [`UniqueNotifierId`], the last [`EventId`], the number of notifications and the
+    /// last payload. `buffer` must be at least
+    /// [`DynamicConfig::notification_payload_capacity()`] bytes large.
+    /// Must be called only by the listener that owns `listener_index` and never concurrently.
+    pub(crate) fn acquire_notifications<F: FnMut(UniqueNotifierId, EventId, u64, &[u8])>(
+        &self,
+        listener_index: usize,
+        buffer: &mut [u8],
+        mut callback: F,
+    ) {
+        for notifier_index in 0..self.notifiers.capacity() {
+            let slot = self.notification_slot(listener_index, notifier_index);
+            if slot[COUNT].load(Ordering::Acquire) == 0 {
+                continue;
+            }
+
+            let mut snapshot = None;
+            for _ in 0..MAX_READ_ATTEMPTS {
+                let sequence = slot[SEQUENCE].load(Ordering::Acquire);
+                if sequence % 2 == 0 {
+                    let event_id = slot[EVENT_ID].load(Ordering::Relaxed);
+                    let notifier_id = (slot[NOTIFIER_ID_HIGH].load(Ordering::Relaxed) as u128)
+                        << 64
+                        | slot[NOTIFIER_ID_LOW].load(Ordering::Relaxed) as u128;
+                    let payload_len =
+                        (slot[PAYLOAD_LEN].load(Ordering::Relaxed) as usize).min(buffer.len());
+                    for (word, chunk) in slot[SLOT_HEADER_SIZE..]
+                        .iter()
+                        .zip(buffer.chunks_mut(WORD_SIZE))
+                    {
+                        let bytes = word.load(Ordering::Relaxed).to_ne_bytes();
+                        chunk.copy_from_slice(&bytes[..chunk.len()]);
+                    }
+                    core::sync::atomic::fence(Ordering::Acquire);
+
+                    if slot[SEQUENCE].load(Ordering::Relaxed) == sequence {
+                        snapshot = Some((event_id, notifier_id, payload_len));
+                        break;
+                    }
+                }
+                core::hint::spin_loop();
+            }
+
+            if let Some((event_id, notifier_id, payload_len)) = snapshot {
+                let count = slot[COUNT].swap(0, Ordering::AcqRel);
+                callback(
+                    UniqueNotifierId(UniqueSystemId::from(notifier_id)),
+                    EventId::new(event_id as usize),
+                    count,
+                    &buffer[..payload_len],
+                );
+            }
+        }
+    }
 }
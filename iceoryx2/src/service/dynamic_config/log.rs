@@ -0,0 +1,203 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let log = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .log()
+//!     .open_or_create()?;
+//!
+//! println!("number of active writers:     {:?}", log.dynamic_config().number_of_writers());
+//! println!("number of active collectors:  {:?}", log.dynamic_config().number_of_collectors());
+//! # Ok(())
+//! # }
+//! ```
+use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+
+use crate::{
+    node::NodeId,
+    port::{
+        details::data_segment::DataSegmentType,
+        port_identifiers::{UniqueLogCollectorId, UniqueLogWriterId, UniquePortId},
+    },
+};
+
+use super::PortCleanupAction;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_collectors: usize,
+    pub number_of_writers: usize,
+}
+
+/// Contains the communication settings of the connected
+/// [`LogWriter`](crate::port::log_writer::LogWriter).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct LogWriterDetails {
+    /// The [`UniqueLogWriterId`] of the [`LogWriter`](crate::port::log_writer::LogWriter).
+    pub writer_id: UniqueLogWriterId,
+    /// The [`NodeId`] of the [`Node`](crate::node::Node) under which the
+    /// [`LogWriter`](crate::port::log_writer::LogWriter) was created.
+    pub node_id: NodeId,
+    /// The total number of log records contained in the
+    /// [`LogWriter`](crate::port::log_writer::LogWriter)s data segment.
+    pub number_of_records: usize,
+    /// The type of data segment the [`LogWriter`](crate::port::log_writer::LogWriter)
+    /// has.
+    pub data_segment_type: DataSegmentType,
+    /// If the [`LogWriter`](crate::port::log_writer::LogWriter) has the
+    /// [`DataSegmentType::Dynamic`] it defines how many segment the
+    /// [`LogWriter`](crate::port::log_writer::LogWriter) can have at most.
+    pub max_number_of_segments: u8,
+}
+
+/// Contains the communication settings of the connected
+/// [`LogCollector`](crate::port::log_collector::LogCollector).
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct LogCollectorDetails {
+    /// The [`UniqueLogCollectorId`] of the [`LogCollector`](crate::port::log_collector::LogCollector).
+    pub collector_id: UniqueLogCollectorId,
+    /// The [`NodeId`] of the [`Node`](crate::node::Node) under which the
+    /// [`LogCollector`](crate::port::log_collector::LogCollector) was created.
+    pub node_id: NodeId,
+    /// The size of the receive buffer that stores [`LogRecord`](crate::log_record::LogRecord)s.
+    pub buffer_size: usize,
+}
+
+/// The dynamic configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Log`]
+/// based service. Contains dynamic parameters like the connected endpoints etc..
+#[repr(C)]
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) collectors: Container<LogCollectorDetails>,
+    pub(crate) writers: Container<LogWriterDetails>,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            collectors: unsafe { Container::new_uninit(config.number_of_collectors) },
+            writers: unsafe { Container::new_uninit(config.number_of_writers) },
+        }
+    }
+
+    pub(crate) unsafe fn init(&mut self, allocator: &BumpAllocator) {
+        fatal_panic!(from self,
+            when self.collectors.init(allocator),
+            "This should never happen! Unable to initialize log collector port id container.");
+        fatal_panic!(from self,
+            when self.writers.init(allocator),
+            "This should never happen! Unable to initialize log writer port id container.");
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<LogCollectorDetails>::memory_size(config.number_of_collectors)
+            + Container::<LogWriterDetails>::memory_size(config.number_of_writers)
+    }
+
+    pub(crate) unsafe fn remove_dead_node_id<
+        PortCleanup: FnMut(UniquePortId) -> PortCleanupAction,
+    >(
+        &self,
+        node_id: &NodeId,
+        mut port_cleanup_callback: PortCleanup,
+    ) {
+        self.writers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_writer| {
+                if registered_writer.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::LogWriter(registered_writer.writer_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_writer_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+
+        self.collectors
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_collector| {
+                if registered_collector.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::LogCollector(
+                        registered_collector.collector_id,
+                    )) == PortCleanupAction::RemovePort
+                {
+                    self.release_collector_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+    }
+
+    /// Returns how many [`crate::port::log_writer::LogWriter`] ports are currently connected.
+    pub fn number_of_writers(&self) -> usize {
+        self.writers.len()
+    }
+
+    /// Returns how many [`crate::port::log_collector::LogCollector`] ports are currently connected.
+    pub fn number_of_collectors(&self) -> usize {
+        self.collectors.len()
+    }
+
+    /// Iterates over all [`LogCollector`](crate::port::log_collector::LogCollector)s and calls the
+    /// callback with the corresponding [`LogCollectorDetails`].
+    /// The callback shall return [`CallbackProgression::Continue`] when the iteration shall
+    /// continue otherwise [`CallbackProgression::Stop`].
+    pub fn list_collectors<F: FnMut(&LogCollectorDetails) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        let state = unsafe { self.collectors.get_state() };
+
+        state.for_each(|_, details| callback(details));
+    }
+
+    /// Iterates over all [`LogWriter`](crate::port::log_writer::LogWriter)s and calls the
+    /// callback with the corresponding [`LogWriterDetails`].
+    /// The callback shall return [`CallbackProgression::Continue`] when the iteration shall
+    /// continue otherwise [`CallbackProgression::Stop`].
+    pub fn list_writers<F: FnMut(&LogWriterDetails) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        let state = unsafe { self.writers.get_state() };
+
+        state.for_each(|_, details| callback(details));
+    }
+
+    pub(crate) fn add_collector_id(&self, details: LogCollectorDetails) -> Option<ContainerHandle> {
+        unsafe { self.collectors.add(details).ok() }
+    }
+
+    pub(crate) fn release_collector_handle(&self, handle: ContainerHandle) {
+        unsafe { self.collectors.remove(handle, ReleaseMode::Default) };
+    }
+
+    pub(crate) fn add_writer_id(&self, details: LogWriterDetails) -> Option<ContainerHandle> {
+        unsafe { self.writers.add(details).ok() }
+    }
+
+    pub(crate) fn release_writer_handle(&self, handle: ContainerHandle) {
+        unsafe { self.writers.remove(handle, ReleaseMode::Default) };
+    }
+}
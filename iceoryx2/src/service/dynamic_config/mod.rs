@@ -30,6 +30,16 @@ pub mod request_response;
 /// based service.
 pub mod blackboard;
 
+/// The dynamic service configuration of an
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+/// based service.
+pub mod pipeline;
+
+/// The dynamic service configuration of an
+/// [`MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log)
+/// based service.
+pub mod log;
+
 use core::fmt::Display;
 use iceoryx2_bb_container::queue::RelocatableContainer;
 use iceoryx2_bb_elementary::CallbackProgression;
@@ -70,6 +80,8 @@ pub(crate) enum MessagingPatternSettings {
     PublishSubscribe(publish_subscribe::DynamicConfigSettings),
     Event(event::DynamicConfigSettings),
     Blackboard(blackboard::DynamicConfigSettings),
+    Pipeline(pipeline::DynamicConfigSettings),
+    Log(log::DynamicConfigSettings),
 }
 
 #[derive(Debug)]
@@ -78,6 +90,8 @@ pub(crate) enum MessagingPattern {
     PublishSubscribe(publish_subscribe::DynamicConfig),
     Event(event::DynamicConfig),
     Blackboard(blackboard::DynamicConfig),
+    Pipeline(pipeline::DynamicConfig),
+    Log(log::DynamicConfig),
 }
 
 impl MessagingPattern {
@@ -95,6 +109,10 @@ impl MessagingPattern {
             MessagingPatternSettings::Blackboard(v) => {
                 MessagingPattern::Blackboard(blackboard::DynamicConfig::new(v))
             }
+            MessagingPatternSettings::Pipeline(v) => {
+                MessagingPattern::Pipeline(pipeline::DynamicConfig::new(v))
+            }
+            MessagingPatternSettings::Log(v) => MessagingPattern::Log(log::DynamicConfig::new(v)),
         }
     }
 }
@@ -139,6 +157,8 @@ impl DynamicConfig {
             MessagingPattern::Event(ref mut v) => v.init(allocator),
             MessagingPattern::RequestResponse(ref mut v) => v.init(allocator),
             MessagingPattern::Blackboard(ref mut v) => v.init(allocator),
+            MessagingPattern::Pipeline(ref mut v) => v.init(allocator),
+            MessagingPattern::Log(ref mut v) => v.init(allocator),
         }
     }
 
@@ -160,6 +180,10 @@ impl DynamicConfig {
             MessagingPattern::Blackboard(ref v) => {
                 v.remove_dead_node_id(node_id, port_cleanup_callback)
             }
+            MessagingPattern::Pipeline(ref v) => {
+                v.remove_dead_node_id(node_id, port_cleanup_callback)
+            }
+            MessagingPattern::Log(ref v) => v.remove_dead_node_id(node_id, port_cleanup_callback),
         };
 
         let mut ret_val = Err(RemoveDeadNodeResult::NodeNotRegistered);
@@ -249,4 +273,22 @@ impl DynamicConfig {
             }
         }
     }
+
+    pub(crate) fn pipeline(&self) -> &pipeline::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Pipeline(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Trying to access pipeline::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+
+    pub(crate) fn log(&self) -> &log::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Log(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Trying to access log::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
 }
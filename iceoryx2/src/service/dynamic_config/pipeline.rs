@@ -0,0 +1,204 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pipeline = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .pipeline::<u64>()
+//!     .open_or_create()?;
+//!
+//! println!("number of active producers:   {:?}", pipeline.dynamic_config().number_of_producers());
+//! println!("number of active consumers:   {:?}", pipeline.dynamic_config().number_of_consumers());
+//! # Ok(())
+//! # }
+//! ```
+use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+
+use crate::{
+    node::NodeId,
+    port::{
+        details::data_segment::DataSegmentType,
+        port_identifiers::{UniqueConsumerId, UniquePortId, UniqueProducerId},
+    },
+};
+
+use super::PortCleanupAction;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_consumers: usize,
+    pub number_of_producers: usize,
+}
+
+/// Contains the communication settings of the connected
+/// [`Producer`](crate::port::producer::Producer).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ProducerDetails {
+    /// The [`UniqueProducerId`] of the [`Producer`](crate::port::producer::Producer).
+    pub producer_id: UniqueProducerId,
+    /// The [`NodeId`] of the [`Node`](crate::node::Node) under which the
+    /// [`Producer`](crate::port::producer::Producer) was created.
+    pub node_id: NodeId,
+    /// The total number of work items contained in the
+    /// [`Producer`](crate::port::producer::Producer)s data segment.
+    pub number_of_items: usize,
+    /// The type of data segment the [`Producer`](crate::port::producer::Producer)
+    /// has.
+    pub data_segment_type: DataSegmentType,
+    /// If the [`Producer`](crate::port::producer::Producer) has the
+    /// [`DataSegmentType::Dynamic`] it defines how many segment the
+    /// [`Producer`](crate::port::producer::Producer) can have at most.
+    pub max_number_of_segments: u8,
+}
+
+/// Contains the communication settings of the connected
+/// [`Consumer`](crate::port::consumer::Consumer).
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ConsumerDetails {
+    /// The [`UniqueConsumerId`] of the [`Consumer`](crate::port::consumer::Consumer).
+    pub consumer_id: UniqueConsumerId,
+    /// The [`NodeId`] of the [`Node`](crate::node::Node) under which the
+    /// [`Consumer`](crate::port::consumer::Consumer) was created.
+    pub node_id: NodeId,
+    /// The size of the receive buffer that stores [`WorkItem`](crate::work_item::WorkItem)s.
+    pub buffer_size: usize,
+}
+
+/// The dynamic configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Pipeline`]
+/// based service. Contains dynamic parameters like the connected endpoints etc..
+#[repr(C)]
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) consumers: Container<ConsumerDetails>,
+    pub(crate) producers: Container<ProducerDetails>,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            consumers: unsafe { Container::new_uninit(config.number_of_consumers) },
+            producers: unsafe { Container::new_uninit(config.number_of_producers) },
+        }
+    }
+
+    pub(crate) unsafe fn init(&mut self, allocator: &BumpAllocator) {
+        fatal_panic!(from self,
+            when self.consumers.init(allocator),
+            "This should never happen! Unable to initialize consumer port id container.");
+        fatal_panic!(from self,
+            when self.producers.init(allocator),
+            "This should never happen! Unable to initialize producer port id container.");
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<ConsumerDetails>::memory_size(config.number_of_consumers)
+            + Container::<ProducerDetails>::memory_size(config.number_of_producers)
+    }
+
+    pub(crate) unsafe fn remove_dead_node_id<
+        PortCleanup: FnMut(UniquePortId) -> PortCleanupAction,
+    >(
+        &self,
+        node_id: &NodeId,
+        mut port_cleanup_callback: PortCleanup,
+    ) {
+        self.producers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_producer| {
+                if registered_producer.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Producer(
+                        registered_producer.producer_id,
+                    )) == PortCleanupAction::RemovePort
+                {
+                    self.release_producer_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+
+        self.consumers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_consumer| {
+                if registered_consumer.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Consumer(
+                        registered_consumer.consumer_id,
+                    )) == PortCleanupAction::RemovePort
+                {
+                    self.release_consumer_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+    }
+
+    /// Returns how many [`crate::port::producer::Producer`] ports are currently connected.
+    pub fn number_of_producers(&self) -> usize {
+        self.producers.len()
+    }
+
+    /// Returns how many [`crate::port::consumer::Consumer`] ports are currently connected.
+    pub fn number_of_consumers(&self) -> usize {
+        self.consumers.len()
+    }
+
+    /// Iterates over all [`Consumer`](crate::port::consumer::Consumer)s and calls the
+    /// callback with the corresponding [`ConsumerDetails`].
+    /// The callback shall return [`CallbackProgression::Continue`] when the iteration shall
+    /// continue otherwise [`CallbackProgression::Stop`].
+    pub fn list_consumers<F: FnMut(&ConsumerDetails) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        let state = unsafe { self.consumers.get_state() };
+
+        state.for_each(|_, details| callback(details));
+    }
+
+    /// Iterates over all [`Producer`](crate::port::producer::Producer)s and calls the
+    /// callback with the corresponding [`ProducerDetails`].
+    /// The callback shall return [`CallbackProgression::Continue`] when the iteration shall
+    /// continue otherwise [`CallbackProgression::Stop`].
+    pub fn list_producers<F: FnMut(&ProducerDetails) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        let state = unsafe { self.producers.get_state() };
+
+        state.for_each(|_, details| callback(details));
+    }
+
+    pub(crate) fn add_consumer_id(&self, details: ConsumerDetails) -> Option<ContainerHandle> {
+        unsafe { self.consumers.add(details).ok() }
+    }
+
+    pub(crate) fn release_consumer_handle(&self, handle: ContainerHandle) {
+        unsafe { self.consumers.remove(handle, ReleaseMode::Default) };
+    }
+
+    pub(crate) fn add_producer_id(&self, details: ProducerDetails) -> Option<ContainerHandle> {
+        unsafe { self.producers.add(details).ok() }
+    }
+
+    pub(crate) fn release_producer_handle(&self, handle: ContainerHandle) {
+        unsafe { self.producers.remove(handle, ReleaseMode::Default) };
+    }
+}
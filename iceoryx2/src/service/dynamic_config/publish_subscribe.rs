@@ -36,6 +36,7 @@ use crate::{
     port::{
         details::data_segment::DataSegmentType,
         port_identifiers::{UniquePortId, UniquePublisherId, UniqueSubscriberId},
+        sample_filter::SampleFilter,
     },
 };
 
@@ -84,6 +85,9 @@ pub struct SubscriberDetails {
     pub node_id: NodeId,
     /// The size of the receive buffer that stores [`Sample`](crate::sample::Sample).
     pub buffer_size: usize,
+    /// The [`SampleFilter`] the [`Publisher`](crate::port::publisher::Publisher) evaluates
+    /// before a [`Sample`](crate::sample::Sample) is delivered.
+    pub filter: SampleFilter,
 }
 
 /// The dynamic configuration of an
@@ -0,0 +1,67 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .log()
+//!     .open_or_create()?;
+//!
+//! let collector = service.collector_builder().create()?;
+//!
+//! while let Some((header, record)) = collector.receive()? {
+//!     println!("header: {:?}", header);
+//!     println!("record: {:?}", record);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+
+use crate::{node::NodeId, port::port_identifiers::UniqueLogWriterId};
+
+/// Log record header used by
+/// [`MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log)
+#[derive(Debug, Copy, Clone, ZeroCopySend, PartialEq, Eq)]
+#[repr(C)]
+pub struct Header {
+    node_id: NodeId,
+    log_writer_id: UniqueLogWriterId,
+}
+
+impl Header {
+    pub(crate) fn new(node_id: NodeId, log_writer_id: UniqueLogWriterId) -> Self {
+        Self {
+            node_id,
+            log_writer_id,
+        }
+    }
+
+    /// Returns the [`NodeId`] of the source node that wrote the
+    /// [`LogRecord`](crate::log_record::LogRecord).
+    pub fn node_id(&self) -> NodeId {
+        self.node_id
+    }
+
+    /// Returns the [`UniqueLogWriterId`] of the source
+    /// [`LogWriter`](crate::port::log_writer::LogWriter).
+    pub fn log_writer_id(&self) -> UniqueLogWriterId {
+        self.log_writer_id
+    }
+}
@@ -10,6 +10,14 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// Work item header used by
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+pub mod pipeline;
+
+/// Log record header used by
+/// [`MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log)
+pub mod log;
+
 /// Sample header used by
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 pub mod publish_subscribe;
@@ -0,0 +1,82 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .pipeline::<u64>()
+//!     .open_or_create()?;
+//!
+//! let consumer = service.consumer_builder().create()?;
+//!
+//! while let Some(work_item) = consumer.receive()? {
+//!     println!("header: {:?}", work_item.header());
+//!     println!("sequence number: {}", work_item.header().sequence_number());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+
+use crate::{node::NodeId, port::port_identifiers::UniqueProducerId};
+
+/// Work item header used by
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+#[derive(Debug, Copy, Clone, ZeroCopySend, PartialEq, Eq)]
+#[repr(C)]
+pub struct Header {
+    node_id: NodeId,
+    producer_port_id: UniqueProducerId,
+    sequence_number: u64,
+}
+
+impl Header {
+    pub(crate) fn new(node_id: NodeId, producer_port_id: UniqueProducerId) -> Self {
+        Self {
+            node_id,
+            producer_port_id,
+            sequence_number: 0,
+        }
+    }
+
+    pub(crate) fn set_sequence_number(&mut self, sequence_number: u64) {
+        self.sequence_number = sequence_number;
+    }
+
+    /// Returns the [`NodeId`] of the source node that produced the
+    /// [`WorkItem`](crate::work_item::WorkItem).
+    pub fn node_id(&self) -> NodeId {
+        self.node_id
+    }
+
+    /// Returns the [`UniqueProducerId`] of the source
+    /// [`Producer`](crate::port::producer::Producer).
+    pub fn producer_id(&self) -> UniqueProducerId {
+        self.producer_port_id
+    }
+
+    /// Returns the sequence number of the [`WorkItem`](crate::work_item::WorkItem). Every
+    /// [`Producer`](crate::port::producer::Producer) increments its sequence number with every
+    /// sent [`WorkItem`](crate::work_item::WorkItem), starting at 0. Since every
+    /// [`Consumer`](crate::port::consumer::Consumer) receives only a part of all work items,
+    /// it can be used to restore the original order of the results.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+}
@@ -26,11 +26,14 @@
 //!
 //! while let Some(sample) = subscriber.receive()? {
 //!     println!("header: {:?}", sample.header());
+//!     println!("sequence number: {}", sample.header().sequence_number());
 //! }
 //! # Ok(())
 //! # }
 //! ```
 
+use core::time::Duration;
+
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 
@@ -44,6 +47,8 @@ pub struct Header {
     node_id: NodeId,
     publisher_port_id: UniquePublisherId,
     number_of_elements: u64,
+    sequence_number: u64,
+    send_timestamp_in_ns: u64,
 }
 
 impl Header {
@@ -56,9 +61,16 @@ impl Header {
             node_id,
             publisher_port_id,
             number_of_elements,
+            sequence_number: 0,
+            send_timestamp_in_ns: 0,
         }
     }
 
+    pub(crate) fn set_send_details(&mut self, sequence_number: u64, send_timestamp: Duration) {
+        self.sequence_number = sequence_number;
+        self.send_timestamp_in_ns = send_timestamp.as_nanos() as u64;
+    }
+
     /// Returns the [`NodeId`] of the source node that published the
     /// [`Sample`](crate::sample::Sample).
     pub fn node_id(&self) -> NodeId {
@@ -83,4 +95,27 @@ impl Header {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns the sequence number of the [`Sample`](crate::sample::Sample). Every
+    /// [`Publisher`](crate::port::publisher::Publisher) increments its sequence number
+    /// with every sent [`Sample`](crate::sample::Sample), starting at 0. A gap in the sequence
+    /// numbers of a [`Publisher`](crate::port::publisher::Publisher) means that samples were
+    /// either lost, for instance due to a safe overflow or a
+    /// [`UnableToDeliverStrategy::DiscardSample`](crate::prelude::UnableToDeliverStrategy::DiscardSample),
+    /// or did not match the sample filter of the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber). Use
+    /// [`Subscriber::number_of_missed_samples()`](crate::port::subscriber::Subscriber::number_of_missed_samples())
+    /// to distinguish both.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Returns the point in time when the [`Sample`](crate::sample::Sample) was sent as
+    /// [`Duration`] since the epoch of the monotonic clock,
+    /// see [`ClockType::Monotonic`](iceoryx2_bb_posix::clock::ClockType::Monotonic). Can be
+    /// used to measure the end-to-end latency when compared with the current time of the
+    /// same clock on the receiving side.
+    pub fn send_timestamp(&self) -> Duration {
+        Duration::from_nanos(self.send_timestamp_in_ns)
+    }
 }
@@ -10,11 +10,15 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::time::Duration;
+
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_cal::zero_copy_connection::ChannelId;
 
 use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+use crate::port::request_delivery_policy::RequestDeliveryPolicy;
 
 /// Request header used by
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
@@ -25,6 +29,8 @@ pub struct RequestHeader {
     pub(crate) channel_id: ChannelId,
     pub(crate) request_id: u64,
     pub(crate) number_of_elements: u64,
+    pub(crate) delivery_policy: RequestDeliveryPolicy,
+    pub(crate) deadline_in_ns: u64,
 }
 
 impl RequestHeader {
@@ -34,6 +40,42 @@ impl RequestHeader {
         self.client_id
     }
 
+    /// Returns the [`RequestDeliveryPolicy`] the [`Client`](crate::port::client::Client)
+    /// used to deliver the [`RequestMut`](crate::request_mut::RequestMut)
+    pub fn delivery_policy(&self) -> RequestDeliveryPolicy {
+        self.delivery_policy
+    }
+
+    /// Returns the deadline until the [`Client`](crate::port::client::Client) expects a
+    /// [`Response`](crate::response::Response) as [`Duration`] since the epoch of the
+    /// monotonic clock, see [`ClockType::Monotonic`]. Returns [`None`] when the
+    /// [`RequestMut`](crate::request_mut::RequestMut) was sent without a deadline.
+    pub fn deadline(&self) -> Option<Duration> {
+        match self.deadline_in_ns {
+            0 => None,
+            v => Some(Duration::from_nanos(v)),
+        }
+    }
+
+    pub(crate) fn set_deadline(&mut self, timeout: Duration) {
+        // a deadline of zero is reserved for requests without a deadline
+        self.deadline_in_ns = monotonic_now()
+            .saturating_add(timeout)
+            .as_nanos()
+            .clamp(1, u64::MAX as u128) as u64;
+    }
+
+    /// Returns the remaining time until the deadline is reached, [`Duration::ZERO`] when the
+    /// deadline was already missed or [`None`] when the request has no deadline.
+    pub(crate) fn time_until_deadline(&self) -> Option<Duration> {
+        self.deadline()
+            .map(|deadline| deadline.saturating_sub(monotonic_now()))
+    }
+
+    pub(crate) fn has_missed_deadline(&self) -> bool {
+        self.time_until_deadline() == Some(Duration::ZERO)
+    }
+
     /// Returns how many elements are stored inside the requests's payload.
     ///
     /// # Details when using
@@ -48,6 +90,13 @@ impl RequestHeader {
     }
 }
 
+fn monotonic_now() -> Duration {
+    // when the monotonic clock is not supported the time is zero
+    Time::now_with_clock(ClockType::Monotonic)
+        .map(|now| now.as_duration())
+        .unwrap_or_default()
+}
+
 /// Response header used by
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
 #[derive(Debug, Copy, Clone, ZeroCopySend)]
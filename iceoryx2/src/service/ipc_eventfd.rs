@@ -0,0 +1,64 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::ipc_eventfd;
+//! use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc_eventfd::Service>()?;
+//!
+//! // use `ipc_eventfd` as communication variant
+//! let event = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let listener = event.listener_builder().create()?;
+//!
+//! // the raw eventfd can be registered in any external event loop like glib or mio
+//! let raw_fd = unsafe { listener.file_descriptor().native_handle() };
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::service::dynamic_config::DynamicConfig;
+use core::fmt::Debug;
+use iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator;
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::*;
+
+#[derive(Debug, Clone)]
+pub struct Service {}
+
+impl crate::service::Service for Service {
+    type StaticStorage = static_storage::recommended::Ipc;
+    type ConfigSerializer = serialize::recommended::Recommended;
+    type DynamicStorage = dynamic_storage::recommended::Ipc<DynamicConfig>;
+    type ServiceNameHasher = hash::recommended::Recommended;
+    type SharedMemory = shared_memory::recommended::Ipc<PoolAllocator>;
+    type ResizableSharedMemory = resizable_shared_memory::recommended::Ipc<PoolAllocator>;
+    type Connection = zero_copy_connection::recommended::Ipc;
+    type Event = event::linux_eventfd::EventImpl;
+    type Monitoring = monitoring::recommended::Ipc;
+    type Reactor = reactor::recommended::Ipc;
+    type ArcThreadSafetyPolicy<T: Send + Debug> =
+        arc_sync_policy::single_threaded::SingleThreaded<T>;
+    type BlackboardMgmt<KeyType: Send + Sync + Debug + 'static> =
+        dynamic_storage::recommended::Ipc<KeyType>;
+    type BlackboardPayload = shared_memory::recommended::Ipc<BumpAllocator>;
+}
+
+impl crate::service::internal::ServiceInternal<Service> for Service {}
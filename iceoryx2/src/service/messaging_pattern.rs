@@ -45,6 +45,22 @@
 //! [`Writer`](crate::port::writer::Writer) and read by many
 //! [`Reader`](crate::port::reader::Reader)s. Updates and reads are made on a key basis, not
 //! on the entire shared memory.
+//!
+//! ### Pipeline
+//!
+//! Distributes work items from `n` [`Producer`](crate::port::producer::Producer)s to `m`
+//! [`Consumer`](crate::port::consumer::Consumer)s where every work item is delivered to exactly
+//! one [`Consumer`](crate::port::consumer::Consumer). Suitable to balance the load between
+//! multiple workers.
+//!
+//! ### Log
+//!
+//! Collects structured log records from `n` [`LogWriter`](crate::port::log_writer::LogWriter)s
+//! in `m` [`LogCollector`](crate::port::log_collector::LogCollector)s. Every
+//! [`LogCollector`](crate::port::log_collector::LogCollector) receives all records. The memory
+//! is bounded, when a [`LogCollector`](crate::port::log_collector::LogCollector) falls behind
+//! the oldest records are overridden and the
+//! [`LogWriter`](crate::port::log_writer::LogWriter)s never block.
 
 use serde::{Deserialize, Serialize};
 
@@ -72,4 +88,15 @@ pub enum MessagingPattern {
     /// writes arbitrary data to a key-value store which can be read by many
     /// [`Reader`](crate::port::reader::Reader)s.
     Blackboard,
+
+    /// Unidirectional communication pattern where the
+    /// [`Producer`](crate::port::producer::Producer) hands over every work item to exactly one
+    /// [`Consumer`](crate::port::consumer::Consumer). The load is distributed between all
+    /// connected [`Consumer`](crate::port::consumer::Consumer)s.
+    Pipeline,
+
+    /// Many-to-one communication pattern where many
+    /// [`LogWriter`](crate::port::log_writer::LogWriter)s write structured log records that are
+    /// drained by [`LogCollector`](crate::port::log_collector::LogCollector)s with bounded memory.
+    Log,
 }
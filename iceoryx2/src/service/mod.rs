@@ -250,6 +250,18 @@ pub mod ipc;
 /// [`Send`] but at the cost of an additional internal mutex.
 pub mod ipc_threadsafe;
 
+/// A configuration when communicating between different processes using posix mechanisms where
+/// the event [`Listener`](crate::port::listener::Listener) is backed by a Linux eventfd so that
+/// it can be attached to external event loops.
+#[cfg(target_os = "linux")]
+pub mod ipc_eventfd;
+
+/// A configuration when communicating between different processes using posix mechanisms where
+/// the event [`Listener`](crate::port::listener::Listener) waits on a Linux futex that lives in
+/// the shared memory of the event for a lower notification latency.
+#[cfg(target_os = "linux")]
+pub mod ipc_futex;
+
 pub(crate) mod config_scheme;
 pub(crate) mod naming_scheme;
 
@@ -719,6 +731,46 @@ pub mod internal {
                     }
                     UniquePortId::Reader(ref _id) => {}
                     UniquePortId::Writer(ref _id) => {}
+                    UniquePortId::Producer(ref id) => {
+                        if remove_sender_connection_and_data_segment::<S>(
+                            id.value(),
+                            config,
+                            &origin,
+                            "producer",
+                        )
+                        .is_err()
+                        {
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
+                    UniquePortId::Consumer(ref id) => {
+                        if let Err(e) = unsafe {
+                            remove_receiver_port_from_all_connections::<S>(id.value(), config)
+                        } {
+                            debug!(from origin, "Failed to remove the consumer ({:?}) from all of its connections ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
+                    UniquePortId::LogWriter(ref id) => {
+                        if remove_sender_connection_and_data_segment::<S>(
+                            id.value(),
+                            config,
+                            &origin,
+                            "log writer",
+                        )
+                        .is_err()
+                        {
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
+                    UniquePortId::LogCollector(ref id) => {
+                        if let Err(e) = unsafe {
+                            remove_receiver_port_from_all_connections::<S>(id.value(), config)
+                        } {
+                            debug!(from origin, "Failed to remove the log collector ({:?}) from all of its connections ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
                 };
 
                 trace!(from origin, "Remove port {:?} from service.", port_id);
@@ -11,6 +11,8 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::port::port_identifiers::UniqueListenerId;
+use crate::service::service_id::ServiceId;
+use crate::service::service_name::ServiceName;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_system_types::file_name::FileName;
@@ -58,3 +60,34 @@ pub(crate) fn blackboard_name(service_id: &str) -> FileName {
 
     fatal_panic!(from origin, when FileName::new(service_id.as_bytes()), "{}", msg)
 }
+
+pub(crate) fn blackboard_entry_event_name(
+    service_id: &ServiceId,
+    entry_index: usize,
+    entry_generation: u64,
+) -> ServiceName {
+    let msg = "The system does not support the required service name length for the event service of the blackboard entry.";
+    let origin = "blackboard_entry_event_name()";
+
+    fatal_panic!(from origin,
+                 when ServiceName::__internal_new_prefixed(&format!("blackboard/{}/{}.{}", service_id.as_str(), entry_index, entry_generation)),
+                 "{}", msg)
+}
+
+pub(crate) fn blackboard_key_set_event_name(service_id: &ServiceId) -> ServiceName {
+    let msg = "The system does not support the required service name length for the event service of the blackboard key set.";
+    let origin = "blackboard_key_set_event_name()";
+
+    fatal_panic!(from origin,
+                 when ServiceName::__internal_new_prefixed(&format!("blackboard/{}/key_set", service_id.as_str())),
+                 "{}", msg)
+}
+
+pub(crate) fn notify_on_send_event_name(service_id: &ServiceId, channel: &str) -> ServiceName {
+    let msg = "The system does not support the required service name length for the notify on send event service.";
+    let origin = "notify_on_send_event_name()";
+
+    fatal_panic!(from origin,
+                 when ServiceName::__internal_new_prefixed(&format!("notify_on_send/{}/{}", service_id.as_str(), channel)),
+                 "{}", msg)
+}
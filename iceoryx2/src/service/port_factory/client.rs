@@ -31,7 +31,10 @@
 
 use super::request_response::PortFactory;
 use crate::{
-    port::{client::Client, DegradationAction, DegradationCallback},
+    port::{
+        client::Client, request_delivery_policy::RequestDeliveryPolicy, DegradationAction,
+        DegradationCallback,
+    },
     prelude::UnableToDeliverStrategy,
     service,
 };
@@ -55,6 +58,10 @@ pub enum ClientCreateError {
     /// [`ArcSyncPolicy`](iceoryx2_cal::arc_sync_policy::ArcSyncPolicy) defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The [`Service`](crate::service::Service) has notify on send enabled but the
+    /// underlying event services, that are used to exchange signals with the
+    /// [`Server`](crate::port::server::Server)s, could not be opened or created.
+    UnableToCreateNotificationChannel,
 }
 
 impl core::fmt::Display for ClientCreateError {
@@ -70,6 +77,7 @@ pub(crate) struct LocalClientConfig {
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
     pub(crate) initial_max_slice_len: usize,
     pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) delivery_policy: RequestDeliveryPolicy,
 }
 
 /// Factory to create a new [`Client`] port/endpoint for
@@ -165,6 +173,7 @@ impl<
                 unable_to_deliver_strategy: defs.client_unable_to_deliver_strategy,
                 initial_max_slice_len: 1,
                 allocation_strategy: AllocationStrategy::Static,
+                delivery_policy: RequestDeliveryPolicy::Broadcast,
             },
             request_degradation_callback: None,
             response_degradation_callback: None,
@@ -181,6 +190,15 @@ impl<
         self
     }
 
+    /// Sets the [`RequestDeliveryPolicy`] which defines to which
+    /// [`Server`](crate::port::server::Server)s the [`Client`] delivers a
+    /// [`RequestMut`](crate::request_mut::RequestMut). By default, every request is
+    /// broadcasted to all connected [`Server`](crate::port::server::Server)s.
+    pub fn delivery_policy(mut self, value: RequestDeliveryPolicy) -> Self {
+        self.config.delivery_policy = value;
+        self
+    }
+
     /// Sets the [`DegradationCallback`] for sending [`RequestMut`](crate::request_mut::RequestMut)
     /// from the [`Client`]. Whenever a connection to a
     /// [`Server`](crate::port::server::Server) is corrupted or it seems to be dead, this callback
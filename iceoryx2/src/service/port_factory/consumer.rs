@@ -0,0 +1,111 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pipeline = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .pipeline::<u64>()
+//!     .open_or_create()?;
+//!
+//! let consumer = pipeline.consumer_builder()
+//!                     .buffer_size(1)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use crate::{
+    port::{
+        consumer::{Consumer, ConsumerCreateError},
+        DegradationAction, DegradationCallback,
+    },
+    service,
+};
+
+use super::pipeline::PortFactory;
+
+#[derive(Debug)]
+pub(crate) struct ConsumerConfig {
+    pub(crate) buffer_size: Option<usize>,
+    pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
+}
+
+/// Factory to create a new [`Consumer`] port/endpoint for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryConsumer<'factory, Service: service::Service, Payload: Debug + ZeroCopySend> {
+    config: ConsumerConfig,
+    pub(crate) factory: &'factory PortFactory<Service, Payload>,
+}
+
+unsafe impl<Service: service::Service, Payload: Debug + ZeroCopySend> Send
+    for PortFactoryConsumer<'_, Service, Payload>
+{
+}
+
+impl<'factory, Service: service::Service, Payload: Debug + ZeroCopySend>
+    PortFactoryConsumer<'factory, Service, Payload>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, Payload>) -> Self {
+        Self {
+            config: ConsumerConfig {
+                buffer_size: None,
+                degradation_callback: None,
+            },
+            factory,
+        }
+    }
+
+    /// Defines the buffer size of the [`Consumer`]. Smallest possible value is `1`.
+    pub fn buffer_size(mut self, value: usize) -> Self {
+        self.config.buffer_size = Some(value.max(1));
+        self
+    }
+
+    /// Sets the [`DegradationCallback`] of the [`Consumer`]. Whenever a connection to a
+    /// [`crate::port::producer::Producer`] is corrupted or it seems to be dead, this callback
+    /// is called and depending on the returned [`DegradationAction`] measures will be taken.
+    pub fn set_degradation_callback<
+        F: Fn(&service::static_config::StaticConfig, u128, u128) -> DegradationAction + 'static,
+    >(
+        mut self,
+        callback: Option<F>,
+    ) -> Self {
+        match callback {
+            Some(c) => self.config.degradation_callback = Some(DegradationCallback::new(c)),
+            None => self.config.degradation_callback = None,
+        }
+
+        self
+    }
+
+    /// Creates a new [`Consumer`] or returns a [`ConsumerCreateError`] on failure.
+    pub fn create(self) -> Result<Consumer<Service, Payload>, ConsumerCreateError> {
+        let origin = format!("{self:?}");
+        Ok(
+            fail!(from origin, when Consumer::new(self.factory.service.clone(), self.factory.service.static_config.pipeline(), self.config),
+                "Failed to create new Consumer port."),
+        )
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let log = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .log()
+//!     .open_or_create()?;
+//!
+//! println!("name:                         {:?}", log.name());
+//! println!("service id:                   {:?}", log.service_id());
+//! println!("max writers:                  {:?}", log.static_config().max_writers());
+//! println!("max collectors:               {:?}", log.static_config().max_collectors());
+//! println!("collector buffer size:        {:?}", log.static_config().collector_max_buffer_size());
+//! println!("number of active writers:     {:?}", log.dynamic_config().number_of_writers());
+//! println!("number of active collectors:  {:?}", log.dynamic_config().number_of_collectors());
+//!
+//! let writer = log.writer_builder().create()?;
+//! let collector = log.collector_builder().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+extern crate alloc;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::node::NodeListFailure;
+use crate::service::attribute::AttributeSet;
+use crate::service::service_id::ServiceId;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dynamic_config, static_config, NoResource, ServiceState};
+use alloc::sync::Arc;
+
+use super::nodes;
+use super::{log_collector::PortFactoryLogCollector, log_writer::PortFactoryLogWriter};
+
+/// The factory for
+/// [`MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::log_writer::LogWriter`]
+/// or [`crate::port::log_collector::LogCollector`] ports.
+#[derive(Debug)]
+pub struct PortFactory<Service: service::Service> {
+    pub(crate) service: Arc<ServiceState<Service, NoResource>>,
+}
+
+unsafe impl<Service: service::Service> Send for PortFactory<Service> {}
+unsafe impl<Service: service::Service> Sync for PortFactory<Service> {}
+
+impl<Service: service::Service> crate::service::port_factory::PortFactory for PortFactory<Service> {
+    type Service = Service;
+    type StaticConfig = static_config::log::StaticConfig;
+    type DynamicConfig = dynamic_config::log::DynamicConfig;
+
+    fn name(&self) -> &ServiceName {
+        self.service.static_config.name()
+    }
+
+    fn service_id(&self) -> &ServiceId {
+        self.service.static_config.service_id()
+    }
+
+    fn attributes(&self) -> &AttributeSet {
+        self.service.static_config.attributes()
+    }
+
+    fn static_config(&self) -> &static_config::log::StaticConfig {
+        self.service.static_config.log()
+    }
+
+    fn dynamic_config(&self) -> &dynamic_config::log::DynamicConfig {
+        self.service.dynamic_storage.get().log()
+    }
+
+    fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
+        &self,
+        callback: F,
+    ) -> Result<(), NodeListFailure> {
+        nodes(
+            self.service.dynamic_storage.get(),
+            self.service.shared_node.config(),
+            callback,
+        )
+    }
+}
+
+impl<Service: service::Service> PortFactory<Service> {
+    pub(crate) fn new(service: ServiceState<Service, NoResource>) -> Self {
+        Self {
+            service: Arc::new(service),
+        }
+    }
+
+    /// Returns a [`PortFactoryLogCollector`] to create a new
+    /// [`crate::port::log_collector::LogCollector`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let log = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .log()
+    ///     .open_or_create()?;
+    ///
+    /// let collector = log.collector_builder().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn collector_builder(&self) -> PortFactoryLogCollector<'_, Service> {
+        PortFactoryLogCollector::new(self)
+    }
+
+    /// Returns a [`PortFactoryLogWriter`] to create a new
+    /// [`crate::port::log_writer::LogWriter`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let log = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .log()
+    ///     .open_or_create()?;
+    ///
+    /// let writer = log.writer_builder().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn writer_builder(&self) -> PortFactoryLogWriter<'_, Service> {
+        PortFactoryLogWriter::new(self)
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let log = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .log()
+//!     .open_or_create()?;
+//!
+//! let collector = log.collector_builder()
+//!                     .buffer_size(1)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_log::fail;
+
+use crate::{
+    port::{
+        log_collector::{LogCollector, LogCollectorCreateError},
+        DegradationAction, DegradationCallback,
+    },
+    service,
+};
+
+use super::log::PortFactory;
+
+#[derive(Debug)]
+pub(crate) struct LogCollectorConfig {
+    pub(crate) buffer_size: Option<usize>,
+    pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
+}
+
+/// Factory to create a new [`LogCollector`] port/endpoint for
+/// [`MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryLogCollector<'factory, Service: service::Service> {
+    config: LogCollectorConfig,
+    pub(crate) factory: &'factory PortFactory<Service>,
+}
+
+unsafe impl<Service: service::Service> Send for PortFactoryLogCollector<'_, Service> {}
+
+impl<'factory, Service: service::Service> PortFactoryLogCollector<'factory, Service> {
+    pub(crate) fn new(factory: &'factory PortFactory<Service>) -> Self {
+        Self {
+            config: LogCollectorConfig {
+                buffer_size: None,
+                degradation_callback: None,
+            },
+            factory,
+        }
+    }
+
+    /// Defines the buffer size of the [`LogCollector`]. Smallest possible value is `1`.
+    pub fn buffer_size(mut self, value: usize) -> Self {
+        self.config.buffer_size = Some(value.max(1));
+        self
+    }
+
+    /// Sets the [`DegradationCallback`] of the [`LogCollector`]. Whenever a connection to a
+    /// [`crate::port::log_writer::LogWriter`] is corrupted or it seems to be dead, this callback
+    /// is called and depending on the returned [`DegradationAction`] measures will be taken.
+    pub fn set_degradation_callback<
+        F: Fn(&service::static_config::StaticConfig, u128, u128) -> DegradationAction + 'static,
+    >(
+        mut self,
+        callback: Option<F>,
+    ) -> Self {
+        match callback {
+            Some(c) => self.config.degradation_callback = Some(DegradationCallback::new(c)),
+            None => self.config.degradation_callback = None,
+        }
+
+        self
+    }
+
+    /// Creates a new [`LogCollector`] or returns a [`LogCollectorCreateError`] on failure.
+    pub fn create(self) -> Result<LogCollector<Service>, LogCollectorCreateError> {
+        let origin = format!("{self:?}");
+        Ok(
+            fail!(from origin, when LogCollector::new(self.factory.service.clone(), self.factory.service.static_config.log(), self.config),
+                "Failed to create new LogCollector port."),
+        )
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let log = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .log()
+//!     .open_or_create()?;
+//!
+//! let writer = log.writer_builder().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_log::fail;
+
+use super::log::PortFactory;
+use crate::{
+    port::log_writer::{LogWriter, LogWriterCreateError},
+    service,
+};
+
+/// Factory to create a new [`LogWriter`] port/endpoint for
+/// [`MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryLogWriter<'factory, Service: service::Service> {
+    pub(crate) factory: &'factory PortFactory<Service>,
+}
+
+unsafe impl<Service: service::Service> Send for PortFactoryLogWriter<'_, Service> {}
+
+impl<'factory, Service: service::Service> PortFactoryLogWriter<'factory, Service> {
+    pub(crate) fn new(factory: &'factory PortFactory<Service>) -> Self {
+        Self { factory }
+    }
+
+    /// Creates a new [`LogWriter`] or returns a [`LogWriterCreateError`] on failure.
+    pub fn create(self) -> Result<LogWriter<Service>, LogWriterCreateError> {
+        let origin = format!("{self:?}");
+        Ok(
+            fail!(from origin, when LogWriter::new(self.factory.service.clone(), self.factory.service.static_config.log()),
+                "Failed to create new LogWriter port."),
+        )
+    }
+}
@@ -46,6 +46,28 @@ pub mod listener;
 /// Factory to create a [`Notifier`](crate::port::notifier::Notifier)
 pub mod notifier;
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log) based
+/// communication and to acquire static and dynamic service information
+pub mod log;
+
+/// Factory to create a [`LogCollector`](crate::port::log_collector::LogCollector)
+pub mod log_collector;
+
+/// Factory to create a [`LogWriter`](crate::port::log_writer::LogWriter)
+pub mod log_writer;
+
+/// Factory to create the endpoints of
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication and to acquire static and dynamic service information
+pub mod pipeline;
+
+/// Factory to create a [`Consumer`](crate::port::consumer::Consumer)
+pub mod consumer;
+
+/// Factory to create a [`Producer`](crate::port::producer::Producer)
+pub mod producer;
+
 /// Factory to create the endpoints of
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
 /// communication and to acquire static and dynamic service information
@@ -0,0 +1,173 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pipeline = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .pipeline::<u64>()
+//!     .open_or_create()?;
+//!
+//! println!("name:                         {:?}", pipeline.name());
+//! println!("service id:                   {:?}", pipeline.service_id());
+//! println!("type details:                 {:?}", pipeline.static_config().message_type_details());
+//! println!("max producers:                {:?}", pipeline.static_config().max_producers());
+//! println!("max consumers:                {:?}", pipeline.static_config().max_consumers());
+//! println!("consumer buffer size:         {:?}", pipeline.static_config().consumer_max_buffer_size());
+//! println!("consumer max borrowed items:  {:?}", pipeline.static_config().consumer_max_borrowed_items());
+//! println!("number of active producers:   {:?}", pipeline.dynamic_config().number_of_producers());
+//! println!("number of active consumers:   {:?}", pipeline.dynamic_config().number_of_consumers());
+//!
+//! let producer = pipeline.producer_builder().create()?;
+//! let consumer = pipeline.consumer_builder().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+extern crate alloc;
+
+use core::{fmt::Debug, marker::PhantomData};
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::node::NodeListFailure;
+use crate::service::attribute::AttributeSet;
+use crate::service::service_id::ServiceId;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dynamic_config, static_config, NoResource, ServiceState};
+use alloc::sync::Arc;
+
+use super::nodes;
+use super::{consumer::PortFactoryConsumer, producer::PortFactoryProducer};
+
+/// The factory for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::producer::Producer`]
+/// or [`crate::port::consumer::Consumer`] ports.
+#[derive(Debug)]
+pub struct PortFactory<Service: service::Service, Payload: Debug + ZeroCopySend> {
+    pub(crate) service: Arc<ServiceState<Service, NoResource>>,
+    _payload: PhantomData<Payload>,
+}
+
+unsafe impl<Service: service::Service, Payload: Debug + ZeroCopySend> Send
+    for PortFactory<Service, Payload>
+{
+}
+unsafe impl<Service: service::Service, Payload: Debug + ZeroCopySend> Sync
+    for PortFactory<Service, Payload>
+{
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend>
+    crate::service::port_factory::PortFactory for PortFactory<Service, Payload>
+{
+    type Service = Service;
+    type StaticConfig = static_config::pipeline::StaticConfig;
+    type DynamicConfig = dynamic_config::pipeline::DynamicConfig;
+
+    fn name(&self) -> &ServiceName {
+        self.service.static_config.name()
+    }
+
+    fn service_id(&self) -> &ServiceId {
+        self.service.static_config.service_id()
+    }
+
+    fn attributes(&self) -> &AttributeSet {
+        self.service.static_config.attributes()
+    }
+
+    fn static_config(&self) -> &static_config::pipeline::StaticConfig {
+        self.service.static_config.pipeline()
+    }
+
+    fn dynamic_config(&self) -> &dynamic_config::pipeline::DynamicConfig {
+        self.service.dynamic_storage.get().pipeline()
+    }
+
+    fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
+        &self,
+        callback: F,
+    ) -> Result<(), NodeListFailure> {
+        nodes(
+            self.service.dynamic_storage.get(),
+            self.service.shared_node.config(),
+            callback,
+        )
+    }
+}
+
+impl<Service: service::Service, Payload: Debug + ZeroCopySend> PortFactory<Service, Payload> {
+    pub(crate) fn new(service: ServiceState<Service, NoResource>) -> Self {
+        Self {
+            service: Arc::new(service),
+            _payload: PhantomData,
+        }
+    }
+
+    /// Returns a [`PortFactoryConsumer`] to create a new
+    /// [`crate::port::consumer::Consumer`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pipeline = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .pipeline::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let consumer = pipeline.consumer_builder().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn consumer_builder(&self) -> PortFactoryConsumer<'_, Service, Payload> {
+        PortFactoryConsumer::new(self)
+    }
+
+    /// Returns a [`PortFactoryProducer`] to create a new
+    /// [`crate::port::producer::Producer`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pipeline = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .pipeline::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let producer = pipeline.producer_builder()
+    ///                     .max_loaned_items(6)
+    ///                     .distribution_policy(DistributionPolicy::RoundRobin)
+    ///                     .create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn producer_builder(&self) -> PortFactoryProducer<'_, Service, Payload> {
+        PortFactoryProducer::new(self)
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pipeline = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .pipeline::<u64>()
+//!     .open_or_create()?;
+//!
+//! let producer = pipeline.producer_builder()
+//!                     .max_loaned_items(6)
+//!                     .distribution_policy(DistributionPolicy::LeastLoaded)
+//!                     .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use super::pipeline::PortFactory;
+use crate::{
+    port::{
+        distribution_policy::DistributionPolicy,
+        producer::{Producer, ProducerCreateError},
+        unable_to_deliver_strategy::UnableToDeliverStrategy,
+    },
+    service,
+};
+
+#[derive(Debug)]
+pub(crate) struct LocalProducerConfig {
+    pub(crate) max_loaned_items: usize,
+    pub(crate) distribution_policy: DistributionPolicy,
+    pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
+}
+
+/// Factory to create a new [`Producer`] port/endpoint for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryProducer<'factory, Service: service::Service, Payload: Debug + ZeroCopySend> {
+    config: LocalProducerConfig,
+    pub(crate) factory: &'factory PortFactory<Service, Payload>,
+}
+
+unsafe impl<Service: service::Service, Payload: Debug + ZeroCopySend> Send
+    for PortFactoryProducer<'_, Service, Payload>
+{
+}
+
+impl<'factory, Service: service::Service, Payload: Debug + ZeroCopySend>
+    PortFactoryProducer<'factory, Service, Payload>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, Payload>) -> Self {
+        let defaults = &factory.service.shared_node.config().defaults.pipeline;
+        Self {
+            config: LocalProducerConfig {
+                max_loaned_items: defaults.producer_max_loaned_items,
+                distribution_policy: defaults.distribution_policy,
+                unable_to_deliver_strategy: defaults.unable_to_deliver_strategy,
+            },
+            factory,
+        }
+    }
+
+    /// Defines how many [`crate::work_item_mut::WorkItemMut`] the [`Producer`] can loan with
+    /// [`Producer::loan()`] or [`Producer::loan_uninit()`] in parallel.
+    pub fn max_loaned_items(mut self, value: usize) -> Self {
+        self.config.max_loaned_items = value;
+        self
+    }
+
+    /// Sets the [`DistributionPolicy`] that decides which
+    /// [`Consumer`](crate::port::consumer::Consumer) receives the next work item.
+    pub fn distribution_policy(mut self, value: DistributionPolicy) -> Self {
+        self.config.distribution_policy = value;
+        self
+    }
+
+    /// Sets the [`UnableToDeliverStrategy`] that is applied when the buffers of all
+    /// [`Consumer`](crate::port::consumer::Consumer)s are full.
+    pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
+        self.config.unable_to_deliver_strategy = value;
+        self
+    }
+
+    /// Creates a new [`Producer`] or returns a [`ProducerCreateError`] on failure.
+    pub fn create(self) -> Result<Producer<Service, Payload>, ProducerCreateError> {
+        let origin = format!("{self:?}");
+        Ok(
+            fail!(from origin, when Producer::new(self.factory.service.clone(), self.factory.service.static_config.pipeline(), self.config),
+                "Failed to create new Producer port."),
+        )
+    }
+}
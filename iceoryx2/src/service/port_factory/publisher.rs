@@ -73,6 +73,7 @@ use crate::{
 #[derive(Debug)]
 pub(crate) struct LocalPublisherConfig {
     pub(crate) max_loaned_samples: usize,
+    pub(crate) max_resend_handles: usize,
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
     pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
     pub(crate) initial_max_slice_len: usize,
@@ -115,6 +116,7 @@ impl<
         Self {
             config: LocalPublisherConfig {
                 max_loaned_samples: self.config.max_loaned_samples,
+                max_resend_handles: self.config.max_resend_handles,
                 unable_to_deliver_strategy: self.config.unable_to_deliver_strategy,
                 degradation_callback: None,
                 initial_max_slice_len: self.config.initial_max_slice_len,
@@ -145,6 +147,7 @@ impl<
                     .defaults
                     .publish_subscribe
                     .publisher_max_loaned_samples,
+                max_resend_handles: 0,
                 unable_to_deliver_strategy: factory
                     .service
                     .shared_node
@@ -165,6 +168,17 @@ impl<
         self
     }
 
+    /// Defines how many [`ResendHandle`](crate::resend_handle::ResendHandle)s, acquired with
+    /// [`SampleMut::send_with_resend_handle()`](crate::sample_mut::SampleMut::send_with_resend_handle()),
+    /// the [`Publisher`] can hold in parallel. Every [`ResendHandle`](crate::resend_handle::ResendHandle)
+    /// keeps a sample alive, therefore the data segment of the [`Publisher`] is enlarged
+    /// accordingly. By default, no [`ResendHandle`](crate::resend_handle::ResendHandle)s are
+    /// supported.
+    pub fn max_resend_handles(mut self, value: usize) -> Self {
+        self.config.max_resend_handles = value;
+        self
+    }
+
     /// Sets the [`UnableToDeliverStrategy`].
     pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
         self.config.unable_to_deliver_strategy = value;
@@ -64,6 +64,10 @@ pub enum ServerCreateError {
     /// [`ArcSyncPolicy`](iceoryx2_cal::arc_sync_policy::ArcSyncPolicy) defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The [`Service`](crate::service::Service) has notify on send enabled but the
+    /// underlying event services, that are used to exchange signals with the
+    /// [`Client`](crate::port::client::Client)s, could not be opened or created.
+    UnableToCreateNotificationChannel,
 }
 
 impl core::fmt::Display for ServerCreateError {
@@ -14,6 +14,7 @@
 //!
 //! ```
 //! use iceoryx2::prelude::*;
+//! use iceoryx2::port::sample_filter::SampleFilter;
 //!
 //! # fn main() -> Result<(), Box<dyn core::error::Error>> {
 //! let node = NodeBuilder::new().create::<ipc::Service>()?;
@@ -24,6 +25,11 @@
 //! let subscriber = pubsub.subscriber_builder()
 //!                     .create()?;
 //!
+//! // a subscriber that receives only even numbers
+//! let filtered_subscriber = pubsub.subscriber_builder()
+//!                     .filter(SampleFilter::payload(0, &[0])?.with_mask(&[1])?)
+//!                     .create()?;
+//!
 //! # Ok(())
 //! # }
 //! ```
@@ -35,6 +41,7 @@ use iceoryx2_bb_log::fail;
 
 use crate::{
     port::{
+        sample_filter::SampleFilter,
         subscriber::{Subscriber, SubscriberCreateError},
         DegradationAction, DegradationCallback,
     },
@@ -47,6 +54,7 @@ use super::publish_subscribe::PortFactory;
 pub(crate) struct SubscriberConfig {
     pub(crate) buffer_size: Option<usize>,
     pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
+    pub(crate) filter: SampleFilter,
 }
 
 /// Factory to create a new [`Subscriber`] port/endpoint for
@@ -87,6 +95,7 @@ impl<
             config: SubscriberConfig {
                 buffer_size: self.config.buffer_size,
                 degradation_callback: None,
+                filter: self.config.filter,
             },
             factory: self.factory,
         }
@@ -97,6 +106,7 @@ impl<
             config: SubscriberConfig {
                 buffer_size: None,
                 degradation_callback: None,
+                filter: SampleFilter::default(),
             },
             factory,
         }
@@ -125,6 +135,15 @@ impl<
         self
     }
 
+    /// Sets the [`SampleFilter`] of the [`Subscriber`]. Only [`Sample`](crate::sample::Sample)s
+    /// that match the filter are delivered to the [`Subscriber`]. The filter is evaluated by
+    /// the [`Publisher`](crate::port::publisher::Publisher) before the sample is delivered,
+    /// therefore non-matching samples never occupy the buffer of the [`Subscriber`].
+    pub fn filter(mut self, filter: SampleFilter) -> Self {
+        self.config.filter = filter;
+        self
+    }
+
     /// Creates a new [`Subscriber`] or returns a [`SubscriberCreateError`] on failure.
     pub fn create(
         self,
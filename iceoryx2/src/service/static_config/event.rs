@@ -28,6 +28,7 @@
 //! println!("notifier created event:       {:?}", event.static_config().notifier_created_event());
 //! println!("notifier dropped event:       {:?}", event.static_config().notifier_dropped_event());
 //! println!("notifier dead event:          {:?}", event.static_config().notifier_dead_event());
+//! println!("notification payload size:    {:?}", event.static_config().notification_payload_size());
 //!
 //! # Ok(())
 //! # }
@@ -61,6 +62,7 @@ pub struct StaticConfig {
     pub(crate) notifier_created_event: Option<usize>,
     pub(crate) notifier_dropped_event: Option<usize>,
     pub(crate) notifier_dead_event: Option<usize>,
+    pub(crate) notification_payload_size: Option<usize>,
 }
 
 impl StaticConfig {
@@ -77,6 +79,7 @@ impl StaticConfig {
             notifier_created_event: config.defaults.event.notifier_created_event,
             notifier_dropped_event: config.defaults.event.notifier_dropped_event,
             notifier_dead_event: config.defaults.event.notifier_dead_event,
+            notification_payload_size: None,
         }
     }
 
@@ -124,4 +127,12 @@ impl StaticConfig {
     pub fn notifier_dead_event(&self) -> Option<EventId> {
         self.notifier_dead_event.map(EventId::new)
     }
+
+    /// Returns the maximum size of the user payload in bytes that a
+    /// [`Notifier`](crate::port::notifier::Notifier) can attach to a notification. If it
+    /// returns [`None`], notifications do not carry any details and only the [`EventId`] is
+    /// delivered to the [`Listener`](crate::port::listener::Listener).
+    pub fn notification_payload_size(&self) -> Option<usize> {
+        self.notification_payload_size
+    }
 }
@@ -0,0 +1,95 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let log = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .log()
+//!     .open_or_create()?;
+//!
+//! println!("max writers:                  {:?}", log.static_config().max_writers());
+//! println!("max collectors:               {:?}", log.static_config().max_collectors());
+//! println!("collector buffer size:        {:?}", log.static_config().collector_max_buffer_size());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use super::message_type_details::MessageTypeDetails;
+use crate::config;
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use serde::{Deserialize, Serialize};
+
+/// The static configuration of an
+/// [`MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log)
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`Service`](crate::service::Service).
+#[derive(Debug, Clone, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize)]
+#[repr(C)]
+pub struct StaticConfig {
+    pub(crate) max_writers: usize,
+    pub(crate) max_collectors: usize,
+    pub(crate) max_nodes: usize,
+    pub(crate) collector_max_buffer_size: usize,
+    pub(crate) message_type_details: MessageTypeDetails,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            max_writers: config.defaults.log.max_writers,
+            max_collectors: config.defaults.log.max_collectors,
+            max_nodes: config.defaults.log.max_nodes,
+            collector_max_buffer_size: config.defaults.log.collector_max_buffer_size,
+            message_type_details: MessageTypeDetails::default(),
+        }
+    }
+
+    pub(crate) fn required_amount_of_records_per_data_segment(&self) -> usize {
+        // a collector copies a record out of the shared memory and releases it immediately,
+        // therefore it borrows at most one record; the log writer loans at most one record
+        self.max_collectors * (self.collector_max_buffer_size + 1) + 1
+    }
+
+    /// Returns the maximum supported amount of [`Node`](crate::node::Node)s that can open the
+    /// [`Service`](crate::service::Service) in parallel.
+    pub fn max_nodes(&self) -> usize {
+        self.max_nodes
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::log_writer::LogWriter`] ports
+    pub fn max_writers(&self) -> usize {
+        self.max_writers
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::log_collector::LogCollector`] ports
+    pub fn max_collectors(&self) -> usize {
+        self.max_collectors
+    }
+
+    /// Returns the maximum supported buffer size for [`crate::port::log_collector::LogCollector`]
+    /// port
+    pub fn collector_max_buffer_size(&self) -> usize {
+        self.collector_max_buffer_size
+    }
+
+    /// Returns the type details of the [`crate::service::Service`].
+    pub fn message_type_details(&self) -> &MessageTypeDetails {
+        &self.message_type_details
+    }
+}
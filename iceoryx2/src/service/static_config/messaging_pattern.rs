@@ -15,6 +15,8 @@ use core::fmt::Display;
 
 use crate::service::static_config::blackboard;
 use crate::service::static_config::event;
+use crate::service::static_config::log;
+use crate::service::static_config::pipeline;
 use crate::service::static_config::publish_subscribe;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -49,6 +51,14 @@ pub enum MessagingPattern {
     /// Stores the static config of the
     /// [`service::MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
     Blackboard(blackboard::StaticConfig),
+
+    /// Stores the static config of the
+    /// [`service::MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+    Pipeline(pipeline::StaticConfig),
+
+    /// Stores the static config of the
+    /// [`service::MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log)
+    Log(log::StaticConfig),
 }
 
 impl Display for MessagingPattern {
@@ -58,6 +68,8 @@ impl Display for MessagingPattern {
             MessagingPattern::Event(_) => write!(f, "Event"),
             MessagingPattern::PublishSubscribe(_) => write!(f, "PublishSubscribe"),
             MessagingPattern::Blackboard(_) => write!(f, "Blackboard"),
+            MessagingPattern::Pipeline(_) => write!(f, "Pipeline"),
+            MessagingPattern::Log(_) => write!(f, "Log"),
         }
     }
 }
@@ -68,6 +80,21 @@ impl MessagingPattern {
         core::mem::discriminant(self) == core::mem::discriminant(rhs)
     }
 
+    /// Returns the [`service::MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
+    /// the static config belongs to.
+    pub(crate) fn kind(&self) -> crate::service::messaging_pattern::MessagingPattern {
+        use crate::service::messaging_pattern::MessagingPattern as Kind;
+
+        match self {
+            MessagingPattern::RequestResponse(_) => Kind::RequestResponse,
+            MessagingPattern::Event(_) => Kind::Event,
+            MessagingPattern::PublishSubscribe(_) => Kind::PublishSubscribe,
+            MessagingPattern::Blackboard(_) => Kind::Blackboard,
+            MessagingPattern::Pipeline(_) => Kind::Pipeline,
+            MessagingPattern::Log(_) => Kind::Log,
+        }
+    }
+
     /// # Safety
     ///
     ///  * User must ensure that publish subscribe is stored inside
@@ -130,6 +157,8 @@ mod tests {
             publish_subscribe: cfg.defaults.publish_subscribe.clone(),
             event: cfg.defaults.event.clone(),
             blackboard: cfg.defaults.blackboard.clone(),
+            pipeline: cfg.defaults.pipeline.clone(),
+            log: cfg.defaults.log.clone(),
         };
         new_defaults.event.event_id_max_value -= 1;
         new_defaults.publish_subscribe.max_nodes -= 1;
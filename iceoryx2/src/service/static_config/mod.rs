@@ -30,6 +30,16 @@ pub mod messaging_pattern;
 
 pub mod blackboard;
 
+/// The static service configuration of an
+/// [`MessagingPattern::Pipeline`]
+/// based service.
+pub mod pipeline;
+
+/// The static service configuration of an
+/// [`MessagingPattern::Log`]
+/// based service.
+pub mod log;
+
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fatal_panic;
@@ -119,6 +129,38 @@ impl StaticConfig {
         }
     }
 
+    pub(crate) fn new_pipeline<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        let messaging_pattern = MessagingPattern::Pipeline(pipeline::StaticConfig::new(config));
+        Self {
+            service_id: ServiceId::new::<Hasher>(
+                service_name,
+                crate::service::messaging_pattern::MessagingPattern::Pipeline,
+            ),
+            service_name: service_name.clone(),
+            messaging_pattern,
+            attributes: AttributeSet::new(),
+        }
+    }
+
+    pub(crate) fn new_log<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        let messaging_pattern = MessagingPattern::Log(log::StaticConfig::new(config));
+        Self {
+            service_id: ServiceId::new::<Hasher>(
+                service_name,
+                crate::service::messaging_pattern::MessagingPattern::Log,
+            ),
+            service_name: service_name.clone(),
+            messaging_pattern,
+            attributes: AttributeSet::new(),
+        }
+    }
+
     /// Returns the attributes of the [`crate::service::Service`]
     pub fn attributes(&self) -> &AttributeSet {
         &self.attributes
@@ -139,6 +181,17 @@ impl StaticConfig {
         &self.messaging_pattern
     }
 
+    /// Returns a copy of the [`StaticConfig`] with a different [`ServiceName`] and the
+    /// [`ServiceId`] that belongs to it. Used by tunnels to announce a service under a remapped
+    /// name.
+    #[doc(hidden)]
+    pub fn __internal_with_name<Hasher: Hash>(&self, service_name: &ServiceName) -> Self {
+        let mut config = self.clone();
+        config.service_id = ServiceId::new::<Hasher>(service_name, self.messaging_pattern.kind());
+        config.service_name = service_name.clone();
+        config
+    }
+
     pub(crate) fn has_same_messaging_pattern(&self, rhs: &StaticConfig) -> bool {
         self.messaging_pattern
             .is_same_pattern(&rhs.messaging_pattern)
@@ -223,4 +276,44 @@ impl StaticConfig {
             }
         }
     }
+
+    /// Unwrap the Pipeline static configuration.
+    pub fn pipeline(&self) -> &pipeline::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Pipeline(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Trying to access pipeline::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn pipeline_mut(&mut self) -> &mut pipeline::StaticConfig {
+        let origin = format!("{self:?}");
+        match &mut self.messaging_pattern {
+            MessagingPattern::Pipeline(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen! Trying to access pipeline::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    /// Unwrap the Log static configuration.
+    pub fn log(&self) -> &log::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Log(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Trying to access log::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn log_mut(&mut self) -> &mut log::StaticConfig {
+        let origin = format!("{self:?}");
+        match &mut self.messaging_pattern {
+            MessagingPattern::Log(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen! Trying to access log::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
 }
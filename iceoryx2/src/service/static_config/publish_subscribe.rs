@@ -28,6 +28,7 @@
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("notify on send:                   {:?}", pubsub.static_config().has_notify_on_send());
 //!
 //! # Ok(())
 //! # }
@@ -53,6 +54,7 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
+    pub(crate) enable_notify_on_send: bool,
     pub(crate) message_type_details: MessageTypeDetails,
 }
 
@@ -72,6 +74,7 @@ impl StaticConfig {
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            enable_notify_on_send: false,
             message_type_details: MessageTypeDetails::default(),
         }
     }
@@ -126,6 +129,13 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns true if every [`crate::port::publisher::Publisher`] signals the
+    /// [`crate::port::subscriber::Subscriber`]s whenever it sent a [`crate::sample::Sample`],
+    /// so that they can be attached to a [`crate::waitset::WaitSet`].
+    pub fn has_notify_on_send(&self) -> bool {
+        self.enable_notify_on_send
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn message_type_details(&self) -> &MessageTypeDetails {
         &self.message_type_details
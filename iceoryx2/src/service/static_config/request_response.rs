@@ -33,6 +33,7 @@
 //! println!("response safe overflow: {:?}", req_res.static_config().has_safe_overflow_for_responses());
 //! println!("max borrowed responses per pending response: {:?}", req_res.static_config().max_borrowed_responses_per_pending_response());
 //! println!("does support fire and forget requests: {:?}", req_res.static_config().does_support_fire_and_forget_requests());
+//! println!("notify on send: {:?}", req_res.static_config().has_notify_on_send());
 //!
 //! # Ok(())
 //! # }
@@ -56,6 +57,7 @@ pub struct StaticConfig {
     pub(crate) enable_safe_overflow_for_requests: bool,
     pub(crate) enable_safe_overflow_for_responses: bool,
     pub(crate) enable_fire_and_forget_requests: bool,
+    pub(crate) enable_notify_on_send: bool,
     pub(crate) max_active_requests_per_client: usize,
     pub(crate) max_loaned_requests: usize,
     pub(crate) max_response_buffer_size: usize,
@@ -95,6 +97,7 @@ impl StaticConfig {
                 .defaults
                 .request_response
                 .enable_fire_and_forget_requests,
+            enable_notify_on_send: false,
             request_message_type_details: MessageTypeDetails::default(),
             response_message_type_details: MessageTypeDetails::default(),
         }
@@ -160,6 +163,14 @@ impl StaticConfig {
         self.enable_fire_and_forget_requests
     }
 
+    /// Returns true if every [`crate::port::client::Client`] signals the
+    /// [`crate::port::server::Server`]s whenever it sent a request and every
+    /// [`crate::port::server::Server`] signals the [`crate::port::client::Client`]s whenever it
+    /// sent a response, so that they can be attached to a [`crate::waitset::WaitSet`].
+    pub fn has_notify_on_send(&self) -> bool {
+        self.enable_notify_on_send
+    }
+
     /// Returns the maximum number of borrowed [`Response`](crate::response::Response)s a
     /// [`Client`](`crate::port::client::Client`) can hold in
     /// parallel per [`PendingResponse`](crate::pending_response::PendingResponse)
@@ -0,0 +1,193 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`Log`] backend that forwards all log messages of the process into a
+//! [`MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log) based
+//! service. A single [`LogCollector`](crate::port::log_collector::LogCollector) can then gather
+//! the logs of every process on the host.
+//!
+//! As long as the [`ServiceLogger`] is not connected to a service, or when the log record
+//! cannot be written, the log messages are printed to the console.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service_logger::use_service_logger;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! // forwards all iceoryx2 log messages of this process into the service
+//! use_service_logger(&"MyApp/Logs".try_into()?)?;
+//!
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use core::cell::Cell;
+use core::fmt::Arguments;
+use std::sync::Mutex;
+
+use iceoryx2_bb_log::{logger::console, set_logger, Log, LogLevel};
+
+use crate::log_record::LogRecord;
+use crate::node::NodeBuilder;
+use crate::port::log_writer::LogWriter;
+use crate::service::ipc_threadsafe;
+use crate::service::service_name::ServiceName;
+
+thread_local! {
+    // is set while the current thread is inside the logger, a log message that is created
+    // while writing a log record is printed to the console instead of ending in a deadlock
+    static IS_LOGGING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Defines the failures that can occur when the [`ServiceLogger`] is connected to a log
+/// service.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServiceLoggerConnectError {
+    /// Another [`Log`]ger was already set for the process.
+    LoggerAlreadySet,
+    /// The [`Node`](crate::node::Node) of the [`ServiceLogger`] could not be created.
+    UnableToCreateNode,
+    /// The log service could not be opened or created.
+    UnableToOpenService,
+    /// The [`LogWriter`] could not be created.
+    UnableToCreateLogWriter,
+}
+
+impl core::fmt::Display for ServiceLoggerConnectError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ServiceLoggerConnectError::{self:?}")
+    }
+}
+
+impl core::error::Error for ServiceLoggerConnectError {}
+
+/// [`Log`] backend that writes every log message as [`LogRecord`] into a
+/// [`MessagingPattern::Log`](crate::service::messaging_pattern::MessagingPattern::Log) based
+/// service.
+#[derive(Default)]
+pub struct ServiceLogger {
+    writer: Mutex<Option<LogWriter<ipc_threadsafe::Service>>>,
+    fallback: console::Logger,
+}
+
+impl core::fmt::Debug for ServiceLogger {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ServiceLogger {{ is_connected: {} }}",
+            self.is_connected()
+        )
+    }
+}
+
+impl ServiceLogger {
+    /// Creates a new [`ServiceLogger`] that is not yet connected to a service.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens or creates the log service with the given [`ServiceName`] and forwards all
+    /// subsequent log messages into it.
+    pub fn connect(&self, service_name: &ServiceName) -> Result<(), ServiceLoggerConnectError> {
+        // the log messages of the node and port creation are printed to the console
+        let _guard = LoggingGuard::acquire();
+
+        let node = NodeBuilder::new()
+            .create::<ipc_threadsafe::Service>()
+            .map_err(|_| ServiceLoggerConnectError::UnableToCreateNode)?;
+        let service = node
+            .service_builder(service_name)
+            .log()
+            .open_or_create()
+            .map_err(|_| ServiceLoggerConnectError::UnableToOpenService)?;
+        let writer = service
+            .writer_builder()
+            .create()
+            .map_err(|_| ServiceLoggerConnectError::UnableToCreateLogWriter)?;
+
+        if let Ok(mut guard) = self.writer.lock() {
+            *guard = Some(writer);
+        }
+
+        Ok(())
+    }
+
+    /// Returns true when the [`ServiceLogger`] is connected to a log service.
+    pub fn is_connected(&self) -> bool {
+        self.writer
+            .lock()
+            .map(|writer| writer.is_some())
+            .unwrap_or(false)
+    }
+}
+
+impl Log for ServiceLogger {
+    fn log(&self, log_level: LogLevel, origin: Arguments, formatted_message: Arguments) {
+        let _guard = match LoggingGuard::acquire() {
+            Some(guard) => guard,
+            None => return self.fallback.log(log_level, origin, formatted_message),
+        };
+
+        let is_written = match self.writer.lock() {
+            Ok(writer) => match writer.as_ref() {
+                Some(writer) => writer
+                    .write(&LogRecord::new(
+                        log_level,
+                        &origin.to_string(),
+                        &formatted_message.to_string(),
+                    ))
+                    .is_ok(),
+                None => false,
+            },
+            Err(_) => false,
+        };
+
+        if !is_written {
+            self.fallback.log(log_level, origin, formatted_message);
+        }
+    }
+}
+
+struct LoggingGuard;
+
+impl LoggingGuard {
+    fn acquire() -> Option<Self> {
+        if IS_LOGGING.with(|v| v.replace(true)) {
+            None
+        } else {
+            Some(Self)
+        }
+    }
+}
+
+impl Drop for LoggingGuard {
+    fn drop(&mut self) {
+        IS_LOGGING.with(|v| v.set(false));
+    }
+}
+
+/// Sets the [`ServiceLogger`] as logger of the process and connects it to the log service
+/// with the given [`ServiceName`]. The logger is set first so that the log messages that are
+/// created while connecting are not lost.
+pub fn use_service_logger(service_name: &ServiceName) -> Result<(), ServiceLoggerConnectError> {
+    // LazyLock is only available in 'std' but since static values are never dropped in Rust,
+    // we can also use Box::leak
+    let logger = Box::leak(Box::new(ServiceLogger::new()));
+    if !set_logger(&*logger) {
+        return Err(ServiceLoggerConnectError::LoggerAlreadySet);
+    }
+
+    logger.connect(service_name)
+}
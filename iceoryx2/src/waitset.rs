@@ -142,6 +142,109 @@
 //! # }
 //! ```
 //!
+//! ## Timer
+//!
+//! Besides fixed intervals, timers can fire once, at absolute time points or aligned to
+//! the period boundaries of the clock. See [`TimerSchedule`].
+//!
+//! The [`WaitSet`] measures its timers with the default
+//! [`ClockType`](iceoryx2_bb_posix::clock::ClockType), usually the monotonic clock.
+//! Therefore, aligned intervals are aligned to the epoch of that clock and not to the
+//! wall-clock. Absolute time points are measured with the clock of the provided
+//! [`Time`](iceoryx2_bb_posix::clock::Time), so that a wall-clock time point still fires at
+//! the intended system time after the system time was adjusted.
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::waitset::{MissedTickPolicy, TimerSchedule};
+//! # use core::time::Duration;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//!
+//! let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+//! // fires on every full 100ms, reports every missed tick
+//! let every_100ms = waitset.attach_timer(
+//!     TimerSchedule::aligned_interval(Duration::from_millis(100))
+//!         .missed_tick_policy(MissedTickPolicy::CatchUp),
+//! )?;
+//! // fires once after 5 seconds
+//! let shutdown = waitset.attach_timer(TimerSchedule::one_shot_after(Duration::from_secs(5)))?;
+//!
+//! let on_event = |attachment_id: WaitSetAttachmentId<ipc::Service>| {
+//!     if attachment_id.has_event_from(&every_100ms) {
+//!         println!("tick");
+//!     } else if attachment_id.has_event_from(&shutdown) {
+//!         return CallbackProgression::Stop;
+//!     }
+//!     CallbackProgression::Continue
+//! };
+//!
+//! waitset.wait_and_process(on_event)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Priorities and Fairness
+//!
+//! By default all events of one wake-up are dispatched in the order in which they were
+//! reported. An attachment with a higher priority is dispatched before all attachments with
+//! a lower priority, so that latency-critical events are handled first. Additionally, a limit
+//! of events that shall be handled per attachment in one wake-up can be defined so that a
+//! flooding attachment cannot starve the others. The remaining events keep the attachment
+//! ready and are handled in the next wake-up.
+//!
+//! The [`WaitSet`] dispatches every ready attachment exactly once per wake-up but it does not
+//! consume the events of an attachment, this is done by the callback. Therefore, the limit is
+//! not enforced by the [`WaitSet`]: the callback must stop consuming events after
+//! [`WaitSetAttachmentId::event_limit()`] events, as shown below. A callback that ignores the
+//! limit drains the attachment as if the fairness mode were not active.
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! # use core::time::Duration;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let control_event = node.service_builder(&"MyEventName_1".try_into()?)
+//! #     .event()
+//! #     .open_or_create()?;
+//! # let data_event = node.service_builder(&"MyEventName_2".try_into()?)
+//! #     .event()
+//! #     .open_or_create()?;
+//!
+//! let control_listener = control_event.listener_builder().create()?;
+//! let data_listener = data_event.listener_builder().create()?;
+//!
+//! let waitset = WaitSetBuilder::new()
+//!                 .event_limit_per_attachment(16)
+//!                 .create::<ipc::Service>()?;
+//!
+//! let control_guard = waitset.attach_notification(&control_listener)?;
+//! control_guard.set_priority(10);
+//! let _data_guard = waitset.attach_notification(&data_listener)?;
+//!
+//! let on_event = |attachment_id: WaitSetAttachmentId<ipc::Service>| {
+//!     let event_limit = attachment_id.event_limit().unwrap_or(usize::MAX);
+//!     let listener = if attachment_id.has_event_from(&control_guard) {
+//!         &control_listener
+//!     } else {
+//!         &data_listener
+//!     };
+//!
+//!     for _ in 0..event_limit {
+//!         match listener.try_wait_one() {
+//!             Ok(Some(event_id)) => println!("received notification {:?}", event_id),
+//!             _ => break,
+//!         }
+//!     }
+//!     CallbackProgression::Continue
+//! };
+//!
+//! waitset.wait_and_process(on_event)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## [`HashMap`](std::collections::HashMap) approach
 //!
 //! ```no_run
@@ -232,6 +335,8 @@ use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 
 use crate::signal_handling_mode::SignalHandlingMode;
 
+pub use iceoryx2_bb_posix::deadline_queue::{MissedTickPolicy, TimerSchedule};
+
 /// States why the [`WaitSet::wait_and_process()`] method returned.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum WaitSetRunResult {
@@ -299,6 +404,12 @@ impl core::fmt::Display for WaitSetCreateError {
 
 impl core::error::Error for WaitSetCreateError {}
 
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+enum PriorityKey {
+    Reactor(i32),
+    DeadlineQueue(DeadlineQueueIndex),
+}
+
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord)]
 enum AttachmentIdType {
     Tick(u64, DeadlineQueueIndex),
@@ -310,6 +421,7 @@ enum AttachmentIdType {
 #[derive(Clone, Copy)]
 pub struct WaitSetAttachmentId<Service: crate::service::Service> {
     attachment_type: AttachmentIdType,
+    event_limit: Option<usize>,
     _data: PhantomData<Service>,
 }
 
@@ -317,9 +429,10 @@ impl<Service: crate::service::Service> Debug for WaitSetAttachmentId<Service> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "WaitSetAttachmentId<{}> {{ attachment_type: {:?} }}",
+            "WaitSetAttachmentId<{}> {{ attachment_type: {:?}, event_limit: {:?} }}",
             core::any::type_name::<Service>(),
-            self.attachment_type
+            self.attachment_type,
+            self.event_limit
         )
     }
 }
@@ -378,6 +491,7 @@ impl<Service: crate::service::Service> WaitSetAttachmentId<Service> {
                 waitset as *const WaitSet<Service> as u64,
                 deadline_queue_idx,
             ),
+            event_limit: waitset.event_limit_per_attachment,
             _data: PhantomData,
         }
     }
@@ -393,6 +507,7 @@ impl<Service: crate::service::Service> WaitSetAttachmentId<Service> {
                 reactor_idx,
                 deadline_queue_idx,
             ),
+            event_limit: waitset.event_limit_per_attachment,
             _data: PhantomData,
         }
     }
@@ -403,6 +518,7 @@ impl<Service: crate::service::Service> WaitSetAttachmentId<Service> {
                 waitset as *const WaitSet<Service> as u64,
                 reactor_idx,
             ),
+            event_limit: waitset.event_limit_per_attachment,
             _data: PhantomData,
         }
     }
@@ -432,6 +548,27 @@ impl<Service: crate::service::Service> WaitSetAttachmentId<Service> {
             false
         }
     }
+
+    /// Returns the maximum number of events that shall be handled for this attachment in the
+    /// current wake-up when the [`WaitSet`] was created with
+    /// [`WaitSetBuilder::event_limit_per_attachment()`], otherwise [`None`]. Unhandled events
+    /// keep the attachment ready so that they are dispatched in the next wake-up.
+    ///
+    /// The limit is a budget for the callback and is not enforced by the [`WaitSet`], since
+    /// the callback is the one consuming the events.
+    pub fn event_limit(&self) -> Option<usize> {
+        self.event_limit
+    }
+
+    fn priority_key(&self) -> PriorityKey {
+        match self.attachment_type {
+            AttachmentIdType::Tick(_, deadline_queue_idx) => {
+                PriorityKey::DeadlineQueue(deadline_queue_idx)
+            }
+            AttachmentIdType::Deadline(_, reactor_idx, _)
+            | AttachmentIdType::Notification(_, reactor_idx) => PriorityKey::Reactor(reactor_idx),
+        }
+    }
 }
 
 enum GuardType<'waitset, 'attachment, Service: crate::service::Service>
@@ -462,14 +599,66 @@ impl<Service: crate::service::Service> Drop for WaitSetGuard<'_, '_, Service> {
             self.waitset
                 .remove_deadline(unsafe { r.file_descriptor().native_handle() }, t.index())
         }
+        self.waitset
+            .priorities
+            .borrow_mut()
+            .remove(&WaitSetAttachmentId::from_guard(self).priority_key());
         self.waitset.detach();
     }
 }
 
+impl<Service: crate::service::Service> WaitSetGuard<'_, '_, Service> {
+    /// Sets the priority of the attachment. When multiple attachments are ready in one
+    /// wake-up, the attachments with a higher priority are dispatched first. Attachments with
+    /// the same priority are dispatched in the order in which they were reported. The
+    /// default priority is `0`.
+    pub fn set_priority(&self, value: u8) {
+        let key = WaitSetAttachmentId::from_guard(self).priority_key();
+        let mut priorities = self.waitset.priorities.borrow_mut();
+        if value == 0 {
+            priorities.remove(&key);
+        } else {
+            priorities.insert(key, value);
+        }
+    }
+
+    /// Returns the priority of the attachment.
+    pub fn priority(&self) -> u8 {
+        self.waitset
+            .priority(&WaitSetAttachmentId::from_guard(self).priority_key())
+    }
+
+    /// Masks or unmasks the notification of the attachment without detaching it. A masked
+    /// attachment is not reported by the [`WaitSet`] even when it has data to read. Ticks and
+    /// missed deadlines are not affected.
+    pub(crate) fn set_masked(&self, value: bool) -> Result<(), WaitSetAttachmentError> {
+        let reactor_guard = match &self.guard_type {
+            GuardType::Tick(_) => return Ok(()),
+            GuardType::Deadline(r, _) | GuardType::Notification(r) => r,
+        };
+
+        let result = if value {
+            reactor_guard.mask()
+        } else {
+            reactor_guard.unmask()
+        };
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(ReactorMaskError::UnknownError(e)) => {
+                fail!(from self.waitset, with WaitSetAttachmentError::InternalError,
+                    "Unable to {} the attachment {:?} due to an internal error (error code = {}).",
+                    if value { "mask" } else { "unmask" }, reactor_guard.file_descriptor(), e);
+            }
+        }
+    }
+}
+
 /// The builder for the [`WaitSet`].
 #[derive(Default, Debug, Clone)]
 pub struct WaitSetBuilder {
     signal_handling_mode: SignalHandlingMode,
+    event_limit_per_attachment: Option<usize>,
 }
 
 impl WaitSetBuilder {
@@ -487,6 +676,16 @@ impl WaitSetBuilder {
         self
     }
 
+    /// Activates the fairness mode. It limits the number of events that shall be handled per
+    /// attachment in one wake-up so that a flooding attachment cannot starve the others. The
+    /// limit is provided to the callback via [`WaitSetAttachmentId::event_limit()`] and the
+    /// callback is responsible to respect it, the [`WaitSet`] only guarantees that every ready
+    /// attachment is dispatched once per wake-up. A limit of `0` is treated as `1`.
+    pub fn event_limit_per_attachment(mut self, value: usize) -> Self {
+        self.event_limit_per_attachment = Some(value.max(1));
+        self
+    }
+
     /// Creates the [`WaitSet`].
     pub fn create<Service: crate::service::Service>(
         self,
@@ -502,8 +701,10 @@ impl WaitSetBuilder {
                 deadline_queue,
                 attachment_to_deadline: RefCell::new(HashMap::new()),
                 deadline_to_attachment: RefCell::new(HashMap::new()),
+                priorities: RefCell::new(HashMap::new()),
                 attachment_counter: IoxAtomicUsize::new(0),
                 signal_handling_mode: self.signal_handling_mode,
+                event_limit_per_attachment: self.event_limit_per_attachment,
             }),
             Err(ReactorCreateError::UnknownError(e)) => {
                 fail!(from self, with WaitSetCreateError::InternalError,
@@ -530,8 +731,10 @@ pub struct WaitSet<Service: crate::service::Service> {
     deadline_queue: DeadlineQueue,
     attachment_to_deadline: RefCell<HashMap<i32, DeadlineQueueIndex>>,
     deadline_to_attachment: RefCell<HashMap<DeadlineQueueIndex, i32>>,
+    priorities: RefCell<HashMap<PriorityKey, u8>>,
     attachment_counter: IoxAtomicUsize,
     signal_handling_mode: SignalHandlingMode,
+    event_limit_per_attachment: Option<usize>,
 }
 
 impl<Service: crate::service::Service> WaitSet<Service> {
@@ -574,25 +777,23 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         }
     }
 
-    fn handle_deadlines<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+    fn priority(&self, key: &PriorityKey) -> u8 {
+        self.priorities.borrow().get(key).copied().unwrap_or(0)
+    }
+
+    fn collect_missed_deadlines(
         &self,
-        fn_call: &mut F,
+        attachments: &mut Vec<WaitSetAttachmentId<Service>>,
         error_msg: &str,
-    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+    ) -> Result<(), WaitSetRunError> {
         let deadline_to_attachment = self.deadline_to_attachment.borrow();
-        let mut result = WaitSetRunResult::AllEventsHandled;
         let call = |idx: DeadlineQueueIndex| -> CallbackProgression {
-            let progression = if let Some(reactor_idx) = deadline_to_attachment.get(&idx) {
-                fn_call(WaitSetAttachmentId::deadline(self, *reactor_idx, idx))
+            if let Some(reactor_idx) = deadline_to_attachment.get(&idx) {
+                attachments.push(WaitSetAttachmentId::deadline(self, *reactor_idx, idx));
             } else {
-                fn_call(WaitSetAttachmentId::tick(self, idx))
-            };
-
-            if let CallbackProgression::Stop = progression {
-                result = WaitSetRunResult::StopRequest;
+                attachments.push(WaitSetAttachmentId::tick(self, idx));
             }
-
-            progression
+            CallbackProgression::Continue
         };
 
         fail!(from self,
@@ -600,7 +801,38 @@ impl<Service: crate::service::Service> WaitSet<Service> {
                   with WaitSetRunError::InternalError,
                   "{error_msg} since the missed deadlines could not be acquired.");
 
-        Ok(result)
+        Ok(())
+    }
+
+    fn dispatch<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+        &self,
+        mut attachments: Vec<WaitSetAttachmentId<Service>>,
+        fn_call: &mut F,
+    ) -> WaitSetRunResult {
+        // the sort is stable, therefore attachments with the same priority are dispatched
+        // in the order in which they were reported
+        if !self.priorities.borrow().is_empty() {
+            attachments.sort_by_key(|id| core::cmp::Reverse(self.priority(&id.priority_key())));
+        }
+
+        for attachment in attachments {
+            if let CallbackProgression::Stop = fn_call(attachment) {
+                return WaitSetRunResult::StopRequest;
+            }
+        }
+
+        WaitSetRunResult::AllEventsHandled
+    }
+
+    fn handle_deadlines<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+        &self,
+        fn_call: &mut F,
+        error_msg: &str,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        let mut attachments = vec![];
+        self.collect_missed_deadlines(&mut attachments, error_msg)?;
+
+        Ok(self.dispatch(attachments, fn_call))
     }
 
     fn handle_all_attachments<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
@@ -619,20 +851,14 @@ impl<Service: crate::service::Service> WaitSet<Service> {
 
         // must be called after the deadlines have been reset, in the case that the
         // event has been received shortly before the deadline ended.
-
-        match self.handle_deadlines(fn_call, error_msg)? {
-            WaitSetRunResult::AllEventsHandled => (),
-            v => return Ok(v),
-        };
+        let mut attachments = Vec::with_capacity(triggered_file_descriptors.len());
+        self.collect_missed_deadlines(&mut attachments, error_msg)?;
 
         for fd in triggered_file_descriptors {
-            if let CallbackProgression::Stop = fn_call(WaitSetAttachmentId::notification(self, *fd))
-            {
-                return Ok(WaitSetRunResult::StopRequest);
-            }
+            attachments.push(WaitSetAttachmentId::notification(self, *fd));
         }
 
-        Ok(WaitSetRunResult::AllEventsHandled)
+        Ok(self.dispatch(attachments, fn_call))
     }
 
     /// Attaches an object as notification to the [`WaitSet`]. Whenever an event is received on the
@@ -663,7 +889,8 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         deadline: Duration,
     ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
         let reactor_guard = self.attach_to_reactor(attachment)?;
-        let deadline_queue_guard = self.attach_to_deadline_queue(deadline)?;
+        let deadline_queue_guard =
+            self.attach_to_deadline_queue(TimerSchedule::interval(deadline))?;
 
         let reactor_idx = unsafe { reactor_guard.file_descriptor().native_handle() };
         let deadline_idx = deadline_queue_guard.index();
@@ -688,7 +915,25 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         &self,
         interval: Duration,
     ) -> Result<WaitSetGuard<'_, '_, Service>, WaitSetAttachmentError> {
-        let deadline_queue_guard = self.attach_to_deadline_queue(interval)?;
+        let deadline_queue_guard =
+            self.attach_to_deadline_queue(TimerSchedule::interval(interval))?;
+        self.attach()?;
+
+        Ok(WaitSetGuard {
+            waitset: self,
+            guard_type: GuardType::Tick(deadline_queue_guard),
+        })
+    }
+
+    /// Attaches a timer to the [`WaitSet`] that fires according to the provided
+    /// [`TimerSchedule`]. Whenever the timer fires the [`WaitSet`] informs the user in
+    /// [`WaitSet::wait_and_process()`]. Depending on the [`MissedTickPolicy`], a timer that
+    /// missed multiple ticks is reported once or once for every missed tick.
+    pub fn attach_timer(
+        &self,
+        schedule: TimerSchedule,
+    ) -> Result<WaitSetGuard<'_, '_, Service>, WaitSetAttachmentError> {
+        let deadline_queue_guard = self.attach_to_deadline_queue(schedule)?;
         self.attach()?;
 
         Ok(WaitSetGuard {
@@ -938,6 +1183,13 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         self.signal_handling_mode
     }
 
+    /// Returns the limit of events per attachment and wake-up with which the [`WaitSet`] was
+    /// created. If the fairness mode is not active it returns [`None`]. See
+    /// [`WaitSetAttachmentId::event_limit()`] for how it is applied.
+    pub fn event_limit_per_attachment(&self) -> Option<usize> {
+        self.event_limit_per_attachment
+    }
+
     fn attach_to_reactor<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
         &'waitset self,
         attachment: &'attachment T,
@@ -965,11 +1217,11 @@ impl<Service: crate::service::Service> WaitSet<Service> {
 
     fn attach_to_deadline_queue(
         &self,
-        timeout: Duration,
+        schedule: TimerSchedule,
     ) -> Result<DeadlineQueueGuard<'_>, WaitSetAttachmentError> {
         let msg = "Unable to attach timeout to underlying Timer";
 
-        match self.deadline_queue.add_deadline_interval(timeout) {
+        match self.deadline_queue.add_timer(schedule) {
             Ok(guard) => Ok(guard),
             Err(e) => {
                 fail!(from self, with WaitSetAttachmentError::InternalError,
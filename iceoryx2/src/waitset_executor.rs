@@ -0,0 +1,732 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Multi-Threaded Event Processing
+//!
+//! The [`WaitSetExecutor`] builds upon the [`WaitSet`] and dispatches the callbacks of all
+//! ready attachments to a pool of worker threads. The [`WaitSet`] itself runs in the thread
+//! that calls [`WaitSetExecutor::run()`] and only waits on the attachments and hands the ready
+//! ones over to the workers.
+//!
+//! The executor guarantees that the callbacks of the same attachment are never executed
+//! concurrently. As long as a callback is running, new events on its attachment are not
+//! dispatched again. They are dispatched as soon as the callback has returned. Interval
+//! ticks that fire while the callback is still running are skipped.
+//!
+//! Every attachment can be pinned to a specific worker with
+//! [`WaitSetExecutor::pin_to_worker()`]. The CPU affinity, the priority and the
+//! [`Scheduler`] of every worker can be configured in the [`WaitSetExecutorBuilder`].
+//!
+//! Since the callbacks are executed in other threads, the attachments must be [`Send`] and
+//! [`Sync`]. Therefore the ports must be created from a thread-safe service variant like
+//! [`ipc_threadsafe`](crate::service::ipc_threadsafe) or
+//! [`local_threadsafe`](crate::service::local_threadsafe).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::waitset_executor::*;
+//! # use core::time::Duration;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc_threadsafe::Service>()?;
+//! let event = node.service_builder(&"MyEventName".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//! let listener = event.listener_builder().create()?;
+//!
+//! let mut executor = WaitSetExecutorBuilder::new()
+//!     .number_of_workers(2)
+//!     // the second worker runs exclusively on the cpu core 1
+//!     .worker_affinity(1, &[1])
+//!     .create::<ipc_threadsafe::Service>()?;
+//!
+//! let listener_id = executor.attach_notification(listener, |listener| {
+//!     while let Ok(Some(event_id)) = listener.try_wait_one() {
+//!         println!("received event {:?}", event_id);
+//!     }
+//!     CallbackProgression::Continue
+//! })?;
+//! // the listener callback is always executed by the second worker
+//! executor.pin_to_worker(listener_id, 1)?;
+//!
+//! executor.attach_interval(Duration::from_secs(1), || {
+//!     println!("tick");
+//!     CallbackProgression::Continue
+//! })?;
+//!
+//! // runs until a termination signal was received or a callback returned
+//! // CallbackProgression::Stop
+//! executor.run()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::{fmt::Debug, time::Duration};
+use std::{
+    collections::{HashMap, VecDeque},
+    panic::AssertUnwindSafe,
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+};
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::{
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::SynchronousMultiplexing,
+    scheduler::Scheduler,
+    socket_pair::StreamingSocket,
+    system_configuration::SystemInfo,
+    thread::{Thread, ThreadBuilder, ThreadName},
+};
+
+use crate::{
+    signal_handling_mode::SignalHandlingMode,
+    waitset::{
+        WaitSet, WaitSetAttachmentError, WaitSetAttachmentId, WaitSetBuilder, WaitSetGuard,
+        WaitSetRunError, WaitSetRunResult,
+    },
+};
+
+// Signals are delivered to an arbitrary thread of the process. When one of the workers
+// receives it, the waiting dispatcher is not interrupted and must check the signal state
+// periodically.
+const SIGNAL_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Defines the failures that can occur when calling [`WaitSetExecutorBuilder::create()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetExecutorCreateError {
+    /// A worker was configured that exceeds the number of workers.
+    WorkerDoesNotExist,
+    /// An internal error has occurred.
+    InternalError,
+}
+
+impl core::fmt::Display for WaitSetExecutorCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "WaitSetExecutorCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for WaitSetExecutorCreateError {}
+
+/// Defines the failures that can occur when calling [`WaitSetExecutor::pin_to_worker()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetExecutorPinError {
+    /// The worker exceeds the number of workers.
+    WorkerDoesNotExist,
+}
+
+impl core::fmt::Display for WaitSetExecutorPinError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "WaitSetExecutorPinError::{self:?}")
+    }
+}
+
+impl core::error::Error for WaitSetExecutorPinError {}
+
+/// Defines the failures that can occur when calling [`WaitSetExecutor::run()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetExecutorRunError {
+    /// The process has not sufficient permissions to wait on the attachments.
+    InsufficientPermissions,
+    /// Running an empty [`WaitSetExecutor`] would lead to a deadlock therefore it causes an
+    /// error.
+    NoAttachments,
+    /// A worker thread could not be started, for instance since the configured
+    /// [`Scheduler`] or priority requires more privileges.
+    UnableToStartWorker,
+    /// A callback has panicked. The [`WaitSetExecutor`] stops all workers since the state
+    /// the callback has left behind is unknown.
+    CallbackPanicked,
+    /// An internal error has occurred.
+    InternalError,
+}
+
+impl From<WaitSetRunError> for WaitSetExecutorRunError {
+    fn from(value: WaitSetRunError) -> Self {
+        match value {
+            WaitSetRunError::InsufficientPermissions => {
+                WaitSetExecutorRunError::InsufficientPermissions
+            }
+            WaitSetRunError::NoAttachments => WaitSetExecutorRunError::NoAttachments,
+            WaitSetRunError::InternalError => WaitSetExecutorRunError::InternalError,
+        }
+    }
+}
+
+impl core::fmt::Display for WaitSetExecutorRunError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "WaitSetExecutorRunError::{self:?}")
+    }
+}
+
+impl core::error::Error for WaitSetExecutorRunError {}
+
+/// Identifies an attachment of the [`WaitSetExecutor`]. It is returned by
+/// [`WaitSetExecutor::attach_notification()`] and [`WaitSetExecutor::attach_interval()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WaitSetExecutorAttachmentId(usize);
+
+#[derive(Debug, Default, Clone)]
+struct WorkerSettings {
+    affinity: Option<Vec<usize>>,
+    priority: Option<u8>,
+    scheduler: Option<Scheduler>,
+}
+
+// Type erased attachment so that attachments of different types can be attached to the
+// same WaitSet.
+struct NotificationSource(Arc<dyn FileDescriptorBased + Send + Sync>);
+
+impl Debug for NotificationSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "NotificationSource {{ fd: {:?} }}",
+            self.0.file_descriptor()
+        )
+    }
+}
+
+impl FileDescriptorBased for NotificationSource {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.0.file_descriptor()
+    }
+}
+
+impl SynchronousMultiplexing for NotificationSource {}
+
+#[derive(Debug)]
+enum TaskType {
+    Notification(NotificationSource),
+    Interval(Duration),
+}
+
+type TaskCallback = Box<dyn FnMut() -> CallbackProgression + Send>;
+
+struct Task {
+    task_type: TaskType,
+    callback: Mutex<TaskCallback>,
+}
+
+impl Debug for Task {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Task {{ task_type: {:?} }}", self.task_type)
+    }
+}
+
+#[derive(Debug)]
+struct WorkQueues {
+    shared: VecDeque<usize>,
+    pinned: Vec<VecDeque<usize>>,
+    completed: Vec<usize>,
+    keep_running: bool,
+    stop_requested: bool,
+    callback_panicked: bool,
+}
+
+#[derive(Debug)]
+struct WorkerState {
+    queues: Mutex<WorkQueues>,
+    has_work: Condvar,
+    tasks: Vec<Arc<Task>>,
+}
+
+// A panicking callback must not poison the executor for all other workers and the
+// dispatcher, it is reported via WorkQueues::callback_panicked instead.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl WorkerState {
+    fn next_task(&self, worker_id: usize) -> Option<usize> {
+        let mut queues = lock(&self.queues);
+        loop {
+            if !queues.keep_running {
+                return None;
+            }
+
+            if let Some(task_idx) = queues.pinned[worker_id].pop_front() {
+                return Some(task_idx);
+            }
+
+            if let Some(task_idx) = queues.shared.pop_front() {
+                return Some(task_idx);
+            }
+
+            queues = self
+                .has_work
+                .wait(queues)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    fn run_worker(&self, worker_id: usize, waker: &StreamingSocket) {
+        while let Some(task_idx) = self.next_task(worker_id) {
+            let result = {
+                let mut callback = lock(&self.tasks[task_idx].callback);
+                std::panic::catch_unwind(AssertUnwindSafe(|| callback()))
+            };
+
+            {
+                let mut queues = lock(&self.queues);
+                queues.completed.push(task_idx);
+                match result {
+                    Ok(CallbackProgression::Stop) => queues.stop_requested = true,
+                    Ok(CallbackProgression::Continue) => (),
+                    Err(_) => {
+                        warn!(from self,
+                            "Worker {worker_id} stops the executor since the callback of {:?} has panicked.",
+                            self.tasks[task_idx]);
+                        queues.callback_panicked = true;
+                    }
+                }
+            }
+
+            // when the socket buffer is full the dispatcher has not yet woken up and will
+            // collect this completion as well
+            if let Err(e) = waker.try_send(&[1]) {
+                warn!(from self,
+                    "Worker {worker_id} was unable to wake up the dispatcher ({:?}). The completion is handled with the next wake up.", e);
+            }
+        }
+    }
+}
+
+// Stops and joins all workers when it goes out of scope.
+#[derive(Debug)]
+struct WorkerPool {
+    state: Arc<WorkerState>,
+    _workers: Vec<Thread>,
+}
+
+impl Drop for WorkerPool {
+    fn drop(&mut self) {
+        lock(&self.state.queues).keep_running = false;
+        self.state.has_work.notify_all();
+    }
+}
+
+/// The builder for the [`WaitSetExecutor`].
+#[derive(Debug, Clone)]
+pub struct WaitSetExecutorBuilder {
+    number_of_workers: usize,
+    workers: Vec<WorkerSettings>,
+    signal_handling_mode: SignalHandlingMode,
+}
+
+impl Default for WaitSetExecutorBuilder {
+    fn default() -> Self {
+        Self {
+            number_of_workers: SystemInfo::NumberOfCpuCores.value(),
+            workers: vec![],
+            signal_handling_mode: SignalHandlingMode::default(),
+        }
+    }
+}
+
+impl WaitSetExecutorBuilder {
+    /// Creates a new [`WaitSetExecutorBuilder`]. By default, the [`WaitSetExecutor`] starts
+    /// one worker per CPU core.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the number of worker threads. A value of `0` is treated as `1`.
+    pub fn number_of_workers(mut self, value: usize) -> Self {
+        self.number_of_workers = value.max(1);
+        self
+    }
+
+    /// Sets the CPU affinity of the worker with the index `worker` to the provided list of
+    /// `cpu_core_ids`. See [`ThreadBuilder::affinity()`].
+    pub fn worker_affinity(mut self, worker: usize, cpu_core_ids: &[usize]) -> Self {
+        self.worker_settings(worker).affinity = Some(cpu_core_ids.to_vec());
+        self
+    }
+
+    /// Sets the priority of the worker with the index `worker`. See
+    /// [`ThreadBuilder::priority()`].
+    pub fn worker_priority(mut self, worker: usize, value: u8) -> Self {
+        self.worker_settings(worker).priority = Some(value);
+        self
+    }
+
+    /// Sets the [`Scheduler`] of the worker with the index `worker`. See
+    /// [`ThreadBuilder::scheduler()`].
+    pub fn worker_scheduler(mut self, worker: usize, value: Scheduler) -> Self {
+        self.worker_settings(worker).scheduler = Some(value);
+        self
+    }
+
+    /// Defines the [`SignalHandlingMode`] for the [`WaitSetExecutor`]. When a signal was
+    /// received, [`WaitSetExecutor::run()`] stops all workers and returns it via its
+    /// [`WaitSetRunResult`] return value.
+    pub fn signal_handling_mode(mut self, value: SignalHandlingMode) -> Self {
+        self.signal_handling_mode = value;
+        self
+    }
+
+    /// Creates the [`WaitSetExecutor`].
+    pub fn create<Service: crate::service::Service>(
+        mut self,
+    ) -> Result<WaitSetExecutor<Service>, WaitSetExecutorCreateError> {
+        let msg = "Unable to create WaitSetExecutor";
+        if self.number_of_workers < self.workers.len() {
+            fail!(from self, with WaitSetExecutorCreateError::WorkerDoesNotExist,
+                "{msg} since the worker {} was configured but only {} workers are used.",
+                self.workers.len() - 1, self.number_of_workers);
+        }
+
+        let waitset = fail!(from self, when WaitSetBuilder::new()
+                                .signal_handling_mode(self.signal_handling_mode)
+                                .create::<Service>(),
+                            with WaitSetExecutorCreateError::InternalError,
+                            "{msg} since the underlying WaitSet could not be created.");
+
+        self.workers
+            .resize(self.number_of_workers, WorkerSettings::default());
+
+        Ok(WaitSetExecutor {
+            waitset,
+            workers: self.workers,
+            tasks: vec![],
+            pinned_workers: vec![],
+        })
+    }
+
+    fn worker_settings(&mut self, worker: usize) -> &mut WorkerSettings {
+        if self.workers.len() <= worker {
+            self.workers.resize(worker + 1, WorkerSettings::default());
+        }
+
+        &mut self.workers[worker]
+    }
+}
+
+/// Runs the callbacks of all ready attachments of an underlying [`WaitSet`] in a pool of
+/// worker threads. The callbacks of one attachment are never executed concurrently.
+///
+/// Can be created via the [`WaitSetExecutorBuilder`].
+#[derive(Debug)]
+pub struct WaitSetExecutor<Service: crate::service::Service> {
+    waitset: WaitSet<Service>,
+    workers: Vec<WorkerSettings>,
+    tasks: Vec<Arc<Task>>,
+    pinned_workers: Vec<Option<usize>>,
+}
+
+impl<Service: crate::service::Service> WaitSetExecutor<Service> {
+    fn add_task(
+        &mut self,
+        task_type: TaskType,
+        callback: TaskCallback,
+    ) -> Result<WaitSetExecutorAttachmentId, WaitSetAttachmentError> {
+        // one attachment of the WaitSet is required to wake up the dispatcher
+        if self.tasks.len() + 1 >= self.waitset.capacity() {
+            fail!(from self, with WaitSetAttachmentError::InsufficientCapacity,
+                "Unable to add attachment since it would exceed the capacity of {}.", self.waitset.capacity() - 1);
+        }
+
+        self.tasks.push(Arc::new(Task {
+            task_type,
+            callback: Mutex::new(callback),
+        }));
+        self.pinned_workers.push(None);
+
+        Ok(WaitSetExecutorAttachmentId(self.tasks.len() - 1))
+    }
+
+    /// Attaches an object as notification to the [`WaitSetExecutor`] and takes its ownership.
+    /// Whenever an event is received on the object, the provided callback is called with the
+    /// object in one of the workers.
+    pub fn attach_notification<
+        T: SynchronousMultiplexing + Debug + Send + Sync + 'static,
+        F: FnMut(&T) -> CallbackProgression + Send + 'static,
+    >(
+        &mut self,
+        attachment: T,
+        mut callback: F,
+    ) -> Result<WaitSetExecutorAttachmentId, WaitSetAttachmentError> {
+        let attachment = Arc::new(attachment);
+        let source = NotificationSource(attachment.clone());
+
+        self.add_task(
+            TaskType::Notification(source),
+            Box::new(move || callback(&attachment)),
+        )
+    }
+
+    /// Attaches a tick event to the [`WaitSetExecutor`]. Whenever the interval has passed,
+    /// the provided callback is called in one of the workers.
+    pub fn attach_interval<F: FnMut() -> CallbackProgression + Send + 'static>(
+        &mut self,
+        interval: Duration,
+        callback: F,
+    ) -> Result<WaitSetExecutorAttachmentId, WaitSetAttachmentError> {
+        self.add_task(TaskType::Interval(interval), Box::new(callback))
+    }
+
+    /// Pins the attachment to the worker with the index `worker`. The callback of the
+    /// attachment is then always executed by this worker. By default, the callback is
+    /// executed by the next idle worker.
+    pub fn pin_to_worker(
+        &mut self,
+        attachment: WaitSetExecutorAttachmentId,
+        worker: usize,
+    ) -> Result<(), WaitSetExecutorPinError> {
+        if self.workers.len() <= worker {
+            fail!(from self, with WaitSetExecutorPinError::WorkerDoesNotExist,
+                "Unable to pin attachment {:?} to worker {worker} since only {} workers are used.",
+                attachment, self.workers.len());
+        }
+
+        self.pinned_workers[attachment.0] = Some(worker);
+        Ok(())
+    }
+
+    /// Returns the number of workers.
+    pub fn number_of_workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Returns the number of attachments.
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns true if the [`WaitSetExecutor`] has no attachments, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Returns the [`SignalHandlingMode`] with which the [`WaitSetExecutor`] was created.
+    pub fn signal_handling_mode(&self) -> SignalHandlingMode {
+        self.waitset.signal_handling_mode()
+    }
+
+    fn start_workers(
+        &self,
+        waker: &StreamingSocket,
+    ) -> Result<WorkerPool, WaitSetExecutorRunError> {
+        let msg = "Unable to start workers";
+        let state = Arc::new(WorkerState {
+            queues: Mutex::new(WorkQueues {
+                shared: VecDeque::new(),
+                pinned: vec![VecDeque::new(); self.workers.len()],
+                completed: vec![],
+                keep_running: true,
+                stop_requested: false,
+                callback_panicked: false,
+            }),
+            has_work: Condvar::new(),
+            tasks: self.tasks.clone(),
+        });
+
+        let mut pool = WorkerPool {
+            state: state.clone(),
+            _workers: Vec::with_capacity(self.workers.len()),
+        };
+
+        for (worker_id, settings) in self.workers.iter().enumerate() {
+            let worker_waker = fail!(from self, when waker.duplicate(),
+                with WaitSetExecutorRunError::InternalError,
+                "{msg} since the wake up socket for worker {worker_id} could not be duplicated.");
+
+            let mut builder = ThreadBuilder::new().name(&ThreadName::from_bytes_truncated(
+                format!("iox2_worker_{worker_id}").as_bytes(),
+            ));
+            if let Some(affinity) = &settings.affinity {
+                builder = builder.affinity(affinity);
+            }
+            if settings.priority.is_some() || settings.scheduler.is_some() {
+                builder = builder
+                    .inherit_scheduling_attributes(false)
+                    .priority(settings.priority.unwrap_or(0))
+                    .scheduler(settings.scheduler.unwrap_or_default());
+            }
+
+            let worker_state = state.clone();
+            let worker = fail!(from self,
+                when builder.spawn(move || worker_state.run_worker(worker_id, &worker_waker)),
+                with WaitSetExecutorRunError::UnableToStartWorker,
+                "{msg} since the thread for worker {worker_id} could not be spawned.");
+            pool._workers.push(worker);
+        }
+
+        Ok(pool)
+    }
+
+    /// Starts all workers and dispatches the callbacks of the ready attachments to them until
+    /// a callback returns [`CallbackProgression::Stop`] or, depending on the
+    /// [`SignalHandlingMode`], an interrupt- (`SIGINT`) or a termination-signal (`SIGTERM`) was
+    /// received. Before it returns, it waits until all running callbacks have finished and
+    /// stops all workers.
+    pub fn run(&self) -> Result<WaitSetRunResult, WaitSetExecutorRunError> {
+        let msg = "Unable to run WaitSetExecutor";
+        if self.tasks.is_empty() {
+            fail!(from self, with WaitSetExecutorRunError::NoAttachments,
+                "{msg} since it has no attachments, therefore the call would end up in a deadlock.");
+        }
+
+        let (wake_receiver, waker) = fail!(from self, when StreamingSocket::create_pair(),
+            with WaitSetExecutorRunError::InternalError,
+            "{msg} since the wake up socket could not be created.");
+
+        let wake_guard = fail!(from self, when self.waitset.attach_notification(&wake_receiver),
+            with WaitSetExecutorRunError::InternalError,
+            "{msg} since the wake up socket could not be attached.");
+
+        let mut guards = Vec::with_capacity(self.tasks.len());
+        let mut task_of_attachment = HashMap::with_capacity(self.tasks.len());
+        for (task_idx, task) in self.tasks.iter().enumerate() {
+            let guard = self.attach_task(task)?;
+            task_of_attachment.insert(WaitSetAttachmentId::from_guard(&guard), task_idx);
+            guards.push(guard);
+        }
+
+        let pool = self.start_workers(&waker)?;
+        drop(waker);
+
+        let timeout = match self.signal_handling_mode() {
+            SignalHandlingMode::HandleTerminationRequests => SIGNAL_CHECK_INTERVAL,
+            SignalHandlingMode::Disabled => Duration::MAX,
+        };
+
+        let mut in_flight = vec![false; self.tasks.len()];
+        loop {
+            let mut ready_tasks = vec![];
+            let mut has_completions = false;
+            let result = self.waitset.wait_and_process_once_with_timeout(
+                |attachment_id| {
+                    if attachment_id.has_event_from(&wake_guard) {
+                        has_completions = true;
+                    } else if let Some(task_idx) = task_of_attachment.get(&attachment_id) {
+                        ready_tasks.push(*task_idx);
+                    }
+                    CallbackProgression::Continue
+                },
+                timeout,
+            );
+
+            match result {
+                Ok(WaitSetRunResult::AllEventsHandled) => (),
+                Ok(v) => return Ok(v),
+                Err(e) => {
+                    fail!(from self, with e.into(),
+                        "{msg} since the underlying WaitSet failed to wait ({:?}).", e);
+                }
+            }
+
+            if has_completions {
+                Self::drain(&wake_receiver);
+
+                let (completed, stop_requested, callback_panicked) = {
+                    let mut queues = lock(&pool.state.queues);
+                    (
+                        core::mem::take(&mut queues.completed),
+                        queues.stop_requested,
+                        queues.callback_panicked,
+                    )
+                };
+
+                if callback_panicked {
+                    fail!(from self, with WaitSetExecutorRunError::CallbackPanicked,
+                        "{msg} since a callback has panicked.");
+                }
+
+                if stop_requested {
+                    return Ok(WaitSetRunResult::StopRequest);
+                }
+
+                for task_idx in completed {
+                    in_flight[task_idx] = false;
+                    self.set_masked(&guards[task_idx], &self.tasks[task_idx], false)?;
+                }
+            }
+
+            if ready_tasks.is_empty() {
+                continue;
+            }
+
+            {
+                let mut queues = lock(&pool.state.queues);
+                for task_idx in ready_tasks {
+                    if in_flight[task_idx] {
+                        continue;
+                    }
+
+                    in_flight[task_idx] = true;
+                    // A notification stays ready until the callback has consumed the events.
+                    // It is masked while the callback runs so that the dispatcher does not
+                    // spin on it.
+                    self.set_masked(&guards[task_idx], &self.tasks[task_idx], true)?;
+
+                    match self.pinned_workers[task_idx] {
+                        Some(worker_id) => queues.pinned[worker_id].push_back(task_idx),
+                        None => queues.shared.push_back(task_idx),
+                    }
+                }
+            }
+            pool.state.has_work.notify_all();
+        }
+    }
+
+    fn set_masked(
+        &self,
+        guard: &WaitSetGuard<'_, '_, Service>,
+        task: &Task,
+        value: bool,
+    ) -> Result<(), WaitSetExecutorRunError> {
+        if let TaskType::Interval(_) = task.task_type {
+            return Ok(());
+        }
+
+        match guard.set_masked(value) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                fail!(from self, with WaitSetExecutorRunError::InternalError,
+                    "Unable to {} {:?} in the underlying WaitSet ({:?}).",
+                    if value { "mask" } else { "unmask" }, task, e);
+            }
+        }
+    }
+
+    fn attach_task<'executor>(
+        &'executor self,
+        task: &'executor Task,
+    ) -> Result<WaitSetGuard<'executor, 'executor, Service>, WaitSetExecutorRunError> {
+        let result = match &task.task_type {
+            TaskType::Notification(source) => self.waitset.attach_notification(source),
+            TaskType::Interval(interval) => self.waitset.attach_interval(*interval),
+        };
+
+        match result {
+            Ok(guard) => Ok(guard),
+            Err(e) => {
+                fail!(from self, with WaitSetExecutorRunError::InternalError,
+                    "Unable to attach {:?} to the underlying WaitSet ({:?}).", task, e);
+            }
+        }
+    }
+
+    fn drain(wake_receiver: &StreamingSocket) {
+        let mut buffer = [0u8; 64];
+        while let Ok(n) = wake_receiver.try_receive(&mut buffer) {
+            if n == 0 {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .pipeline::<u64>()
+//! #   .open_or_create()?;
+//! # let consumer = service.consumer_builder().create()?;
+//!
+//! while let Some(work_item) = consumer.receive()? {
+//!     println!("received: {:?}", *work_item);
+//!     println!("header producer id {:?}", work_item.header().producer_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::{fmt::Debug, ops::Deref};
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_cal::zero_copy_connection::ChannelId;
+
+use crate::port::consumer::ConsumerSharedState;
+use crate::port::details::chunk_details::ChunkDetails;
+use crate::port::port_identifiers::UniqueProducerId;
+use crate::raw_sample::RawSample;
+use crate::service::header::pipeline::Header;
+
+/// It stores the payload and is acquired by the [`Consumer`](crate::port::consumer::Consumer)
+/// whenever it receives new work from a [`Producer`](crate::port::producer::Producer). The
+/// memory is handed back to the [`Producer`](crate::port::producer::Producer) when the
+/// [`WorkItem`] goes out of scope.
+pub struct WorkItem<Service: crate::service::Service, Payload: Debug + ZeroCopySend> {
+    pub(crate) ptr: RawSample<Header, (), Payload>,
+    pub(crate) consumer_shared_state: Service::ArcThreadSafetyPolicy<ConsumerSharedState<Service>>,
+    pub(crate) details: ChunkDetails,
+}
+
+unsafe impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend> Send
+    for WorkItem<Service, Payload>
+where
+    Service::ArcThreadSafetyPolicy<ConsumerSharedState<Service>>: Send + Sync,
+{
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend> Debug
+    for WorkItem<Service, Payload>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "WorkItem<{}, {}> {{ ptr: {:?}, details: {:?} }}",
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<Service>(),
+            self.ptr,
+            self.details,
+        )
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend> Deref
+    for WorkItem<Service, Payload>
+{
+    type Target = Payload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_payload_ref()
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend> Drop
+    for WorkItem<Service, Payload>
+{
+    fn drop(&mut self) {
+        self.consumer_shared_state
+            .lock()
+            .receiver
+            .release_offset(&self.details, ChannelId::new(0));
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend> WorkItem<Service, Payload> {
+    /// Returns a reference to the payload of the [`WorkItem`]
+    pub fn payload(&self) -> &Payload {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a reference to the [`Header`] of the [`WorkItem`].
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns the [`UniqueProducerId`] of the [`Producer`](crate::port::producer::Producer)
+    pub fn origin(&self) -> UniqueProducerId {
+        UniqueProducerId(UniqueSystemId::from(self.details.origin))
+    }
+}
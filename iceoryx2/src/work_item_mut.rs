@@ -0,0 +1,154 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! #
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .pipeline::<u64>()
+//! #     .open_or_create()?;
+//! #
+//! # let producer = service.producer_builder().create()?;
+//!
+//! let mut work_item = producer.loan()?;
+//! *work_item.payload_mut() = 456;
+//!
+//! println!("producer port id: {:?}", work_item.header().producer_id());
+//! if let Some(consumer_id) = work_item.send()? {
+//!     println!("handed over to consumer: {:?}", consumer_id);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::{Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_cal::shared_memory::*;
+
+use crate::{
+    port::port_identifiers::UniqueConsumerId, port::producer::ProducerSharedState, port::SendError,
+    raw_sample::RawSampleMut, service::header::pipeline::Header,
+};
+
+/// Acquired by a [`crate::port::producer::Producer`] via
+/// [`crate::port::producer::Producer::loan()`].
+///
+/// It stores the payload that will be handed to exactly one
+/// [`crate::port::consumer::Consumer`]. If the [`WorkItemMut`] is not sent
+/// it will release the loaned memory when going out of scope.
+pub struct WorkItemMut<Service: crate::service::Service, Payload: Debug + ZeroCopySend> {
+    pub(crate) producer_shared_state: Service::ArcThreadSafetyPolicy<ProducerSharedState<Service>>,
+    pub(crate) ptr: RawSampleMut<Header, (), Payload>,
+    pub(crate) offset_to_chunk: PointerOffset,
+    pub(crate) item_size: usize,
+}
+
+unsafe impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend> Send
+    for WorkItemMut<Service, Payload>
+where
+    Service::ArcThreadSafetyPolicy<ProducerSharedState<Service>>: Send + Sync,
+{
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend> Deref
+    for WorkItemMut<Service, Payload>
+{
+    type Target = Payload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_payload_ref()
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend> DerefMut
+    for WorkItemMut<Service, Payload>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.ptr.as_payload_mut()
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend> Debug
+    for WorkItemMut<Service, Payload>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "WorkItemMut<{}, {}> {{ producer_shared_state: {:?}, offset_to_chunk: {:?}, item_size: {} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<Payload>(),
+            self.producer_shared_state,
+            self.offset_to_chunk,
+            self.item_size
+        )
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend> Drop
+    for WorkItemMut<Service, Payload>
+{
+    fn drop(&mut self) {
+        self.producer_shared_state
+            .lock()
+            .sender
+            .return_loaned_sample(self.offset_to_chunk);
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        M: Debug + ZeroCopySend, // `M` is either a `Payload` or a `MaybeUninit<Payload>`
+    > WorkItemMut<Service, M>
+{
+    /// Returns a reference to the [`Header`] of the work item.
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the payload of the work item.
+    pub fn payload(&self) -> &M {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the work item.
+    pub fn payload_mut(&mut self) -> &mut M {
+        self.ptr.as_payload_mut()
+    }
+
+    /// Hands a previously loaned [`crate::port::producer::Producer::loan_uninit()`] or
+    /// [`crate::port::producer::Producer::loan()`] [`WorkItemMut`] to exactly one
+    /// [`crate::port::consumer::Consumer`] of the service. The
+    /// [`crate::port::consumer::Consumer`] is selected by the
+    /// [`DistributionPolicy`](crate::port::distribution_policy::DistributionPolicy) of the
+    /// [`crate::port::producer::Producer`].
+    ///
+    /// On success the [`UniqueConsumerId`] of the [`crate::port::consumer::Consumer`] that
+    /// received the work item is returned. When no [`crate::port::consumer::Consumer`] is
+    /// connected or the buffers of all of them are full and the
+    /// [`crate::port::producer::Producer`] uses
+    /// [`UnableToDeliverStrategy::DiscardSample`](crate::prelude::UnableToDeliverStrategy::DiscardSample)
+    /// [`None`] is returned. Otherwise a [`SendError`] describing the failure.
+    pub fn send(mut self) -> Result<Option<UniqueConsumerId>, SendError> {
+        self.producer_shared_state.lock().send_item(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            self.item_size,
+        )
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! #
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .pipeline::<u64>()
+//! #     .open_or_create()?;
+//! #
+//! # let producer = service.producer_builder().create()?;
+//!
+//! let work_item = producer.loan_uninit()?;
+//! // write 1234 into the work item
+//! let mut work_item = work_item.write_payload(1234);
+//! // override contents with 456 because its fun
+//! *work_item.payload_mut() = 456;
+//!
+//! work_item.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use core::{fmt::Debug, mem::MaybeUninit};
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::{
+    port::producer::ProducerSharedState, raw_sample::RawSampleMut,
+    service::header::pipeline::Header, work_item_mut::WorkItemMut,
+};
+
+/// Acquired by a [`crate::port::producer::Producer`] via
+/// [`crate::port::producer::Producer::loan_uninit()`].
+///
+/// It stores the not yet initialized payload that will be handed to exactly one
+/// [`crate::port::consumer::Consumer`]. If the [`WorkItemMutUninit`] is not sent
+/// it will release the loaned memory when going out of scope.
+#[repr(transparent)]
+pub struct WorkItemMutUninit<Service: crate::service::Service, Payload: Debug + ZeroCopySend> {
+    work_item: WorkItemMut<Service, Payload>,
+}
+
+unsafe impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend> Send
+    for WorkItemMutUninit<Service, Payload>
+where
+    Service::ArcThreadSafetyPolicy<ProducerSharedState<Service>>: Send + Sync,
+{
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend> Debug
+    for WorkItemMutUninit<Service, Payload>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "WorkItemMutUninit {{ work_item: {:?} }}", self.work_item)
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend>
+    WorkItemMutUninit<Service, Payload>
+{
+    /// Returns a reference to the [`Header`] of the [`WorkItemMutUninit`].
+    pub fn header(&self) -> &Header {
+        self.work_item.header()
+    }
+
+    /// Returns a reference to the payload of the [`WorkItemMutUninit`].
+    pub fn payload(&self) -> &Payload {
+        self.work_item.payload()
+    }
+
+    /// Returns a mutable reference to the payload of the [`WorkItemMutUninit`].
+    pub fn payload_mut(&mut self) -> &mut Payload {
+        self.work_item.payload_mut()
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend>
+    WorkItemMutUninit<Service, MaybeUninit<Payload>>
+{
+    pub(crate) fn new(
+        producer_shared_state: &Service::ArcThreadSafetyPolicy<ProducerSharedState<Service>>,
+        ptr: RawSampleMut<Header, (), MaybeUninit<Payload>>,
+        offset_to_chunk: PointerOffset,
+        item_size: usize,
+    ) -> Self {
+        Self {
+            work_item: WorkItemMut {
+                producer_shared_state: producer_shared_state.clone(),
+                ptr,
+                offset_to_chunk,
+                item_size,
+            },
+        }
+    }
+
+    /// Writes the payload to the work item and labels the work item as initialized
+    pub fn write_payload(mut self, value: Payload) -> WorkItemMut<Service, Payload> {
+        self.payload_mut().write(value);
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`core::mem::MaybeUninit<Payload>`] container and labels the
+    /// work item as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`core::mem::MaybeUninit<Payload>`] really is initialized.
+    /// Calling this when the content is not fully initialized causes immediate undefined
+    /// behavior.
+    pub unsafe fn assume_init(self) -> WorkItemMut<Service, Payload> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        let initialized_work_item = core::mem::transmute_copy(&self.work_item);
+        core::mem::forget(self);
+        initialized_work_item
+    }
+}
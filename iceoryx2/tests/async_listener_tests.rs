@@ -0,0 +1,303 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "async")]
+
+#[generic_tests::define]
+mod async_listener {
+    use core::pin::Pin;
+    use core::time::Duration;
+
+    use futures_core::Stream;
+    use iceoryx2::port::async_listener::AsyncListener;
+    use iceoryx2::port::notifier::Notifier;
+    use iceoryx2::port::AsyncReceiveError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::Event;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .enable_time()
+            .build()
+            .unwrap()
+    }
+
+    fn create_event<S: Service>(node: &Node<S>) -> (AsyncListener<S>, Notifier<S>)
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        (
+            AsyncListener::new(service.listener_builder().create().unwrap()).unwrap(),
+            service.notifier_builder().create().unwrap(),
+        )
+    }
+
+    #[test]
+    fn wait_one_returns_notified_event_id<S: Service>()
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        runtime().block_on(async {
+            let (sut, notifier) = create_event::<S>(&node);
+
+            notifier
+                .notify_with_custom_event_id(EventId::new(12))
+                .unwrap();
+
+            let event_id = sut.wait_one().await.unwrap();
+            assert_that!(event_id, eq EventId::new(12));
+        });
+    }
+
+    #[test]
+    fn wait_one_blocks_until_notified<S: Service>()
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        runtime().block_on(async {
+            let (sut, notifier) = create_event::<S>(&node);
+
+            let result = tokio::time::timeout(TIMEOUT, sut.wait_one()).await;
+            assert_that!(result, is_err);
+
+            let notify = async {
+                tokio::time::sleep(TIMEOUT).await;
+                notifier
+                    .notify_with_custom_event_id(EventId::new(5))
+                    .unwrap();
+            };
+
+            let (event_id, _) = tokio::join!(sut.wait_one(), notify);
+            assert_that!(event_id.unwrap(), eq EventId::new(5));
+        });
+    }
+
+    #[test]
+    fn wait_all_collects_all_event_ids<S: Service>()
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        runtime().block_on(async {
+            let (sut, notifier) = create_event::<S>(&node);
+
+            for i in 0..4 {
+                notifier
+                    .notify_with_custom_event_id(EventId::new(i))
+                    .unwrap();
+            }
+
+            let mut event_ids = vec![];
+            sut.wait_all(|id| event_ids.push(id)).await.unwrap();
+
+            assert_that!(event_ids, len 4);
+            for i in 0..4 {
+                assert_that!(event_ids, contains EventId::new(i));
+            }
+        });
+    }
+
+    #[test]
+    fn event_stream_yields_event_ids<S: Service>()
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        runtime().block_on(async {
+            let (sut, notifier) = create_event::<S>(&node);
+            let mut events = sut.events();
+
+            for i in 1..3 {
+                notifier
+                    .notify_with_custom_event_id(EventId::new(i))
+                    .unwrap();
+
+                let event_id =
+                    core::future::poll_fn(|cx| Pin::new(&mut events).poll_next(cx)).await;
+                assert_that!(event_id, eq Some(Ok(EventId::new(i))));
+            }
+        });
+    }
+
+    #[test]
+    fn subscriber_receive_async_returns_sample<S: Service>()
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        runtime().block_on(async {
+            let send = async {
+                tokio::time::sleep(TIMEOUT).await;
+                publisher.send_copy(8192).unwrap();
+            };
+
+            let (sample, _) = tokio::join!(subscriber.receive_async(), send);
+            assert_that!(*sample.unwrap(), eq 8192);
+        });
+    }
+
+    #[test]
+    fn subscriber_receive_async_does_not_wait_when_sample_is_available<S: Service>()
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u64]>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        runtime().block_on(async {
+            let sample = publisher.loan_slice_uninit(4).unwrap();
+            sample.write_from_slice(&[1, 2, 3, 4]).send().unwrap();
+
+            let sample = subscriber.receive_async().await.unwrap();
+            assert_that!(*sample, eq [1, 2, 3, 4]);
+        });
+    }
+
+    #[test]
+    fn subscriber_receive_async_receives_consecutive_samples<S: Service>()
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        runtime().block_on(async {
+            for i in 0..3 {
+                publisher.send_copy(i).unwrap();
+            }
+
+            for i in 0..3 {
+                let sample = subscriber.receive_async().await.unwrap();
+                assert_that!(*sample, eq i);
+            }
+
+            let result = tokio::time::timeout(TIMEOUT, subscriber.receive_async()).await;
+            assert_that!(result, is_err);
+        });
+    }
+
+    #[test]
+    fn subscriber_receive_async_fails_when_notify_on_send_is_disabled<S: Service>()
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        runtime().block_on(async {
+            let result = subscriber.receive_async().await;
+            assert_that!(result.err(), eq Some(AsyncReceiveError::NotifyOnSendDisabled));
+        });
+    }
+
+    #[test]
+    fn request_response_receive_async_works<S: Service>()
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        runtime().block_on(async {
+            let pending_response = client.send_copy(3).unwrap();
+
+            let active_request = server.receive_async().await.unwrap();
+            assert_that!(*active_request, eq 3);
+
+            let respond = async {
+                tokio::time::sleep(TIMEOUT).await;
+                active_request.send_copy(*active_request * 2).unwrap();
+            };
+
+            let (response, _) = tokio::join!(pending_response.receive_async(), respond);
+            assert_that!(*response.unwrap(), eq 6);
+        });
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service>)]
+    mod ipc_threadsafe {}
+
+    #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
+    mod local_threadsafe {}
+}
@@ -789,6 +789,208 @@ mod client {
         }
     }
 
+    #[test]
+    fn broadcast_delivery_policy_delivers_request_to_all_servers<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(2)
+            .create()
+            .unwrap();
+
+        let server_1 = service.server_builder().create().unwrap();
+        let server_2 = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let pending_response = sut.send_copy(42).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 2);
+
+        for server in [&server_1, &server_2] {
+            let active_request = server.receive().unwrap().unwrap();
+            assert_that!(*active_request, eq 42);
+            assert_that!(active_request.header().delivery_policy(), eq RequestDeliveryPolicy::Broadcast);
+        }
+    }
+
+    #[test]
+    fn round_robin_delivery_policy_delivers_every_request_to_one_server<Sut: Service>() {
+        const NUMBER_OF_REQUESTS: usize = 4;
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(2)
+            .max_active_requests_per_client(NUMBER_OF_REQUESTS)
+            .create()
+            .unwrap();
+
+        let server_1 = service.server_builder().create().unwrap();
+        let server_2 = service.server_builder().create().unwrap();
+        let sut = service
+            .client_builder()
+            .delivery_policy(RequestDeliveryPolicy::AnyOne(
+                DistributionPolicy::RoundRobin,
+            ))
+            .create()
+            .unwrap();
+
+        let mut pending_responses = vec![];
+        for n in 0..NUMBER_OF_REQUESTS {
+            let pending_response = sut.send_copy(n as u64).unwrap();
+            assert_that!(pending_response.number_of_server_connections(), eq 1);
+            pending_responses.push(pending_response);
+        }
+
+        for server in [&server_1, &server_2] {
+            let mut number_of_requests = 0;
+            while let Some(active_request) = server.receive().unwrap() {
+                assert_that!(active_request.header().delivery_policy(), eq
+                    RequestDeliveryPolicy::AnyOne(DistributionPolicy::RoundRobin));
+                number_of_requests += 1;
+            }
+            assert_that!(number_of_requests, eq NUMBER_OF_REQUESTS / 2);
+        }
+    }
+
+    #[test]
+    fn least_loaded_delivery_policy_delivers_request_to_server_with_fewest_pending_requests<
+        Sut: Service,
+    >() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(2)
+            .max_active_requests_per_client(4)
+            .create()
+            .unwrap();
+
+        let server_1 = service.server_builder().create().unwrap();
+        let server_2 = service.server_builder().create().unwrap();
+        let sut = service
+            .client_builder()
+            .delivery_policy(RequestDeliveryPolicy::AnyOne(
+                DistributionPolicy::LeastLoaded,
+            ))
+            .create()
+            .unwrap();
+
+        let _pending_response_1 = sut.send_copy(1).unwrap();
+        let _pending_response_2 = sut.send_copy(2).unwrap();
+
+        // every server has one pending request, the first server processes its request
+        let active_request = server_1.receive().unwrap();
+        assert_that!(active_request, is_some);
+        assert_that!(server_2.has_requests().unwrap(), eq true);
+
+        let _pending_response_3 = sut.send_copy(3).unwrap();
+        assert_that!(server_1.has_requests().unwrap(), eq true);
+
+        let active_request = server_1.receive().unwrap().unwrap();
+        assert_that!(*active_request, eq 3);
+        assert_that!(active_request.header().delivery_policy(), eq
+            RequestDeliveryPolicy::AnyOne(DistributionPolicy::LeastLoaded));
+    }
+
+    #[test]
+    fn addressed_delivery_policy_delivers_request_only_to_addressed_server<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(2)
+            .create()
+            .unwrap();
+
+        let server_1 = service.server_builder().create().unwrap();
+        let server_2 = service.server_builder().create().unwrap();
+        let sut = service
+            .client_builder()
+            .delivery_policy(RequestDeliveryPolicy::Addressed(server_2.id()))
+            .create()
+            .unwrap();
+
+        let pending_response = sut.send_copy(123).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 1);
+
+        assert_that!(server_1.receive().unwrap(), is_none);
+        let active_request = server_2.receive().unwrap().unwrap();
+        assert_that!(*active_request, eq 123);
+        assert_that!(active_request.header().delivery_policy(), eq
+            RequestDeliveryPolicy::Addressed(server_2.id()));
+    }
+
+    #[test]
+    fn addressed_delivery_policy_does_not_deliver_request_when_server_is_not_connected<
+        Sut: Service,
+    >() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(2)
+            .create()
+            .unwrap();
+
+        let server_1 = service.server_builder().create().unwrap();
+        let server_2 = service.server_builder().create().unwrap();
+        let disconnected_server_id = server_2.id();
+        drop(server_2);
+
+        let sut = service
+            .client_builder()
+            .delivery_policy(RequestDeliveryPolicy::Addressed(disconnected_server_id))
+            .create()
+            .unwrap();
+
+        let pending_response = sut.send_copy(123).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 0);
+        assert_that!(pending_response.is_connected(), eq false);
+        assert_that!(server_1.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn pending_response_of_single_server_request_disconnects_when_server_drops_request<
+        Sut: Service,
+    >() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(2)
+            .create()
+            .unwrap();
+
+        let server_1 = service.server_builder().create().unwrap();
+        let server_2 = service.server_builder().create().unwrap();
+        let sut = service
+            .client_builder()
+            .delivery_policy(RequestDeliveryPolicy::AnyOne(
+                DistributionPolicy::RoundRobin,
+            ))
+            .create()
+            .unwrap();
+
+        let pending_response = sut.send_copy(123).unwrap();
+        assert_that!(pending_response.is_connected(), eq true);
+
+        let active_request = match server_1.receive().unwrap() {
+            Some(active_request) => active_request,
+            None => server_2.receive().unwrap().unwrap(),
+        };
+        assert_that!(pending_response.is_connected(), eq true);
+
+        drop(active_request);
+        assert_that!(pending_response.is_connected(), eq false);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -189,6 +189,68 @@ mod node_death_tests {
         }
     }
 
+    #[test]
+    fn samples_held_by_subscriber_of_dead_node_are_returned_to_publisher<S: Test>() {
+        let _watchdog = Watchdog::new();
+        const BUFFER_SIZE: usize = 4;
+        const NUMBER_OF_ITERATIONS: u64 = 32;
+
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let service_name = generate_service_name();
+        let mut bad_node = S::create_test_node(&config).node;
+        let good_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+
+        let service = good_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(2)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(BUFFER_SIZE)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        let bad_service = bad_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let bad_subscriber = bad_service.subscriber_builder().create().unwrap();
+
+        for n in 0..BUFFER_SIZE as u64 * 2 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        // the dead subscriber holds borrowed samples and a full buffer
+        let mut borrowed_samples = vec![];
+        for _ in 0..BUFFER_SIZE {
+            borrowed_samples.push(bad_subscriber.receive().unwrap().unwrap());
+        }
+        for n in BUFFER_SIZE as u64..BUFFER_SIZE as u64 * 2 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        S::staged_death(&mut bad_node);
+        core::mem::forget(borrowed_samples);
+        core::mem::forget(bad_subscriber);
+        core::mem::forget(bad_service);
+
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(&config), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 0);
+
+        let subscriber = service.subscriber_builder().create().unwrap();
+        for n in 0..NUMBER_OF_ITERATIONS {
+            let sample = publisher.loan_uninit().unwrap();
+            assert_that!(sample.write_payload(n).send(), eq Ok(1));
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq n);
+        }
+    }
+
     #[test]
     fn dead_node_is_removed_from_event_service<S: Test>() {
         let _watchdog = Watchdog::new();
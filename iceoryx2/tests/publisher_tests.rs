@@ -17,7 +17,7 @@ mod publisher {
     use std::sync::Mutex;
     use std::time::Instant;
 
-    use iceoryx2::port::{publisher::PublisherCreateError, LoanError};
+    use iceoryx2::port::{publisher::PublisherCreateError, LoanError, SendError};
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
@@ -659,6 +659,201 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn resend_handle_contains_subscribers_that_did_not_receive_the_sample<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(false)
+            .subscriber_max_buffer_size(1)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .max_resend_handles(1)
+            .create()?;
+        let subscriber_with_full_buffer = service.subscriber_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(1)?;
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1);
+
+        let sample = sut.loan_uninit()?.write_payload(2);
+        let resend_handle = sample.send_with_resend_handle()?;
+
+        assert_that!(resend_handle.number_of_recipients(), eq 1);
+        assert_that!(resend_handle.is_delivered_to_all(), eq false);
+        assert_that!(
+            resend_handle.undelivered_subscribers(),
+            eq & [subscriber_with_full_buffer.id()]
+        );
+        assert_that!(*subscriber.receive()?.unwrap(), eq 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resend_delivers_sample_only_to_undelivered_subscribers<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(false)
+            .subscriber_max_buffer_size(1)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .max_resend_handles(1)
+            .create()?;
+        let subscriber_with_full_buffer = service.subscriber_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(1)?;
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1);
+
+        let sample = sut.loan_uninit()?.write_payload(2);
+        let mut resend_handle = sample.send_with_resend_handle()?;
+
+        // buffer is still full
+        assert_that!(resend_handle.resend(), eq Ok(0));
+        assert_that!(resend_handle.undelivered_subscribers(), len 1);
+
+        assert_that!(*subscriber_with_full_buffer.receive()?.unwrap(), eq 1);
+        assert_that!(resend_handle.resend(), eq Ok(1));
+        assert_that!(resend_handle.is_delivered_to_all(), eq true);
+        assert_that!(resend_handle.number_of_recipients(), eq 2);
+
+        assert_that!(*subscriber_with_full_buffer.receive()?.unwrap(), eq 2);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 2);
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resend_removes_disconnected_subscribers<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(false)
+            .subscriber_max_buffer_size(1)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .max_resend_handles(1)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(1)?;
+        let mut resend_handle = sut.loan()?.send_with_resend_handle()?;
+        assert_that!(resend_handle.number_of_recipients(), eq 0);
+        assert_that!(resend_handle.undelivered_subscribers(), len 1);
+
+        drop(subscriber);
+
+        assert_that!(resend_handle.resend(), eq Ok(0));
+        assert_that!(resend_handle.is_delivered_to_all(), eq true);
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_with_resend_handle_fails_when_max_resend_handles_is_exceeded<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_resend_handles(2).create()?;
+
+        let resend_handle_1 = sut.loan()?.send_with_resend_handle()?;
+        let _resend_handle_2 = sut.loan()?.send_with_resend_handle()?;
+        assert_that!(
+            sut.loan()?.send_with_resend_handle().err(),
+            eq Some(SendError::ExceedsMaxResendHandles)
+        );
+
+        drop(resend_handle_1);
+        assert_that!(sut.loan()?.send_with_resend_handle(), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_with_resend_handle_fails_by_default<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+
+        assert_that!(
+            sut.loan()?.send_with_resend_handle().err(),
+            eq Some(SendError::ExceedsMaxResendHandles)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn resend_handles_do_not_reduce_the_number_of_loanable_samples<Sut: Service>() -> TestResult<()>
+    {
+        const MAX_LOANED_SAMPLES: usize = 2;
+        const MAX_RESEND_HANDLES: usize = 3;
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(1)
+            .subscriber_max_buffer_size(1)
+            .subscriber_max_borrowed_samples(1)
+            .history_size(0)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .max_resend_handles(MAX_RESEND_HANDLES)
+            .create()?;
+        let _subscriber = service.subscriber_builder().create()?;
+
+        let mut resend_handles = vec![];
+        for _ in 0..MAX_RESEND_HANDLES {
+            resend_handles.push(sut.loan()?.send_with_resend_handle()?);
+        }
+
+        let mut samples = vec![];
+        for _ in 0..MAX_LOANED_SAMPLES {
+            samples.push(sut.loan()?);
+        }
+
+        Ok(())
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -19,6 +19,7 @@ mod sample {
     use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::clock::{ClockType, Time};
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
 
@@ -82,6 +83,44 @@ mod sample {
         assert_that!(sample.origin(), eq test_context.publisher_2.id());
     }
 
+    #[test]
+    fn sequence_number_is_incremented_per_publisher<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+
+        for n in 0..3 {
+            assert_that!(test_context.publisher_1.send_copy(n), eq Ok(1));
+            let sample = test_context.subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.sequence_number(), eq n);
+        }
+
+        assert_that!(test_context.publisher_2.send_copy(0), eq Ok(1));
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.sequence_number(), eq 0);
+        assert_that!(sample.header().sequence_number(), eq 0);
+    }
+
+    #[test]
+    fn send_timestamp_is_set_when_sample_is_sent<Sut: Service>() {
+        let config = generate_isolated_config();
+        let test_context = TestContext::<Sut>::new(&config);
+
+        let sample = test_context.publisher_1.loan_uninit().unwrap();
+        let sample = sample.write_payload(42);
+        let before_send = Time::now_with_clock(ClockType::Monotonic)
+            .unwrap()
+            .as_duration();
+        assert_that!(sample.send(), eq Ok(1));
+        let after_send = Time::now_with_clock(ClockType::Monotonic)
+            .unwrap()
+            .as_duration();
+
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.send_timestamp(), ge before_send);
+        assert_that!(sample.send_timestamp(), le after_send);
+        assert_that!(sample.header().send_timestamp(), eq sample.send_timestamp());
+    }
+
     #[test]
     fn sample_of_dropped_service_does_block_new_service_creation<Sut: Service>() {
         let config = generate_isolated_config();
@@ -21,10 +21,13 @@ mod service_blackboard {
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
     use iceoryx2_bb_container::byte_string::FixedSizeByteString;
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::event::Event;
+    use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
     use std::ptr::copy_nonoverlapping;
     use std::sync::Arc;
     use std::sync::Barrier;
@@ -1652,7 +1655,504 @@ mod service_blackboard {
         }
     }
 
+    #[test]
+    fn slice_entry_provides_initial_value<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u8>(0, 32, b"hello")
+            .add_slice::<u64>(1, 4, &[])
+            .add::<u16>(2, 9)
+            .create()
+            .unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        let entry_handle = reader.slice_entry::<u8>(&0).unwrap();
+        assert_that!(entry_handle.max_slice_len(), eq 32);
+        assert_that!(entry_handle.get().to_vec(), eq b"hello");
+        assert_that!(entry_handle.get().capacity(), eq 32);
+
+        let entry_handle = reader.slice_entry::<u64>(&1).unwrap();
+        assert_that!(entry_handle.get(), len 0);
+        assert_that!(reader.entry::<u16>(&2).unwrap().get(), eq 9);
+    }
+
+    #[test]
+    fn slice_entry_max_slice_len_is_adjusted_to_initial_value<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<i32>(0, 2, &[1, 2, 3, 4])
+            .add_slice::<i32>(1, 0, &[])
+            .create()
+            .unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        let entry_handle = reader.slice_entry::<i32>(&0).unwrap();
+        assert_that!(entry_handle.max_slice_len(), eq 4);
+        assert_that!(entry_handle.get().to_vec(), eq [1, 2, 3, 4]);
+        assert_that!(reader.slice_entry::<i32>(&1).unwrap().max_slice_len(), eq 1);
+    }
+
+    #[test]
+    fn slice_entry_requires_matching_value_type_and_variant<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u32>(0, 8, &[1])
+            .add::<u32>(1, 1)
+            .create()
+            .unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+
+        assert_that!(reader.slice_entry::<u16>(&0).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(reader.entry::<u32>(&0).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(reader.slice_entry::<u32>(&1).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(writer.slice_entry::<u16>(&0).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+        assert_that!(writer.entry::<u32>(&0).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+        assert_that!(writer.slice_entry::<u32>(&1).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+    }
+
+    #[test]
+    fn there_can_be_only_one_slice_entry_handle_mut<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u8>(0, 8, b"a")
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+
+        let entry_handle_mut = writer.slice_entry::<u8>(&0).unwrap();
+        assert_that!(writer.slice_entry::<u8>(&0).err(), eq Some(EntryHandleMutError::HandleAlreadyExists));
+
+        drop(entry_handle_mut);
+        assert_that!(writer.slice_entry::<u8>(&0), is_ok);
+    }
+
+    #[test]
+    fn slice_entry_update_with_copy_works<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u64>(0, 5, &[1, 2])
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let entry_handle_mut = writer.slice_entry::<u64>(&0).unwrap();
+        let entry_handle = reader.slice_entry::<u64>(&0).unwrap();
+
+        assert_that!(entry_handle_mut.update_with_copy(&[5, 4, 3, 2, 1]), is_ok);
+        assert_that!(entry_handle.get().to_vec(), eq [5, 4, 3, 2, 1]);
+
+        assert_that!(entry_handle_mut.update_with_copy(&[7]), is_ok);
+        assert_that!(entry_handle.get().to_vec(), eq[7]);
+
+        assert_that!(entry_handle_mut.update_with_copy(&[]), is_ok);
+        assert_that!(entry_handle.get(), len 0);
+
+        assert_that!(entry_handle_mut.update_with_copy(&[1, 2, 3, 4, 5, 6]).err(), eq Some(SliceEntryUpdateError::ExceedsMaxSliceLen));
+        assert_that!(entry_handle.get(), len 0);
+    }
+
+    #[test]
+    fn slice_entry_loan_and_update_works<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u32>(0, 6, &[0])
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let entry_handle = reader.slice_entry::<u32>(&0).unwrap();
+
+        let entry_handle_mut = writer.slice_entry::<u32>(&0).unwrap();
+        let entry_value = entry_handle_mut
+            .loan_slice_uninit(4)
+            .unwrap()
+            .write_from_fn(|i| i as u32 * 3);
+        assert_that!(entry_value.payload(), eq [0, 3, 6, 9]);
+        // not visible before the update
+        assert_that!(entry_handle.get().to_vec(), eq[0]);
+        let entry_handle_mut = entry_value.update();
+        assert_that!(entry_handle.get().to_vec(), eq [0, 3, 6, 9]);
+
+        let mut entry_value = entry_handle_mut
+            .loan_slice_uninit(2)
+            .unwrap()
+            .write_from_slice(&[8, 9]);
+        entry_value.payload_mut()[0] = 1;
+        let entry_handle_mut = entry_value.discard();
+        assert_that!(entry_handle.get().to_vec(), eq [0, 3, 6, 9]);
+
+        let entry_handle_mut = entry_handle_mut.loan_slice_uninit(6).unwrap().discard();
+        assert_that!(entry_handle_mut.loan_slice_uninit(7).err(), eq Some(SliceEntryUpdateError::ExceedsMaxSliceLen));
+        assert_that!(entry_handle.get().to_vec(), eq [0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn concurrent_write_and_read_of_slice_entry_provides_consistent_snapshots<S: Service>() {
+        let _watch_dog = Watchdog::new();
+        const MAX_SLICE_LEN: usize = 64;
+        let number_of_readers = (SystemInfo::NumberOfCpuCores.value()).clamp(2, 4);
+        let number_of_reads = 1000;
+
+        let barrier = Barrier::new(number_of_readers + 1);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u64>(0, MAX_SLICE_LEN, &[])
+            .create()
+            .unwrap();
+
+        let keep_running = IoxAtomicBool::new(true);
+
+        std::thread::scope(|s| {
+            let t = s.spawn(|| {
+                let sut = node
+                    .service_builder(&service_name)
+                    .blackboard_opener::<u64>()
+                    .open()
+                    .unwrap();
+                let writer = sut.writer_builder().create().unwrap();
+                let mut entry_handle_mut = writer.slice_entry::<u64>(&0).unwrap();
+                let mut counter = 0u64;
+
+                barrier.wait();
+
+                // every snapshot consists of `len` elements with the value `len`
+                while keep_running.load(Ordering::Relaxed) {
+                    counter += 1;
+                    let len = (counter % MAX_SLICE_LEN as u64) as usize;
+                    entry_handle_mut = entry_handle_mut
+                        .loan_slice_uninit(len)
+                        .unwrap()
+                        .write_from_fn(|_| len as u64)
+                        .update();
+                }
+            });
+            let mut threads = vec![];
+            for _ in 0..number_of_readers {
+                threads.push(s.spawn(|| {
+                    let sut = node
+                        .service_builder(&service_name)
+                        .blackboard_opener::<u64>()
+                        .open()
+                        .unwrap();
+                    let reader = sut.reader_builder().create().unwrap();
+                    let entry_handle = reader.slice_entry::<u64>(&0).unwrap();
+                    barrier.wait();
+                    for _ in 0..number_of_reads {
+                        let value = entry_handle.get();
+                        for element in value.iter() {
+                            assert_that!(*element, eq value.len() as u64);
+                        }
+                    }
+                }));
+            }
+            for t in threads {
+                t.join().unwrap();
+            }
+            keep_running.store(false, Ordering::Relaxed);
+            t.join().unwrap();
+        });
+    }
+
     // TODO [#817] move the following tests to testing.rs and replace u64 with CustomKeyMarker
+    #[test]
+    fn service_with_only_reserved_entries_can_be_created<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .reserve::<u32>(1)
+            .create()
+            .unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(reader.keys(), len 0);
+    }
+
+    #[test]
+    fn inserted_entries_can_be_read_and_written<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 1)
+            .reserve::<u32>(1)
+            .reserve_slice::<u16>(1, 4)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(writer.insert::<u32>(1, 42), is_ok);
+        assert_that!(writer.insert_slice::<u16>(2, 4, &[1, 2]), is_ok);
+
+        assert_that!(reader.entry::<u32>(&1).unwrap().get(), eq 42);
+        assert_that!(reader.slice_entry::<u16>(&2).unwrap().get().to_vec(), eq [1, 2]);
+
+        writer.entry::<u32>(&1).unwrap().update_with_copy(73);
+        assert_that!(reader.entry::<u32>(&1).unwrap().get(), eq 73);
+    }
+
+    #[test]
+    fn inserting_existing_key_fails<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .reserve::<u32>(1)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+
+        assert_that!(writer.insert::<u32>(0, 2).err(), eq Some(EntryInsertError::KeyAlreadyExists));
+        assert_that!(writer.insert::<u64>(0, 2).err(), eq Some(EntryInsertError::KeyAlreadyExists));
+    }
+
+    #[test]
+    fn inserting_more_entries_than_reserved_fails<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .reserve::<u32>(2)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+
+        assert_that!(writer.insert::<u32>(0, 1), is_ok);
+        assert_that!(writer.insert::<u32>(1, 1), is_ok);
+        assert_that!(writer.insert::<u32>(2, 1).err(), eq Some(EntryInsertError::ExceedsMaxNumberOfEntries));
+    }
+
+    #[test]
+    fn removed_entries_are_no_longer_accessible<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .add::<u32>(1, 2)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(writer.remove(&0), is_ok);
+        assert_that!(writer.remove(&0).err(), eq Some(EntryRemoveError::EntryDoesNotExist));
+        assert_that!(reader.entry::<u32>(&0).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(writer.entry::<u32>(&0).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+        assert_that!(reader.keys(), eq vec![1]);
+    }
+
+    #[test]
+    fn handles_of_removed_entries_stay_valid<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let entry_handle_mut = writer.entry::<u32>(&0).unwrap();
+        let entry_handle = reader.entry::<u32>(&0).unwrap();
+
+        assert_that!(writer.remove(&0), is_ok);
+        // the memory of the removed entry is still in use and cannot be reused
+        assert_that!(writer.insert::<u32>(1, 5).err(), eq Some(EntryInsertError::ExceedsMaxNumberOfEntries));
+
+        entry_handle_mut.update_with_copy(9);
+        assert_that!(entry_handle.get(), eq 9);
+
+        drop(entry_handle_mut);
+        drop(entry_handle);
+        assert_that!(writer.insert::<u32>(1, 5), is_ok);
+        assert_that!(reader.entry::<u32>(&1).unwrap().get(), eq 5);
+    }
+
+    #[test]
+    fn removed_entry_capacity_can_be_reused_for_different_type<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 1)
+            .reserve::<u8>(1)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(writer.remove(&0), is_ok);
+        assert_that!(writer.insert::<u8>(0, 3), is_ok);
+        assert_that!(writer.insert::<u8>(1, 4), is_ok);
+
+        assert_that!(reader.entry::<u8>(&0).unwrap().get(), eq 3);
+        assert_that!(reader.entry::<u8>(&1).unwrap().get(), eq 4);
+        assert_that!(reader.entry::<u64>(&0).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+    }
+
+    #[test]
+    fn reader_detects_key_set_changes<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .reserve::<u32>(1)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(reader.has_key_set_changed(), eq false);
+        writer.entry::<u32>(&0).unwrap().update_with_copy(2);
+        assert_that!(reader.has_key_set_changed(), eq false);
+
+        assert_that!(writer.insert::<u32>(1, 2), is_ok);
+        assert_that!(reader.has_key_set_changed(), eq true);
+        assert_that!(reader.has_key_set_changed(), eq false);
+
+        assert_that!(writer.remove(&0), is_ok);
+        let keys = reader.keys();
+        assert_that!(keys, eq vec![1]);
+        assert_that!(reader.has_key_set_changed(), eq false);
+    }
+
+    #[test]
+    fn key_set_listener_is_signaled_on_key_set_changes<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .reserve::<u32>(1)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let key_set_listener = reader.key_set_listener().unwrap();
+
+        assert_that!(key_set_listener.has_changed(), eq Ok(false));
+        writer.entry::<u32>(&0).unwrap().update_with_copy(2);
+        assert_that!(key_set_listener.has_changed(), eq Ok(false));
+
+        assert_that!(writer.insert::<u32>(1, 2), is_ok);
+        assert_that!(key_set_listener.has_changed(), eq Ok(true));
+        assert_that!(key_set_listener.has_changed(), eq Ok(false));
+
+        assert_that!(writer.remove(&0), is_ok);
+        assert_that!(key_set_listener.has_changed(), eq Ok(true));
+
+        assert_that!(writer.remove(&0), is_err);
+        assert_that!(key_set_listener.has_changed(), eq Ok(false));
+    }
+
+    #[test]
+    fn listeners_of_a_reinserted_key_are_not_signaled_by_the_removed_entry<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        let old_entry_listener = reader.entry::<u32>(&0).unwrap().entry_listener().unwrap();
+        assert_that!(writer.remove(&0), is_ok);
+
+        // reuses the memory and the slot of the removed entry
+        assert_that!(writer.insert::<u32>(1, 2), is_ok);
+        let entry_handle = reader.entry::<u32>(&1).unwrap();
+        let entry_listener = entry_handle.entry_listener().unwrap();
+        assert_that!(entry_listener.has_changed(), eq Ok(false));
+
+        writer.entry::<u32>(&1).unwrap().update_with_copy(3);
+        assert_that!(entry_listener.has_changed(), eq Ok(true));
+        assert_that!(old_entry_listener.has_changed(), eq Ok(false));
+        assert_that!(entry_handle.get(), eq 3);
+    }
+
+    #[test]
+    fn inserted_entries_are_visible_to_opened_services<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .reserve::<i64>(2)
+            .create()
+            .unwrap();
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+        let writer = sut2.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(writer.insert::<i64>(7, -7), is_ok);
+        assert_that!(writer.insert::<i64>(8, -8), is_ok);
+
+        let mut keys = reader.keys();
+        keys.sort();
+        assert_that!(keys, eq vec![7, 8]);
+        assert_that!(reader.entry::<i64>(&8).unwrap().get(), eq - 8);
+    }
+
     #[test]
     fn loan_uninit_and_write_works_with_custom_key_type<S: Service>() {
         type ValueType = u64;
@@ -1807,6 +2307,184 @@ mod service_blackboard {
         assert_that!(entry_handle.get(), eq write_value);
     }
 
+    #[test]
+    fn entry_handle_has_changed_after_update<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let entry_handle_mut = writer.entry::<u32>(&0).unwrap();
+        let entry_handle = reader.entry::<u32>(&0).unwrap();
+        let entry_listener = entry_handle.entry_listener().unwrap();
+
+        assert_that!(entry_listener.has_changed(), eq Ok(false));
+
+        entry_handle_mut.update_with_copy(2);
+        entry_handle_mut.update_with_copy(3);
+        assert_that!(entry_listener.has_changed(), eq Ok(true));
+        assert_that!(entry_listener.has_changed(), eq Ok(false));
+
+        let entry_handle_mut = entry_handle_mut.loan_uninit().write(4).update();
+        assert_that!(entry_listener.has_changed(), eq Ok(true));
+        assert_that!(entry_handle.get(), eq 4);
+
+        let _entry_handle_mut = entry_handle_mut.loan_uninit().write(5).discard();
+        assert_that!(entry_listener.has_changed(), eq Ok(false));
+    }
+
+    #[test]
+    fn slice_entry_handle_has_changed_after_update<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add_slice::<u32>(0, 4, &[1])
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let entry_handle_mut = writer.slice_entry::<u32>(&0).unwrap();
+        let entry_handle = reader.slice_entry::<u32>(&0).unwrap();
+        let entry_listener = entry_handle.entry_listener().unwrap();
+
+        assert_that!(entry_listener.has_changed(), eq Ok(false));
+
+        assert_that!(entry_handle_mut.update_with_copy(&[2, 3]), is_ok);
+        assert_that!(entry_listener.has_changed(), eq Ok(true));
+        assert_that!(entry_listener.has_changed(), eq Ok(false));
+
+        assert_that!(entry_handle_mut.update_with_copy(&[1, 2, 3, 4, 5]), is_err);
+        assert_that!(entry_listener.has_changed(), eq Ok(false));
+    }
+
+    #[test]
+    fn only_listeners_of_the_updated_entry_have_changed<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .add::<u64>(1, 1)
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let entry_handle_mut_0 = writer.entry::<u32>(&0).unwrap();
+        let entry_handle_0 = reader.entry::<u32>(&0).unwrap();
+        let entry_handle_1 = reader.entry::<u64>(&1).unwrap();
+        let entry_listener_0 = entry_handle_0.entry_listener().unwrap();
+        let another_entry_listener_0 = entry_handle_0.entry_listener().unwrap();
+        let entry_listener_1 = entry_handle_1.entry_listener().unwrap();
+
+        entry_handle_mut_0.update_with_copy(2);
+
+        assert_that!(entry_listener_0.has_changed(), eq Ok(true));
+        assert_that!(another_entry_listener_0.has_changed(), eq Ok(true));
+        assert_that!(entry_listener_1.has_changed(), eq Ok(false));
+    }
+
+    #[test]
+    fn creating_more_entry_listeners_than_supported_fails<S: Service>() {
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        config.defaults.event.max_listeners = 1;
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .create()
+            .unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        let entry_handle = reader.entry::<u32>(&0).unwrap();
+        let _entry_listener = entry_handle.entry_listener().unwrap();
+        let result = entry_handle.entry_listener();
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq EntryHandleError::ExceedsMaxSupportedListeners);
+    }
+
+    #[test]
+    fn entry_handles_do_not_require_listener_resources<S: Service>() {
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        config.defaults.event.max_listeners = 1;
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .create()
+            .unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+
+        let mut entry_handles = vec![];
+        for _ in 0..4 {
+            entry_handles.push(reader.entry::<u32>(&0).unwrap());
+        }
+
+        assert_that!(entry_handles[0].entry_listener(), is_ok);
+    }
+
+    #[test]
+    fn waitset_wakes_up_on_entry_update<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 1)
+            .add_slice::<u8>(1, 8, &[1])
+            .create()
+            .unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let entry_handle_mut = writer.entry::<u32>(&0).unwrap();
+        let entry_handle_0 = reader.entry::<u32>(&0).unwrap();
+        let entry_handle_1 = reader.slice_entry::<u8>(&1).unwrap();
+        let entry_listener_0 = entry_handle_0.entry_listener().unwrap();
+        let entry_listener_1 = entry_handle_1.entry_listener().unwrap();
+
+        let waitset = WaitSetBuilder::new().create::<S>().unwrap();
+        let guard_0 = waitset.attach_notification(&entry_listener_0).unwrap();
+        let guard_1 = waitset.attach_notification(&entry_listener_1).unwrap();
+
+        entry_handle_mut.update_with_copy(2);
+
+        let mut changed_entries = vec![];
+        waitset
+            .wait_and_process_once(|attachment_id| {
+                if attachment_id.has_event_from(&guard_0) {
+                    changed_entries.push(entry_listener_0.entry_id());
+                    assert_that!(entry_listener_0.has_changed(), eq Ok(true));
+                } else if attachment_id.has_event_from(&guard_1) {
+                    changed_entries.push(entry_listener_1.entry_id());
+                }
+
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+        assert_that!(changed_entries, eq vec![entry_handle_0.entry_id()]);
+        assert_that!(entry_handle_0.get(), eq 2);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
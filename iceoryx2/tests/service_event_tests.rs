@@ -26,6 +26,7 @@ mod service_event {
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::event::ListenerWaitError;
 
     const TIMEOUT: Duration = Duration::from_millis(50);
 
@@ -1568,6 +1569,157 @@ mod service_event {
         assert_that!(received_events, eq 1);
     }
 
+    #[test]
+    fn notification_payload_size_is_verified_on_open<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .notification_payload_size(16)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().notification_payload_size(), eq Some(16));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .event()
+            .notification_payload_size(8)
+            .open();
+        assert_that!(sut_open, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .event()
+            .notification_payload_size(17)
+            .open();
+        assert_that!(sut_open.err(), eq Some(EventOpenError::DoesNotSupportRequestedNotificationPayloadSize));
+
+        let service_name = generate_name();
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let sut_open = node
+            .service_builder(&service_name)
+            .event()
+            .notification_payload_size(0)
+            .open();
+        assert_that!(sut_open.err(), eq Some(EventOpenError::DoesNotSupportRequestedNotificationPayloadSize));
+    }
+
+    #[test]
+    fn notify_with_payload_fails_when_payload_is_too_large<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&generate_name())
+            .event()
+            .notification_payload_size(4)
+            .create()
+            .unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+        assert_that!(notifier.notify_with_payload(&[1, 2, 3, 4]), is_ok);
+        assert_that!(notifier.notify_with_payload(&[1, 2, 3, 4, 5]).err(), eq Some(NotifierNotifyError::PayloadTooLarge));
+
+        let sut = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+        assert_that!(notifier.notify_with_payload(&[]), is_ok);
+        assert_that!(notifier.notify_with_payload(&[1]).err(), eq Some(NotifierNotifyError::PayloadTooLarge));
+    }
+
+    #[test]
+    fn wait_with_details_fails_when_service_has_no_notification_details<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        assert_that!(listener.try_wait_all_with_details(|_| {}).err(), eq Some(ListenerWaitError::ContractViolation));
+        assert_that!(listener.timed_wait_all_with_details(|_| {}, TIMEOUT).err(), eq Some(ListenerWaitError::ContractViolation));
+        assert_that!(listener.blocking_wait_all_with_details(|_| {}).err(), eq Some(ListenerWaitError::ContractViolation));
+    }
+
+    #[test]
+    fn listener_receives_notification_details_of_every_notifier<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&generate_name())
+            .event()
+            .notification_payload_size(12)
+            .disable_notifier_created_event()
+            .disable_notifier_dropped_event()
+            .create()
+            .unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier_1 = sut.notifier_builder().create().unwrap();
+        let notifier_2 = sut
+            .notifier_builder()
+            .default_event_id(EventId::new(5))
+            .create()
+            .unwrap();
+
+        notifier_1
+            .notify_with_custom_event_id_and_payload(EventId::new(1), b"first")
+            .unwrap();
+        notifier_1
+            .notify_with_custom_event_id_and_payload(EventId::new(2), b"second")
+            .unwrap();
+        notifier_1
+            .notify_with_custom_event_id_and_payload(EventId::new(3), b"hello world!")
+            .unwrap();
+        notifier_2.notify().unwrap();
+
+        let mut received = vec![];
+        listener
+            .timed_wait_all_with_details(
+                |details| {
+                    received.push((
+                        details.notifier_id,
+                        details.event_id,
+                        details.number_of_notifications,
+                        details.payload.to_vec(),
+                    ))
+                },
+                TIMEOUT,
+            )
+            .unwrap();
+        received.sort_by_key(|v| v.1);
+
+        assert_that!(received, len 2);
+        assert_that!(
+            received[0],
+            eq(
+                notifier_1.id(),
+                EventId::new(3),
+                3,
+                b"hello world!".to_vec()
+            )
+        );
+        assert_that!(received[1], eq(notifier_2.id(), EventId::new(5), 1, vec![]));
+
+        let mut number_of_details = 0;
+        listener
+            .try_wait_all_with_details(|_| number_of_details += 1)
+            .unwrap();
+        assert_that!(number_of_details, eq 0);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -1579,4 +1731,12 @@ mod service_event {
 
     #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
     mod local_threadsafe {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2::service::ipc_eventfd::Service>)]
+    mod ipc_eventfd {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2::service::ipc_futex::Service>)]
+    mod ipc_futex {}
 }
@@ -0,0 +1,297 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_log {
+    use iceoryx2::log_record::{
+        LogRecord, LOG_FIELD_KEY_CAPACITY, LOG_RECORD_MAX_FIELDS, LOG_RECORD_MESSAGE_CAPACITY,
+    };
+    use iceoryx2::port::log_collector::LogCollectorCreateError;
+    use iceoryx2::port::log_writer::LogWriterCreateError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::log::{LogCreateError, LogOpenError};
+    use iceoryx2::service::messaging_pattern::MessagingPattern;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_log::LogLevel;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn create_and_open_works<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node.service_builder(&service_name).log().create();
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+
+        let sut2 = node.service_builder(&service_name).log().open();
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        assert_that!(sut.service_id(), eq sut2.service_id());
+        assert_that!(
+            Sut::does_exist(&service_name, &config, MessagingPattern::Log).unwrap(),
+            eq true
+        );
+    }
+
+    #[test]
+    fn create_fails_when_service_already_exists<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node.service_builder(&service_name).log().create().unwrap();
+
+        let sut2 = node.service_builder(&service_name).log().create();
+        assert_that!(sut2.err(), eq Some(LogCreateError::AlreadyExists));
+    }
+
+    #[test]
+    fn open_fails_when_requirements_are_not_satisfied<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .log()
+            .max_writers(2)
+            .max_collectors(3)
+            .collector_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .log()
+            .max_writers(3)
+            .open();
+        assert_that!(sut2.err(), eq Some(LogOpenError::DoesNotSupportRequestedAmountOfWriters));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .log()
+            .max_collectors(4)
+            .open();
+        assert_that!(sut2.err(), eq Some(LogOpenError::DoesNotSupportRequestedAmountOfCollectors));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .log()
+            .collector_max_buffer_size(5)
+            .open();
+        assert_that!(sut2.err(), eq Some(LogOpenError::DoesNotSupportRequestedMinBufferSize));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .log()
+            .max_writers(1)
+            .max_collectors(2)
+            .collector_max_buffer_size(3)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn every_collector_receives_the_records_of_all_writers<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_service_name())
+            .log()
+            .create()
+            .unwrap();
+
+        let writer_1 = sut.writer_builder().create().unwrap();
+        let writer_2 = sut.writer_builder().create().unwrap();
+        let collector_1 = sut.collector_builder().create().unwrap();
+        let collector_2 = sut.collector_builder().create().unwrap();
+
+        let record = LogRecord::new(LogLevel::Warn, "origin", "first").with_field("key", "value");
+        assert_that!(writer_1.write(&record).unwrap(), eq 2);
+        assert_that!(
+            writer_2
+                .write(&LogRecord::new(LogLevel::Error, "origin", "second"))
+                .unwrap(),
+            eq 2
+        );
+
+        for collector in [&collector_1, &collector_2] {
+            assert_that!(collector.has_records().unwrap(), eq true);
+
+            let mut messages = vec![];
+            while let Some((header, received)) = collector.receive().unwrap() {
+                assert_that!(header.node_id(), eq * node.id());
+                if received.message() == "first" {
+                    assert_that!(header.log_writer_id(), eq writer_1.id());
+                    assert_that!(received, eq record);
+                    assert_that!(received.level(), eq LogLevel::Warn);
+                    assert_that!(received.fields()[0].key(), eq "key");
+                    assert_that!(received.fields()[0].value(), eq "value");
+                } else {
+                    assert_that!(header.log_writer_id(), eq writer_2.id());
+                    assert_that!(received.level(), eq LogLevel::Error);
+                }
+                messages.push(received.message().to_string());
+            }
+
+            messages.sort();
+            assert_that!(messages, eq vec!["first".to_string(), "second".to_string()]);
+            assert_that!(collector.has_records().unwrap(), eq false);
+        }
+    }
+
+    #[test]
+    fn full_collector_overrides_the_oldest_records<Sut: Service>() {
+        const BUFFER_SIZE: usize = 3;
+        const NUMBER_OF_RECORDS: usize = 50;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_service_name())
+            .log()
+            .collector_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let collector = sut.collector_builder().create().unwrap();
+
+        // the writer must never block or run out of memory when the collector falls behind
+        for n in 0..NUMBER_OF_RECORDS {
+            let record = LogRecord::new(LogLevel::Info, "origin", &n.to_string());
+            assert_that!(writer.write(&record).unwrap(), eq 1);
+        }
+
+        for n in NUMBER_OF_RECORDS - BUFFER_SIZE..NUMBER_OF_RECORDS {
+            let (_, record) = collector.receive().unwrap().unwrap();
+            assert_that!(record.message(), eq n.to_string());
+        }
+        assert_that!(collector.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn write_without_collectors_delivers_to_nobody<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_service_name())
+            .log()
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        for _ in 0..100 {
+            let record = LogRecord::new(LogLevel::Info, "origin", "nobody listens");
+            assert_that!(writer.write(&record).unwrap(), eq 0);
+        }
+    }
+
+    #[test]
+    fn log_record_truncates_oversized_content<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_service_name())
+            .log()
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let collector = sut.collector_builder().create().unwrap();
+
+        // multi-byte characters must not be split
+        let message = "ä".repeat(LOG_RECORD_MESSAGE_CAPACITY);
+        let key = "k".repeat(LOG_FIELD_KEY_CAPACITY + 1);
+        let mut record = LogRecord::new(LogLevel::Debug, "origin", &message);
+        for _ in 0..LOG_RECORD_MAX_FIELDS + 1 {
+            record = record.with_field(&key, "value");
+        }
+        writer.write(&record).unwrap();
+
+        let (_, received) = collector.receive().unwrap().unwrap();
+        assert_that!(received.message(), eq "ä".repeat(LOG_RECORD_MESSAGE_CAPACITY / 2));
+        assert_that!(received.fields(), len LOG_RECORD_MAX_FIELDS);
+        assert_that!(received.fields()[0].key(), eq "k".repeat(LOG_FIELD_KEY_CAPACITY));
+    }
+
+    #[test]
+    fn collector_buffer_size_cannot_exceed_service_buffer_size<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_service_name())
+            .log()
+            .collector_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let collector = sut.collector_builder().buffer_size(2).create().unwrap();
+        assert_that!(collector.buffer_size(), eq 2);
+
+        assert_that!(
+            sut.collector_builder().buffer_size(5).create().err(),
+            eq Some(LogCollectorCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService)
+        );
+    }
+
+    #[test]
+    fn number_of_writers_and_collectors_is_limited<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_service_name())
+            .log()
+            .max_writers(1)
+            .max_collectors(1)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create();
+        assert_that!(writer, is_ok);
+        assert_that!(
+            sut.writer_builder().create().err(),
+            eq Some(LogWriterCreateError::ExceedsMaxSupportedWriters)
+        );
+
+        let collector = sut.collector_builder().create();
+        assert_that!(collector, is_ok);
+        assert_that!(
+            sut.collector_builder().create().err(),
+            eq Some(LogCollectorCreateError::ExceedsMaxSupportedCollectors)
+        );
+
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_collectors(), eq 1);
+
+        drop(writer);
+        drop(collector);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_collectors(), eq 0);
+        assert_that!(sut.writer_builder().create(), is_ok);
+        assert_that!(sut.collector_builder().create(), is_ok);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service>)]
+    mod ipc_threadsafe {}
+
+    #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
+    mod local_threadsafe {}
+}
@@ -0,0 +1,57 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod service_logger {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service_logger::ServiceLogger;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_log::{Log, LogLevel};
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn unconnected_service_logger_falls_back_to_console() {
+        let sut = ServiceLogger::new();
+        assert_that!(sut.is_connected(), eq false);
+
+        sut.log(
+            LogLevel::Info,
+            format_args!("origin"),
+            format_args!("printed to console"),
+        );
+    }
+
+    #[test]
+    fn connected_service_logger_writes_log_records_into_service() {
+        let service_name = generate_service_name();
+        let sut = ServiceLogger::new();
+        assert_that!(sut.connect(&service_name), is_ok);
+        assert_that!(sut.is_connected(), eq true);
+
+        let node = NodeBuilder::new()
+            .create::<ipc_threadsafe::Service>()
+            .unwrap();
+        let service = node.service_builder(&service_name).log().open().unwrap();
+        let collector = service.collector_builder().create().unwrap();
+
+        sut.log(
+            LogLevel::Error,
+            format_args!("{}", "SomeOrigin"),
+            format_args!("something failed {}", 42),
+        );
+
+        let (_, record) = collector.receive().unwrap().unwrap();
+        assert_that!(record.level(), eq LogLevel::Error);
+        assert_that!(record.origin(), eq "SomeOrigin");
+        assert_that!(record.message(), eq "something failed 42");
+        assert_that!(record.timestamp().as_secs(), gt 0);
+    }
+}
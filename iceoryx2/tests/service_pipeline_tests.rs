@@ -0,0 +1,376 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_pipeline {
+    use std::collections::HashSet;
+
+    use iceoryx2::port::consumer::ConsumerCreateError;
+    use iceoryx2::port::producer::ProducerCreateError;
+    use iceoryx2::port::LoanError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::pipeline::{PipelineCreateError, PipelineOpenError};
+    use iceoryx2::service::messaging_pattern::MessagingPattern;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn create_and_open_works<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+
+        let sut2 = node.service_builder(&service_name).pipeline::<u64>().open();
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        assert_that!(sut.service_id(), eq sut2.service_id());
+        assert_that!(
+            Sut::does_exist(&service_name, &config, MessagingPattern::Pipeline).unwrap(),
+            eq true
+        );
+    }
+
+    #[test]
+    fn create_fails_when_service_already_exists<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .create();
+        assert_that!(sut2.err(), eq Some(PipelineCreateError::AlreadyExists));
+    }
+
+    #[test]
+    fn open_fails_with_incompatible_type<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node.service_builder(&service_name).pipeline::<u32>().open();
+        assert_that!(sut2.err(), eq Some(PipelineOpenError::IncompatibleTypes));
+    }
+
+    #[test]
+    fn open_fails_when_requirements_are_not_satisfied<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .max_producers(2)
+            .max_consumers(3)
+            .consumer_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .max_producers(3)
+            .open();
+        assert_that!(sut2.err(), eq Some(PipelineOpenError::DoesNotSupportRequestedAmountOfProducers));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .max_consumers(4)
+            .open();
+        assert_that!(sut2.err(), eq Some(PipelineOpenError::DoesNotSupportRequestedAmountOfConsumers));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .consumer_max_buffer_size(5)
+            .open();
+        assert_that!(sut2.err(), eq Some(PipelineOpenError::DoesNotSupportRequestedMinBufferSize));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .max_producers(1)
+            .max_consumers(2)
+            .consumer_max_buffer_size(3)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn every_work_item_is_received_by_exactly_one_consumer<Sut: Service>() {
+        const NUMBER_OF_CONSUMERS: usize = 3;
+        const NUMBER_OF_ITEMS: u64 = 6;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_service_name())
+            .pipeline::<u64>()
+            .max_consumers(NUMBER_OF_CONSUMERS)
+            .consumer_max_buffer_size(NUMBER_OF_ITEMS as usize)
+            .create()
+            .unwrap();
+
+        let producer = sut.producer_builder().create().unwrap();
+        let consumers: Vec<_> = (0..NUMBER_OF_CONSUMERS)
+            .map(|_| sut.consumer_builder().create().unwrap())
+            .collect();
+
+        for n in 0..NUMBER_OF_ITEMS {
+            let consumer_id = producer.send_copy(n).unwrap();
+            assert_that!(consumer_id, is_some);
+        }
+
+        let mut received = vec![];
+        for consumer in &consumers {
+            while let Some(work_item) = consumer.receive().unwrap() {
+                assert_that!(work_item.origin(), eq producer.id());
+                received.push(*work_item);
+            }
+        }
+
+        received.sort();
+        assert_that!(received, eq(0..NUMBER_OF_ITEMS).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn round_robin_distributes_work_items_evenly<Sut: Service>() {
+        const NUMBER_OF_CONSUMERS: usize = 3;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_service_name())
+            .pipeline::<u64>()
+            .max_consumers(NUMBER_OF_CONSUMERS)
+            .consumer_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let producer = sut
+            .producer_builder()
+            .distribution_policy(DistributionPolicy::RoundRobin)
+            .create()
+            .unwrap();
+        let consumers: Vec<_> = (0..NUMBER_OF_CONSUMERS)
+            .map(|_| sut.consumer_builder().create().unwrap())
+            .collect();
+
+        let mut recipients = vec![];
+        for n in 0..NUMBER_OF_CONSUMERS as u64 * 2 {
+            recipients.push(producer.send_copy(n).unwrap().unwrap());
+        }
+
+        let distinct: HashSet<_> = recipients[0..NUMBER_OF_CONSUMERS].iter().collect();
+        assert_that!(distinct, len NUMBER_OF_CONSUMERS);
+        assert_that!(recipients[0..NUMBER_OF_CONSUMERS], eq recipients[NUMBER_OF_CONSUMERS..]);
+
+        for consumer in &consumers {
+            let mut counter = 0;
+            while consumer.receive().unwrap().is_some() {
+                counter += 1;
+            }
+            assert_that!(counter, eq 2);
+        }
+    }
+
+    #[test]
+    fn least_loaded_prefers_consumer_with_fewest_pending_work_items<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_service_name())
+            .pipeline::<u64>()
+            .max_consumers(2)
+            .consumer_max_buffer_size(8)
+            .create()
+            .unwrap();
+
+        let producer = sut
+            .producer_builder()
+            .distribution_policy(DistributionPolicy::LeastLoaded)
+            .create()
+            .unwrap();
+        let busy_consumer = sut.consumer_builder().create().unwrap();
+        let idle_consumer = sut.consumer_builder().create().unwrap();
+
+        let first = producer.send_copy(1).unwrap().unwrap();
+        let second = producer.send_copy(2).unwrap().unwrap();
+        assert_that!(first, ne second);
+
+        // the idle consumer drains its queue, the busy one does not
+        let (busy_consumer, idle_consumer) = if first == idle_consumer.id() {
+            (busy_consumer, idle_consumer)
+        } else {
+            (idle_consumer, busy_consumer)
+        };
+        assert_that!(idle_consumer.receive().unwrap(), is_some);
+
+        for n in 0..3 {
+            assert_that!(producer.send_copy(n).unwrap(), eq Some(idle_consumer.id()));
+            assert_that!(idle_consumer.receive().unwrap(), is_some);
+        }
+
+        assert_that!(busy_consumer.has_work_items().unwrap(), eq true);
+    }
+
+    #[test]
+    fn discard_strategy_returns_none_when_all_consumers_are_full<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_service_name())
+            .pipeline::<u64>()
+            .max_consumers(2)
+            .consumer_max_buffer_size(1)
+            .create()
+            .unwrap();
+
+        let producer = sut
+            .producer_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        let consumer_1 = sut.consumer_builder().create().unwrap();
+        let consumer_2 = sut.consumer_builder().create().unwrap();
+
+        assert_that!(producer.send_copy(1).unwrap(), is_some);
+        assert_that!(producer.send_copy(2).unwrap(), is_some);
+        assert_that!(producer.send_copy(3).unwrap(), is_none);
+
+        assert_that!(consumer_1.receive().unwrap(), is_some);
+        assert_that!(producer.send_copy(4).unwrap(), eq Some(consumer_1.id()));
+        assert_that!(consumer_2.receive().unwrap(), is_some);
+    }
+
+    #[test]
+    fn send_without_consumers_returns_none<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_service_name())
+            .pipeline::<u64>()
+            .create()
+            .unwrap();
+
+        let producer = sut.producer_builder().create().unwrap();
+
+        assert_that!(producer.send_copy(1).unwrap(), is_none);
+        let work_item = producer.loan_uninit().unwrap().write_payload(2);
+        assert_that!(work_item.send().unwrap(), is_none);
+    }
+
+    #[test]
+    fn dropped_work_items_are_returned_to_the_producer<Sut: Service>() {
+        const MAX_LOANED_ITEMS: usize = 2;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_service_name())
+            .pipeline::<u64>()
+            .max_consumers(1)
+            .consumer_max_buffer_size(1)
+            .consumer_max_borrowed_items(1)
+            .create()
+            .unwrap();
+
+        let producer = sut
+            .producer_builder()
+            .max_loaned_items(MAX_LOANED_ITEMS)
+            .create()
+            .unwrap();
+        let consumer = sut.consumer_builder().create().unwrap();
+
+        for n in 0..10 {
+            assert_that!(producer.send_copy(n).unwrap(), eq Some(consumer.id()));
+            let work_item = consumer.receive().unwrap().unwrap();
+            assert_that!(*work_item, eq n);
+            assert_that!(work_item.header().sequence_number(), eq n);
+        }
+
+        let item_1 = producer.loan().unwrap();
+        let _item_2 = producer.loan().unwrap();
+        assert_that!(producer.loan().err(), eq Some(LoanError::ExceedsMaxLoans));
+        drop(item_1);
+        assert_that!(producer.loan(), is_ok);
+    }
+
+    #[test]
+    fn number_of_producers_and_consumers_is_limited<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_service_name())
+            .pipeline::<u64>()
+            .max_producers(1)
+            .max_consumers(1)
+            .create()
+            .unwrap();
+
+        let producer = sut.producer_builder().create();
+        assert_that!(producer, is_ok);
+        assert_that!(
+            sut.producer_builder().create().err(),
+            eq Some(ProducerCreateError::ExceedsMaxSupportedProducers)
+        );
+
+        let consumer = sut.consumer_builder().create();
+        assert_that!(consumer, is_ok);
+        assert_that!(
+            sut.consumer_builder().create().err(),
+            eq Some(ConsumerCreateError::ExceedsMaxSupportedConsumers)
+        );
+
+        assert_that!(sut.dynamic_config().number_of_producers(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_consumers(), eq 1);
+
+        drop(producer);
+        drop(consumer);
+        assert_that!(sut.dynamic_config().number_of_producers(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_consumers(), eq 0);
+        assert_that!(sut.producer_builder().create(), is_ok);
+        assert_that!(sut.consumer_builder().create(), is_ok);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service>)]
+    mod ipc_threadsafe {}
+
+    #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
+    mod local_threadsafe {}
+}
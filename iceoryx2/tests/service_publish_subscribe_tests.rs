@@ -13,6 +13,7 @@
 #[generic_tests::define]
 mod service_publish_subscribe {
     use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use core::time::Duration;
     use std::sync::{Barrier, Mutex};
     use std::thread;
 
@@ -33,9 +34,13 @@ mod service_publish_subscribe {
     use iceoryx2_bb_elementary::alignment::Alignment;
     use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::event::Event;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
 
     #[derive(Debug, ZeroCopySend)]
     #[repr(C)]
@@ -431,6 +436,147 @@ mod service_publish_subscribe {
         );
     }
 
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_notify_on_send_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(false)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(true)
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatibleNotifyOnSendBehavior
+        );
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+
+        assert_that!(sut3, is_ok);
+        assert_that!(sut3.unwrap().static_config().has_notify_on_send(), eq false);
+    }
+
+    #[test]
+    fn subscriber_wakes_up_waitset_when_notify_on_send_is_enabled<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().has_notify_on_send(), eq true);
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = waitset
+            .attach_notification(subscriber.signal_listener().unwrap())
+            .unwrap();
+
+        publisher.send_copy(8912).unwrap();
+        publisher.send_copy(1234).unwrap();
+
+        let mut received_samples = vec![];
+        waitset
+            .wait_and_process_once(|attachment_id| {
+                assert_that!(attachment_id.has_event_from(&guard), eq true);
+                while let Some(sample) = subscriber.receive().unwrap() {
+                    received_samples.push(*sample);
+                }
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+        assert_that!(received_samples, eq vec![8912, 1234]);
+
+        // all signals were consumed by receive, therefore the waitset must not wake up
+        let mut number_of_wakeups = 0;
+        waitset
+            .wait_and_process_once_with_timeout(
+                |_| {
+                    number_of_wakeups += 1;
+                    CallbackProgression::Continue
+                },
+                TIMEOUT,
+            )
+            .unwrap();
+        assert_that!(number_of_wakeups, eq 0);
+    }
+
+    #[test]
+    fn subscriber_has_no_signal_listener_when_notify_on_send_is_disabled<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(subscriber.signal_listener().is_none(), eq true);
+    }
+
+    #[test]
+    fn publisher_does_not_notify_when_no_sample_was_delivered<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        publisher.send_copy(123).unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let _guard = waitset
+            .attach_notification(subscriber.signal_listener().unwrap())
+            .unwrap();
+
+        let mut number_of_wakeups = 0;
+        waitset
+            .wait_and_process_once_with_timeout(
+                |_| {
+                    number_of_wakeups += 1;
+                    CallbackProgression::Continue
+                },
+                TIMEOUT,
+            )
+            .unwrap();
+        assert_that!(number_of_wakeups, eq 0);
+    }
+
     #[test]
     fn open_fails_when_service_does_not_satisfy_history_requirement<Sut: Service>() {
         let service_name = generate_name();
@@ -2765,6 +2911,8 @@ mod service_publish_subscribe {
                                   "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes");
         assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleOverflowBehavior), eq
                                   "PublishSubscribeOpenError::IncompatibleOverflowBehavior");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleNotifyOnSendBehavior), eq
+                                  "PublishSubscribeOpenError::IncompatibleNotifyOnSendBehavior");
         assert_that!(format!("{}", PublishSubscribeOpenError::InsufficientPermissions), eq
                                   "PublishSubscribeOpenError::InsufficientPermissions");
         assert_that!(format!("{}", PublishSubscribeOpenError::ServiceInCorruptedState), eq
@@ -508,6 +508,34 @@ mod service_request_response {
         assert_that!(sut_open, is_ok);
     }
 
+    #[test]
+    fn open_verifies_notify_on_send_setting_correctly<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_notify_on_send(true)
+            .create();
+        assert_that!(sut_create, is_ok);
+        assert_that!(sut_create.unwrap().static_config().has_notify_on_send(), eq true);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_notify_on_send(false)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleNotifyOnSendBehavior));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_notify_on_send(true)
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
     #[test]
     fn open_verifies_max_borrowed_responses_per_pending_response_correctly<Sut: Service>() {
         let service_name = generate_service_name();
@@ -13,16 +13,22 @@
 #[generic_tests::define]
 mod service_request_response {
     use std::collections::HashSet;
+    use std::sync::Barrier;
+    use std::time::Duration;
 
     use iceoryx2::node::NodeBuilder;
     use iceoryx2::port::client::Client;
     use iceoryx2::port::server::Server;
     use iceoryx2::port::LoanError;
+    use iceoryx2::port::TimedReceiveError;
     use iceoryx2::prelude::{PortFactory, *};
     use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::testing;
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
     use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::event::Event;
 
     #[derive(Clone, Copy)]
     struct Args {
@@ -1606,6 +1612,400 @@ mod service_request_response {
         assert_that!(active_request.has_disconnect_hint(), eq false);
     }
 
+    #[test]
+    fn server_and_pending_response_wake_up_waitset_when_notify_on_send_is_enabled<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let server_guard = waitset
+            .attach_notification(server.signal_listener().unwrap())
+            .unwrap();
+
+        let pending_response = client.send_copy(5).unwrap();
+        let response_guard = waitset
+            .attach_notification(pending_response.signal_listener().unwrap())
+            .unwrap();
+
+        let mut received_requests = vec![];
+        waitset
+            .wait_and_process_once(|attachment_id| {
+                assert_that!(attachment_id.has_event_from(&server_guard), eq true);
+                while let Some(active_request) = server.receive().unwrap() {
+                    received_requests.push(*active_request);
+                    active_request.send_copy(*active_request + 1).unwrap();
+                }
+                CallbackProgression::Continue
+            })
+            .unwrap();
+        assert_that!(received_requests, eq vec![5]);
+
+        let mut received_responses = vec![];
+        waitset
+            .wait_and_process_once(|attachment_id| {
+                assert_that!(attachment_id.has_event_from(&response_guard), eq true);
+                while let Some(response) = pending_response.receive().unwrap() {
+                    received_responses.push(*response);
+                }
+                CallbackProgression::Continue
+            })
+            .unwrap();
+        assert_that!(received_responses, eq vec![6]);
+    }
+
+    #[test]
+    fn response_wakes_up_only_the_addressed_client<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+
+        let client_a = service.client_builder().create().unwrap();
+        let client_b = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response_a = client_a.send_copy(1).unwrap();
+        let pending_response_b = client_b.send_copy(2).unwrap();
+
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard_b = waitset
+            .attach_notification(pending_response_b.signal_listener().unwrap())
+            .unwrap();
+
+        while let Some(active_request) = server.receive().unwrap() {
+            if *active_request == 1 {
+                active_request.send_copy(10).unwrap();
+            }
+        }
+
+        let mut number_of_wakeups = 0;
+        waitset
+            .wait_and_process_once_with_timeout(
+                |_| {
+                    number_of_wakeups += 1;
+                    CallbackProgression::Continue
+                },
+                Duration::from_millis(50),
+            )
+            .unwrap();
+        assert_that!(number_of_wakeups, eq 0);
+
+        let guard_a = waitset
+            .attach_notification(pending_response_a.signal_listener().unwrap())
+            .unwrap();
+        let mut woken_up_a = false;
+        waitset
+            .wait_and_process_once(|attachment_id| {
+                woken_up_a |= attachment_id.has_event_from(&guard_a);
+                assert_that!(attachment_id.has_event_from(&guard_b), eq false);
+                CallbackProgression::Continue
+            })
+            .unwrap();
+        assert_that!(woken_up_a, eq true);
+    }
+
+    #[test]
+    fn server_has_no_signal_listener_when_notify_on_send_is_disabled<Sut: Service>() {
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+        let pending_response = client.send_copy(5).unwrap();
+
+        assert_that!(server.signal_listener().is_none(), eq true);
+        assert_that!(pending_response.signal_listener().is_none(), eq true);
+    }
+
+    #[test]
+    fn request_without_deadline_has_no_deadline<Sut: Service>() {
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy(5).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+
+        assert_that!(pending_response.header().deadline(), is_none);
+        assert_that!(pending_response.time_until_deadline(), is_none);
+        assert_that!(pending_response.has_missed_deadline(), eq false);
+        assert_that!(active_request.header().deadline(), is_none);
+        assert_that!(active_request.time_until_deadline(), is_none);
+        assert_that!(active_request.has_missed_deadline(), eq false);
+    }
+
+    #[test]
+    fn deadline_of_request_is_readable_from_active_request<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_secs(3600);
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy_with_deadline(5, DEADLINE).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+
+        assert_that!(active_request.header().deadline(), eq pending_response.header().deadline());
+        assert_that!(active_request.time_until_deadline().unwrap(), le DEADLINE);
+        assert_that!(active_request.time_until_deadline().unwrap(), gt Duration::ZERO);
+        assert_that!(active_request.has_missed_deadline(), eq false);
+        assert_that!(pending_response.has_missed_deadline(), eq false);
+    }
+
+    #[test]
+    fn deadline_is_missed_after_timeout<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(10);
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy_with_deadline(5, DEADLINE).unwrap();
+        std::thread::sleep(DEADLINE * 2);
+        let active_request = server.receive().unwrap().unwrap();
+
+        assert_that!(active_request.has_missed_deadline(), eq true);
+        assert_that!(active_request.time_until_deadline(), eq Some(Duration::ZERO));
+        assert_that!(pending_response.has_missed_deadline(), eq true);
+        assert_that!(pending_response.time_until_deadline(), eq Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn timed_receive_returns_none_when_timeout_passes_before_deadline<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        const TIMEOUT: Duration = Duration::from_millis(10);
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let _server = service.server_builder().create().unwrap();
+
+        let pending_response = client
+            .send_copy_with_deadline(5, Duration::from_secs(3600))
+            .unwrap();
+
+        let result = pending_response.timed_receive(TIMEOUT);
+        assert_that!(result, is_ok);
+        assert_that!(result.unwrap(), is_none);
+    }
+
+    #[test]
+    fn timed_receive_fails_when_deadline_is_missed<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        const DEADLINE: Duration = Duration::from_millis(10);
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let _server = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy_with_deadline(5, DEADLINE).unwrap();
+
+        let result = pending_response.timed_receive(Duration::from_secs(3600));
+        assert_that!(result.err(), eq Some(TimedReceiveError::DeadlineMissed));
+    }
+
+    #[test]
+    fn timed_receive_returns_response_before_deadline<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response = client
+            .send_copy_with_deadline(5, Duration::from_secs(3600))
+            .unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        active_request.send_copy(*active_request + 1).unwrap();
+
+        let response = pending_response
+            .timed_receive(Duration::from_secs(3600))
+            .unwrap();
+        assert_that!(response, is_some);
+        assert_that!(*response.unwrap(), eq 6);
+    }
+
+    #[test]
+    fn timed_receive_with_notify_on_send_fails_when_deadline_is_missed<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        const DEADLINE: Duration = Duration::from_millis(10);
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let _server = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy_with_deadline(5, DEADLINE).unwrap();
+
+        let result = pending_response.timed_receive(Duration::from_secs(3600));
+        assert_that!(result.err(), eq Some(TimedReceiveError::DeadlineMissed));
+    }
+
+    #[test]
+    fn timed_receive_with_notify_on_send_wakes_up_on_response<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+                let service = node
+                    .service_builder(&service_name)
+                    .request_response::<u64, u64>()
+                    .open()
+                    .unwrap();
+                let server = service.server_builder().create().unwrap();
+                barrier.wait();
+
+                loop {
+                    if let Some(active_request) = server.receive().unwrap() {
+                        std::thread::sleep(Duration::from_millis(50));
+                        active_request.send_copy(*active_request + 1).unwrap();
+                        break;
+                    }
+                    std::thread::yield_now();
+                }
+            });
+
+            barrier.wait();
+            let pending_response = client.send_copy(5).unwrap();
+            let response = pending_response
+                .timed_receive(Duration::from_secs(3600))
+                .unwrap();
+            assert_that!(response, is_some);
+            assert_that!(*response.unwrap(), eq 6);
+        });
+    }
+
+    #[test]
+    fn pending_response_deadline_wakes_up_waitset<Sut: Service>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        const DEADLINE: Duration = Duration::from_millis(10);
+        let service_name = testing::generate_service_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_notify_on_send(true)
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let _server = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy_with_deadline(5, DEADLINE).unwrap();
+
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = waitset
+            .attach_deadline(
+                &pending_response,
+                pending_response.time_until_deadline().unwrap(),
+            )
+            .unwrap();
+
+        let mut missed_deadline = false;
+        waitset
+            .wait_and_process_once(|attachment_id| {
+                missed_deadline = attachment_id.has_missed_deadline(&guard);
+                CallbackProgression::Stop
+            })
+            .unwrap();
+
+        assert_that!(missed_deadline, eq true);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -12,6 +12,9 @@
 
 #[generic_tests::define]
 mod subscriber {
+    use iceoryx2::port::sample_filter::{
+        SampleFilter, SampleFilterCreateError, MAX_SAMPLE_FILTER_LEN,
+    };
     use iceoryx2::port::ReceiveError;
     use iceoryx2::service::builder::CustomPayloadMarker;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
@@ -72,6 +75,264 @@ mod subscriber {
         }
     }
 
+    #[test]
+    fn number_of_missed_samples_is_none_for_disconnected_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().create().unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let publisher_id = publisher.id();
+
+        assert_that!(sut.receive().unwrap(), is_none);
+        assert_that!(sut.number_of_missed_samples(&publisher_id), eq Some(0));
+
+        drop(publisher);
+        assert_that!(sut.receive().unwrap(), is_none);
+        assert_that!(sut.number_of_missed_samples(&publisher_id), eq None);
+    }
+
+    #[test]
+    fn number_of_missed_samples_counts_overflown_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(1)
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().create().unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        publisher.send_copy(0).unwrap();
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 0);
+        assert_that!(sut.number_of_missed_samples(&publisher.id()), eq Some(0));
+
+        for n in 1..5 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 4);
+        assert_that!(sample.sequence_number(), eq 4);
+        assert_that!(sut.number_of_missed_samples(&publisher.id()), eq Some(3));
+    }
+
+    #[test]
+    fn subscriber_with_filter_receives_only_matching_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(10)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .filter(
+                SampleFilter::payload(0, &[0])
+                    .unwrap()
+                    .with_mask(&[1])
+                    .unwrap(),
+            )
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        for n in 0..10 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        for n in (0..10).step_by(2) {
+            assert_that!(*sut.receive().unwrap().unwrap(), eq n);
+        }
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn number_of_missed_samples_does_not_count_filtered_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(1)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .filter(
+                SampleFilter::payload(0, &[0])
+                    .unwrap()
+                    .with_mask(&[1])
+                    .unwrap(),
+            )
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        for n in 0..4 {
+            publisher.send_copy(n).unwrap();
+            if n % 2 == 0 {
+                assert_that!(*sut.receive().unwrap().unwrap(), eq n);
+            }
+        }
+        assert_that!(sut.number_of_missed_samples(&publisher.id()), eq Some(0));
+
+        for n in 4..10 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 8);
+        assert_that!(sample.sequence_number(), eq 8);
+        assert_that!(sut.number_of_missed_samples(&publisher.id()), eq Some(2));
+    }
+
+    #[test]
+    fn subscriber_with_filter_can_filter_by_user_header<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .user_header::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::user_header(0, &42u64.to_ne_bytes()).unwrap())
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(2)
+            .create()
+            .unwrap();
+
+        for user_header in [1, 42, 2] {
+            let mut sample = publisher.loan_slice_uninit(2).unwrap();
+            *sample.user_header_mut() = user_header;
+            sample.write_from_slice(&[3, 4]).send().unwrap();
+        }
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample.user_header(), eq 42);
+        assert_that!(*sample, eq [3, 4]);
+        drop(sample);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn subscriber_with_filter_does_not_buffer_rejected_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::payload(0, &9u64.to_ne_bytes()).unwrap())
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(9).unwrap(), eq 1);
+        for n in 0..9 {
+            assert_that!(publisher.send_copy(n).unwrap(), eq 0);
+        }
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 9);
+        assert_that!(sut.has_samples().unwrap(), eq false);
+    }
+
+    #[test]
+    fn subscriber_with_filter_receives_only_matching_history<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(10)
+            .subscriber_max_buffer_size(2)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        for n in 0..10 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let sut = service
+            .subscriber_builder()
+            .filter(
+                SampleFilter::payload(0, &[1])
+                    .unwrap()
+                    .with_mask(&[1])
+                    .unwrap(),
+            )
+            .create()
+            .unwrap();
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 7);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 9);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn subscriber_with_filter_beyond_user_header_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<u32>()
+            .create()
+            .unwrap();
+
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::user_header(2, &[0, 0, 0]).unwrap())
+            .create();
+
+        assert_that!(sut.err(), eq Some(SubscriberCreateError::SampleFilterOutOfBounds));
+    }
+
+    #[test]
+    fn sample_filter_with_wrong_mask_length_fails<Sut: Service>() {
+        let sut = SampleFilter::payload(0, &[1, 2]).unwrap().with_mask(&[1]);
+        assert_that!(sut.err(), eq Some(SampleFilterCreateError::MaskLengthMismatch));
+
+        let sut = SampleFilter::payload(0, &[0; MAX_SAMPLE_FILTER_LEN + 1]);
+        assert_that!(sut.err(), eq Some(SampleFilterCreateError::ExceedsMaxLength));
+    }
+
     #[test]
     #[should_panic]
     #[cfg(debug_assertions)]
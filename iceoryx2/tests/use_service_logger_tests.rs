@@ -0,0 +1,49 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// the process wide logger can be set only once, therefore this test requires its own process
+mod use_service_logger {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service_logger::{use_service_logger, ServiceLoggerConnectError};
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_log::LogLevel;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn use_service_logger_forwards_process_logs_into_service() {
+        let service_name = generate_service_name();
+        assert_that!(use_service_logger(&service_name), is_ok);
+        assert_that!(
+            use_service_logger(&service_name).err(),
+            eq Some(ServiceLoggerConnectError::LoggerAlreadySet)
+        );
+
+        let node = NodeBuilder::new()
+            .create::<ipc_threadsafe::Service>()
+            .unwrap();
+        let service = node.service_builder(&service_name).log().open().unwrap();
+        let collector = service.collector_builder().create().unwrap();
+
+        iceoryx2_bb_log::set_log_level(LogLevel::Info);
+        iceoryx2_bb_log::warn!(from "TestOrigin", "forwarded warning");
+
+        let mut is_received = false;
+        while let Some((_, record)) = collector.receive().unwrap() {
+            if record.message() == "forwarded warning" {
+                assert_that!(record.level(), eq LogLevel::Warn);
+                assert_that!(record.origin(), eq "\"TestOrigin\"");
+                is_received = true;
+            }
+        }
+        assert_that!(is_received, eq true);
+    }
+}
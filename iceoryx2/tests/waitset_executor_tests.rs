@@ -0,0 +1,266 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod waitset_executor {
+    use core::fmt::Debug;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use core::time::Duration;
+    use std::sync::{Arc, Barrier, Mutex};
+
+    use iceoryx2::port::listener::Listener;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2::waitset::WaitSetRunResult;
+    use iceoryx2::waitset_executor::*;
+    use iceoryx2_bb_posix::thread::{ThreadHandle, ThreadProperties};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+
+    const INTERVAL: Duration = Duration::from_millis(1);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "waitset_executor_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn create_executor<S: Service>(number_of_workers: usize) -> WaitSetExecutor<S> {
+        WaitSetExecutorBuilder::new()
+            .number_of_workers(number_of_workers)
+            .signal_handling_mode(SignalHandlingMode::Disabled)
+            .create::<S>()
+            .unwrap()
+    }
+
+    #[test]
+    fn number_of_workers_of_zero_is_treated_as_one<S: Service>() {
+        let sut = create_executor::<S>(0);
+
+        assert_that!(sut.number_of_workers(), eq 1);
+    }
+
+    #[test]
+    fn configuring_non_existing_worker_fails<S: Service>() {
+        let sut = WaitSetExecutorBuilder::new()
+            .number_of_workers(2)
+            .worker_priority(2, 0)
+            .create::<S>();
+
+        assert_that!(sut.err(), eq Some(WaitSetExecutorCreateError::WorkerDoesNotExist));
+    }
+
+    #[test]
+    fn run_without_attachments_fails<S: Service>() {
+        let sut = create_executor::<S>(1);
+
+        assert_that!(sut.is_empty(), eq true);
+        assert_that!(sut.run().err(), eq Some(WaitSetExecutorRunError::NoAttachments));
+    }
+
+    #[test]
+    fn pin_to_non_existing_worker_fails<S: Service>() {
+        let mut sut = create_executor::<S>(2);
+        let id = sut
+            .attach_interval(INTERVAL, || CallbackProgression::Continue)
+            .unwrap();
+
+        assert_that!(sut.len(), eq 1);
+        assert_that!(sut.pin_to_worker(id, 1), is_ok);
+        assert_that!(sut.pin_to_worker(id, 2).err(), eq Some(WaitSetExecutorPinError::WorkerDoesNotExist));
+    }
+
+    #[test]
+    fn notification_callback_is_called_by_worker<S: Service>()
+    where
+        Listener<S>: SynchronousMultiplexing + Debug + Send + Sync + 'static,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+        let mut sut = create_executor::<S>(2);
+
+        let received_events = Arc::new(Mutex::new(vec![]));
+        let worker_name = Arc::new(Mutex::new(String::new()));
+        let received_events_cb = received_events.clone();
+        let worker_name_cb = worker_name.clone();
+        sut.attach_notification(listener, move |listener| {
+            listener
+                .try_wait_all(|id| received_events_cb.lock().unwrap().push(id))
+                .unwrap();
+            *worker_name_cb.lock().unwrap() =
+                ThreadHandle::from_self().get_name().unwrap().to_string();
+            CallbackProgression::Stop
+        })
+        .unwrap();
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(7))
+            .unwrap();
+
+        assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+        assert_that!(*received_events.lock().unwrap(), eq vec![EventId::new(7)]);
+        assert_that!(worker_name.lock().unwrap().starts_with("iox2_worker_"), eq true);
+    }
+
+    #[test]
+    fn notification_with_pending_events_is_dispatched_again_after_callback_returned<S: Service>()
+    where
+        Listener<S>: SynchronousMultiplexing + Debug + Send + Sync + 'static,
+    {
+        const NUMBER_OF_EVENTS: usize = 3;
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+        let mut sut = create_executor::<S>(2);
+
+        let received_events = Arc::new(Mutex::new(vec![]));
+        let received_events_cb = received_events.clone();
+        sut.attach_notification(listener, move |listener| {
+            let mut received_events = received_events_cb.lock().unwrap();
+            if let Some(id) = listener.try_wait_one().unwrap() {
+                received_events.push(id);
+            }
+
+            if received_events.len() == NUMBER_OF_EVENTS {
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        })
+        .unwrap();
+
+        for i in 0..NUMBER_OF_EVENTS {
+            notifier
+                .notify_with_custom_event_id(EventId::new(i))
+                .unwrap();
+        }
+
+        assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+        assert_that!(*received_events.lock().unwrap(), len NUMBER_OF_EVENTS);
+    }
+
+    #[test]
+    fn panicking_callback_stops_the_executor<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let mut sut = create_executor::<S>(2);
+
+        sut.attach_interval(INTERVAL, || CallbackProgression::Continue)
+            .unwrap();
+        sut.attach_interval(INTERVAL * 5, || panic!("callback failure"))
+            .unwrap();
+
+        assert_that!(sut.run().err(), eq Some(WaitSetExecutorRunError::CallbackPanicked));
+    }
+
+    #[test]
+    fn callbacks_of_same_attachment_are_never_executed_concurrently<S: Service>() {
+        const NUMBER_OF_CALLS: usize = 10;
+        let _watchdog = Watchdog::new();
+        let mut sut = create_executor::<S>(4);
+
+        let active = Arc::new(AtomicUsize::new(0));
+        let max_active = Arc::new(AtomicUsize::new(0));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let max_active_cb = max_active.clone();
+        let calls_cb = calls.clone();
+        sut.attach_interval(INTERVAL, move || {
+            let current = active.fetch_add(1, Ordering::Relaxed) + 1;
+            max_active_cb.fetch_max(current, Ordering::Relaxed);
+            std::thread::sleep(INTERVAL * 5);
+            active.fetch_sub(1, Ordering::Relaxed);
+
+            if calls_cb.fetch_add(1, Ordering::Relaxed) + 1 == NUMBER_OF_CALLS {
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        })
+        .unwrap();
+
+        assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+        assert_that!(calls.load(Ordering::Relaxed), eq NUMBER_OF_CALLS);
+        assert_that!(max_active.load(Ordering::Relaxed), eq 1);
+    }
+
+    #[test]
+    fn callbacks_of_different_attachments_are_executed_in_parallel<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let mut sut = create_executor::<S>(2);
+
+        // would deadlock when both callbacks are executed by the same worker
+        let barrier = Arc::new(Barrier::new(2));
+        for _ in 0..2 {
+            let barrier = barrier.clone();
+            sut.attach_interval(INTERVAL, move || {
+                barrier.wait();
+                CallbackProgression::Stop
+            })
+            .unwrap();
+        }
+
+        assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+    }
+
+    #[test]
+    fn pinned_attachment_is_executed_by_its_worker<S: Service>() {
+        const NUMBER_OF_CALLS: usize = 5;
+        let _watchdog = Watchdog::new();
+        let mut sut = create_executor::<S>(3);
+
+        let worker_names = Arc::new(Mutex::new(vec![]));
+        let worker_names_cb = worker_names.clone();
+        let id = sut
+            .attach_interval(INTERVAL, move || {
+                let mut worker_names = worker_names_cb.lock().unwrap();
+                worker_names.push(ThreadHandle::from_self().get_name().unwrap().to_string());
+
+                if worker_names.len() == NUMBER_OF_CALLS {
+                    CallbackProgression::Stop
+                } else {
+                    CallbackProgression::Continue
+                }
+            })
+            .unwrap();
+        sut.pin_to_worker(id, 2).unwrap();
+
+        assert_that!(sut.run(), eq Ok(WaitSetRunResult::StopRequest));
+        let worker_names = worker_names.lock().unwrap();
+        assert_that!(*worker_names, len NUMBER_OF_CALLS);
+        for name in worker_names.iter() {
+            assert_that!(name, eq "iox2_worker_2");
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service>)]
+    mod ipc_threadsafe {}
+
+    #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
+    mod local_threadsafe {}
+}
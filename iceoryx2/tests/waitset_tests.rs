@@ -19,7 +19,9 @@ mod waitset {
     use iceoryx2::port::notifier::Notifier;
     use iceoryx2::prelude::{WaitSetBuilder, *};
     use iceoryx2::testing::*;
-    use iceoryx2::waitset::{WaitSetAttachmentError, WaitSetRunError};
+    use iceoryx2::waitset::{
+        MissedTickPolicy, TimerSchedule, WaitSetAttachmentError, WaitSetRunError,
+    };
     use iceoryx2_bb_posix::config::test_directory;
     use iceoryx2_bb_posix::directory::Directory;
     use iceoryx2_bb_posix::file::Permission;
@@ -298,6 +300,63 @@ mod waitset {
         assert_that!(start.elapsed(), time_at_least TIMEOUT);
     }
 
+    #[test]
+    fn one_shot_timer_is_reported_only_once<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let guard = sut
+            .attach_timer(TimerSchedule::one_shot_after(Duration::from_millis(1)))
+            .unwrap();
+
+        let mut callback_counter = 0;
+        sut.wait_and_process_once(|id| {
+            assert_that!(id.has_event_from(&guard), eq true);
+            callback_counter += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(callback_counter, eq 1);
+
+        let start = Instant::now();
+        sut.wait_and_process_once_with_timeout(
+            |_| {
+                callback_counter += 1;
+                CallbackProgression::Continue
+            },
+            TIMEOUT,
+        )
+        .unwrap();
+
+        assert_that!(callback_counter, eq 1);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+    }
+
+    #[test]
+    fn timer_with_catch_up_policy_reports_every_missed_tick<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let guard = sut
+            .attach_timer(
+                TimerSchedule::interval(Duration::from_millis(1))
+                    .missed_tick_policy(MissedTickPolicy::CatchUp),
+            )
+            .unwrap();
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        let mut callback_counter = 0;
+        sut.wait_and_process_once(|id| {
+            assert_that!(id.has_event_from(&guard), eq true);
+            callback_counter += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(callback_counter, ge 20);
+    }
+
     #[test]
     fn wait_and_process_once_with_timeout_blocks_at_each_invocation<S: Service>()
     where
@@ -627,6 +686,213 @@ mod waitset {
         assert_that!(sut.signal_handling_mode(), eq SignalHandlingMode::HandleTerminationRequests);
     }
 
+    #[test]
+    fn attachment_priority_is_zero_by_default_and_can_be_set<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, _) = create_event::<S>(&node);
+        let guard = sut.attach_notification(&listener).unwrap();
+        let tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        assert_that!(guard.priority(), eq 0);
+        assert_that!(tick_guard.priority(), eq 0);
+
+        guard.set_priority(12);
+        tick_guard.set_priority(7);
+
+        assert_that!(guard.priority(), eq 12);
+        assert_that!(tick_guard.priority(), eq 7);
+    }
+
+    #[test]
+    fn attachment_priority_is_reset_when_detached<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, _) = create_event::<S>(&node);
+        let guard = sut.attach_notification(&listener).unwrap();
+        guard.set_priority(200);
+        drop(guard);
+
+        let guard = sut.attach_notification(&listener).unwrap();
+        assert_that!(guard.priority(), eq 0);
+    }
+
+    #[test]
+    fn attachments_with_higher_priority_are_dispatched_first<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        const NUMBER_OF_ATTACHMENTS: usize = 8;
+
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let mut listeners = vec![];
+        let mut notifiers = vec![];
+        for _ in 0..NUMBER_OF_ATTACHMENTS {
+            let (listener, notifier) = create_event::<S>(&node);
+            listeners.push(listener);
+            notifiers.push(notifier);
+        }
+
+        let mut guards = vec![];
+        for (n, listener) in listeners.iter().enumerate() {
+            let guard = sut.attach_notification(listener).unwrap();
+            // the last attachment has the highest priority and some share the same priority
+            guard.set_priority((n / 2) as u8);
+            guards.push(guard);
+        }
+
+        for notifier in &notifiers {
+            notifier.notify().unwrap();
+        }
+
+        let mut dispatched_priorities = vec![];
+        sut.wait_and_process_once(|id| {
+            for guard in &guards {
+                if id.has_event_from(guard) {
+                    dispatched_priorities.push(guard.priority());
+                }
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(dispatched_priorities, len NUMBER_OF_ATTACHMENTS);
+        for window in dispatched_priorities.windows(2) {
+            assert_that!(window[0], ge window[1]);
+        }
+    }
+
+    #[test]
+    fn event_limit_is_not_set_by_default<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, notifier) = create_event::<S>(&node);
+        let _guard = sut.attach_notification(&listener).unwrap();
+        notifier.notify().unwrap();
+
+        let mut event_limit = Some(0);
+        sut.wait_and_process_once(|id| {
+            event_limit = id.event_limit();
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(sut.event_limit_per_attachment(), eq None);
+        assert_that!(event_limit, eq None);
+    }
+
+    #[test]
+    fn event_limit_is_provided_to_callback_in_fairness_mode<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        const EVENT_LIMIT: usize = 3;
+
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new()
+            .event_limit_per_attachment(EVENT_LIMIT)
+            .create::<S>()
+            .unwrap();
+
+        let (listener, notifier) = create_event::<S>(&node);
+        let _guard = sut.attach_notification(&listener).unwrap();
+        let _tick_guard = sut.attach_interval(Duration::from_nanos(1)).unwrap();
+        notifier.notify().unwrap();
+
+        let mut event_limits = vec![];
+        sut.wait_and_process_once(|id| {
+            event_limits.push(id.event_limit());
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(sut.event_limit_per_attachment(), eq Some(EVENT_LIMIT));
+        assert_that!(event_limits, is_not_empty);
+        for event_limit in event_limits {
+            assert_that!(event_limit, eq Some(EVENT_LIMIT));
+        }
+    }
+
+    #[test]
+    fn events_beyond_the_event_limit_are_dispatched_in_the_next_wake_up<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        const EVENT_LIMIT: usize = 2;
+        const NUMBER_OF_EVENTS: usize = 5;
+
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new()
+            .event_limit_per_attachment(EVENT_LIMIT)
+            .create::<S>()
+            .unwrap();
+
+        let (listener, notifier) = create_event::<S>(&node);
+        let _guard = sut.attach_notification(&listener).unwrap();
+        for i in 0..NUMBER_OF_EVENTS {
+            notifier
+                .notify_with_custom_event_id(EventId::new(i))
+                .unwrap();
+        }
+
+        let mut events_per_wake_up = vec![];
+        while events_per_wake_up.iter().sum::<usize>() < NUMBER_OF_EVENTS {
+            let mut dispatches = 0;
+            let mut events = 0;
+            sut.wait_and_process_once_with_timeout(
+                |id| {
+                    dispatches += 1;
+                    for _ in 0..id.event_limit().unwrap() {
+                        match listener.try_wait_one() {
+                            Ok(Some(_)) => events += 1,
+                            _ => break,
+                        }
+                    }
+                    CallbackProgression::Continue
+                },
+                TIMEOUT,
+            )
+            .unwrap();
+
+            assert_that!(dispatches, le 1);
+            assert_that!(events, le EVENT_LIMIT);
+            assert_that!(events, ne 0);
+            events_per_wake_up.push(events);
+        }
+
+        assert_that!(events_per_wake_up, eq vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn event_limit_of_zero_is_treated_as_one<S: Service>() {
+        let sut = WaitSetBuilder::new()
+            .event_limit_per_attachment(0)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(sut.event_limit_per_attachment(), eq Some(1));
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -638,4 +904,8 @@ mod waitset {
 
     #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
     mod local_threadsafe {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2::service::ipc_eventfd::Service>)]
+    mod ipc_eventfd {}
 }
@@ -61,6 +61,7 @@ iceoryx2-services-discovery
 
 PACKAGE_LIST_TUNNEL="
 iceoryx2-tunnels-end-to-end-testing
+iceoryx2-tunnels-socket
 iceoryx2-tunnels-zenoh
 "
 